@@ -1,7 +1,8 @@
 //! Types for balance queries.
 use casper_hashing::Digest;
-use casper_types::{Key, StoredValue, URef, U512};
+use casper_types::{account::AccountHash, Key, PublicKey, StoredValue, URef, U512};
 
+use super::Error;
 use crate::storage::trie::merkle_proof::TrieMerkleProof;
 
 /// Result enum that represents all possible outcomes of a balance request.
@@ -36,6 +37,39 @@ impl BalanceResult {
     }
 }
 
+/// Identifies the purse whose balance is requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BalanceIdentifier {
+    /// A purse.
+    Purse(URef),
+    /// The main purse of an account, identified by its account hash.
+    Account(AccountHash),
+    /// The main purse of an account, identified by its public key.
+    PublicKey(PublicKey),
+    /// The payment purse of the handle payment contract.
+    Payment,
+    /// The accumulation purse of the handle payment contract.
+    Accumulate,
+}
+
+impl From<URef> for BalanceIdentifier {
+    fn from(purse_uref: URef) -> Self {
+        BalanceIdentifier::Purse(purse_uref)
+    }
+}
+
+impl From<AccountHash> for BalanceIdentifier {
+    fn from(account_hash: AccountHash) -> Self {
+        BalanceIdentifier::Account(account_hash)
+    }
+}
+
+impl From<PublicKey> for BalanceIdentifier {
+    fn from(public_key: PublicKey) -> Self {
+        BalanceIdentifier::PublicKey(public_key)
+    }
+}
+
 /// Represents a balance request.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BalanceRequest {
@@ -62,3 +96,135 @@ impl BalanceRequest {
         self.purse_uref
     }
 }
+
+/// Represents a request for the balance of the purse identified by a [`BalanceIdentifier`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentifiedBalanceRequest {
+    state_hash: Digest,
+    identifier: BalanceIdentifier,
+}
+
+impl IdentifiedBalanceRequest {
+    /// Creates a new [`IdentifiedBalanceRequest`].
+    pub fn new(state_hash: Digest, identifier: BalanceIdentifier) -> Self {
+        IdentifiedBalanceRequest {
+            state_hash,
+            identifier,
+        }
+    }
+
+    /// Returns a state hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the identifier of the purse.
+    pub fn identifier(&self) -> &BalanceIdentifier {
+        &self.identifier
+    }
+}
+
+impl From<BalanceRequest> for IdentifiedBalanceRequest {
+    fn from(balance_request: BalanceRequest) -> Self {
+        IdentifiedBalanceRequest::new(
+            balance_request.state_hash,
+            BalanceIdentifier::Purse(balance_request.purse_uref),
+        )
+    }
+}
+
+/// Represents a request for the balances of several purses, all read from the same state root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalancesRequest {
+    state_hash: Digest,
+    identifiers: Vec<BalanceIdentifier>,
+}
+
+impl BalancesRequest {
+    /// Creates a new [`BalancesRequest`].
+    pub fn new(state_hash: Digest, identifiers: Vec<BalanceIdentifier>) -> Self {
+        BalancesRequest {
+            state_hash,
+            identifiers,
+        }
+    }
+
+    /// Returns a state hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the identifiers of the purses.
+    pub fn identifiers(&self) -> &[BalanceIdentifier] {
+        &self.identifiers
+    }
+
+    /// Consumes the request, returning the identifiers of the purses.
+    pub fn take_identifiers(self) -> Vec<BalanceIdentifier> {
+        self.identifiers
+    }
+}
+
+/// The balance of a single purse within the result of a [`BalancesRequest`].
+#[derive(Debug)]
+pub struct IdentifiedBalance {
+    identifier: BalanceIdentifier,
+    balance: Result<(U512, Box<TrieMerkleProof<Key, StoredValue>>), Error>,
+}
+
+impl IdentifiedBalance {
+    pub(super) fn new(
+        identifier: BalanceIdentifier,
+        balance: Result<(U512, Box<TrieMerkleProof<Key, StoredValue>>), Error>,
+    ) -> Self {
+        IdentifiedBalance {
+            identifier,
+            balance,
+        }
+    }
+
+    /// Returns the identifier of the purse.
+    pub fn identifier(&self) -> &BalanceIdentifier {
+        &self.identifier
+    }
+
+    /// Returns the amount of motes, or `None` if the balance couldn't be read.
+    pub fn motes(&self) -> Option<&U512> {
+        self.balance.as_ref().ok().map(|(motes, _)| motes)
+    }
+
+    /// Returns the Merkle proof of the balance, or `None` if the balance couldn't be read.
+    pub fn proof(self) -> Option<TrieMerkleProof<Key, StoredValue>> {
+        self.balance.ok().map(|(_, proof)| *proof)
+    }
+
+    /// Returns the error which prevented the balance being read, if any.
+    pub fn error(&self) -> Option<&Error> {
+        self.balance.as_ref().err()
+    }
+}
+
+/// Result enum that represents all possible outcomes of a balances request.
+#[derive(Debug)]
+pub enum BalancesResult {
+    /// Returned if a passed state root hash is not found.
+    RootNotFound,
+    /// The balances, in the order in which their identifiers were requested.
+    ///
+    /// Failing to read one balance, e.g. as the identified account doesn't exist, doesn't affect
+    /// the others.
+    Success {
+        /// The balance of each requested purse.
+        balances: Vec<IdentifiedBalance>,
+    },
+}
+
+impl BalancesResult {
+    /// Returns the balances for a [`BalancesResult::Success`] variant.
+    pub fn into_success(self) -> Option<Vec<IdentifiedBalance>> {
+        match self {
+            BalancesResult::Success { balances } => Some(balances),
+            BalancesResult::RootNotFound => None,
+        }
+    }
+}
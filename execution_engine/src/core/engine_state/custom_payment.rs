@@ -0,0 +1,184 @@
+//! The terms custom payment code is executed under.
+//!
+//! Payment code other than the standard payment may declare two runtime arguments, which the engine
+//! enforces once the payment code has run:
+//!
+//! * [`ARG_AMOUNT`], a `U512`: the least amount of motes the payment code has to deposit into the
+//!   payment purse.  The deposit always has to cover the cost of the payment code itself.
+//! * [`ARG_GAS_BUDGET`], a `u64`: the most gas the payment code may spend.  It can only lower the
+//!   default budget, and caps what the account is charged should the payment code fail.
+//!
+//! Failing custom payment code is reported as [`Error::CustomPaymentGasBudgetExceeded`],
+//! [`Error::CustomPaymentInsufficientDeposit`] or [`Error::CustomPaymentReverted`], so that a
+//! wallet can tell the failures apart.
+
+use num::Zero;
+
+use casper_types::{Gas, Motes, RuntimeArgs, U512};
+
+use crate::core::{
+    engine_state::{Error, ExecutionResult},
+    execution,
+};
+
+/// Name of the argument holding the least amount of motes custom payment code has to deposit.
+pub const ARG_AMOUNT: &str = "amount";
+/// Name of the argument holding the most gas custom payment code may spend.
+pub const ARG_GAS_BUDGET: &str = "gas_budget";
+
+/// The terms custom payment code is executed under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomPaymentTerms {
+    gas_budget: Gas,
+    required_deposit: Motes,
+}
+
+impl CustomPaymentTerms {
+    /// Reads the terms declared by the arguments of the payment code, where `max_gas_budget` is the
+    /// budget of payment code not declaring one.
+    pub fn new(args: &RuntimeArgs, max_gas_budget: Gas) -> Result<Self, Error> {
+        let gas_budget = match args.get(ARG_GAS_BUDGET) {
+            Some(cl_value) => {
+                let gas_budget: u64 = cl_value.clone().into_t().map_err(execution::Error::from)?;
+                Gas::new(U512::from(gas_budget)).min(max_gas_budget)
+            }
+            None => max_gas_budget,
+        };
+        let required_deposit = match args.get(ARG_AMOUNT) {
+            Some(cl_value) => {
+                Motes::new(cl_value.clone().into_t().map_err(execution::Error::from)?)
+            }
+            None => Motes::zero(),
+        };
+        Ok(CustomPaymentTerms {
+            gas_budget,
+            required_deposit,
+        })
+    }
+
+    /// Returns the most gas the payment code may spend.
+    pub fn gas_budget(&self) -> Gas {
+        self.gas_budget
+    }
+
+    /// Returns the least amount of motes the payment code has to deposit, not counting its cost.
+    pub fn required_deposit(&self) -> Motes {
+        self.required_deposit
+    }
+
+    /// Returns the error describing how the payment code with the given result broke the terms, or
+    /// `None` if it kept them or failed in a way not covered by them.
+    pub fn check(
+        &self,
+        payment_result: &ExecutionResult,
+        payment_purse_balance: Motes,
+        gas_price: u64,
+    ) -> Option<Error> {
+        match payment_result.as_error() {
+            Some(Error::Exec(execution::Error::GasLimit)) => {
+                return Some(Error::CustomPaymentGasBudgetExceeded(self.gas_budget))
+            }
+            Some(Error::Exec(execution::Error::Revert(api_error))) => {
+                return Some(Error::CustomPaymentReverted(*api_error))
+            }
+            Some(_) => return None,
+            None => (),
+        }
+        let payment_cost = Motes::from_gas(payment_result.cost(), gas_price)?;
+        let required = self.required_deposit.max(payment_cost);
+        if payment_purse_balance < required {
+            return Some(Error::CustomPaymentInsufficientDeposit {
+                required: Box::new(required),
+                deposited: Box::new(payment_purse_balance),
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{runtime_args, ApiError, RuntimeArgs};
+
+    use super::*;
+
+    const GAS_PRICE: u64 = 1;
+
+    fn max_gas_budget() -> Gas {
+        Gas::new(U512::from(1_000))
+    }
+
+    fn success(cost: u64) -> ExecutionResult {
+        ExecutionResult::default().with_cost(Gas::new(U512::from(cost)))
+    }
+
+    fn failure(error: execution::Error) -> ExecutionResult {
+        ExecutionResult::precondition_failure(Error::Exec(error))
+    }
+
+    #[test]
+    fn should_only_lower_gas_budget() {
+        let terms = CustomPaymentTerms::new(&RuntimeArgs::new(), max_gas_budget()).unwrap();
+        assert_eq!(terms.gas_budget(), max_gas_budget());
+        assert_eq!(terms.required_deposit(), Motes::zero());
+
+        let terms =
+            CustomPaymentTerms::new(&runtime_args! { ARG_GAS_BUDGET => 10u64 }, max_gas_budget())
+                .unwrap();
+        assert_eq!(terms.gas_budget(), Gas::new(U512::from(10)));
+
+        let terms = CustomPaymentTerms::new(
+            &runtime_args! { ARG_GAS_BUDGET => u64::MAX },
+            max_gas_budget(),
+        )
+        .unwrap();
+        assert_eq!(terms.gas_budget(), max_gas_budget());
+
+        assert!(CustomPaymentTerms::new(
+            &runtime_args! { ARG_GAS_BUDGET => "ten" },
+            max_gas_budget()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_categorize_failures() {
+        let terms = CustomPaymentTerms::new(
+            &runtime_args! { ARG_AMOUNT => U512::from(100) },
+            max_gas_budget(),
+        )
+        .unwrap();
+        let deposit = |amount: u64| Motes::new(U512::from(amount));
+
+        assert!(terms.check(&success(10), deposit(100), GAS_PRICE).is_none());
+        assert!(matches!(
+            terms.check(&success(10), deposit(99), GAS_PRICE),
+            Some(Error::CustomPaymentInsufficientDeposit { required, deposited })
+                if *required == deposit(100) && *deposited == deposit(99)
+        ));
+        // The deposit has to cover the cost of the payment code, even if that's more than declared.
+        assert!(matches!(
+            terms.check(&success(200), deposit(150), GAS_PRICE),
+            Some(Error::CustomPaymentInsufficientDeposit { required, .. }) if *required == deposit(200)
+        ));
+        assert!(matches!(
+            terms.check(&failure(execution::Error::GasLimit), deposit(100), GAS_PRICE),
+            Some(Error::CustomPaymentGasBudgetExceeded(gas_budget)) if gas_budget == max_gas_budget()
+        ));
+        assert!(matches!(
+            terms.check(
+                &failure(execution::Error::Revert(ApiError::User(7))),
+                deposit(100),
+                GAS_PRICE
+            ),
+            Some(Error::CustomPaymentReverted(ApiError::User(7)))
+        ));
+        assert!(terms
+            .check(
+                &failure(execution::Error::InvalidContext),
+                deposit(100),
+                GAS_PRICE
+            )
+            .is_none());
+    }
+}
@@ -1,18 +1,37 @@
 //! Support for runtime configuration of the execution engine - as an integral property of the
 //! `EngineState` instance.
+mod compatibility_mode;
+mod delta;
+mod existential_deposit_handling;
+mod fee_distribution;
 mod fee_handling;
 mod refund_handling;
+mod storage_refund;
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use num_rational::Ratio;
 use num_traits::One;
 
-use casper_types::{account::AccountHash, PublicKey};
+use casper_types::{
+    account::AccountHash,
+    system::auction::{DelegationRate, DELEGATION_RATE_DENOMINATOR},
+    ProtocolVersion, PublicKey,
+};
 
-use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
+use crate::shared::{
+    module_cache::DEFAULT_MODULE_CACHE_SIZE, system_config::SystemConfig, wasm_config::WasmConfig,
+};
 
-pub use self::{fee_handling::FeeHandling, refund_handling::RefundHandling};
+pub use self::{
+    compatibility_mode::{CompatibilityMode, CompatibilityModeConfig, Quirk},
+    delta::{EngineConfigDelta, EngineConfigDeltaError},
+    existential_deposit_handling::ExistentialDepositHandling,
+    fee_distribution::FeeDistribution,
+    fee_handling::FeeHandling,
+    refund_handling::RefundHandling,
+    storage_refund::StorageRefund,
+};
 
 /// Default value for a maximum query depth configuration option.
 pub const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
@@ -20,6 +39,12 @@ pub const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
 pub const DEFAULT_MAX_ASSOCIATED_KEYS: u32 = 100;
 /// Default value for maximum runtime call stack height configuration option.
 pub const DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT: u32 = 12;
+/// Default value for maximum number of stored contract frames on the runtime call stack
+/// configuration option.
+pub const DEFAULT_MAX_CONTRACT_CALL_STACK_HEIGHT: u32 = DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT;
+/// Default value for maximum serialized size of the effects of a single deploy configuration
+/// option.
+pub const DEFAULT_MAX_EFFECTS_SIZE_BYTES: u32 = 8 * 1024 * 1024;
 /// Default max serialized size of `StoredValue`s.
 #[deprecated(
     since = "3.2.0",
@@ -47,6 +72,28 @@ pub const DEFAULT_REFUND_HANDLING: RefundHandling = RefundHandling::Refund {
 };
 /// Default fee handling.
 pub const DEFAULT_FEE_HANDLING: FeeHandling = FeeHandling::PayToProposer;
+/// Default existential deposit in motes (disabled).
+pub const DEFAULT_EXISTENTIAL_DEPOSIT: u64 = 0;
+/// Default existential deposit handling.
+pub const DEFAULT_EXISTENTIAL_DEPOSIT_HANDLING: ExistentialDepositHandling =
+    ExistentialDepositHandling::Reject;
+/// Default number of eras a validator has to wait between increases of its delegation rate
+/// (disabled).
+pub const DEFAULT_DELEGATION_RATE_CHANGE_COOLDOWN: u64 = 0;
+/// Default maximum increase of a validator's delegation rate at once (unlimited).
+pub const DEFAULT_MAX_DELEGATION_RATE_INCREASE: DelegationRate = DELEGATION_RATE_DENOMINATOR;
+/// Default gas limit of a call to an account's authorization contract.
+pub const DEFAULT_AUTHORIZATION_GAS_LIMIT: u64 = 1_000_000_000;
+/// Default maximum gas budget of a callback scheduled by a contract.
+pub const DEFAULT_MAX_SCHEDULED_CALLBACK_GAS: u64 = 100_000_000_000;
+/// Default maximum number of scheduled callbacks called by a single step.
+pub const DEFAULT_MAX_SCHEDULED_CALLBACKS_PER_STEP: u32 = 100;
+/// Default value for maintaining the purse ownership index.
+pub const DEFAULT_ENABLE_PURSE_OWNERSHIP_INDEX: bool = false;
+/// Default value for making executions independent of their block time.
+pub const DEFAULT_DETERMINISTIC_EXECUTION: bool = false;
+/// Default value for recording the keys read by each deploy.
+pub const DEFAULT_RECORD_READ_KEYS: bool = false;
 
 /// The runtime configuration of the execution engine
 #[derive(Debug, Clone)]
@@ -58,6 +105,11 @@ pub struct EngineConfig {
     /// [`Weight`](casper_types::account::Weight)s) for a single account.
     max_associated_keys: u32,
     max_runtime_call_stack_height: u32,
+    /// Maximum number of stored contract frames on the runtime call stack, enforced in addition to
+    /// `max_runtime_call_stack_height`.
+    max_contract_call_stack_height: u32,
+    /// Maximum serialized size in bytes of the effects of a single deploy's session code.
+    max_effects_size_bytes: u32,
     minimum_delegation_amount: u64,
     /// This flag indicates if arguments passed to contracts are checked against the defined types.
     strict_argument_checking: bool,
@@ -81,6 +133,47 @@ pub struct EngineConfig {
     pub(crate) refund_handling: RefundHandling,
     /// Fee handling.
     pub(crate) fee_handling: FeeHandling,
+    /// Distribution of accumulated fees.
+    pub(crate) fee_distribution: FeeDistribution,
+    /// Minimum balance in motes an account's main purse has to retain after a transfer, unless it
+    /// is emptied entirely. A value of `0` disables the check.
+    pub(crate) existential_deposit: u64,
+    /// Existential deposit handling.
+    pub(crate) existential_deposit_handling: ExistentialDepositHandling,
+    /// Gas refunded for freed storage.
+    pub(crate) storage_refund: StorageRefund,
+    /// Number of eras a validator has to wait after increasing its delegation rate before
+    /// increasing it again. A value of `0` disables the check.
+    pub(crate) delegation_rate_change_cooldown: u64,
+    /// Maximum amount by which a validator can increase its delegation rate at once.
+    pub(crate) max_delegation_rate_increase: DelegationRate,
+    /// Names of the auction entry points which fail when called.
+    disabled_auction_entry_points: BTreeSet<String>,
+    /// Whether the mint records the owner of each purse it creates under a [`Key::PurseOwner`].
+    ///
+    /// [`Key::PurseOwner`]: casper_types::Key::PurseOwner
+    enable_purse_ownership_index: bool,
+    /// Gas limit of a call to an account's authorization contract.
+    authorization_gas_limit: u64,
+    /// Maximum gas budget of a callback scheduled by a contract.
+    max_scheduled_callback_gas: u64,
+    /// Maximum number of scheduled callbacks called by a single step.  Further due callbacks are
+    /// called by later steps.
+    max_scheduled_callbacks_per_step: u32,
+    /// Capacity in bytes of the cache of preprocessed session and payment modules.
+    module_cache_size: usize,
+    /// Whether the keys read by each deploy are recorded in its execution result.
+    record_read_keys: bool,
+    /// Whether executions are made independent of inputs which vary between otherwise identical
+    /// runs, so their effects can be compared against golden files.
+    deterministic_execution: bool,
+    /// Behavior switches for earlier protocol versions, keyed by the last protocol version each
+    /// applies to.
+    compatibility_modes: BTreeMap<ProtocolVersion, CompatibilityMode>,
+    /// Host functions which can't be imported by Wasm.
+    unavailable_host_functions: BTreeSet<String>,
+    /// Behaviors of earlier protocol versions which are reproduced.
+    quirks: BTreeSet<Quirk>,
 }
 
 impl Default for EngineConfig {
@@ -89,6 +182,8 @@ impl Default for EngineConfig {
             max_query_depth: DEFAULT_MAX_QUERY_DEPTH,
             max_associated_keys: DEFAULT_MAX_ASSOCIATED_KEYS,
             max_runtime_call_stack_height: DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT,
+            max_contract_call_stack_height: DEFAULT_MAX_CONTRACT_CALL_STACK_HEIGHT,
+            max_effects_size_bytes: DEFAULT_MAX_EFFECTS_SIZE_BYTES,
             minimum_delegation_amount: DEFAULT_MINIMUM_DELEGATION_AMOUNT,
             strict_argument_checking: DEFAULT_STRICT_ARGUMENT_CHECKING,
             vesting_schedule_period_millis: DEFAULT_VESTING_SCHEDULE_LENGTH_MILLIS,
@@ -100,6 +195,23 @@ impl Default for EngineConfig {
             allow_unrestricted_transfers: DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS,
             refund_handling: DEFAULT_REFUND_HANDLING,
             fee_handling: DEFAULT_FEE_HANDLING,
+            fee_distribution: FeeDistribution::default(),
+            existential_deposit: DEFAULT_EXISTENTIAL_DEPOSIT,
+            existential_deposit_handling: DEFAULT_EXISTENTIAL_DEPOSIT_HANDLING,
+            storage_refund: StorageRefund::default(),
+            delegation_rate_change_cooldown: DEFAULT_DELEGATION_RATE_CHANGE_COOLDOWN,
+            max_delegation_rate_increase: DEFAULT_MAX_DELEGATION_RATE_INCREASE,
+            disabled_auction_entry_points: BTreeSet::new(),
+            enable_purse_ownership_index: DEFAULT_ENABLE_PURSE_OWNERSHIP_INDEX,
+            authorization_gas_limit: DEFAULT_AUTHORIZATION_GAS_LIMIT,
+            max_scheduled_callback_gas: DEFAULT_MAX_SCHEDULED_CALLBACK_GAS,
+            max_scheduled_callbacks_per_step: DEFAULT_MAX_SCHEDULED_CALLBACKS_PER_STEP,
+            module_cache_size: DEFAULT_MODULE_CACHE_SIZE,
+            record_read_keys: DEFAULT_RECORD_READ_KEYS,
+            deterministic_execution: DEFAULT_DETERMINISTIC_EXECUTION,
+            compatibility_modes: BTreeMap::new(),
+            unavailable_host_functions: BTreeSet::new(),
+            quirks: BTreeSet::new(),
         }
     }
 }
@@ -129,6 +241,8 @@ impl EngineConfig {
             max_query_depth,
             max_associated_keys,
             max_runtime_call_stack_height,
+            max_contract_call_stack_height: DEFAULT_MAX_CONTRACT_CALL_STACK_HEIGHT,
+            max_effects_size_bytes: DEFAULT_MAX_EFFECTS_SIZE_BYTES,
             minimum_delegation_amount,
             strict_argument_checking,
             vesting_schedule_period_millis,
@@ -140,6 +254,23 @@ impl EngineConfig {
             allow_unrestricted_transfers: DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS,
             refund_handling: DEFAULT_REFUND_HANDLING,
             fee_handling: DEFAULT_FEE_HANDLING,
+            fee_distribution: FeeDistribution::default(),
+            existential_deposit: DEFAULT_EXISTENTIAL_DEPOSIT,
+            existential_deposit_handling: DEFAULT_EXISTENTIAL_DEPOSIT_HANDLING,
+            storage_refund: StorageRefund::default(),
+            delegation_rate_change_cooldown: DEFAULT_DELEGATION_RATE_CHANGE_COOLDOWN,
+            max_delegation_rate_increase: DEFAULT_MAX_DELEGATION_RATE_INCREASE,
+            disabled_auction_entry_points: BTreeSet::new(),
+            enable_purse_ownership_index: DEFAULT_ENABLE_PURSE_OWNERSHIP_INDEX,
+            authorization_gas_limit: DEFAULT_AUTHORIZATION_GAS_LIMIT,
+            max_scheduled_callback_gas: DEFAULT_MAX_SCHEDULED_CALLBACK_GAS,
+            max_scheduled_callbacks_per_step: DEFAULT_MAX_SCHEDULED_CALLBACKS_PER_STEP,
+            module_cache_size: DEFAULT_MODULE_CACHE_SIZE,
+            record_read_keys: DEFAULT_RECORD_READ_KEYS,
+            deterministic_execution: DEFAULT_DETERMINISTIC_EXECUTION,
+            compatibility_modes: BTreeMap::new(),
+            unavailable_host_functions: BTreeSet::new(),
+            quirks: BTreeSet::new(),
         }
     }
 
@@ -153,6 +284,16 @@ impl EngineConfig {
         self.max_runtime_call_stack_height
     }
 
+    /// Returns the current max contract call stack height config.
+    pub fn max_contract_call_stack_height(&self) -> u32 {
+        self.max_contract_call_stack_height
+    }
+
+    /// Returns the current max effects size config.
+    pub fn max_effects_size_bytes(&self) -> u32 {
+        self.max_effects_size_bytes
+    }
+
     /// Returns the current wasm config.
     pub fn wasm_config(&self) -> &WasmConfig {
         &self.wasm_config
@@ -213,6 +354,132 @@ impl EngineConfig {
         self.fee_handling
     }
 
+    /// Returns the engine config's distribution of accumulated fees.
+    pub fn fee_distribution(&self) -> &FeeDistribution {
+        &self.fee_distribution
+    }
+
+    /// Returns the existential deposit in motes.
+    pub fn existential_deposit(&self) -> u64 {
+        self.existential_deposit
+    }
+
+    /// Returns the engine config's existential deposit handling strategy.
+    pub fn existential_deposit_handling(&self) -> ExistentialDepositHandling {
+        self.existential_deposit_handling
+    }
+
+    /// Returns the engine config's refund for freed storage.
+    pub fn storage_refund(&self) -> StorageRefund {
+        self.storage_refund
+    }
+
+    /// Returns the number of eras a validator has to wait between increases of its delegation
+    /// rate.
+    pub fn delegation_rate_change_cooldown(&self) -> u64 {
+        self.delegation_rate_change_cooldown
+    }
+
+    /// Returns the maximum amount by which a validator can increase its delegation rate at once.
+    pub fn max_delegation_rate_increase(&self) -> DelegationRate {
+        self.max_delegation_rate_increase
+    }
+
+    /// Returns the names of the auction entry points which fail when called.
+    pub fn disabled_auction_entry_points(&self) -> &BTreeSet<String> {
+        &self.disabled_auction_entry_points
+    }
+
+    /// Returns `true` if the given auction entry point is disabled.
+    pub(crate) fn is_auction_entry_point_disabled(&self, entry_point_name: &str) -> bool {
+        self.disabled_auction_entry_points
+            .contains(entry_point_name)
+    }
+
+    /// Returns `true` if the mint records the owner of each purse it creates.
+    pub fn enable_purse_ownership_index(&self) -> bool {
+        self.enable_purse_ownership_index
+    }
+
+    /// Returns the gas limit of a call to an account's authorization contract.
+    pub fn authorization_gas_limit(&self) -> u64 {
+        self.authorization_gas_limit
+    }
+
+    /// Returns the maximum gas budget of a callback scheduled by a contract.
+    pub fn max_scheduled_callback_gas(&self) -> u64 {
+        self.max_scheduled_callback_gas
+    }
+
+    /// Returns the maximum number of scheduled callbacks called by a single step.
+    pub fn max_scheduled_callbacks_per_step(&self) -> u32 {
+        self.max_scheduled_callbacks_per_step
+    }
+
+    /// Returns the capacity in bytes of the cache of preprocessed session and payment modules.
+    pub fn module_cache_size(&self) -> usize {
+        self.module_cache_size
+    }
+
+    /// Returns whether the keys read by each deploy are recorded in its execution result.
+    pub fn record_read_keys(&self) -> bool {
+        self.record_read_keys
+    }
+
+    /// Returns the compatibility modes, keyed by the last protocol version each applies to.
+    pub fn compatibility_modes(&self) -> &BTreeMap<ProtocolVersion, CompatibilityMode> {
+        &self.compatibility_modes
+    }
+
+    /// Returns the config to execute blocks of `protocol_version` with.
+    ///
+    /// This is the config itself with the overrides of the compatibility mode registered for the
+    /// lowest protocol version not below `protocol_version` applied.  Protocol versions newer than
+    /// every compatibility mode are executed with the config unchanged.
+    pub fn for_protocol_version(&self, protocol_version: ProtocolVersion) -> EngineConfig {
+        let mut config = self.clone();
+        let compatibility_mode = match self.compatibility_modes.range(protocol_version..).next() {
+            Some((_, compatibility_mode)) => compatibility_mode,
+            None => return config,
+        };
+        if let Some(wasm_config) = compatibility_mode.wasm_config() {
+            config.wasm_config = *wasm_config;
+        }
+        if let Some(system_config) = compatibility_mode.system_config() {
+            config.system_config = *system_config;
+        }
+        config.unavailable_host_functions = compatibility_mode.unavailable_host_functions().clone();
+        config.quirks = compatibility_mode.quirks().clone();
+        config
+    }
+
+    /// Returns `true` if `quirk` is reproduced when executing blocks of `protocol_version`, without
+    /// building the whole config for it.
+    pub fn has_quirk_at(&self, protocol_version: ProtocolVersion, quirk: Quirk) -> bool {
+        self.compatibility_modes
+            .range(protocol_version..)
+            .next()
+            .map_or(false, |(_, compatibility_mode)| {
+                compatibility_mode.quirks().contains(&quirk)
+            })
+    }
+
+    /// Returns the names of the host functions Wasm can't import.
+    pub fn unavailable_host_functions(&self) -> &BTreeSet<String> {
+        &self.unavailable_host_functions
+    }
+
+    /// Returns `true` if executions are made independent of inputs which vary between otherwise
+    /// identical runs.
+    pub fn deterministic_execution(&self) -> bool {
+        self.deterministic_execution
+    }
+
+    /// Returns `true` if `quirk` is reproduced.
+    pub fn has_quirk(&self, quirk: Quirk) -> bool {
+        self.quirks.contains(&quirk)
+    }
+
     /// Sets the `wasm_config.max_memory` to `new_value`.
     #[cfg(feature = "test-support")]
     pub fn set_max_memory(&mut self, new_value: u32) {
@@ -229,6 +496,8 @@ pub struct EngineConfigBuilder {
     max_query_depth: Option<u64>,
     max_associated_keys: Option<u32>,
     max_runtime_call_stack_height: Option<u32>,
+    max_contract_call_stack_height: Option<u32>,
+    max_effects_size_bytes: Option<u32>,
     minimum_delegation_amount: Option<u64>,
     strict_argument_checking: Option<bool>,
     vesting_schedule_period_millis: Option<u64>,
@@ -240,6 +509,21 @@ pub struct EngineConfigBuilder {
     allow_unrestricted_transfers: Option<bool>,
     refund_handling: Option<RefundHandling>,
     fee_handling: Option<FeeHandling>,
+    fee_distribution: Option<FeeDistribution>,
+    existential_deposit: Option<u64>,
+    existential_deposit_handling: Option<ExistentialDepositHandling>,
+    storage_refund: Option<StorageRefund>,
+    delegation_rate_change_cooldown: Option<u64>,
+    max_delegation_rate_increase: Option<DelegationRate>,
+    disabled_auction_entry_points: Option<BTreeSet<String>>,
+    enable_purse_ownership_index: Option<bool>,
+    authorization_gas_limit: Option<u64>,
+    max_scheduled_callback_gas: Option<u64>,
+    max_scheduled_callbacks_per_step: Option<u32>,
+    module_cache_size: Option<usize>,
+    record_read_keys: Option<bool>,
+    deterministic_execution: Option<bool>,
+    compatibility_modes: BTreeMap<ProtocolVersion, CompatibilityMode>,
 }
 
 impl EngineConfigBuilder {
@@ -269,6 +553,21 @@ impl EngineConfigBuilder {
         self
     }
 
+    /// Sets the max contract call stack height config option.
+    pub fn with_max_contract_call_stack_height(
+        mut self,
+        max_contract_call_stack_height: u32,
+    ) -> Self {
+        self.max_contract_call_stack_height = Some(max_contract_call_stack_height);
+        self
+    }
+
+    /// Sets the max effects size config option.
+    pub fn with_max_effects_size_bytes(mut self, max_effects_size_bytes: u32) -> Self {
+        self.max_effects_size_bytes = Some(max_effects_size_bytes);
+        self
+    }
+
     /// Sets the strict argument checking config option.
     pub fn with_strict_argument_checking(mut self, value: bool) -> Self {
         self.strict_argument_checking = Some(value);
@@ -354,6 +653,126 @@ impl EngineConfigBuilder {
         self
     }
 
+    /// Sets the distribution of accumulated fees config option.
+    pub fn with_fee_distribution(mut self, fee_distribution: FeeDistribution) -> Self {
+        self.fee_distribution = Some(fee_distribution);
+        self
+    }
+
+    /// Sets the existential deposit config option.
+    pub fn with_existential_deposit(mut self, existential_deposit: u64) -> Self {
+        self.existential_deposit = Some(existential_deposit);
+        self
+    }
+
+    /// Sets the existential deposit handling config option.
+    pub fn with_existential_deposit_handling(
+        mut self,
+        existential_deposit_handling: ExistentialDepositHandling,
+    ) -> Self {
+        self.existential_deposit_handling = Some(existential_deposit_handling);
+        self
+    }
+
+    /// Sets the refund for freed storage config option.
+    pub fn with_storage_refund(mut self, storage_refund: StorageRefund) -> Self {
+        self.storage_refund = Some(storage_refund);
+        self
+    }
+
+    /// Sets the number of eras a validator has to wait between increases of its delegation rate.
+    pub fn with_delegation_rate_change_cooldown(
+        mut self,
+        delegation_rate_change_cooldown: u64,
+    ) -> Self {
+        self.delegation_rate_change_cooldown = Some(delegation_rate_change_cooldown);
+        self
+    }
+
+    /// Sets the maximum amount by which a validator can increase its delegation rate at once.
+    pub fn with_max_delegation_rate_increase(
+        mut self,
+        max_delegation_rate_increase: DelegationRate,
+    ) -> Self {
+        self.max_delegation_rate_increase = Some(max_delegation_rate_increase);
+        self
+    }
+
+    /// Sets the names of the auction entry points which fail when called.
+    pub fn with_disabled_auction_entry_points(
+        mut self,
+        disabled_auction_entry_points: BTreeSet<String>,
+    ) -> Self {
+        self.disabled_auction_entry_points = Some(disabled_auction_entry_points);
+        self
+    }
+
+    /// Sets whether the mint records the owner of each purse it creates.
+    pub fn with_enable_purse_ownership_index(mut self, enable_purse_ownership_index: bool) -> Self {
+        self.enable_purse_ownership_index = Some(enable_purse_ownership_index);
+        self
+    }
+
+    /// Sets the gas limit of a call to an account's authorization contract.
+    pub fn with_authorization_gas_limit(mut self, authorization_gas_limit: u64) -> Self {
+        self.authorization_gas_limit = Some(authorization_gas_limit);
+        self
+    }
+
+    /// Sets the maximum gas budget of a callback scheduled by a contract.
+    pub fn with_max_scheduled_callback_gas(mut self, max_scheduled_callback_gas: u64) -> Self {
+        self.max_scheduled_callback_gas = Some(max_scheduled_callback_gas);
+        self
+    }
+
+    /// Sets the maximum number of scheduled callbacks called by a single step.
+    pub fn with_max_scheduled_callbacks_per_step(
+        mut self,
+        max_scheduled_callbacks_per_step: u32,
+    ) -> Self {
+        self.max_scheduled_callbacks_per_step = Some(max_scheduled_callbacks_per_step);
+        self
+    }
+
+    /// Sets the capacity in bytes of the cache of preprocessed session and payment modules.  A
+    /// size of `0` disables the cache.
+    pub fn with_module_cache_size(mut self, module_cache_size: usize) -> Self {
+        self.module_cache_size = Some(module_cache_size);
+        self
+    }
+
+    /// Sets whether the keys read by each deploy, including keys holding no value, are recorded in
+    /// its execution result.
+    pub fn with_record_read_keys(mut self, record_read_keys: bool) -> Self {
+        self.record_read_keys = Some(record_read_keys);
+        self
+    }
+
+    /// Sets whether executions are made independent of inputs which vary between otherwise
+    /// identical runs.
+    ///
+    /// Contracts then see a block time of zero, and the addresses they create are derived from
+    /// nothing but the deploy hash and the execution phase.  Only meant for tests comparing
+    /// execution effects against golden files.
+    #[cfg(feature = "test-support")]
+    pub fn with_deterministic_execution(mut self, deterministic_execution: bool) -> Self {
+        self.deterministic_execution = Some(deterministic_execution);
+        self
+    }
+
+    /// Registers the behavior switches for executing blocks of protocol versions up to and
+    /// including `last_protocol_version` which aren't covered by a mode registered for a lower
+    /// protocol version.
+    pub fn with_compatibility_mode(
+        mut self,
+        last_protocol_version: ProtocolVersion,
+        compatibility_mode: CompatibilityMode,
+    ) -> Self {
+        self.compatibility_modes
+            .insert(last_protocol_version, compatibility_mode);
+        self
+    }
+
     /// Builds a new [`EngineConfig`] object.
     pub fn build(self) -> EngineConfig {
         let max_query_depth = self.max_query_depth.unwrap_or(DEFAULT_MAX_QUERY_DEPTH);
@@ -363,6 +782,12 @@ impl EngineConfigBuilder {
         let max_runtime_call_stack_height = self
             .max_runtime_call_stack_height
             .unwrap_or(DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT);
+        let max_contract_call_stack_height = self
+            .max_contract_call_stack_height
+            .unwrap_or(DEFAULT_MAX_CONTRACT_CALL_STACK_HEIGHT);
+        let max_effects_size_bytes = self
+            .max_effects_size_bytes
+            .unwrap_or(DEFAULT_MAX_EFFECTS_SIZE_BYTES);
         let minimum_delegation_amount = self
             .minimum_delegation_amount
             .unwrap_or(DEFAULT_MINIMUM_DELEGATION_AMOUNT);
@@ -383,6 +808,38 @@ impl EngineConfigBuilder {
             .unwrap_or(DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS);
         let refund_handling = self.refund_handling.unwrap_or(DEFAULT_REFUND_HANDLING);
         let fee_handling = self.fee_handling.unwrap_or(DEFAULT_FEE_HANDLING);
+        let fee_distribution = self.fee_distribution.unwrap_or_default();
+        let existential_deposit = self
+            .existential_deposit
+            .unwrap_or(DEFAULT_EXISTENTIAL_DEPOSIT);
+        let existential_deposit_handling = self
+            .existential_deposit_handling
+            .unwrap_or(DEFAULT_EXISTENTIAL_DEPOSIT_HANDLING);
+        let storage_refund = self.storage_refund.unwrap_or_default();
+        let delegation_rate_change_cooldown = self
+            .delegation_rate_change_cooldown
+            .unwrap_or(DEFAULT_DELEGATION_RATE_CHANGE_COOLDOWN);
+        let max_delegation_rate_increase = self
+            .max_delegation_rate_increase
+            .unwrap_or(DEFAULT_MAX_DELEGATION_RATE_INCREASE);
+        let disabled_auction_entry_points = self.disabled_auction_entry_points.unwrap_or_default();
+        let enable_purse_ownership_index = self
+            .enable_purse_ownership_index
+            .unwrap_or(DEFAULT_ENABLE_PURSE_OWNERSHIP_INDEX);
+        let authorization_gas_limit = self
+            .authorization_gas_limit
+            .unwrap_or(DEFAULT_AUTHORIZATION_GAS_LIMIT);
+        let max_scheduled_callback_gas = self
+            .max_scheduled_callback_gas
+            .unwrap_or(DEFAULT_MAX_SCHEDULED_CALLBACK_GAS);
+        let max_scheduled_callbacks_per_step = self
+            .max_scheduled_callbacks_per_step
+            .unwrap_or(DEFAULT_MAX_SCHEDULED_CALLBACKS_PER_STEP);
+        let module_cache_size = self.module_cache_size.unwrap_or(DEFAULT_MODULE_CACHE_SIZE);
+        let record_read_keys = self.record_read_keys.unwrap_or(DEFAULT_RECORD_READ_KEYS);
+        let deterministic_execution = self
+            .deterministic_execution
+            .unwrap_or(DEFAULT_DETERMINISTIC_EXECUTION);
 
         let strict_argument_checking = self
             .strict_argument_checking
@@ -396,6 +853,8 @@ impl EngineConfigBuilder {
             max_query_depth,
             max_associated_keys,
             max_runtime_call_stack_height,
+            max_contract_call_stack_height,
+            max_effects_size_bytes,
             minimum_delegation_amount,
             wasm_config,
             system_config,
@@ -404,9 +863,26 @@ impl EngineConfigBuilder {
             allow_unrestricted_transfers,
             refund_handling,
             fee_handling,
+            fee_distribution,
+            existential_deposit,
+            existential_deposit_handling,
+            storage_refund,
+            delegation_rate_change_cooldown,
+            max_delegation_rate_increase,
+            disabled_auction_entry_points,
+            enable_purse_ownership_index,
+            authorization_gas_limit,
+            max_scheduled_callback_gas,
+            max_scheduled_callbacks_per_step,
+            module_cache_size,
+            record_read_keys,
+            deterministic_execution,
             strict_argument_checking,
             vesting_schedule_period_millis,
             max_delegators_per_validator,
+            compatibility_modes: self.compatibility_modes,
+            unavailable_host_functions: BTreeSet::new(),
+            quirks: BTreeSet::new(),
         }
     }
 }
@@ -0,0 +1,282 @@
+use std::collections::BTreeSet;
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    ProtocolVersion,
+};
+
+use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
+
+const QUIRK_REINVEST_ONLY_DELEGATOR_REWARDS_TAG: u8 = 0;
+const QUIRK_UNRECORDED_PAYMENT_TRANSFERS_TAG: u8 = 1;
+const QUIRK_UNRECORDED_BLOCK_EVENTS_TAG: u8 = 2;
+const QUIRK_UNRECORDED_BLOCK_GAS_UTILIZATION_TAG: u8 = 3;
+const QUIRK_NO_NAMED_PURSE_TRANSFER_TARGETS_TAG: u8 = 4;
+const QUIRK_UNLIMITED_EFFECTS_SIZE_TAG: u8 = 5;
+
+/// A behavior of an earlier protocol version which has since been changed, but which has to be
+/// reproduced to re-execute blocks of that protocol version exactly.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, DataSize,
+)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Quirk {
+    /// Rewards paid out to delegators are only reinvested, without being recorded as withdrawable
+    /// through the auction's `withdraw_delegator_rewards` entry point.
+    ReinvestOnlyDelegatorRewards,
+    /// Refunds and fees moved out of the payment purse while finalizing payment aren't recorded as
+    /// transfers.
+    UnrecordedPaymentTransfers,
+    /// The events contracts emit in a block aren't recorded under `Key::BlockEvents`, nor is their
+    /// checksum added to the checksum registry.
+    UnrecordedBlockEvents,
+    /// The gas consumed by the deploys of a block isn't recorded under `Key::BlockGasUtilization`.
+    UnrecordedBlockGasUtilization,
+    /// The `target` argument of a native transfer can't be a `String` naming a purse of the
+    /// initiator.
+    NoNamedPurseTransferTargets,
+    /// The serialized size of a deploy's session effects isn't limited, so session code never fails
+    /// with `EffectsSizeExceeded`.
+    UnlimitedEffectsSize,
+}
+
+impl ToBytes for Quirk {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            Quirk::ReinvestOnlyDelegatorRewards => QUIRK_REINVEST_ONLY_DELEGATOR_REWARDS_TAG,
+            Quirk::UnrecordedPaymentTransfers => QUIRK_UNRECORDED_PAYMENT_TRANSFERS_TAG,
+            Quirk::UnrecordedBlockEvents => QUIRK_UNRECORDED_BLOCK_EVENTS_TAG,
+            Quirk::UnrecordedBlockGasUtilization => QUIRK_UNRECORDED_BLOCK_GAS_UTILIZATION_TAG,
+            Quirk::NoNamedPurseTransferTargets => QUIRK_NO_NAMED_PURSE_TRANSFER_TARGETS_TAG,
+            Quirk::UnlimitedEffectsSize => QUIRK_UNLIMITED_EFFECTS_SIZE_TAG,
+        };
+        Ok(vec![tag])
+    }
+
+    fn serialized_length(&self) -> usize {
+        1
+    }
+}
+
+impl FromBytes for Quirk {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let quirk = match tag {
+            QUIRK_REINVEST_ONLY_DELEGATOR_REWARDS_TAG => Quirk::ReinvestOnlyDelegatorRewards,
+            QUIRK_UNRECORDED_PAYMENT_TRANSFERS_TAG => Quirk::UnrecordedPaymentTransfers,
+            QUIRK_UNRECORDED_BLOCK_EVENTS_TAG => Quirk::UnrecordedBlockEvents,
+            QUIRK_UNRECORDED_BLOCK_GAS_UTILIZATION_TAG => Quirk::UnrecordedBlockGasUtilization,
+            QUIRK_NO_NAMED_PURSE_TRANSFER_TARGETS_TAG => Quirk::NoNamedPurseTransferTargets,
+            QUIRK_UNLIMITED_EFFECTS_SIZE_TAG => Quirk::UnlimitedEffectsSize,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((quirk, remainder))
+    }
+}
+
+/// The behavior switches the execution engine applies when executing blocks of a range of earlier
+/// protocol versions.
+///
+/// Anything not overridden by the mode is taken from the engine's current config.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompatibilityMode {
+    wasm_config: Option<WasmConfig>,
+    system_config: Option<SystemConfig>,
+    unavailable_host_functions: BTreeSet<String>,
+    quirks: BTreeSet<Quirk>,
+}
+
+impl CompatibilityMode {
+    /// Creates a new `CompatibilityMode` which doesn't override anything.
+    pub fn new() -> Self {
+        CompatibilityMode::default()
+    }
+
+    /// Overrides the wasm config, which holds the opcode, storage and host function costs.
+    pub fn with_wasm_config(mut self, wasm_config: WasmConfig) -> Self {
+        self.wasm_config = Some(wasm_config);
+        self
+    }
+
+    /// Overrides the system config, which holds the costs of system contract calls.
+    pub fn with_system_config(mut self, system_config: SystemConfig) -> Self {
+        self.system_config = Some(system_config);
+        self
+    }
+
+    /// Makes the host function `name` unavailable, so Wasm importing it fails to instantiate as
+    /// it did before the host function was introduced.
+    pub fn with_unavailable_host_function(mut self, name: &str) -> Self {
+        self.unavailable_host_functions.insert(name.to_string());
+        self
+    }
+
+    /// Enables `quirk`.
+    pub fn with_quirk(mut self, quirk: Quirk) -> Self {
+        self.quirks.insert(quirk);
+        self
+    }
+
+    /// Returns the overridden wasm config, if any.
+    pub fn wasm_config(&self) -> Option<&WasmConfig> {
+        self.wasm_config.as_ref()
+    }
+
+    /// Returns the overridden system config, if any.
+    pub fn system_config(&self) -> Option<&SystemConfig> {
+        self.system_config.as_ref()
+    }
+
+    /// Returns the names of the host functions which are unavailable.
+    pub fn unavailable_host_functions(&self) -> &BTreeSet<String> {
+        &self.unavailable_host_functions
+    }
+
+    /// Returns the enabled quirks.
+    pub fn quirks(&self) -> &BTreeSet<Quirk> {
+        &self.quirks
+    }
+}
+
+/// A compatibility mode as configured in the chainspec, which can only make host functions
+/// unavailable and enable quirks, as the costs of earlier protocol versions aren't part of it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DataSize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct CompatibilityModeConfig {
+    /// The latest protocol version the mode applies to.
+    pub protocol_version: ProtocolVersion,
+    /// Names of the host functions unavailable under the mode.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub unavailable_host_functions: BTreeSet<String>,
+    /// The quirks enabled under the mode.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub quirks: BTreeSet<Quirk>,
+}
+
+impl CompatibilityModeConfig {
+    /// Returns the configured compatibility mode.
+    pub fn compatibility_mode(&self) -> CompatibilityMode {
+        CompatibilityMode {
+            wasm_config: None,
+            system_config: None,
+            unavailable_host_functions: self.unavailable_host_functions.clone(),
+            quirks: self.quirks.clone(),
+        }
+    }
+}
+
+impl ToBytes for CompatibilityModeConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.protocol_version.to_bytes()?);
+        buffer.extend(self.unavailable_host_functions.to_bytes()?);
+        buffer.extend(self.quirks.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.protocol_version.serialized_length()
+            + self.unavailable_host_functions.serialized_length()
+            + self.quirks.serialized_length()
+    }
+}
+
+impl FromBytes for CompatibilityModeConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (protocol_version, remainder) = ProtocolVersion::from_bytes(bytes)?;
+        let (unavailable_host_functions, remainder) = BTreeSet::from_bytes(remainder)?;
+        let (quirks, remainder) = BTreeSet::from_bytes(remainder)?;
+        let config = CompatibilityModeConfig {
+            protocol_version,
+            unavailable_host_functions,
+            quirks,
+        };
+        Ok((config, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use super::*;
+    use crate::core::engine_state::EngineConfigBuilder;
+
+    const EMIT_EVENT: &str = "casper_emit_event";
+
+    #[test]
+    fn should_select_mode_of_lowest_covering_protocol_version() {
+        let config = EngineConfigBuilder::new()
+            .with_compatibility_mode(
+                ProtocolVersion::from_parts(1, 2, 0),
+                CompatibilityMode::new()
+                    .with_unavailable_host_function(EMIT_EVENT)
+                    .with_quirk(Quirk::ReinvestOnlyDelegatorRewards),
+            )
+            .with_compatibility_mode(
+                ProtocolVersion::from_parts(1, 4, 0),
+                CompatibilityMode::new().with_quirk(Quirk::ReinvestOnlyDelegatorRewards),
+            )
+            .build();
+
+        let config_1_0_0 = config.for_protocol_version(ProtocolVersion::V1_0_0);
+        assert!(config_1_0_0
+            .unavailable_host_functions()
+            .contains(EMIT_EVENT));
+        assert!(config_1_0_0.has_quirk(Quirk::ReinvestOnlyDelegatorRewards));
+
+        let config_1_3_0 = config.for_protocol_version(ProtocolVersion::from_parts(1, 3, 0));
+        assert!(config_1_3_0.unavailable_host_functions().is_empty());
+        assert!(config_1_3_0.has_quirk(Quirk::ReinvestOnlyDelegatorRewards));
+
+        let config_1_4_0 = config.for_protocol_version(ProtocolVersion::from_parts(1, 4, 0));
+        assert!(config_1_4_0.has_quirk(Quirk::ReinvestOnlyDelegatorRewards));
+
+        let config_1_5_0 = config.for_protocol_version(ProtocolVersion::from_parts(1, 5, 0));
+        assert!(config_1_5_0.unavailable_host_functions().is_empty());
+        assert!(!config_1_5_0.has_quirk(Quirk::ReinvestOnlyDelegatorRewards));
+
+        assert!(config.has_quirk_at(
+            ProtocolVersion::from_parts(1, 3, 0),
+            Quirk::ReinvestOnlyDelegatorRewards
+        ));
+        assert!(!config.has_quirk_at(
+            ProtocolVersion::from_parts(1, 5, 0),
+            Quirk::ReinvestOnlyDelegatorRewards
+        ));
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip_for_config() {
+        let config = CompatibilityModeConfig {
+            protocol_version: ProtocolVersion::from_parts(1, 5, 6),
+            unavailable_host_functions: iter::once(EMIT_EVENT.to_string()).collect(),
+            quirks: [
+                Quirk::ReinvestOnlyDelegatorRewards,
+                Quirk::UnrecordedPaymentTransfers,
+                Quirk::UnrecordedBlockEvents,
+                Quirk::UnrecordedBlockGasUtilization,
+                Quirk::NoNamedPurseTransferTargets,
+                Quirk::UnlimitedEffectsSize,
+            ]
+            .into_iter()
+            .collect(),
+        };
+        bytesrepr::test_serialization_roundtrip(&config);
+    }
+
+    #[test]
+    fn should_keep_current_costs_unless_overridden() {
+        let config = EngineConfigBuilder::new()
+            .with_compatibility_mode(ProtocolVersion::V1_0_0, CompatibilityMode::new())
+            .build();
+        let legacy_config = config.for_protocol_version(ProtocolVersion::V1_0_0);
+        assert_eq!(legacy_config.wasm_config(), config.wasm_config());
+        assert_eq!(legacy_config.system_config(), config.system_config());
+    }
+}
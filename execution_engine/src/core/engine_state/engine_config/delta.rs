@@ -0,0 +1,303 @@
+use thiserror::Error;
+
+use super::EngineConfig;
+
+/// An error validating an [`EngineConfigDelta`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EngineConfigDeltaError {
+    /// The max query depth must be at least 1.
+    #[error("max query depth must be at least 1")]
+    InvalidMaxQueryDepth,
+    /// The max associated keys must be at least 1.
+    #[error("max associated keys must be at least 1")]
+    InvalidMaxAssociatedKeys,
+    /// The max runtime call stack height must be at least 1.
+    #[error("max runtime call stack height must be at least 1")]
+    InvalidMaxRuntimeCallStackHeight,
+    /// The max contract call stack height must be at least 1, and not above the max runtime call
+    /// stack height.
+    #[error(
+        "max contract call stack height {max_contract_call_stack_height} must be at least 1 and \
+        not above the max runtime call stack height {max_runtime_call_stack_height}"
+    )]
+    InvalidMaxContractCallStackHeight {
+        /// The resulting max contract call stack height.
+        max_contract_call_stack_height: u32,
+        /// The resulting max runtime call stack height.
+        max_runtime_call_stack_height: u32,
+    },
+    /// The authorization gas limit must be at least 1.
+    #[error("authorization gas limit must be at least 1")]
+    InvalidAuthorizationGasLimit,
+    /// The delta changes an option which affects execution results outside of an upgrade.
+    #[error("{0} affects execution results and can only be changed in an upgrade")]
+    ConsensusAffecting(&'static str),
+}
+
+/// A change to a subset of the options of an [`EngineConfig`], applied to a running engine.
+///
+/// The max query depth and the module cache size only affect how the node serves queries and how
+/// fast it executes, so they can be changed at any time.  The remaining options affect execution
+/// results, so changing them outside of an upgrade would make the node diverge from the rest of
+/// the network.
+///
+/// The administrative accounts aren't part of a delta: they are set by the chainspec and the
+/// mint's administrator registry in global state, which every node has to agree on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EngineConfigDelta {
+    max_query_depth: Option<u64>,
+    module_cache_size: Option<usize>,
+    max_associated_keys: Option<u32>,
+    max_runtime_call_stack_height: Option<u32>,
+    max_contract_call_stack_height: Option<u32>,
+    minimum_delegation_amount: Option<u64>,
+    authorization_gas_limit: Option<u64>,
+}
+
+impl EngineConfigDelta {
+    /// Creates a new `EngineConfigDelta` which doesn't change anything.
+    pub fn new() -> Self {
+        EngineConfigDelta::default()
+    }
+
+    /// Changes the max query depth.
+    pub fn with_max_query_depth(mut self, max_query_depth: u64) -> Self {
+        self.max_query_depth = Some(max_query_depth);
+        self
+    }
+
+    /// Changes the capacity in bytes of the cache of preprocessed modules.  A capacity of `0`
+    /// disables caching.
+    pub fn with_module_cache_size(mut self, module_cache_size: usize) -> Self {
+        self.module_cache_size = Some(module_cache_size);
+        self
+    }
+
+    /// Changes the max associated keys.  Only allowed in an upgrade.
+    pub fn with_max_associated_keys(mut self, max_associated_keys: u32) -> Self {
+        self.max_associated_keys = Some(max_associated_keys);
+        self
+    }
+
+    /// Changes the max runtime call stack height.  Only allowed in an upgrade.
+    pub fn with_max_runtime_call_stack_height(
+        mut self,
+        max_runtime_call_stack_height: u32,
+    ) -> Self {
+        self.max_runtime_call_stack_height = Some(max_runtime_call_stack_height);
+        self
+    }
+
+    /// Changes the max contract call stack height.  Only allowed in an upgrade.
+    pub fn with_max_contract_call_stack_height(
+        mut self,
+        max_contract_call_stack_height: u32,
+    ) -> Self {
+        self.max_contract_call_stack_height = Some(max_contract_call_stack_height);
+        self
+    }
+
+    /// Changes the minimum delegation amount in motes.  Only allowed in an upgrade.
+    pub fn with_minimum_delegation_amount(mut self, minimum_delegation_amount: u64) -> Self {
+        self.minimum_delegation_amount = Some(minimum_delegation_amount);
+        self
+    }
+
+    /// Changes the gas limit of a call to an account's authorization contract.  Only allowed in
+    /// an upgrade.
+    pub fn with_authorization_gas_limit(mut self, authorization_gas_limit: u64) -> Self {
+        self.authorization_gas_limit = Some(authorization_gas_limit);
+        self
+    }
+
+    /// Returns `true` if the delta doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        *self == EngineConfigDelta::default()
+    }
+
+    /// Returns the name of the first option changed by the delta which affects execution results,
+    /// if any.
+    pub fn consensus_affecting_option(&self) -> Option<&'static str> {
+        if self.max_associated_keys.is_some() {
+            Some("max_associated_keys")
+        } else if self.max_runtime_call_stack_height.is_some() {
+            Some("max_runtime_call_stack_height")
+        } else if self.max_contract_call_stack_height.is_some() {
+            Some("max_contract_call_stack_height")
+        } else if self.minimum_delegation_amount.is_some() {
+            Some("minimum_delegation_amount")
+        } else if self.authorization_gas_limit.is_some() {
+            Some("authorization_gas_limit")
+        } else {
+            None
+        }
+    }
+
+    /// Checks the delta can be applied to `config`.
+    ///
+    /// Options affecting execution results are rejected unless `during_upgrade` is `true`.
+    pub fn validate(
+        &self,
+        config: &EngineConfig,
+        during_upgrade: bool,
+    ) -> Result<(), EngineConfigDeltaError> {
+        if !during_upgrade {
+            if let Some(option) = self.consensus_affecting_option() {
+                return Err(EngineConfigDeltaError::ConsensusAffecting(option));
+            }
+        }
+
+        if self.max_query_depth == Some(0) {
+            return Err(EngineConfigDeltaError::InvalidMaxQueryDepth);
+        }
+        if self.max_associated_keys == Some(0) {
+            return Err(EngineConfigDeltaError::InvalidMaxAssociatedKeys);
+        }
+        if self.max_runtime_call_stack_height == Some(0) {
+            return Err(EngineConfigDeltaError::InvalidMaxRuntimeCallStackHeight);
+        }
+        if self.authorization_gas_limit == Some(0) {
+            return Err(EngineConfigDeltaError::InvalidAuthorizationGasLimit);
+        }
+
+        if self.max_runtime_call_stack_height.is_some()
+            || self.max_contract_call_stack_height.is_some()
+        {
+            let max_runtime_call_stack_height = self
+                .max_runtime_call_stack_height
+                .unwrap_or(config.max_runtime_call_stack_height);
+            let max_contract_call_stack_height = self
+                .max_contract_call_stack_height
+                .unwrap_or(config.max_contract_call_stack_height);
+            if max_contract_call_stack_height == 0
+                || max_contract_call_stack_height > max_runtime_call_stack_height
+            {
+                return Err(EngineConfigDeltaError::InvalidMaxContractCallStackHeight {
+                    max_contract_call_stack_height,
+                    max_runtime_call_stack_height,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `config` with the delta applied.
+    ///
+    /// The delta should be [validated](Self::validate) against `config` first.
+    pub fn apply_to(&self, config: &EngineConfig) -> EngineConfig {
+        let mut config = config.clone();
+        if let Some(max_query_depth) = self.max_query_depth {
+            config.max_query_depth = max_query_depth;
+        }
+        if let Some(module_cache_size) = self.module_cache_size {
+            config.module_cache_size = module_cache_size;
+        }
+        if let Some(max_associated_keys) = self.max_associated_keys {
+            config.max_associated_keys = max_associated_keys;
+        }
+        if let Some(max_runtime_call_stack_height) = self.max_runtime_call_stack_height {
+            config.max_runtime_call_stack_height = max_runtime_call_stack_height;
+        }
+        if let Some(max_contract_call_stack_height) = self.max_contract_call_stack_height {
+            config.max_contract_call_stack_height = max_contract_call_stack_height;
+        }
+        if let Some(minimum_delegation_amount) = self.minimum_delegation_amount {
+            config.minimum_delegation_amount = minimum_delegation_amount;
+        }
+        if let Some(authorization_gas_limit) = self.authorization_gas_limit {
+            config.authorization_gas_limit = authorization_gas_limit;
+        }
+        config
+    }
+
+    /// Combines the delta with a `later` one, whose changes take precedence.
+    pub fn merge(self, later: EngineConfigDelta) -> Self {
+        EngineConfigDelta {
+            max_query_depth: later.max_query_depth.or(self.max_query_depth),
+            module_cache_size: later.module_cache_size.or(self.module_cache_size),
+            max_associated_keys: later.max_associated_keys.or(self.max_associated_keys),
+            max_runtime_call_stack_height: later
+                .max_runtime_call_stack_height
+                .or(self.max_runtime_call_stack_height),
+            max_contract_call_stack_height: later
+                .max_contract_call_stack_height
+                .or(self.max_contract_call_stack_height),
+            minimum_delegation_amount: later
+                .minimum_delegation_amount
+                .or(self.minimum_delegation_amount),
+            authorization_gas_limit: later
+                .authorization_gas_limit
+                .or(self.authorization_gas_limit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_apply_runtime_options() {
+        let delta = EngineConfigDelta::new()
+            .with_max_query_depth(10)
+            .with_module_cache_size(0);
+
+        let config = EngineConfig::default();
+        assert_eq!(delta.validate(&config, false), Ok(()));
+        let config = delta.apply_to(&config);
+        assert_eq!(config.max_query_depth, 10);
+        assert_eq!(config.module_cache_size(), 0);
+    }
+
+    #[test]
+    fn should_only_change_consensus_affecting_options_in_upgrades() {
+        let delta = EngineConfigDelta::new().with_max_associated_keys(5);
+        let config = EngineConfig::default();
+        assert_eq!(
+            delta.validate(&config, false),
+            Err(EngineConfigDeltaError::ConsensusAffecting(
+                "max_associated_keys"
+            ))
+        );
+        assert_eq!(delta.validate(&config, true), Ok(()));
+        assert_eq!(delta.apply_to(&config).max_associated_keys(), 5);
+    }
+
+    #[test]
+    fn should_reject_out_of_range_values() {
+        let config = EngineConfig::default();
+        assert_eq!(
+            EngineConfigDelta::new()
+                .with_max_query_depth(0)
+                .validate(&config, false),
+            Err(EngineConfigDeltaError::InvalidMaxQueryDepth)
+        );
+        assert_eq!(
+            EngineConfigDelta::new()
+                .with_max_runtime_call_stack_height(config.max_contract_call_stack_height() - 1)
+                .validate(&config, true),
+            Err(EngineConfigDeltaError::InvalidMaxContractCallStackHeight {
+                max_contract_call_stack_height: config.max_contract_call_stack_height(),
+                max_runtime_call_stack_height: config.max_contract_call_stack_height() - 1,
+            })
+        );
+    }
+
+    #[test]
+    fn should_merge_with_later_delta_taking_precedence() {
+        let merged = EngineConfigDelta::new()
+            .with_max_query_depth(7)
+            .with_module_cache_size(1024)
+            .merge(EngineConfigDelta::new().with_max_query_depth(9));
+        assert_eq!(
+            merged,
+            EngineConfigDelta::new()
+                .with_max_query_depth(9)
+                .with_module_cache_size(1024)
+        );
+        assert!(!merged.is_empty());
+        assert!(EngineConfigDelta::new().is_empty());
+    }
+}
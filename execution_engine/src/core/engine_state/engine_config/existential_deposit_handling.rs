@@ -0,0 +1,61 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
+const EXISTENTIAL_DEPOSIT_HANDLING_REJECT_TAG: u8 = 0;
+const EXISTENTIAL_DEPOSIT_HANDLING_SWEEP_TAG: u8 = 1;
+
+/// Defines how a transfer that would leave an account's main purse with a non-zero balance below
+/// the existential deposit is handled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DataSize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExistentialDepositHandling {
+    /// The transfer fails.
+    Reject,
+    /// The remainder is swept along with the transferred amount, leaving the source purse empty.
+    Sweep,
+}
+
+impl ToBytes for ExistentialDepositHandling {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            ExistentialDepositHandling::Reject => Ok(vec![EXISTENTIAL_DEPOSIT_HANDLING_REJECT_TAG]),
+            ExistentialDepositHandling::Sweep => Ok(vec![EXISTENTIAL_DEPOSIT_HANDLING_SWEEP_TAG]),
+        }
+    }
+
+    fn serialized_length(&self) -> usize {
+        1
+    }
+}
+
+impl FromBytes for ExistentialDepositHandling {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            EXISTENTIAL_DEPOSIT_HANDLING_REJECT_TAG => {
+                Ok((ExistentialDepositHandling::Reject, rem))
+            }
+            EXISTENTIAL_DEPOSIT_HANDLING_SWEEP_TAG => Ok((ExistentialDepositHandling::Sweep, rem)),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip_for_reject() {
+        let handling = ExistentialDepositHandling::Reject;
+        bytesrepr::test_serialization_roundtrip(&handling);
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip_for_sweep() {
+        let handling = ExistentialDepositHandling::Sweep;
+        bytesrepr::test_serialization_roundtrip(&handling);
+    }
+}
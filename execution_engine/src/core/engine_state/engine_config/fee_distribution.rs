@@ -0,0 +1,155 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    PublicKey,
+};
+
+/// Defines when and to whom fees accumulated under
+/// [`FeeHandling::Accumulate`](super::FeeHandling::Accumulate) are distributed.
+///
+/// The accumulated balance is split between the administrator accounts, the validators of the
+/// ending era and the treasury account in proportion to their weights.  A group without any
+/// recipients is left out, so its share goes to the other groups.  Each group's share is split
+/// evenly among its recipients, and any dust is left in the accumulation purse.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DataSize)]
+#[serde(deny_unknown_fields)]
+pub struct FeeDistribution {
+    /// Accumulated fees are distributed at the end of every era whose successor's id is a
+    /// multiple of `frequency`.
+    pub frequency: u64,
+    /// Weight of the administrator accounts' share.
+    pub administrators_weight: u32,
+    /// Weight of the share of the validators of the ending era.
+    pub validators_weight: u32,
+    /// Weight of the treasury account's share.
+    pub treasury_weight: u32,
+    /// The treasury account, required if `treasury_weight` is non-zero.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub treasury: Option<PublicKey>,
+    /// Accumulated balance in motes below which distribution is skipped and the funds roll over to
+    /// the next distribution.
+    pub minimum_amount: u64,
+}
+
+impl FeeDistribution {
+    /// Returns `true` if accumulated fees should be distributed when `next_era_id` begins.
+    pub fn is_distribution_era(&self, next_era_id: u64) -> bool {
+        self.frequency != 0 && next_era_id % self.frequency == 0
+    }
+
+    /// Returns `true` if the distribution can be carried out as configured.
+    pub fn is_valid(&self) -> bool {
+        self.frequency != 0 && (self.treasury_weight == 0 || self.treasury.is_some())
+    }
+}
+
+impl Default for FeeDistribution {
+    fn default() -> Self {
+        FeeDistribution {
+            frequency: 1,
+            administrators_weight: 1,
+            validators_weight: 0,
+            treasury_weight: 0,
+            treasury: None,
+            minimum_amount: 0,
+        }
+    }
+}
+
+impl ToBytes for FeeDistribution {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.frequency.to_bytes()?);
+        buffer.extend(self.administrators_weight.to_bytes()?);
+        buffer.extend(self.validators_weight.to_bytes()?);
+        buffer.extend(self.treasury_weight.to_bytes()?);
+        buffer.extend(self.treasury.to_bytes()?);
+        buffer.extend(self.minimum_amount.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.frequency.serialized_length()
+            + self.administrators_weight.serialized_length()
+            + self.validators_weight.serialized_length()
+            + self.treasury_weight.serialized_length()
+            + self.treasury.serialized_length()
+            + self.minimum_amount.serialized_length()
+    }
+}
+
+impl FromBytes for FeeDistribution {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (frequency, rem) = u64::from_bytes(bytes)?;
+        let (administrators_weight, rem) = u32::from_bytes(rem)?;
+        let (validators_weight, rem) = u32::from_bytes(rem)?;
+        let (treasury_weight, rem) = u32::from_bytes(rem)?;
+        let (treasury, rem) = Option::<PublicKey>::from_bytes(rem)?;
+        let (minimum_amount, rem) = u64::from_bytes(rem)?;
+        let fee_distribution = FeeDistribution {
+            frequency,
+            administrators_weight,
+            validators_weight,
+            treasury_weight,
+            treasury,
+            minimum_amount,
+        };
+        Ok((fee_distribution, rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::SecretKey;
+
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip_for_default() {
+        bytesrepr::test_serialization_roundtrip(&FeeDistribution::default());
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip_with_treasury() {
+        let secret_key = SecretKey::ed25519_from_bytes([7; SecretKey::ED25519_LENGTH]).unwrap();
+        let fee_distribution = FeeDistribution {
+            frequency: 3,
+            administrators_weight: 1,
+            validators_weight: 2,
+            treasury_weight: 3,
+            treasury: Some(PublicKey::from(&secret_key)),
+            minimum_amount: 1_000,
+        };
+        bytesrepr::test_serialization_roundtrip(&fee_distribution);
+    }
+
+    #[test]
+    fn should_distribute_every_nth_era() {
+        let fee_distribution = FeeDistribution {
+            frequency: 3,
+            ..FeeDistribution::default()
+        };
+        let distribution_eras: Vec<u64> = (1..10)
+            .filter(|era_id| fee_distribution.is_distribution_era(*era_id))
+            .collect();
+        assert_eq!(distribution_eras, vec![3, 6, 9]);
+        assert!((1..10).all(|era_id| FeeDistribution::default().is_distribution_era(era_id)));
+    }
+
+    #[test]
+    fn should_require_treasury_for_treasury_weight() {
+        let fee_distribution = FeeDistribution {
+            treasury_weight: 1,
+            ..FeeDistribution::default()
+        };
+        assert!(!fee_distribution.is_valid());
+        assert!(!FeeDistribution {
+            frequency: 0,
+            ..FeeDistribution::default()
+        }
+        .is_valid());
+        assert!(FeeDistribution::default().is_valid());
+    }
+}
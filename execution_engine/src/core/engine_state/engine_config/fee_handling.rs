@@ -1,7 +1,10 @@
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
 
 const FEE_HANDLING_PROPOSER_TAG: u8 = 0;
 const FEE_HANDLING_ACCUMULATE_TAG: u8 = 1;
@@ -16,7 +19,7 @@ pub enum FeeHandling {
     /// This is the default option for public chains.
     PayToProposer,
     /// Transaction fees are accumulated in a special purse and then distributed during end of era
-    /// processing evenly among all administrator accounts.
+    /// processing as configured by [`FeeDistribution`](super::FeeDistribution).
     ///
     /// This setting is applicable for some private chains (but not all).
     Accumulate,
@@ -24,6 +27,12 @@ pub enum FeeHandling {
     Burn,
 }
 
+impl CLTyped for FeeHandling {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
 impl ToBytes for FeeHandling {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         match self {
@@ -3,7 +3,10 @@
 use num_rational::Ratio;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
 
 const REFUND_HANDLING_REFUND_TAG: u8 = 0;
 const REFUND_HANDLING_BURN_TAG: u8 = 1;
@@ -32,6 +35,12 @@ pub enum RefundHandling {
     },
 }
 
+impl CLTyped for RefundHandling {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
 impl ToBytes for RefundHandling {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
@@ -0,0 +1,94 @@
+use num_rational::Ratio;
+use serde::{Deserialize, Serialize};
+
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
+/// Defines the gas refunded to a deploy for storage it frees.
+///
+/// Removing a named key, removing a sorted index entry or overwriting a dictionary item with a
+/// smaller value credits the session with `refund_ratio` of the storage cost of the freed bytes.
+/// The refund lowers the gas the deploy is charged, and is capped at `max_refund` per deploy.
+/// Storage freed during the payment phase or by system contracts is not refunded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StorageRefund {
+    /// Fraction of the storage cost of the freed bytes which is refunded.
+    pub refund_ratio: Ratio<u64>,
+    /// Maximum gas refunded to a single deploy.
+    pub max_refund: u64,
+}
+
+impl StorageRefund {
+    /// Returns `true` if freeing storage is refunded.
+    pub fn is_enabled(&self) -> bool {
+        *self.refund_ratio.numer() != 0 && self.max_refund != 0
+    }
+
+    /// Returns `true` if the refund never exceeds the storage cost of the freed bytes.
+    pub fn is_valid(&self) -> bool {
+        *self.refund_ratio.denom() != 0 && self.refund_ratio <= Ratio::new(1, 1)
+    }
+}
+
+impl Default for StorageRefund {
+    fn default() -> Self {
+        StorageRefund {
+            refund_ratio: Ratio::new(0, 1),
+            max_refund: 0,
+        }
+    }
+}
+
+impl ToBytes for StorageRefund {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.refund_ratio.to_bytes()?);
+        buffer.extend(self.max_refund.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.refund_ratio.serialized_length() + self.max_refund.serialized_length()
+    }
+}
+
+impl FromBytes for StorageRefund {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (refund_ratio, rem) = FromBytes::from_bytes(bytes)?;
+        let (max_refund, rem) = u64::from_bytes(rem)?;
+        let storage_refund = StorageRefund {
+            refund_ratio,
+            max_refund,
+        };
+        Ok((storage_refund, rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&StorageRefund::default());
+        bytesrepr::test_serialization_roundtrip(&StorageRefund {
+            refund_ratio: Ratio::new(1, 2),
+            max_refund: 1_000_000,
+        });
+    }
+
+    #[test]
+    fn should_be_disabled_by_default() {
+        assert!(!StorageRefund::default().is_enabled());
+        assert!(StorageRefund::default().is_valid());
+    }
+
+    #[test]
+    fn should_not_refund_more_than_freed() {
+        let storage_refund = StorageRefund {
+            refund_ratio: Ratio::new(3, 2),
+            max_refund: 1_000_000,
+        };
+        assert!(!storage_refund.is_valid());
+    }
+}
@@ -0,0 +1,89 @@
+//! Support for inspecting and completing the move of era summaries from the legacy
+//! `Key::EraInfo(era_id)` records to the single `Key::EraSummary` record.
+use casper_types::EraId;
+
+/// The progress of the move of era summaries to `Key::EraSummary` under a state root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EraSummaryStatus {
+    has_era_summary: bool,
+    legacy_era_ids: Vec<EraId>,
+    mapped_era_id: Option<EraId>,
+}
+
+impl EraSummaryStatus {
+    pub(crate) fn new(
+        has_era_summary: bool,
+        legacy_era_ids: Vec<EraId>,
+        mapped_era_id: Option<EraId>,
+    ) -> Self {
+        EraSummaryStatus {
+            has_era_summary,
+            legacy_era_ids,
+            mapped_era_id,
+        }
+    }
+
+    /// Returns `true` if a record is held under `Key::EraSummary`.
+    pub fn has_era_summary(&self) -> bool {
+        self.has_era_summary
+    }
+
+    /// Returns the eras which still have a record under a legacy `Key::EraInfo`, in ascending
+    /// order.
+    pub fn legacy_era_ids(&self) -> &[EraId] {
+        &self.legacy_era_ids
+    }
+
+    /// Returns `true` if any legacy `Key::EraInfo` records remain.
+    pub fn has_legacy_era_infos(&self) -> bool {
+        !self.legacy_era_ids.is_empty()
+    }
+
+    /// Returns the latest era with a legacy record if that record is the one held under
+    /// `Key::EraSummary`.
+    pub fn mapped_era_id(&self) -> Option<EraId> {
+        self.mapped_era_id
+    }
+
+    /// Returns the number of legacy records which can be pruned without losing the latest era
+    /// summary.
+    ///
+    /// Until a record is held under `Key::EraSummary`, the latest legacy record is the only copy
+    /// of the latest era summary and isn't prunable.
+    pub fn prunable_count(&self) -> usize {
+        if self.has_era_summary {
+            self.legacy_era_ids.len()
+        } else {
+            self.legacy_era_ids.len().saturating_sub(1)
+        }
+    }
+
+    /// Returns `true` if the move is complete: a record is held under `Key::EraSummary` and no
+    /// legacy records remain.
+    pub fn is_complete(&self) -> bool {
+        self.has_era_summary && self.legacy_era_ids.is_empty()
+    }
+}
+
+/// Represents a result of an `era_summary_status` request.
+#[derive(Debug)]
+pub enum EraSummaryStatusResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the progress of the move under the requested state root.
+    Success {
+        /// The progress of the move.
+        status: EraSummaryStatus,
+    },
+}
+
+impl EraSummaryStatusResult {
+    /// Returns the wrapped status if this represents a successful query result.
+    pub fn into_success(self) -> Option<EraSummaryStatus> {
+        if let Self::Success { status } = self {
+            Some(status)
+        } else {
+            None
+        }
+    }
+}
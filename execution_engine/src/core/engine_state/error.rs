@@ -3,11 +3,14 @@ use datasize::DataSize;
 use thiserror::Error;
 
 use casper_hashing::Digest;
-use casper_types::{bytesrepr, system::mint, ApiError, ProtocolVersion};
+use casper_types::{
+    bytesrepr, system::mint, ApiError, CLValueError, Gas, Motes, ProtocolVersion, StoredValue,
+    StoredValueTypeMismatch,
+};
 
 use crate::{
     core::{
-        engine_state::{genesis::GenesisError, upgrade::ProtocolUpgradeError},
+        engine_state::{genesis::GenesisError, upgrade::ProtocolUpgradeError, view::ViewId},
         execution,
         runtime::stack,
     },
@@ -106,6 +109,38 @@ pub enum Error {
     /// Failed to retrieve accumulation purse from handle payment system contract.
     #[error("Failed to retrieve accumulation purse from the handle payment contract")]
     FailedToRetrieveAccumulationPurse,
+    /// The view was released or has expired.
+    #[error("View not found or expired: {0}")]
+    ViewNotFound(ViewId),
+    /// The state root is pinned by a live view.
+    #[error("State root is pinned by a live view: {0}")]
+    StateRootPinned(Digest),
+    /// A maintenance task with the same name is already registered.
+    #[error("Maintenance task already registered: {0}")]
+    DuplicateMaintenanceTask(String),
+    /// A trie node reachable from a state root is missing from global state.
+    #[error("Missing trie node: {0}")]
+    MissingTrieNode(Digest),
+    /// Custom payment code ran out of its gas budget.
+    #[error("Custom payment exceeded its gas budget of {0}")]
+    CustomPaymentGasBudgetExceeded(Gas),
+    /// Custom payment code deposited less than it had to into the payment purse.
+    #[error("Custom payment deposited {deposited} motes, {required} required")]
+    CustomPaymentInsufficientDeposit {
+        /// The least amount the payment code had to deposit.
+        required: Box<Motes>,
+        /// The amount the payment code deposited.
+        deposited: Box<Motes>,
+    },
+    /// Custom payment code reverted.
+    #[error("Custom payment reverted: {0}")]
+    CustomPaymentReverted(ApiError),
+    /// A value expected in global state, or a named key expected on a system contract, is missing.
+    #[error("Missing key: {0}")]
+    MissingKey(String),
+    /// A value read from global state is not of the expected type.
+    #[error("{0}")]
+    TypeMismatch(StoredValueTypeMismatch),
 }
 
 impl Error {
@@ -117,6 +152,63 @@ impl Error {
     pub fn reverter(api_error: impl Into<ApiError>) -> Error {
         Error::Exec(execution::Error::Revert(api_error.into()))
     }
+
+    /// Returns the numeric code identifying the variant of this error.
+    ///
+    /// Engine state errors use the codes below 1000, while [`Error::Exec`] takes the code of the
+    /// wrapped [`execution::Error`].  Codes are stable across versions: a variant keeps its code
+    /// for as long as it exists, and the code of a removed variant is never reassigned.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Error::RootNotFound(_) => 1,
+            Error::InvalidProtocolVersion(_) => 2,
+            Error::Genesis(_) => 3,
+            Error::WasmPreprocessing(_) => 4,
+            Error::WasmSerialization(_) => 5,
+            Error::Exec(error) => error.error_code(),
+            Error::Storage(_) => 7,
+            Error::Authorization => 8,
+            Error::InsufficientPayment => 9,
+            Error::GasConversionOverflow => 10,
+            Error::Deploy => 11,
+            Error::Finalization => 12,
+            Error::Bytesrepr(_) => 13,
+            Error::Mint(_) => 14,
+            Error::InvalidKeyVariant => 15,
+            Error::ProtocolUpgrade(_) => 16,
+            Error::InvalidDeployItemVariant(_) => 17,
+            Error::CommitError(_) => 18,
+            Error::MissingSystemContractRegistry => 19,
+            Error::MissingSystemContractHash(_) => 20,
+            Error::MissingChecksumRegistry => 21,
+            Error::RuntimeStackOverflow => 22,
+            Error::FailedToGetWithdrawKeys => 23,
+            Error::FailedToGetStoredWithdraws => 24,
+            Error::FailedToGetWithdrawPurses => 25,
+            Error::FailedToRetrieveUnbondingDelay => 26,
+            Error::FailedToRetrieveEraId => 27,
+            Error::MissingTrieNodeChildren(_) => 28,
+            Error::FailedToRetrieveAccumulationPurse => 29,
+            Error::ViewNotFound(_) => 30,
+            Error::StateRootPinned(_) => 31,
+            Error::DuplicateMaintenanceTask(_) => 32,
+            Error::MissingTrieNode(_) => 33,
+            Error::CustomPaymentGasBudgetExceeded(_) => 34,
+            Error::CustomPaymentInsufficientDeposit { .. } => 35,
+            Error::CustomPaymentReverted(_) => 36,
+            Error::MissingKey(_) => 37,
+            Error::TypeMismatch(_) => 38,
+        }
+    }
+
+    /// Creates an [`Error::TypeMismatch`] for a value read from global state which isn't the
+    /// `expected` kind of [`StoredValue`].
+    pub(crate) fn unexpected_stored_value(expected: &str, found: &StoredValue) -> Error {
+        Error::TypeMismatch(StoredValueTypeMismatch::new(
+            expected.to_string(),
+            found.type_name(),
+        ))
+    }
 }
 
 impl From<execution::Error> for Error {
@@ -136,6 +228,18 @@ impl From<bytesrepr::Error> for Error {
     }
 }
 
+impl From<CLValueError> for Error {
+    fn from(error: CLValueError) -> Self {
+        match error {
+            CLValueError::Serialization(error) => error.into(),
+            CLValueError::Type(mismatch) => Error::TypeMismatch(StoredValueTypeMismatch::new(
+                format!("{:?}", mismatch.expected),
+                format!("{:?}", mismatch.found),
+            )),
+        }
+    }
+}
+
 impl From<lmdb::Error> for Error {
     fn from(error: lmdb::Error) -> Self {
         Error::Storage(storage::error::Error::Lmdb(error))
@@ -171,3 +275,90 @@ impl DataSize for Error {
         12 // TODO: replace with some actual estimation depending on the variant
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, time::Duration};
+
+    use crate::core::engine_state::view::ViewRegistry;
+
+    use super::*;
+
+    /// One instance of every variant, so that a new variant without a unique code fails the test.
+    fn all_variants() -> Vec<Error> {
+        let view_id = ViewRegistry::default()
+            .pin(Digest::default(), Duration::ZERO)
+            .id();
+        vec![
+            Error::RootNotFound(Digest::default()),
+            Error::InvalidProtocolVersion(ProtocolVersion::V1_0_0),
+            Error::Genesis(Box::new(GenesisError::UnableToCreateRuntime)),
+            Error::WasmPreprocessing(wasm_prep::PreprocessingError::StackLimiter),
+            Error::WasmSerialization(casper_wasm::SerializationError::UnexpectedEof),
+            Error::Exec(execution::Error::GasLimit),
+            Error::Storage(storage::error::Error::Poison),
+            Error::Authorization,
+            Error::InsufficientPayment,
+            Error::GasConversionOverflow,
+            Error::Deploy,
+            Error::Finalization,
+            Error::Bytesrepr(String::new()),
+            Error::Mint(String::new()),
+            Error::InvalidKeyVariant,
+            Error::ProtocolUpgrade(ProtocolUpgradeError::InvalidUpgradeConfig),
+            Error::InvalidDeployItemVariant(String::new()),
+            Error::CommitError(CommitError::RootNotFound(Digest::default())),
+            Error::MissingSystemContractRegistry,
+            Error::MissingSystemContractHash(String::new()),
+            Error::MissingChecksumRegistry,
+            Error::RuntimeStackOverflow,
+            Error::FailedToGetWithdrawKeys,
+            Error::FailedToGetStoredWithdraws,
+            Error::FailedToGetWithdrawPurses,
+            Error::FailedToRetrieveUnbondingDelay,
+            Error::FailedToRetrieveEraId,
+            Error::MissingTrieNodeChildren(vec![]),
+            Error::FailedToRetrieveAccumulationPurse,
+            Error::ViewNotFound(view_id),
+            Error::StateRootPinned(Digest::default()),
+            Error::DuplicateMaintenanceTask(String::new()),
+            Error::MissingTrieNode(Digest::default()),
+            Error::CustomPaymentGasBudgetExceeded(Gas::default()),
+            Error::CustomPaymentInsufficientDeposit {
+                required: Box::default(),
+                deposited: Box::default(),
+            },
+            Error::CustomPaymentReverted(ApiError::None),
+            Error::MissingKey(String::new()),
+            Error::TypeMismatch(StoredValueTypeMismatch::new(String::new(), String::new())),
+        ]
+    }
+
+    #[test]
+    fn error_codes_should_be_unique_and_in_range() {
+        let all_variants = all_variants();
+        let codes: BTreeSet<u32> = all_variants
+            .iter()
+            .filter(|error| !matches!(error, Error::Exec(_)))
+            .map(Error::error_code)
+            .collect();
+        assert_eq!(codes.len(), all_variants.len() - 1);
+        assert!(codes.iter().all(|code| (1..1000).contains(code)));
+    }
+
+    #[test]
+    fn exec_error_code_should_be_passed_through() {
+        let exec_error = execution::Error::GasLimit;
+        assert_eq!(
+            Error::Exec(exec_error.clone()).error_code(),
+            exec_error.error_code()
+        );
+    }
+
+    #[test]
+    fn error_codes_should_be_stable() {
+        assert_eq!(Error::RootNotFound(Digest::default()).error_code(), 1);
+        assert_eq!(Error::InsufficientPayment.error_code(), 9);
+        assert_eq!(Error::MissingTrieNode(Digest::default()).error_code(), 33);
+    }
+}
@@ -1,12 +1,13 @@
 //! Outcome of an `ExecutionRequest`.
 
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 
 use casper_types::{
-    bytesrepr::FromBytes, CLTyped, CLValue, Gas, Key, Motes, StoredValue, TransferAddr,
+    bytesrepr::FromBytes, CLTyped, CLValue, ContractEvent, Gas, Key, Motes, StoredValue,
+    TransferAddr,
 };
 
-use super::error;
+use super::{error, execution_warning::ExecutionWarning};
 use crate::{
     core::execution::Error as ExecError,
     shared::{execution_journal::ExecutionJournal, transform::Transform},
@@ -49,6 +50,10 @@ pub enum ExecutionResult {
         cost: Gas,
         /// Journal of execution.
         execution_journal: ExecutionJournal,
+        /// Keys read during execution, if recording them was enabled.
+        read_keys: Option<BTreeSet<Key>>,
+        /// Warnings raised during execution.
+        warnings: Vec<ExecutionWarning>,
     },
     /// Execution was finished successfully
     Success {
@@ -58,6 +63,12 @@ pub enum ExecutionResult {
         cost: Gas,
         /// Journal of execution.
         execution_journal: ExecutionJournal,
+        /// Events emitted by contracts during execution.
+        events: Vec<ContractEvent>,
+        /// Keys read during execution, if recording them was enabled.
+        read_keys: Option<BTreeSet<Key>>,
+        /// Warnings raised during execution.
+        warnings: Vec<ExecutionWarning>,
     },
 }
 
@@ -67,6 +78,9 @@ impl Default for ExecutionResult {
             execution_journal: Default::default(),
             transfers: Default::default(),
             cost: Default::default(),
+            events: Default::default(),
+            read_keys: None,
+            warnings: Vec::new(),
         }
     }
 }
@@ -94,6 +108,8 @@ impl ExecutionResult {
             transfers: Vec::default(),
             cost: Gas::default(),
             execution_journal: Default::default(),
+            read_keys: None,
+            warnings: Vec::new(),
         }
     }
 
@@ -144,6 +160,14 @@ impl ExecutionResult {
         }
     }
 
+    /// Events emitted by contracts, which are only retained by a successful execution.
+    pub fn events(&self) -> &[ContractEvent] {
+        match self {
+            ExecutionResult::Failure { .. } => &[],
+            ExecutionResult::Success { events, .. } => events,
+        }
+    }
+
     /// The journal of transforms regardless of variant.
     pub fn execution_journal(&self) -> &ExecutionJournal {
         match self {
@@ -156,6 +180,11 @@ impl ExecutionResult {
         }
     }
 
+    /// The size in bytes of the serialized effects regardless of variant.
+    pub fn effects_size(&self) -> usize {
+        self.execution_journal().serialized_size()
+    }
+
     /// Returns a new execution result with updated gas cost.
     ///
     /// This method preserves the [`ExecutionResult`] variant and updates the cost field
@@ -166,21 +195,31 @@ impl ExecutionResult {
                 error,
                 transfers,
                 execution_journal,
+                read_keys,
+                warnings,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                read_keys,
+                warnings,
             },
             ExecutionResult::Success {
                 transfers,
                 execution_journal,
+                events,
+                read_keys,
+                warnings,
                 ..
             } => ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal,
+                events,
+                read_keys,
+                warnings,
             },
         }
     }
@@ -195,21 +234,31 @@ impl ExecutionResult {
                 error,
                 cost,
                 execution_journal,
+                read_keys,
+                warnings,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                read_keys,
+                warnings,
             },
             ExecutionResult::Success {
                 cost,
                 execution_journal,
+                events,
+                read_keys,
+                warnings,
                 ..
             } => ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal,
+                events,
+                read_keys,
+                warnings,
             },
         }
     }
@@ -225,24 +274,129 @@ impl ExecutionResult {
                 transfers,
                 cost,
                 execution_journal: _,
+                read_keys,
+                warnings,
             } => ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                read_keys,
+                warnings,
             },
             ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal: _,
+                events,
+                read_keys,
+                warnings,
+            } => ExecutionResult::Success {
+                transfers,
+                cost,
+                execution_journal,
+                events,
+                read_keys,
+                warnings,
+            },
+        }
+    }
+
+    /// Returns a new execution result with the keys read during execution.
+    ///
+    /// This method preserves the [`ExecutionResult`] variant and updates the `read_keys` field
+    /// only.
+    pub fn with_read_keys(self, read_keys: BTreeSet<Key>) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                transfers,
+                cost,
+                execution_journal,
+                warnings,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                transfers,
+                cost,
+                execution_journal,
+                read_keys: Some(read_keys),
+                warnings,
+            },
+            ExecutionResult::Success {
+                transfers,
+                cost,
+                execution_journal,
+                events,
+                warnings,
+                ..
+            } => ExecutionResult::Success {
+                transfers,
+                cost,
+                execution_journal,
+                events,
+                read_keys: Some(read_keys),
+                warnings,
+            },
+        }
+    }
+
+    /// Returns the keys read during execution regardless of variant, or `None` if recording them
+    /// wasn't enabled.
+    pub fn read_keys(&self) -> Option<&BTreeSet<Key>> {
+        match self {
+            ExecutionResult::Failure { read_keys, .. }
+            | ExecutionResult::Success { read_keys, .. } => read_keys.as_ref(),
+        }
+    }
+
+    /// Returns a new execution result with the warnings raised during execution.
+    ///
+    /// This method preserves the [`ExecutionResult`] variant and updates the `warnings` field
+    /// only.
+    pub fn with_warnings(self, warnings: Vec<ExecutionWarning>) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                transfers,
+                cost,
+                execution_journal,
+                read_keys,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                transfers,
+                cost,
+                execution_journal,
+                read_keys,
+                warnings,
+            },
+            ExecutionResult::Success {
+                transfers,
+                cost,
+                execution_journal,
+                events,
+                read_keys,
+                ..
             } => ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal,
+                events,
+                read_keys,
+                warnings,
             },
         }
     }
 
+    /// Returns the warnings raised during execution regardless of variant.
+    pub fn warnings(&self) -> &[ExecutionWarning] {
+        match self {
+            ExecutionResult::Failure { warnings, .. }
+            | ExecutionResult::Success { warnings, .. } => warnings,
+        }
+    }
+
     /// Returns error value, if possible.
     ///
     /// Returns a reference to a wrapped [`error::Error`] instance if the object is a failure
@@ -254,6 +408,13 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns the numeric code of the error if the object is a failure variant.
+    ///
+    /// See [`error::Error::error_code`].
+    pub fn error_code(&self) -> Option<u32> {
+        self.as_error().map(error::Error::error_code)
+    }
+
     /// Consumes [`ExecutionResult`] instance and optionally returns [`error::Error`] instance for
     /// [`ExecutionResult::Failure`] variant.
     pub fn take_error(self) -> Option<error::Error> {
@@ -328,6 +489,8 @@ impl ExecutionResult {
             execution_journal,
             transfers,
             cost: gas_cost,
+            read_keys: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -349,6 +512,7 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 transfers,
                 cost,
                 execution_journal,
+                ..
             } => casper_types::ExecutionResult::Success {
                 effect: execution_journal.into(),
                 transfers: transfers.clone(),
@@ -359,6 +523,7 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 transfers,
                 cost,
                 execution_journal,
+                ..
             } => casper_types::ExecutionResult::Failure {
                 effect: execution_journal.into(),
                 transfers: transfers.clone(),
@@ -376,6 +541,7 @@ impl From<ExecutionResult> for casper_types::ExecutionResult {
                 transfers,
                 cost,
                 execution_journal,
+                ..
             } => casper_types::ExecutionResult::Success {
                 effect: execution_journal.into(),
                 transfers,
@@ -386,6 +552,7 @@ impl From<ExecutionResult> for casper_types::ExecutionResult {
                 transfers,
                 cost,
                 execution_journal,
+                ..
             } => casper_types::ExecutionResult::Failure {
                 effect: execution_journal.into(),
                 transfers,
@@ -486,11 +653,13 @@ impl ExecutionResultBuilder {
         let mut transfers = self.transfers();
         let cost = self.total_cost();
 
-        let mut journal = match self.payment_execution_result {
+        let (mut journal, mut events) = match self.payment_execution_result {
             Some(result @ ExecutionResult::Failure { .. }) => return Ok(result),
             Some(ExecutionResult::Success {
-                execution_journal, ..
-            }) => execution_journal,
+                execution_journal,
+                events,
+                ..
+            }) => (execution_journal, events),
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
         };
 
@@ -502,13 +671,20 @@ impl ExecutionResultBuilder {
                 transfers: session_transfers,
                 execution_journal: _,
                 cost: _,
+                read_keys: _,
+                warnings: _,
             }) => {
                 error = Some(session_error);
                 transfers = session_transfers;
             }
             Some(ExecutionResult::Success {
-                execution_journal, ..
-            }) => journal.extend(execution_journal),
+                execution_journal,
+                events: session_events,
+                ..
+            }) => {
+                journal.extend(execution_journal);
+                events.extend(session_events);
+            }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
         };
 
@@ -530,12 +706,17 @@ impl ExecutionResultBuilder {
                 transfers,
                 cost,
                 execution_journal: journal,
+                events,
+                read_keys: None,
+                warnings: Vec::new(),
             }),
             Some(error) => Ok(ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal: journal,
+                read_keys: None,
+                warnings: Vec::new(),
             }),
         }
     }
@@ -0,0 +1,41 @@
+//! Measurements of executing a deploy which aren't part of its execution result.
+use std::time::Duration;
+
+/// How long executing a deploy took, how much Wasm memory it used and how large its effects are.
+///
+/// Unlike the execution result, the duration and memory usage differ between nodes and runs, so
+/// these must never feed into anything consensus relies on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionStats {
+    duration: Duration,
+    memory_high_water_mark: u64,
+    effects_size: u64,
+}
+
+impl ExecutionStats {
+    /// Creates new execution stats.
+    pub fn new(duration: Duration, memory_high_water_mark: u64, effects_size: u64) -> Self {
+        ExecutionStats {
+            duration,
+            memory_high_water_mark,
+            effects_size,
+        }
+    }
+
+    /// Returns the wall-clock time taken to execute the deploy.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the size in bytes of the largest Wasm memory used by the deploy's payment, session
+    /// or any stored contract they called, or `0` if no Wasm was executed.
+    pub fn memory_high_water_mark(&self) -> u64 {
+        self.memory_high_water_mark
+    }
+
+    /// Returns the size in bytes of the deploy's effects once serialized, or `0` if the deploy
+    /// couldn't be executed.  The share of the session code is limited by `max_effects_size_bytes`.
+    pub fn effects_size(&self) -> u64 {
+        self.effects_size
+    }
+}
@@ -0,0 +1,98 @@
+//! Warnings about behaviors of a deploy which may stop working at a future upgrade.
+use std::{
+    cell::RefCell,
+    fmt::{self, Display, Formatter},
+    rc::Rc,
+};
+
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{ContractHash, U512};
+
+/// How many times the cost of a deploy its payment must be to raise
+/// [`ExecutionWarning::ExcessivePayment`].
+pub const EXCESSIVE_PAYMENT_FACTOR: u64 = 10;
+
+/// A warning raised while executing a deploy.
+///
+/// Warnings don't affect the outcome of the execution and aren't part of the execution result
+/// covered by consensus.  They give early notice of behaviors which are legacy or likely to be a
+/// mistake.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DataSize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub enum ExecutionWarning {
+    /// A contract was called by its hash rather than through its contract package.  Such a call
+    /// is tied to one version of the contract, which can be disabled at an upgrade of the package.
+    UnversionedContractCall {
+        /// The hash of the contract called.
+        contract_hash: ContractHash,
+    },
+    /// The payment of the deploy far exceeded its cost.
+    ExcessivePayment {
+        /// The motes paid for the deploy.
+        payment: U512,
+        /// The cost of the deploy in motes.
+        cost: U512,
+    },
+}
+
+impl Display for ExecutionWarning {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionWarning::UnversionedContractCall { contract_hash } => write!(
+                formatter,
+                "called contract {} by hash rather than through its contract package",
+                contract_hash
+            ),
+            ExecutionWarning::ExcessivePayment { payment, cost } => write!(
+                formatter,
+                "paid {} motes for a deploy costing {} motes",
+                payment, cost
+            ),
+        }
+    }
+}
+
+/// The warnings raised while executing a deploy.
+///
+/// The warnings are backed by an `Rc<RefCell<Vec<ExecutionWarning>>>`, meaning that clones will
+/// all share state.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct ExecutionWarnings {
+    warnings: Rc<RefCell<Vec<ExecutionWarning>>>,
+}
+
+impl ExecutionWarnings {
+    /// Records `warning`, unless an identical one was already recorded.
+    pub(crate) fn raise(&self, warning: ExecutionWarning) {
+        let mut warnings = self.warnings.borrow_mut();
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+
+    /// Returns the recorded warnings, clearing them.
+    pub(crate) fn take(&self) -> Vec<ExecutionWarning> {
+        self.warnings.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_raise_each_warning_once() {
+        let warnings = ExecutionWarnings::default();
+        let warning = ExecutionWarning::UnversionedContractCall {
+            contract_hash: ContractHash::new([1; 32]),
+        };
+        warnings.clone().raise(warning.clone());
+        warnings.raise(warning.clone());
+        assert_eq!(warnings.take(), vec![warning]);
+        assert!(warnings.take().is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+//! Support for forecasting the outcome of the next auction without running a step.
+use casper_hashing::Digest;
+use casper_types::{system::auction::ValidatorWeights, U512};
+
+/// Represents a request to forecast the validators the next auction would select.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForecastValidatorsRequest {
+    state_hash: Digest,
+}
+
+impl ForecastValidatorsRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        ForecastValidatorsRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// The validators the next auction would select if it were run against the current bids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorsForecast {
+    validator_weights: ValidatorWeights,
+    validator_slots: usize,
+}
+
+impl ValidatorsForecast {
+    /// Creates new forecast.
+    pub fn new(validator_weights: ValidatorWeights, validator_slots: usize) -> Self {
+        ValidatorsForecast {
+            validator_weights,
+            validator_slots,
+        }
+    }
+
+    /// Returns the would-be validators and their total stakes.
+    pub fn validator_weights(&self) -> &ValidatorWeights {
+        &self.validator_weights
+    }
+
+    /// Returns the number of validator slots being auctioned.
+    pub fn validator_slots(&self) -> usize {
+        self.validator_slots
+    }
+
+    /// Returns the smallest total stake among the would-be validators.
+    ///
+    /// Returns `None` if no validator would be selected.  While fewer validators than there are
+    /// slots would be selected, any active bid wins a slot regardless of this amount.
+    pub fn minimum_winning_bid(&self) -> Option<U512> {
+        self.validator_weights.values().min().copied()
+    }
+
+    /// Returns `true` if every validator slot would be taken.
+    pub fn all_slots_taken(&self) -> bool {
+        self.validator_weights.len() >= self.validator_slots
+    }
+}
+
+/// Represents a result of a `forecast_next_era_validators` request.
+#[derive(Debug)]
+pub enum ForecastValidatorsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the forecast computed from the global state.
+    Success {
+        /// The validators forecast.
+        forecast: ValidatorsForecast,
+    },
+}
+
+impl ForecastValidatorsResult {
+    /// Returns wrapped [`ValidatorsForecast`] if this represents a successful result.
+    pub fn into_success(self) -> Option<ValidatorsForecast> {
+        if let Self::Success { forecast } = self {
+            Some(forecast)
+        } else {
+            None
+        }
+    }
+}
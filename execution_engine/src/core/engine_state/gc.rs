@@ -0,0 +1,185 @@
+//! Support for garbage collecting historical state roots from global state.
+use casper_hashing::Digest;
+use casper_types::EraId;
+
+pub use crate::storage::global_state::lmdb::{GarbageCollectionProgress, GarbageCollectionStats};
+
+/// A state root hash known to the caller, along with the era of the block it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateRootRecord {
+    state_root_hash: Digest,
+    era_id: EraId,
+    is_switch_block: bool,
+}
+
+impl StateRootRecord {
+    /// Creates a new state root record.
+    pub fn new(state_root_hash: Digest, era_id: EraId, is_switch_block: bool) -> Self {
+        StateRootRecord {
+            state_root_hash,
+            era_id,
+            is_switch_block,
+        }
+    }
+
+    /// Returns the state root hash.
+    pub fn state_root_hash(&self) -> Digest {
+        self.state_root_hash
+    }
+
+    /// Returns the era of the block this state root belongs to.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns `true` if this state root belongs to a switch block.
+    pub fn is_switch_block(&self) -> bool {
+        self.is_switch_block
+    }
+}
+
+/// Describes which state roots survive a garbage collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    retained_eras: u64,
+    retain_switch_blocks: bool,
+}
+
+impl RetentionPolicy {
+    /// Creates a new retention policy keeping the roots of the last `retained_eras` eras (the
+    /// current era included), and optionally the roots of all switch blocks.
+    pub fn new(retained_eras: u64, retain_switch_blocks: bool) -> Self {
+        RetentionPolicy {
+            retained_eras,
+            retain_switch_blocks,
+        }
+    }
+
+    /// Returns the number of most recent eras whose roots are retained.
+    pub fn retained_eras(&self) -> u64 {
+        self.retained_eras
+    }
+
+    /// Returns `true` if switch block roots are retained regardless of their age.
+    pub fn retain_switch_blocks(&self) -> bool {
+        self.retain_switch_blocks
+    }
+
+    /// Returns `true` if `record` should be retained while in `current_era`.
+    pub fn retains(&self, current_era: EraId, record: &StateRootRecord) -> bool {
+        if self.retain_switch_blocks && record.is_switch_block() {
+            return true;
+        }
+        record.era_id().value().saturating_add(self.retained_eras) > current_era.value()
+    }
+}
+
+/// Represents the configuration of a garbage collection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcConfig {
+    current_era: EraId,
+    retention_policy: RetentionPolicy,
+    state_roots: Vec<StateRootRecord>,
+}
+
+impl GcConfig {
+    /// Creates a new garbage collection config.
+    ///
+    /// `state_roots` should list every root the caller still tracks; roots not listed are
+    /// considered unreachable and will be collected.
+    pub fn new(
+        current_era: EraId,
+        retention_policy: RetentionPolicy,
+        state_roots: Vec<StateRootRecord>,
+    ) -> Self {
+        GcConfig {
+            current_era,
+            retention_policy,
+            state_roots,
+        }
+    }
+
+    /// Returns the current era.
+    pub fn current_era(&self) -> EraId {
+        self.current_era
+    }
+
+    /// Returns the retention policy.
+    pub fn retention_policy(&self) -> RetentionPolicy {
+        self.retention_policy
+    }
+
+    /// Returns the state roots known to the caller.
+    pub fn state_roots(&self) -> &[StateRootRecord] {
+        &self.state_roots
+    }
+
+    /// Returns the state root hashes which are retained by the retention policy.
+    pub fn retained_roots(&self) -> Vec<Digest> {
+        self.state_roots
+            .iter()
+            .filter(|record| self.retention_policy.retains(self.current_era, record))
+            .map(StateRootRecord::state_root_hash)
+            .collect()
+    }
+}
+
+/// The result of performing a garbage collection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GcResult {
+    /// The retention policy did not retain any root; nothing was collected.
+    NothingRetained,
+    /// Unreachable tries were collected.
+    Success {
+        /// State root hashes which are still available.
+        retained_roots: Vec<Digest>,
+        /// Statistics of the collection.
+        stats: GarbageCollectionStats,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(era: u64, is_switch_block: bool) -> StateRootRecord {
+        StateRootRecord::new(
+            Digest::hash(era.to_le_bytes()),
+            EraId::new(era),
+            is_switch_block,
+        )
+    }
+
+    #[test]
+    fn should_retain_recent_eras() {
+        let policy = RetentionPolicy::new(2, false);
+        let current_era = EraId::new(10);
+        assert!(policy.retains(current_era, &record(10, false)));
+        assert!(policy.retains(current_era, &record(9, false)));
+        assert!(!policy.retains(current_era, &record(8, false)));
+        assert!(!policy.retains(current_era, &record(0, false)));
+    }
+
+    #[test]
+    fn should_retain_switch_blocks_when_configured() {
+        let current_era = EraId::new(10);
+        assert!(RetentionPolicy::new(1, true).retains(current_era, &record(3, true)));
+        assert!(!RetentionPolicy::new(1, false).retains(current_era, &record(3, true)));
+    }
+
+    #[test]
+    fn should_list_retained_roots() {
+        let config = GcConfig::new(
+            EraId::new(5),
+            RetentionPolicy::new(1, true),
+            vec![record(1, false), record(2, true), record(5, false)],
+        );
+        assert_eq!(
+            config.retained_roots(),
+            vec![
+                record(2, true).state_root_hash(),
+                record(5, false).state_root_hash()
+            ]
+        );
+    }
+}
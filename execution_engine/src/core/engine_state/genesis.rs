@@ -18,14 +18,17 @@ use casper_types::{
     contracts::{ContractPackageStatus, ContractVersions, DisabledVersions, Groups, NamedKeys},
     system::{
         auction::{
-            self, Bid, Bids, DelegationRate, Delegator, SeigniorageRecipient,
+            self, Bid, Bids, DelegationRate, Delegator, EraValidators, SeigniorageRecipient,
             SeigniorageRecipients, SeigniorageRecipientsSnapshot, AUCTION_DELAY_KEY,
             DELEGATION_RATE_DENOMINATOR, ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY,
             INITIAL_ERA_END_TIMESTAMP_MILLIS, INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY,
             SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
-        handle_payment::{self, ACCUMULATION_PURSE_KEY},
-        mint::{self, ARG_ROUND_SEIGNIORAGE_RATE, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_SUPPLY_KEY},
+        handle_payment::{self, ACCUMULATION_PURSE_KEY, FEE_HANDLING_KEY, REFUND_HANDLING_KEY},
+        mint::{
+            self, ARG_ROUND_SEIGNIORAGE_RATE, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_BURNED_KEY,
+            TOTAL_SUPPLY_KEY,
+        },
         standard_payment, AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
     },
     AccessRights, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash,
@@ -36,14 +39,15 @@ use casper_types::{
 use crate::{
     core::{
         engine_state::{
-            execution_effect::ExecutionEffect, ChainspecRegistry, SystemContractRegistry,
+            execution_effect::ExecutionEffect, ChainspecRegistry, SystemEntityRegistry,
         },
         execution,
         execution::AddressGenerator,
-        tracking_copy::TrackingCopy,
+        tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
     shared::{newtypes::CorrelationId, system_config::SystemConfig, wasm_config::WasmConfig},
     storage::global_state::StateProvider,
+    system::auction::detail::era_validators_from_snapshot,
 };
 
 use super::engine_config::{
@@ -91,6 +95,49 @@ impl fmt::Display for GenesisSuccess {
     }
 }
 
+/// Represents the outcome of a genesis run which was planned, but not committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisPlan {
+    system_account_hash: AccountHash,
+    mint: ContractHash,
+    auction: ContractHash,
+    handle_payment: ContractHash,
+    standard_payment: ContractHash,
+    era_validators: EraValidators,
+}
+
+impl GenesisPlan {
+    /// Returns the account hash of the system account.
+    pub fn system_account_hash(&self) -> AccountHash {
+        self.system_account_hash
+    }
+
+    /// Returns the contract hash the mint would be installed under.
+    pub fn mint(&self) -> ContractHash {
+        self.mint
+    }
+
+    /// Returns the contract hash the auction would be installed under.
+    pub fn auction(&self) -> ContractHash {
+        self.auction
+    }
+
+    /// Returns the contract hash the handle payment contract would be installed under.
+    pub fn handle_payment(&self) -> ContractHash {
+        self.handle_payment
+    }
+
+    /// Returns the contract hash the standard payment contract would be installed under.
+    pub fn standard_payment(&self) -> ContractHash {
+        self.standard_payment
+    }
+
+    /// Returns the validator weights of the eras covered by the initial auction snapshot.
+    pub fn era_validators(&self) -> &EraValidators {
+        &self.era_validators
+    }
+}
+
 #[repr(u8)]
 enum GenesisAccountTag {
     System = 0,
@@ -774,6 +821,16 @@ impl ExecConfig {
     pub fn genesis_timestamp_millis(&self) -> u64 {
         self.genesis_timestamp_millis
     }
+
+    /// Returns the refund handling config.
+    pub fn refund_handling(&self) -> RefundHandling {
+        self.refund_handling
+    }
+
+    /// Returns the fee handling config.
+    pub fn fee_handling(&self) -> FeeHandling {
+        self.fee_handling
+    }
 }
 
 impl Distribution<ExecConfig> for Standard {
@@ -1067,6 +1124,51 @@ where
         self.tracking_copy.borrow().effect()
     }
 
+    /// Reads back the outcome of a complete system installation from its uncommitted effects.
+    pub(crate) fn plan(&self) -> Result<GenesisPlan, Box<GenesisError>> {
+        let mut tracking_copy = self.tracking_copy.borrow_mut();
+
+        let registry = tracking_copy
+            .get_system_contracts(self.correlation_id)
+            .map_err(|_| GenesisError::FailedToCreateSystemRegistry)?;
+        let system_contract_hash = |name: &str| {
+            registry
+                .get(name)
+                .copied()
+                .ok_or(GenesisError::FailedToCreateSystemRegistry)
+        };
+        let mint = system_contract_hash(MINT)?;
+        let auction = system_contract_hash(AUCTION)?;
+        let handle_payment = system_contract_hash(HANDLE_PAYMENT)?;
+        let standard_payment = system_contract_hash(STANDARD_PAYMENT)?;
+
+        let snapshot_error =
+            || GenesisError::CLValue(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY.to_string());
+        let snapshot_key = tracking_copy
+            .get_contract(self.correlation_id, auction)
+            .map_err(|_| snapshot_error())?
+            .named_keys()
+            .get(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY)
+            .copied()
+            .ok_or_else(snapshot_error)?;
+        let snapshot: SeigniorageRecipientsSnapshot =
+            match tracking_copy.read(self.correlation_id, &snapshot_key) {
+                Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                    .into_t()
+                    .map_err(|error| GenesisError::CLValue(error.to_string()))?,
+                _ => return Err(snapshot_error().into()),
+            };
+
+        Ok(GenesisPlan {
+            system_account_hash: PublicKey::System.to_account_hash(),
+            mint,
+            auction,
+            handle_payment,
+            standard_payment,
+            era_validators: era_validators_from_snapshot(snapshot),
+        })
+    }
+
     fn create_mint(&mut self) -> Result<Key, Box<GenesisError>> {
         let round_seigniorage_rate_uref =
             {
@@ -1107,6 +1209,22 @@ where
             total_supply_uref
         };
 
+        let total_burned_uref = {
+            let total_burned_uref = self
+                .address_generator
+                .borrow_mut()
+                .new_uref(AccessRights::READ_ADD_WRITE);
+
+            self.tracking_copy.borrow_mut().write(
+                total_burned_uref.into(),
+                StoredValue::CLValue(
+                    CLValue::from_t(U512::zero())
+                        .map_err(|_| GenesisError::CLValue(TOTAL_BURNED_KEY.to_string()))?,
+                ),
+            );
+            total_burned_uref
+        };
+
         let named_keys = {
             let mut named_keys = NamedKeys::new();
             named_keys.insert(
@@ -1114,6 +1232,7 @@ where
                 round_seigniorage_rate_uref.into(),
             );
             named_keys.insert(TOTAL_SUPPLY_KEY.to_string(), total_supply_uref.into());
+            named_keys.insert(TOTAL_BURNED_KEY.to_string(), total_burned_uref.into());
 
             named_keys
         };
@@ -1131,9 +1250,9 @@ where
             // Insert a partial registry into global state.
             // This allows for default values to be accessible when the remaining system contracts
             // call the `call_host_mint` function during their creation.
-            let mut partial_registry = BTreeMap::<String, ContractHash>::new();
-            partial_registry.insert(MINT.to_string(), mint_hash);
-            partial_registry.insert(HANDLE_PAYMENT.to_string(), DEFAULT_ADDRESS.into());
+            let mut partial_registry = SystemEntityRegistry::new();
+            partial_registry.insert(MINT.to_string(), mint_hash.value());
+            partial_registry.insert(HANDLE_PAYMENT.to_string(), DEFAULT_ADDRESS);
             let cl_registry = CLValue::from_t(partial_registry)
                 .map_err(|error| GenesisError::CLValue(error.to_string()))?;
             self.tracking_copy.borrow_mut().write(
@@ -1162,6 +1281,32 @@ where
                 rewards_purse_uref.into(),
             );
 
+            let refund_handling_uref = self
+                .address_generator
+                .borrow_mut()
+                .new_uref(AccessRights::READ_ADD_WRITE);
+            self.tracking_copy.borrow_mut().write(
+                refund_handling_uref.into(),
+                StoredValue::CLValue(
+                    CLValue::from_t(self.exec_config.refund_handling())
+                        .map_err(|_| GenesisError::CLValue(REFUND_HANDLING_KEY.to_string()))?,
+                ),
+            );
+            named_keys.insert(REFUND_HANDLING_KEY.to_string(), refund_handling_uref.into());
+
+            let fee_handling_uref = self
+                .address_generator
+                .borrow_mut()
+                .new_uref(AccessRights::READ_ADD_WRITE);
+            self.tracking_copy.borrow_mut().write(
+                fee_handling_uref.into(),
+                StoredValue::CLValue(
+                    CLValue::from_t(self.exec_config.fee_handling())
+                        .map_err(|_| GenesisError::CLValue(FEE_HANDLING_KEY.to_string()))?,
+                ),
+            );
+            named_keys.insert(FEE_HANDLING_KEY.to_string(), fee_handling_uref.into());
+
             named_keys
         };
 
@@ -1621,9 +1766,9 @@ where
             .as_cl_value()
             .ok_or_else(|| GenesisError::CLValue("failed to convert to CLValue".to_string()))?
             .to_owned();
-        let mut partial_registry = CLValue::into_t::<SystemContractRegistry>(partial_cl_registry)
+        let mut partial_registry = SystemEntityRegistry::from_cl_value(partial_cl_registry)
             .map_err(|error| GenesisError::CLValue(error.to_string()))?;
-        partial_registry.insert(contract_name.to_string(), contract_hash);
+        partial_registry.insert(contract_name.to_string(), contract_hash.value());
         let cl_registry = CLValue::from_t(partial_registry)
             .map_err(|error| GenesisError::CLValue(error.to_string()))?;
         self.tracking_copy.borrow_mut().write(
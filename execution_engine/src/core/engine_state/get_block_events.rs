@@ -0,0 +1,45 @@
+//! Support for obtaining the contract events emitted in a block.
+use casper_hashing::Digest;
+use casper_types::ContractEvent;
+
+/// Represents a request to obtain the contract events emitted in the block which produced a given
+/// state root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetBlockEventsRequest {
+    state_hash: Digest,
+}
+
+impl GetBlockEventsRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        GetBlockEventsRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// Represents a result of a `get_block_events` request.
+#[derive(Debug)]
+pub enum GetBlockEventsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the events returned from the global state.
+    Success {
+        /// Events in the order they were emitted.
+        events: Vec<ContractEvent>,
+    },
+}
+
+impl GetBlockEventsResult {
+    /// Returns wrapped [`Vec<ContractEvent>`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<Vec<ContractEvent>> {
+        if let Self::Success { events } = self {
+            Some(events)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Support for obtaining the gas consumed by the deploys of a block against the block gas limit.
+use datasize::DataSize;
+
+use casper_hashing::Digest;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Gas, U512,
+};
+
+/// The gas consumed by the deploys of a block, and the block gas limit it was consumed against.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, DataSize)]
+pub struct BlockGasUtilization {
+    gas_consumed: U512,
+    block_gas_limit: u64,
+}
+
+impl BlockGasUtilization {
+    /// Creates a new block gas utilization with no gas consumed.
+    pub fn new(block_gas_limit: u64) -> Self {
+        BlockGasUtilization {
+            gas_consumed: U512::zero(),
+            block_gas_limit,
+        }
+    }
+
+    /// Adds the gas consumed by a deploy.
+    pub fn record(&mut self, gas: Gas) {
+        self.gas_consumed = self.gas_consumed.saturating_add(gas.value());
+    }
+
+    /// Returns the gas consumed by the deploys of the block.
+    pub fn gas_consumed(&self) -> U512 {
+        self.gas_consumed
+    }
+
+    /// Returns the block gas limit.
+    pub fn block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+    }
+
+    /// Returns the gas consumed as a percentage of the block gas limit, rounded down.
+    ///
+    /// A block can consume more than its limit, as the limit applies to the payment amounts of its
+    /// deploys rather than to the gas they go on to use.
+    pub fn utilization_percent(&self) -> U512 {
+        if self.block_gas_limit == 0 {
+            return U512::zero();
+        }
+        self.gas_consumed.saturating_mul(U512::from(100)) / self.block_gas_limit
+    }
+}
+
+impl CLTyped for BlockGasUtilization {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for BlockGasUtilization {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.gas_consumed.to_bytes()?);
+        buffer.extend(self.block_gas_limit.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.gas_consumed.serialized_length() + self.block_gas_limit.serialized_length()
+    }
+}
+
+impl FromBytes for BlockGasUtilization {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (gas_consumed, rem) = U512::from_bytes(bytes)?;
+        let (block_gas_limit, rem) = u64::from_bytes(rem)?;
+        Ok((
+            BlockGasUtilization {
+                gas_consumed,
+                block_gas_limit,
+            },
+            rem,
+        ))
+    }
+}
+
+/// Represents a request to obtain the gas utilization of the block which produced a given state
+/// root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetBlockGasUtilizationRequest {
+    state_hash: Digest,
+}
+
+impl GetBlockGasUtilizationRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        GetBlockGasUtilizationRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// Represents a result of a `get_block_gas_utilization` request.
+#[derive(Debug)]
+pub enum GetBlockGasUtilizationResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the block gas utilization returned from the global state.
+    Success {
+        /// The gas utilization of the block, or `None` if it wasn't recorded.
+        gas_utilization: Option<BlockGasUtilization>,
+    },
+}
+
+impl GetBlockGasUtilizationResult {
+    /// Returns wrapped [`BlockGasUtilization`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<Option<BlockGasUtilization>> {
+        if let Self::Success { gas_utilization } = self {
+            Some(gas_utilization)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        let mut gas_utilization = BlockGasUtilization::new(1_000);
+        gas_utilization.record(Gas::new(U512::from(250)));
+        bytesrepr::test_serialization_roundtrip(&gas_utilization);
+    }
+
+    #[test]
+    fn should_compute_utilization_percent() {
+        let mut gas_utilization = BlockGasUtilization::new(1_000);
+        assert_eq!(gas_utilization.utilization_percent(), U512::zero());
+
+        gas_utilization.record(Gas::new(U512::from(250)));
+        gas_utilization.record(Gas::new(U512::from(5)));
+        assert_eq!(gas_utilization.gas_consumed(), U512::from(255));
+        assert_eq!(gas_utilization.utilization_percent(), U512::from(25));
+
+        assert_eq!(
+            BlockGasUtilization::new(0).utilization_percent(),
+            U512::zero()
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Support for obtaining the Wasm bytecode of a stored contract.
+use casper_hashing::Digest;
+use casper_types::{
+    ContractHash, ContractPackageHash, ContractVersion, ContractWasm, ContractWasmHash,
+};
+
+/// Identifies the contract whose Wasm is requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoredContractIdentifier {
+    /// The contract stored under the given hash.
+    Hash(ContractHash),
+    /// A contract of the given package.
+    Package {
+        /// Hash of the contract package.
+        contract_package_hash: ContractPackageHash,
+        /// Version of the contract within the package, or `None` for the current version.
+        version: Option<ContractVersion>,
+    },
+}
+
+/// Represents a request to obtain the Wasm bytecode of a stored contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetContractWasmRequest {
+    state_hash: Digest,
+    contract_identifier: StoredContractIdentifier,
+    expected_digest: Option<Digest>,
+}
+
+impl GetContractWasmRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest, contract_identifier: StoredContractIdentifier) -> Self {
+        GetContractWasmRequest {
+            state_hash,
+            contract_identifier,
+            expected_digest: None,
+        }
+    }
+
+    /// Requires the BLAKE2b digest of the bytecode to equal `expected_digest`.
+    pub fn with_expected_digest(mut self, expected_digest: Digest) -> Self {
+        self.expected_digest = Some(expected_digest);
+        self
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the identifier of the contract.
+    pub fn contract_identifier(&self) -> StoredContractIdentifier {
+        self.contract_identifier
+    }
+
+    /// Returns the digest the bytecode is required to have, if any.
+    pub fn expected_digest(&self) -> Option<Digest> {
+        self.expected_digest
+    }
+}
+
+/// Represents a result of a `get_contract_wasm` request.
+#[derive(Debug)]
+pub enum GetContractWasmResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// No contract package is stored under the requested hash.
+    ContractPackageNotFound(ContractPackageHash),
+    /// The contract package has no enabled contract of the requested version.
+    ContractVersionNotFound {
+        /// Hash of the contract package.
+        contract_package_hash: ContractPackageHash,
+        /// The requested version, or `None` for the current version.
+        version: Option<ContractVersion>,
+    },
+    /// No contract is stored under the requested hash.
+    ContractNotFound(ContractHash),
+    /// No Wasm is stored under the hash the contract refers to.
+    ContractWasmNotFound(ContractWasmHash),
+    /// The bytecode doesn't have the digest required by the request.
+    DigestMismatch {
+        /// The digest required by the request.
+        expected: Digest,
+        /// The digest of the stored bytecode.
+        actual: Digest,
+    },
+    /// Contains the bytecode returned from the global state.
+    Success {
+        /// Hash of the contract the bytecode belongs to.
+        contract_hash: ContractHash,
+        /// Hash of the bytecode.
+        contract_wasm_hash: ContractWasmHash,
+        /// The bytecode.
+        contract_wasm: ContractWasm,
+        /// BLAKE2b digest of the bytecode.
+        digest: Digest,
+    },
+}
+
+impl GetContractWasmResult {
+    /// Returns wrapped [`ContractWasm`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<ContractWasm> {
+        if let Self::Success { contract_wasm, .. } = self {
+            Some(contract_wasm)
+        } else {
+            None
+        }
+    }
+}
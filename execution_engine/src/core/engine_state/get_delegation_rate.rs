@@ -0,0 +1,108 @@
+//! Support for obtaining a validator's delegation rate directly from global state.
+use casper_hashing::Digest;
+use casper_types::{system::auction::DelegationRate, EraId, PublicKey};
+
+/// Represents a request to obtain the delegation rate of a validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetDelegationRateRequest {
+    state_hash: Digest,
+    public_key: PublicKey,
+}
+
+impl GetDelegationRateRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest, public_key: PublicKey) -> Self {
+        GetDelegationRateRequest {
+            state_hash,
+            public_key,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the public key of the validator.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+/// A validator's delegation rate, both as set in its bid and as applied to the current era.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ValidatorDelegationRate {
+    pending: DelegationRate,
+    effective: Option<DelegationRate>,
+    last_increase_era_id: Option<EraId>,
+    next_increase_era_id: Option<EraId>,
+}
+
+impl ValidatorDelegationRate {
+    /// Creates a new validator delegation rate.
+    pub fn new(
+        pending: DelegationRate,
+        effective: Option<DelegationRate>,
+        last_increase_era_id: Option<EraId>,
+        next_increase_era_id: Option<EraId>,
+    ) -> Self {
+        ValidatorDelegationRate {
+            pending,
+            effective,
+            last_increase_era_id,
+            next_increase_era_id,
+        }
+    }
+
+    /// Returns the delegation rate set in the validator's bid.
+    ///
+    /// It applies from the first era whose validators are selected by an auction run after it was
+    /// set.
+    pub fn pending(&self) -> DelegationRate {
+        self.pending
+    }
+
+    /// Returns the delegation rate applied to the rewards of the current era, or `None` if the
+    /// validator isn't in the current era's validator set.
+    pub fn effective(&self) -> Option<DelegationRate> {
+        self.effective
+    }
+
+    /// Returns the era in which the validator last increased its delegation rate, if recorded.
+    ///
+    /// Increases are only recorded while a delegation rate change cooldown is configured.
+    pub fn last_increase_era_id(&self) -> Option<EraId> {
+        self.last_increase_era_id
+    }
+
+    /// Returns the first era in which the validator can increase its delegation rate again, or
+    /// `None` if it can do so at any time.
+    pub fn next_increase_era_id(&self) -> Option<EraId> {
+        self.next_increase_era_id
+    }
+}
+
+/// Represents a result of a `get_delegation_rate` request.
+#[derive(Debug)]
+pub enum GetDelegationRateResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// The validator has no bid.
+    ValidatorNotFound,
+    /// Contains the delegation rate of the validator returned from the global state.
+    Success {
+        /// Delegation rate of the validator.
+        delegation_rate: ValidatorDelegationRate,
+    },
+}
+
+impl GetDelegationRateResult {
+    /// Returns wrapped [`ValidatorDelegationRate`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<ValidatorDelegationRate> {
+        if let Self::Success { delegation_rate } = self {
+            Some(delegation_rate)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Support for obtaining a dictionary item, along with its proof, by the dictionary's seed and the
+//! item's key.
+use casper_hashing::Digest;
+use casper_types::{Key, StoredValue, URef};
+
+use crate::storage::trie::merkle_proof::TrieMerkleProof;
+
+/// Represents a request to obtain an item of a dictionary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetDictionaryItemRequest {
+    state_hash: Digest,
+    seed_uref: URef,
+    dictionary_item_key: Vec<u8>,
+}
+
+impl GetDictionaryItemRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest, seed_uref: URef, dictionary_item_key: Vec<u8>) -> Self {
+        GetDictionaryItemRequest {
+            state_hash,
+            seed_uref,
+            dictionary_item_key,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the seed URef of the dictionary.
+    pub fn seed_uref(&self) -> URef {
+        self.seed_uref
+    }
+
+    /// Returns the key of the item within the dictionary.
+    pub fn dictionary_item_key(&self) -> &[u8] {
+        &self.dictionary_item_key
+    }
+
+    /// Returns the global state key the item is stored under.
+    pub fn dictionary_key(&self) -> Key {
+        Key::dictionary(self.seed_uref, &self.dictionary_item_key)
+    }
+}
+
+/// Represents a result of a `get_dictionary_item` request.
+#[derive(Debug)]
+pub enum GetDictionaryItemResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// No item is stored under the given dictionary key.
+    ItemNotFound {
+        /// The global state key derived from the seed and the item key.
+        dictionary_key: Key,
+    },
+    /// The value stored under the dictionary key isn't a dictionary value.
+    InvalidItem {
+        /// The global state key derived from the seed and the item key.
+        dictionary_key: Key,
+        /// Why the stored value couldn't be read as a dictionary value.
+        error: String,
+    },
+    /// Contains the item returned from the global state.
+    Success {
+        /// The global state key derived from the seed and the item key.
+        dictionary_key: Key,
+        /// The value of the item, unwrapped from the stored dictionary value.
+        value: Box<StoredValue>,
+        /// Merkle proof of the stored dictionary value.
+        proof: Box<TrieMerkleProof<Key, StoredValue>>,
+    },
+}
+
+impl GetDictionaryItemResult {
+    /// Returns the wrapped value if this represents a successful query result.
+    pub fn into_success(self) -> Option<StoredValue> {
+        if let Self::Success { value, .. } = self {
+            Some(*value)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Support for obtaining the auction's current era directly from global state.
+use casper_hashing::Digest;
+use casper_types::EraId;
+
+/// Represents a request to obtain the auction's current era.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetEraInfoRequest {
+    state_hash: Digest,
+}
+
+impl GetEraInfoRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        GetEraInfoRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// The auction's view of the current era, as recorded in global state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CurrentEraInfo {
+    era_id: EraId,
+    era_start_timestamp_millis: u64,
+    auction_delay: u64,
+}
+
+impl CurrentEraInfo {
+    /// Creates new era info.
+    pub fn new(era_id: EraId, era_start_timestamp_millis: u64, auction_delay: u64) -> Self {
+        CurrentEraInfo {
+            era_id,
+            era_start_timestamp_millis,
+            auction_delay,
+        }
+    }
+
+    /// Returns the id of the current era.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns the block time at which the current era started.
+    ///
+    /// This is the end timestamp of the previous era as recorded by the last auction run.
+    pub fn era_start_timestamp_millis(&self) -> u64 {
+        self.era_start_timestamp_millis
+    }
+
+    /// Returns the number of eras between an auction and the era whose validators it selects.
+    pub fn auction_delay(&self) -> u64 {
+        self.auction_delay
+    }
+}
+
+/// Represents a result of a `get_era_info` request.
+#[derive(Debug)]
+pub enum GetEraInfoResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the current era info returned from the global state.
+    Success {
+        /// Current era info.
+        era_info: CurrentEraInfo,
+    },
+}
+
+impl GetEraInfoResult {
+    /// Returns wrapped [`CurrentEraInfo`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<CurrentEraInfo> {
+        if let Self::Success { era_info } = self {
+            Some(era_info)
+        } else {
+            None
+        }
+    }
+}
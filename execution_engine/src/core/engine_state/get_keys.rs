@@ -0,0 +1,67 @@
+//! Support for paging through the keys of global state sharing a tag.
+use casper_hashing::Digest;
+use casper_types::{Key, KeyTag};
+
+/// Represents a request for a page of the keys sharing a tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetKeysRequest {
+    state_hash: Digest,
+    key_tag: KeyTag,
+    cursor: Option<Key>,
+    limit: usize,
+}
+
+impl GetKeysRequest {
+    /// Creates new request for at most `limit` keys tagged `key_tag` which come after `cursor`.
+    pub fn new(state_hash: Digest, key_tag: KeyTag, cursor: Option<Key>, limit: usize) -> Self {
+        GetKeysRequest {
+            state_hash,
+            key_tag,
+            cursor,
+            limit,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the tag of the requested keys.
+    pub fn key_tag(&self) -> KeyTag {
+        self.key_tag
+    }
+
+    /// Returns the key the page starts after, if any.
+    pub fn cursor(&self) -> Option<&Key> {
+        self.cursor.as_ref()
+    }
+
+    /// Returns the maximum number of keys in the page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// Represents a result of a `get_keys` request.
+#[derive(Debug)]
+pub enum GetKeysResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains a page of keys returned from the global state.
+    Success {
+        /// Keys in ascending order of their serialized form.
+        keys: Vec<Key>,
+    },
+}
+
+impl GetKeysResult {
+    /// Returns wrapped keys if this represents a successful query result.
+    pub fn into_success(self) -> Option<Vec<Key>> {
+        if let Self::Success { keys } = self {
+            Some(keys)
+        } else {
+            None
+        }
+    }
+}
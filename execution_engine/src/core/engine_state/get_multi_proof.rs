@@ -0,0 +1,127 @@
+//! Support for proving the values of several keys of global state with a single Merkle proof.
+use casper_hashing::{Digest, TrieMultiProof, TrieMultiProofNode};
+use casper_types::{bytesrepr, Key, StoredValue};
+
+use crate::{
+    core::engine_state::Error,
+    shared::newtypes::CorrelationId,
+    storage::{
+        global_state::StateProvider,
+        trie::{Pointer, Trie},
+    },
+};
+
+/// Represents a result of a `get_multi_proof` request.
+#[derive(Debug)]
+pub enum GetMultiProofResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the proof of the requested keys.
+    Success {
+        /// The proof, covering every requested key whether or not it has a value.
+        proof: TrieMultiProof<Key, StoredValue>,
+    },
+}
+
+impl GetMultiProofResult {
+    /// Returns the wrapped proof if this represents a successful result.
+    pub fn into_success(self) -> Option<TrieMultiProof<Key, StoredValue>> {
+        if let Self::Success { proof } = self {
+            Some(proof)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads the trie with hash `trie_key`.
+pub(crate) fn read_trie<S>(
+    state: &S,
+    correlation_id: CorrelationId,
+    trie_key: &Digest,
+) -> Result<Option<Trie<Key, StoredValue>>, Error>
+where
+    S: StateProvider,
+    Error: From<S::Error>,
+{
+    match state.get_trie_full(correlation_id, trie_key)? {
+        Some(trie_raw) => Ok(Some(bytesrepr::deserialize(trie_raw.into_inner().into())?)),
+        None => Ok(None),
+    }
+}
+
+/// Expands `trie` into a node of a multi-proof of the keys with serialized form `paths`, each of
+/// which leads to `trie` through its first `depth` bytes.
+pub(crate) fn build_proof_node<S>(
+    state: &S,
+    correlation_id: CorrelationId,
+    trie: Trie<Key, StoredValue>,
+    paths: &[&[u8]],
+    depth: usize,
+) -> Result<TrieMultiProofNode<Key, StoredValue>, Error>
+where
+    S: StateProvider,
+    Error: From<S::Error>,
+{
+    match trie {
+        Trie::Leaf { key, value } => Ok(TrieMultiProofNode::Leaf { key, value }),
+        Trie::Node { pointer_block } => {
+            let mut children = Vec::new();
+            for (index, pointer) in pointer_block.as_indexed_pointers() {
+                let child_paths: Vec<&[u8]> = paths
+                    .iter()
+                    .copied()
+                    .filter(|path| path.get(depth) == Some(&index))
+                    .collect();
+                let child =
+                    build_proof_child(state, correlation_id, pointer, &child_paths, depth + 1)?;
+                children.push((index, child));
+            }
+            Ok(TrieMultiProofNode::Node { children })
+        }
+        Trie::Extension { affix, pointer } => {
+            let child_paths: Vec<&[u8]> = paths
+                .iter()
+                .copied()
+                .filter(|path| {
+                    path.get(depth..)
+                        .map_or(false, |remaining_path| remaining_path.starts_with(&affix))
+                })
+                .collect();
+            let child = build_proof_child(
+                state,
+                correlation_id,
+                pointer,
+                &child_paths,
+                depth + affix.len(),
+            )?;
+            Ok(TrieMultiProofNode::Extension {
+                affix,
+                child: Box::new(child),
+            })
+        }
+    }
+}
+
+/// Expands the child of a trie node if any of `paths` lead through it, and prunes it otherwise.
+fn build_proof_child<S>(
+    state: &S,
+    correlation_id: CorrelationId,
+    pointer: Pointer,
+    paths: &[&[u8]],
+    depth: usize,
+) -> Result<TrieMultiProofNode<Key, StoredValue>, Error>
+where
+    S: StateProvider,
+    Error: From<S::Error>,
+{
+    if paths.is_empty() {
+        return Ok(match pointer {
+            Pointer::LeafPointer(hash) => TrieMultiProofNode::PrunedLeaf(hash),
+            Pointer::NodePointer(hash) => TrieMultiProofNode::PrunedNode(hash),
+        });
+    }
+    let hash = pointer.into_hash();
+    let trie = read_trie(state, correlation_id, &hash)?.ok_or(Error::MissingTrieNode(hash))?;
+    build_proof_node(state, correlation_id, trie, paths, depth)
+}
@@ -0,0 +1,112 @@
+//! Support for inspecting the purses of the handle payment contract and how fees are handled.
+use casper_hashing::Digest;
+use casper_types::{URef, U512};
+
+use super::engine_config::{FeeHandling, RefundHandling};
+
+/// Represents a request to obtain the payment configuration and purses of the handle payment
+/// contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetPaymentInfoRequest {
+    state_hash: Digest,
+}
+
+impl GetPaymentInfoRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        GetPaymentInfoRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// The purses of the handle payment contract and how refunds and fees are handled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PaymentInfo {
+    payment_purse: URef,
+    payment_purse_balance: U512,
+    accumulation_purse: Option<(URef, U512)>,
+    refund_handling: Option<RefundHandling>,
+    fee_handling: Option<FeeHandling>,
+}
+
+impl PaymentInfo {
+    /// Creates new payment info.
+    pub fn new(
+        payment_purse: URef,
+        payment_purse_balance: U512,
+        accumulation_purse: Option<(URef, U512)>,
+        refund_handling: Option<RefundHandling>,
+        fee_handling: Option<FeeHandling>,
+    ) -> Self {
+        PaymentInfo {
+            payment_purse,
+            payment_purse_balance,
+            accumulation_purse,
+            refund_handling,
+            fee_handling,
+        }
+    }
+
+    /// Returns the purse holding the payment of the deploy being executed.
+    pub fn payment_purse(&self) -> URef {
+        self.payment_purse
+    }
+
+    /// Returns the balance of the payment purse, which is zero between deploys.
+    pub fn payment_purse_balance(&self) -> U512 {
+        self.payment_purse_balance
+    }
+
+    /// Returns the purse accumulating fees, or `None` if the handle payment contract predates it.
+    pub fn accumulation_purse(&self) -> Option<URef> {
+        self.accumulation_purse.map(|(purse, _)| purse)
+    }
+
+    /// Returns the balance of the accumulation purse, if there is one.
+    pub fn accumulation_purse_balance(&self) -> Option<U512> {
+        self.accumulation_purse.map(|(_, balance)| balance)
+    }
+
+    /// Returns how the unspent part of a payment is handled, as recorded at genesis or at the last
+    /// upgrade.
+    ///
+    /// This is `None` on networks which haven't been upgraded since it started being recorded.
+    pub fn refund_handling(&self) -> Option<RefundHandling> {
+        self.refund_handling
+    }
+
+    /// Returns how the fees paid for deploys are handled, as recorded at genesis or at the last
+    /// upgrade.
+    ///
+    /// This is `None` on networks which haven't been upgraded since it started being recorded.
+    pub fn fee_handling(&self) -> Option<FeeHandling> {
+        self.fee_handling
+    }
+}
+
+/// Represents a result of a `get_payment_info` request.
+#[derive(Debug)]
+pub enum GetPaymentInfoResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the payment info returned from the global state.
+    Success {
+        /// The payment info.
+        payment_info: Box<PaymentInfo>,
+    },
+}
+
+impl GetPaymentInfoResult {
+    /// Returns wrapped [`PaymentInfo`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<PaymentInfo> {
+        if let Self::Success { payment_info } = self {
+            Some(*payment_info)
+        } else {
+            None
+        }
+    }
+}
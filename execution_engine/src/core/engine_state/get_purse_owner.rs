@@ -0,0 +1,54 @@
+//! Support for looking up the owner of a purse in the purse ownership index.
+use casper_hashing::Digest;
+use casper_types::{Key, URefAddr};
+
+/// Represents a request to obtain the owner of a purse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetPurseOwnerRequest {
+    state_hash: Digest,
+    purse_addr: URefAddr,
+}
+
+impl GetPurseOwnerRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest, purse_addr: URefAddr) -> Self {
+        GetPurseOwnerRequest {
+            state_hash,
+            purse_addr,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the address of the purse.
+    pub fn purse_addr(&self) -> URefAddr {
+        self.purse_addr
+    }
+}
+
+/// Represents a result of a `get_purse_owner` request.
+#[derive(Debug)]
+pub enum GetPurseOwnerResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the owner of the purse returned from the global state.
+    Success {
+        /// The account or contract which created the purse, or `None` if the purse wasn't created
+        /// through the mint while the purse ownership index was enabled.
+        owner: Option<Key>,
+    },
+}
+
+impl GetPurseOwnerResult {
+    /// Returns the wrapped owner if this represents a successful query result.
+    pub fn into_success(self) -> Option<Option<Key>> {
+        if let Self::Success { owner } = self {
+            Some(owner)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Support for obtaining the delegator slots reserved by validators in the auction system.
+use casper_hashing::Digest;
+use casper_types::system::auction::Reservations;
+
+/// Represents a request to obtain the reserved delegator slots in the auction system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetReservationsRequest {
+    state_hash: Digest,
+}
+
+impl GetReservationsRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        GetReservationsRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// Represents a result of a `get_reservations` request.
+#[derive(Debug)]
+pub enum GetReservationsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the reserved delegator slots returned from the global state.
+    Success {
+        /// Reserved delegator slots, by validator.
+        reservations: Reservations,
+    },
+}
+
+impl GetReservationsResult {
+    /// Returns wrapped [`Reservations`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<Reservations> {
+        if let Self::Success { reservations } = self {
+            Some(reservations)
+        } else {
+            None
+        }
+    }
+}
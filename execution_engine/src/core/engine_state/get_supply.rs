@@ -0,0 +1,75 @@
+//! Support for obtaining the mint's supply figures directly from global state.
+use casper_hashing::Digest;
+use casper_types::U512;
+
+/// Represents a request to obtain the supply breakdown of the mint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetSupplyRequest {
+    state_hash: Digest,
+}
+
+impl GetSupplyRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        GetSupplyRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// The supply of token as recorded by the mint.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SupplyBreakdown {
+    total_supply: U512,
+    total_burned: Option<U512>,
+}
+
+impl SupplyBreakdown {
+    /// Creates new supply breakdown.
+    pub fn new(total_supply: U512, total_burned: Option<U512>) -> Self {
+        SupplyBreakdown {
+            total_supply,
+            total_burned,
+        }
+    }
+
+    /// Returns the amount of token currently in existence.
+    pub fn total_supply(&self) -> U512 {
+        self.total_supply
+    }
+
+    /// Returns the cumulative amount of token burned, or `None` if the state predates burns being
+    /// recorded.
+    ///
+    /// On networks started before burns were recorded this only covers burns since the upgrade
+    /// which introduced the counter.
+    pub fn total_burned(&self) -> Option<U512> {
+        self.total_burned
+    }
+}
+
+/// Represents a result of a `get_supply` request.
+#[derive(Debug)]
+pub enum GetSupplyResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the supply breakdown returned from the global state.
+    Success {
+        /// The supply breakdown.
+        supply: SupplyBreakdown,
+    },
+}
+
+impl GetSupplyResult {
+    /// Returns wrapped [`SupplyBreakdown`] if this represents a successful query result.
+    pub fn into_success(self) -> Option<SupplyBreakdown> {
+        if let Self::Success { supply } = self {
+            Some(supply)
+        } else {
+            None
+        }
+    }
+}
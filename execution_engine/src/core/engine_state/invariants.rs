@@ -0,0 +1,262 @@
+//! Support for checking that global state satisfies invariants spanning the system contracts.
+//!
+//! The checks read large parts of global state, so they're only meant for debugging, e.g. to
+//! catch state corruption on a devnet at the block which introduced it.
+use std::fmt::{self, Display, Formatter};
+
+use casper_hashing::Digest;
+use casper_types::{
+    account::AccountHash, AccessRights, Key, KeyTag, PublicKey, StoredValue, URef, URefAddr, U512,
+};
+
+use crate::{
+    core::{engine_state::Error, execution},
+    shared::newtypes::CorrelationId,
+    storage::global_state::StateReader,
+};
+
+/// Represents a request to check the invariants of the global state under a state root hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckInvariantsRequest {
+    state_hash: Digest,
+}
+
+impl CheckInvariantsRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        CheckInvariantsRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// An invariant which doesn't hold in global state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// The total supply recorded by the mint differs from the sum of all purse balances.
+    SupplyMismatch {
+        /// The total supply recorded by the mint.
+        total_supply: U512,
+        /// The sum of all purse balances.
+        total_balances: U512,
+    },
+    /// The bonding purse of a validator's bid doesn't exist.
+    MissingBidPurse {
+        /// The validator.
+        validator_public_key: PublicKey,
+        /// The missing purse.
+        bonding_purse: URef,
+    },
+    /// The bonding purse of a delegation doesn't exist.
+    MissingDelegatorPurse {
+        /// The validator delegated to.
+        validator_public_key: PublicKey,
+        /// The delegator.
+        delegator_public_key: PublicKey,
+        /// The missing purse.
+        bonding_purse: URef,
+    },
+    /// The main purse of an account doesn't exist.
+    MissingMainPurse {
+        /// The account.
+        account_hash: AccountHash,
+        /// The missing purse.
+        main_purse: URef,
+    },
+    /// A balance is recorded for a purse which doesn't exist.
+    BalanceWithoutPurse {
+        /// The address of the purse.
+        purse_addr: URefAddr,
+    },
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::SupplyMismatch {
+                total_supply,
+                total_balances,
+            } => write!(
+                formatter,
+                "total supply {} differs from sum of balances {}",
+                total_supply, total_balances
+            ),
+            InvariantViolation::MissingBidPurse {
+                validator_public_key,
+                bonding_purse,
+            } => write!(
+                formatter,
+                "bonding purse {} of bid of {} doesn't exist",
+                bonding_purse, validator_public_key
+            ),
+            InvariantViolation::MissingDelegatorPurse {
+                validator_public_key,
+                delegator_public_key,
+                bonding_purse,
+            } => write!(
+                formatter,
+                "bonding purse {} of delegation of {} to {} doesn't exist",
+                bonding_purse, delegator_public_key, validator_public_key
+            ),
+            InvariantViolation::MissingMainPurse {
+                account_hash,
+                main_purse,
+            } => write!(
+                formatter,
+                "main purse {} of account {} doesn't exist",
+                main_purse, account_hash
+            ),
+            InvariantViolation::BalanceWithoutPurse { purse_addr } => write!(
+                formatter,
+                "balance recorded for purse {} which doesn't exist",
+                base16::encode_lower(purse_addr)
+            ),
+        }
+    }
+}
+
+/// Represents a result of a `check_invariants` request.
+#[derive(Debug)]
+pub enum CheckInvariantsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the invariants which don't hold.
+    Success {
+        /// The violated invariants, empty if all invariants hold.
+        violations: Vec<InvariantViolation>,
+    },
+}
+
+impl CheckInvariantsResult {
+    /// Returns the wrapped violations if this represents a successful check.
+    pub fn into_success(self) -> Option<Vec<InvariantViolation>> {
+        if let Self::Success { violations } = self {
+            Some(violations)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks the invariants of the global state read through a [`StateReader`].
+pub(crate) struct InvariantChecker<'a, R> {
+    correlation_id: CorrelationId,
+    reader: &'a R,
+}
+
+impl<'a, R> InvariantChecker<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    pub(crate) fn new(correlation_id: CorrelationId, reader: &'a R) -> Self {
+        InvariantChecker {
+            correlation_id,
+            reader,
+        }
+    }
+
+    /// Returns the violated invariants, given the total supply recorded by the mint.
+    pub(crate) fn check(&self, total_supply: U512) -> Result<Vec<InvariantViolation>, Error> {
+        let mut violations = Vec::new();
+        self.check_balances(total_supply, &mut violations)?;
+        self.check_bids(&mut violations)?;
+        self.check_accounts(&mut violations)?;
+        Ok(violations)
+    }
+
+    fn check_balances(
+        &self,
+        total_supply: U512,
+        violations: &mut Vec<InvariantViolation>,
+    ) -> Result<(), Error> {
+        let mut total_balances = U512::zero();
+        for key in self.keys(KeyTag::Balance)? {
+            let purse_addr = match key {
+                Key::Balance(purse_addr) => purse_addr,
+                _ => continue,
+            };
+            let balance: U512 = match self.read(&key)? {
+                Some(StoredValue::CLValue(cl_value)) => cl_value.into_t()?,
+                Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => continue,
+            };
+            total_balances = total_balances.saturating_add(balance);
+
+            let purse_key = Key::URef(URef::new(purse_addr, AccessRights::NONE));
+            if self.read(&purse_key)?.is_none() {
+                violations.push(InvariantViolation::BalanceWithoutPurse { purse_addr });
+            }
+        }
+
+        if total_balances != total_supply {
+            violations.push(InvariantViolation::SupplyMismatch {
+                total_supply,
+                total_balances,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_bids(&self, violations: &mut Vec<InvariantViolation>) -> Result<(), Error> {
+        for key in self.keys(KeyTag::Bid)? {
+            let bid = match self.read(&key)? {
+                Some(StoredValue::Bid(bid)) => bid,
+                Some(_) | None => continue,
+            };
+            if !self.purse_exists(bid.bonding_purse())? {
+                violations.push(InvariantViolation::MissingBidPurse {
+                    validator_public_key: bid.validator_public_key().clone(),
+                    bonding_purse: *bid.bonding_purse(),
+                });
+            }
+            for delegator in bid.delegators().values() {
+                if !self.purse_exists(delegator.bonding_purse())? {
+                    violations.push(InvariantViolation::MissingDelegatorPurse {
+                        validator_public_key: bid.validator_public_key().clone(),
+                        delegator_public_key: delegator.delegator_public_key().clone(),
+                        bonding_purse: *delegator.bonding_purse(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_accounts(&self, violations: &mut Vec<InvariantViolation>) -> Result<(), Error> {
+        for key in self.keys(KeyTag::Account)? {
+            let account = match self.read(&key)? {
+                Some(StoredValue::Account(account)) => account,
+                Some(_) | None => continue,
+            };
+            if !self.purse_exists(&account.main_purse())? {
+                violations.push(InvariantViolation::MissingMainPurse {
+                    account_hash: account.account_hash(),
+                    main_purse: account.main_purse(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn purse_exists(&self, purse: &URef) -> Result<bool, Error> {
+        Ok(self.read(&Key::Balance(purse.addr()))?.is_some())
+    }
+
+    fn keys(&self, key_tag: KeyTag) -> Result<Vec<Key>, Error> {
+        Ok(self
+            .reader
+            .keys_with_prefix(self.correlation_id, &[key_tag as u8])
+            .map_err(Into::into)?)
+    }
+
+    fn read(&self, key: &Key) -> Result<Option<StoredValue>, Error> {
+        Ok(self
+            .reader
+            .read(self.correlation_id, key)
+            .map_err(Into::into)?)
+    }
+}
@@ -0,0 +1,298 @@
+//! Support for maintenance tasks run by a step.
+//!
+//! System-level upkeep which has to happen once per era, such as draining a migration queue or
+//! accruing rent, is registered on an [`EngineState`](super::EngineState) as a
+//! [`MaintenanceTask`] with an order and a gas budget, rather than being wired into
+//! [`EngineState::commit_step`](super::EngineState::commit_step) by hand.
+//!
+//! After running the auction, a step runs the registered tasks in ascending order, breaking ties
+//! by name.  A task sees the writes of the tasks run before it.  A task which fails or exceeds its
+//! gas budget has its writes discarded without failing the step, and is reported in the
+//! [`StepReport`](super::StepReport).
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+use casper_types::{bytesrepr::ToBytes, EraId, Gas, Key, ProtocolVersion, StoredValue};
+
+use crate::{
+    core::{engine_state::Error, execution, tracking_copy::TrackingCopy},
+    shared::{newtypes::CorrelationId, storage_costs::StorageCosts},
+    storage::global_state::StateReader,
+};
+
+type MaintenanceFn = dyn Fn(&mut MaintenanceContext) -> Result<(), Error> + Send + Sync;
+
+/// A maintenance task run by every step.
+#[derive(Clone)]
+pub struct MaintenanceTask {
+    name: String,
+    order: u32,
+    gas_budget: Gas,
+    run: Arc<MaintenanceFn>,
+}
+
+impl MaintenanceTask {
+    /// Creates a new maintenance task.
+    ///
+    /// Tasks with a lower `order` are run first.  `run` is called with a context through which the
+    /// task reads and writes global state, and which fails the task once it would spend more than
+    /// `gas_budget`.
+    pub fn new<F>(name: impl Into<String>, order: u32, gas_budget: Gas, run: F) -> Self
+    where
+        F: Fn(&mut MaintenanceContext) -> Result<(), Error> + Send + Sync + 'static,
+    {
+        MaintenanceTask {
+            name: name.into(),
+            order,
+            gas_budget,
+            run: Arc::new(run),
+        }
+    }
+
+    /// Returns the name of the task.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the order of the task.
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+
+    /// Returns the gas budget of the task.
+    pub fn gas_budget(&self) -> Gas {
+        self.gas_budget
+    }
+
+    /// Runs the task in `context`, returning its writes if it succeeds.
+    pub(crate) fn run(
+        &self,
+        mut context: MaintenanceContext,
+    ) -> Result<BTreeMap<Key, StoredValue>, Error> {
+        (self.run)(&mut context)?;
+        Ok(context.into_writes())
+    }
+}
+
+impl Debug for MaintenanceTask {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("MaintenanceTask")
+            .field("name", &self.name)
+            .field("order", &self.order)
+            .field("gas_budget", &self.gas_budget)
+            .finish()
+    }
+}
+
+/// Read access to global state for maintenance tasks.
+pub(crate) trait MaintenanceReader {
+    fn read(
+        &mut self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<StoredValue>, Error>;
+
+    fn keys_with_prefix(
+        &mut self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<BTreeSet<Key>, Error>;
+}
+
+impl<R> MaintenanceReader for TrackingCopy<R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read(
+        &mut self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<StoredValue>, Error> {
+        TrackingCopy::read(self, correlation_id, key).map_err(|error| Error::Exec(error.into()))
+    }
+
+    fn keys_with_prefix(
+        &mut self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<BTreeSet<Key>, Error> {
+        self.get_keys_with_prefix(correlation_id, prefix)
+            .map_err(|error| Error::Exec(error.into()))
+    }
+}
+
+/// The context in which a maintenance task is run.
+///
+/// Writes are buffered in the context, and only applied to global state once the task succeeds.
+pub struct MaintenanceContext<'a> {
+    correlation_id: CorrelationId,
+    reader: &'a mut dyn MaintenanceReader,
+    storage_costs: StorageCosts,
+    protocol_version: ProtocolVersion,
+    next_era_id: EraId,
+    era_end_timestamp_millis: u64,
+    gas_budget: Gas,
+    gas_used: Gas,
+    writes: BTreeMap<Key, StoredValue>,
+}
+
+impl<'a> MaintenanceContext<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        correlation_id: CorrelationId,
+        reader: &'a mut dyn MaintenanceReader,
+        storage_costs: StorageCosts,
+        protocol_version: ProtocolVersion,
+        next_era_id: EraId,
+        era_end_timestamp_millis: u64,
+        gas_budget: Gas,
+    ) -> Self {
+        MaintenanceContext {
+            correlation_id,
+            reader,
+            storage_costs,
+            protocol_version,
+            next_era_id,
+            era_end_timestamp_millis,
+            gas_budget,
+            gas_used: Gas::default(),
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the protocol version of the step.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the id of the era starting after the step.
+    pub fn next_era_id(&self) -> EraId {
+        self.next_era_id
+    }
+
+    /// Returns the timestamp in milliseconds of the end of the era ending with the step.
+    pub fn era_end_timestamp_millis(&self) -> u64 {
+        self.era_end_timestamp_millis
+    }
+
+    /// Returns the gas spent by the task so far.
+    pub fn gas_used(&self) -> Gas {
+        self.gas_used
+    }
+
+    /// Returns the gas left in the task's budget.
+    pub fn gas_remaining(&self) -> Gas {
+        self.gas_budget
+            .checked_sub(self.gas_used)
+            .unwrap_or_default()
+    }
+
+    /// Spends `gas` from the task's budget, failing with [`execution::Error::GasLimit`] if the
+    /// budget would be exceeded.
+    pub fn charge(&mut self, gas: Gas) -> Result<(), Error> {
+        match self.gas_used.checked_add(gas) {
+            Some(gas_used) if gas_used <= self.gas_budget => {
+                self.gas_used = gas_used;
+                Ok(())
+            }
+            _ => Err(Error::Exec(execution::Error::GasLimit)),
+        }
+    }
+
+    /// Reads the value stored under `key`, including the task's own writes.
+    pub fn read(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
+        let key = key.normalize();
+        if let Some(value) = self.writes.get(&key) {
+            return Ok(Some(value.clone()));
+        }
+        self.reader.read(self.correlation_id, &key)
+    }
+
+    /// Writes `value` under `key`, charging the storage cost of the serialized value.
+    pub fn write(&mut self, key: Key, value: StoredValue) -> Result<(), Error> {
+        self.charge(
+            self.storage_costs
+                .calculate_gas_cost(value.serialized_length()),
+        )?;
+        self.writes.insert(key.normalize(), value);
+        Ok(())
+    }
+
+    /// Returns the keys whose serialized form starts with `prefix`, including the task's own
+    /// writes.
+    pub fn keys_with_prefix(&mut self, prefix: &[u8]) -> Result<BTreeSet<Key>, Error> {
+        let mut keys = self.reader.keys_with_prefix(self.correlation_id, prefix)?;
+        for key in self.writes.keys() {
+            if key.to_bytes()?.starts_with(prefix) {
+                keys.insert(*key);
+            }
+        }
+        Ok(keys)
+    }
+
+    fn into_writes(self) -> BTreeMap<Key, StoredValue> {
+        self.writes
+    }
+}
+
+/// The maintenance tasks registered on an engine state, in the order they are run.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MaintenanceRegistry {
+    tasks: Vec<MaintenanceTask>,
+}
+
+impl MaintenanceRegistry {
+    /// Registers `task`, failing if a task with the same name is already registered.
+    pub(crate) fn register(&mut self, task: MaintenanceTask) -> Result<(), Error> {
+        if self.tasks.iter().any(|existing| existing.name == task.name) {
+            return Err(Error::DuplicateMaintenanceTask(task.name));
+        }
+        let index = self.tasks.partition_point(|existing| {
+            (existing.order, &existing.name) < (task.order, &task.name)
+        });
+        self.tasks.insert(index, task);
+        Ok(())
+    }
+
+    /// Returns the registered tasks in the order they are run.
+    pub(crate) fn tasks(&self) -> &[MaintenanceTask] {
+        &self.tasks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noop_task(name: &str, order: u32) -> MaintenanceTask {
+        MaintenanceTask::new(name, order, Gas::default(), |_| Ok(()))
+    }
+
+    #[test]
+    fn should_order_tasks_by_order_then_name() {
+        let mut registry = MaintenanceRegistry::default();
+        registry.register(noop_task("c", 1)).unwrap();
+        registry.register(noop_task("b", 2)).unwrap();
+        registry.register(noop_task("a", 1)).unwrap();
+        registry.register(noop_task("d", 0)).unwrap();
+
+        let names: Vec<&str> = registry.tasks().iter().map(MaintenanceTask::name).collect();
+        assert_eq!(names, vec!["d", "a", "c", "b"]);
+    }
+
+    #[test]
+    fn should_reject_duplicate_task_names() {
+        let mut registry = MaintenanceRegistry::default();
+        registry.register(noop_task("a", 1)).unwrap();
+        assert!(matches!(
+            registry.register(noop_task("a", 2)),
+            Err(Error::DuplicateMaintenanceTask(name)) if name == "a"
+        ));
+        assert_eq!(registry.tasks().len(), 1);
+    }
+}
@@ -2,30 +2,61 @@
 pub mod balance;
 pub mod chainspec_registry;
 pub mod checksum_registry;
+pub mod custom_payment;
 pub mod deploy_item;
 pub mod engine_config;
+pub mod era_summary_migration;
 pub mod era_validators;
 mod error;
 pub mod executable_deploy_item;
 pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
+pub mod execution_stats;
+pub mod execution_warning;
+pub mod forecast_validators;
+pub mod gc;
 pub mod genesis;
 pub mod get_bids;
+pub mod get_block_events;
+pub mod get_block_gas_utilization;
+pub mod get_contract_wasm;
+pub mod get_delegation_rate;
+pub mod get_dictionary_item;
+pub mod get_era_info;
+pub mod get_keys;
+pub mod get_multi_proof;
+pub mod get_payment_info;
+pub mod get_purse_owner;
+pub mod get_reservations;
+pub mod get_supply;
+pub mod invariants;
+pub mod maintenance;
 pub mod op;
+pub mod prove_absence;
 mod prune;
+pub mod purse_changes;
 pub mod query;
 pub mod run_genesis_request;
+pub mod scheduled_callbacks;
+pub mod simulate_key_management;
+pub mod state_stats;
 pub mod step;
+pub mod system_contract_extension;
 pub mod system_contract_registry;
+pub mod system_entity_registry;
 mod transfer;
 pub mod upgrade;
+pub mod validate_block_payload;
+pub mod view;
 
 use std::{
     cell::RefCell,
     collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
     rc::Rc,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use num::Zero;
@@ -33,72 +64,145 @@ use num_rational::Ratio;
 use once_cell::sync::Lazy;
 use tracing::{debug, error, trace, warn};
 
-use casper_hashing::Digest;
+use casper_hashing::{Digest, TrieMultiProof};
 use casper_types::{
-    account::{Account, AccountHash},
-    bytesrepr::ToBytes,
+    account::{
+        check_approvals, Account, AccountHash, ActionType, ApprovalsFailure, ARG_APPROVALS,
+        ARG_DEPLOY_HASH,
+    },
+    bytesrepr::{self, ToBytes},
     contracts::NamedKeys,
     system::{
         auction::{
-            EraValidators, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
+            Bids, DelegationRateChanges, EraValidators, Reservation, Reservations,
+            SeigniorageRecipientsSnapshot, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
             ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
+            DELEGATION_RATE_CHANGES_KEY, ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY,
             LOCKED_FUNDS_PERIOD_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY,
             VALIDATOR_SLOTS_KEY,
         },
-        handle_payment::{self, ACCUMULATION_PURSE_KEY},
-        mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
-        AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
+        handle_payment::{self, ACCUMULATION_PURSE_KEY, FEE_HANDLING_KEY, REFUND_HANDLING_KEY},
+        mint::{self, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_BURNED_KEY, TOTAL_SUPPLY_KEY},
+        standard_payment, AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
     },
-    AccessRights, ApiError, BlockTime, CLValue, ContractHash, DeployHash, DeployInfo, Gas, Key,
-    KeyTag, Motes, Phase, ProtocolVersion, PublicKey, RuntimeArgs, StoredValue, URef, U512,
+    AccessRights, ApiError, BlockTime, CLValue, ContractHash, DeployHash, DeployInfo, EraId, Gas,
+    Key, KeyTag, Motes, Phase, ProtocolVersion, PublicKey, RuntimeArgs, StoredValue, Tagged, URef,
+    U512,
 };
 
 pub use self::{
-    balance::{BalanceRequest, BalanceResult},
+    balance::{
+        BalanceIdentifier, BalanceRequest, BalanceResult, BalancesRequest, BalancesResult,
+        IdentifiedBalance, IdentifiedBalanceRequest,
+    },
     chainspec_registry::ChainspecRegistry,
     checksum_registry::ChecksumRegistry,
+    custom_payment::CustomPaymentTerms,
     deploy_item::DeployItem,
     engine_config::{
-        EngineConfig, EngineConfigBuilder, DEFAULT_MAX_QUERY_DEPTH,
-        DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT,
+        EngineConfig, EngineConfigBuilder, EngineConfigDelta, EngineConfigDeltaError,
+        DEFAULT_MAX_QUERY_DEPTH, DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT,
     },
+    era_summary_migration::{EraSummaryStatus, EraSummaryStatusResult},
     era_validators::{GetEraValidatorsError, GetEraValidatorsRequest},
     error::Error,
     executable_deploy_item::{ExecutableDeployItem, ExecutableDeployItemIdentifier},
     execute_request::ExecuteRequest,
     execution::Error as ExecError,
     execution_result::{ExecutionResult, ForcedTransferResult},
-    genesis::{ExecConfig, GenesisAccount, GenesisConfig, GenesisSuccess},
+    execution_stats::ExecutionStats,
+    execution_warning::{ExecutionWarning, EXCESSIVE_PAYMENT_FACTOR},
+    forecast_validators::{
+        ForecastValidatorsRequest, ForecastValidatorsResult, ValidatorsForecast,
+    },
+    gc::{GcConfig, GcResult, RetentionPolicy, StateRootRecord},
+    genesis::{ExecConfig, GenesisAccount, GenesisConfig, GenesisPlan, GenesisSuccess},
     get_bids::{GetBidsRequest, GetBidsResult},
+    get_block_events::{GetBlockEventsRequest, GetBlockEventsResult},
+    get_block_gas_utilization::{
+        BlockGasUtilization, GetBlockGasUtilizationRequest, GetBlockGasUtilizationResult,
+    },
+    get_contract_wasm::{GetContractWasmRequest, GetContractWasmResult, StoredContractIdentifier},
+    get_delegation_rate::{
+        GetDelegationRateRequest, GetDelegationRateResult, ValidatorDelegationRate,
+    },
+    get_dictionary_item::{GetDictionaryItemRequest, GetDictionaryItemResult},
+    get_era_info::{CurrentEraInfo, GetEraInfoRequest, GetEraInfoResult},
+    get_keys::{GetKeysRequest, GetKeysResult},
+    get_multi_proof::GetMultiProofResult,
+    get_payment_info::{GetPaymentInfoRequest, GetPaymentInfoResult, PaymentInfo},
+    get_purse_owner::{GetPurseOwnerRequest, GetPurseOwnerResult},
+    get_reservations::{GetReservationsRequest, GetReservationsResult},
+    get_supply::{GetSupplyRequest, GetSupplyResult, SupplyBreakdown},
+    invariants::{CheckInvariantsRequest, CheckInvariantsResult, InvariantViolation},
+    maintenance::{MaintenanceContext, MaintenanceTask},
+    prove_absence::ProveAbsenceResult,
     prune::{PruneConfig, PruneResult},
+    purse_changes::PurseChanges,
     query::{QueryRequest, QueryResult},
     run_genesis_request::RunGenesisRequest,
-    step::{RewardItem, SlashItem, StepError, StepRequest, StepSuccess},
+    scheduled_callbacks::{ScheduledCallback, ScheduledCallbackResult},
+    simulate_key_management::{
+        KeyManagementOp, KeyManagementOpFailure, SimulateKeyManagementRequest,
+        SimulateKeyManagementResult,
+    },
+    state_stats::{KeyTagStats, StateStats, StateStatsRequest, StateStatsResult},
+    step::{
+        RewardItem, SlashItem, StepError, StepReport, StepRequest, StepSuccess,
+        ValidatorWeightChange,
+    },
+    system_contract_extension::{
+        SystemContractExtensionCallRequest, SystemContractExtensionCallResult,
+    },
     system_contract_registry::SystemContractRegistry,
+    system_entity_registry::SystemEntityRegistry,
     transfer::{TransferArgs, TransferRuntimeArgsBuilder, TransferTargetMode},
-    upgrade::{UpgradeConfig, UpgradeSuccess},
+    upgrade::{UpgradeConfig, UpgradeConfigBuilder, UpgradeConfigError, UpgradeSuccess},
+    validate_block_payload::{
+        InvalidDeploy, InvalidDeployReason, ValidateBlockPayloadRequest, ValidateBlockPayloadResult,
+    },
+    view::{ViewHandle, ViewId},
+};
+use self::{
+    engine_config::{FeeHandling, Quirk},
+    invariants::InvariantChecker,
+    transfer::NewTransferTargetMode,
 };
-use self::{engine_config::FeeHandling, transfer::NewTransferTargetMode};
 use crate::{
     core::{
         engine_state::{
             executable_deploy_item::ExecutionKind,
             execution_result::{ExecutionResultBuilder, ExecutionResults},
             genesis::GenesisInstaller,
+            maintenance::MaintenanceRegistry,
             upgrade::{ProtocolUpgradeError, SystemUpgrader},
+            view::ViewRegistry,
         },
         execution::{self, DirectSystemContractCall, Executor},
         runtime::RuntimeStack,
-        tracking_copy::{TrackingCopy, TrackingCopyExt},
+        runtime_context::dictionary,
+        tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
+    },
+    shared::{
+        additive_map::AdditiveMap,
+        module_cache::{ModuleCache, ModuleCacheStats},
+        newtypes::CorrelationId,
+        storage_costs::StorageCosts,
+        transform::Transform,
+        wasm_prep::{self, HostFunctionImport, WasmDiagnostic},
     },
-    shared::{additive_map::AdditiveMap, newtypes::CorrelationId, transform::Transform},
     storage::{
         global_state::{
-            lmdb::LmdbGlobalState, scratch::ScratchGlobalState, CommitProvider, StateProvider,
-            StateReader,
+            lmdb::LmdbGlobalState,
+            read_recording::{ReadRecordingGlobalState, ReadSet},
+            scratch::ScratchGlobalState,
+            CommitProvider, StateProvider, StateReader,
         },
         trie::{merkle_proof::TrieMerkleProof, TrieRaw},
-        trie_store::operations::DeleteResult,
+        trie_store::{
+            liveness::{LivenessStats, LivenessViolation},
+            operations::DeleteResult,
+        },
     },
     system::auction,
 };
@@ -125,6 +229,11 @@ pub const WASMLESS_TRANSFER_FIXED_GAS_PRICE: u64 = 1;
 pub struct EngineState<S> {
     config: EngineConfig,
     state: S,
+    views: ViewRegistry,
+    module_cache: Arc<ModuleCache>,
+    maintenance_tasks: MaintenanceRegistry,
+    /// Config changes staged to be applied at the next block boundary.
+    pending_config_delta: Mutex<Option<EngineConfigDelta>>,
 }
 
 impl EngineState<ScratchGlobalState> {
@@ -148,11 +257,82 @@ impl EngineState<LmdbGlobalState> {
         Ok(())
     }
 
+    /// Garbage collects global state according to the retention policy in `gc_config`.
+    ///
+    /// Every trie which is not reachable from a retained state root is removed from the store.
+    /// `on_progress` is periodically called while the collection is running.
+    pub fn gc<F>(
+        &self,
+        correlation_id: CorrelationId,
+        gc_config: GcConfig,
+        on_progress: F,
+    ) -> Result<GcResult, Error>
+    where
+        F: FnMut(gc::GarbageCollectionProgress),
+    {
+        let mut retained_roots = gc_config.retained_roots();
+        if retained_roots.is_empty() {
+            warn!(
+                current_era = %gc_config.current_era(),
+                "retention policy retains no state roots; skipping garbage collection"
+            );
+            return Ok(GcResult::NothingRetained);
+        }
+        for pinned_root in self.views.pinned_state_roots() {
+            if !retained_roots.contains(&pinned_root) {
+                retained_roots.push(pinned_root);
+            }
+        }
+
+        let stats = self
+            .state
+            .collect_garbage(correlation_id, &retained_roots, on_progress)?;
+        Ok(GcResult::Success {
+            retained_roots,
+            stats,
+        })
+    }
+
+    /// Enables reference counting of global state tries, tracking `state_roots` and every state
+    /// root committed from now on.
+    pub fn enable_liveness_tracking(&self, state_roots: &[Digest]) -> Result<(), Error> {
+        self.state
+            .enable_liveness_tracking(state_roots)
+            .map_err(Into::into)
+    }
+
+    /// Returns statistics about the tracked tries, or `None` if liveness tracking is not enabled.
+    pub fn liveness_stats(&self) -> Result<Option<LivenessStats>, Error> {
+        self.state.liveness_stats().map_err(Into::into)
+    }
+
+    /// Verifies the tracked trie reference counts, returning any violations found, or `None` if
+    /// liveness tracking is not enabled.
+    pub fn check_liveness_invariants(&self) -> Result<Option<Vec<LivenessViolation>>, Error> {
+        self.state.check_liveness_invariants().map_err(Into::into)
+    }
+
+    /// Releases a tracked state root, removing the tries which are no longer reachable from any
+    /// other tracked root.
+    ///
+    /// Returns the number of removed tries, or `None` if the root is not tracked.  Fails if the
+    /// root is pinned by a live view.
+    pub fn release_state_root(&self, state_root_hash: Digest) -> Result<Option<usize>, Error> {
+        if self.views.pinned_state_roots().contains(&state_root_hash) {
+            return Err(Error::StateRootPinned(state_root_hash));
+        }
+        self.state.release_root(state_root_hash).map_err(Into::into)
+    }
+
     /// Provide a local cached-only version of engine-state.
     pub fn get_scratch_engine_state(&self) -> EngineState<ScratchGlobalState> {
         EngineState {
             config: self.config.clone(),
             state: self.state.create_scratch(),
+            views: ViewRegistry::default(),
+            module_cache: Arc::clone(&self.module_cache),
+            maintenance_tasks: self.maintenance_tasks.clone(),
+            pending_config_delta: Mutex::new(None),
         }
     }
 
@@ -176,7 +356,15 @@ where
 {
     /// Creates new engine state.
     pub fn new(state: S, config: EngineConfig) -> EngineState<S> {
-        EngineState { config, state }
+        let module_cache = Arc::new(ModuleCache::new(config.module_cache_size()));
+        EngineState {
+            config,
+            state,
+            views: ViewRegistry::default(),
+            module_cache,
+            maintenance_tasks: MaintenanceRegistry::default(),
+            pending_config_delta: Mutex::new(None),
+        }
     }
 
     /// Returns engine config.
@@ -184,11 +372,84 @@ where
         &self.config
     }
 
+    /// Returns the statistics of the cache of preprocessed session and payment modules.
+    pub fn module_cache_stats(&self) -> ModuleCacheStats {
+        self.module_cache.stats()
+    }
+
+    /// Drops all modules from the cache of preprocessed session and payment modules, returning
+    /// how many there were.
+    pub fn flush_module_cache(&self) -> usize {
+        self.module_cache.flush()
+    }
+
     /// Updates current engine config with a new instance.
     pub fn update_config(&mut self, new_config: EngineConfig) {
         self.config = new_config
     }
 
+    /// Registers a maintenance task to be run by every subsequent step.
+    ///
+    /// Fails if a task with the same name is already registered.
+    pub fn register_maintenance_task(&mut self, task: MaintenanceTask) -> Result<(), Error> {
+        self.maintenance_tasks.register(task)
+    }
+
+    /// Returns the registered maintenance tasks, in the order they are run by a step.
+    pub fn maintenance_tasks(&self) -> &[MaintenanceTask] {
+        self.maintenance_tasks.tasks()
+    }
+
+    /// Stages a change to the engine config, to be applied by the next call to
+    /// [`EngineState::apply_pending_config_delta`].
+    ///
+    /// The delta is validated against the config as it will be once any previously staged deltas
+    /// are applied, and merged with them.  Options affecting execution results are rejected
+    /// unless `during_upgrade` is `true`.
+    pub fn stage_config_delta(
+        &self,
+        delta: EngineConfigDelta,
+        during_upgrade: bool,
+    ) -> Result<(), EngineConfigDeltaError> {
+        let mut pending_config_delta = self
+            .pending_config_delta
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let config = match pending_config_delta.as_ref() {
+            Some(pending) => pending.apply_to(&self.config),
+            None => self.config.clone(),
+        };
+        delta.validate(&config, during_upgrade)?;
+        let merged = match pending_config_delta.take() {
+            Some(pending) => pending.merge(delta),
+            None => delta,
+        };
+        *pending_config_delta = Some(merged);
+        Ok(())
+    }
+
+    /// Applies the config changes staged since the last call, returning `true` if there were any.
+    ///
+    /// This should be called at a block boundary, so that a block is always executed with a
+    /// single config.  The cache of preprocessed modules is replaced if its size changed.
+    pub fn apply_pending_config_delta(&mut self) -> bool {
+        let pending_config_delta = self
+            .pending_config_delta
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        let delta = match pending_config_delta {
+            Some(delta) if !delta.is_empty() => delta,
+            _ => return false,
+        };
+        let new_config = delta.apply_to(&self.config);
+        if new_config.module_cache_size() != self.config.module_cache_size() {
+            self.module_cache = Arc::new(ModuleCache::new(new_config.module_cache_size()));
+        }
+        self.config = new_config;
+        true
+    }
+
     /// Commits genesis process.
     ///
     /// This process is run only once per network to initiate the system. By definition users are
@@ -248,6 +509,40 @@ where
         })
     }
 
+    /// Plans genesis without committing it.
+    ///
+    /// Runs the same system installation as [`EngineState::commit_genesis`] given the same
+    /// arguments, and returns the deterministic hashes the system contracts would be installed
+    /// under along with the initial era validators.  Global state is left untouched.
+    pub fn plan_genesis(
+        &self,
+        correlation_id: CorrelationId,
+        genesis_config_hash: Digest,
+        protocol_version: ProtocolVersion,
+        ee_config: &ExecConfig,
+        chainspec_registry: ChainspecRegistry,
+    ) -> Result<GenesisPlan, Error> {
+        let initial_root_hash = self.state.empty_root();
+
+        let tracking_copy = match self.tracking_copy(initial_root_hash) {
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+            Ok(None) => panic!("state has not been initialized properly"),
+            Err(error) => return Err(error),
+        };
+
+        let mut genesis_installer: GenesisInstaller<S> = GenesisInstaller::new(
+            genesis_config_hash,
+            protocol_version,
+            correlation_id,
+            ee_config.clone(),
+            tracking_copy,
+        );
+
+        genesis_installer.install(chainspec_registry)?;
+
+        Ok(genesis_installer.plan()?)
+    }
+
     /// Commits upgrade.
     ///
     /// This process applies changes to the global state.
@@ -283,11 +578,11 @@ where
             return Err(Error::InvalidProtocolVersion(new_protocol_version));
         }
 
-        let registry = if let Ok(registry) = tracking_copy
+        let (registry, registry_exists) = if let Ok(registry) = tracking_copy
             .borrow_mut()
             .get_system_contracts(correlation_id)
         {
-            registry
+            (registry, true)
         } else {
             // Check the upgrade config for the registry
             let upgrade_registry = upgrade_config
@@ -299,11 +594,14 @@ where
                 })?
                 .to_owned();
             if let StoredValue::CLValue(cl_registry) = upgrade_registry {
-                CLValue::into_t::<SystemContractRegistry>(cl_registry).map_err(|error| {
-                    let error_msg = format!("Conversion to system registry failed: {:?}", error);
-                    error!("{}", error_msg);
-                    Error::Bytesrepr(error_msg)
-                })?
+                let registry =
+                    SystemEntityRegistry::from_cl_value(cl_registry).map_err(|error| {
+                        let error_msg =
+                            format!("Conversion to system registry failed: {:?}", error);
+                        error!("{}", error_msg);
+                        Error::Bytesrepr(error_msg)
+                    })?;
+                (SystemContractRegistry::from(registry), false)
             } else {
                 error!("Failed to create registry as StoreValue in upgrade config is not CLValue");
                 return Err(Error::ProtocolUpgrade(
@@ -357,6 +655,14 @@ where
             )
             .map_err(Error::ProtocolUpgrade)?;
 
+        system_upgrader
+            .create_total_burned_if_required(correlation_id, mint_hash)
+            .map_err(Error::ProtocolUpgrade)?;
+
+        system_upgrader
+            .record_payment_handling(correlation_id, handle_payment_hash, &self.config)
+            .map_err(Error::ProtocolUpgrade)?;
+
         system_upgrader
             .refresh_system_contracts(
                 correlation_id,
@@ -431,10 +737,89 @@ where
                 .write(locked_funds_period_key, value);
         }
 
+        // Balances, the total supply and an existing registry are only ever overwritten on
+        // purpose.
+        let total_supply_key = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, *mint_hash)?
+            .named_keys()
+            .get(TOTAL_SUPPLY_KEY)
+            .map(|key| key.normalize());
+        for key in upgrade_config.global_state_update().keys() {
+            let is_protected = match key {
+                Key::Balance(_) => true,
+                Key::SystemContractRegistry => registry_exists,
+                _ => Some(key.normalize()) == total_supply_key,
+            };
+            if !is_protected {
+                continue;
+            }
+            if !upgrade_config.allow_dangerous_overrides() {
+                error!(%key, "global state update overwrites protected key");
+                return Err(Error::ProtocolUpgrade(
+                    ProtocolUpgradeError::DangerousOverride(*key),
+                ));
+            }
+            warn!(%key, "applying dangerous override from global state update");
+        }
+
         // apply the accepted modifications to global state.
         for (key, value) in upgrade_config.global_state_update() {
             tracking_copy.borrow_mut().write(*key, value.clone());
         }
+
+        // Extensions are registered once the global state update is applied, so the update can
+        // install the contracts being registered.
+        if !upgrade_config.system_contract_extensions().is_empty() {
+            let mut registry = tracking_copy
+                .borrow_mut()
+                .get_system_entity_registry(correlation_id)?;
+            for (contract_name, contract_hash) in upgrade_config.system_contract_extensions() {
+                if system_contract_registry::is_core_system_contract(contract_name) {
+                    error!(%contract_name, "system contract extension uses a reserved name");
+                    return Err(Error::ProtocolUpgrade(
+                        ProtocolUpgradeError::ReservedSystemContractName(contract_name.clone()),
+                    ));
+                }
+                if tracking_copy
+                    .borrow_mut()
+                    .get_contract(correlation_id, *contract_hash)
+                    .is_err()
+                {
+                    error!(%contract_name, "system contract extension refers to a missing contract");
+                    return Err(Error::ProtocolUpgrade(
+                        ProtocolUpgradeError::MissingSystemContractExtension(contract_name.clone()),
+                    ));
+                }
+                debug!(%contract_name, %contract_hash, "registering system contract extension");
+                registry.insert(contract_name.clone(), contract_hash.value());
+            }
+            let cl_registry =
+                CLValue::from_t(registry).map_err(|error| Error::Bytesrepr(error.to_string()))?;
+            tracking_copy.borrow_mut().write(
+                Key::SystemContractRegistry,
+                StoredValue::CLValue(cl_registry),
+            );
+        }
+
+        // A registry still stored in the legacy format is migrated to a system entity registry.
+        let maybe_registry = tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &Key::SystemContractRegistry)
+            .map_err(Into::into)?;
+        if let Some(StoredValue::CLValue(cl_registry)) = maybe_registry {
+            if system_entity_registry::is_legacy_registry(&cl_registry) {
+                debug!("migrating system contract registry to system entity registry");
+                let registry = SystemEntityRegistry::from_cl_value(cl_registry)
+                    .map_err(|error| Error::Bytesrepr(error.to_string()))?;
+                let cl_registry = CLValue::from_t(registry)
+                    .map_err(|error| Error::Bytesrepr(error.to_string()))?;
+                tracking_copy.borrow_mut().write(
+                    Key::SystemContractRegistry,
+                    StoredValue::CLValue(cl_registry),
+                );
+            }
+        }
         // We insert the new unbonding delay once the purses to be paid out have been transformed
         // based on the previous unbonding delay.
         if let Some(new_unbonding_delay) = upgrade_config.new_unbonding_delay() {
@@ -504,6 +889,194 @@ where
         }
     }
 
+    /// Prunes the `Key::DeployInfo` records of the given deploys from global state, typically once
+    /// their replay protection window has passed.
+    ///
+    /// Deploys without a record under `pre_state_hash` are skipped, so a deploy hash may be passed
+    /// again after its record has already been pruned.
+    pub fn commit_prune_deploy_infos(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Digest,
+        deploy_hashes: &[DeployHash],
+    ) -> Result<PruneResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(PruneResult::RootNotFound),
+        };
+
+        let mut keys_to_prune = Vec::with_capacity(deploy_hashes.len());
+        for deploy_hash in deploy_hashes.iter().collect::<BTreeSet<_>>() {
+            let key = Key::DeployInfo(*deploy_hash);
+            if tracking_copy
+                .get(correlation_id, &key)
+                .map_err(Into::into)?
+                .is_some()
+            {
+                keys_to_prune.push(key);
+            }
+        }
+
+        self.commit_prune(
+            correlation_id,
+            PruneConfig::new(pre_state_hash, keys_to_prune),
+        )
+    }
+
+    /// Prunes from global state the items of namespace generations retired by contracts clearing
+    /// their namespaces.
+    ///
+    /// At most `max_keys` records are pruned, so the items of a namespace with many records are
+    /// pruned over several calls.  A generation's retirement marker is pruned along with its last
+    /// items.
+    pub fn commit_prune_namespaces(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Digest,
+        max_keys: usize,
+    ) -> Result<PruneResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(PruneResult::RootNotFound),
+        };
+
+        let retired_namespaces = tracking_copy
+            .get_keys_with_prefix(correlation_id, &Key::retired_namespaces_prefix())
+            .map_err(Into::into)?;
+
+        let mut keys_to_prune = Vec::new();
+        for retired_namespace in retired_namespaces {
+            let items_prefix = match retired_namespace.as_retired_namespace_prefix() {
+                Some(items_prefix) => items_prefix,
+                None => continue,
+            };
+            let budget = max_keys.saturating_sub(keys_to_prune.len());
+            if budget == 0 {
+                break;
+            }
+            let items = tracking_copy
+                .get_keys_with_prefix(correlation_id, &items_prefix)
+                .map_err(Into::into)?;
+            let is_exhausted = items.len() < budget;
+            keys_to_prune.extend(items.into_iter().take(budget));
+            if is_exhausted {
+                keys_to_prune.push(retired_namespace);
+            }
+        }
+
+        self.commit_prune(
+            correlation_id,
+            PruneConfig::new(pre_state_hash, keys_to_prune),
+        )
+    }
+
+    /// Reports the progress under `state_root_hash` of the move of era summaries from the legacy
+    /// `Key::EraInfo` records to `Key::EraSummary`.
+    pub fn era_summary_status(
+        &self,
+        correlation_id: CorrelationId,
+        state_root_hash: Digest,
+    ) -> Result<EraSummaryStatusResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(state_root_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(EraSummaryStatusResult::RootNotFound),
+        };
+
+        let status = self.get_era_summary_status(correlation_id, &mut tracking_copy)?;
+        Ok(EraSummaryStatusResult::Success { status })
+    }
+
+    /// Moves era summaries from the legacy `Key::EraInfo` records to `Key::EraSummary`, pruning at
+    /// most `max_keys` legacy records.
+    ///
+    /// If no record is held under `Key::EraSummary`, the latest legacy record is copied there
+    /// first.  Legacy records are then pruned oldest first.  Once the move is complete the state
+    /// root is left unchanged, so the move can be driven in batches, and retried, until
+    /// [`EngineState::era_summary_status`] reports it complete.
+    pub fn commit_era_summary_migration(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Digest,
+        max_keys: usize,
+    ) -> Result<PruneResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(PruneResult::RootNotFound),
+        };
+
+        let status = self.get_era_summary_status(correlation_id, &mut tracking_copy)?;
+
+        let mut state_hash = pre_state_hash;
+        if let (false, Some(latest_era_id)) =
+            (status.has_era_summary(), status.legacy_era_ids().last())
+        {
+            let latest_era_info = tracking_copy
+                .get(correlation_id, &Key::EraInfo(*latest_era_id))
+                .map_err(Into::into)?;
+            if let Some(latest_era_info) = latest_era_info {
+                tracking_copy.write(Key::EraSummary, latest_era_info);
+                state_hash = self
+                    .state
+                    .commit(
+                        correlation_id,
+                        pre_state_hash,
+                        tracking_copy.effect().transforms,
+                    )
+                    .map_err(Into::into)?;
+            }
+        }
+
+        let keys_to_prune = status
+            .legacy_era_ids()
+            .iter()
+            .take(max_keys)
+            .copied()
+            .map(Key::EraInfo)
+            .collect();
+        self.commit_prune(correlation_id, PruneConfig::new(state_hash, keys_to_prune))
+    }
+
+    fn get_era_summary_status(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+    ) -> Result<EraSummaryStatus, Error> {
+        let mut legacy_era_ids: Vec<EraId> = tracking_copy
+            .get_keys(correlation_id, &KeyTag::EraInfo)
+            .map_err(Into::into)?
+            .into_iter()
+            .filter_map(|key| match key {
+                Key::EraInfo(era_id) => Some(era_id),
+                _ => None,
+            })
+            .collect();
+        legacy_era_ids.sort();
+
+        let era_summary = tracking_copy
+            .get(correlation_id, &Key::EraSummary)
+            .map_err(Into::into)?;
+
+        let mapped_era_id = match (&era_summary, legacy_era_ids.last()) {
+            (Some(era_summary), Some(latest_era_id)) => {
+                let latest_era_info = tracking_copy
+                    .get(correlation_id, &Key::EraInfo(*latest_era_id))
+                    .map_err(Into::into)?;
+                if latest_era_info.as_ref() == Some(era_summary) {
+                    Some(*latest_era_id)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        Ok(EraSummaryStatus::new(
+            era_summary.is_some(),
+            legacy_era_ids,
+            mapped_era_id,
+        ))
+    }
+
     /// Creates a new tracking copy instance.
     pub fn tracking_copy(&self, hash: Digest) -> Result<Option<TrackingCopy<S::Reader>>, Error> {
         match self.state.checkout(hash).map_err(Into::into)? {
@@ -529,10 +1102,15 @@ where
 
         let tracking_copy = tracking_copy.borrow();
 
+        let max_query_depth = match query_request.max_query_depth() {
+            Some(max_query_depth) => max_query_depth.min(self.config().max_query_depth),
+            None => self.config().max_query_depth,
+        };
+
         Ok(tracking_copy
-            .query(
+            .query_with_max_depth(
                 correlation_id,
-                self.config(),
+                max_query_depth,
                 query_request.key(),
                 query_request.path(),
             )
@@ -540,110 +1118,636 @@ where
             .into())
     }
 
-    /// Runs a deploy execution request.
-    ///
-    /// For each deploy stored in the request it will execute it.
-    ///
-    /// Currently a special shortcut is taken to distinguish a native transfer, from a deploy.
+    /// Gets an item of a dictionary, identified by the dictionary's seed URef and the item's key,
+    /// along with the Merkle proof of its stored value.
     ///
-    /// Return execution results which contains results from each deploy ran.
-    pub fn run_execute(
+    /// The global state key of the item is derived as it is by the dictionary host functions.
+    pub fn get_dictionary_item(
         &self,
         correlation_id: CorrelationId,
-        mut exec_request: ExecuteRequest,
-    ) -> Result<ExecutionResults, Error> {
-        let executor = Executor::new(self.config().clone());
+        get_dictionary_item_request: GetDictionaryItemRequest,
+    ) -> Result<GetDictionaryItemResult, Error> {
+        let tracking_copy = match self.tracking_copy(get_dictionary_item_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetDictionaryItemResult::RootNotFound),
+        };
 
-        let deploys = exec_request.take_deploys();
-        let mut results = ExecutionResults::with_capacity(deploys.len());
+        let dictionary_key = get_dictionary_item_request.dictionary_key();
+        let proof = match tracking_copy
+            .reader()
+            .read_with_proof(correlation_id, &dictionary_key)
+            .map_err(Into::into)?
+        {
+            Some(proof) => proof,
+            None => return Ok(GetDictionaryItemResult::ItemNotFound { dictionary_key }),
+        };
 
-        for deploy_item in deploys {
-            let result = match deploy_item.session {
-                ExecutableDeployItem::Transfer { .. } => self.transfer(
-                    correlation_id,
-                    &executor,
-                    exec_request.protocol_version,
-                    exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
-                    deploy_item,
-                    exec_request.proposer.clone(),
-                ),
-                _ => self.deploy(
-                    correlation_id,
-                    &executor,
-                    exec_request.protocol_version,
-                    exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
-                    deploy_item,
-                    exec_request.proposer.clone(),
-                ),
-            };
-            match result {
-                Ok(result) => results.push_back(result),
-                Err(error) => {
-                    return Err(error);
-                }
-            };
+        match dictionary::handle_stored_value(dictionary_key, proof.value().clone()) {
+            Ok(value) => Ok(GetDictionaryItemResult::Success {
+                dictionary_key,
+                value: Box::new(value),
+                proof: Box::new(proof),
+            }),
+            Err(error) => Ok(GetDictionaryItemResult::InvalidItem {
+                dictionary_key,
+                error: error.to_string(),
+            }),
         }
-
-        Ok(results)
     }
 
-    fn get_authorized_account(
+    /// Applies a sequence of key management operations to a copy of an account, as the key
+    /// management host functions would, without committing anything.
+    ///
+    /// The result reports the first operation which would fail, or whether the resulting keys can
+    /// still meet the account's own key management threshold, so that sequences which would lock
+    /// the account out can be rejected before being submitted.
+    pub fn simulate_key_management(
         &self,
         correlation_id: CorrelationId,
-        account_hash: AccountHash,
-        authorization_keys: &BTreeSet<AccountHash>,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
-    ) -> Result<Account, Error> {
-        let account: Account = match tracking_copy
-            .borrow_mut()
-            .get_account(correlation_id, account_hash)
-        {
+        simulate_key_management_request: SimulateKeyManagementRequest,
+    ) -> Result<SimulateKeyManagementResult, Error> {
+        let mut tracking_copy =
+            match self.tracking_copy(simulate_key_management_request.state_hash())? {
+                Some(tracking_copy) => tracking_copy,
+                None => return Ok(SimulateKeyManagementResult::RootNotFound),
+            };
+
+        let account_hash = simulate_key_management_request.account_hash();
+        let account = match tracking_copy.get_account(correlation_id, account_hash) {
             Ok(account) => account,
-            Err(_) => {
-                return Err(error::Error::Authorization);
+            Err(execution::Error::KeyNotFound(_)) => {
+                return Ok(SimulateKeyManagementResult::AccountNotFound(account_hash))
             }
+            Err(error) => return Err(error.into()),
         };
 
-        let admin_set = self.config().administrative_accounts();
+        Ok(simulate_key_management::simulate(
+            account,
+            simulate_key_management_request.operations(),
+            self.config.max_associated_keys(),
+        ))
+    }
 
-        if !admin_set.is_empty() && admin_set.intersection(authorization_keys).next().is_some() {
-            // Exit early if there's at least a single signature coming from an admin.
-            return Ok(account);
-        }
+    /// Checks the deploys of a proposed block against global state without executing them.
+    ///
+    /// Each deploy's account must exist and be authorized by its approvals, and the main purse of
+    /// each account must cover the payments of all of its deploys in the block, counting the
+    /// standard payment amount or [`MAX_PAYMENT`], whichever is higher.  Authorization contracts
+    /// aren't called, so a deploy which passes can still fail authorization when executed.
+    pub fn validate_block_payload(
+        &self,
+        correlation_id: CorrelationId,
+        validate_block_payload_request: ValidateBlockPayloadRequest,
+    ) -> Result<ValidateBlockPayloadResult, Error> {
+        let mut tracking_copy =
+            match self.tracking_copy(validate_block_payload_request.state_hash())? {
+                Some(tracking_copy) => tracking_copy,
+                None => return Ok(ValidateBlockPayloadResult::RootNotFound),
+            };
 
-        // Authorize using provided authorization keys
-        if !account.can_authorize(authorization_keys) {
-            return Err(error::Error::Authorization);
-        }
+        let admin_set = self.config.administrative_accounts();
+        let mut deploy_hashes = BTreeSet::new();
+        let mut available_balances: BTreeMap<AccountHash, Motes> = BTreeMap::new();
+        let mut invalid_deploys = vec![];
 
-        // Check total key weight against deploy threshold
-        if !account.can_deploy_with(authorization_keys) {
-            return Err(execution::Error::DeploymentAuthorizationFailure.into());
-        }
+        for deploy_item in validate_block_payload_request.deploy_items() {
+            let deploy_hash = deploy_item.deploy_hash;
+            let mut invalidate = |reason| {
+                invalid_deploys.push(InvalidDeploy {
+                    deploy_hash,
+                    reason,
+                })
+            };
 
-        Ok(account)
-    }
+            if !deploy_hashes.insert(deploy_hash) {
+                invalidate(InvalidDeployReason::Duplicate);
+                continue;
+            }
 
-    /// Get the balance of a passed purse referenced by its [`URef`].
-    pub fn get_purse_balance(
-        &self,
+            let account_hash = deploy_item.address;
+            let account = match tracking_copy.get_account(correlation_id, account_hash) {
+                Ok(account) => account,
+                Err(execution::Error::KeyNotFound(_)) => {
+                    invalidate(InvalidDeployReason::AccountNotFound(account_hash));
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            let authorization_keys = &deploy_item.authorization_keys;
+            if admin_set.intersection(authorization_keys).next().is_none() {
+                if let Err(failure) =
+                    check_approvals(&account, authorization_keys, ActionType::Deployment)
+                {
+                    invalidate(InvalidDeployReason::Authorization(failure));
+                    continue;
+                }
+            }
+
+            let available = match available_balances.get(&account_hash) {
+                Some(available) => *available,
+                None => {
+                    let balance_key = tracking_copy
+                        .get_purse_balance_key(correlation_id, Key::URef(account.main_purse()))?;
+                    tracking_copy.get_purse_balance(correlation_id, balance_key)?
+                }
+            };
+            let standard_payment_amount = if deploy_item.payment.is_standard_payment(Phase::Payment)
+            {
+                deploy_item
+                    .payment
+                    .args()
+                    .get(standard_payment::ARG_AMOUNT)
+                    .and_then(|amount| amount.clone().into_t::<U512>().ok())
+                    .unwrap_or_default()
+            } else {
+                U512::zero()
+            };
+            let required = Motes::new(standard_payment_amount.max(*MAX_PAYMENT));
+            match available.checked_sub(required) {
+                Some(remaining) => {
+                    available_balances.insert(account_hash, remaining);
+                }
+                None => {
+                    available_balances.insert(account_hash, available);
+                    invalidate(InvalidDeployReason::InsufficientBalance {
+                        required,
+                        available,
+                    });
+                }
+            }
+        }
+
+        Ok(ValidateBlockPayloadResult::Success { invalid_deploys })
+    }
+
+    /// Pins a view of global state at `state_root_hash` for `ttl`.
+    ///
+    /// While the view is live its state root is retained by garbage collection.  Returns `None`
+    /// if the state root does not exist.
+    pub fn pin_view(
+        &self,
+        state_root_hash: Digest,
+        ttl: Duration,
+    ) -> Result<Option<ViewHandle>, Error> {
+        if self
+            .state
+            .checkout(state_root_hash)
+            .map_err(Into::into)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        Ok(Some(self.views.pin(state_root_hash, ttl)))
+    }
+
+    /// Extends the lifetime of a pinned view to `ttl` from now.
+    pub fn renew_view(&self, view: &ViewHandle, ttl: Duration) -> Result<(), Error> {
+        if self.views.renew(view, ttl) {
+            Ok(())
+        } else {
+            Err(Error::ViewNotFound(view.id()))
+        }
+    }
+
+    /// Releases a pinned view.  Returns `false` if the view was already released or expired.
+    pub fn release_view(&self, view: &ViewHandle) -> bool {
+        self.views.release(view)
+    }
+
+    /// Returns the state root hashes currently pinned by live views.
+    pub fn pinned_state_roots(&self) -> Vec<Digest> {
+        self.views.pinned_state_roots()
+    }
+
+    /// Executes a query against a pinned view.
+    ///
+    /// Fails with [`Error::ViewNotFound`] if the view was released or has expired.
+    pub fn run_query_in_view(
+        &self,
+        correlation_id: CorrelationId,
+        view: &ViewHandle,
+        key: Key,
+        path: Vec<String>,
+    ) -> Result<QueryResult, Error> {
+        if !self.views.is_live(view) {
+            return Err(Error::ViewNotFound(view.id()));
+        }
+        let query_request = QueryRequest::new(view.state_root_hash(), key, path);
+        self.run_query(correlation_id, query_request)
+    }
+
+    /// Runs a deploy execution request against a pinned view, ignoring the request's parent state
+    /// hash.
+    ///
+    /// Keeping the view live for the duration of the call guarantees its state root isn't
+    /// collected while the deploys execute.  Fails with [`Error::ViewNotFound`] if the view was
+    /// released or has expired.
+    pub fn run_execute_in_view(
+        &self,
+        correlation_id: CorrelationId,
+        view: &ViewHandle,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<ExecutionResults, Error> {
+        if !self.views.is_live(view) {
+            return Err(Error::ViewNotFound(view.id()));
+        }
+        exec_request.parent_state_hash = view.state_root_hash();
+        self.run_execute(correlation_id, exec_request)
+    }
+
+    /// Runs a deploy execution request against a pinned view like
+    /// [`EngineState::run_execute_in_view`], additionally returning the reads the deploys made from
+    /// global state.
+    ///
+    /// The keys read include those found to have no value, and those listed by prefix.  A value a
+    /// deploy reads after writing it itself isn't read from global state, so its key is only in the
+    /// deploy's execution journal.
+    pub fn run_execute_in_view_recording_reads(
+        &self,
+        correlation_id: CorrelationId,
+        view: &ViewHandle,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<(ExecutionResults, ReadSet), Error> {
+        if !self.views.is_live(view) {
+            return Err(Error::ViewNotFound(view.id()));
+        }
+        exec_request.parent_state_hash = view.state_root_hash();
+        let recording_engine_state = self.read_recording();
+        let execution_results = recording_engine_state.run_execute(correlation_id, exec_request)?;
+        Ok((execution_results, recording_engine_state.state.take_reads()))
+    }
+
+    /// Returns an engine state sharing this one's global state, config and module cache, which
+    /// records the keys read from global state.
+    fn read_recording(&self) -> EngineState<ReadRecordingGlobalState<'_, S>> {
+        EngineState {
+            config: self.config.clone(),
+            state: ReadRecordingGlobalState::new(&self.state),
+            views: ViewRegistry::default(),
+            module_cache: Arc::clone(&self.module_cache),
+            maintenance_tasks: self.maintenance_tasks.clone(),
+            pending_config_delta: Mutex::new(None),
+        }
+    }
+
+    /// Runs a deploy execution request.
+    ///
+    /// For each deploy stored in the request it will execute it.
+    ///
+    /// Currently a special shortcut is taken to distinguish a native transfer, from a deploy.
+    ///
+    /// If [`EngineConfig::record_read_keys`] is set, the keys each deploy read from global state
+    /// are recorded in its execution result.
+    ///
+    /// Return execution results which contains results from each deploy ran.
+    pub fn run_execute(
+        &self,
+        correlation_id: CorrelationId,
+        exec_request: ExecuteRequest,
+    ) -> Result<ExecutionResults, Error> {
+        self.run_execute_measured(correlation_id, exec_request, None)
+    }
+
+    /// Runs a deploy execution request like [`EngineState::run_execute`], additionally returning
+    /// the [`ExecutionStats`] of each deploy, in the same order as the execution results.
+    pub fn run_execute_with_stats(
+        &self,
+        correlation_id: CorrelationId,
+        exec_request: ExecuteRequest,
+    ) -> Result<(ExecutionResults, Vec<ExecutionStats>), Error> {
+        let mut execution_stats = Vec::with_capacity(exec_request.deploys.len());
+        let execution_results =
+            self.run_execute_measured(correlation_id, exec_request, Some(&mut execution_stats))?;
+        Ok((execution_results, execution_stats))
+    }
+
+    fn run_execute_measured(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+        mut execution_stats: Option<&mut Vec<ExecutionStats>>,
+    ) -> Result<ExecutionResults, Error> {
+        let config = self.config_at(
+            correlation_id,
+            exec_request.protocol_version,
+            exec_request.parent_state_hash,
+        )?;
+        let executor = Executor::new(config).with_module_cache(Arc::clone(&self.module_cache));
+
+        let deploys = exec_request.take_deploys();
+        let mut results = ExecutionResults::with_capacity(deploys.len());
+
+        for deploy_item in deploys {
+            let start = Instant::now();
+            let result = if self.config().record_read_keys() {
+                let recording_engine_state = self.read_recording();
+                recording_engine_state
+                    .execute_deploy_item(correlation_id, &executor, &exec_request, deploy_item)
+                    .map(|result| {
+                        result.with_read_keys(recording_engine_state.state.take_reads().keys)
+                    })
+            } else {
+                self.execute_deploy_item(correlation_id, &executor, &exec_request, deploy_item)
+            };
+            // Taken regardless, as neither the high-water mark nor the warnings must carry over to
+            // the next deploy.
+            let memory_high_water_mark = executor.take_memory_high_water_mark();
+            let warnings = executor.take_warnings();
+            let result = result.map(|result| result.with_warnings(warnings));
+            if let Some(execution_stats) = execution_stats.as_mut() {
+                let effects_size = result
+                    .as_ref()
+                    .map_or(0, |result| result.effects_size() as u64);
+                execution_stats.push(ExecutionStats::new(
+                    start.elapsed(),
+                    memory_high_water_mark,
+                    effects_size,
+                ));
+            }
+            match result {
+                Ok(result) => results.push_back(result),
+                Err(error) => {
+                    return Err(error);
+                }
+            };
+        }
+
+        Ok(results)
+    }
+
+    /// Returns the engine config for executing against the state root `state_hash` under
+    /// `protocol_version`.
+    ///
+    /// Administrative accounts stored in the administrator registry take precedence over the
+    /// configured ones.
+    fn config_at(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        state_hash: Digest,
+    ) -> Result<EngineConfig, Error> {
+        let mut config = self.config().for_protocol_version(protocol_version);
+        // A missing state root is reported by the caller's own lookup of it.
+        if let Some(mut tracking_copy) = self.tracking_copy(state_hash)? {
+            match tracking_copy
+                .get(correlation_id, &Key::AdministratorRegistry)
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => {
+                    config.administrative_accounts = cl_value.into_t()?;
+                }
+                Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => (),
+            }
+        }
+        Ok(config)
+    }
+
+    fn execute_deploy_item(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        exec_request: &ExecuteRequest,
+        deploy_item: DeployItem,
+    ) -> Result<ExecutionResult, Error> {
+        match deploy_item.session {
+            ExecutableDeployItem::Transfer { .. } => self.transfer(
+                correlation_id,
+                executor,
+                exec_request.protocol_version,
+                exec_request.parent_state_hash,
+                BlockTime::new(exec_request.block_time),
+                deploy_item,
+                exec_request.proposer.clone(),
+            ),
+            _ => self.deploy(
+                correlation_id,
+                executor,
+                exec_request.protocol_version,
+                exec_request.parent_state_hash,
+                BlockTime::new(exec_request.block_time),
+                deploy_item,
+                exec_request.proposer.clone(),
+            ),
+        }
+    }
+
+    fn get_authorized_account(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        account_hash: AccountHash,
+        authorization_keys: &BTreeSet<AccountHash>,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+    ) -> Result<Account, Error> {
+        let account: Account = match tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, account_hash)
+        {
+            Ok(account) => account,
+            Err(_) => {
+                return Err(error::Error::Authorization);
+            }
+        };
+
+        let admin_set = executor.config().administrative_accounts();
+
+        if !admin_set.is_empty() && admin_set.intersection(authorization_keys).next().is_some() {
+            // Exit early if there's at least a single signature coming from an admin.
+            return Ok(account);
+        }
+
+        match check_approvals(&account, authorization_keys, ActionType::Deployment) {
+            Ok(_) => Ok(account),
+            // Total key weight is below the deploy threshold
+            Err(ApprovalsFailure::InsufficientWeight { .. }) => {
+                Err(execution::Error::DeploymentAuthorizationFailure.into())
+            }
+            Err(_) => Err(error::Error::Authorization),
+        }
+    }
+
+    /// Consults the authorization contract of `account`, if it registered one, on whether the
+    /// deploy may be executed.
+    ///
+    /// The contract is called with the engine's authorization gas limit and its effects are
+    /// discarded.  Deploys approved by an administrator aren't subject to it.
+    #[allow(clippy::too_many_arguments)]
+    fn check_authorization_contract(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        blocktime: BlockTime,
+        deploy_hash: DeployHash,
+        account: &Account,
+        authorization_keys: &BTreeSet<AccountHash>,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+    ) -> Result<(), Error> {
+        let contract_hash = match account.authorization_contract() {
+            Some(contract_hash) => contract_hash,
+            None => return Ok(()),
+        };
+
+        let admin_set = executor.config().administrative_accounts();
+        if admin_set.intersection(authorization_keys).next().is_some() {
+            return Ok(());
+        }
+
+        let runtime_args = RuntimeArgs::try_new(|args| {
+            args.insert(ARG_DEPLOY_HASH, deploy_hash.value())?;
+            args.insert(ARG_APPROVALS, authorization_keys.clone())?;
+            Ok(())
+        })
+        .map_err(ExecError::from)?;
+        let stack = self.get_new_account_call_stack(account.account_hash());
+        let gas_limit = Gas::from(executor.config().authorization_gas_limit());
+
+        let tracking_copy = tracking_copy.borrow();
+        let authorization_tracking_copy = Rc::new(RefCell::new(tracking_copy.fork()));
+
+        match executor.call_authorization_contract(
+            contract_hash,
+            runtime_args,
+            account,
+            authorization_keys.clone(),
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            authorization_tracking_copy,
+            stack,
+        ) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(Error::Authorization),
+            Err(error) => {
+                debug!(%error, %contract_hash, "authorization contract failed");
+                Err(Error::Authorization)
+            }
+        }
+    }
+
+    /// Get the balance of a passed purse referenced by its [`URef`].
+    pub fn get_purse_balance(
+        &self,
         correlation_id: CorrelationId,
         state_hash: Digest,
         purse_uref: URef,
     ) -> Result<BalanceResult, Error> {
-        let tracking_copy = match self.tracking_copy(state_hash)? {
+        self.balance(correlation_id, BalanceRequest::new(state_hash, purse_uref))
+    }
+
+    /// Returns the balance of the purse referenced by the request.
+    pub fn balance(
+        &self,
+        correlation_id: CorrelationId,
+        balance_request: BalanceRequest,
+    ) -> Result<BalanceResult, Error> {
+        self.identified_balance(correlation_id, balance_request.into())
+    }
+
+    /// Returns the balance of the purse identified by the request.
+    ///
+    /// All identifiers resolve to a purse first, so that the same purse queried through different
+    /// identifiers yields the same balance and proof.
+    pub fn identified_balance(
+        &self,
+        correlation_id: CorrelationId,
+        balance_request: IdentifiedBalanceRequest,
+    ) -> Result<BalanceResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(balance_request.state_hash())? {
             Some(tracking_copy) => tracking_copy,
             None => return Ok(BalanceResult::RootNotFound),
         };
+        let (motes, proof) = self.balance_with_proof(
+            correlation_id,
+            &mut tracking_copy,
+            balance_request.identifier(),
+        )?;
+        Ok(BalanceResult::Success { motes, proof })
+    }
+
+    /// Returns the balances of all the purses identified by the request.
+    ///
+    /// All balances are read through a single checkout of the state root, and each is resolved
+    /// exactly as by [`EngineState::balance`].
+    pub fn get_balances(
+        &self,
+        correlation_id: CorrelationId,
+        balances_request: BalancesRequest,
+    ) -> Result<BalancesResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(balances_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(BalancesResult::RootNotFound),
+        };
+        let balances = balances_request
+            .take_identifiers()
+            .into_iter()
+            .map(|identifier| {
+                let balance =
+                    self.balance_with_proof(correlation_id, &mut tracking_copy, &identifier);
+                IdentifiedBalance::new(identifier, balance)
+            })
+            .collect();
+        Ok(BalancesResult::Success { balances })
+    }
+
+    /// Resolves `identifier` to a purse and reads its balance along with the proof.
+    fn balance_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+        identifier: &BalanceIdentifier,
+    ) -> Result<(U512, Box<TrieMerkleProof<Key, StoredValue>>), Error> {
+        let purse_uref = match identifier {
+            BalanceIdentifier::Purse(purse_uref) => *purse_uref,
+            BalanceIdentifier::Account(account_hash) => tracking_copy
+                .get_account(correlation_id, *account_hash)?
+                .main_purse(),
+            BalanceIdentifier::PublicKey(public_key) => tracking_copy
+                .get_account(correlation_id, public_key.to_account_hash())?
+                .main_purse(),
+            BalanceIdentifier::Payment => self.get_handle_payment_purse(
+                correlation_id,
+                tracking_copy,
+                handle_payment::PAYMENT_PURSE_KEY,
+            )?,
+            BalanceIdentifier::Accumulate => self.get_handle_payment_purse(
+                correlation_id,
+                tracking_copy,
+                ACCUMULATION_PURSE_KEY,
+            )?,
+        };
         let purse_balance_key =
             tracking_copy.get_purse_balance_key(correlation_id, purse_uref.into())?;
         let (balance, proof) =
             tracking_copy.get_purse_balance_with_proof(correlation_id, purse_balance_key)?;
-        let proof = Box::new(proof);
-        let motes = balance.value();
-        Ok(BalanceResult::Success { motes, proof })
+        Ok((balance.value(), Box::new(proof)))
+    }
+
+    /// Returns the purse stored under `purse_name` in the named keys of the handle payment
+    /// contract.
+    fn get_handle_payment_purse(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+        purse_name: &str,
+    ) -> Result<URef, Error> {
+        let handle_payment_hash = *tracking_copy
+            .get_system_contracts(correlation_id)?
+            .get(HANDLE_PAYMENT)
+            .ok_or_else(|| Error::MissingSystemContractHash(HANDLE_PAYMENT.to_string()))?;
+        let handle_payment_contract =
+            tracking_copy.get_contract(correlation_id, handle_payment_hash)?;
+        match handle_payment_contract.named_keys().get(purse_name) {
+            Some(Key::URef(purse_uref)) => Ok(*purse_uref),
+            Some(_) => Err(Error::InvalidKeyVariant),
+            None => Err(Error::Exec(ExecError::NamedKeyNotFound(
+                purse_name.to_string(),
+            ))),
+        }
     }
 
     /// Executes a native transfer.
@@ -675,6 +1779,7 @@ where
 
         let account = match self.get_authorized_account(
             correlation_id,
+            executor,
             account_hash,
             &authorization_keys,
             Rc::clone(&tracking_copy),
@@ -683,6 +1788,19 @@ where
             Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
         };
 
+        if let Err(error) = self.check_authorization_contract(
+            correlation_id,
+            executor,
+            protocol_version,
+            blocktime,
+            deploy_item.deploy_hash,
+            &account,
+            &authorization_keys,
+            Rc::clone(&tracking_copy),
+        ) {
+            return Ok(ExecutionResult::precondition_failure(error));
+        }
+
         let system_account = match tracking_copy
             .borrow_mut()
             .read_account(correlation_id, PublicKey::System.to_account_hash())
@@ -718,7 +1836,7 @@ where
         let gas_limit = Gas::new(U512::from(std::u64::MAX));
 
         let wasmless_transfer_gas_cost = Gas::new(U512::from(
-            self.config().system_config().wasmless_transfer_cost(),
+            executor.config().system_config().wasmless_transfer_cost(),
         ));
 
         let wasmless_transfer_motes = match Motes::from_gas(
@@ -792,20 +1910,27 @@ where
         // Any error that occurs in logic below this point would result in a charge for user error.
 
         let mut runtime_args_builder =
-            TransferRuntimeArgsBuilder::new(deploy_item.session.args().clone());
+            TransferRuntimeArgsBuilder::new(deploy_item.session.args().clone())
+                .with_named_purse_targets(
+                    !self
+                        .config
+                        .has_quirk_at(protocol_version, Quirk::NoNamedPurseTransferTargets),
+                );
 
-        let transfer_target_mode = match runtime_args_builder
-            .resolve_transfer_target_mode(correlation_id, Rc::clone(&tracking_copy))
-        {
+        let transfer_target_mode = match runtime_args_builder.resolve_transfer_target_mode(
+            &account,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+        ) {
             Ok(transfer_target_mode) => transfer_target_mode,
             Err(error) => return Ok(make_charged_execution_failure(error)),
         };
 
-        // At this point we know target refers to either a purse on an existing account or an
-        // account which has to be created.
+        // At this point we know target refers to either a purse on an existing account, a purse of
+        // the initiator, or an account which has to be created.
 
-        if !self.config.allow_unrestricted_transfers()
-            && !self.config.is_administrator(&account_hash)
+        if !executor.config().allow_unrestricted_transfers()
+            && !executor.config().is_administrator(&account_hash)
         {
             // We need to make sure that source or target has to be admin.
             match transfer_target_mode {
@@ -817,7 +1942,7 @@ where
                     let is_target_system_account =
                         target_account_hash == PublicKey::System.to_account_hash();
                     let is_target_administrator =
-                        self.config.is_administrator(&target_account_hash);
+                        executor.config().is_administrator(&target_account_hash);
                     if !(is_target_system_account || is_target_administrator) {
                         // Transferring from normal account to a purse doesn't work.
                         return Ok(make_charged_execution_failure(
@@ -825,6 +1950,13 @@ where
                         ));
                     }
                 }
+                NewTransferTargetMode::OwnPurse(_) => {
+                    // Consistent with transfers to own purses in session code, which are only
+                    // allowed for administrators.
+                    return Ok(make_charged_execution_failure(
+                        execution::Error::DisabledUnrestrictedTransfers.into(),
+                    ));
+                }
                 NewTransferTargetMode::PurseExists(_) => {
                     // We don't know who is the target and we can't simply reverse search
                     // account/contract that owns it. We also can't know if purse is owned exactly
@@ -838,7 +1970,8 @@ where
 
         match transfer_target_mode {
             NewTransferTargetMode::ExistingAccount { .. }
-            | NewTransferTargetMode::PurseExists(_) => {
+            | NewTransferTargetMode::PurseExists(_)
+            | NewTransferTargetMode::OwnPurse(_) => {
                 // Noop
             }
             NewTransferTargetMode::CreateAccount(account_hash) => {
@@ -1062,8 +2195,8 @@ where
         };
 
         let transfer_stack = self.get_new_system_call_stack();
-        let (_, mut session_result): (Option<Result<(), u8>>, ExecutionResult) = executor
-            .call_system_contract(
+        let (session_mint_result, mut session_result): (Option<Result<(), u8>>, ExecutionResult) =
+            executor.call_system_contract(
                 DirectSystemContractCall::Transfer,
                 runtime_args,
                 &account,
@@ -1085,6 +2218,23 @@ where
         // charge for anything that happens while calling transfer entrypoint.
         session_result = session_result.with_cost(Gas::default());
 
+        // Funds are validated up front, but the mint can still refuse the transfer (i.e. when it
+        // would breach the existential deposit).
+        if let Some(Err(mint_error)) = session_mint_result {
+            let error = match mint::Error::try_from(mint_error) {
+                Ok(mint_error) => ApiError::from(mint_error),
+                Err(_) => ApiError::Transfer,
+            };
+            session_result = ExecutionResult::Failure {
+                error: Error::Exec(ExecError::Revert(error)),
+                transfers: Vec::new(),
+                cost: Gas::default(),
+                execution_journal: Default::default(),
+                read_keys: None,
+                warnings: Vec::new(),
+            };
+        }
+
         let finalize_result = {
             let handle_payment_args = {
                 // Gas spent during payment code execution
@@ -1134,7 +2284,7 @@ where
                     Phase::FinalizePayment,
                     finalize_payment_stack,
                     // Spending limit is cost of wasmless execution.
-                    U512::from(self.config().system_config().wasmless_transfer_cost()),
+                    U512::from(executor.config().system_config().wasmless_transfer_cost()),
                 );
 
             finalize_result
@@ -1216,6 +2366,7 @@ where
             let account_hash = deploy_item.address;
             match self.get_authorized_account(
                 correlation_id,
+                executor,
                 account_hash,
                 &authorization_keys,
                 Rc::clone(&tracking_copy),
@@ -1225,6 +2376,19 @@ where
             }
         };
 
+        if let Err(error) = self.check_authorization_contract(
+            correlation_id,
+            executor,
+            protocol_version,
+            blocktime,
+            deploy_item.deploy_hash,
+            &account,
+            &authorization_keys,
+            Rc::clone(&tracking_copy),
+        ) {
+            return Ok(ExecutionResult::precondition_failure(error));
+        }
+
         // Finalization is executed by system account (currently genesis account)
         // payment_code_spec_5: system executes finalization
         let system_account = match tracking_copy
@@ -1240,6 +2404,7 @@ where
         let deploy_hash = deploy_item.deploy_hash;
 
         let session_args = session.args().clone();
+        let is_unversioned_session = session.is_stored_contract();
 
         // Create session code `A` from provided session bytes
         // validation_spec_1: valid wasm bytes
@@ -1257,6 +2422,9 @@ where
                 return Ok(ExecutionResult::precondition_failure(error));
             }
         };
+        if is_unversioned_session {
+            raise_unversioned_contract_call_warning(executor, &session_execution_kind);
+        }
 
         // Get account main purse balance key
         // validation_spec_5: account main purse minimum balance
@@ -1363,27 +2531,48 @@ where
         // [`ExecutionResultBuilder`] handles merging of multiple execution results
         let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
 
-        // Execute provided payment code
-        let payment_result = {
-            // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
-            // gas_price)
-            let payment_gas_limit = match Gas::from_motes(max_payment_cost, deploy_item.gas_price) {
-                Some(gas) => gas,
+        // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
+        // gas_price)
+        let max_payment_gas_limit = match Gas::from_motes(max_payment_cost, deploy_item.gas_price) {
+            Some(gas) => gas,
+            None => {
+                return Ok(ExecutionResult::precondition_failure(
+                    Error::GasConversionOverflow,
+                ))
+            }
+        };
+
+        // Custom payment code may lower its gas budget, which in turn caps what the account is
+        // charged should the payment code fail.
+        let custom_payment_terms = if payment.is_standard_payment(Phase::Payment) {
+            None
+        } else {
+            match CustomPaymentTerms::new(payment.args(), max_payment_gas_limit) {
+                Ok(terms) => Some(terms),
+                Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            }
+        };
+        let payment_gas_limit =
+            custom_payment_terms.map_or(max_payment_gas_limit, |terms| terms.gas_budget());
+        let payment_error_cost = match custom_payment_terms {
+            Some(terms) => match Motes::from_gas(terms.gas_budget(), deploy_item.gas_price) {
+                Some(motes) => motes,
                 None => {
                     return Ok(ExecutionResult::precondition_failure(
                         Error::GasConversionOverflow,
                     ))
                 }
-            };
+            },
+            None => max_payment_cost,
+        };
 
+        // Execute provided payment code
+        let payment_result = {
             // Create payment code module from bytes
             // validation_spec_1: valid wasm bytes
             let phase = Phase::Payment;
 
-            let payment_stack = RuntimeStack::from_account_hash(
-                deploy_item.address,
-                self.config.max_runtime_call_stack_height() as usize,
-            );
+            let payment_stack = self.get_new_account_call_stack(deploy_item.address);
 
             // payment_code_spec_2: execute payment code
             let payment_access_rights = account.extract_access_rights();
@@ -1411,6 +2600,7 @@ where
                     payment_stack,
                 )
             } else {
+                let is_unversioned_payment = payment.is_stored_contract();
                 let payment_execution_kind = match ExecutionKind::new(
                     Rc::clone(&tracking_copy),
                     account.named_keys(),
@@ -1424,6 +2614,9 @@ where
                         return Ok(ExecutionResult::precondition_failure(error));
                     }
                 };
+                if is_unversioned_payment {
+                    raise_unversioned_contract_call_warning(executor, &payment_execution_kind);
+                }
                 executor.exec(
                     payment_execution_kind,
                     payment_args,
@@ -1453,7 +2646,7 @@ where
 
             match ExecutionResult::new_payment_code_error(
                 error,
-                max_payment_cost,
+                payment_error_cost,
                 account_main_purse_balance,
                 payment_result.cost(),
                 account_main_purse_balance_key,
@@ -1480,33 +2673,37 @@ where
             }
         };
 
-        if let Some(forced_transfer) =
-            payment_result.check_forced_transfer(payment_purse_balance, deploy_item.gas_price)
-        {
+        // Custom payment code breaking its terms is reported with a more specific error than
+        // payment code failing in general.
+        let payment_error = custom_payment_terms
+            .and_then(|terms| {
+                terms.check(
+                    &payment_result,
+                    payment_purse_balance,
+                    deploy_item.gas_price,
+                )
+            })
+            .or_else(|| {
+                payment_result
+                    .check_forced_transfer(payment_purse_balance, deploy_item.gas_price)
+                    .map(|forced_transfer| match forced_transfer {
+                        ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
+                        ForcedTransferResult::GasConversionOverflow => Error::GasConversionOverflow,
+                        ForcedTransferResult::PaymentFailure => payment_result
+                            .as_error()
+                            .cloned()
+                            .unwrap_or(Error::InsufficientPayment),
+                    })
+            });
+
+        if let Some(error) = payment_error {
             // Get rewards purse balance key
             // payment_code_spec_6: system contract validity
-            let error = match forced_transfer {
-                ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
-                ForcedTransferResult::GasConversionOverflow => Error::GasConversionOverflow,
-                ForcedTransferResult::PaymentFailure => payment_result
-                    .take_error()
-                    .unwrap_or(Error::InsufficientPayment),
-            };
-
-            let gas_cost = match Gas::from_motes(max_payment_cost, deploy_item.gas_price) {
-                Some(gas) => gas,
-                None => {
-                    return Ok(ExecutionResult::precondition_failure(
-                        Error::GasConversionOverflow,
-                    ))
-                }
-            };
-
             match ExecutionResult::new_payment_code_error(
                 error,
-                max_payment_cost,
+                payment_error_cost,
                 account_main_purse_balance,
-                gas_cost,
+                payment_gas_limit,
                 account_main_purse_balance_key,
                 rewards_target_purse_balance_key,
             ) {
@@ -1522,10 +2719,7 @@ where
         let post_payment_tracking_copy = tracking_copy.borrow();
         let session_tracking_copy = Rc::new(RefCell::new(post_payment_tracking_copy.fork()));
 
-        let session_stack = RuntimeStack::from_account_hash(
-            deploy_item.address,
-            self.config.max_runtime_call_stack_height() as usize,
-        );
+        let session_stack = self.get_new_account_call_stack(deploy_item.address);
 
         let session_access_rights = account.extract_access_rights();
 
@@ -1615,9 +2809,32 @@ where
             // so we start again from the post-payment state.
             Rc::new(RefCell::new(post_payment_tracking_copy.fork()))
         } else {
-            session_result =
-                session_result.with_journal(session_tracking_copy.borrow().execution_journal());
-            session_tracking_copy
+            let session_journal = session_tracking_copy.borrow().execution_journal();
+            let effects_size = session_journal.serialized_size() as u64;
+            let max_effects_size = u64::from(self.config.max_effects_size_bytes());
+            if effects_size > max_effects_size
+                && !self
+                    .config
+                    .has_quirk_at(protocol_version, Quirk::UnlimitedEffectsSize)
+            {
+                // Oversized effects are treated like any other session failure: they are dropped
+                // and the deploy is charged for the gas it consumed.
+                session_result = ExecutionResult::Failure {
+                    error: Error::Exec(ExecError::EffectsSizeExceeded {
+                        size: effects_size,
+                        max: max_effects_size,
+                    }),
+                    transfers: session_result.transfers().clone(),
+                    cost: session_result.cost(),
+                    execution_journal: Default::default(),
+                    read_keys: None,
+                    warnings: Vec::new(),
+                };
+                Rc::new(RefCell::new(post_payment_tracking_copy.fork()))
+            } else {
+                session_result = session_result.with_journal(session_journal);
+                session_tracking_copy
+            }
         };
 
         // NOTE: session_code_spec_3: (do not include session execution effects in
@@ -1714,6 +2931,18 @@ where
             .build()
             .expect("ExecutionResultBuilder not initialized properly");
 
+        if let Some(cost) = Motes::from_gas(ret.cost(), deploy_item.gas_price) {
+            let excessive_payment = cost
+                .value()
+                .saturating_mul(U512::from(EXCESSIVE_PAYMENT_FACTOR));
+            if !cost.is_zero() && payment_purse_balance.value() >= excessive_payment {
+                executor.raise_warning(ExecutionWarning::ExcessivePayment {
+                    payment: payment_purse_balance.value(),
+                    cost: cost.value(),
+                });
+            }
+        }
+
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
@@ -1790,6 +3019,23 @@ where
             .map_err(|err| Error::Exec(err.into()))
     }
 
+    /// Inspects Wasm bytes against the configured Wasm limits and returns every problem which
+    /// would cause the module to be rejected at execution time.
+    ///
+    /// This does not touch global state, so contract builds can be validated before deploying.
+    pub fn diagnose_wasm(&self, module_bytes: &[u8]) -> Result<Vec<WasmDiagnostic>, Error> {
+        let diagnostics = wasm_prep::diagnose(*self.config.wasm_config(), module_bytes)?;
+        Ok(diagnostics)
+    }
+
+    /// Lists the host functions imported by Wasm bytes, with the cost of calling each of them
+    /// under the configured cost table.
+    pub fn wasm_imports(&self, module_bytes: &[u8]) -> Result<Vec<HostFunctionImport>, Error> {
+        let host_function_costs = self.config.wasm_config().take_host_function_costs();
+        let imports = wasm_prep::wasm_imports(&host_function_costs, module_bytes)?;
+        Ok(imports)
+    }
+
     /// Gets a trie object for given state root hash.
     pub fn get_trie_full(
         &self,
@@ -1865,8 +3111,8 @@ where
                 error!(%error, "unexpected query failure; circular reference");
                 return Err(GetEraValidatorsError::UnexpectedQueryFailure);
             }
-            QueryResult::DepthLimit { depth } => {
-                error!(%depth, "unexpected query failure; depth limit exceeded");
+            QueryResult::DepthLimitExceeded { limit } => {
+                error!(%limit, "unexpected query failure; depth limit exceeded");
                 return Err(GetEraValidatorsError::UnexpectedQueryFailure);
             }
             QueryResult::Success { value, proofs: _ } => {
@@ -1919,241 +3165,1392 @@ where
         Ok(GetBidsResult::Success { bids })
     }
 
-    /// Executes a step request.
-    pub fn commit_step(
+    /// Gets a page of the keys in global state sharing a tag.
+    ///
+    /// Keys are returned in ascending order of their serialized form; passing the last key of a
+    /// page as the cursor of the next request resumes the enumeration.
+    pub fn get_keys(
         &self,
         correlation_id: CorrelationId,
-        step_request: StepRequest,
-    ) -> Result<StepSuccess, StepError> {
-        let state_root_hash = step_request.pre_state_hash;
-        let tracking_copy = match self.tracking_copy(state_root_hash) {
-            Err(error) => return Err(StepError::TrackingCopyError(error)),
-            Ok(None) => return Err(StepError::RootNotFound(state_root_hash)),
-            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        get_keys_request: GetKeysRequest,
+    ) -> Result<GetKeysResult, Error>
+    where
+        S::Error: From<bytesrepr::Error>,
+    {
+        let mut tracking_copy = match self.tracking_copy(get_keys_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetKeysResult::RootNotFound),
         };
 
-        let executor = Executor::new(self.config().clone());
-
-        let virtual_system_account = {
-            let purse = URef::new(Default::default(), AccessRights::READ_ADD_WRITE);
-            Account::create(
-                PublicKey::System.to_account_hash(),
-                NamedKeys::default(),
-                purse,
+        let keys = tracking_copy
+            .get_keys_after(
+                correlation_id,
+                &get_keys_request.key_tag(),
+                get_keys_request.cursor(),
+                get_keys_request.limit(),
             )
-        };
+            .map_err(Into::into)?;
 
-        let authorization_keys = {
-            let mut ret = BTreeSet::new();
-            ret.insert(PublicKey::System.to_account_hash());
-            ret
-        };
+        Ok(GetKeysResult::Success { keys })
+    }
 
-        let gas_limit = Gas::new(U512::from(std::u64::MAX));
-        let deploy_hash = {
-            // seeds address generator w/ era_end_timestamp_millis
-            let mut bytes = step_request.era_end_timestamp_millis.into_bytes()?;
-            bytes.append(&mut step_request.next_era_id.into_bytes()?);
-            DeployHash::new(Digest::hash(&bytes).value())
+    /// Gets a single Merkle proof of the values of `keys` under `state_root_hash`, or of their
+    /// absence.
+    ///
+    /// Trie nodes shared by the paths to several of the keys are included in the proof only once.
+    pub fn get_multi_proof(
+        &self,
+        correlation_id: CorrelationId,
+        state_root_hash: Digest,
+        keys: Vec<Key>,
+    ) -> Result<GetMultiProofResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let root = match get_multi_proof::read_trie(&self.state, correlation_id, &state_root_hash)?
+        {
+            Some(root) => root,
+            None => return Ok(GetMultiProofResult::RootNotFound),
         };
 
-        let distribute_accumulated_fees_stack = self.get_new_system_call_stack();
-        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
-            DirectSystemContractCall::DistributeAccumulatedFees,
-            RuntimeArgs::default(),
-            &virtual_system_account,
-            authorization_keys.clone(),
-            BlockTime::default(),
-            deploy_hash,
-            gas_limit,
-            step_request.protocol_version,
-            correlation_id,
-            Rc::clone(&tracking_copy),
-            Phase::Session,
-            distribute_accumulated_fees_stack,
-            // There should be no tokens transferred during rewards distribution.
-            U512::zero(),
-        );
+        let mut paths = keys
+            .iter()
+            .map(|key| key.normalize().to_bytes())
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.sort();
+        paths.dedup();
+        let paths: Vec<&[u8]> = paths.iter().map(Vec::as_slice).collect();
+
+        let root = get_multi_proof::build_proof_node(&self.state, correlation_id, root, &paths, 0)?;
+        Ok(GetMultiProofResult::Success {
+            proof: TrieMultiProof::new(root),
+        })
+    }
 
-        if let Some(exec_error) = execution_result.take_error() {
-            return Err(StepError::DistributeAccumulatedFeesError(exec_error));
+    /// Gets a Merkle proof that `key` has no value under `state_root_hash`.
+    ///
+    /// The proof covers the path to `key` up to where it leaves the trie, along with the hashes of
+    /// the subtrees adjacent to that path.
+    pub fn prove_absence(
+        &self,
+        correlation_id: CorrelationId,
+        state_root_hash: Digest,
+        key: Key,
+    ) -> Result<ProveAbsenceResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let reader = match self.state.checkout(state_root_hash).map_err(Into::into)? {
+            Some(reader) => reader,
+            None => return Ok(ProveAbsenceResult::RootNotFound),
+        };
+        let key = key.normalize();
+        if reader
+            .read(correlation_id, &key)
+            .map_err(Into::into)?
+            .is_some()
+        {
+            return Ok(ProveAbsenceResult::KeyPresent);
         }
 
-        let reward_factors = match step_request.reward_factors() {
-            Ok(reward_factors) => reward_factors,
-            Err(error) => {
-                error!(
-                    "failed to deserialize reward factors: {}",
-                    error.to_string()
-                );
-                return Err(StepError::BytesRepr(error));
-            }
+        let root = match get_multi_proof::read_trie(&self.state, correlation_id, &state_root_hash)?
+        {
+            Some(root) => root,
+            None => return Ok(ProveAbsenceResult::RootNotFound),
         };
-        let reward_args = RuntimeArgs::try_new(|args| {
-            args.insert(ARG_REWARD_FACTORS, reward_factors)?;
-            Ok(())
-        })?;
-
-        let distribute_rewards_stack = self.get_new_system_call_stack();
-        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
-            DirectSystemContractCall::DistributeRewards,
-            reward_args,
-            &virtual_system_account,
-            authorization_keys.clone(),
-            BlockTime::default(),
-            deploy_hash,
-            gas_limit,
-            step_request.protocol_version,
+        let path = key.to_bytes()?;
+        let root = get_multi_proof::build_proof_node(
+            &self.state,
             correlation_id,
-            Rc::clone(&tracking_copy),
-            Phase::Session,
-            distribute_rewards_stack,
-            // There should be no tokens transferred during rewards distribution.
-            U512::zero(),
-        );
+            root,
+            &[path.as_slice()],
+            0,
+        )?;
+        Ok(ProveAbsenceResult::Success {
+            proof: TrieMultiProof::new(root),
+        })
+    }
 
-        if let Some(exec_error) = execution_result.take_error() {
-            return Err(StepError::DistributeError(exec_error));
+    /// Gets the number and total serialized size of the entries of global state under a state root
+    /// hash, grouped by the tag of their keys.
+    ///
+    /// Every entry of global state is read, so this is expensive for a large global state.
+    pub fn state_stats(
+        &self,
+        correlation_id: CorrelationId,
+        state_stats_request: StateStatsRequest,
+    ) -> Result<StateStatsResult, Error> {
+        let reader = match self
+            .state
+            .checkout(state_stats_request.state_hash())
+            .map_err(Into::into)?
+        {
+            Some(reader) => reader,
+            None => return Ok(StateStatsResult::RootNotFound),
+        };
+
+        let mut state_stats = StateStats::default();
+        // Keys are listed one leading byte at a time to bound how many are held in memory at once.
+        for prefix in 0..=u8::MAX {
+            let keys = reader
+                .keys_with_prefix(correlation_id, &[prefix])
+                .map_err(Into::into)?;
+            for key in keys {
+                let value = match reader.read(correlation_id, &key).map_err(Into::into)? {
+                    Some(value) => value,
+                    None => continue,
+                };
+                state_stats.record(
+                    key.tag(),
+                    key.serialized_length(),
+                    value.serialized_length(),
+                );
+            }
         }
 
-        let slashed_validators: Vec<PublicKey> = step_request.slashed_validators();
+        Ok(StateStatsResult::Success { state_stats })
+    }
 
-        if !slashed_validators.is_empty() {
-            let slash_args = {
-                let mut runtime_args = RuntimeArgs::new();
-                runtime_args
-                    .insert(ARG_VALIDATOR_PUBLIC_KEYS, slashed_validators)
-                    .map_err(|e| Error::Exec(e.into()))?;
-                runtime_args
-            };
+    /// Gets the delegator slots reserved by validators in the auction system.
+    pub fn get_reservations(
+        &self,
+        correlation_id: CorrelationId,
+        get_reservations_request: GetReservationsRequest,
+    ) -> Result<GetReservationsResult, Error> {
+        let state_hash = get_reservations_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetReservationsResult::RootNotFound),
+        };
 
-            let slash_stack = self.get_new_system_call_stack();
-            let (_, execution_result): (Option<()>, ExecutionResult) = executor
-                .call_system_contract(
-                    DirectSystemContractCall::Slash,
-                    slash_args,
-                    &virtual_system_account,
-                    authorization_keys.clone(),
-                    BlockTime::default(),
-                    deploy_hash,
-                    gas_limit,
-                    step_request.protocol_version,
-                    correlation_id,
-                    Rc::clone(&tracking_copy),
-                    Phase::Session,
-                    slash_stack,
-                    // No transfer should occur when slashing.
-                    U512::zero(),
-                );
+        let reservation_keys = tracking_copy
+            .get_keys(correlation_id, &KeyTag::Reservations)
+            .map_err(|err| Error::Exec(err.into()))?;
 
-            if let Some(exec_error) = execution_result.take_error() {
-                return Err(StepError::SlashingError(exec_error));
+        let mut reservations = Reservations::new();
+        for key in reservation_keys.iter() {
+            let validator_reservations: Vec<Reservation> =
+                match tracking_copy.get(correlation_id, key).map_err(Into::into)? {
+                    Some(StoredValue::CLValue(cl_value)) => cl_value.into_t()?,
+                    Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+                    None => return Err(Error::MissingKey(key.to_formatted_string())),
+                };
+            // Validators which cancelled all their reservations are left with an empty entry.
+            if let Some(reservation) = validator_reservations.first() {
+                reservations.insert(
+                    reservation.validator_public_key().clone(),
+                    validator_reservations,
+                );
             }
         }
 
-        let run_auction_args = RuntimeArgs::try_new(|args| {
-            args.insert(
-                ARG_ERA_END_TIMESTAMP_MILLIS,
-                step_request.era_end_timestamp_millis,
-            )?;
-            args.insert(
-                ARG_EVICTED_VALIDATORS,
-                step_request
-                    .evict_items
-                    .iter()
-                    .map(|item| item.validator_id.clone())
-                    .collect::<Vec<PublicKey>>(),
-            )?;
-            Ok(())
-        })?;
+        Ok(GetReservationsResult::Success { reservations })
+    }
 
-        let run_auction_stack = self.get_new_system_call_stack();
-        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
-            DirectSystemContractCall::RunAuction,
-            run_auction_args,
-            &virtual_system_account,
-            authorization_keys,
-            BlockTime::default(),
-            deploy_hash,
-            gas_limit,
-            step_request.protocol_version,
-            correlation_id,
-            Rc::clone(&tracking_copy),
-            Phase::Session,
-            run_auction_stack,
-            // RunAuction should not consume tokens.
-            U512::zero(),
-        );
+    /// Gets a validator's delegation rate as set in its bid and as applied to the current era,
+    /// along with when it can next be increased.
+    pub fn get_delegation_rate(
+        &self,
+        correlation_id: CorrelationId,
+        get_delegation_rate_request: GetDelegationRateRequest,
+    ) -> Result<GetDelegationRateResult, Error> {
+        let state_hash = get_delegation_rate_request.state_hash();
+        let public_key = get_delegation_rate_request.public_key();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetDelegationRateResult::RootNotFound),
+        };
 
-        if let Some(exec_error) = execution_result.take_error() {
-            return Err(StepError::AuctionError(exec_error));
-        }
+        let bid_key = Key::Bid(AccountHash::from(public_key));
+        let pending = match tracking_copy
+            .get(correlation_id, &bid_key)
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::Bid(bid)) => *bid.delegation_rate(),
+            Some(_) | None => return Ok(GetDelegationRateResult::ValidatorNotFound),
+        };
 
-        let execution_effect = tracking_copy.borrow().effect();
-        let execution_journal = tracking_copy.borrow().execution_journal();
+        let auction_hash = self.get_system_auction_hash(correlation_id, state_hash)?;
+        let auction_contract = tracking_copy.get_contract(correlation_id, auction_hash)?;
+        let named_keys = auction_contract.named_keys();
 
-        // commit
-        let post_state_hash = self
-            .state
-            .commit(
-                correlation_id,
-                step_request.pre_state_hash,
-                execution_effect.transforms,
+        let mut read_named_value = |name: &str| -> Result<Option<CLValue>, Error> {
+            let key = match named_keys.get(name) {
+                Some(key) => key,
+                None => return Ok(None),
+            };
+            match tracking_copy
+                .get(correlation_id, &key.normalize())
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => Ok(Some(cl_value)),
+                Some(other) => Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => Err(Error::MissingKey(key.to_formatted_string())),
+            }
+        };
+
+        let era_id: EraId = read_named_value(ERA_ID_KEY)?
+            .ok_or_else(|| Error::MissingKey(ERA_ID_KEY.to_string()))?
+            .into_t()?;
+        let snapshot: SeigniorageRecipientsSnapshot =
+            read_named_value(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY)?
+                .ok_or_else(|| Error::MissingKey(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY.to_string()))?
+                .into_t()?;
+        // No validator has increased its delegation rate while a cooldown was configured yet.
+        let delegation_rate_changes: DelegationRateChanges =
+            match read_named_value(DELEGATION_RATE_CHANGES_KEY)? {
+                Some(cl_value) => cl_value.into_t()?,
+                None => DelegationRateChanges::new(),
+            };
+
+        let effective = snapshot
+            .get(&era_id)
+            .and_then(|recipients| recipients.get(public_key))
+            .map(|recipient| *recipient.delegation_rate());
+        let last_increase_era_id = delegation_rate_changes.get(public_key).copied();
+        let cooldown = self.config.delegation_rate_change_cooldown();
+        let next_increase_era_id = last_increase_era_id
+            .filter(|_| cooldown > 0)
+            .map(|last_increase_era_id| last_increase_era_id.saturating_add(cooldown))
+            .filter(|next_increase_era_id| *next_increase_era_id > era_id);
+
+        Ok(GetDelegationRateResult::Success {
+            delegation_rate: ValidatorDelegationRate::new(
+                pending,
+                effective,
+                last_increase_era_id,
+                next_increase_era_id,
+            ),
+        })
+    }
+
+    /// Gets the auction's current era id, era start time and auction delay.
+    ///
+    /// This reads the auction's named keys directly rather than calling its `read_era_id` entry
+    /// point through execution.
+    pub fn get_era_info(
+        &self,
+        correlation_id: CorrelationId,
+        get_era_info_request: GetEraInfoRequest,
+    ) -> Result<GetEraInfoResult, Error> {
+        let state_hash = get_era_info_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetEraInfoResult::RootNotFound),
+        };
+
+        let auction_hash = self.get_system_auction_hash(correlation_id, state_hash)?;
+        let auction_contract = tracking_copy.get_contract(correlation_id, auction_hash)?;
+        let named_keys = auction_contract.named_keys();
+
+        let mut read_named_value = |name: &str| -> Result<CLValue, Error> {
+            let key = named_keys
+                .get(name)
+                .ok_or_else(|| Error::MissingKey(name.to_string()))?;
+            match tracking_copy
+                .get(correlation_id, &key.normalize())
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => Ok(cl_value),
+                Some(other) => Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => Err(Error::MissingKey(key.to_formatted_string())),
+            }
+        };
+
+        let era_id = read_named_value(ERA_ID_KEY)?.into_t()?;
+        let era_start_timestamp_millis =
+            read_named_value(ERA_END_TIMESTAMP_MILLIS_KEY)?.into_t()?;
+        let auction_delay = read_named_value(AUCTION_DELAY_KEY)?.into_t()?;
+
+        Ok(GetEraInfoResult::Success {
+            era_info: CurrentEraInfo::new(era_id, era_start_timestamp_millis, auction_delay),
+        })
+    }
+
+    /// Forecasts the validators the next auction would select, and the smallest winning stake.
+    ///
+    /// The auction's selection logic is run read-only against the current bids, as of the end
+    /// timestamp of the last era recorded by the auction.  Nothing is committed, and validators
+    /// which would be evicted at the end of the current era are not known in advance.
+    pub fn forecast_next_era_validators(
+        &self,
+        correlation_id: CorrelationId,
+        forecast_validators_request: ForecastValidatorsRequest,
+    ) -> Result<ForecastValidatorsResult, Error> {
+        let state_hash = forecast_validators_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(ForecastValidatorsResult::RootNotFound),
+        };
+
+        let auction_hash = self.get_system_auction_hash(correlation_id, state_hash)?;
+        let auction_contract = tracking_copy.get_contract(correlation_id, auction_hash)?;
+        let named_keys = auction_contract.named_keys();
+
+        let mut read_named_value = |name: &str| -> Result<CLValue, Error> {
+            let key = named_keys
+                .get(name)
+                .ok_or_else(|| Error::MissingKey(name.to_string()))?;
+            match tracking_copy
+                .get(correlation_id, &key.normalize())
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => Ok(cl_value),
+                Some(other) => Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => Err(Error::MissingKey(key.to_formatted_string())),
+            }
+        };
+
+        let validator_slots: u32 = read_named_value(VALIDATOR_SLOTS_KEY)?.into_t()?;
+        let era_end_timestamp_millis: u64 =
+            read_named_value(ERA_END_TIMESTAMP_MILLIS_KEY)?.into_t()?;
+
+        let bid_keys = tracking_copy
+            .get_keys(correlation_id, &KeyTag::Bid)
+            .map_err(|err| Error::Exec(err.into()))?;
+
+        let vesting_schedule_period_millis = self.config.vesting_schedule_period_millis();
+        let mut bids = Bids::new();
+        for key in bid_keys.iter() {
+            if let Some(StoredValue::Bid(mut bid)) =
+                tracking_copy.get(correlation_id, key).map_err(Into::into)?
+            {
+                bid.process_with_vesting_schedule(
+                    era_end_timestamp_millis,
+                    vesting_schedule_period_millis,
+                );
+                bids.insert(bid.validator_public_key().clone(), *bid);
+            }
+        }
+
+        let validator_slots = validator_slots as usize;
+        let validator_weights = auction::detail::select_auction_winners(
+            &bids,
+            era_end_timestamp_millis,
+            vesting_schedule_period_millis,
+            validator_slots,
+        )
+        .map_err(|error| Error::Exec(ExecError::Revert(error.into())))?;
+
+        Ok(ForecastValidatorsResult::Success {
+            forecast: ValidatorsForecast::new(validator_weights, validator_slots),
+        })
+    }
+
+    /// Gets the total supply and the cumulative amount of token burned.
+    ///
+    /// This reads the mint's named keys directly rather than summing up the burns recorded in the
+    /// execution results of past blocks.
+    pub fn get_supply(
+        &self,
+        correlation_id: CorrelationId,
+        get_supply_request: GetSupplyRequest,
+    ) -> Result<GetSupplyResult, Error> {
+        let state_hash = get_supply_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetSupplyResult::RootNotFound),
+        };
+
+        let mint_hash = self.get_system_mint_hash(correlation_id, state_hash)?;
+        let mint_contract = tracking_copy.get_contract(correlation_id, mint_hash)?;
+        let named_keys = mint_contract.named_keys();
+
+        let mut read_named_value = |name: &str| -> Result<Option<U512>, Error> {
+            let key = match named_keys.get(name) {
+                Some(key) => key,
+                None => return Ok(None),
+            };
+            match tracking_copy
+                .get(correlation_id, &key.normalize())
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => Ok(Some(cl_value.into_t()?)),
+                Some(other) => Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => Err(Error::MissingKey(key.to_formatted_string())),
+            }
+        };
+
+        let total_supply = read_named_value(TOTAL_SUPPLY_KEY)?
+            .ok_or_else(|| Error::MissingKey(TOTAL_SUPPLY_KEY.to_string()))?;
+        let total_burned = read_named_value(TOTAL_BURNED_KEY)?;
+
+        Ok(GetSupplyResult::Success {
+            supply: SupplyBreakdown::new(total_supply, total_burned),
+        })
+    }
+
+    /// Obtains the payment and accumulation purses of the handle payment contract with their
+    /// balances, along with how this engine handles refunds and fees.
+    pub fn get_payment_info(
+        &self,
+        correlation_id: CorrelationId,
+        get_payment_info_request: GetPaymentInfoRequest,
+    ) -> Result<GetPaymentInfoResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(get_payment_info_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetPaymentInfoResult::RootNotFound),
+        };
+
+        let payment_purse = self.get_handle_payment_purse(
+            correlation_id,
+            &mut tracking_copy,
+            handle_payment::PAYMENT_PURSE_KEY,
+        )?;
+        // The accumulation purse is missing on networks started before it was introduced, until
+        // an upgrade switches them to accumulating fees.
+        let accumulation_purse = match self.get_handle_payment_purse(
+            correlation_id,
+            &mut tracking_copy,
+            ACCUMULATION_PURSE_KEY,
+        ) {
+            Ok(accumulation_purse) => Some(accumulation_purse),
+            Err(Error::Exec(ExecError::NamedKeyNotFound(_))) => None,
+            Err(error) => return Err(error),
+        };
+
+        let read_balance = |purse: URef| -> Result<U512, Error> {
+            let purse_balance_key =
+                tracking_copy.get_purse_balance_key(correlation_id, purse.into())?;
+            let purse_balance =
+                tracking_copy.get_purse_balance(correlation_id, purse_balance_key)?;
+            Ok(purse_balance.value())
+        };
+        let payment_purse_balance = read_balance(payment_purse)?;
+        let accumulation_purse = match accumulation_purse {
+            Some(accumulation_purse) => {
+                Some((accumulation_purse, read_balance(accumulation_purse)?))
+            }
+            None => None,
+        };
+
+        // The handling modes are recorded from genesis, or the first upgrade recording them on
+        // older networks.
+        let mut read_recorded_handling = |name: &str| -> Result<Option<CLValue>, Error> {
+            let uref = match self.get_handle_payment_purse(correlation_id, &mut tracking_copy, name)
+            {
+                Ok(uref) => uref,
+                Err(Error::Exec(ExecError::NamedKeyNotFound(_))) => return Ok(None),
+                Err(error) => return Err(error),
+            };
+            match tracking_copy
+                .get(correlation_id, &Key::URef(uref))
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => Ok(Some(cl_value)),
+                Some(other) => Err(Error::unexpected_stored_value("CLValue", &other)),
+                None => Err(Error::MissingKey(name.to_string())),
+            }
+        };
+        let refund_handling = match read_recorded_handling(REFUND_HANDLING_KEY)? {
+            Some(cl_value) => Some(cl_value.into_t()?),
+            None => None,
+        };
+        let fee_handling = match read_recorded_handling(FEE_HANDLING_KEY)? {
+            Some(cl_value) => Some(cl_value.into_t()?),
+            None => None,
+        };
+
+        Ok(GetPaymentInfoResult::Success {
+            payment_info: Box::new(PaymentInfo::new(
+                payment_purse,
+                payment_purse_balance,
+                accumulation_purse,
+                refund_handling,
+                fee_handling,
+            )),
+        })
+    }
+
+    /// Obtains the contract events emitted in the block which produced the requested state root.
+    ///
+    /// State roots committed before events were recorded yield no events.
+    pub fn get_block_events(
+        &self,
+        correlation_id: CorrelationId,
+        get_block_events_request: GetBlockEventsRequest,
+    ) -> Result<GetBlockEventsResult, Error> {
+        let state_hash = get_block_events_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetBlockEventsResult::RootNotFound),
+        };
+
+        let events = match tracking_copy
+            .get(correlation_id, &Key::BlockEvents)
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::CLValue(cl_value)) => cl_value.into_t()?,
+            Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+            None => Vec::new(),
+        };
+
+        Ok(GetBlockEventsResult::Success { events })
+    }
+
+    /// Obtains the gas consumed by the deploys of the block which produced the requested state
+    /// root, against the block gas limit.
+    ///
+    /// State roots committed before gas utilization was recorded yield `None`.
+    pub fn get_block_gas_utilization(
+        &self,
+        correlation_id: CorrelationId,
+        get_block_gas_utilization_request: GetBlockGasUtilizationRequest,
+    ) -> Result<GetBlockGasUtilizationResult, Error> {
+        let state_hash = get_block_gas_utilization_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetBlockGasUtilizationResult::RootNotFound),
+        };
+
+        let gas_utilization = match tracking_copy
+            .get(correlation_id, &Key::BlockGasUtilization)
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::CLValue(cl_value)) => Some(cl_value.into_t()?),
+            Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+            None => None,
+        };
+
+        Ok(GetBlockGasUtilizationResult::Success { gas_utilization })
+    }
+
+    /// Obtains the account or contract which created the given purse, as recorded in the purse
+    /// ownership index.
+    ///
+    /// Purses created before the index was enabled, or by genesis, yield `None`.
+    pub fn get_purse_owner(
+        &self,
+        correlation_id: CorrelationId,
+        get_purse_owner_request: GetPurseOwnerRequest,
+    ) -> Result<GetPurseOwnerResult, Error> {
+        let state_hash = get_purse_owner_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetPurseOwnerResult::RootNotFound),
+        };
+
+        let key = Key::PurseOwner(get_purse_owner_request.purse_addr());
+        let owner = match tracking_copy
+            .get(correlation_id, &key)
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::CLValue(cl_value)) => Some(cl_value.into_t()?),
+            Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+            None => None,
+        };
+
+        Ok(GetPurseOwnerResult::Success { owner })
+    }
+
+    /// Checks invariants spanning the system contracts against the global state under the
+    /// requested state root:
+    ///
+    /// * the total supply recorded by the mint equals the sum of all purse balances (burning
+    ///   reduces both),
+    /// * the bonding purses of all bids and delegations exist,
+    /// * the main purses of all accounts exist,
+    /// * every balance belongs to an existing purse.
+    ///
+    /// This reads every balance, bid and account, so it's meant for debugging only.
+    pub fn check_invariants(
+        &self,
+        correlation_id: CorrelationId,
+        check_invariants_request: CheckInvariantsRequest,
+    ) -> Result<CheckInvariantsResult, Error> {
+        let state_hash = check_invariants_request.state_hash();
+        let reader = match self.state.checkout(state_hash).map_err(Into::into)? {
+            Some(reader) => reader,
+            None => return Ok(CheckInvariantsResult::RootNotFound),
+        };
+
+        let total_supply =
+            match self.get_supply(correlation_id, GetSupplyRequest::new(state_hash))? {
+                GetSupplyResult::Success { supply } => supply.total_supply(),
+                GetSupplyResult::RootNotFound => return Ok(CheckInvariantsResult::RootNotFound),
+            };
+
+        let violations = InvariantChecker::new(correlation_id, &reader).check(total_supply)?;
+        Ok(CheckInvariantsResult::Success { violations })
+    }
+
+    /// Obtains the Wasm bytecode of a stored contract, identified either directly or by its
+    /// package and version.
+    ///
+    /// The BLAKE2b digest of the bytecode is returned alongside it and, if the request carries an
+    /// expected digest, checked against that.
+    pub fn get_contract_wasm(
+        &self,
+        correlation_id: CorrelationId,
+        get_contract_wasm_request: GetContractWasmRequest,
+    ) -> Result<GetContractWasmResult, Error> {
+        let state_hash = get_contract_wasm_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetContractWasmResult::RootNotFound),
+        };
+
+        let contract_hash = match get_contract_wasm_request.contract_identifier() {
+            StoredContractIdentifier::Hash(contract_hash) => contract_hash,
+            StoredContractIdentifier::Package {
+                contract_package_hash,
+                version,
+            } => {
+                let contract_package = match tracking_copy
+                    .get_contract_package(correlation_id, contract_package_hash)
+                {
+                    Ok(contract_package) => contract_package,
+                    Err(execution::Error::KeyNotFound(_)) => {
+                        return Ok(GetContractWasmResult::ContractPackageNotFound(
+                            contract_package_hash,
+                        ))
+                    }
+                    Err(error) => return Err(error.into()),
+                };
+                let maybe_contract_hash = match version {
+                    None => contract_package.current_contract_hash(),
+                    Some(version) => contract_package
+                        .enabled_versions()
+                        .into_iter()
+                        .filter(|(version_key, _)| version_key.contract_version() == version)
+                        .map(|(_, contract_hash)| contract_hash)
+                        .last(),
+                };
+                match maybe_contract_hash {
+                    Some(contract_hash) => contract_hash,
+                    None => {
+                        return Ok(GetContractWasmResult::ContractVersionNotFound {
+                            contract_package_hash,
+                            version,
+                        })
+                    }
+                }
+            }
+        };
+
+        let contract = match tracking_copy.get_contract(correlation_id, contract_hash) {
+            Ok(contract) => contract,
+            Err(execution::Error::KeyNotFound(_)) => {
+                return Ok(GetContractWasmResult::ContractNotFound(contract_hash))
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let contract_wasm_hash = contract.contract_wasm_hash();
+        let contract_wasm =
+            match tracking_copy.get_contract_wasm(correlation_id, contract_wasm_hash) {
+                Ok(contract_wasm) => contract_wasm,
+                Err(execution::Error::KeyNotFound(_)) => {
+                    return Ok(GetContractWasmResult::ContractWasmNotFound(
+                        contract_wasm_hash,
+                    ))
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+        let digest = Digest::hash(contract_wasm.bytes());
+        if let Some(expected) = get_contract_wasm_request.expected_digest() {
+            if expected != digest {
+                return Ok(GetContractWasmResult::DigestMismatch {
+                    expected,
+                    actual: digest,
+                });
+            }
+        }
+
+        Ok(GetContractWasmResult::Success {
+            contract_hash,
+            contract_wasm_hash,
+            contract_wasm,
+            digest,
+        })
+    }
+
+    /// Executes a step request.
+    pub fn commit_step(
+        &self,
+        correlation_id: CorrelationId,
+        step_request: StepRequest,
+    ) -> Result<StepSuccess, StepError> {
+        let state_root_hash = step_request.pre_state_hash;
+        let tracking_copy = match self.tracking_copy(state_root_hash) {
+            Err(error) => return Err(StepError::TrackingCopyError(error)),
+            Ok(None) => return Err(StepError::RootNotFound(state_root_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        let config = self
+            .config_at(
+                correlation_id,
+                step_request.protocol_version,
+                state_root_hash,
+            )
+            .map_err(StepError::TrackingCopyError)?;
+        let executor = Executor::new(config);
+
+        let virtual_system_account = {
+            let purse = URef::new(Default::default(), AccessRights::READ_ADD_WRITE);
+            Account::create(
+                PublicKey::System.to_account_hash(),
+                NamedKeys::default(),
+                purse,
+            )
+        };
+
+        let authorization_keys = {
+            let mut ret = BTreeSet::new();
+            ret.insert(PublicKey::System.to_account_hash());
+            ret
+        };
+
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let deploy_hash = {
+            // seeds address generator w/ era_end_timestamp_millis
+            let mut bytes = step_request.era_end_timestamp_millis.into_bytes()?;
+            bytes.append(&mut step_request.next_era_id.into_bytes()?);
+            DeployHash::new(Digest::hash(&bytes).value())
+        };
+
+        let (bids_before, era_validators_before) =
+            self.get_step_auction_state(correlation_id, &mut tracking_copy.borrow_mut())?;
+
+        // Fees accumulated during the ending era are shared among its validators.
+        let ending_era_validators: BTreeSet<PublicKey> = step_request
+            .next_era_id
+            .predecessor()
+            .and_then(|ending_era_id| era_validators_before.get(&ending_era_id))
+            .map(|validator_weights| validator_weights.keys().cloned().collect())
+            .unwrap_or_default();
+        let distribute_accumulated_fees_args = RuntimeArgs::try_new(|args| {
+            args.insert(handle_payment::ARG_NEXT_ERA_ID, step_request.next_era_id)?;
+            args.insert(handle_payment::ARG_VALIDATORS, ending_era_validators)?;
+            Ok(())
+        })?;
+
+        let distribute_accumulated_fees_stack = self.get_new_system_call_stack();
+        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
+            DirectSystemContractCall::DistributeAccumulatedFees,
+            distribute_accumulated_fees_args,
+            &virtual_system_account,
+            authorization_keys.clone(),
+            BlockTime::default(),
+            deploy_hash,
+            gas_limit,
+            step_request.protocol_version,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            Phase::Session,
+            distribute_accumulated_fees_stack,
+            // There should be no tokens transferred during rewards distribution.
+            U512::zero(),
+        );
+
+        if let Some(exec_error) = execution_result.take_error() {
+            return Err(StepError::DistributeAccumulatedFeesError(exec_error));
+        }
+
+        let reward_factors = match step_request.reward_factors() {
+            Ok(reward_factors) => reward_factors,
+            Err(error) => {
+                error!(
+                    "failed to deserialize reward factors: {}",
+                    error.to_string()
+                );
+                return Err(StepError::BytesRepr(error));
+            }
+        };
+        let reward_args = RuntimeArgs::try_new(|args| {
+            args.insert(ARG_REWARD_FACTORS, reward_factors)?;
+            Ok(())
+        })?;
+
+        let distribute_rewards_stack = self.get_new_system_call_stack();
+        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
+            DirectSystemContractCall::DistributeRewards,
+            reward_args,
+            &virtual_system_account,
+            authorization_keys.clone(),
+            BlockTime::default(),
+            deploy_hash,
+            gas_limit,
+            step_request.protocol_version,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            Phase::Session,
+            distribute_rewards_stack,
+            // There should be no tokens transferred during rewards distribution.
+            U512::zero(),
+        );
+
+        if let Some(exec_error) = execution_result.take_error() {
+            return Err(StepError::DistributeError(exec_error));
+        }
+
+        let slashed_validators: Vec<PublicKey> = step_request.slashed_validators();
+
+        if !slashed_validators.is_empty() {
+            let slash_args = {
+                let mut runtime_args = RuntimeArgs::new();
+                runtime_args
+                    .insert(ARG_VALIDATOR_PUBLIC_KEYS, slashed_validators)
+                    .map_err(|e| Error::Exec(e.into()))?;
+                runtime_args
+            };
+
+            let slash_stack = self.get_new_system_call_stack();
+            let (_, execution_result): (Option<()>, ExecutionResult) = executor
+                .call_system_contract(
+                    DirectSystemContractCall::Slash,
+                    slash_args,
+                    &virtual_system_account,
+                    authorization_keys.clone(),
+                    BlockTime::default(),
+                    deploy_hash,
+                    gas_limit,
+                    step_request.protocol_version,
+                    correlation_id,
+                    Rc::clone(&tracking_copy),
+                    Phase::Session,
+                    slash_stack,
+                    // No transfer should occur when slashing.
+                    U512::zero(),
+                );
+
+            if let Some(exec_error) = execution_result.take_error() {
+                return Err(StepError::SlashingError(exec_error));
+            }
+        }
+
+        let run_auction_args = RuntimeArgs::try_new(|args| {
+            args.insert(
+                ARG_ERA_END_TIMESTAMP_MILLIS,
+                step_request.era_end_timestamp_millis,
+            )?;
+            args.insert(
+                ARG_EVICTED_VALIDATORS,
+                step_request
+                    .evict_items
+                    .iter()
+                    .map(|item| item.validator_id.clone())
+                    .collect::<Vec<PublicKey>>(),
+            )?;
+            Ok(())
+        })?;
+
+        let run_auction_stack = self.get_new_system_call_stack();
+        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
+            DirectSystemContractCall::RunAuction,
+            run_auction_args,
+            &virtual_system_account,
+            authorization_keys,
+            BlockTime::default(),
+            deploy_hash,
+            gas_limit,
+            step_request.protocol_version,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            Phase::Session,
+            run_auction_stack,
+            // RunAuction should not consume tokens.
+            U512::zero(),
+        );
+
+        if let Some(exec_error) = execution_result.take_error() {
+            return Err(StepError::AuctionError(exec_error));
+        }
+
+        let failed_maintenance_tasks = self.run_maintenance_tasks(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            executor.config().wasm_config().storage_costs(),
+            &step_request,
+        );
+
+        let scheduled_callbacks = self.run_scheduled_callbacks(
+            correlation_id,
+            &executor,
+            &tracking_copy,
+            &step_request,
+            deploy_hash,
+        )?;
+
+        let (bids_after, era_validators_after) =
+            self.get_step_auction_state(correlation_id, &mut tracking_copy.borrow_mut())?;
+        let step_report = StepReport::new(
+            &step_request,
+            &bids_before,
+            &bids_after,
+            &era_validators_before,
+            &era_validators_after,
+            failed_maintenance_tasks,
+            scheduled_callbacks,
+        );
+
+        let execution_effect = tracking_copy.borrow().effect();
+        let execution_journal = tracking_copy.borrow().execution_journal();
+
+        // commit
+        let post_state_hash = self
+            .state
+            .commit(
+                correlation_id,
+                step_request.pre_state_hash,
+                execution_effect.transforms,
             )
             .map_err(Into::into)?;
 
         Ok(StepSuccess {
             post_state_hash,
             execution_journal,
+            step_report,
         })
     }
 
-    /// Gets the balance of a given public key.
-    pub fn get_balance(
+    /// Calls an entry point of a contract registered as a system contract extension, as the system
+    /// account and without a gas limit, committing its effects if it succeeds.
+    ///
+    /// The call goes through the same path as the engine's own calls into the core system
+    /// contracts, so the entry point must return `()`.
+    pub fn commit_system_contract_extension_call(
         &self,
         correlation_id: CorrelationId,
-        state_hash: Digest,
-        public_key: PublicKey,
-    ) -> Result<BalanceResult, Error> {
-        // Look up the account, get the main purse, and then do the existing balance check
-        let tracking_copy = match self.tracking_copy(state_hash) {
-            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
-            Ok(None) => return Ok(BalanceResult::RootNotFound),
-            Err(error) => return Err(error),
+        request: SystemContractExtensionCallRequest,
+    ) -> Result<SystemContractExtensionCallResult, Error> {
+        let pre_state_hash = request.pre_state_hash();
+        let tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(SystemContractExtensionCallResult::RootNotFound),
         };
 
-        let account_addr = public_key.to_account_hash();
-
-        let account = match tracking_copy
+        // Only extensions may be called this way; the core system contracts are called by the
+        // engine itself where needed.
+        let registry = tracking_copy
             .borrow_mut()
-            .get_account(correlation_id, account_addr)
+            .get_system_contracts(correlation_id)?;
+        if system_contract_registry::is_core_system_contract(request.contract_name())
+            || registry.get(request.contract_name()).is_none()
         {
-            Ok(account) => account,
-            Err(error) => return Err(error.into()),
+            return Ok(SystemContractExtensionCallResult::NotRegistered(
+                request.contract_name().to_string(),
+            ));
+        }
+
+        let executor = Executor::new(
+            self.config()
+                .for_protocol_version(request.protocol_version()),
+        );
+
+        let virtual_system_account = {
+            let purse = URef::new(Default::default(), AccessRights::READ_ADD_WRITE);
+            Account::create(
+                PublicKey::System.to_account_hash(),
+                NamedKeys::default(),
+                purse,
+            )
         };
 
-        let main_purse_balance_key = {
-            let main_purse = account.main_purse();
-            match tracking_copy
-                .borrow()
-                .get_purse_balance_key(correlation_id, main_purse.into())
+        let authorization_keys = {
+            let mut ret = BTreeSet::new();
+            ret.insert(PublicKey::System.to_account_hash());
+            ret
+        };
+
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let deploy_hash = {
+            // seeds address generator w/ the pre state hash and the call itself
+            let mut bytes = pre_state_hash.into_bytes()?;
+            bytes.append(&mut request.contract_name().to_bytes()?);
+            bytes.append(&mut request.entry_point().to_bytes()?);
+            bytes.append(&mut request.args().to_bytes()?);
+            bytes.append(&mut request.blocktime().into_bytes()?);
+            DeployHash::new(Digest::hash(&bytes).value())
+        };
+
+        let stack = self.get_new_system_call_stack();
+        let (_, execution_result): (Option<()>, ExecutionResult) = executor.call_system_contract(
+            DirectSystemContractCall::Extension {
+                contract_name: request.contract_name().to_string(),
+                entry_point: request.entry_point().to_string(),
+            },
+            request.args().clone(),
+            &virtual_system_account,
+            authorization_keys,
+            request.blocktime(),
+            deploy_hash,
+            gas_limit,
+            request.protocol_version(),
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            Phase::Session,
+            stack,
+            U512::zero(),
+        );
+
+        if let Some(error) = execution_result.take_error() {
+            return Ok(SystemContractExtensionCallResult::Failure(error));
+        }
+
+        let execution_effect = tracking_copy.borrow().effect();
+
+        // commit
+        let post_state_hash = self
+            .state
+            .commit(
+                correlation_id,
+                pre_state_hash,
+                execution_effect.transforms.to_owned(),
+            )
+            .map_err(Into::into)?;
+
+        Ok(SystemContractExtensionCallResult::Success {
+            post_state_hash,
+            execution_effect,
+        })
+    }
+
+    /// Reads the bids and the era validators of the seigniorage recipients snapshot as seen by
+    /// `tracking_copy`.
+    fn get_step_auction_state(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+    ) -> Result<(Bids, EraValidators), Error> {
+        let bid_keys = tracking_copy
+            .get_keys(correlation_id, &KeyTag::Bid)
+            .map_err(Into::into)?;
+        let mut bids = BTreeMap::new();
+        for key in bid_keys.iter() {
+            if let Some(StoredValue::Bid(bid)) =
+                tracking_copy.get(correlation_id, key).map_err(Into::into)?
             {
-                Ok(balance_key) => balance_key,
-                Err(error) => return Err(error.into()),
+                bids.insert(bid.validator_public_key().clone(), *bid);
+            }
+        }
+
+        let auction_hash = tracking_copy
+            .get_system_contracts(correlation_id)?
+            .get(AUCTION)
+            .copied()
+            .ok_or_else(|| Error::MissingSystemContractHash(AUCTION.to_string()))?;
+        let auction_contract = tracking_copy.get_contract(correlation_id, auction_hash)?;
+        let snapshot_key = auction_contract
+            .named_keys()
+            .get(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY)
+            .ok_or_else(|| {
+                Error::Exec(execution::Error::NamedKeyNotFound(
+                    SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY.to_string(),
+                ))
+            })?;
+        let snapshot = match tracking_copy
+            .get(correlation_id, &snapshot_key.normalize())
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::CLValue(cl_value)) => cl_value.into_t()?,
+            Some(other) => return Err(Error::unexpected_stored_value("CLValue", &other)),
+            None => return Err(Error::MissingKey(snapshot_key.to_formatted_string())),
+        };
+
+        Ok((
+            bids,
+            auction::detail::era_validators_from_snapshot(snapshot),
+        ))
+    }
+
+    /// Runs the registered maintenance tasks in order against `tracking_copy`, applying the writes
+    /// of each task which succeeds.  Returns the names of the tasks which failed.
+    fn run_maintenance_tasks(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+        storage_costs: StorageCosts,
+        step_request: &StepRequest,
+    ) -> Vec<String> {
+        let mut failed_maintenance_tasks = Vec::new();
+        for task in self.maintenance_tasks.tasks() {
+            let context = MaintenanceContext::new(
+                correlation_id,
+                tracking_copy,
+                storage_costs,
+                step_request.protocol_version,
+                step_request.next_era_id,
+                step_request.era_end_timestamp_millis,
+                task.gas_budget(),
+            );
+            match task.run(context) {
+                Ok(writes) => {
+                    for (key, value) in writes {
+                        tracking_copy.write(key, value);
+                    }
+                }
+                Err(error) => {
+                    warn!("maintenance task {} failed: {}", task.name(), error);
+                    failed_maintenance_tasks.push(task.name().to_string());
+                }
+            }
+        }
+        failed_maintenance_tasks
+    }
+
+    /// Calls the scheduled callbacks which are due in the era started by the step, most overdue
+    /// first and up to the configured maximum per step, and reschedules them.
+    fn run_scheduled_callbacks(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        tracking_copy: &Rc<RefCell<TrackingCopy<S::Reader>>>,
+        step_request: &StepRequest,
+        deploy_hash: DeployHash,
+    ) -> Result<Vec<ScheduledCallbackResult>, Error> {
+        let callback_keys = tracking_copy
+            .borrow_mut()
+            .get_keys(correlation_id, &KeyTag::ScheduledCallback)
+            .map_err(Into::into)?;
+        if callback_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let accumulation_purse = self.get_handle_payment_purse(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            ACCUMULATION_PURSE_KEY,
+        )?;
+
+        let mut due_callbacks = Vec::new();
+        for key in callback_keys {
+            let maybe_value = tracking_copy
+                .borrow_mut()
+                .read(correlation_id, &key)
+                .map_err(Into::into)?;
+            // Cancelled callbacks are left as `None`.
+            let callback = match maybe_value {
+                Some(StoredValue::CLValue(cl_value)) => {
+                    match cl_value.into_t::<Option<ScheduledCallback>>() {
+                        Ok(Some(callback)) => callback,
+                        Ok(None) => continue,
+                        Err(error) => {
+                            warn!(%key, ?error, "failed to parse scheduled callback");
+                            continue;
+                        }
+                    }
+                }
+                Some(_) | None => continue,
+            };
+            if callback.is_due(step_request.next_era_id) {
+                due_callbacks.push((key, callback));
             }
+        }
+
+        // Call the most overdue callbacks first.  A callback left out by the cap keeps its
+        // `next_era_id` while the ones called are rescheduled past it, so it moves ahead of them
+        // at the following steps rather than being starved by lower keys.  The sort is stable, so
+        // ties stay ordered by key.
+        due_callbacks.sort_by_key(|(_, callback)| callback.next_era_id());
+        let max_callbacks = self.config.max_scheduled_callbacks_per_step() as usize;
+
+        let mut results = Vec::new();
+        for (key, mut callback) in due_callbacks.into_iter().take(max_callbacks) {
+            callback.reschedule(step_request.next_era_id);
+            let cl_value = CLValue::from_t(Some(callback.clone())).map_err(ExecError::from)?;
+            tracking_copy
+                .borrow_mut()
+                .write(key, StoredValue::CLValue(cl_value));
+
+            let result = self.run_scheduled_callback(
+                correlation_id,
+                executor,
+                tracking_copy,
+                step_request,
+                deploy_hash,
+                key,
+                &callback,
+                accumulation_purse,
+            )?;
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    /// Calls a single scheduled callback, applying its effects if it succeeds and charging its
+    /// purse for the gas used either way.
+    ///
+    /// The whole gas budget is moved from the callback's purse to the handle payment purse before
+    /// the callback runs, so the callback can't spend it.  Afterwards the cost of the gas used is
+    /// moved on to the accumulation purse, and the rest is refunded.
+    #[allow(clippy::too_many_arguments)]
+    fn run_scheduled_callback(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        tracking_copy: &Rc<RefCell<TrackingCopy<S::Reader>>>,
+        step_request: &StepRequest,
+        deploy_hash: DeployHash,
+        key: Key,
+        callback: &ScheduledCallback,
+        accumulation_purse: URef,
+    ) -> Result<ScheduledCallbackResult, Error> {
+        let mut result = ScheduledCallbackResult {
+            key,
+            entry_point: callback.entry_point().to_string(),
+            cost: U512::zero(),
+            error: None,
         };
 
-        let (account_balance, proof) = match tracking_copy
+        // Callbacks pay for gas at a price of one mote per unit.
+        let gas_budget = Gas::from(callback.gas_budget());
+        let max_cost = Motes::from_gas(gas_budget, 1).ok_or(Error::GasConversionOverflow)?;
+        let purse_balance_key = tracking_copy
             .borrow()
-            .get_purse_balance_with_proof(correlation_id, main_purse_balance_key)
+            .get_purse_balance_key(correlation_id, callback.purse().into())?;
+        let purse_balance = tracking_copy
+            .borrow()
+            .get_purse_balance(correlation_id, purse_balance_key)?;
+        if purse_balance < max_cost {
+            result.error = Some(Error::InsufficientPayment.to_string());
+            return Ok(result);
+        }
+
+        let account = match tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, callback.account_hash())
         {
-            Ok((balance, proof)) => (balance, proof),
-            Err(error) => return Err(error.into()),
+            Ok(account) => account,
+            Err(error) => {
+                result.error = Some(error.to_string());
+                return Ok(result);
+            }
         };
 
-        let proof = Box::new(proof);
-        let motes = account_balance.value();
-        Ok(BalanceResult::Success { motes, proof })
+        let payment_purse = self.get_handle_payment_purse(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            handle_payment::PAYMENT_PURSE_KEY,
+        )?;
+        let payment_balance_key = tracking_copy
+            .borrow()
+            .get_purse_balance_key(correlation_id, payment_purse.into())?;
+        Self::move_motes(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            purse_balance_key,
+            payment_balance_key,
+            max_cost.value(),
+        )?;
+
+        // Each callback gets its own address space.
+        let callback_deploy_hash = {
+            let mut bytes = deploy_hash.value().to_vec();
+            bytes.append(&mut key.into_bytes()?);
+            DeployHash::new(Digest::hash(&bytes).value())
+        };
+
+        let (cost, journal) = {
+            let tracking_copy = tracking_copy.borrow();
+            let callback_tracking_copy = Rc::new(RefCell::new(tracking_copy.fork()));
+            let execution_result = executor.call_scheduled_callback(
+                callback.contract_hash(),
+                callback.entry_point(),
+                callback.args().clone(),
+                &account,
+                BlockTime::new(step_request.era_end_timestamp_millis),
+                callback_deploy_hash,
+                gas_budget,
+                step_request.protocol_version,
+                correlation_id,
+                Rc::clone(&callback_tracking_copy),
+                self.get_new_account_call_stack(callback.account_hash()),
+            );
+            let cost = execution_result.cost();
+            let journal = match execution_result.take_error() {
+                Some(error) => {
+                    debug!(%key, %error, "scheduled callback failed");
+                    result.error = Some(error.to_string());
+                    None
+                }
+                None => Some(callback_tracking_copy.borrow().execution_journal()),
+            };
+            (cost, journal)
+        };
+
+        let mut tracking_copy = tracking_copy.borrow_mut();
+        if let Some(journal) = journal {
+            match tracking_copy
+                .merge_journal(correlation_id, journal)
+                .map_err(Into::into)?
+            {
+                AddResult::Success => {}
+                error => {
+                    // The fork was taken from this tracking copy, so this shouldn't happen.
+                    warn!(%key, ?error, "failed to apply the effects of a scheduled callback");
+                    result.error = Some(format!("{:?}", error));
+                }
+            }
+        }
+
+        let charge = Motes::from_gas(cost, 1)
+            .ok_or(Error::GasConversionOverflow)?
+            .value()
+            .min(max_cost.value());
+        let accumulation_balance_key =
+            tracking_copy.get_purse_balance_key(correlation_id, accumulation_purse.into())?;
+        Self::move_motes(
+            correlation_id,
+            &mut tracking_copy,
+            payment_balance_key,
+            accumulation_balance_key,
+            charge,
+        )?;
+        Self::move_motes(
+            correlation_id,
+            &mut tracking_copy,
+            payment_balance_key,
+            purse_balance_key,
+            max_cost.value() - charge,
+        )?;
+
+        result.cost = charge;
+        Ok(result)
+    }
+
+    /// Moves `amount` motes from the purse whose balance is stored under `source` to the one whose
+    /// balance is stored under `target`.
+    fn move_motes(
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<S::Reader>,
+        source: Key,
+        target: Key,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let source_balance = tracking_copy
+            .get_purse_balance(correlation_id, source)?
+            .value()
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientPayment)?;
+        tracking_copy.write(
+            source,
+            StoredValue::CLValue(CLValue::from_t(source_balance).map_err(ExecError::from)?),
+        );
+        let target_balance = tracking_copy
+            .get_purse_balance(correlation_id, target)?
+            .value()
+            .checked_add(amount)
+            .ok_or(Error::GasConversionOverflow)?;
+        tracking_copy.write(
+            target,
+            StoredValue::CLValue(CLValue::from_t(target_balance).map_err(ExecError::from)?),
+        );
+        Ok(())
+    }
+
+    /// Gets the balance of a given public key.
+    pub fn get_balance(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Digest,
+        public_key: PublicKey,
+    ) -> Result<BalanceResult, Error> {
+        self.identified_balance(
+            correlation_id,
+            IdentifiedBalanceRequest::new(state_hash, BalanceIdentifier::PublicKey(public_key)),
+        )
     }
 
     /// Obtains an instance of a system contract registry for a given state root hash.
@@ -2162,15 +4559,28 @@ where
         correlation_id: CorrelationId,
         state_root_hash: Digest,
     ) -> Result<SystemContractRegistry, Error> {
+        self.get_system_entity_registry(correlation_id, state_root_hash)
+            .map(SystemContractRegistry::from)
+    }
+
+    /// Obtains an instance of a system entity registry for a given state root hash.
+    ///
+    /// A registry stored in the legacy [`SystemContractRegistry`] format is converted, so state
+    /// roots from before and after the migration resolve the same way.
+    pub fn get_system_entity_registry(
+        &self,
+        correlation_id: CorrelationId,
+        state_root_hash: Digest,
+    ) -> Result<SystemEntityRegistry, Error> {
         let tracking_copy = match self.tracking_copy(state_root_hash)? {
             None => return Err(Error::RootNotFound(state_root_hash)),
             Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
         };
         let result = tracking_copy
             .borrow_mut()
-            .get_system_contracts(correlation_id)
+            .get_system_entity_registry(correlation_id)
             .map_err(|error| {
-                warn!(%error, "Failed to retrieve system contract registry");
+                warn!(%error, "Failed to retrieve system entity registry");
                 Error::MissingSystemContractRegistry
             });
         result
@@ -2182,12 +4592,7 @@ where
         correlation_id: CorrelationId,
         state_hash: Digest,
     ) -> Result<ContractHash, Error> {
-        let registry = self.get_system_contract_registry(correlation_id, state_hash)?;
-        let mint_hash = registry.get(MINT).ok_or_else(|| {
-            error!("Missing system mint contract hash");
-            Error::MissingSystemContractHash(MINT.to_string())
-        })?;
-        Ok(*mint_hash)
+        self.get_system_entity_hash(correlation_id, state_hash, MINT)
     }
 
     /// Returns auction system contract hash.
@@ -2196,12 +4601,7 @@ where
         correlation_id: CorrelationId,
         state_hash: Digest,
     ) -> Result<ContractHash, Error> {
-        let registry = self.get_system_contract_registry(correlation_id, state_hash)?;
-        let auction_hash = registry.get(AUCTION).ok_or_else(|| {
-            error!("Missing system auction contract hash");
-            Error::MissingSystemContractHash(AUCTION.to_string())
-        })?;
-        Ok(*auction_hash)
+        self.get_system_entity_hash(correlation_id, state_hash, AUCTION)
     }
 
     /// Returns handle payment system contract hash.
@@ -2210,12 +4610,7 @@ where
         correlation_id: CorrelationId,
         state_hash: Digest,
     ) -> Result<ContractHash, Error> {
-        let registry = self.get_system_contract_registry(correlation_id, state_hash)?;
-        let handle_payment = registry.get(HANDLE_PAYMENT).ok_or_else(|| {
-            error!("Missing system handle payment contract hash");
-            Error::MissingSystemContractHash(HANDLE_PAYMENT.to_string())
-        })?;
-        Ok(*handle_payment)
+        self.get_system_entity_hash(correlation_id, state_hash, HANDLE_PAYMENT)
     }
 
     /// Returns standard payment system contract hash.
@@ -2224,12 +4619,21 @@ where
         correlation_id: CorrelationId,
         state_hash: Digest,
     ) -> Result<ContractHash, Error> {
-        let registry = self.get_system_contract_registry(correlation_id, state_hash)?;
-        let standard_payment = registry.get(STANDARD_PAYMENT).ok_or_else(|| {
-            error!("Missing system standard payment contract hash");
-            Error::MissingSystemContractHash(STANDARD_PAYMENT.to_string())
+        self.get_system_entity_hash(correlation_id, state_hash, STANDARD_PAYMENT)
+    }
+
+    fn get_system_entity_hash(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Digest,
+        entity_name: &str,
+    ) -> Result<ContractHash, Error> {
+        let registry = self.get_system_entity_registry(correlation_id, state_hash)?;
+        let entity_addr = registry.get(entity_name).ok_or_else(|| {
+            error!(%entity_name, "Missing system contract hash");
+            Error::MissingSystemContractHash(entity_name.to_string())
         })?;
-        Ok(*standard_payment)
+        Ok(ContractHash::new(entity_addr))
     }
 
     fn get_new_system_call_stack(&self) -> RuntimeStack {
@@ -2237,6 +4641,13 @@ where
         RuntimeStack::new_system_call_stack(max_height)
     }
 
+    fn get_new_account_call_stack(&self, account_hash: AccountHash) -> RuntimeStack {
+        let max_height = self.config.max_runtime_call_stack_height() as usize;
+        let max_contract_frames = self.config.max_contract_call_stack_height() as usize;
+        RuntimeStack::from_account_hash(account_hash, max_height)
+            .with_max_contract_frames(max_contract_frames)
+    }
+
     /// Returns the checksum registry at the given state root hash.
     pub fn get_checksum_registry(
         &self,
@@ -2282,11 +4693,14 @@ fn log_execution_result(preamble: &'static str, result: &ExecutionResult) {
             transfers,
             cost,
             execution_journal,
+            events,
+            ..
         } => {
             debug!(
                 %cost,
                 transfer_count=%transfers.len(),
                 journal_entries=%execution_journal.len(),
+                event_count=%events.len(),
                 "{}: execution success",
                 preamble
             );
@@ -2296,6 +4710,7 @@ fn log_execution_result(preamble: &'static str, result: &ExecutionResult) {
             transfers,
             cost,
             execution_journal,
+            ..
         } => {
             debug!(
                 %error,
@@ -2309,6 +4724,15 @@ fn log_execution_result(preamble: &'static str, result: &ExecutionResult) {
     }
 }
 
+/// Raises [`ExecutionWarning::UnversionedContractCall`] if `execution_kind` calls a contract.
+fn raise_unversioned_contract_call_warning(executor: &Executor, execution_kind: &ExecutionKind) {
+    if let ExecutionKind::Contract { contract_hash, .. } = execution_kind {
+        executor.raise_warning(ExecutionWarning::UnversionedContractCall {
+            contract_hash: *contract_hash,
+        });
+    }
+}
+
 fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool {
     match execution_result {
         ExecutionResult::Failure {
@@ -2316,6 +4740,8 @@ fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool
             transfers: _,
             cost: _,
             execution_journal: _,
+            read_keys: _,
+            warnings: _,
         } => match error {
             Error::Exec(err) => match err {
                 ExecError::WasmPreprocessing(_) | ExecError::UnsupportedWasmStart => true,
@@ -2365,7 +4791,10 @@ fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool
                 | ExecError::ValueTooLarge
                 | ExecError::MissingRuntimeStack
                 | ExecError::DisabledContract(_)
-                | ExecError::DisabledUnrestrictedTransfers => false,
+                | ExecError::DisabledUnrestrictedTransfers
+                | ExecError::EntityPaused(_)
+                | ExecError::ReentrantCall { .. }
+                | ExecError::EffectsSizeExceeded { .. } => false,
             },
             Error::WasmPreprocessing(_) => true,
             Error::WasmSerialization(_) => true,
@@ -2394,7 +4823,16 @@ fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool
             | Error::FailedToRetrieveUnbondingDelay
             | Error::FailedToRetrieveEraId
             | Error::MissingTrieNodeChildren(_)
-            | Error::FailedToRetrieveAccumulationPurse => false,
+            | Error::FailedToRetrieveAccumulationPurse
+            | Error::ViewNotFound(_)
+            | Error::StateRootPinned(_)
+            | Error::DuplicateMaintenanceTask(_)
+            | Error::MissingTrieNode(_)
+            | Error::CustomPaymentGasBudgetExceeded(_)
+            | Error::CustomPaymentInsufficientDeposit { .. }
+            | Error::CustomPaymentReverted(_)
+            | Error::MissingKey(_)
+            | Error::TypeMismatch(_) => false,
         },
         ExecutionResult::Success { .. } => false,
     }
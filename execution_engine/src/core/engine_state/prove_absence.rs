@@ -0,0 +1,30 @@
+//! Support for proving that a key of global state has no value.
+use casper_hashing::TrieMultiProof;
+use casper_types::{Key, StoredValue};
+
+/// Represents a result of a `prove_absence` request.
+#[derive(Debug)]
+pub enum ProveAbsenceResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// The key has a value, so its absence can't be proven.
+    KeyPresent,
+    /// Contains the proof of the key's absence.
+    Success {
+        /// The proof, which includes the trie nodes adjacent to where the key's path ends.
+        ///
+        /// It can be checked with [`TrieMultiProof::verify_absence`].
+        proof: TrieMultiProof<Key, StoredValue>,
+    },
+}
+
+impl ProveAbsenceResult {
+    /// Returns the wrapped proof if this represents a successful result.
+    pub fn into_success(self) -> Option<TrieMultiProof<Key, StoredValue>> {
+        if let Self::Success { proof } = self {
+            Some(proof)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Support for tracking the purses created and removed in a block.
+use std::collections::BTreeSet;
+
+use datasize::DataSize;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, Key, StoredValue, URefAddr,
+};
+
+use crate::shared::{execution_journal::ExecutionJournal, transform::Transform};
+
+/// The purses created and removed by the deploys of a block.
+#[derive(Debug, Default, Clone, PartialEq, Eq, DataSize)]
+pub struct PurseChanges {
+    created: BTreeSet<URefAddr>,
+    removed: BTreeSet<URefAddr>,
+}
+
+impl PurseChanges {
+    /// Returns the purses created by the transforms of `execution_journal`.
+    ///
+    /// The mint creates a purse by writing a new unit [`URef`](casper_types::URef) and the purse's
+    /// balance, whereas writing the balance of an existing purse leaves its `URef` untouched.
+    pub fn from_execution_journal(execution_journal: &ExecutionJournal) -> Self {
+        let mut unit_urefs = BTreeSet::new();
+        let mut balances = BTreeSet::new();
+        for (key, transform) in execution_journal.iter() {
+            match (key, transform) {
+                (Key::URef(uref), Transform::Write(StoredValue::CLValue(cl_value)))
+                    if *cl_value.cl_type() == CLType::Unit =>
+                {
+                    unit_urefs.insert(uref.addr());
+                }
+                (Key::Balance(addr), Transform::Write(_)) => {
+                    balances.insert(*addr);
+                }
+                _ => {}
+            }
+        }
+        PurseChanges {
+            created: unit_urefs.intersection(&balances).copied().collect(),
+            removed: BTreeSet::new(),
+        }
+    }
+
+    /// Adds the purse changes of a later execution to `self`.
+    pub fn extend(&mut self, other: PurseChanges) {
+        for addr in other.removed {
+            if !self.created.remove(&addr) {
+                self.removed.insert(addr);
+            }
+        }
+        self.created.extend(other.created);
+    }
+
+    /// Returns the addresses of the purses created.
+    pub fn created(&self) -> &BTreeSet<URefAddr> {
+        &self.created
+    }
+
+    /// Returns the addresses of the purses removed.
+    ///
+    /// No operation removes purses yet, so this is empty until one does.
+    pub fn removed(&self) -> &BTreeSet<URefAddr> {
+        &self.removed
+    }
+
+    /// Returns `true` if no purses were created or removed.
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl ToBytes for PurseChanges {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.created.to_bytes()?);
+        buffer.extend(self.removed.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.created.serialized_length() + self.removed.serialized_length()
+    }
+}
+
+impl FromBytes for PurseChanges {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (created, rem) = BTreeSet::<URefAddr>::from_bytes(bytes)?;
+        let (removed, rem) = BTreeSet::<URefAddr>::from_bytes(rem)?;
+        Ok((PurseChanges { created, removed }, rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{AccessRights, CLValue, URef, U512};
+
+    use super::*;
+
+    fn uref(addr: u8) -> URef {
+        URef::new([addr; 32], AccessRights::READ_ADD_WRITE)
+    }
+
+    fn balance_write(addr: u8) -> (Key, Transform) {
+        (
+            Key::Balance([addr; 32]),
+            Transform::Write(StoredValue::CLValue(CLValue::from_t(U512::one()).unwrap())),
+        )
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        let mut purse_changes = PurseChanges::default();
+        purse_changes.created.insert([1; 32]);
+        purse_changes.removed.insert([2; 32]);
+        bytesrepr::test_serialization_roundtrip(&purse_changes);
+    }
+
+    #[test]
+    fn should_only_record_purses_with_new_urefs() {
+        let execution_journal = ExecutionJournal::new(vec![
+            (
+                Key::URef(uref(1)),
+                Transform::Write(StoredValue::CLValue(CLValue::unit())),
+            ),
+            balance_write(1),
+            // An existing purse whose balance changed.
+            balance_write(2),
+            // A unit URef which isn't a purse.
+            (
+                Key::URef(uref(3)),
+                Transform::Write(StoredValue::CLValue(CLValue::unit())),
+            ),
+        ]);
+
+        let purse_changes = PurseChanges::from_execution_journal(&execution_journal);
+        assert_eq!(
+            purse_changes.created().iter().collect::<Vec<_>>(),
+            vec![&[1; 32]]
+        );
+        assert!(purse_changes.removed().is_empty());
+    }
+
+    #[test]
+    fn should_cancel_purses_created_and_removed() {
+        let mut purse_changes = PurseChanges::default();
+        purse_changes.created.insert([1; 32]);
+
+        let mut later = PurseChanges::default();
+        later.removed.insert([1; 32]);
+        later.removed.insert([2; 32]);
+        purse_changes.extend(later);
+
+        assert!(purse_changes.created().is_empty());
+        assert_eq!(
+            purse_changes.removed().iter().collect::<Vec<_>>(),
+            vec![&[2; 32]]
+        );
+    }
+}
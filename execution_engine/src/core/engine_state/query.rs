@@ -15,10 +15,10 @@ pub enum QueryResult {
     ValueNotFound(String),
     /// Circular reference error.
     CircularReference(String),
-    /// Depth limit reached.
-    DepthLimit {
-        /// Current depth limit.
-        depth: u64,
+    /// The query would have to follow more keys than allowed.
+    DepthLimitExceeded {
+        /// The depth limit applied to the query.
+        limit: u64,
     },
     /// Successful query.
     Success {
@@ -35,6 +35,7 @@ pub struct QueryRequest {
     state_hash: Digest,
     key: Key,
     path: Vec<String>,
+    max_query_depth: Option<u64>,
 }
 
 impl QueryRequest {
@@ -44,9 +45,19 @@ impl QueryRequest {
             state_hash,
             key,
             path,
+            max_query_depth: None,
         }
     }
 
+    /// Limits the number of keys the query may follow to `max_query_depth`.
+    ///
+    /// The limit is clamped to the maximum query depth of the engine config, which applies if no
+    /// limit is set.
+    pub fn with_max_query_depth(mut self, max_query_depth: u64) -> Self {
+        self.max_query_depth = Some(max_query_depth);
+        self
+    }
+
     /// Returns state root hash.
     pub fn state_hash(&self) -> Digest {
         self.state_hash
@@ -61,6 +72,11 @@ impl QueryRequest {
     pub fn path(&self) -> &[String] {
         &self.path
     }
+
+    /// Returns the depth limit requested for this query, if any.
+    pub fn max_query_depth(&self) -> Option<u64> {
+        self.max_query_depth
+    }
 }
 
 impl From<TrackingCopyQueryResult> for QueryResult {
@@ -74,7 +90,9 @@ impl From<TrackingCopyQueryResult> for QueryResult {
                 let value = Box::new(value);
                 QueryResult::Success { value, proofs }
             }
-            TrackingCopyQueryResult::DepthLimit { depth } => QueryResult::DepthLimit { depth },
+            TrackingCopyQueryResult::DepthLimit { depth } => {
+                QueryResult::DepthLimitExceeded { limit: depth }
+            }
         }
     }
 }
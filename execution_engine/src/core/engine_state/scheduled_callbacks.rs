@@ -0,0 +1,219 @@
+//! Support for callbacks scheduled by contracts.
+//!
+//! A contract can schedule one of its own entry points to be called every `era_interval` eras,
+//! with fixed runtime args and a gas budget paid for from a purse of the contract's choosing.  The
+//! callback is stored under a [`Key::ScheduledCallback`] until the contract cancels it.
+//!
+//! After running its maintenance tasks, a step calls each callback which is due, each in isolation
+//! from the others.  A callback which fails or runs out of gas has its writes discarded, but is
+//! still charged for the gas it used.  The whole gas budget is taken from the callback's purse into
+//! the handle payment purse before the callback is called, and once it returns the charge is moved
+//! on to the handle payment accumulation purse and the rest refunded.  A callback whose purse can't
+//! cover its gas budget is skipped.
+//!
+//! A step calls at most a configured number of callbacks, the most overdue first.  Due callbacks
+//! left over stay due, and so are called ahead of the others by the following steps.
+use datasize::DataSize;
+use serde::Serialize;
+
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractHash, EraId, Key, RuntimeArgs, URef, U512,
+};
+
+/// A callback scheduled by a contract, as stored under a [`Key::ScheduledCallback`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledCallback {
+    contract_hash: ContractHash,
+    entry_point: String,
+    args: RuntimeArgs,
+    account_hash: AccountHash,
+    purse: URef,
+    era_interval: u64,
+    gas_budget: u64,
+    next_era_id: EraId,
+}
+
+impl ScheduledCallback {
+    /// Creates a new scheduled callback.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        contract_hash: ContractHash,
+        entry_point: String,
+        args: RuntimeArgs,
+        account_hash: AccountHash,
+        purse: URef,
+        era_interval: u64,
+        gas_budget: u64,
+        next_era_id: EraId,
+    ) -> Self {
+        ScheduledCallback {
+            contract_hash,
+            entry_point,
+            args,
+            account_hash,
+            purse,
+            era_interval,
+            gas_budget,
+            next_era_id,
+        }
+    }
+
+    /// Returns the hash of the contract whose entry point is called.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    /// Returns the name of the entry point called.
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    /// Returns the runtime args the entry point is called with.
+    pub fn args(&self) -> &RuntimeArgs {
+        &self.args
+    }
+
+    /// Returns the account which scheduled the callback, and in whose context it's called.
+    pub fn account_hash(&self) -> AccountHash {
+        self.account_hash
+    }
+
+    /// Returns the purse the callback's gas is paid from.
+    pub fn purse(&self) -> URef {
+        self.purse
+    }
+
+    /// Returns the number of eras between calls.
+    pub fn era_interval(&self) -> u64 {
+        self.era_interval
+    }
+
+    /// Returns the gas budget of each call.
+    pub fn gas_budget(&self) -> u64 {
+        self.gas_budget
+    }
+
+    /// Returns the era whose step calls the callback next.
+    pub fn next_era_id(&self) -> EraId {
+        self.next_era_id
+    }
+
+    /// Returns `true` if the step starting `era_id` should call the callback.
+    pub fn is_due(&self, era_id: EraId) -> bool {
+        self.next_era_id <= era_id
+    }
+
+    /// Schedules the next call `era_interval` eras after `era_id`.
+    pub fn reschedule(&mut self, era_id: EraId) {
+        self.next_era_id = era_id.saturating_add(self.era_interval);
+    }
+}
+
+impl CLTyped for ScheduledCallback {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for ScheduledCallback {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.contract_hash.to_bytes()?);
+        buffer.extend(self.entry_point.to_bytes()?);
+        buffer.extend(self.args.to_bytes()?);
+        buffer.extend(self.account_hash.to_bytes()?);
+        buffer.extend(self.purse.to_bytes()?);
+        buffer.extend(self.era_interval.to_bytes()?);
+        buffer.extend(self.gas_budget.to_bytes()?);
+        buffer.extend(self.next_era_id.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_hash.serialized_length()
+            + self.entry_point.serialized_length()
+            + self.args.serialized_length()
+            + self.account_hash.serialized_length()
+            + self.purse.serialized_length()
+            + self.era_interval.serialized_length()
+            + self.gas_budget.serialized_length()
+            + self.next_era_id.serialized_length()
+    }
+}
+
+impl FromBytes for ScheduledCallback {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_hash, rem) = ContractHash::from_bytes(bytes)?;
+        let (entry_point, rem) = String::from_bytes(rem)?;
+        let (args, rem) = RuntimeArgs::from_bytes(rem)?;
+        let (account_hash, rem) = AccountHash::from_bytes(rem)?;
+        let (purse, rem) = URef::from_bytes(rem)?;
+        let (era_interval, rem) = u64::from_bytes(rem)?;
+        let (gas_budget, rem) = u64::from_bytes(rem)?;
+        let (next_era_id, rem) = EraId::from_bytes(rem)?;
+        Ok((
+            ScheduledCallback {
+                contract_hash,
+                entry_point,
+                args,
+                account_hash,
+                purse,
+                era_interval,
+                gas_budget,
+                next_era_id,
+            },
+            rem,
+        ))
+    }
+}
+
+/// The outcome of a scheduled callback called by a step.
+#[derive(DataSize, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScheduledCallbackResult {
+    /// The key the callback is stored under.
+    pub key: Key,
+    /// The name of the entry point called.
+    pub entry_point: String,
+    /// The motes charged to the callback's purse.
+    pub cost: U512,
+    /// The error the callback failed with, or `None` if it succeeded.
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{runtime_args, AccessRights, RuntimeArgs};
+
+    use super::*;
+
+    fn scheduled_callback() -> ScheduledCallback {
+        ScheduledCallback::new(
+            ContractHash::new([1; 32]),
+            "tick".to_string(),
+            runtime_args! { "amount" => 42u64 },
+            AccountHash::new([2; 32]),
+            URef::new([3; 32], AccessRights::READ_ADD_WRITE),
+            3,
+            1_000,
+            EraId::from(5),
+        )
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&scheduled_callback());
+    }
+
+    #[test]
+    fn should_reschedule_after_interval() {
+        let mut callback = scheduled_callback();
+        assert!(!callback.is_due(EraId::from(4)));
+        assert!(callback.is_due(EraId::from(5)));
+
+        callback.reschedule(EraId::from(5));
+        assert_eq!(callback.next_era_id(), EraId::from(8));
+        assert!(!callback.is_due(EraId::from(7)));
+    }
+}
@@ -0,0 +1,268 @@
+//! Support for checking a sequence of key management operations on an account before submitting
+//! it, so a sequence which would lock the account can be rejected up front.
+use std::fmt::{self, Display, Formatter};
+
+use casper_hashing::Digest;
+use casper_types::account::{
+    Account, AccountHash, ActionThresholds, ActionType, AddKeyFailure, AssociatedKeys,
+    RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure, Weight,
+};
+
+/// An operation on the associated keys or action thresholds of an account, as performed by the
+/// key management host functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyManagementOp {
+    /// Associates a new key with the account.
+    AddKey {
+        /// The key to associate.
+        account_hash: AccountHash,
+        /// The weight of the key.
+        weight: Weight,
+    },
+    /// Removes an associated key from the account.
+    RemoveKey {
+        /// The key to remove.
+        account_hash: AccountHash,
+    },
+    /// Changes the weight of an associated key.
+    UpdateKeyWeight {
+        /// The key to update.
+        account_hash: AccountHash,
+        /// The new weight of the key.
+        weight: Weight,
+    },
+    /// Sets the deployment threshold.
+    SetDeploymentThreshold(Weight),
+    /// Sets the key management threshold.
+    SetKeyManagementThreshold(Weight),
+}
+
+/// The reason a [`KeyManagementOp`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyManagementOpFailure {
+    /// Adding a key fails.
+    AddKey(AddKeyFailure),
+    /// Removing a key fails.
+    RemoveKey(RemoveKeyFailure),
+    /// Updating the weight of a key fails.
+    UpdateKey(UpdateKeyFailure),
+    /// Setting a threshold fails.
+    SetThreshold(SetThresholdFailure),
+}
+
+impl Display for KeyManagementOpFailure {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyManagementOpFailure::AddKey(failure) => Display::fmt(failure, formatter),
+            KeyManagementOpFailure::RemoveKey(failure) => Display::fmt(failure, formatter),
+            KeyManagementOpFailure::UpdateKey(failure) => Display::fmt(failure, formatter),
+            KeyManagementOpFailure::SetThreshold(failure) => Display::fmt(failure, formatter),
+        }
+    }
+}
+
+/// Represents a request to simulate key management operations on an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulateKeyManagementRequest {
+    state_hash: Digest,
+    account_hash: AccountHash,
+    operations: Vec<KeyManagementOp>,
+}
+
+impl SimulateKeyManagementRequest {
+    /// Creates new request.
+    pub fn new(
+        state_hash: Digest,
+        account_hash: AccountHash,
+        operations: Vec<KeyManagementOp>,
+    ) -> Self {
+        SimulateKeyManagementRequest {
+            state_hash,
+            account_hash,
+            operations,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the hash of the account the operations are applied to.
+    pub fn account_hash(&self) -> AccountHash {
+        self.account_hash
+    }
+
+    /// Returns the operations, in the order they are applied.
+    pub fn operations(&self) -> &[KeyManagementOp] {
+        &self.operations
+    }
+}
+
+/// Represents a result of a `simulate_key_management` request.
+#[derive(Debug)]
+pub enum SimulateKeyManagementResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// The account doesn't exist.
+    AccountNotFound(AccountHash),
+    /// An operation fails as it would when executed.
+    OperationFailed {
+        /// Index of the failing operation.
+        index: usize,
+        /// Why the operation fails.
+        failure: KeyManagementOpFailure,
+    },
+    /// All operations succeed, but the associated keys can't together meet the key management
+    /// threshold any more, so the account could never manage its keys again.
+    LockedOut {
+        /// The resulting associated keys.
+        associated_keys: AssociatedKeys,
+        /// The resulting action thresholds.
+        action_thresholds: ActionThresholds,
+    },
+    /// All operations succeed and the account can still manage its keys.
+    Success {
+        /// The resulting associated keys.
+        associated_keys: AssociatedKeys,
+        /// The resulting action thresholds.
+        action_thresholds: ActionThresholds,
+    },
+}
+
+impl SimulateKeyManagementResult {
+    /// Returns `true` if the operations can safely be applied.
+    pub fn is_success(&self) -> bool {
+        matches!(self, SimulateKeyManagementResult::Success { .. })
+    }
+}
+
+/// Applies `operations` to `account` as the key management host functions would for a
+/// non-administrator account.
+pub(crate) fn simulate(
+    mut account: Account,
+    operations: &[KeyManagementOp],
+    max_associated_keys: u32,
+) -> SimulateKeyManagementResult {
+    for (index, operation) in operations.iter().enumerate() {
+        let outcome = match *operation {
+            KeyManagementOp::AddKey {
+                account_hash,
+                weight,
+            } => {
+                if account.associated_keys().len() >= max_associated_keys as usize {
+                    Err(KeyManagementOpFailure::AddKey(AddKeyFailure::MaxKeysLimit))
+                } else {
+                    account
+                        .add_associated_key(account_hash, weight)
+                        .map_err(KeyManagementOpFailure::AddKey)
+                }
+            }
+            KeyManagementOp::RemoveKey { account_hash } => account
+                .remove_associated_key(account_hash)
+                .map_err(KeyManagementOpFailure::RemoveKey),
+            KeyManagementOp::UpdateKeyWeight {
+                account_hash,
+                weight,
+            } => account
+                .update_associated_key(account_hash, weight)
+                .map_err(KeyManagementOpFailure::UpdateKey),
+            KeyManagementOp::SetDeploymentThreshold(weight) => account
+                .set_action_threshold(ActionType::Deployment, weight)
+                .map_err(KeyManagementOpFailure::SetThreshold),
+            KeyManagementOp::SetKeyManagementThreshold(weight) => account
+                .set_action_threshold(ActionType::KeyManagement, weight)
+                .map_err(KeyManagementOpFailure::SetThreshold),
+        };
+        if let Err(failure) = outcome {
+            return SimulateKeyManagementResult::OperationFailed { index, failure };
+        }
+    }
+
+    let associated_keys = account.associated_keys().clone();
+    let action_thresholds = account.action_thresholds().clone();
+    // The key management threshold is never below the deployment threshold, so meeting it means
+    // the account can still both deploy and manage its keys.
+    if associated_keys.total_keys_weight() < *action_thresholds.key_management() {
+        SimulateKeyManagementResult::LockedOut {
+            associated_keys,
+            action_thresholds,
+        }
+    } else {
+        SimulateKeyManagementResult::Success {
+            associated_keys,
+            action_thresholds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::URef;
+
+    use super::*;
+
+    const OWNER: AccountHash = AccountHash::new([1; 32]);
+    const OTHER: AccountHash = AccountHash::new([2; 32]);
+
+    fn account() -> Account {
+        Account::create(OWNER, Default::default(), URef::default())
+    }
+
+    #[test]
+    fn should_accept_safe_key_rotation() {
+        let operations = [
+            KeyManagementOp::AddKey {
+                account_hash: OTHER,
+                weight: Weight::new(1),
+            },
+            KeyManagementOp::RemoveKey {
+                account_hash: OWNER,
+            },
+        ];
+        let result = simulate(account(), &operations, 10);
+        assert!(result.is_success(), "{:?}", result);
+    }
+
+    #[test]
+    fn should_report_failing_operation() {
+        let operations = [
+            KeyManagementOp::AddKey {
+                account_hash: OTHER,
+                weight: Weight::new(1),
+            },
+            KeyManagementOp::SetKeyManagementThreshold(Weight::new(2)),
+            KeyManagementOp::RemoveKey {
+                account_hash: OWNER,
+            },
+        ];
+        match simulate(account(), &operations, 10) {
+            SimulateKeyManagementResult::OperationFailed { index, failure } => {
+                assert_eq!(index, 2);
+                assert_eq!(
+                    failure,
+                    KeyManagementOpFailure::RemoveKey(RemoveKeyFailure::ThresholdViolation)
+                );
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_enforce_max_associated_keys() {
+        let operations = [KeyManagementOp::AddKey {
+            account_hash: OTHER,
+            weight: Weight::new(1),
+        }];
+        match simulate(account(), &operations, 1) {
+            SimulateKeyManagementResult::OperationFailed { index, failure } => {
+                assert_eq!(index, 0);
+                assert_eq!(
+                    failure,
+                    KeyManagementOpFailure::AddKey(AddKeyFailure::MaxKeysLimit)
+                );
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
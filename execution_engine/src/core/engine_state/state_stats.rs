@@ -0,0 +1,160 @@
+//! Support for reporting the number and size of the entries in global state.
+use std::collections::BTreeMap;
+
+use casper_hashing::Digest;
+use casper_types::KeyTag;
+
+/// Represents a request for the statistics of the global state under a state root hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateStatsRequest {
+    state_hash: Digest,
+}
+
+impl StateStatsRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest) -> Self {
+        StateStatsRequest { state_hash }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+}
+
+/// The number of global state entries whose keys share a tag, and their total serialized sizes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyTagStats {
+    count: u64,
+    keys_size: u64,
+    values_size: u64,
+}
+
+impl KeyTagStats {
+    /// Returns the number of entries.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the total serialized size in bytes of the keys of the entries.
+    pub fn keys_size(&self) -> u64 {
+        self.keys_size
+    }
+
+    /// Returns the total serialized size in bytes of the values of the entries.
+    pub fn values_size(&self) -> u64 {
+        self.values_size
+    }
+
+    /// Returns the total serialized size in bytes of the keys and values of the entries.
+    pub fn total_size(&self) -> u64 {
+        self.keys_size.saturating_add(self.values_size)
+    }
+
+    pub(crate) fn record(&mut self, key_size: usize, value_size: usize) {
+        self.count = self.count.saturating_add(1);
+        self.keys_size = self.keys_size.saturating_add(key_size as u64);
+        self.values_size = self.values_size.saturating_add(value_size as u64);
+    }
+
+    fn merge(&mut self, other: &KeyTagStats) {
+        self.count = self.count.saturating_add(other.count);
+        self.keys_size = self.keys_size.saturating_add(other.keys_size);
+        self.values_size = self.values_size.saturating_add(other.values_size);
+    }
+}
+
+/// The statistics of the entries of global state under a state root hash, grouped by key tag.
+///
+/// Sizes are those of the serialized keys and values, and don't include the overhead of the trie
+/// nodes or of the database storing them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateStats {
+    key_tag_stats: BTreeMap<KeyTag, KeyTagStats>,
+}
+
+impl StateStats {
+    /// Returns the statistics of the entries whose keys are tagged `key_tag`.
+    pub fn get(&self, key_tag: KeyTag) -> KeyTagStats {
+        self.key_tag_stats
+            .get(&key_tag)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the statistics of the key tags with at least one entry, in
+    /// ascending order of key tag.
+    pub fn iter(&self) -> impl Iterator<Item = (&KeyTag, &KeyTagStats)> {
+        self.key_tag_stats.iter()
+    }
+
+    /// Returns the statistics of all entries.
+    pub fn total(&self) -> KeyTagStats {
+        let mut total = KeyTagStats::default();
+        for key_tag_stats in self.key_tag_stats.values() {
+            total.merge(key_tag_stats);
+        }
+        total
+    }
+
+    pub(crate) fn record(&mut self, key_tag: KeyTag, key_size: usize, value_size: usize) {
+        self.key_tag_stats
+            .entry(key_tag)
+            .or_default()
+            .record(key_size, value_size);
+    }
+}
+
+/// Represents a result of a `state_stats` request.
+#[derive(Debug)]
+pub enum StateStatsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the statistics of the global state.
+    Success {
+        /// The state statistics.
+        state_stats: StateStats,
+    },
+}
+
+impl StateStatsResult {
+    /// Returns wrapped [`StateStats`] if this represents a successful result.
+    pub fn into_success(self) -> Option<StateStats> {
+        if let Self::Success { state_stats } = self {
+            Some(state_stats)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_total_stats_of_all_key_tags() {
+        let mut state_stats = StateStats::default();
+        state_stats.record(KeyTag::Account, 33, 100);
+        state_stats.record(KeyTag::Account, 33, 200);
+        state_stats.record(KeyTag::Hash, 33, 50);
+
+        let accounts = state_stats.get(KeyTag::Account);
+        assert_eq!(accounts.count(), 2);
+        assert_eq!(accounts.keys_size(), 66);
+        assert_eq!(accounts.values_size(), 300);
+        assert_eq!(accounts.total_size(), 366);
+        assert_eq!(state_stats.get(KeyTag::Bid), KeyTagStats::default());
+        assert_eq!(
+            state_stats
+                .iter()
+                .map(|(key_tag, _)| *key_tag)
+                .collect::<Vec<_>>(),
+            vec![KeyTag::Account, KeyTag::Hash]
+        );
+
+        let total = state_stats.total();
+        assert_eq!(total.count(), 3);
+        assert_eq!(total.total_size(), 99 + 350);
+    }
+}
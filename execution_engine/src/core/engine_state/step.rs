@@ -4,11 +4,22 @@
 //! rewards.
 use std::{collections::BTreeMap, vec::Vec};
 
+use datasize::DataSize;
+use serde::Serialize;
+
 use casper_hashing::Digest;
-use casper_types::{bytesrepr, CLValueError, EraId, ProtocolVersion, PublicKey};
+use casper_types::{
+    bytesrepr,
+    system::auction::{Bids, EraValidators},
+    CLValueError, EraId, ProtocolVersion, PublicKey, U512,
+};
 
 use crate::{
-    core::{engine_state::Error, execution, runtime::stack::RuntimeStackOverflow},
+    core::{
+        engine_state::{scheduled_callbacks::ScheduledCallbackResult, Error},
+        execution,
+        runtime::stack::RuntimeStackOverflow,
+    },
     shared::execution_journal::ExecutionJournal,
 };
 
@@ -189,6 +200,106 @@ impl From<RuntimeStackOverflow> for StepError {
     }
 }
 
+/// The weight of a validator in the latest era of the seigniorage recipients snapshot before and
+/// after a step.
+#[derive(DataSize, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidatorWeightChange {
+    /// Weight before the step, or `None` if the validator wasn't in the latest era.
+    pub before: Option<U512>,
+    /// Weight after the step, or `None` if the validator isn't in the latest era.
+    pub after: Option<U512>,
+}
+
+/// Describes what a step did to the auction.
+#[derive(DataSize, Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StepReport {
+    /// Validators whose stake was slashed.
+    pub slashed: Vec<PublicKey>,
+    /// Validators whose bid was deactivated by eviction.
+    pub evicted: Vec<PublicKey>,
+    /// The era added to the seigniorage recipients snapshot by running the auction, if any.
+    pub new_snapshot_era: Option<EraId>,
+    /// Validators whose weight in the latest snapshot era differs from before the step.
+    pub validator_weight_changes: BTreeMap<PublicKey, ValidatorWeightChange>,
+    /// The names of the maintenance tasks which failed, and whose writes were discarded.
+    pub failed_maintenance_tasks: Vec<String>,
+    /// The outcomes of the scheduled callbacks called by the step.
+    pub scheduled_callbacks: Vec<ScheduledCallbackResult>,
+}
+
+impl StepReport {
+    /// Creates a report by comparing the bids and era validators before and after a step.
+    pub(crate) fn new(
+        step_request: &StepRequest,
+        bids_before: &Bids,
+        bids_after: &Bids,
+        era_validators_before: &EraValidators,
+        era_validators_after: &EraValidators,
+        failed_maintenance_tasks: Vec<String>,
+        scheduled_callbacks: Vec<ScheduledCallbackResult>,
+    ) -> Self {
+        let staked_amount = |bids: &Bids, public_key: &PublicKey| {
+            bids.get(public_key).map(|bid| *bid.staked_amount())
+        };
+
+        let slashed = step_request
+            .slash_items
+            .iter()
+            .map(|item| &item.validator_id)
+            .filter(|public_key| {
+                staked_amount(bids_before, public_key).unwrap_or_default()
+                    > staked_amount(bids_after, public_key).unwrap_or_default()
+            })
+            .cloned()
+            .collect();
+
+        let evicted = step_request
+            .evict_items
+            .iter()
+            .map(|item| &item.validator_id)
+            .filter(|public_key| {
+                let was_active = bids_before
+                    .get(*public_key)
+                    .map_or(false, |bid| !bid.inactive());
+                let is_active = bids_after
+                    .get(*public_key)
+                    .map_or(false, |bid| !bid.inactive());
+                was_active && !is_active
+            })
+            .cloned()
+            .collect();
+
+        let new_snapshot_era = era_validators_after
+            .keys()
+            .next_back()
+            .filter(|era_id| !era_validators_before.contains_key(era_id))
+            .copied();
+
+        let empty = BTreeMap::new();
+        let weights_before = era_validators_before.values().next_back().unwrap_or(&empty);
+        let weights_after = era_validators_after.values().next_back().unwrap_or(&empty);
+        let validator_weight_changes = weights_before
+            .keys()
+            .chain(weights_after.keys())
+            .filter_map(|public_key| {
+                let before = weights_before.get(public_key).copied();
+                let after = weights_after.get(public_key).copied();
+                (before != after)
+                    .then(|| (public_key.clone(), ValidatorWeightChange { before, after }))
+            })
+            .collect();
+
+        StepReport {
+            slashed,
+            evicted,
+            new_snapshot_era,
+            validator_weight_changes,
+            failed_maintenance_tasks,
+            scheduled_callbacks,
+        }
+    }
+}
+
 /// Represents a successfully executed step request.
 #[derive(Debug)]
 pub struct StepSuccess {
@@ -196,4 +307,6 @@ pub struct StepSuccess {
     pub post_state_hash: Digest,
     /// Effects of executing a step request.
     pub execution_journal: ExecutionJournal,
+    /// What the step did to the auction.
+    pub step_report: StepReport,
 }
@@ -0,0 +1,86 @@
+//! Support for calling contracts registered as system contract extensions.
+use casper_hashing::Digest;
+use casper_types::{BlockTime, ProtocolVersion, RuntimeArgs};
+
+use super::{execution_effect::ExecutionEffect, Error};
+
+/// Represents a request to call an entry point of a system contract extension as the system
+/// account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemContractExtensionCallRequest {
+    pre_state_hash: Digest,
+    protocol_version: ProtocolVersion,
+    contract_name: String,
+    entry_point: String,
+    args: RuntimeArgs,
+    blocktime: BlockTime,
+}
+
+impl SystemContractExtensionCallRequest {
+    /// Creates new request.
+    pub fn new(
+        pre_state_hash: Digest,
+        protocol_version: ProtocolVersion,
+        contract_name: String,
+        entry_point: String,
+        args: RuntimeArgs,
+        blocktime: BlockTime,
+    ) -> Self {
+        SystemContractExtensionCallRequest {
+            pre_state_hash,
+            protocol_version,
+            contract_name,
+            entry_point,
+            args,
+            blocktime,
+        }
+    }
+
+    /// Returns the state root hash the call is executed against.
+    pub fn pre_state_hash(&self) -> Digest {
+        self.pre_state_hash
+    }
+
+    /// Returns the protocol version.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the name the contract is registered under.
+    pub fn contract_name(&self) -> &str {
+        &self.contract_name
+    }
+
+    /// Returns the name of the entry point to call.
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    /// Returns the runtime args passed to the entry point.
+    pub fn args(&self) -> &RuntimeArgs {
+        &self.args
+    }
+
+    /// Returns the block time the call is executed at.
+    pub fn blocktime(&self) -> BlockTime {
+        self.blocktime
+    }
+}
+
+/// Represents a result of a `commit_system_contract_extension_call` request.
+#[derive(Debug)]
+pub enum SystemContractExtensionCallResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// No extension is registered under the requested name.
+    NotRegistered(String),
+    /// The call failed, so nothing was committed.
+    Failure(Error),
+    /// The call succeeded and its effects were committed.
+    Success {
+        /// New state root hash generated after effects were applied.
+        post_state_hash: Digest,
+        /// Effects of the call.
+        execution_effect: ExecutionEffect,
+    },
+}
@@ -7,9 +7,14 @@ use serde::{Deserialize, Serialize};
 
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
+    system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
     CLType, CLTyped, ContractHash,
 };
 
+/// The names of the system contracts every network has, as opposed to extensions registered by a
+/// protocol upgrade.
+pub const CORE_SYSTEM_CONTRACT_NAMES: [&str; 4] = [MINT, AUCTION, HANDLE_PAYMENT, STANDARD_PAYMENT];
+
 /// The system contract registry.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Debug, DataSize)]
 pub struct SystemContractRegistry(BTreeMap<String, ContractHash>);
@@ -31,6 +36,18 @@ impl SystemContractRegistry {
         self.0.get(contract_name)
     }
 
+    /// Returns an iterator over all registered contracts.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ContractHash)> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over the contracts registered in addition to the core system contracts.
+    pub fn extensions(&self) -> impl Iterator<Item = (&String, &ContractHash)> {
+        self.0
+            .iter()
+            .filter(|(contract_name, _)| !is_core_system_contract(contract_name))
+    }
+
     /// Returns `true` if the given contract hash exists as a value in the registry.
     pub fn has_contract_hash(&self, contract_hash: &ContractHash) -> bool {
         self.0
@@ -39,6 +56,11 @@ impl SystemContractRegistry {
     }
 }
 
+/// Returns `true` if `contract_name` is the name of one of the core system contracts.
+pub fn is_core_system_contract(contract_name: &str) -> bool {
+    CORE_SYSTEM_CONTRACT_NAMES.contains(&contract_name)
+}
+
 impl ToBytes for SystemContractRegistry {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         self.0.to_bytes()
@@ -72,4 +94,16 @@ mod tests {
         system_contract_registry.insert("a".to_string(), ContractHash::new([9; 32]));
         bytesrepr::test_serialization_roundtrip(&system_contract_registry);
     }
+
+    #[test]
+    fn should_list_only_extensions() {
+        let mut system_contract_registry = SystemContractRegistry::new();
+        system_contract_registry.insert(MINT.to_string(), ContractHash::new([1; 32]));
+        system_contract_registry.insert("oracle".to_string(), ContractHash::new([2; 32]));
+        let extensions: Vec<_> = system_contract_registry.extensions().collect();
+        assert_eq!(
+            extensions,
+            vec![(&"oracle".to_string(), &ContractHash::new([2; 32]))]
+        );
+    }
 }
@@ -0,0 +1,162 @@
+//! The registry of system entities.
+//!
+//! This supersedes the [`SystemContractRegistry`]: every system entity is recorded under its
+//! entity key, a [`Key::Hash`], rather than a bare contract hash.  Both formats are stored under
+//! [`Key::SystemContractRegistry`], so global state written before the migration can still be read.
+
+use std::collections::BTreeMap;
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue, CLValueError, ContractHash, HashAddr, Key,
+};
+
+use super::system_contract_registry::{self, SystemContractRegistry};
+
+/// The system entity registry.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Debug, DataSize)]
+pub struct SystemEntityRegistry(BTreeMap<String, Key>);
+
+impl SystemEntityRegistry {
+    /// Returns a new `SystemEntityRegistry`.
+    #[allow(clippy::new_without_default)] // Consistent with `SystemContractRegistry::new()`.
+    pub fn new() -> Self {
+        SystemEntityRegistry(BTreeMap::new())
+    }
+
+    /// Decodes a registry stored in global state, accepting both the current format and the
+    /// legacy [`SystemContractRegistry`] format.
+    pub fn from_cl_value(cl_value: CLValue) -> Result<Self, CLValueError> {
+        if is_legacy_registry(&cl_value) {
+            trace!("reading system contract registry in deprecated format");
+            let legacy_registry: SystemContractRegistry = cl_value.into_t()?;
+            return Ok(legacy_registry.into());
+        }
+        cl_value.into_t()
+    }
+
+    /// Inserts an entity's address into the registry.
+    pub fn insert(&mut self, entity_name: String, entity_addr: HashAddr) {
+        self.0.insert(entity_name, Key::Hash(entity_addr));
+    }
+
+    /// Gets an entity's address from the registry.
+    pub fn get(&self, entity_name: &str) -> Option<HashAddr> {
+        self.0.get(entity_name).and_then(|key| key.into_hash())
+    }
+
+    /// Returns an iterator over the entities registered in addition to the core system contracts.
+    pub fn extensions(&self) -> impl Iterator<Item = (&String, HashAddr)> {
+        self.iter().filter(|(entity_name, _)| {
+            !system_contract_registry::is_core_system_contract(entity_name)
+        })
+    }
+
+    /// Returns `true` if the given address exists as a value in the registry.
+    pub fn has_entity_addr(&self, entity_addr: &HashAddr) -> bool {
+        self.0
+            .values()
+            .any(|key| key.into_hash() == Some(*entity_addr))
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&String, HashAddr)> {
+        self.0
+            .iter()
+            .filter_map(|(entity_name, key)| Some((entity_name, key.into_hash()?)))
+    }
+}
+
+/// Returns `true` if `cl_value` holds a registry in the legacy [`SystemContractRegistry`] format.
+pub fn is_legacy_registry(cl_value: &CLValue) -> bool {
+    *cl_value.cl_type() == SystemContractRegistry::cl_type()
+}
+
+impl From<SystemContractRegistry> for SystemEntityRegistry {
+    fn from(legacy_registry: SystemContractRegistry) -> Self {
+        let mut registry = SystemEntityRegistry::new();
+        for (contract_name, contract_hash) in legacy_registry.iter() {
+            registry.insert(contract_name.clone(), contract_hash.value());
+        }
+        registry
+    }
+}
+
+impl From<SystemEntityRegistry> for SystemContractRegistry {
+    fn from(registry: SystemEntityRegistry) -> Self {
+        let mut legacy_registry = SystemContractRegistry::new();
+        for (entity_name, entity_addr) in registry.iter() {
+            legacy_registry.insert(entity_name.clone(), ContractHash::new(entity_addr));
+        }
+        legacy_registry
+    }
+}
+
+impl ToBytes for SystemEntityRegistry {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for SystemEntityRegistry {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (inner, remainder) = BTreeMap::from_bytes(bytes)?;
+        Ok((SystemEntityRegistry(inner), remainder))
+    }
+}
+
+impl CLTyped for SystemEntityRegistry {
+    fn cl_type() -> CLType {
+        BTreeMap::<String, Key>::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::system::MINT;
+
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        let mut registry = SystemEntityRegistry::new();
+        registry.insert("a".to_string(), [9; 32]);
+        bytesrepr::test_serialization_roundtrip(&registry);
+    }
+
+    #[test]
+    fn should_read_both_formats() {
+        let mut legacy_registry = SystemContractRegistry::new();
+        legacy_registry.insert(MINT.to_string(), ContractHash::new([1; 32]));
+        legacy_registry.insert("oracle".to_string(), ContractHash::new([2; 32]));
+        let registry = SystemEntityRegistry::from(legacy_registry.clone());
+
+        let legacy_cl_value = CLValue::from_t(legacy_registry.clone()).unwrap();
+        assert!(is_legacy_registry(&legacy_cl_value));
+        assert_eq!(
+            SystemEntityRegistry::from_cl_value(legacy_cl_value).unwrap(),
+            registry
+        );
+
+        let cl_value = CLValue::from_t(registry.clone()).unwrap();
+        assert!(!is_legacy_registry(&cl_value));
+        assert_eq!(
+            SystemEntityRegistry::from_cl_value(cl_value).unwrap(),
+            registry
+        );
+
+        assert_eq!(registry.get(MINT), Some([1; 32]));
+        assert_eq!(
+            registry.extensions().collect::<Vec<_>>(),
+            vec![(&"oracle".to_string(), [2; 32])]
+        );
+        assert_eq!(SystemContractRegistry::from(registry), legacy_registry);
+    }
+}
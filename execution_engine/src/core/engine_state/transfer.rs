@@ -42,6 +42,9 @@ pub(crate) enum NewTransferTargetMode {
     },
     /// Native transfer arguments resolved into a transfer to a purse.
     PurseExists(URef),
+    /// Native transfer arguments resolved into a transfer to a purse in the initiator's named
+    /// keys.
+    OwnPurse(URef),
     /// Native transfer arguments resolved into a transfer to a new account.
     CreateAccount(AccountHash),
 }
@@ -120,6 +123,7 @@ impl TryFrom<TransferArgs> for RuntimeArgs {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransferRuntimeArgsBuilder {
     inner: RuntimeArgs,
+    named_purse_targets: bool,
 }
 
 impl TransferRuntimeArgsBuilder {
@@ -129,9 +133,18 @@ impl TransferRuntimeArgsBuilder {
     pub fn new(imputed_runtime_args: RuntimeArgs) -> TransferRuntimeArgsBuilder {
         TransferRuntimeArgsBuilder {
             inner: imputed_runtime_args,
+            named_purse_targets: true,
         }
     }
 
+    /// Sets whether the "target" argument may name a purse in the initiator's named keys.
+    ///
+    /// Enabled by default.
+    pub fn with_named_purse_targets(mut self, named_purse_targets: bool) -> Self {
+        self.named_purse_targets = named_purse_targets;
+        self
+    }
+
     /// Checks if a purse exists.
     fn purse_exists<R>(
         &self,
@@ -225,6 +238,8 @@ impl TransferRuntimeArgsBuilder {
     ///
     /// User has to specify a "target" argument which must be one of the following types:
     ///   * an existing purse [`URef`]
+    ///   * a [`String`] naming an existing purse in the initiator's named keys, unless disabled
+    ///     through [`TransferRuntimeArgsBuilder::with_named_purse_targets`]
     ///   * a 32-byte array, interpreted as an account hash
     ///   * a [`Key::Account`], from which the account hash is extracted
     ///   * a [`PublicKey`], which is converted to an account hash
@@ -235,6 +250,7 @@ impl TransferRuntimeArgsBuilder {
     /// Returns [`NewTransferTargetMode`] with a resolved variant.
     pub(super) fn resolve_transfer_target_mode<R>(
         &mut self,
+        account: &Account,
         correlation_id: CorrelationId,
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
     ) -> Result<NewTransferTargetMode, Error>
@@ -255,6 +271,34 @@ impl TransferRuntimeArgsBuilder {
 
                 return Ok(NewTransferTargetMode::PurseExists(uref));
             }
+            Some(cl_value) if self.named_purse_targets && *cl_value.cl_type() == CLType::String => {
+                let name: String = cl_value.clone().into_t().map_err(Error::reverter)?;
+
+                let uref = match account.named_keys().get(&name) {
+                    Some(Key::URef(uref)) => *uref,
+                    Some(key) => {
+                        return Err(Error::Exec(ExecError::TypeMismatch(
+                            casper_types::StoredValueTypeMismatch::new(
+                                "Key::URef".to_string(),
+                                key.type_string(),
+                            ),
+                        )))
+                    }
+                    None => return Err(Error::reverter(ApiError::MissingKey)),
+                };
+
+                if !uref.is_addable() {
+                    return Err(Error::Exec(ExecError::InvalidAccess {
+                        required: AccessRights::ADD,
+                    }));
+                }
+
+                if !self.purse_exists(uref, correlation_id, tracking_copy) {
+                    return Err(Error::reverter(ApiError::InvalidPurse));
+                }
+
+                return Ok(NewTransferTargetMode::OwnPurse(uref));
+            }
             Some(cl_value) if *cl_value.cl_type() == CLType::ByteArray(32) => {
                 let account_hash: AccountHash =
                     cl_value.clone().into_t().map_err(Error::reverter)?;
@@ -341,14 +385,17 @@ impl TransferRuntimeArgsBuilder {
         R: StateReader<Key, StoredValue>,
         R::Error: Into<ExecError>,
     {
-        let (to, target_uref) = match self
-            .resolve_transfer_target_mode(correlation_id, Rc::clone(&tracking_copy))?
-        {
+        let (to, target_uref) = match self.resolve_transfer_target_mode(
+            from,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+        )? {
             NewTransferTargetMode::ExistingAccount {
                 main_purse: purse_uref,
                 target_account_hash: target_account,
             } => (Some(target_account), purse_uref),
             NewTransferTargetMode::PurseExists(purse_uref) => (None, purse_uref),
+            NewTransferTargetMode::OwnPurse(purse_uref) => (Some(from.account_hash()), purse_uref),
             NewTransferTargetMode::CreateAccount(_) => {
                 // Method "build()" is called after `resolve_transfer_target_mode` is first called
                 // and handled by creating a new account. Calling `resolve_transfer_target_mode`
@@ -6,10 +6,14 @@ use thiserror::Error;
 
 use casper_hashing::Digest;
 use casper_types::{
-    bytesrepr::{self, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes},
     contracts::NamedKeys,
-    system::{handle_payment::ACCUMULATION_PURSE_KEY, SystemContractType},
-    AccessRights, CLValue, CLValueError, Contract, ContractHash, EraId, Key, Phase,
+    system::{
+        handle_payment::{ACCUMULATION_PURSE_KEY, FEE_HANDLING_KEY, REFUND_HANDLING_KEY},
+        mint::TOTAL_BURNED_KEY,
+        SystemContractType,
+    },
+    AccessRights, CLType, CLValue, CLValueError, Contract, ContractHash, EraId, Key, Phase,
     ProtocolVersion, StoredValue, U512,
 };
 
@@ -58,6 +62,8 @@ pub struct UpgradeConfig {
     new_unbonding_delay: Option<u64>,
     global_state_update: BTreeMap<Key, StoredValue>,
     chainspec_registry: ChainspecRegistry,
+    allow_dangerous_overrides: bool,
+    system_contract_extensions: BTreeMap<String, ContractHash>,
 }
 
 impl UpgradeConfig {
@@ -88,6 +94,8 @@ impl UpgradeConfig {
             new_unbonding_delay,
             global_state_update,
             chainspec_registry,
+            allow_dangerous_overrides: false,
+            system_contract_extensions: BTreeMap::new(),
         }
     }
 
@@ -146,10 +154,363 @@ impl UpgradeConfig {
         &self.chainspec_registry
     }
 
+    /// Returns `true` if the global state update may overwrite balances, the mint's total supply
+    /// or the system contract registry.
+    pub fn allow_dangerous_overrides(&self) -> bool {
+        self.allow_dangerous_overrides
+    }
+
+    /// Returns the contracts to register in the system contract registry, by name, in addition to
+    /// the core system contracts.
+    pub fn system_contract_extensions(&self) -> &BTreeMap<String, ContractHash> {
+        &self.system_contract_extensions
+    }
+
     /// Sets new pre state hash.
     pub fn with_pre_state_hash(&mut self, pre_state_hash: Digest) {
         self.pre_state_hash = pre_state_hash;
     }
+
+    /// Sets whether the global state update may overwrite balances, the mint's total supply or the
+    /// system contract registry.
+    pub fn with_allow_dangerous_overrides(&mut self, allow_dangerous_overrides: bool) {
+        self.allow_dangerous_overrides = allow_dangerous_overrides;
+    }
+
+    /// Sets the contracts to register in the system contract registry in addition to the core
+    /// system contracts.
+    ///
+    /// Registered contracts are treated as system contracts, so they're exempt from storage costs
+    /// and the URefs passed to them aren't attenuated.  They may be installed by the global state
+    /// update of the same upgrade.
+    pub fn with_system_contract_extensions(
+        &mut self,
+        system_contract_extensions: BTreeMap<String, ContractHash>,
+    ) {
+        self.system_contract_extensions = system_contract_extensions;
+    }
+
+    /// Checks that the upgrade moves to a valid next protocol version, that the new auction
+    /// parameters are within range, and that each value of the global state update is of a type
+    /// which can be stored under its key.
+    pub fn validate(&self) -> Result<(), UpgradeConfigError> {
+        if self
+            .current_protocol_version
+            .check_next_version(&self.new_protocol_version)
+            .is_invalid()
+        {
+            return Err(UpgradeConfigError::InvalidProtocolVersion {
+                current_protocol_version: self.current_protocol_version,
+                new_protocol_version: self.new_protocol_version,
+            });
+        }
+
+        if self.new_validator_slots == Some(0) {
+            return Err(UpgradeConfigError::InvalidValidatorSlots);
+        }
+
+        if let Some(rate) = self.new_round_seigniorage_rate {
+            if *rate.denom() == 0 || rate.numer() > rate.denom() {
+                return Err(UpgradeConfigError::InvalidRoundSeigniorageRate(rate));
+            }
+        }
+
+        if let (Some(unbonding_delay), Some(auction_delay)) =
+            (self.new_unbonding_delay, self.new_auction_delay)
+        {
+            if unbonding_delay <= auction_delay {
+                return Err(UpgradeConfigError::InvalidUnbondingDelay {
+                    unbonding_delay,
+                    auction_delay,
+                });
+            }
+        }
+
+        for (key, value) in &self.global_state_update {
+            if !is_storable_under(key, value) {
+                return Err(UpgradeConfigError::InvalidGlobalStateUpdate {
+                    key: *key,
+                    value_type: value.type_name(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ToBytes for UpgradeConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.pre_state_hash.to_bytes()?);
+        buffer.extend(self.current_protocol_version.to_bytes()?);
+        buffer.extend(self.new_protocol_version.to_bytes()?);
+        buffer.extend(self.activation_point.to_bytes()?);
+        buffer.extend(self.new_validator_slots.to_bytes()?);
+        buffer.extend(self.new_auction_delay.to_bytes()?);
+        buffer.extend(self.new_locked_funds_period_millis.to_bytes()?);
+        buffer.extend(self.new_round_seigniorage_rate.to_bytes()?);
+        buffer.extend(self.new_unbonding_delay.to_bytes()?);
+        buffer.extend(self.global_state_update.to_bytes()?);
+        buffer.extend(self.chainspec_registry.to_bytes()?);
+        buffer.extend(self.allow_dangerous_overrides.to_bytes()?);
+        buffer.extend(self.system_contract_extensions.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.pre_state_hash.serialized_length()
+            + self.current_protocol_version.serialized_length()
+            + self.new_protocol_version.serialized_length()
+            + self.activation_point.serialized_length()
+            + self.new_validator_slots.serialized_length()
+            + self.new_auction_delay.serialized_length()
+            + self.new_locked_funds_period_millis.serialized_length()
+            + self.new_round_seigniorage_rate.serialized_length()
+            + self.new_unbonding_delay.serialized_length()
+            + self.global_state_update.serialized_length()
+            + self.chainspec_registry.serialized_length()
+            + self.allow_dangerous_overrides.serialized_length()
+            + self.system_contract_extensions.serialized_length()
+    }
+}
+
+impl FromBytes for UpgradeConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (pre_state_hash, remainder) = FromBytes::from_bytes(bytes)?;
+        let (current_protocol_version, remainder) = FromBytes::from_bytes(remainder)?;
+        let (new_protocol_version, remainder) = FromBytes::from_bytes(remainder)?;
+        let (activation_point, remainder) = FromBytes::from_bytes(remainder)?;
+        let (new_validator_slots, remainder) = FromBytes::from_bytes(remainder)?;
+        let (new_auction_delay, remainder) = FromBytes::from_bytes(remainder)?;
+        let (new_locked_funds_period_millis, remainder) = FromBytes::from_bytes(remainder)?;
+        let (new_round_seigniorage_rate, remainder) = FromBytes::from_bytes(remainder)?;
+        let (new_unbonding_delay, remainder) = FromBytes::from_bytes(remainder)?;
+        let (global_state_update, remainder) = FromBytes::from_bytes(remainder)?;
+        let (chainspec_registry, remainder) = FromBytes::from_bytes(remainder)?;
+        let (allow_dangerous_overrides, remainder) = FromBytes::from_bytes(remainder)?;
+        let (system_contract_extensions, remainder) = FromBytes::from_bytes(remainder)?;
+        let upgrade_config = UpgradeConfig {
+            pre_state_hash,
+            current_protocol_version,
+            new_protocol_version,
+            activation_point,
+            new_validator_slots,
+            new_auction_delay,
+            new_locked_funds_period_millis,
+            new_round_seigniorage_rate,
+            new_unbonding_delay,
+            global_state_update,
+            chainspec_registry,
+            allow_dangerous_overrides,
+            system_contract_extensions,
+        };
+        Ok((upgrade_config, remainder))
+    }
+}
+
+/// Returns `true` if `value` is of a type the engine stores under `key`.
+fn is_storable_under(key: &Key, value: &StoredValue) -> bool {
+    match key {
+        Key::Account(_) => matches!(value, StoredValue::Account(_)),
+        Key::Hash(_) => matches!(
+            value,
+            StoredValue::Contract(_)
+                | StoredValue::ContractPackage(_)
+                | StoredValue::ContractWasm(_)
+                | StoredValue::CLValue(_)
+        ),
+        Key::Transfer(_) => matches!(value, StoredValue::Transfer(_)),
+        Key::DeployInfo(_) => matches!(value, StoredValue::DeployInfo(_)),
+        Key::EraInfo(_) | Key::EraSummary => matches!(value, StoredValue::EraInfo(_)),
+        Key::Balance(_) => matches!(
+            value,
+            StoredValue::CLValue(cl_value) if *cl_value.cl_type() == CLType::U512
+        ),
+        Key::Bid(_) => matches!(value, StoredValue::Bid(_)),
+        Key::Withdraw(_) => matches!(value, StoredValue::Withdraw(_)),
+        Key::Unbond(_) => matches!(value, StoredValue::Unbonding(_)),
+        _ => matches!(value, StoredValue::CLValue(_)),
+    }
+}
+
+/// An error validating an [`UpgradeConfig`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UpgradeConfigError {
+    /// The new protocol version isn't a valid next version of the current one.
+    #[error(
+        "protocol version {new_protocol_version} is not a valid next version of \
+        {current_protocol_version}"
+    )]
+    InvalidProtocolVersion {
+        /// The protocol version being upgraded from.
+        current_protocol_version: ProtocolVersion,
+        /// The protocol version being upgraded to.
+        new_protocol_version: ProtocolVersion,
+    },
+    /// The activation point isn't after the era whose switch block produced the pre-state.
+    #[error("activation point {activation_point} must come after era {pre_state_era_id}")]
+    InvalidActivationPoint {
+        /// The activation point of the upgrade.
+        activation_point: EraId,
+        /// The era whose switch block produced the pre-state.
+        pre_state_era_id: EraId,
+    },
+    /// The new validator slots must be at least 1.
+    #[error("validator slots must be at least 1")]
+    InvalidValidatorSlots,
+    /// The new round seigniorage rate must be a fraction between 0 and 1.
+    #[error("round seigniorage rate {0} must be between 0 and 1")]
+    InvalidRoundSeigniorageRate(Ratio<u64>),
+    /// The new unbonding delay must be greater than the new auction delay.
+    #[error(
+        "unbonding delay {unbonding_delay} must be greater than auction delay {auction_delay}"
+    )]
+    InvalidUnbondingDelay {
+        /// The new unbonding delay.
+        unbonding_delay: u64,
+        /// The new auction delay.
+        auction_delay: u64,
+    },
+    /// A value of the global state update is of a type which can't be stored under its key.
+    #[error("global state update can't store a {value_type} under {key}")]
+    InvalidGlobalStateUpdate {
+        /// The key of the value.
+        key: Key,
+        /// The type of the value.
+        value_type: String,
+    },
+}
+
+/// Builds a validated [`UpgradeConfig`].
+#[derive(Clone, Debug)]
+pub struct UpgradeConfigBuilder {
+    upgrade_config: UpgradeConfig,
+    pre_state_era_id: Option<EraId>,
+}
+
+impl UpgradeConfigBuilder {
+    /// Creates a new `UpgradeConfigBuilder` for an upgrade of the global state under
+    /// `pre_state_hash` from `current_protocol_version` to `new_protocol_version`, which neither
+    /// changes the auction parameters nor updates global state.
+    pub fn new(
+        pre_state_hash: Digest,
+        current_protocol_version: ProtocolVersion,
+        new_protocol_version: ProtocolVersion,
+        chainspec_registry: ChainspecRegistry,
+    ) -> Self {
+        let upgrade_config = UpgradeConfig::new(
+            pre_state_hash,
+            current_protocol_version,
+            new_protocol_version,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            BTreeMap::new(),
+            chainspec_registry,
+        );
+        UpgradeConfigBuilder {
+            upgrade_config,
+            pre_state_era_id: None,
+        }
+    }
+
+    /// Sets the activation point.
+    pub fn with_activation_point(mut self, activation_point: EraId) -> Self {
+        self.upgrade_config.activation_point = Some(activation_point);
+        self
+    }
+
+    /// Sets the era whose switch block produced the pre-state, which the activation point must
+    /// come after.
+    pub fn with_pre_state_era_id(mut self, pre_state_era_id: EraId) -> Self {
+        self.pre_state_era_id = Some(pre_state_era_id);
+        self
+    }
+
+    /// Sets the new validator slots.
+    pub fn with_new_validator_slots(mut self, new_validator_slots: u32) -> Self {
+        self.upgrade_config.new_validator_slots = Some(new_validator_slots);
+        self
+    }
+
+    /// Sets the new auction delay.
+    pub fn with_new_auction_delay(mut self, new_auction_delay: u64) -> Self {
+        self.upgrade_config.new_auction_delay = Some(new_auction_delay);
+        self
+    }
+
+    /// Sets the new locked funds period.
+    pub fn with_new_locked_funds_period_millis(
+        mut self,
+        new_locked_funds_period_millis: u64,
+    ) -> Self {
+        self.upgrade_config.new_locked_funds_period_millis = Some(new_locked_funds_period_millis);
+        self
+    }
+
+    /// Sets the new round seigniorage rate.
+    pub fn with_new_round_seigniorage_rate(
+        mut self,
+        new_round_seigniorage_rate: Ratio<u64>,
+    ) -> Self {
+        self.upgrade_config.new_round_seigniorage_rate = Some(new_round_seigniorage_rate);
+        self
+    }
+
+    /// Sets the new unbonding delay.
+    pub fn with_new_unbonding_delay(mut self, new_unbonding_delay: u64) -> Self {
+        self.upgrade_config.new_unbonding_delay = Some(new_unbonding_delay);
+        self
+    }
+
+    /// Sets the emergency global state update.
+    pub fn with_global_state_update(
+        mut self,
+        global_state_update: BTreeMap<Key, StoredValue>,
+    ) -> Self {
+        self.upgrade_config.global_state_update = global_state_update;
+        self
+    }
+
+    /// Sets whether the global state update may overwrite balances, the mint's total supply or the
+    /// system contract registry.
+    pub fn with_allow_dangerous_overrides(mut self, allow_dangerous_overrides: bool) -> Self {
+        self.upgrade_config
+            .with_allow_dangerous_overrides(allow_dangerous_overrides);
+        self
+    }
+
+    /// Sets the contracts to register in the system contract registry in addition to the core
+    /// system contracts.
+    pub fn with_system_contract_extensions(
+        mut self,
+        system_contract_extensions: BTreeMap<String, ContractHash>,
+    ) -> Self {
+        self.upgrade_config
+            .with_system_contract_extensions(system_contract_extensions);
+        self
+    }
+
+    /// Validates and returns the [`UpgradeConfig`].
+    pub fn build(self) -> Result<UpgradeConfig, UpgradeConfigError> {
+        if let (Some(activation_point), Some(pre_state_era_id)) =
+            (self.upgrade_config.activation_point, self.pre_state_era_id)
+        {
+            if activation_point <= pre_state_era_id {
+                return Err(UpgradeConfigError::InvalidActivationPoint {
+                    activation_point,
+                    pre_state_era_id,
+                });
+            }
+        }
+        self.upgrade_config.validate()?;
+        Ok(self.upgrade_config)
+    }
 }
 
 /// Represents outcomes of a failed protocol upgrade.
@@ -179,6 +540,18 @@ pub enum ProtocolUpgradeError {
     /// Found unexpected variant of a stored value.
     #[error("Unexpected stored value variant")]
     UnexpectedStoredValueVariant,
+    /// Global state update overwrites a protected key without dangerous overrides allowed.
+    #[error("Global state update overwrites protected key {0}")]
+    DangerousOverride(Key),
+    /// A system contract extension uses the name of a core system contract.
+    #[error("System contract extension can't replace core system contract: {0}")]
+    ReservedSystemContractName(String),
+    /// A system contract extension refers to a contract which doesn't exist.
+    #[error("System contract extension {0} refers to a missing contract")]
+    MissingSystemContractExtension(String),
+    /// A named key of a system contract doesn't refer to a URef.
+    #[error("Named key {0} of a system contract is not a URef")]
+    InvalidNamedKey(String),
 }
 
 impl From<bytesrepr::Error> for ProtocolUpgradeError {
@@ -411,4 +784,282 @@ where
 
         Ok(())
     }
+
+    /// Records the refund and fee handling of the upgraded network in the handle payment system
+    /// contract.
+    ///
+    /// Networks started before these were recorded get the named keys created by the first upgrade
+    /// writing them.
+    pub(crate) fn record_payment_handling(
+        &self,
+        correlation_id: CorrelationId,
+        handle_payment_hash: &ContractHash,
+        engine_config: &EngineConfig,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let system_contract = SystemContractType::HandlePayment;
+        let contract_name = system_contract.contract_name();
+        let mut contract = if let StoredValue::Contract(contract) = self
+            .tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &Key::Hash(handle_payment_hash.value()))
+            .map_err(|_| {
+                ProtocolUpgradeError::UnableToRetrieveSystemContract(contract_name.to_string())
+            })?
+            .ok_or_else(|| {
+                ProtocolUpgradeError::UnableToRetrieveSystemContract(contract_name.to_string())
+            })? {
+            contract
+        } else {
+            return Err(ProtocolUpgradeError::UnableToRetrieveSystemContract(
+                contract_name,
+            ));
+        };
+
+        let mut new_named_keys = NamedKeys::new();
+        for (name, cl_value) in [
+            (
+                REFUND_HANDLING_KEY,
+                CLValue::from_t(*engine_config.refund_handling())?,
+            ),
+            (
+                FEE_HANDLING_KEY,
+                CLValue::from_t(engine_config.fee_handling())?,
+            ),
+        ] {
+            let uref = match contract.named_keys().get(name) {
+                Some(Key::URef(uref)) => *uref,
+                Some(_) => return Err(ProtocolUpgradeError::InvalidNamedKey(name.to_string())),
+                None => {
+                    // Seeded by name, as other URefs may be created during the same upgrade.
+                    let seed_bytes = (
+                        self.old_protocol_version,
+                        self.new_protocol_version,
+                        name.to_string(),
+                    )
+                        .to_bytes()?;
+                    let uref = AddressGenerator::new(&seed_bytes, Phase::System)
+                        .new_uref(AccessRights::READ_ADD_WRITE);
+                    new_named_keys.insert(name.to_string(), Key::from(uref));
+                    uref
+                }
+            };
+            self.tracking_copy
+                .borrow_mut()
+                .write(Key::URef(uref), StoredValue::CLValue(cl_value));
+        }
+
+        if !new_named_keys.is_empty() {
+            contract.named_keys_append(&mut new_named_keys);
+            self.tracking_copy.borrow_mut().write(
+                (*handle_payment_hash).into(),
+                StoredValue::Contract(contract),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Creates the counter of burned token in the mint system contract if its not present.
+    ///
+    /// Networks started before burns were recorded only count the token burned after the upgrade
+    /// creating the counter.
+    pub(crate) fn create_total_burned_if_required(
+        &self,
+        correlation_id: CorrelationId,
+        mint_hash: &ContractHash,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let system_contract = SystemContractType::Mint;
+        let contract_name = system_contract.contract_name();
+        let mut contract = if let StoredValue::Contract(contract) = self
+            .tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &Key::Hash(mint_hash.value()))
+            .map_err(|_| {
+                ProtocolUpgradeError::UnableToRetrieveSystemContract(contract_name.to_string())
+            })?
+            .ok_or_else(|| {
+                ProtocolUpgradeError::UnableToRetrieveSystemContract(contract_name.to_string())
+            })? {
+            contract
+        } else {
+            return Err(ProtocolUpgradeError::UnableToRetrieveSystemContract(
+                contract_name,
+            ));
+        };
+
+        if contract.named_keys().contains_key(TOTAL_BURNED_KEY) {
+            return Ok(());
+        }
+
+        // Seeded differently from the accumulation purse's address generator, which may create a
+        // URef during the same upgrade.
+        let mut address_generator = {
+            let seed_bytes = (
+                self.old_protocol_version,
+                self.new_protocol_version,
+                TOTAL_BURNED_KEY.to_string(),
+            )
+                .to_bytes()?;
+            AddressGenerator::new(&seed_bytes, Phase::System)
+        };
+
+        let total_burned_uref = address_generator.new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            Key::URef(total_burned_uref),
+            StoredValue::CLValue(CLValue::from_t(U512::zero())?),
+        );
+
+        let mut new_named_keys = NamedKeys::new();
+        new_named_keys.insert(TOTAL_BURNED_KEY.into(), Key::from(total_burned_uref));
+        contract.named_keys_append(&mut new_named_keys);
+
+        self.tracking_copy
+            .borrow_mut()
+            .write((*mint_hash).into(), StoredValue::Contract(contract));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::URef;
+
+    use super::*;
+
+    const PRE_STATE_ERA_ID: EraId = EraId::new(9);
+
+    fn builder() -> UpgradeConfigBuilder {
+        UpgradeConfigBuilder::new(
+            Digest::hash([1; 32]),
+            ProtocolVersion::V1_0_0,
+            ProtocolVersion::from_parts(1, 1, 0),
+            ChainspecRegistry::new_with_optional_global_state(&[2; 32], None),
+        )
+        .with_activation_point(PRE_STATE_ERA_ID.successor())
+        .with_pre_state_era_id(PRE_STATE_ERA_ID)
+    }
+
+    fn balance_update(value: StoredValue) -> BTreeMap<Key, StoredValue> {
+        let purse = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let mut global_state_update = BTreeMap::new();
+        global_state_update.insert(Key::Balance(purse.addr()), value);
+        global_state_update
+    }
+
+    #[test]
+    fn should_round_trip_upgrade_config() {
+        let mut global_state_update =
+            balance_update(StoredValue::CLValue(CLValue::from_t(U512::one()).unwrap()));
+        global_state_update.insert(
+            Key::URef(URef::new([4; 32], AccessRights::READ)),
+            StoredValue::CLValue(CLValue::from_t("value".to_string()).unwrap()),
+        );
+        let mut system_contract_extensions = BTreeMap::new();
+        system_contract_extensions.insert("extension".to_string(), ContractHash::new([5; 32]));
+
+        let upgrade_config = builder()
+            .with_new_validator_slots(100)
+            .with_new_auction_delay(1)
+            .with_new_locked_funds_period_millis(0)
+            .with_new_round_seigniorage_rate(Ratio::new(7, 175_000_000_000))
+            .with_new_unbonding_delay(7)
+            .with_global_state_update(global_state_update)
+            .with_allow_dangerous_overrides(true)
+            .with_system_contract_extensions(system_contract_extensions)
+            .build()
+            .expect("should build upgrade config");
+        bytesrepr::test_serialization_roundtrip(&upgrade_config);
+
+        let upgrade_config = builder().build().expect("should build upgrade config");
+        bytesrepr::test_serialization_roundtrip(&upgrade_config);
+    }
+
+    #[test]
+    fn should_reject_invalid_protocol_version() {
+        for new_protocol_version in [
+            ProtocolVersion::V1_0_0,
+            ProtocolVersion::from_parts(3, 0, 0),
+        ] {
+            let result = UpgradeConfigBuilder::new(
+                Digest::hash([1; 32]),
+                ProtocolVersion::V1_0_0,
+                new_protocol_version,
+                ChainspecRegistry::new_with_optional_global_state(&[2; 32], None),
+            )
+            .build();
+            assert_eq!(
+                result,
+                Err(UpgradeConfigError::InvalidProtocolVersion {
+                    current_protocol_version: ProtocolVersion::V1_0_0,
+                    new_protocol_version,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_activation_point_not_after_pre_state_era() {
+        let result = builder().with_activation_point(PRE_STATE_ERA_ID).build();
+        assert_eq!(
+            result,
+            Err(UpgradeConfigError::InvalidActivationPoint {
+                activation_point: PRE_STATE_ERA_ID,
+                pre_state_era_id: PRE_STATE_ERA_ID,
+            })
+        );
+    }
+
+    #[test]
+    fn should_reject_out_of_range_parameters() {
+        assert_eq!(
+            builder().with_new_validator_slots(0).build(),
+            Err(UpgradeConfigError::InvalidValidatorSlots)
+        );
+
+        let rate = Ratio::new(3, 2);
+        assert_eq!(
+            builder().with_new_round_seigniorage_rate(rate).build(),
+            Err(UpgradeConfigError::InvalidRoundSeigniorageRate(rate))
+        );
+
+        assert_eq!(
+            builder()
+                .with_new_auction_delay(7)
+                .with_new_unbonding_delay(7)
+                .build(),
+            Err(UpgradeConfigError::InvalidUnbondingDelay {
+                unbonding_delay: 7,
+                auction_delay: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn should_reject_mistyped_global_state_update() {
+        let value = StoredValue::CLValue(CLValue::from_t(1u64).unwrap());
+        let global_state_update = balance_update(value.clone());
+        let key = *global_state_update.keys().next().unwrap();
+        assert_eq!(
+            builder()
+                .with_global_state_update(global_state_update)
+                .build(),
+            Err(UpgradeConfigError::InvalidGlobalStateUpdate {
+                key,
+                value_type: value.type_name(),
+            })
+        );
+
+        let mut global_state_update = BTreeMap::new();
+        global_state_update.insert(Key::EraSummary, value.clone());
+        assert_eq!(
+            builder()
+                .with_global_state_update(global_state_update)
+                .build(),
+            Err(UpgradeConfigError::InvalidGlobalStateUpdate {
+                key: Key::EraSummary,
+                value_type: value.type_name(),
+            })
+        );
+    }
 }
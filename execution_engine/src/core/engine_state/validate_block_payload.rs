@@ -0,0 +1,109 @@
+//! Support for cheaply checking the deploys of a proposed block against global state, so obviously
+//! invalid payloads can be rejected before they are executed.
+use std::fmt::{self, Display, Formatter};
+
+use casper_hashing::Digest;
+use casper_types::{
+    account::{AccountHash, ApprovalsFailure},
+    DeployHash, Motes,
+};
+
+use crate::core::engine_state::deploy_item::DeployItem;
+
+/// The reason a deploy of a block payload is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDeployReason {
+    /// The deploy appears earlier in the payload already.
+    Duplicate,
+    /// The account the deploy is executed by doesn't exist.
+    AccountNotFound(AccountHash),
+    /// The approvals of the deploy don't authorize it on behalf of its account.
+    Authorization(ApprovalsFailure),
+    /// The main purse of the account can't cover the payment of the deploy on top of the payments
+    /// of its earlier deploys in the payload.
+    InsufficientBalance {
+        /// The amount the deploy can be charged.
+        required: Motes,
+        /// The balance left after the payments of the account's earlier deploys.
+        available: Motes,
+    },
+}
+
+impl Display for InvalidDeployReason {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidDeployReason::Duplicate => formatter.write_str("duplicate deploy"),
+            InvalidDeployReason::AccountNotFound(account_hash) => {
+                write!(formatter, "account {} not found", account_hash)
+            }
+            InvalidDeployReason::Authorization(failure) => Display::fmt(failure, formatter),
+            InvalidDeployReason::InsufficientBalance {
+                required,
+                available,
+            } => write!(
+                formatter,
+                "insufficient balance: {} required, {} available",
+                required.value(),
+                available.value()
+            ),
+        }
+    }
+}
+
+/// A deploy of a block payload which failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDeploy {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// Why the deploy is invalid.
+    pub reason: InvalidDeployReason,
+}
+
+/// Represents a request to validate the deploys of a proposed block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidateBlockPayloadRequest {
+    state_hash: Digest,
+    deploy_items: Vec<DeployItem>,
+}
+
+impl ValidateBlockPayloadRequest {
+    /// Creates new request.
+    pub fn new(state_hash: Digest, deploy_items: Vec<DeployItem>) -> Self {
+        ValidateBlockPayloadRequest {
+            state_hash,
+            deploy_items,
+        }
+    }
+
+    /// Returns the state root hash the block would be executed on top of.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the deploys of the block, in execution order.
+    pub fn deploy_items(&self) -> &[DeployItem] {
+        &self.deploy_items
+    }
+}
+
+/// Represents a result of a `validate_block_payload` request.
+#[derive(Debug)]
+pub enum ValidateBlockPayloadResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the deploys which failed validation, in payload order.
+    Success {
+        /// The invalid deploys, empty if the whole payload is valid.
+        invalid_deploys: Vec<InvalidDeploy>,
+    },
+}
+
+impl ValidateBlockPayloadResult {
+    /// Returns `true` if the state root was found and no deploy failed validation.
+    pub fn is_valid(&self) -> bool {
+        matches!(
+            self,
+            ValidateBlockPayloadResult::Success { invalid_deploys } if invalid_deploys.is_empty()
+        )
+    }
+}
@@ -0,0 +1,188 @@
+//! Support for pinning a consistent view of global state.
+//!
+//! Consumers paging through large result sets can pin the state root they started with, and
+//! issue every subsequent query against the same [`ViewHandle`].  A pinned state root is not
+//! collected by garbage collection until the view is released or its time to live elapses.
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use casper_hashing::Digest;
+
+/// Identifier of a pinned view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ViewId(u64);
+
+impl ViewId {
+    /// Returns the raw value of this id.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for ViewId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "view-{}", self.0)
+    }
+}
+
+/// A handle to a pinned view of global state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewHandle {
+    id: ViewId,
+    state_root_hash: Digest,
+}
+
+impl ViewHandle {
+    /// Returns the id of the view.
+    pub fn id(&self) -> ViewId {
+        self.id
+    }
+
+    /// Returns the state root hash the view is pinned to.
+    pub fn state_root_hash(&self) -> Digest {
+        self.state_root_hash
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PinnedView {
+    state_root_hash: Digest,
+    /// `None` if the time to live is too long to be represented, in which case the view never
+    /// expires.
+    expires_at: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+struct Views {
+    next_id: u64,
+    pinned: HashMap<ViewId, PinnedView>,
+}
+
+impl Views {
+    fn remove_expired(&mut self, now: Instant) {
+        self.pinned.retain(|_, view| match view.expires_at {
+            Some(expires_at) => expires_at > now,
+            None => true,
+        });
+    }
+}
+
+/// Registry of the views pinned on an engine state.
+#[derive(Debug, Default)]
+pub(crate) struct ViewRegistry {
+    views: Mutex<Views>,
+}
+
+impl ViewRegistry {
+    /// Pins `state_root_hash` for `ttl`.
+    pub(crate) fn pin(&self, state_root_hash: Digest, ttl: Duration) -> ViewHandle {
+        let mut views = self.lock();
+        let id = ViewId(views.next_id);
+        views.next_id += 1;
+        views.pinned.insert(
+            id,
+            PinnedView {
+                state_root_hash,
+                expires_at: Instant::now().checked_add(ttl),
+            },
+        );
+        ViewHandle {
+            id,
+            state_root_hash,
+        }
+    }
+
+    /// Extends the lifetime of a live view to `ttl` from now.  Returns `false` if the view has
+    /// been released or has expired.
+    pub(crate) fn renew(&self, handle: &ViewHandle, ttl: Duration) -> bool {
+        let now = Instant::now();
+        let mut views = self.lock();
+        views.remove_expired(now);
+        match views.pinned.get_mut(&handle.id) {
+            Some(view) => {
+                view.expires_at = now.checked_add(ttl);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Releases a view.  Returns `false` if the view has already been released or has expired.
+    pub(crate) fn release(&self, handle: &ViewHandle) -> bool {
+        let mut views = self.lock();
+        views.remove_expired(Instant::now());
+        views.pinned.remove(&handle.id).is_some()
+    }
+
+    /// Returns `true` if the view is still pinned.
+    pub(crate) fn is_live(&self, handle: &ViewHandle) -> bool {
+        let mut views = self.lock();
+        views.remove_expired(Instant::now());
+        views.pinned.contains_key(&handle.id)
+    }
+
+    /// Returns the state root hashes of all live views.
+    pub(crate) fn pinned_state_roots(&self) -> Vec<Digest> {
+        let mut views = self.lock();
+        views.remove_expired(Instant::now());
+        let mut roots: Vec<Digest> = views
+            .pinned
+            .values()
+            .map(|view| view.state_root_hash)
+            .collect();
+        roots.sort();
+        roots.dedup();
+        roots
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Views> {
+        // The registry holds no invariants which a panicking thread could break.
+        self.views
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_pin_and_release_views() {
+        let registry = ViewRegistry::default();
+        let root = Digest::hash([1u8; 32]);
+        let handle_1 = registry.pin(root, Duration::from_secs(60));
+        let handle_2 = registry.pin(root, Duration::from_secs(60));
+        assert_ne!(handle_1.id(), handle_2.id());
+        assert_eq!(registry.pinned_state_roots(), vec![root]);
+
+        assert!(registry.release(&handle_1));
+        assert!(!registry.release(&handle_1));
+        assert_eq!(registry.pinned_state_roots(), vec![root]);
+
+        assert!(registry.release(&handle_2));
+        assert!(registry.pinned_state_roots().is_empty());
+    }
+
+    #[test]
+    fn should_expire_views() {
+        let registry = ViewRegistry::default();
+        let handle = registry.pin(Digest::hash([1u8; 32]), Duration::ZERO);
+        assert!(!registry.is_live(&handle));
+        assert!(!registry.renew(&handle, Duration::from_secs(60)));
+        assert!(registry.pinned_state_roots().is_empty());
+    }
+
+    #[test]
+    fn should_not_expire_views_with_unrepresentable_ttl() {
+        let registry = ViewRegistry::default();
+        let handle = registry.pin(Digest::hash([1u8; 32]), Duration::MAX);
+        assert!(registry.is_live(&handle));
+        assert!(registry.renew(&handle, Duration::MAX));
+        assert!(registry.is_live(&handle));
+    }
+}
@@ -10,7 +10,10 @@ use crate::core::{Address, ADDRESS_LENGTH};
 const SEED_LENGTH: usize = 32;
 
 /// An `AddressGenerator` generates `URef` addresses.
-pub struct AddressGenerator(ChaChaRng);
+pub struct AddressGenerator {
+    rng: ChaChaRng,
+    unique_address_count: u64,
+}
 
 impl AddressGenerator {
     /// Creates an [`AddressGenerator`] from a 32-byte hash digest and [`Phase`].
@@ -24,7 +27,7 @@ impl AddressGenerator {
     /// Creates a new [`Address`] by using an internal instance of PRNG.
     pub fn create_address(&mut self) -> Address {
         let mut buff = [0u8; ADDRESS_LENGTH];
-        self.0.fill_bytes(&mut buff);
+        self.rng.fill_bytes(&mut buff);
         buff
     }
 
@@ -39,6 +42,17 @@ impl AddressGenerator {
         let addr = self.create_address();
         URef::new(addr, access_rights)
     }
+
+    /// Returns the index of the next unique address, counting the unique addresses requested by
+    /// contracts so far.
+    ///
+    /// Unlike [`AddressGenerator::create_address`], this doesn't advance the PRNG, so it doesn't
+    /// affect the addresses of `URef`s created afterwards.
+    pub fn next_unique_address_index(&mut self) -> u64 {
+        let index = self.unique_address_count;
+        self.unique_address_count += 1;
+        index
+    }
 }
 
 /// A builder for [`AddressGenerator`].
@@ -64,7 +78,10 @@ impl AddressGeneratorBuilder {
     /// This method hashes the seed bytes, and seeds the PRNG with it.
     pub fn build(self) -> AddressGenerator {
         let seed: [u8; SEED_LENGTH] = Digest::hash(self.data).value();
-        AddressGenerator(ChaChaRng::from_seed(seed))
+        AddressGenerator {
+            rng: ChaChaRng::from_seed(seed),
+            unique_address_count: 0,
+        }
     }
 }
 
@@ -116,4 +133,14 @@ mod tests {
             "different phase should have different output"
         );
     }
+
+    #[test]
+    fn should_not_advance_prng_when_counting_unique_addresses() {
+        let mut ag_a = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut ag_b = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        assert_eq!(ag_a.next_unique_address_index(), 0);
+        assert_eq!(ag_a.next_unique_address_index(), 1);
+
+        assert_eq!(ag_a.create_address(), ag_b.create_address());
+    }
 }
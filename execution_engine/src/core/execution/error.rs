@@ -176,6 +176,26 @@ pub enum Error {
     /// Failed to transfer tokens on a private chain.
     #[error("Failed to transfer with unrestricted transfers disabled")]
     DisabledUnrestrictedTransfers,
+    /// Contract package is paused by its owner.
+    #[error("Contract package is paused: {}", _0)]
+    EntityPaused(ContractPackageHash),
+    /// An attempt to call a non-reentrant entry point which is already executing further up the
+    /// runtime stack.
+    #[error("Reentrant call to entry point {entry_point_name} of contract {contract_hash}")]
+    ReentrantCall {
+        /// The hash of the called contract.
+        contract_hash: ContractHash,
+        /// The name of the called entry point.
+        entry_point_name: String,
+    },
+    /// The serialized effects of the session code exceed the maximum allowed size.
+    #[error("Effects size of {size} bytes exceeds the maximum of {max} bytes")]
+    EffectsSizeExceeded {
+        /// The serialized size of the effects in bytes.
+        size: u64,
+        /// The maximum allowed serialized size of the effects in bytes.
+        max: u64,
+    },
 }
 
 impl From<wasm_prep::PreprocessingError> for Error {
@@ -198,6 +218,68 @@ impl Error {
             format!("{:?}", found),
         ))
     }
+
+    /// Returns the numeric code identifying the variant of this error.
+    ///
+    /// Execution errors use the codes from 1001 upwards, so they never collide with the codes of
+    /// [`crate::core::engine_state::Error`].  Codes are stable across versions: a variant keeps its
+    /// code for as long as it exists, and the code of a removed variant is never reassigned.
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Error::Interpreter(_) => 1001,
+            Error::Storage(_) => 1002,
+            Error::BytesRepr(_) => 1003,
+            Error::NamedKeyNotFound(_) => 1004,
+            Error::KeyNotFound(_) => 1005,
+            Error::AccountNotFound(_) => 1006,
+            Error::TypeMismatch(_) => 1007,
+            Error::InvalidAccess { .. } => 1008,
+            Error::ForgedReference(_) => 1009,
+            Error::URefNotFound(_) => 1010,
+            Error::FunctionNotFound(_) => 1011,
+            Error::ParityWasm(_) => 1012,
+            Error::WasmOptimizer => 1013,
+            Error::GasLimit => 1014,
+            Error::Ret(_) => 1015,
+            Error::Resolver(_) => 1016,
+            Error::Revert(_) => 1017,
+            Error::AddKeyFailure(_) => 1018,
+            Error::RemoveKeyFailure(_) => 1019,
+            Error::UpdateKeyFailure(_) => 1020,
+            Error::SetThresholdFailure(_) => 1021,
+            Error::SystemContract(_) => 1022,
+            Error::DeploymentAuthorizationFailure => 1023,
+            Error::ExpectedReturnValue => 1024,
+            Error::UnexpectedReturnValue => 1025,
+            Error::InvalidContext => 1026,
+            Error::IncompatibleProtocolMajorVersion { .. } => 1027,
+            Error::CLValue(_) => 1028,
+            Error::HostBufferEmpty => 1029,
+            Error::UnsupportedWasmStart => 1030,
+            Error::NoActiveContractVersions(_) => 1031,
+            Error::InvalidContractVersion(_) => 1032,
+            Error::NoSuchMethod(_) => 1033,
+            Error::WasmPreprocessing(_) => 1034,
+            Error::KeyIsNotAURef(_) => 1035,
+            Error::UnexpectedStoredValueVariant => 1036,
+            Error::LockedContract(_) => 1037,
+            Error::InvalidContractPackage(_) => 1038,
+            Error::InvalidContract(_) => 1039,
+            Error::InvalidContractWasm(_) => 1040,
+            Error::MissingArgument { .. } => 1041,
+            Error::DictionaryItemKeyExceedsLength => 1042,
+            Error::MissingSystemContractRegistry => 1043,
+            Error::MissingSystemContractHash(_) => 1044,
+            Error::RuntimeStackOverflow => 1045,
+            Error::ValueTooLarge => 1046,
+            Error::MissingRuntimeStack => 1047,
+            Error::DisabledContract(_) => 1048,
+            Error::DisabledUnrestrictedTransfers => 1049,
+            Error::EntityPaused(_) => 1050,
+            Error::ReentrantCall { .. } => 1051,
+            Error::EffectsSizeExceeded { .. } => 1052,
+        }
+    }
 }
 
 impl casper_wasmi::HostError for Error {}
@@ -279,3 +361,106 @@ impl From<stack::RuntimeStackOverflow> for Error {
         Error::RuntimeStackOverflow
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use casper_types::{
+        account::AccountHash,
+        system::{mint, Error as SystemError},
+    };
+
+    use super::*;
+
+    /// One instance of every variant, so that a new variant without a unique code fails the test.
+    fn all_variants() -> Vec<Error> {
+        let key = Key::Account(AccountHash::new([1; 32]));
+        let uref = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let contract_hash = ContractHash::new([3; 32]);
+        let contract_package_hash = ContractPackageHash::new([4; 32]);
+        vec![
+            Error::Interpreter(String::new()),
+            Error::Storage(storage::error::Error::Poison),
+            Error::BytesRepr(bytesrepr::Error::Formatting),
+            Error::NamedKeyNotFound(String::new()),
+            Error::KeyNotFound(key),
+            Error::AccountNotFound(key),
+            Error::type_mismatch(CLType::U8, CLType::U512),
+            Error::InvalidAccess {
+                required: AccessRights::WRITE,
+            },
+            Error::ForgedReference(uref),
+            Error::URefNotFound(uref),
+            Error::FunctionNotFound(String::new()),
+            Error::ParityWasm(elements::Error::UnexpectedEof),
+            Error::WasmOptimizer,
+            Error::GasLimit,
+            Error::Ret(vec![uref]),
+            Error::Resolver(ResolverError::NoImportedMemory),
+            Error::Revert(ApiError::User(1)),
+            Error::AddKeyFailure(AddKeyFailure::MaxKeysLimit),
+            Error::RemoveKeyFailure(RemoveKeyFailure::MissingKey),
+            Error::UpdateKeyFailure(UpdateKeyFailure::MissingKey),
+            Error::SetThresholdFailure(SetThresholdFailure::KeyManagementThreshold),
+            Error::SystemContract(SystemError::Mint(mint::Error::InsufficientFunds)),
+            Error::DeploymentAuthorizationFailure,
+            Error::ExpectedReturnValue,
+            Error::UnexpectedReturnValue,
+            Error::InvalidContext,
+            Error::IncompatibleProtocolMajorVersion {
+                expected: 1,
+                actual: 2,
+            },
+            Error::CLValue(CLValueError::Serialization(bytesrepr::Error::Formatting)),
+            Error::HostBufferEmpty,
+            Error::UnsupportedWasmStart,
+            Error::NoActiveContractVersions(contract_package_hash),
+            Error::InvalidContractVersion(ContractVersionKey::new(1, 1)),
+            Error::NoSuchMethod(String::new()),
+            Error::WasmPreprocessing(wasm_prep::PreprocessingError::StackLimiter),
+            Error::KeyIsNotAURef(key),
+            Error::UnexpectedStoredValueVariant,
+            Error::LockedContract(contract_package_hash),
+            Error::InvalidContractPackage(contract_package_hash),
+            Error::InvalidContract(contract_hash),
+            Error::InvalidContractWasm(ContractWasmHash::new([5; 32])),
+            Error::MissingArgument {
+                name: String::new(),
+            },
+            Error::DictionaryItemKeyExceedsLength,
+            Error::MissingSystemContractRegistry,
+            Error::MissingSystemContractHash(String::new()),
+            Error::RuntimeStackOverflow,
+            Error::ValueTooLarge,
+            Error::MissingRuntimeStack,
+            Error::DisabledContract(contract_hash),
+            Error::DisabledUnrestrictedTransfers,
+            Error::EntityPaused(contract_package_hash),
+            Error::ReentrantCall {
+                contract_hash,
+                entry_point_name: String::new(),
+            },
+            Error::EffectsSizeExceeded { size: 2, max: 1 },
+        ]
+    }
+
+    #[test]
+    fn error_codes_should_be_unique_and_in_range() {
+        let all_variants = all_variants();
+        let codes: BTreeSet<u32> = all_variants.iter().map(Error::error_code).collect();
+        assert_eq!(codes.len(), all_variants.len());
+        assert!(codes.iter().all(|code| (1001..2000).contains(code)));
+    }
+
+    #[test]
+    fn error_codes_should_be_stable() {
+        assert_eq!(Error::Interpreter(String::new()).error_code(), 1001);
+        assert_eq!(Error::GasLimit.error_code(), 1014);
+        assert_eq!(Error::Revert(ApiError::User(1)).error_code(), 1017);
+        assert_eq!(
+            Error::EffectsSizeExceeded { size: 2, max: 1 }.error_code(),
+            1052
+        );
+    }
+}
@@ -1,26 +1,28 @@
-use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
+use std::{cell::RefCell, collections::BTreeSet, rc::Rc, sync::Arc};
 
 use casper_types::{
-    account::{Account, AccountHash},
+    account::{Account, AccountHash, METHOD_AUTHORIZE},
     bytesrepr::FromBytes,
     contracts::NamedKeys,
     system::{auction, handle_payment, mint, AUCTION, HANDLE_PAYMENT, MINT},
-    BlockTime, CLTyped, ContextAccessRights, DeployHash, EntryPointType, Gas, Key, Phase,
-    ProtocolVersion, RuntimeArgs, StoredValue, U512,
+    BlockTime, CLTyped, ContextAccessRights, ContractHash, DeployHash, EntryPointType, Gas, Key,
+    Phase, ProtocolVersion, RuntimeArgs, StoredValue, U512,
 };
 
 use crate::{
     core::{
         engine_state::{
-            executable_deploy_item::ExecutionKind, execution_result::ExecutionResult, EngineConfig,
-            ExecError,
+            executable_deploy_item::ExecutionKind,
+            execution_result::ExecutionResult,
+            execution_warning::{ExecutionWarning, ExecutionWarnings},
+            EngineConfig, ExecError,
         },
         execution::{address_generator::AddressGenerator, Error},
-        runtime::{Runtime, RuntimeStack},
+        runtime::{memory_high_water_mark::MemoryHighWaterMark, utils, Runtime, RuntimeStack},
         runtime_context::RuntimeContext,
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
-    shared::newtypes::CorrelationId,
+    shared::{module_cache::ModuleCache, newtypes::CorrelationId},
     storage::global_state::StateReader,
 };
 
@@ -35,12 +37,49 @@ fn try_get_amount(runtime_args: &RuntimeArgs) -> Result<U512, ExecError> {
 /// Executor object deals with execution of WASM modules.
 pub struct Executor {
     config: EngineConfig,
+    module_cache: Arc<ModuleCache>,
+    memory_high_water_mark: MemoryHighWaterMark,
+    warnings: ExecutionWarnings,
 }
 
 impl Executor {
     /// Creates new executor object.
+    ///
+    /// The executor doesn't cache preprocessed modules unless given a cache via
+    /// [`Executor::with_module_cache`].
     pub fn new(config: EngineConfig) -> Self {
-        Executor { config }
+        Executor {
+            config,
+            module_cache: Arc::new(ModuleCache::new(0)),
+            memory_high_water_mark: MemoryHighWaterMark::default(),
+            warnings: ExecutionWarnings::default(),
+        }
+    }
+
+    /// Sets the cache of preprocessed modules used for session and payment code.
+    pub fn with_module_cache(mut self, module_cache: Arc<ModuleCache>) -> Self {
+        self.module_cache = module_cache;
+        self
+    }
+
+    /// Returns the config the executor executes with.
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
+    /// Returns the size in bytes of the largest Wasm memory used since the last call, resetting it.
+    pub(crate) fn take_memory_high_water_mark(&self) -> u64 {
+        self.memory_high_water_mark.take()
+    }
+
+    /// Records a warning about the deploy being executed.
+    pub(crate) fn raise_warning(&self, warning: ExecutionWarning) {
+        self.warnings.raise(warning)
+    }
+
+    /// Returns the warnings raised since the last call, clearing them.
+    pub(crate) fn take_warnings(&self) -> Vec<ExecutionWarning> {
+        self.warnings.take()
     }
 
     /// Executes a WASM module.
@@ -100,11 +139,13 @@ impl Executor {
             spending_limit,
         );
 
-        let mut runtime = Runtime::new(self.config.clone(), context);
+        let mut runtime = Runtime::new(self.config.clone(), context)
+            .with_memory_high_water_mark(self.memory_high_water_mark.clone())
+            .with_warnings(self.warnings.clone());
 
         let result = match execution_kind {
             ExecutionKind::Module(module_bytes) => {
-                runtime.execute_module_bytes(&module_bytes, stack)
+                runtime.execute_module_bytes(&module_bytes, stack, &self.module_cache)
             }
             ExecutionKind::Contract {
                 contract_hash,
@@ -122,12 +163,17 @@ impl Executor {
                 execution_journal: runtime.context().execution_journal(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
+                events: runtime.context().events().to_owned(),
             },
             Err(error) => ExecutionResult::Failure {
                 error: error.into(),
                 execution_journal: runtime.context().execution_journal(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
             },
         }
     }
@@ -197,12 +243,17 @@ impl Executor {
                 execution_journal: runtime.context().execution_journal(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
+                events: runtime.context().events().to_owned(),
             },
             Err(error) => ExecutionResult::Failure {
                 execution_journal,
                 error: error.into(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
             },
         }
     }
@@ -250,7 +301,7 @@ impl Executor {
 
         let entry_point_name = direct_system_contract_call.entry_point_name();
 
-        let contract_hash = match direct_system_contract_call {
+        let contract_hash = match &direct_system_contract_call {
             DirectSystemContractCall::Slash
             | DirectSystemContractCall::RunAuction
             | DirectSystemContractCall::DistributeRewards => {
@@ -273,6 +324,15 @@ impl Executor {
                     .expect("should have handle payment");
                 *handle_payment_hash
             }
+            DirectSystemContractCall::Extension { contract_name, .. } => {
+                match system_contract_registry.get(contract_name) {
+                    Some(contract_hash) => *contract_hash,
+                    None => {
+                        let error = Error::MissingSystemContractHash(contract_name.clone());
+                        return (None, ExecutionResult::precondition_failure(error.into()));
+                    }
+                }
+            }
         };
 
         let contract = match tracking_copy
@@ -322,6 +382,9 @@ impl Executor {
                     execution_journal: runtime.context().execution_journal(),
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    read_keys: None,
+                    warnings: Vec::new(),
+                    events: runtime.context().events().to_owned(),
                 }
                 .take_with_ret(ret),
                 Err(error) => ExecutionResult::Failure {
@@ -329,6 +392,8 @@ impl Executor {
                     error: Error::CLValue(error).into(),
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    read_keys: None,
+                    warnings: Vec::new(),
                 }
                 .take_without_ret(),
             },
@@ -337,11 +402,173 @@ impl Executor {
                 error: error.into(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
             }
             .take_without_ret(),
         }
     }
 
+    /// Calls the [`METHOD_AUTHORIZE`] entry point of an account's authorization contract in the
+    /// context of the account, returning whether it approved the deploy.
+    ///
+    /// The call is bounded by `gas_limit`.  It's up to the caller to discard the effects written
+    /// to `tracking_copy`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn call_authorization_contract<R>(
+        &self,
+        contract_hash: ContractHash,
+        runtime_args: RuntimeArgs,
+        account: &Account,
+        authorization_keys: BTreeSet<AccountHash>,
+        blocktime: BlockTime,
+        deploy_hash: DeployHash,
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        stack: RuntimeStack,
+    ) -> Result<bool, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        let address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), Phase::Session);
+            Rc::new(RefCell::new(generator))
+        };
+
+        let mut named_keys = account.named_keys().clone();
+        let access_rights = account.extract_access_rights();
+
+        let runtime_context = self.create_runtime_context(
+            EntryPointType::Session,
+            runtime_args.clone(),
+            &mut named_keys,
+            access_rights,
+            Key::from(account.account_hash()),
+            account,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            address_generator,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::Session,
+            U512::zero(),
+        );
+
+        let mut runtime = Runtime::new(self.config.clone(), runtime_context);
+
+        runtime
+            .call_contract_with_stack(contract_hash, METHOD_AUTHORIZE, runtime_args, stack)?
+            .into_t()
+            .map_err(Error::CLValue)
+    }
+
+    /// Calls an entry point of a contract on behalf of a callback scheduled by the contract, in
+    /// the context of the account which scheduled it.
+    ///
+    /// The call is bounded by `gas_limit` and isn't authorized by any keys of the account.  It's up
+    /// to the caller to discard the effects written to `tracking_copy` if the call fails.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn call_scheduled_callback<R>(
+        &self,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+        runtime_args: RuntimeArgs,
+        account: &Account,
+        blocktime: BlockTime,
+        deploy_hash: DeployHash,
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        stack: RuntimeStack,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        let execution_journal = tracking_copy.borrow().execution_journal();
+
+        // Session entry points would run with the account's authority, so only stored contract
+        // code may be called back.
+        let entry_point_type = match tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, contract_hash)
+        {
+            Ok(contract) => match contract.entry_point(entry_point_name) {
+                Some(entry_point) => entry_point.entry_point_type(),
+                None => {
+                    let error = Error::NoSuchMethod(entry_point_name.to_string());
+                    return ExecutionResult::precondition_failure(error.into());
+                }
+            },
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
+        };
+        if entry_point_type == EntryPointType::Session {
+            return ExecutionResult::precondition_failure(Error::InvalidContext.into());
+        }
+
+        let address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), Phase::Session);
+            Rc::new(RefCell::new(generator))
+        };
+
+        let mut named_keys = account.named_keys().clone();
+        let mut access_rights = account.extract_access_rights();
+        for arg in runtime_args.to_values() {
+            match utils::extract_urefs(arg) {
+                Ok(urefs) => access_rights.extend(&urefs),
+                Err(error) => return ExecutionResult::precondition_failure(error.into()),
+            }
+        }
+
+        let runtime_context = self.create_runtime_context(
+            EntryPointType::Session,
+            runtime_args.clone(),
+            &mut named_keys,
+            access_rights,
+            Key::from(account.account_hash()),
+            account,
+            BTreeSet::new(),
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            address_generator,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::Session,
+            U512::zero(),
+        );
+
+        let mut runtime = Runtime::new(self.config.clone(), runtime_context);
+
+        match runtime.call_contract_with_stack(contract_hash, entry_point_name, runtime_args, stack)
+        {
+            Ok(_) => ExecutionResult::Success {
+                execution_journal: runtime.context().execution_journal(),
+                transfers: runtime.context().transfers().to_owned(),
+                cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
+                events: runtime.context().events().to_owned(),
+            },
+            Err(error) => ExecutionResult::Failure {
+                execution_journal,
+                error: error.into(),
+                transfers: runtime.context().transfers().to_owned(),
+                cost: runtime.context().gas_counter(),
+                read_keys: None,
+                warnings: Vec::new(),
+            },
+        }
+    }
+
     /// Creates new runtime context.
     #[allow(clippy::too_many_arguments)]
     fn create_runtime_context<'a, R>(
@@ -370,6 +597,15 @@ impl Executor {
         let gas_counter = Gas::default();
         let transfers = Vec::default();
 
+        // Contracts can read the block time, so it's hidden from them to keep the effects of a
+        // deterministic execution independent of when it ran.  The addresses contracts create
+        // are already derived from nothing but the deploy hash and the phase.
+        let blocktime = if self.config.deterministic_execution() {
+            BlockTime::new(0)
+        } else {
+            blocktime
+        };
+
         RuntimeContext::new(
             tracking_copy,
             entry_point_type,
@@ -412,6 +648,13 @@ pub(crate) enum DirectSystemContractCall {
     GetPaymentPurse,
     /// Calls handle payment's `distribute_accumulated_fees` entry point.
     DistributeAccumulatedFees,
+    /// Calls an entry point of a contract registered as a system contract extension.
+    Extension {
+        /// The name the contract is registered under.
+        contract_name: String,
+        /// The name of the entry point.
+        entry_point: String,
+    },
 }
 
 impl DirectSystemContractCall {
@@ -427,6 +670,7 @@ impl DirectSystemContractCall {
             DirectSystemContractCall::DistributeAccumulatedFees => {
                 handle_payment::METHOD_DISTRIBUTE_ACCUMULATED_FEES
             }
+            DirectSystemContractCall::Extension { entry_point, .. } => entry_point,
         }
     }
 }
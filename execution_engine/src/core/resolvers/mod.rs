@@ -4,7 +4,7 @@ pub mod memory_resolver;
 pub(crate) mod v1_function_index;
 mod v1_resolver;
 
-use casper_wasmi::ModuleImportResolver;
+use casper_wasmi::{ModuleImportResolver, Signature};
 
 use casper_types::ProtocolVersion;
 
@@ -23,11 +23,19 @@ pub(crate) fn create_module_resolver(
     if protocol_version >= ProtocolVersion::V1_0_0 {
         return Ok(v1_resolver::RuntimeModuleImportResolver::new(
             engine_config.wasm_config().max_memory,
+            engine_config.unavailable_host_functions().clone(),
         ));
     }
     Err(ResolverError::UnknownProtocolVersion(protocol_version))
 }
 
+/// Returns `true` if the host exports a function named `field_name` to Wasm modules.
+pub(crate) fn is_host_function(field_name: &str) -> bool {
+    v1_resolver::RuntimeModuleImportResolver::new(0, Default::default())
+        .resolve_func(field_name, &Signature::new(&[][..], None))
+        .is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use casper_types::ProtocolVersion;
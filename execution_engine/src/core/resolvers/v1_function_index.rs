@@ -58,6 +58,21 @@ pub(crate) enum FunctionIndex {
     RandomBytes,
     DictionaryReadFuncIndex,
     EnableContractVersion,
+    CreateContractPackageAtSeed,
+    SetContractPackagePaused,
+    EmitEvent,
+    GetRemainingCallDepth,
+    SortedIndexInsert,
+    SortedIndexRemove,
+    SortedIndexGet,
+    SortedIndexRange,
+    NamespacePut,
+    NamespaceGet,
+    NamespaceClear,
+    NewUniqueAddress,
+    ScheduleCallback,
+    CancelCallback,
+    TransferFromPurseToPurseWithResult,
 }
 
 impl From<FunctionIndex> for usize {
@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, collections::BTreeSet};
 
 use casper_wasmi::{
     memory_units::Pages, Error as InterpreterError, FuncInstance, FuncRef, MemoryDescriptor,
@@ -12,13 +12,15 @@ use super::{
 pub(crate) struct RuntimeModuleImportResolver {
     memory: RefCell<Option<MemoryRef>>,
     max_memory: u32,
+    unavailable_host_functions: BTreeSet<String>,
 }
 
 impl RuntimeModuleImportResolver {
-    pub(crate) fn new(max_memory: u32) -> Self {
+    pub(crate) fn new(max_memory: u32, unavailable_host_functions: BTreeSet<String>) -> Self {
         Self {
             memory: RefCell::new(None),
             max_memory,
+            unavailable_host_functions,
         }
     }
 }
@@ -39,6 +41,12 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
         field_name: &str,
         _signature: &Signature,
     ) -> Result<FuncRef, InterpreterError> {
+        if self.unavailable_host_functions.contains(field_name) {
+            return Err(InterpreterError::Function(format!(
+                "host module doesn't export function with name {}",
+                field_name
+            )));
+        }
         let func_ref = match field_name {
             "casper_read_value" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
@@ -245,6 +253,66 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
                 FunctionIndex::EnableContractVersion.into(),
             ),
+            "casper_create_contract_package_at_seed" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::CreateContractPackageAtSeed.into(),
+            ),
+            "casper_set_contract_package_paused" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::SetContractPackagePaused.into(),
+            ),
+            "casper_emit_event" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::EmitEvent.into(),
+            ),
+            "casper_get_remaining_call_depth" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], None),
+                FunctionIndex::GetRemainingCallDepth.into(),
+            ),
+            "casper_sorted_index_insert" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::SortedIndexInsert.into(),
+            ),
+            "casper_sorted_index_remove" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::SortedIndexRemove.into(),
+            ),
+            "casper_sorted_index_get" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
+                FunctionIndex::SortedIndexGet.into(),
+            ),
+            "casper_sorted_index_range" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32)),
+                FunctionIndex::SortedIndexRange.into(),
+            ),
+            "casper_namespace_put" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::NamespacePut.into(),
+            ),
+            "casper_namespace_get" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
+                FunctionIndex::NamespaceGet.into(),
+            ),
+            "casper_namespace_clear" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], None),
+                FunctionIndex::NamespaceClear.into(),
+            ),
+            "casper_new_unique_address" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::NewUniqueAddress.into(),
+            ),
+            "casper_schedule_callback" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32)),
+                FunctionIndex::ScheduleCallback.into(),
+            ),
+            "casper_cancel_callback" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::CancelCallback.into(),
+            ),
+            "casper_transfer_from_purse_to_purse_with_result" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32)),
+                FunctionIndex::TransferFromPurseToPurseWithResult.into(),
+            ),
             _ => {
                 return Err(InterpreterError::Function(format!(
                     "host module doesn't export function with name {}",
@@ -5,7 +5,7 @@ use casper_types::{
     bytesrepr::{FromBytes, ToBytes},
     crypto,
     system::{
-        auction::{Bid, EraInfo, Error, UnbondingPurse},
+        auction::{Bid, EraInfo, Error, Reservation, UnbondingPurse},
         mint,
     },
     CLTyped, CLValue, EraId, Key, KeyTag, PublicKey, RuntimeArgs, StoredValue, URef,
@@ -14,7 +14,7 @@ use casper_types::{
 
 use super::Runtime;
 use crate::{
-    core::execution,
+    core::{engine_state::engine_config::Quirk, execution},
     storage::global_state::StateReader,
     system::auction::{
         providers::{AccountProvider, MintProvider, RuntimeProvider, StorageProvider},
@@ -39,6 +39,13 @@ where
     R: StateReader<Key, StoredValue>,
     R::Error: Into<execution::Error>,
 {
+    fn new_uref<T: ToBytes + CLTyped>(&mut self, init: T) -> Result<URef, Error> {
+        let cl_value = CLValue::from_t(init).map_err(|_| Error::CLValue)?;
+        self.context
+            .new_uref(StoredValue::CLValue(cl_value))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn read<T: FromBytes + CLTyped>(&mut self, uref: URef) -> Result<Option<T>, Error> {
         match self.context.read_gs(&uref.into()) {
             Ok(Some(StoredValue::CLValue(cl_value))) => {
@@ -106,6 +113,35 @@ where
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
     }
 
+    fn read_reservations(&mut self, account_hash: &AccountHash) -> Result<Vec<Reservation>, Error> {
+        match self.context.read_gs(&Key::Reservations(*account_hash)) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => {
+                Ok(cl_value.into_t().map_err(|_| Error::CLValue)?)
+            }
+            Ok(Some(_)) => Err(Error::Storage),
+            Ok(None) => Ok(Vec::new()),
+            Err(execution::Error::BytesRepr(_)) => Err(Error::Serialization),
+            // NOTE: This extra condition is needed to correctly propagate GasLimit to the user. See
+            // also [`Runtime::reverter`] and [`to_auction_error`]
+            Err(execution::Error::GasLimit) => Err(Error::GasLimit),
+            Err(_) => Err(Error::Storage),
+        }
+    }
+
+    fn write_reservations(
+        &mut self,
+        account_hash: AccountHash,
+        reservations: Vec<Reservation>,
+    ) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(reservations).map_err(|_| Error::CLValue)?;
+        self.context
+            .metered_write_gs_unsafe(
+                Key::Reservations(account_hash),
+                StoredValue::CLValue(cl_value),
+            )
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn record_era_info(&mut self, _era_id: EraId, era_summary: EraInfo) -> Result<(), Error> {
         Runtime::record_era_summary(self, era_summary)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::RecordEraInfo))
@@ -129,6 +165,12 @@ where
         self.context.named_keys_get(name).cloned()
     }
 
+    fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error> {
+        self.context
+            .put_key(name.to_string(), key)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error> {
         self.context.get_keys(key_tag).map_err(|_| Error::Storage)
     }
@@ -144,6 +186,10 @@ where
     fn allow_auction_bids(&self) -> bool {
         self.config.allow_auction_bids()
     }
+
+    fn record_delegator_rewards(&self) -> bool {
+        !self.config.has_quirk(Quirk::ReinvestOnlyDelegatorRewards)
+    }
 }
 
 impl<'a, R> MintProvider for Runtime<'a, R>
@@ -6,7 +6,7 @@ use casper_types::{
     account::AccountHash,
     api_error,
     bytesrepr::{self, ToBytes},
-    contracts::{ContractPackageStatus, EntryPoints, NamedKeys},
+    contracts::{ContractPackageStatus, EntryPoints, NamedKeys, INSTALL_SEED_LENGTH},
     crypto,
     system::auction::EraInfo,
     ApiError, ContractHash, ContractPackageHash, ContractVersion, EraId, Gas, Group, Key,
@@ -16,7 +16,7 @@ use casper_types::{
 use super::{args::Args, Error, Runtime};
 use crate::{
     core::resolvers::v1_function_index::FunctionIndex,
-    shared::host_function_costs::{Cost, HostFunction},
+    shared::host_function_costs::{Cost, HostFunction, UNLISTED_HOST_FUNCTION_COST},
     storage::global_state::StateReader,
 };
 
@@ -1034,7 +1034,7 @@ where
                 let (call_stack_len_ptr, result_size_ptr) = Args::parse(args)?;
                 // TODO: add cost table entry once we can upgrade safely
                 self.charge_host_function_call(
-                    &HostFunction::fixed(10_000),
+                    &HostFunction::fixed(UNLISTED_HOST_FUNCTION_COST),
                     [call_stack_len_ptr, result_size_ptr],
                 )?;
                 let ret = self.load_call_stack(call_stack_len_ptr, result_size_ptr)?;
@@ -1046,7 +1046,7 @@ where
                 // args(1) (Output) Pointer to size in bytes of the total bytes.
                 let (len_ptr, result_size_ptr) = Args::parse(args)?;
                 self.charge_host_function_call(
-                    &HostFunction::fixed(10_000),
+                    &HostFunction::fixed(UNLISTED_HOST_FUNCTION_COST),
                     [len_ptr, result_size_ptr],
                 )?;
                 let ret = self.load_authorization_keys(len_ptr, result_size_ptr)?;
@@ -1077,6 +1077,75 @@ where
 
                 Ok(Some(RuntimeValue::I32(0)))
             }
+            FunctionIndex::CreateContractPackageAtSeed => {
+                // args(0) = pointer to install seed in wasm memory
+                // args(1) = size of install seed in wasm memory
+                // args(2) = pointer to wasm memory where to write 32-byte Hash address
+                // args(3) = pointer to wasm memory where to write 32-byte access key address
+                // args(4) = boolean flag to determine if the contract can be versioned
+                // args(5) = pointer to output size (output param)
+                let (
+                    seed_ptr,
+                    seed_size,
+                    hash_dest_ptr,
+                    access_dest_ptr,
+                    is_locked,
+                    output_size_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.create_contract_package_at_seed,
+                    [
+                        seed_ptr,
+                        seed_size,
+                        hash_dest_ptr,
+                        access_dest_ptr,
+                        u32::from(is_locked),
+                        output_size_ptr,
+                    ],
+                )?;
+                let install_seed: [u8; INSTALL_SEED_LENGTH] =
+                    self.t_from_mem(seed_ptr, seed_size)?;
+                let package_status = ContractPackageStatus::new(is_locked);
+                let seeded_package =
+                    self.create_contract_package_at_seed(install_seed, package_status)?;
+
+                self.function_address(seeded_package.package_addr, hash_dest_ptr)?;
+                self.function_address(seeded_package.access_addr, access_dest_ptr)?;
+                let ret =
+                    self.write_installed_version(seeded_package.latest_contract, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::EmitEvent => {
+                // args(0) = pointer to event name in wasm memory
+                // args(1) = size of event name in wasm memory
+                // args(2) = pointer to serialized event data
+                // args(3) = size of serialized event data
+                let (name_ptr, name_size, data_ptr, data_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_event,
+                    [name_ptr, name_size, data_ptr, data_size],
+                )?;
+                let ret = self.emit_event(name_ptr, name_size, data_ptr, data_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SetContractPackagePaused => {
+                // args(0) = pointer to package hash in wasm memory
+                // args(1) = size of package hash in wasm memory
+                // args(2) = boolean flag to determine if the package is paused
+                let (package_key_ptr, package_key_size, paused) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.set_contract_package_paused,
+                    [package_key_ptr, package_key_size, u32::from(paused)],
+                )?;
+                let contract_package_hash = self.t_from_mem(package_key_ptr, package_key_size)?;
+
+                self.set_contract_package_paused(contract_package_hash, paused)?;
+
+                Ok(Some(RuntimeValue::I32(0)))
+            }
+
             FunctionIndex::EnableContractVersion => {
                 // args(0) = pointer to package hash in wasm memory
                 // args(1) = size of package hash in wasm memory
@@ -1100,6 +1169,373 @@ where
 
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
             }
+
+            FunctionIndex::GetRemainingCallDepth => {
+                // args(0) = pointer to Wasm memory where to write.
+                let (dest_ptr,) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.get_remaining_call_depth,
+                    [dest_ptr],
+                )?;
+                self.get_remaining_call_depth(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::SortedIndexInsert => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                // args(2) = pointer to entry key in Wasm memory
+                // args(3) = size of entry key in Wasm memory
+                // args(4) = pointer to value bytes in Wasm memory
+                // args(5) = size of value bytes in Wasm memory
+                let (uref_ptr, uref_size, entry_key_ptr, entry_key_size, value_ptr, value_size): (
+                    _,
+                    u32,
+                    _,
+                    u32,
+                    _,
+                    u32,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.sorted_index_insert,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        entry_key_ptr,
+                        entry_key_size,
+                        value_ptr,
+                        value_size,
+                    ],
+                )?;
+                let ret = self.sorted_index_insert(
+                    uref_ptr,
+                    uref_size,
+                    entry_key_ptr,
+                    entry_key_size,
+                    value_ptr,
+                    value_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SortedIndexRemove => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                // args(2) = pointer to entry key in Wasm memory
+                // args(3) = size of entry key in Wasm memory
+                let (uref_ptr, uref_size, entry_key_ptr, entry_key_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.sorted_index_remove,
+                    [uref_ptr, uref_size, entry_key_ptr, entry_key_size],
+                )?;
+                let ret =
+                    self.sorted_index_remove(uref_ptr, uref_size, entry_key_ptr, entry_key_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SortedIndexGet => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                // args(2) = pointer to entry key in Wasm memory
+                // args(3) = size of entry key in Wasm memory
+                // args(4) = pointer to output size (output param)
+                let (uref_ptr, uref_size, entry_key_ptr, entry_key_size, output_size_ptr): (
+                    _,
+                    u32,
+                    _,
+                    u32,
+                    _,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.sorted_index_get,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        entry_key_ptr,
+                        entry_key_size,
+                        output_size_ptr,
+                    ],
+                )?;
+                let ret = self.sorted_index_get(
+                    uref_ptr,
+                    uref_size,
+                    entry_key_ptr,
+                    entry_key_size,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SortedIndexRange => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                // args(2) = pointer to optional start key in Wasm memory
+                // args(3) = size of optional start key in Wasm memory
+                // args(4) = maximum number of entry keys to return
+                // args(5) = non-zero to return entry keys in descending order
+                // args(6) = pointer to output size (output param)
+                let (
+                    uref_ptr,
+                    uref_size,
+                    start_ptr,
+                    start_size,
+                    limit,
+                    descending,
+                    output_size_ptr,
+                ): (_, u32, _, u32, u32, u32, _) = Args::parse(args)?;
+                // Each entry visited is additionally charged as a read.
+                self.charge_host_function_call(
+                    &host_function_costs.sorted_index_range,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        start_ptr,
+                        start_size,
+                        limit,
+                        descending,
+                        output_size_ptr,
+                    ],
+                )?;
+                let ret = self.sorted_index_range(
+                    uref_ptr,
+                    uref_size,
+                    start_ptr,
+                    start_size,
+                    limit,
+                    descending,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::NamespacePut => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                // args(2) = pointer to item key in Wasm memory
+                // args(3) = size of item key in Wasm memory
+                // args(4) = pointer to value bytes in Wasm memory
+                // args(5) = size of value bytes in Wasm memory
+                let (uref_ptr, uref_size, key_bytes_ptr, key_bytes_size, value_ptr, value_size): (
+                    _,
+                    u32,
+                    _,
+                    u32,
+                    _,
+                    u32,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.namespace_put,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        key_bytes_ptr,
+                        key_bytes_size,
+                        value_ptr,
+                        value_size,
+                    ],
+                )?;
+                let ret = self.namespace_put(
+                    uref_ptr,
+                    uref_size,
+                    key_bytes_ptr,
+                    key_bytes_size,
+                    value_ptr,
+                    value_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::NamespaceGet => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                // args(2) = pointer to item key in Wasm memory
+                // args(3) = size of item key in Wasm memory
+                // args(4) = pointer to output size (output param)
+                let (uref_ptr, uref_size, key_bytes_ptr, key_bytes_size, output_size_ptr): (
+                    _,
+                    u32,
+                    _,
+                    u32,
+                    _,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.namespace_get,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        key_bytes_ptr,
+                        key_bytes_size,
+                        output_size_ptr,
+                    ],
+                )?;
+                let ret = self.namespace_get(
+                    uref_ptr,
+                    uref_size,
+                    key_bytes_ptr,
+                    key_bytes_size,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::NamespaceClear => {
+                // args(0) = pointer to uref in Wasm memory
+                // args(1) = size of uref in Wasm memory
+                let (uref_ptr, uref_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.namespace_clear,
+                    [uref_ptr, uref_size],
+                )?;
+                self.namespace_clear(uref_ptr, uref_size)?;
+                Ok(None)
+            }
+            FunctionIndex::NewUniqueAddress => {
+                // args(0) = pointer to tag in Wasm memory
+                // args(1) = size of tag in Wasm memory
+                // args(2) = pointer to output buffer of 32 bytes for the address
+                let (tag_ptr, tag_size, out_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.new_unique_address,
+                    [tag_ptr, tag_size, out_ptr],
+                )?;
+                let tag = self.bytes_from_mem(tag_ptr, tag_size as usize)?;
+                let address = self.context.new_unique_address(&tag)?;
+                self.try_get_memory()?
+                    .set(out_ptr, &address)
+                    .map_err(|error| Error::Interpreter(error.into()))?;
+                Ok(Some(RuntimeValue::I32(0)))
+            }
+            FunctionIndex::ScheduleCallback => {
+                // args(0) = pointer to entry point name in Wasm memory
+                // args(1) = size of entry point name in Wasm memory
+                // args(2) = pointer to runtime args in Wasm memory
+                // args(3) = size of runtime args in Wasm memory
+                // args(4) = pointer to (era interval, gas budget) in Wasm memory
+                // args(5) = size of (era interval, gas budget) in Wasm memory
+                // args(6) = pointer to purse in Wasm memory
+                // args(7) = size of purse in Wasm memory
+                // args(8) = pointer to output buffer of 32 bytes for the callback address
+                let (
+                    entry_point_ptr,
+                    entry_point_size,
+                    args_ptr,
+                    args_size,
+                    schedule_ptr,
+                    schedule_size,
+                    purse_ptr,
+                    purse_size,
+                    out_ptr,
+                ): (_, u32, _, u32, _, _, _, _, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.schedule_callback,
+                    [
+                        entry_point_ptr,
+                        entry_point_size,
+                        args_ptr,
+                        args_size,
+                        schedule_ptr,
+                        schedule_size,
+                        purse_ptr,
+                        purse_size,
+                        out_ptr,
+                    ],
+                )?;
+                let ret = self.schedule_callback(
+                    entry_point_ptr,
+                    entry_point_size,
+                    args_ptr,
+                    args_size,
+                    schedule_ptr,
+                    schedule_size,
+                    purse_ptr,
+                    purse_size,
+                    out_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+            FunctionIndex::CancelCallback => {
+                // args(0) = pointer to the 32 byte callback address in Wasm memory
+                let (callback_addr_ptr,) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.cancel_callback,
+                    [callback_addr_ptr],
+                )?;
+                let ret = self.cancel_callback(callback_addr_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+            FunctionIndex::TransferFromPurseToPurseWithResult => {
+                // args(0) = pointer to array of bytes in Wasm memory of a source purse
+                // args(1) = length of array of bytes in Wasm memory of a source purse
+                // args(2) = pointer to array of bytes in Wasm memory of a target purse
+                // args(3) = length of array of bytes in Wasm memory of a target purse
+                // args(4) = pointer to array of bytes in Wasm memory of an amount
+                // args(5) = length of array of bytes in Wasm memory of an amount
+                // args(6) = pointer to array of bytes in Wasm memory of an id
+                // args(7) = length of array of bytes in Wasm memory of an id
+                // args(8) = pointer to the output location of the serialized
+                //           `Option<TransferAddr>`
+                let (
+                    source_ptr,
+                    source_size,
+                    target_ptr,
+                    target_size,
+                    amount_ptr,
+                    amount_size,
+                    id_ptr,
+                    id_size,
+                    result_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_from_purse_to_purse_with_result,
+                    [
+                        source_ptr,
+                        source_size,
+                        target_ptr,
+                        target_size,
+                        amount_ptr,
+                        amount_size,
+                        id_ptr,
+                        id_size,
+                        result_ptr,
+                    ],
+                )?;
+
+                let source: URef = {
+                    let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
+                    bytesrepr::deserialize_from_slice(bytes).map_err(Error::BytesRepr)?
+                };
+
+                let target: URef = {
+                    let bytes = self.bytes_from_mem(target_ptr, target_size as usize)?;
+                    bytesrepr::deserialize_from_slice(bytes).map_err(Error::BytesRepr)?
+                };
+
+                let amount: U512 = {
+                    let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
+                    bytesrepr::deserialize_from_slice(bytes).map_err(Error::BytesRepr)?
+                };
+
+                let id: Option<u64> = {
+                    let bytes = self.bytes_from_mem(id_ptr, id_size as usize)?;
+                    bytesrepr::deserialize_from_slice(bytes).map_err(Error::BytesRepr)?
+                };
+
+                let ret = match self
+                    .transfer_from_purse_to_purse_with_result(source, target, amount, id)?
+                {
+                    Ok(maybe_transfer_addr) => {
+                        let result_bytes =
+                            maybe_transfer_addr.to_bytes().map_err(Error::BytesRepr)?;
+                        self.try_get_memory()?
+                            .set(result_ptr, &result_bytes)
+                            .map_err(|error| Error::Interpreter(error.into()))?;
+                        Ok(())
+                    }
+                    Err(mint_error) => Err(mint_error),
+                };
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
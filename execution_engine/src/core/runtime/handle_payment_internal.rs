@@ -2,12 +2,12 @@ use std::collections::BTreeSet;
 
 use casper_types::{
     account::AccountHash, system::handle_payment::Error, BlockTime, CLValue, Key, Phase,
-    StoredValue, TransferredTo, URef, U512,
+    StoredValue, SystemTransferKind, TransferredTo, URef, U512,
 };
 
 use crate::{
     core::{
-        engine_state::engine_config::{FeeHandling, RefundHandling},
+        engine_state::engine_config::{FeeDistribution, FeeHandling, RefundHandling},
         execution,
         runtime::Runtime,
     },
@@ -131,9 +131,38 @@ where
         self.config.fee_handling()
     }
 
+    fn fee_distribution(&self) -> &FeeDistribution {
+        self.config.fee_distribution()
+    }
+
     fn administrative_accounts(&self) -> &BTreeSet<AccountHash> {
         self.config.administrative_accounts()
     }
+
+    fn record_payment_transfer(
+        &mut self,
+        kind: SystemTransferKind,
+        to: Option<AccountHash>,
+        source: URef,
+        target: Option<URef>,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let target = match (target, to) {
+            (Some(target), _) => target,
+            (None, Some(account_hash)) => {
+                match self.context.read_account(&Key::Account(account_hash)) {
+                    Ok(Some(StoredValue::Account(account))) => account.main_purse_add_only(),
+                    Ok(_) => return Err(Error::FailedTransferToAccountPurse),
+                    Err(exec_error) => {
+                        return Err(<Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+                    }
+                }
+            }
+            (None, None) => return Err(Error::Transfer),
+        };
+        Runtime::record_system_transfer(self, kind, to, source, target, amount)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
 }
 
 impl<'a, R> StorageProvider for Runtime<'a, R>
@@ -0,0 +1,31 @@
+use std::{cell::Cell, rc::Rc};
+
+use casper_wasmi::{memory_units::Bytes, MemoryRef};
+
+/// The size in bytes of the largest Wasm memory used while executing a deploy.
+///
+/// A deploy may run several Wasm instances, i.e. its payment and session code along with the stored
+/// contracts they call, each with its own memory.  As a Wasm memory never shrinks, its size once
+/// its instance has returned is the most it used.
+///
+/// The high-water mark is backed by an `Rc<Cell<u64>>`, meaning that clones will all share state.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct MemoryHighWaterMark {
+    bytes: Rc<Cell<u64>>,
+}
+
+impl MemoryHighWaterMark {
+    /// Raises the high-water mark to the current size of `memory` if that's larger.
+    pub(crate) fn record(&self, memory: &MemoryRef) {
+        let Bytes(bytes) = Bytes::from(memory.current_size());
+        let bytes = u64::try_from(bytes).unwrap_or(u64::MAX);
+        if bytes > self.bytes.get() {
+            self.bytes.set(bytes);
+        }
+    }
+
+    /// Returns the high-water mark, resetting it to zero.
+    pub(crate) fn take(&self) -> u64 {
+        self.bytes.replace(0)
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
@@ -7,7 +9,10 @@ use casper_types::{
 
 use super::Runtime;
 use crate::{
-    core::{engine_state::SystemContractRegistry, execution},
+    core::{
+        engine_state::{engine_config::ExistentialDepositHandling, SystemContractRegistry},
+        execution,
+    },
     storage::global_state::StateReader,
     system::mint::{
         runtime_provider::RuntimeProvider, storage_provider::StorageProvider,
@@ -40,6 +45,10 @@ where
         Runtime::<'a, R>::get_immediate_caller(self)
     }
 
+    fn get_authorization_keys(&self) -> &BTreeSet<AccountHash> {
+        self.context.authorization_keys()
+    }
+
     fn get_phase(&self) -> Phase {
         self.context.phase()
     }
@@ -72,10 +81,26 @@ where
         self.config.is_administrator(account_hash)
     }
 
+    fn administrative_accounts(&self) -> &BTreeSet<AccountHash> {
+        self.config.administrative_accounts()
+    }
+
     fn allow_unrestricted_transfers(&self) -> bool {
         self.config.allow_unrestricted_transfers()
     }
 
+    fn existential_deposit(&self) -> U512 {
+        U512::from(self.config.existential_deposit())
+    }
+
+    fn existential_deposit_handling(&self) -> ExistentialDepositHandling {
+        self.config.existential_deposit_handling()
+    }
+
+    fn enable_purse_ownership_index(&self) -> bool {
+        self.config.enable_purse_ownership_index()
+    }
+
     fn get_system_contract_registry(&self) -> Result<SystemContractRegistry, execution::Error> {
         self.context.system_contract_registry()
     }
@@ -162,6 +187,26 @@ where
             .metered_add_gs_unsafe(Key::Balance(uref.addr()), StoredValue::CLValue(cl_value))
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
     }
+
+    fn write_administrative_accounts(
+        &mut self,
+        administrators: BTreeSet<AccountHash>,
+    ) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(administrators).map_err(|_| Error::CLValue)?;
+        self.context
+            .metered_write_gs_unsafe(Key::AdministratorRegistry, StoredValue::CLValue(cl_value))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
+    fn write_purse_owner(&mut self, purse: URef, owner: Key) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(owner).map_err(|_| Error::CLValue)?;
+        self.context
+            .metered_write_gs_unsafe(
+                Key::PurseOwner(purse.addr()),
+                StoredValue::CLValue(cl_value),
+            )
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
 }
 
 impl<'a, R> SystemProvider for Runtime<'a, R>
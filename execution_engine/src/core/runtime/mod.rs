@@ -4,10 +4,11 @@ mod auction_internal;
 mod externals;
 mod handle_payment_internal;
 mod host_function_flag;
+pub(crate) mod memory_high_water_mark;
 mod mint_internal;
 pub mod stack;
 mod standard_payment_internal;
-mod utils;
+pub(crate) mod utils;
 
 use std::{
     cmp,
@@ -32,7 +33,7 @@ use casper_types::{
     contracts::{
         self, Contract, ContractPackage, ContractPackageStatus, ContractVersion, ContractVersions,
         DisabledVersions, EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
-        DEFAULT_ENTRY_POINT_NAME,
+        DEFAULT_ENTRY_POINT_NAME, INSTALL_SEED_LENGTH,
     },
     system::{
         self,
@@ -40,22 +41,31 @@ use casper_types::{
         handle_payment, mint, standard_payment, CallStackElement, SystemContractType, AUCTION,
         HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
     },
-    AccessRights, ApiError, CLTyped, CLValue, ContextAccessRights, ContractHash,
-    ContractPackageHash, ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Gas,
-    GrantedAccess, Key, NamedArg, Parameter, Phase, PublicKey, RuntimeArgs, StoredValue, Transfer,
-    TransferResult, TransferredTo, URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, U512,
+    AccessRights, ApiError, CLTyped, CLValue, ContextAccessRights, ContractEvent, ContractHash,
+    ContractPackageHash, ContractVersionKey, ContractWasm, DeployHash, EntryPointType, EraId, Gas,
+    GrantedAccess, Key, NamedArg, Parameter, Phase, PublicKey, RuntimeArgs, ScheduledCallbackAddr,
+    StoredValue, SystemTransferKind, Transfer, TransferAddr, TransferResult, TransferredTo, URef,
+    CONTRACT_EVENT_NAME_MAX_LENGTH, DICTIONARY_ITEM_KEY_MAX_LENGTH, KEY_SCHEDULED_CALLBACK_LENGTH,
+    U128, U512,
 };
 
 use crate::{
     core::{
-        engine_state::EngineConfig,
+        engine_state::{
+            engine_config::Quirk,
+            execution_warning::{ExecutionWarning, ExecutionWarnings},
+            EngineConfig,
+        },
         execution::{self, Error},
-        runtime::host_function_flag::HostFunctionFlag,
+        runtime::{
+            host_function_flag::HostFunctionFlag, memory_high_water_mark::MemoryHighWaterMark,
+        },
         runtime_context::{self, RuntimeContext},
         tracking_copy::TrackingCopyExt,
     },
     shared::{
         host_function_costs::{Cost, HostFunction},
+        module_cache::ModuleCache,
         wasm_prep::{self, PreprocessingError},
     },
     storage::global_state::StateReader,
@@ -76,6 +86,16 @@ enum CallContractIdentifier {
     },
 }
 
+/// A contract package installed at the address derived from an install seed.
+struct SeededContractPackage {
+    /// The address of the contract package.
+    package_addr: [u8; 32],
+    /// The address of the access key of the contract package.
+    access_addr: [u8; 32],
+    /// The hash and version of the latest enabled contract of the package, if any.
+    latest_contract: Option<(ContractHash, ContractVersion)>,
+}
+
 /// Represents the runtime properties of a WASM execution.
 pub struct Runtime<'a, R> {
     config: EngineConfig,
@@ -85,6 +105,8 @@ pub struct Runtime<'a, R> {
     context: RuntimeContext<'a, R>,
     stack: Option<RuntimeStack>,
     host_function_flag: HostFunctionFlag,
+    memory_high_water_mark: MemoryHighWaterMark,
+    warnings: ExecutionWarnings,
 }
 
 impl<'a, R> Runtime<'a, R>
@@ -102,9 +124,28 @@ where
             context,
             stack: None,
             host_function_flag: HostFunctionFlag::default(),
+            memory_high_water_mark: MemoryHighWaterMark::default(),
+            warnings: ExecutionWarnings::default(),
         }
     }
 
+    /// Sets the memory high-water mark which this runtime and the runtimes of the contracts it
+    /// calls record the sizes of their Wasm memories in.
+    pub(crate) fn with_memory_high_water_mark(
+        mut self,
+        memory_high_water_mark: MemoryHighWaterMark,
+    ) -> Self {
+        self.memory_high_water_mark = memory_high_water_mark;
+        self
+    }
+
+    /// Sets the warnings which this runtime and the runtimes of the contracts it calls raise
+    /// warnings in.
+    pub(crate) fn with_warnings(mut self, warnings: ExecutionWarnings) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
     /// Creates a new runtime instance by cloning the config, and host function flag from `self`.
     fn new_invocation_runtime(
         &self,
@@ -122,6 +163,8 @@ where
             context,
             stack: Some(stack),
             host_function_flag: self.host_function_flag.clone(),
+            memory_high_water_mark: self.memory_high_water_mark.clone(),
+            warnings: self.warnings.clone(),
         }
     }
 
@@ -140,6 +183,15 @@ where
             context,
             stack: Some(stack),
             host_function_flag: self.host_function_flag.clone(),
+            memory_high_water_mark: self.memory_high_water_mark.clone(),
+            warnings: self.warnings.clone(),
+        }
+    }
+
+    /// Records the size of this runtime's Wasm memory, if any, in the memory high-water mark.
+    fn record_memory_usage(&self) {
+        if let Some(memory) = self.memory.as_ref() {
+            self.memory_high_water_mark.record(memory);
         }
     }
 
@@ -415,6 +467,19 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes the number of further contract calls which can be made before the runtime stack
+    /// overflows to dest_ptr in the Wasm memory.
+    fn get_remaining_call_depth(&mut self, dest_ptr: u32) -> Result<(), Trap> {
+        let remaining_call_depth =
+            u32::try_from(self.try_get_stack()?.remaining_call_depth()).unwrap_or(u32::MAX);
+        let bytes = remaining_call_depth
+            .into_bytes()
+            .map_err(Error::BytesRepr)?;
+        self.try_get_memory()?
+            .set(dest_ptr, &bytes)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
     /// Writes current blocktime to dest_ptr in Wasm memory.
     fn get_blocktime(&self, dest_ptr: u32) -> Result<(), Trap> {
         let blocktime = self
@@ -700,6 +765,18 @@ where
                     mint_runtime.mint_into_existing_purse(existing_purse, amount);
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
+            // Type: `fn update_administrators(administrators: Vec<AccountHash>)`
+            mint::METHOD_UPDATE_ADMINISTRATORS => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.update_administrators)?;
+
+                let administrators: BTreeSet<AccountHash> =
+                    Self::get_named_argument(runtime_args, mint::ARG_ADMINISTRATORS)?;
+
+                mint_runtime
+                    .update_administrators(administrators)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
 
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
@@ -798,8 +875,13 @@ where
             })(),
             handle_payment::METHOD_DISTRIBUTE_ACCUMULATED_FEES => (|| {
                 runtime.charge_system_contract_call(handle_payment_costs.finalize_payment)?;
+
+                let next_era_id: EraId =
+                    Self::get_named_argument(runtime_args, handle_payment::ARG_NEXT_ERA_ID)?;
+                let validators: BTreeSet<PublicKey> =
+                    Self::get_named_argument(runtime_args, handle_payment::ARG_VALIDATORS)?;
                 runtime
-                    .distribute_accumulated_fees()
+                    .distribute_accumulated_fees(next_era_id, validators)
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)
             })(),
@@ -843,6 +925,13 @@ where
         access_rights: ContextAccessRights,
         stack: RuntimeStack,
     ) -> Result<CLValue, Error> {
+        if self
+            .config
+            .is_auction_entry_point_disabled(entry_point_name)
+        {
+            return Err(Self::reverter(auction::Error::EntryPointDisabled));
+        }
+
         let gas_counter = self.gas_counter();
 
         let auction_hash = self.context.get_system_contract(AUCTION)?;
@@ -884,8 +973,17 @@ where
                     Self::get_named_argument(runtime_args, auction::ARG_DELEGATION_RATE)?;
                 let amount = Self::get_named_argument(runtime_args, auction::ARG_AMOUNT)?;
 
+                let delegation_rate_change_cooldown = self.config.delegation_rate_change_cooldown();
+                let max_delegation_rate_increase = self.config.max_delegation_rate_increase();
+
                 let result = runtime
-                    .add_bid(account_hash, delegation_rate, amount)
+                    .add_bid(
+                        account_hash,
+                        delegation_rate,
+                        amount,
+                        delegation_rate_change_cooldown,
+                        max_delegation_rate_increase,
+                    )
                     .map_err(Self::reverter)?;
 
                 CLValue::from_t(result).map_err(Self::reverter)
@@ -1028,6 +1126,71 @@ where
                 CLValue::from_t(()).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_CHANGE_BID_PUBLIC_KEY => (|| {
+                runtime.charge_system_contract_call(auction_costs.change_bid_public_key)?;
+
+                let public_key = Self::get_named_argument(runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let new_public_key =
+                    Self::get_named_argument(runtime_args, auction::ARG_NEW_PUBLIC_KEY)?;
+
+                runtime
+                    .change_bid_public_key(public_key, new_public_key)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_ADD_RESERVATIONS => (|| {
+                runtime.charge_system_contract_call(auction_costs.add_reservations)?;
+
+                let reservations =
+                    Self::get_named_argument(runtime_args, auction::ARG_RESERVATIONS)?;
+
+                let max_delegators_per_validator = self.config.max_delegators_per_validator();
+
+                runtime
+                    .add_reservations(reservations, max_delegators_per_validator)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_CANCEL_RESERVATIONS => (|| {
+                runtime.charge_system_contract_call(auction_costs.cancel_reservations)?;
+
+                let validator = Self::get_named_argument(runtime_args, auction::ARG_VALIDATOR)?;
+                let delegators = Self::get_named_argument(runtime_args, auction::ARG_DELEGATORS)?;
+
+                runtime
+                    .cancel_reservations(validator, delegators)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_WITHDRAW_DELEGATOR_REWARDS => (|| {
+                runtime.charge_system_contract_call(auction_costs.withdraw_delegator_reward)?;
+
+                let delegator: PublicKey =
+                    Self::get_named_argument(runtime_args, auction::ARG_DELEGATOR)?;
+
+                let result = runtime
+                    .withdraw_delegator_rewards(delegator.clone())
+                    .map_err(Self::reverter)?;
+
+                for (validator, amount) in &result {
+                    let data = CLValue::from_t((delegator.clone(), validator.clone(), *amount))
+                        .map_err(Self::reverter)?;
+                    runtime.context.events_mut().push(ContractEvent::new(
+                        auction_hash,
+                        auction::EVENT_DELEGATOR_REWARD_WITHDRAWN.to_string(),
+                        data,
+                    ));
+                }
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
@@ -1046,6 +1209,10 @@ where
             let transfers = self.context.transfers_mut();
             *transfers = runtime.context.transfers().to_owned();
         }
+        {
+            let events = self.context.events_mut();
+            *events = runtime.context.events().to_owned();
+        }
 
         Ok(ret)
     }
@@ -1066,10 +1233,11 @@ where
         &mut self,
         module_bytes: &Bytes,
         stack: RuntimeStack,
+        module_cache: &ModuleCache,
     ) -> Result<CLValue, Error> {
         let protocol_version = self.context.protocol_version();
         let engine_config = self.config.clone();
-        let module = wasm_prep::preprocess(*engine_config.wasm_config(), module_bytes)?;
+        let module = module_cache.preprocess(*engine_config.wasm_config(), module_bytes)?;
         let (instance, memory) =
             utils::instance_and_memory(module.clone(), protocol_version, &engine_config)?;
         self.memory = Some(memory);
@@ -1082,6 +1250,7 @@ where
         )?);
 
         let result = instance.invoke_export(DEFAULT_ENTRY_POINT_NAME, &[], self);
+        self.record_memory_usage();
 
         let error = match result {
             Err(error) => error,
@@ -1151,7 +1320,10 @@ where
         let current = self.context.entry_point_type();
         let next = entry_point.entry_point_type();
         match (current, next) {
-            (EntryPointType::Contract, EntryPointType::Session) => {
+            (
+                EntryPointType::Contract | EntryPointType::NonReentrantContract,
+                EntryPointType::Session,
+            ) => {
                 // Session code can't be called from Contract code for security reasons.
                 Err(Error::InvalidContext)
             }
@@ -1159,8 +1331,9 @@ where
                 // Session code called from session reuses current base key
                 Ok(self.context.base_key())
             }
-            (EntryPointType::Session, EntryPointType::Contract)
-            | (EntryPointType::Contract, EntryPointType::Contract) => Ok(contract_hash.into()),
+            (_, EntryPointType::Contract | EntryPointType::NonReentrantContract) => {
+                Ok(contract_hash.into())
+            }
         }
     }
 
@@ -1238,6 +1411,10 @@ where
             }
         };
 
+        if contract_package.is_paused() {
+            return Err(Error::EntityPaused(contract.contract_package_hash()));
+        }
+
         let entry_point = contract
             .entry_point(entry_point_name)
             .cloned()
@@ -1302,7 +1479,7 @@ where
                 self.context.account().named_keys().clone(),
                 self.context.account().extract_access_rights(),
             ),
-            EntryPointType::Contract => (
+            EntryPointType::Contract | EntryPointType::NonReentrantContract => (
                 contract.named_keys().clone(),
                 contract.extract_access_rights(contract_hash),
             ),
@@ -1317,12 +1494,22 @@ where
                     contract.contract_package_hash(),
                     contract_hash,
                 ),
-                EntryPointType::Contract => CallStackElement::stored_contract(
-                    contract.contract_package_hash(),
-                    contract_hash,
-                ),
+                EntryPointType::Contract | EntryPointType::NonReentrantContract => {
+                    CallStackElement::stored_contract(
+                        contract.contract_package_hash(),
+                        contract_hash,
+                    )
+                }
             };
-            stack.push(call_stack_element)?;
+            if entry_point.entry_point_type() == EntryPointType::NonReentrantContract
+                && stack.is_executing(&contract_hash, entry_point_name)
+            {
+                return Err(Error::ReentrantCall {
+                    contract_hash,
+                    entry_point_name: entry_point_name.to_string(),
+                });
+            }
+            stack.push_call(call_stack_element, entry_point_name.to_string())?;
 
             stack
         };
@@ -1408,16 +1595,23 @@ where
         let runtime = &mut Runtime::new_invocation_runtime(self, context, module, memory, stack);
 
         let result = instance.invoke_export(entry_point.name(), &[], runtime);
+        runtime.record_memory_usage();
 
         // The `runtime`'s context was initialized with our counter from before the call and any gas
         // charged by the sub-call was added to its counter - so let's copy the correct value of the
         // counter from there to our counter.
         self.context.set_gas_counter(runtime.context.gas_counter());
+        self.context
+            .set_storage_refunded(runtime.context.storage_refunded());
 
         {
             let transfers = self.context.transfers_mut();
             *transfers = runtime.context.transfers().to_owned();
         }
+        {
+            let events = self.context.events_mut();
+            *events = runtime.context.events().to_owned();
+        }
 
         let error = match result {
             Err(error) => error,
@@ -1484,6 +1678,11 @@ where
             return Ok(Err(err));
         }
         let args: RuntimeArgs = bytesrepr::deserialize_from_slice(args_bytes)?;
+        // System contracts are only ever called by hash, so calling them that way isn't flagged.
+        if !self.context.is_system_contract(&contract_hash)? {
+            self.warnings
+                .raise(ExecutionWarning::UnversionedContractCall { contract_hash });
+        }
         let result = self.call_contract(contract_hash, entry_point_name, args)?;
         self.manage_call_contract_host_buffer(result_size_ptr, result)
     }
@@ -1621,6 +1820,74 @@ where
         Ok((addr, access_key.addr()))
     }
 
+    /// Creates a contract package at the address derived from the caller and `install_seed`.
+    ///
+    /// If a package already exists at that address nothing is written; its access key is
+    /// returned as long as the current context already holds it, along with the hash and version
+    /// of its latest enabled contract, if any.
+    fn create_contract_package_at_seed(
+        &mut self,
+        install_seed: [u8; INSTALL_SEED_LENGTH],
+        lock_status: ContractPackageStatus,
+    ) -> Result<SeededContractPackage, Error> {
+        let contract_package_hash =
+            ContractPackageHash::from_install_seed(self.context.get_caller(), install_seed);
+        let addr = contract_package_hash.value();
+
+        match self.context.read_gs(&Key::Hash(addr))? {
+            Some(StoredValue::ContractPackage(contract_package)) => {
+                let access_key = contract_package.access_key();
+                self.context.validate_uref(&access_key)?;
+                let latest_contract = contract_package
+                    .current_contract_hash()
+                    .zip(contract_package.current_contract_version())
+                    .map(|(contract_hash, version_key)| {
+                        (contract_hash, version_key.contract_version())
+                    });
+                Ok(SeededContractPackage {
+                    package_addr: addr,
+                    access_addr: access_key.addr(),
+                    latest_contract,
+                })
+            }
+            Some(_) => Err(Error::UnexpectedStoredValueVariant),
+            None => {
+                let (contract_package, access_key) = self.create_contract_package(lock_status)?;
+                self.context
+                    .metered_write_gs_unsafe(Key::Hash(addr), contract_package)?;
+                Ok(SeededContractPackage {
+                    package_addr: addr,
+                    access_addr: access_key.addr(),
+                    latest_contract: None,
+                })
+            }
+        }
+    }
+
+    /// Writes the latest installed version of a seeded contract package to the host buffer.
+    fn write_installed_version(
+        &mut self,
+        installed: Option<(ContractHash, ContractVersion)>,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        let installed_value = CLValue::from_t(installed)?;
+        let value_size = installed_value.inner_bytes().len();
+        if let Err(err) = self.write_host_buffer(installed_value) {
+            return Ok(Err(err));
+        }
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self
+            .try_get_memory()?
+            .set(output_size_ptr, &output_size_bytes)
+        {
+            return Err(Error::Interpreter(error.into()));
+        }
+        Ok(Ok(()))
+    }
+
     fn create_contract_user_group(
         &mut self,
         contract_package_hash: ContractPackageHash,
@@ -1820,6 +2087,42 @@ where
         Ok(Ok(()))
     }
 
+    fn set_contract_package_paused(
+        &mut self,
+        contract_package_hash: ContractPackageHash,
+        paused: bool,
+    ) -> Result<(), Error> {
+        let contract_package_key = contract_package_hash.into();
+        self.context.validate_key(&contract_package_key)?;
+
+        let mut contract_package: ContractPackage = self
+            .context
+            .get_validated_contract_package(contract_package_hash)?;
+
+        contract_package.set_paused(paused);
+
+        self.context
+            .metered_write_gs_unsafe(contract_package_key, contract_package)?;
+
+        Ok(())
+    }
+
+    fn emit_event(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        data_ptr: u32,
+        data_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let name: String = self.t_from_mem(name_ptr, name_size)?;
+        if name.is_empty() || name.len() > CONTRACT_EVENT_NAME_MAX_LENGTH {
+            return Ok(Err(ApiError::InvalidArgument));
+        }
+        let data = self.cl_value_from_mem(data_ptr, data_size)?;
+        self.context.emit_event(name, data)?;
+        Ok(Ok(()))
+    }
+
     fn enable_contract_version(
         &mut self,
         contract_package_hash: ContractPackageHash,
@@ -1912,6 +2215,40 @@ where
         Ok(())
     }
 
+    /// Records a transfer out of the payment purse made by the Handle Payment contract while
+    /// finalizing payment.
+    fn record_system_transfer(
+        &mut self,
+        kind: SystemTransferKind,
+        maybe_to: Option<AccountHash>,
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Result<(), Error> {
+        if self.context.base_key() != Key::from(self.context.get_system_contract(HANDLE_PAYMENT)?) {
+            return Err(Error::InvalidContext);
+        }
+
+        if self.context.phase() != Phase::FinalizePayment
+            || self.config.has_quirk(Quirk::UnrecordedPaymentTransfers)
+        {
+            return Ok(());
+        }
+
+        let transfer_addr = self.context.new_transfer_addr()?;
+        let transfer = Transfer::new_system(
+            self.context.get_deploy_hash(),
+            kind,
+            maybe_to,
+            source,
+            target,
+            amount,
+        );
+        self.context
+            .write_transfer(Key::Transfer(transfer_addr), transfer);
+        Ok(())
+    }
+
     /// Records given auction info at a given era id
     fn record_era_summary(&mut self, era_info: EraInfo) -> Result<(), Error> {
         if self.context.base_key() != Key::from(self.context.get_system_contract(AUCTION)?) {
@@ -2431,6 +2768,27 @@ where
         }
     }
 
+    /// Transfers `amount` of motes from `source` purse to `target` purse, returning the address
+    /// of the [`Transfer`] recording it.
+    ///
+    /// Transfers are only recorded during the session phase, so in other phases the address is
+    /// `None`.
+    fn transfer_from_purse_to_purse_with_result(
+        &mut self,
+        source: URef,
+        target: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<Result<Option<TransferAddr>, mint::Error>, Error> {
+        let transfer_count = self.context.transfers().len();
+        if let Err(mint_error) = self.transfer_from_purse_to_purse(source, target, amount, id)? {
+            return Ok(Err(mint_error));
+        }
+        // The mint appends the address of the transfer it made to those of the deploy.
+        let maybe_transfer_addr = self.context.transfers().get(transfer_count).copied();
+        Ok(Ok(maybe_transfer_addr))
+    }
+
     fn get_balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         let maybe_value = self.context.read_gs_direct(&Key::Balance(purse.addr()))?;
         match maybe_value {
@@ -2971,6 +3329,280 @@ where
         Ok(Ok(()))
     }
 
+    /// Inserts a `key`, `value` pair in a sorted index, replacing the value of an existing entry.
+    fn sorted_index_insert(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        entry_key_ptr: u32,
+        entry_key_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let entry_key: U128 = self.t_from_mem(entry_key_ptr, entry_key_size)?;
+        let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context
+            .sorted_index_insert(uref, entry_key.as_u128(), cl_value)?;
+        Ok(Ok(()))
+    }
+
+    /// Removes the entry under a `key` from a sorted index.
+    fn sorted_index_remove(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        entry_key_ptr: u32,
+        entry_key_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let entry_key: U128 = self.t_from_mem(entry_key_ptr, entry_key_size)?;
+        if !self
+            .context
+            .sorted_index_remove(uref, entry_key.as_u128())?
+        {
+            return Ok(Err(ApiError::ValueNotFound));
+        }
+        Ok(Ok(()))
+    }
+
+    /// Reads the `value` under a `key` in a sorted index.
+    fn sorted_index_get(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        entry_key_ptr: u32,
+        entry_key_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        // check we can write to the host buffer
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let entry_key: U128 = self.t_from_mem(entry_key_ptr, entry_key_size)?;
+        let cl_value = match self.context.sorted_index_get(uref, entry_key.as_u128())? {
+            Some(cl_value) => cl_value,
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size: u32 = match cl_value.inner_bytes().len().try_into() {
+            Ok(value) => value,
+            Err(_) => return Ok(Err(ApiError::BufferTooSmall)),
+        };
+
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.try_get_memory()?.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Reads at most `limit` keys of a sorted index, in ascending order from the optional `start`
+    /// key onwards, or in descending order from it downwards.
+    ///
+    /// Every entry visited is charged as a read from global state, including removed entries which
+    /// are skipped.
+    #[allow(clippy::too_many_arguments)]
+    fn sorted_index_range(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        start_ptr: u32,
+        start_size: u32,
+        limit: u32,
+        descending: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        // check we can write to the host buffer
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let start: Option<U128> = self.t_from_mem(start_ptr, start_size)?;
+        let sorted_index_keys = self.context.sorted_index_keys(
+            uref,
+            start.map(|start| start.as_u128()),
+            descending != 0,
+        )?;
+
+        let read_cost = Gas::new(
+            self.config
+                .wasm_config()
+                .take_host_function_costs()
+                .read_value
+                .cost()
+                .into(),
+        );
+        let mut entry_keys: Vec<U128> = Vec::new();
+        for sorted_index_key in sorted_index_keys {
+            if entry_keys.len() >= limit as usize {
+                break;
+            }
+            self.gas(read_cost)?;
+            if self.context.sorted_index_read(sorted_index_key)?.is_some() {
+                if let Some(entry_key) = sorted_index_key.as_sorted_index_entry_key() {
+                    entry_keys.push(U128::from(entry_key));
+                }
+            }
+        }
+
+        let cl_value = CLValue::from_t(entry_keys).map_err(Error::CLValue)?;
+        let value_size: u32 = match cl_value.inner_bytes().len().try_into() {
+            Ok(value) => value,
+            Err(_) => return Ok(Err(ApiError::BufferTooSmall)),
+        };
+
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.try_get_memory()?.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Puts a `key`, `value` pair in the current generation of a namespace.
+    fn namespace_put(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let item_key = match self.namespace_item_key_from_mem(key_ptr, key_size)? {
+            Ok(item_key) => item_key,
+            Err(error) => return Ok(Err(error)),
+        };
+        let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context.namespace_put(uref, &item_key, cl_value)?;
+        Ok(Ok(()))
+    }
+
+    /// Reads the `value` under a `key` in the current generation of a namespace.
+    fn namespace_get(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        // check we can write to the host buffer
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let item_key = match self.namespace_item_key_from_mem(key_ptr, key_size)? {
+            Ok(item_key) => item_key,
+            Err(error) => return Ok(Err(error)),
+        };
+        let cl_value = match self.context.namespace_get(uref, &item_key)? {
+            Some(cl_value) => cl_value,
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size: u32 = match cl_value.inner_bytes().len().try_into() {
+            Ok(value) => value,
+            Err(_) => return Ok(Err(ApiError::BufferTooSmall)),
+        };
+
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.try_get_memory()?.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Clears a namespace, making all of its items unreadable.
+    fn namespace_clear(&mut self, uref_ptr: u32, uref_size: u32) -> Result<(), Trap> {
+        let uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        self.context.namespace_clear(uref)?;
+        Ok(())
+    }
+
+    /// Schedules an entry point of the current contract to be called back by the step, writing the
+    /// address of the scheduled callback to `output_ptr`.
+    #[allow(clippy::too_many_arguments)]
+    fn schedule_callback(
+        &mut self,
+        entry_point_ptr: u32,
+        entry_point_size: u32,
+        args_ptr: u32,
+        args_size: u32,
+        schedule_ptr: u32,
+        schedule_size: u32,
+        purse_ptr: u32,
+        purse_size: u32,
+        output_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let entry_point = self.string_from_mem(entry_point_ptr, entry_point_size)?;
+        let args: RuntimeArgs = self.t_from_mem(args_ptr, args_size)?;
+        let (era_interval, gas_budget): (u64, u64) =
+            self.t_from_mem(schedule_ptr, schedule_size)?;
+        let purse: URef = self.t_from_mem(purse_ptr, purse_size)?;
+
+        if era_interval == 0
+            || gas_budget == 0
+            || gas_budget > self.config.max_scheduled_callback_gas()
+        {
+            return Ok(Err(ApiError::InvalidArgument));
+        }
+
+        let callback_addr =
+            self.context
+                .schedule_callback(entry_point, args, era_interval, gas_budget, purse)?;
+        self.try_get_memory()?
+            .set(output_ptr, &callback_addr)
+            .map_err(|error| Error::Interpreter(error.into()))?;
+        Ok(Ok(()))
+    }
+
+    /// Cancels a callback scheduled by the current contract.
+    fn cancel_callback(&mut self, callback_addr_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        let callback_addr: ScheduledCallbackAddr =
+            self.t_from_mem(callback_addr_ptr, KEY_SCHEDULED_CALLBACK_LENGTH as u32)?;
+        if self.context.cancel_callback(callback_addr)? {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(ApiError::MissingKey))
+        }
+    }
+
+    /// Reads the UTF-8 key of a namespace item, which is limited like a dictionary item key.
+    fn namespace_item_key_from_mem(
+        &self,
+        key_ptr: u32,
+        key_size: u32,
+    ) -> Result<Result<String, ApiError>, Trap> {
+        if (key_size as usize) > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+            return Ok(Err(ApiError::DictionaryItemKeyExceedsLength));
+        }
+        let item_key = self.checked_memory_slice(key_ptr as usize, key_size as usize, |data| {
+            std::str::from_utf8(data).map(ToOwned::to_owned)
+        })?;
+        Ok(item_key.map_err(|_| ApiError::InvalidDictionaryItemKey))
+    }
+
     /// Checks if immediate caller is a system contract or account.
     ///
     /// For cases where call stack is only the session code, then this method returns `true` if the
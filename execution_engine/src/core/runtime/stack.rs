@@ -1,6 +1,6 @@
 //! Runtime stacks.
 
-use casper_types::{account::AccountHash, system::CallStackElement, PublicKey};
+use casper_types::{account::AccountHash, system::CallStackElement, ContractHash, PublicKey};
 
 /// A runtime stack frame.
 ///
@@ -14,7 +14,13 @@ pub type RuntimeStackFrame = CallStackElement;
 #[derive(Clone)]
 pub struct RuntimeStack {
     frames: Vec<RuntimeStackFrame>,
+    /// The name of the entry point called by each frame, if it is a stored code frame.
+    ///
+    /// Kept alongside the frames rather than in them, as [`CallStackElement`] is exposed to
+    /// contracts and its serialized form can't change.
+    entry_points: Vec<Option<String>>,
     max_height: usize,
+    max_contract_frames: usize,
 }
 
 /// Error returned on an attempt to pop off an empty stack.
@@ -22,7 +28,8 @@ pub struct RuntimeStack {
 #[derive(Debug)]
 struct RuntimeStackUnderflow;
 
-/// Error returned on an attempt to push to a stack already at the maximum height.
+/// Error returned on an attempt to push to a stack already at the maximum height, or to push a
+/// stored contract frame to a stack already holding the maximum number of them.
 #[derive(Debug)]
 pub struct RuntimeStackOverflow;
 
@@ -31,7 +38,9 @@ impl RuntimeStack {
     pub fn new(max_height: usize) -> Self {
         Self {
             frames: Vec::with_capacity(max_height),
+            entry_points: Vec::with_capacity(max_height),
             max_height,
+            max_contract_frames: max_height,
         }
     }
 
@@ -39,7 +48,14 @@ impl RuntimeStack {
     pub fn new_with_frame(max_height: usize, frame: RuntimeStackFrame) -> Self {
         let mut frames = Vec::with_capacity(max_height);
         frames.push(frame);
-        Self { frames, max_height }
+        let mut entry_points = Vec::with_capacity(max_height);
+        entry_points.push(None);
+        Self {
+            frames,
+            entry_points,
+            max_height,
+            max_contract_frames: max_height,
+        }
     }
 
     /// Creates a new call instance that starts with a system account.
@@ -50,6 +66,16 @@ impl RuntimeStack {
         )
     }
 
+    /// Limits the number of stored contract frames the stack can hold, independently of its
+    /// maximum height.
+    ///
+    /// Session frames, whether originating from an account's session code or from stored session
+    /// code, are only bound by the maximum height.
+    pub fn with_max_contract_frames(mut self, max_contract_frames: usize) -> Self {
+        self.max_contract_frames = max_contract_frames;
+        self
+    }
+
     /// Is the stack empty?
     pub fn is_empty(&self) -> bool {
         self.frames.is_empty()
@@ -75,21 +101,77 @@ impl RuntimeStack {
         self.frames.first()
     }
 
+    /// The number of stored contract frames on the stack.
+    pub fn contract_frames(&self) -> usize {
+        self.frames
+            .iter()
+            .filter(|frame| matches!(frame, CallStackElement::StoredContract { .. }))
+            .count()
+    }
+
+    /// The number of frames which can still be pushed before a call fails with
+    /// [`RuntimeStackOverflow`].
+    ///
+    /// Stored contract frames are additionally bound by the maximum number of contract frames, so
+    /// this is the lower of the two remaining depths.
+    pub fn remaining_call_depth(&self) -> usize {
+        let remaining_height = self.max_height.saturating_sub(self.len());
+        let remaining_contract_frames = self
+            .max_contract_frames
+            .saturating_sub(self.contract_frames());
+        remaining_height.min(remaining_contract_frames)
+    }
+
+    /// Returns `true` if the given entry point of the given contract is being executed by any frame
+    /// on the stack.
+    pub fn is_executing(&self, contract_hash: &ContractHash, entry_point_name: &str) -> bool {
+        self.frames
+            .iter()
+            .zip(&self.entry_points)
+            .any(|(frame, entry_point)| {
+                frame.contract_hash() == Some(contract_hash)
+                    && entry_point.as_deref() == Some(entry_point_name)
+            })
+    }
+
     /// Pops the current frame from the stack.
     #[cfg(test)]
     fn pop(&mut self) -> Result<(), RuntimeStackUnderflow> {
         self.frames.pop().ok_or(RuntimeStackUnderflow)?;
+        self.entry_points.pop();
         Ok(())
     }
 
     /// Pushes a frame onto the stack.
     pub fn push(&mut self, frame: RuntimeStackFrame) -> Result<(), RuntimeStackOverflow> {
-        if self.len() < self.max_height {
-            self.frames.push(frame);
-            Ok(())
-        } else {
-            Err(RuntimeStackOverflow)
+        self.push_frame(frame, None)
+    }
+
+    /// Pushes a frame calling the named entry point of stored code onto the stack.
+    pub fn push_call(
+        &mut self,
+        frame: RuntimeStackFrame,
+        entry_point_name: String,
+    ) -> Result<(), RuntimeStackOverflow> {
+        self.push_frame(frame, Some(entry_point_name))
+    }
+
+    fn push_frame(
+        &mut self,
+        frame: RuntimeStackFrame,
+        entry_point_name: Option<String>,
+    ) -> Result<(), RuntimeStackOverflow> {
+        if self.len() >= self.max_height {
+            return Err(RuntimeStackOverflow);
         }
+        if matches!(frame, CallStackElement::StoredContract { .. })
+            && self.contract_frames() >= self.max_contract_frames
+        {
+            return Err(RuntimeStackOverflow);
+        }
+        self.frames.push(frame);
+        self.entry_points.push(entry_point_name);
+        Ok(())
     }
 
     // It is here for backwards compatibility only.
@@ -102,7 +184,9 @@ impl RuntimeStack {
     pub fn from_account_hash(account_hash: AccountHash, max_height: usize) -> Self {
         RuntimeStack {
             frames: vec![CallStackElement::session(account_hash)],
+            entry_points: vec![None],
             max_height,
+            max_contract_frames: max_height,
         }
     }
 }
@@ -111,7 +195,10 @@ impl RuntimeStack {
 mod test {
     use core::convert::TryInto;
 
-    use casper_types::account::{AccountHash, ACCOUNT_HASH_LENGTH};
+    use casper_types::{
+        account::{AccountHash, ACCOUNT_HASH_LENGTH},
+        ContractPackageHash,
+    };
 
     use super::*;
 
@@ -136,6 +223,57 @@ mod test {
         assert_eq!(stack2.len(), MAX_HEIGHT);
     }
 
+    fn nth_contract_frame(n: usize) -> CallStackElement {
+        let mut bytes = [0_u8; 32];
+        let n: u32 = n.try_into().unwrap();
+        bytes[0..4].copy_from_slice(&n.to_le_bytes());
+        CallStackElement::stored_contract(ContractPackageHash::new(bytes), ContractHash::new(bytes))
+    }
+
+    #[test]
+    fn stack_should_limit_contract_frames_independently() {
+        const MAX_HEIGHT: usize = 5;
+        const MAX_CONTRACT_FRAMES: usize = 2;
+        let mut stack = RuntimeStack::new(MAX_HEIGHT).with_max_contract_frames(MAX_CONTRACT_FRAMES);
+        stack.push(nth_frame(0)).unwrap();
+        assert_eq!(stack.remaining_call_depth(), MAX_CONTRACT_FRAMES);
+
+        stack.push(nth_contract_frame(1)).unwrap();
+        stack.push(nth_contract_frame(2)).unwrap();
+        assert_eq!(stack.contract_frames(), MAX_CONTRACT_FRAMES);
+        assert_eq!(stack.remaining_call_depth(), 0);
+        stack.push(nth_contract_frame(3)).unwrap_err();
+
+        // Session frames are still bound only by the maximum height.
+        stack.push(nth_frame(3)).unwrap();
+        stack.push(nth_frame(4)).unwrap();
+        stack.push(nth_frame(5)).unwrap_err();
+        assert_eq!(stack.len(), MAX_HEIGHT);
+
+        stack.pop().unwrap();
+        stack.pop().unwrap();
+        stack.pop().unwrap();
+        assert_eq!(stack.contract_frames(), 1);
+        assert_eq!(stack.remaining_call_depth(), 1);
+    }
+
+    #[test]
+    fn stack_should_track_executing_entry_points() {
+        let mut stack = RuntimeStack::new(5);
+        stack.push(nth_frame(0)).unwrap();
+        let contract_frame = nth_contract_frame(1);
+        let contract_hash = *contract_frame.contract_hash().unwrap();
+        assert!(!stack.is_executing(&contract_hash, "a"));
+
+        stack.push_call(contract_frame, "a".to_string()).unwrap();
+        assert!(stack.is_executing(&contract_hash, "a"));
+        assert!(!stack.is_executing(&contract_hash, "b"));
+        assert!(!stack.is_executing(nth_contract_frame(2).contract_hash().unwrap(), "a"));
+
+        stack.pop().unwrap();
+        assert!(!stack.is_executing(&contract_hash, "a"));
+    }
+
     #[test]
     fn stack_should_work_as_expected() {
         const MAX_HEIGHT: usize = 6;
@@ -9,6 +9,7 @@ use std::{
 
 use tracing::error;
 
+use casper_hashing::Digest;
 use casper_types::{
     account::{
         Account, AccountHash, ActionType, AddKeyFailure, RemoveKeyFailure, SetThresholdFailure,
@@ -16,18 +17,25 @@ use casper_types::{
     },
     bytesrepr::ToBytes,
     contracts::NamedKeys,
-    system::auction::EraInfo,
-    AccessRights, BlockTime, CLType, CLValue, ContextAccessRights, Contract, ContractHash,
-    ContractPackage, ContractPackageHash, DeployHash, DeployInfo, EntryPointAccess, EntryPointType,
-    Gas, GrantedAccess, Key, KeyTag, Phase, ProtocolVersion, PublicKey, RuntimeArgs, StoredValue,
-    Transfer, TransferAddr, URef, URefAddr, DICTIONARY_ITEM_KEY_MAX_LENGTH, KEY_HASH_LENGTH, U512,
+    system::{
+        auction::{EraInfo, ERA_ID_KEY},
+        AUCTION,
+    },
+    AccessRights, BlockTime, CLType, CLValue, ContextAccessRights, Contract, ContractEvent,
+    ContractHash, ContractPackage, ContractPackageHash, DeployHash, DeployInfo, EntryPointAccess,
+    EntryPointType, EraId, Gas, GrantedAccess, Key, KeyTag, Phase, ProtocolVersion, PublicKey,
+    RuntimeArgs, ScheduledCallbackAddr, StoredValue, Transfer, TransferAddr, URef, URefAddr,
+    DICTIONARY_ITEM_KEY_MAX_LENGTH, KEY_HASH_LENGTH, U512,
 };
 
 use crate::{
     core::{
-        engine_state::{execution_effect::ExecutionEffect, EngineConfig, SystemContractRegistry},
+        engine_state::{
+            execution_effect::ExecutionEffect, scheduled_callbacks::ScheduledCallback,
+            EngineConfig, SystemContractRegistry,
+        },
         execution::{AddressGenerator, Error},
-        runtime_context::dictionary::DictionaryValue,
+        runtime_context::{dictionary::DictionaryValue, sorted_index::SortedIndexValue},
         tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
     },
     shared::{execution_journal::ExecutionJournal, newtypes::CorrelationId},
@@ -35,6 +43,7 @@ use crate::{
 };
 
 pub(crate) mod dictionary;
+pub(crate) mod sorted_index;
 #[cfg(test)]
 mod tests;
 
@@ -96,6 +105,8 @@ pub struct RuntimeContext<'a, R> {
     deploy_hash: DeployHash,
     gas_limit: Gas,
     gas_counter: Gas,
+    // Gas refunded so far for freed storage
+    storage_refunded: Gas,
     address_generator: Rc<RefCell<AddressGenerator>>,
     protocol_version: ProtocolVersion,
     correlation_id: CorrelationId,
@@ -103,6 +114,7 @@ pub struct RuntimeContext<'a, R> {
     engine_config: EngineConfig,
     entry_point_type: EntryPointType,
     transfers: Vec<TransferAddr>,
+    events: Vec<ContractEvent>,
     remaining_spending_limit: U512,
 }
 
@@ -149,12 +161,14 @@ where
             base_key,
             gas_limit,
             gas_counter,
+            storage_refunded: Gas::default(),
             address_generator,
             protocol_version,
             correlation_id,
             phase,
             engine_config,
             transfers,
+            events: Vec::new(),
             remaining_spending_limit,
         }
     }
@@ -177,12 +191,14 @@ where
         let deploy_hash = self.deploy_hash;
         let gas_limit = self.gas_limit;
         let gas_counter = self.gas_counter;
+        let storage_refunded = self.storage_refunded;
         let address_generator = self.address_generator.clone();
         let protocol_version = self.protocol_version;
         let correlation_id = self.correlation_id;
         let phase = self.phase;
         let engine_config = self.engine_config.clone();
         let transfers = self.transfers.clone();
+        let events = self.events.clone();
         let remaining_spending_limit = self.remaining_spending_limit();
 
         RuntimeContext {
@@ -198,12 +214,14 @@ where
             base_key,
             gas_limit,
             gas_counter,
+            storage_refunded,
             address_generator,
             protocol_version,
             correlation_id,
             phase,
             engine_config,
             transfers,
+            events,
             remaining_spending_limit,
         }
     }
@@ -240,11 +258,12 @@ where
         mut contract: Contract,
         name: &str,
     ) -> Result<(), Error> {
-        if contract.remove_named_key(name).is_none() {
-            return Ok(());
-        }
+        let removed_key = match contract.remove_named_key(name) {
+            Some(removed_key) => removed_key,
+            None => return Ok(()),
+        };
         self.metered_write_gs_unsafe(key, contract)?;
-        Ok(())
+        self.credit_storage_refund(name.serialized_length() + removed_key.serialized_length())
     }
 
     /// Remove Key from the `named_keys` map of the current context.
@@ -254,15 +273,20 @@ where
     pub fn remove_key(&mut self, name: &str) -> Result<(), Error> {
         match self.base_key() {
             account_hash @ Key::Account(_) => {
-                let account: Account = {
+                let (account, removed_key): (Account, Option<Key>) = {
                     let mut account: Account = self.read_gs_typed(&account_hash)?;
-                    account.named_keys_mut().remove(name);
-                    account
+                    let removed_key = account.named_keys_mut().remove(name);
+                    (account, removed_key)
                 };
                 self.named_keys.remove(name);
                 let account_value = self.account_to_validated_value(account)?;
                 self.metered_write_gs_unsafe(account_hash, account_value)?;
-                Ok(())
+                match removed_key {
+                    Some(removed_key) => self.credit_storage_refund(
+                        name.serialized_length() + removed_key.serialized_length(),
+                    ),
+                    None => Ok(()),
+                }
             }
             contract_uref @ Key::URef(_) => {
                 let contract: Contract = {
@@ -338,6 +362,38 @@ where
                 error!("should not remove the checksum registry key");
                 Err(Error::RemoveKeyFailure(RemoveKeyFailure::PermissionDenied))
             }
+            Key::BlockEvents => {
+                error!("should not remove the block events key");
+                Err(Error::RemoveKeyFailure(RemoveKeyFailure::PermissionDenied))
+            }
+            Key::BlockGasUtilization => {
+                error!("should not remove the block gas utilization key");
+                Err(Error::RemoveKeyFailure(RemoveKeyFailure::PermissionDenied))
+            }
+            Key::AdministratorRegistry => {
+                error!("should not remove the administrator registry key");
+                Err(Error::RemoveKeyFailure(RemoveKeyFailure::PermissionDenied))
+            }
+            Key::PurseOwner(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
+            Key::Reservations(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
+            Key::SortedIndex(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
+            Key::Namespace(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
+            Key::ScheduledCallback(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
         }
     }
 
@@ -405,6 +461,16 @@ where
         self.gas_counter = new_gas_counter;
     }
 
+    /// Returns the gas refunded so far for freed storage.
+    pub fn storage_refunded(&self) -> Gas {
+        self.storage_refunded
+    }
+
+    /// Sets the gas refunded so far for freed storage to a new value.
+    pub fn set_storage_refunded(&mut self, new_storage_refunded: Gas) {
+        self.storage_refunded = new_storage_refunded;
+    }
+
     /// Returns the base key.
     ///
     /// This could be either a [`Key::Account`] or a [`Key::Hash`] depending on the entry point
@@ -438,6 +504,26 @@ where
         Ok(self.address_generator.borrow_mut().create_address())
     }
 
+    /// Returns a new address unique within the deploy.
+    ///
+    /// The address is the hash of the deploy hash, the phase, `tag` and the number of unique
+    /// addresses created so far in the phase, so it is the same whenever the deploy is executed,
+    /// but differs between deploys included in the same block.
+    pub fn new_unique_address(&mut self, tag: &[u8]) -> Result<[u8; KEY_HASH_LENGTH], Error> {
+        let index = self
+            .address_generator
+            .borrow_mut()
+            .next_unique_address_index();
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(self.deploy_hash.as_ref());
+        preimage.push(self.phase as u8);
+        // The tag is length-prefixed so that it can't run into the index.
+        preimage.extend_from_slice(&(tag.len() as u64).to_le_bytes());
+        preimage.extend_from_slice(tag);
+        preimage.extend_from_slice(&index.to_le_bytes());
+        Ok(Digest::hash(preimage).value())
+    }
+
     /// Creates new [`URef`] instance.
     pub fn new_uref(&mut self, value: StoredValue) -> Result<URef, Error> {
         let uref = self
@@ -655,6 +741,35 @@ where
         &mut self.transfers
     }
 
+    /// Returns list of contract events.
+    pub fn events(&self) -> &Vec<ContractEvent> {
+        &self.events
+    }
+
+    /// Returns mutable list of contract events.
+    pub fn events_mut(&mut self) -> &mut Vec<ContractEvent> {
+        &mut self.events
+    }
+
+    /// Records an event emitted by the currently executing contract, charging for its storage.
+    ///
+    /// Only stored contracts may emit events; in any other context [`Error::InvalidContext`] is
+    /// returned.
+    pub(crate) fn emit_event(&mut self, name: String, data: CLValue) -> Result<(), Error> {
+        let contract_hash = match (self.entry_point_type, self.base_key) {
+            (
+                EntryPointType::Contract | EntryPointType::NonReentrantContract,
+                Key::Hash(contract_hash_addr),
+            ) => ContractHash::new(contract_hash_addr),
+            _ => return Err(Error::InvalidContext),
+        };
+        self.validate_cl_value(&data)?;
+        let event = ContractEvent::new(contract_hash, name, data);
+        self.charge_gas_storage(event.serialized_length())?;
+        self.events.push(event);
+        Ok(())
+    }
+
     fn validate_cl_value(&self, cl_value: &CLValue) -> Result<(), Error> {
         match cl_value.cl_type() {
             CLType::Bool
@@ -796,6 +911,14 @@ where
             Key::Unbond(_) => true,
             Key::ChainspecRegistry => true,
             Key::ChecksumRegistry => true,
+            Key::BlockEvents => true,
+            Key::SortedIndex(_) => false,
+            Key::Namespace(_) => false,
+            Key::ScheduledCallback(_) => false,
+            Key::BlockGasUtilization => true,
+            Key::AdministratorRegistry => true,
+            Key::PurseOwner(_) => true,
+            Key::Reservations(_) => true,
         }
     }
 
@@ -816,6 +939,14 @@ where
             Key::Unbond(_) => false,
             Key::ChainspecRegistry => false,
             Key::ChecksumRegistry => false,
+            Key::BlockEvents => false,
+            Key::SortedIndex(_) => false,
+            Key::Namespace(_) => false,
+            Key::ScheduledCallback(_) => false,
+            Key::BlockGasUtilization => false,
+            Key::AdministratorRegistry => false,
+            Key::PurseOwner(_) => false,
+            Key::Reservations(_) => false,
         }
     }
 
@@ -836,6 +967,14 @@ where
             Key::Unbond(_) => false,
             Key::ChainspecRegistry => false,
             Key::ChecksumRegistry => false,
+            Key::BlockEvents => false,
+            Key::SortedIndex(_) => false,
+            Key::Namespace(_) => false,
+            Key::ScheduledCallback(_) => false,
+            Key::BlockGasUtilization => false,
+            Key::AdministratorRegistry => false,
+            Key::PurseOwner(_) => false,
+            Key::Reservations(_) => false,
         }
     }
 
@@ -888,6 +1027,38 @@ where
         self.charge_gas(gas_cost)
     }
 
+    /// Credits the session with a refund for the specified amount of bytes of storage freed.
+    ///
+    /// The refund lowers the gas counter, up to the per-deploy cap of the storage refund config.
+    /// Storage freed in the payment phase or by a system contract isn't refunded.
+    fn credit_storage_refund(&mut self, bytes_count: usize) -> Result<(), Error> {
+        let storage_refund = self.engine_config.storage_refund();
+        if !storage_refund.is_enabled() || self.phase != Phase::Session || bytes_count == 0 {
+            return Ok(());
+        }
+        if let Some(base_key) = self.base_key().into_hash() {
+            let contract_hash = ContractHash::new(base_key);
+            if self.is_system_contract(&contract_hash)? {
+                return Ok(());
+            }
+        }
+
+        let storage_costs = self.engine_config.wasm_config().storage_costs();
+        let refund_ratio = storage_refund.refund_ratio;
+        let refund = (storage_costs.calculate_gas_cost(bytes_count).value()
+            * U512::from(*refund_ratio.numer()))
+        .checked_div(U512::from(*refund_ratio.denom()))
+        .unwrap_or_default();
+        let remaining_refund = Gas::from(storage_refund.max_refund)
+            .checked_sub(self.storage_refunded)
+            .unwrap_or_default();
+        let refund = Gas::new(refund).min(remaining_refund);
+
+        self.storage_refunded += refund;
+        self.gas_counter = self.gas_counter.checked_sub(refund).unwrap_or_default();
+        Ok(())
+    }
+
     /// Charges gas for using a host system contract's entrypoint.
     pub(crate) fn charge_system_contract_call<T>(&mut self, call_cost: T) -> Result<(), Error>
     where
@@ -1243,8 +1414,317 @@ where
         };
 
         let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key_bytes);
+        // Shrinking an existing item, down to a unit value to zero it, frees storage.
+        let freed_bytes = if self.engine_config.storage_refund().is_enabled() {
+            match self
+                .tracking_copy
+                .borrow_mut()
+                .read(self.correlation_id, &dictionary_key)
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(existing_cl_value)) => existing_cl_value
+                    .serialized_length()
+                    .saturating_sub(wrapped_cl_value.serialized_length()),
+                _ => 0,
+            }
+        } else {
+            0
+        };
         self.metered_write_gs_unsafe(dictionary_key, wrapped_cl_value)?;
-        Ok(())
+        self.credit_storage_refund(freed_bytes)
+    }
+
+    /// Inserts `cl_value` under `entry_key` in the sorted index referenced by `seed_uref`,
+    /// replacing the value of an existing entry.
+    pub(crate) fn sorted_index_insert(
+        &mut self,
+        seed_uref: URef,
+        entry_key: u128,
+        cl_value: CLValue,
+    ) -> Result<(), Error> {
+        self.validate_writeable(&seed_uref.into())?;
+        self.validate_uref(&seed_uref)?;
+
+        self.validate_cl_value(&cl_value)?;
+
+        let wrapped_cl_value = {
+            let sorted_index_value =
+                SortedIndexValue::new(Some(cl_value), seed_uref.addr().to_vec());
+            CLValue::from_t(sorted_index_value).map_err(Error::from)?
+        };
+
+        let sorted_index_key = Key::sorted_index(seed_uref, entry_key);
+        self.metered_write_gs_unsafe(sorted_index_key, wrapped_cl_value)
+    }
+
+    /// Removes the entry under `entry_key` from the sorted index referenced by `seed_uref`.
+    ///
+    /// Returns `false` if the index has no such entry.
+    pub(crate) fn sorted_index_remove(
+        &mut self,
+        seed_uref: URef,
+        entry_key: u128,
+    ) -> Result<bool, Error> {
+        self.validate_writeable(&seed_uref.into())?;
+        self.validate_uref(&seed_uref)?;
+
+        let sorted_index_key = Key::sorted_index(seed_uref, entry_key);
+        let removed_cl_value_length = match self.sorted_index_read(sorted_index_key)? {
+            Some(existing_cl_value) => existing_cl_value.serialized_length(),
+            None => return Ok(false),
+        };
+
+        let removed_cl_value = {
+            let sorted_index_value = SortedIndexValue::new(None, seed_uref.addr().to_vec());
+            CLValue::from_t(sorted_index_value).map_err(Error::from)?
+        };
+        self.metered_write_gs_unsafe(sorted_index_key, removed_cl_value)?;
+        self.credit_storage_refund(removed_cl_value_length)?;
+        Ok(true)
+    }
+
+    /// Gets the value under `entry_key` in the sorted index referenced by `seed_uref`.
+    pub(crate) fn sorted_index_get(
+        &mut self,
+        seed_uref: URef,
+        entry_key: u128,
+    ) -> Result<Option<CLValue>, Error> {
+        self.validate_readable(&seed_uref.into())?;
+        self.validate_key(&seed_uref.into())?;
+        self.sorted_index_read(Key::sorted_index(seed_uref, entry_key))
+    }
+
+    /// Gets the keys of the sorted index referenced by `seed_uref`, in ascending order of their
+    /// entry keys from `start` onwards, or in descending order from `start` downwards.
+    ///
+    /// The keys of removed entries are included; their values are read as `None` by
+    /// [`Self::sorted_index_read`].
+    pub(crate) fn sorted_index_keys(
+        &mut self,
+        seed_uref: URef,
+        start: Option<u128>,
+        descending: bool,
+    ) -> Result<Vec<Key>, Error> {
+        self.validate_readable(&seed_uref.into())?;
+        self.validate_key(&seed_uref.into())?;
+
+        let keys = self
+            .tracking_copy
+            .borrow_mut()
+            .get_keys_with_prefix(self.correlation_id, &Key::sorted_index_prefix(seed_uref))
+            .map_err(Into::into)?;
+
+        // The entry key is the big-endian suffix of a sorted index key, so the keys of an index are
+        // ordered by their entry keys.
+        let start_key = start.map(|start| Key::sorted_index(seed_uref, start));
+        let keys = match (start_key, descending) {
+            (None, false) => keys.into_iter().collect(),
+            (None, true) => keys.into_iter().rev().collect(),
+            (Some(start_key), false) => keys.range(start_key..).copied().collect(),
+            (Some(start_key), true) => keys.range(..=start_key).rev().copied().collect(),
+        };
+        Ok(keys)
+    }
+
+    /// Reads the value of a sorted index entry, returning `None` if the entry doesn't exist or has
+    /// been removed.
+    pub(crate) fn sorted_index_read(
+        &mut self,
+        sorted_index_key: Key,
+    ) -> Result<Option<CLValue>, Error> {
+        let maybe_stored_value = self
+            .tracking_copy
+            .borrow_mut()
+            .read(self.correlation_id, &sorted_index_key)
+            .map_err(Into::into)?;
+
+        match maybe_stored_value {
+            Some(stored_value) => {
+                let cl_value = CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?;
+                let sorted_index_value: SortedIndexValue = cl_value.into_t()?;
+                Ok(sorted_index_value.into_cl_value())
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Puts `cl_value` under `item_key` in the current generation of the namespace referenced by
+    /// `seed_uref`.
+    pub(crate) fn namespace_put(
+        &mut self,
+        seed_uref: URef,
+        item_key: &str,
+        cl_value: CLValue,
+    ) -> Result<(), Error> {
+        let item_key_bytes = item_key.as_bytes();
+
+        if item_key_bytes.len() > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+            return Err(Error::DictionaryItemKeyExceedsLength);
+        }
+
+        self.validate_writeable(&seed_uref.into())?;
+        self.validate_uref(&seed_uref)?;
+
+        self.validate_cl_value(&cl_value)?;
+
+        let generation = self.namespace_generation(seed_uref)?;
+        let namespace_key = Key::namespace_item(seed_uref, generation, item_key_bytes);
+        self.metered_write_gs_unsafe(namespace_key, cl_value)
+    }
+
+    /// Gets the value under `item_key` in the current generation of the namespace referenced by
+    /// `seed_uref`.
+    pub(crate) fn namespace_get(
+        &mut self,
+        seed_uref: URef,
+        item_key: &str,
+    ) -> Result<Option<CLValue>, Error> {
+        self.validate_readable(&seed_uref.into())?;
+        self.validate_key(&seed_uref.into())?;
+        let item_key_bytes = item_key.as_bytes();
+
+        if item_key_bytes.len() > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+            return Err(Error::DictionaryItemKeyExceedsLength);
+        }
+
+        let generation = self.namespace_generation(seed_uref)?;
+        let namespace_key = Key::namespace_item(seed_uref, generation, item_key_bytes);
+        let maybe_stored_value = self
+            .tracking_copy
+            .borrow_mut()
+            .read(self.correlation_id, &namespace_key)
+            .map_err(Into::into)?;
+
+        match maybe_stored_value {
+            Some(stored_value) => Ok(Some(
+                CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Clears the namespace referenced by `seed_uref` by starting a new generation of it.
+    ///
+    /// The items of the retired generation can no longer be read, and the generation is recorded
+    /// under [`Key::retired_namespace`] so that their records can be pruned outside of execution.
+    pub(crate) fn namespace_clear(&mut self, seed_uref: URef) -> Result<(), Error> {
+        self.validate_writeable(&seed_uref.into())?;
+        self.validate_uref(&seed_uref)?;
+
+        let generation = self.namespace_generation(seed_uref)?;
+        let next_generation = CLValue::from_t(generation.saturating_add(1))?;
+        self.metered_write_gs_unsafe(seed_uref, next_generation)?;
+        self.metered_write_gs_unsafe(
+            Key::retired_namespace(seed_uref, generation),
+            CLValue::unit(),
+        )
+    }
+
+    /// Reads the current generation of the namespace referenced by `seed_uref`, stored as a `u64`
+    /// under the seed.
+    fn namespace_generation(&mut self, seed_uref: URef) -> Result<u64, Error> {
+        let seed_key = Key::from(seed_uref).normalize();
+        let stored_value = self
+            .tracking_copy
+            .borrow_mut()
+            .read(self.correlation_id, &seed_key)
+            .map_err(Into::into)?
+            .ok_or(Error::KeyNotFound(seed_key))?;
+        let cl_value = CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?;
+        Ok(cl_value.into_t()?)
+    }
+
+    /// Schedules `entry_point` of the current contract to be called with `args` by the step
+    /// starting every `era_interval`th era from now, paying for up to `gas_budget` from `purse`.
+    pub(crate) fn schedule_callback(
+        &mut self,
+        entry_point: String,
+        args: RuntimeArgs,
+        era_interval: u64,
+        gas_budget: u64,
+        purse: URef,
+    ) -> Result<ScheduledCallbackAddr, Error> {
+        let contract_hash = match self.base_key {
+            Key::Hash(addr) => ContractHash::new(addr),
+            _ => return Err(Error::InvalidContext),
+        };
+
+        self.validate_writeable(&purse.into())?;
+        self.validate_uref(&purse)?;
+        for arg in args.to_values() {
+            self.validate_cl_value(arg)?;
+        }
+
+        let contract = self
+            .tracking_copy
+            .borrow_mut()
+            .get_contract(self.correlation_id, contract_hash)?;
+        match contract.entry_point(&entry_point) {
+            Some(entry_point) if entry_point.entry_point_type() == EntryPointType::Contract => {}
+            Some(_) => return Err(Error::InvalidContext),
+            None => return Err(Error::NoSuchMethod(entry_point)),
+        }
+
+        let next_era_id = self.current_era_id()?.saturating_add(era_interval);
+        let callback = ScheduledCallback::new(
+            contract_hash,
+            entry_point,
+            args,
+            self.get_caller(),
+            purse,
+            era_interval,
+            gas_budget,
+            next_era_id,
+        );
+        let callback_addr = self.new_hash_address()?;
+        self.metered_write_gs_unsafe(
+            Key::ScheduledCallback(callback_addr),
+            CLValue::from_t(Some(callback))?,
+        )?;
+        Ok(callback_addr)
+    }
+
+    /// Cancels a callback scheduled by the current contract.  Returns `false` if there is no such
+    /// callback.
+    pub(crate) fn cancel_callback(
+        &mut self,
+        callback_addr: ScheduledCallbackAddr,
+    ) -> Result<bool, Error> {
+        let key = Key::ScheduledCallback(callback_addr);
+        let callback = match self.read_gs_direct(&key)? {
+            Some(StoredValue::CLValue(cl_value)) => {
+                cl_value.into_t::<Option<ScheduledCallback>>()?
+            }
+            Some(_) | None => None,
+        };
+        match callback {
+            Some(callback) if Key::from(callback.contract_hash()) == self.base_key => {
+                // The record is kept as `None` as keys can't be deleted from global state.
+                let cancelled: Option<ScheduledCallback> = None;
+                self.metered_write_gs_unsafe(key, CLValue::from_t(cancelled)?)?;
+                Ok(true)
+            }
+            Some(_) => Err(Error::InvalidContext),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the current era, as recorded by the auction.
+    fn current_era_id(&mut self) -> Result<EraId, Error> {
+        let auction_hash = self.get_system_contract(AUCTION)?;
+        let auction_contract = self
+            .tracking_copy
+            .borrow_mut()
+            .get_contract(self.correlation_id, auction_hash)?;
+        let era_id_key = *auction_contract
+            .named_keys()
+            .get(ERA_ID_KEY)
+            .ok_or_else(|| Error::NamedKeyNotFound(ERA_ID_KEY.to_string()))?;
+        let stored_value = self
+            .read_gs_direct(&era_id_key)?
+            .ok_or(Error::KeyNotFound(era_id_key))?;
+        let cl_value = CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?;
+        Ok(cl_value.into_t()?)
     }
 
     /// Gets system contract by name.
@@ -0,0 +1,68 @@
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue,
+};
+
+/// Wraps a [`CLValue`] for storage in a sorted index.
+///
+/// Global state entries can't be deleted during execution, so a removed entry is kept with no
+/// value and skipped when the index is read.  As for dictionaries, we include the seed
+/// [`casper_types::URef`] address of the index the entry belongs to.
+#[derive(Clone)]
+pub struct SortedIndexValue {
+    /// The [`CLValue`] of the entry, or `None` if the entry has been removed.
+    cl_value: Option<CLValue>,
+    /// [`URef`] seed bytes.
+    seed_uref_addr: Bytes,
+}
+
+impl SortedIndexValue {
+    pub fn new(cl_value: Option<CLValue>, seed_uref_addr: Vec<u8>) -> Self {
+        Self {
+            cl_value,
+            seed_uref_addr: seed_uref_addr.into(),
+        }
+    }
+
+    /// Returns the wrapped [`CLValue`], or `None` if the entry has been removed.
+    pub fn into_cl_value(self) -> Option<CLValue> {
+        self.cl_value
+    }
+}
+
+impl CLTyped for SortedIndexValue {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for SortedIndexValue {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (cl_value, remainder) = FromBytes::from_bytes(bytes)?;
+        let (seed_uref_addr, remainder) = FromBytes::from_bytes(remainder)?;
+        let sorted_index_value = SortedIndexValue {
+            cl_value,
+            seed_uref_addr,
+        };
+        Ok((sorted_index_value, remainder))
+    }
+}
+
+impl ToBytes for SortedIndexValue {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.cl_value.to_bytes()?);
+        buffer.extend(self.seed_uref_addr.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.cl_value.serialized_length() + self.seed_uref_addr.serialized_length()
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.cl_value.write_bytes(writer)?;
+        self.seed_uref_addr.write_bytes(writer)?;
+        Ok(())
+    }
+}
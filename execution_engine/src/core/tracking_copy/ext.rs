@@ -6,7 +6,7 @@ use casper_types::{
 
 use crate::{
     core::{
-        engine_state::{ChecksumRegistry, SystemContractRegistry},
+        engine_state::{ChecksumRegistry, SystemContractRegistry, SystemEntityRegistry},
         execution,
         tracking_copy::TrackingCopy,
     },
@@ -88,6 +88,12 @@ pub trait TrackingCopyExt<R> {
         correlation_id: CorrelationId,
     ) -> Result<SystemContractRegistry, Self::Error>;
 
+    /// Gets the system entity registry, converting a registry stored in the legacy format.
+    fn get_system_entity_registry(
+        &mut self,
+        correlation_id: CorrelationId,
+    ) -> Result<SystemEntityRegistry, Self::Error>;
+
     /// Gets the system checksum registry.
     fn get_checksum_registry(
         &mut self,
@@ -252,13 +258,21 @@ where
         &mut self,
         correlation_id: CorrelationId,
     ) -> Result<SystemContractRegistry, Self::Error> {
+        self.get_system_entity_registry(correlation_id)
+            .map(SystemContractRegistry::from)
+    }
+
+    fn get_system_entity_registry(
+        &mut self,
+        correlation_id: CorrelationId,
+    ) -> Result<SystemEntityRegistry, Self::Error> {
         match self
             .get(correlation_id, &Key::SystemContractRegistry)
             .map_err(Into::into)?
         {
             Some(StoredValue::CLValue(registry)) => {
-                let registry: SystemContractRegistry =
-                    CLValue::into_t(registry).map_err(Self::Error::from)?;
+                let registry =
+                    SystemEntityRegistry::from_cl_value(registry).map_err(Self::Error::from)?;
                 Ok(registry)
             }
             Some(other) => Err(execution::Error::TypeMismatch(
@@ -8,7 +8,7 @@ mod meter;
 mod tests;
 
 use std::{
-    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     convert::{From, TryInto},
     iter,
 };
@@ -18,7 +18,7 @@ use thiserror::Error;
 
 use casper_hashing::Digest;
 use casper_types::{
-    bytesrepr::{self},
+    bytesrepr::{self, ToBytes},
     CLType, CLValue, CLValueError, Key, KeyTag, StoredValue, StoredValueTypeMismatch, Tagged, U512,
 };
 
@@ -278,11 +278,8 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
     /// allows isolating a specific set of changes (those in the new
     /// `TrackingCopy`) from existing changes. Note that mutations to state
     /// caused by new changes (i.e. writes and adds) only impact the new
-    /// `TrackingCopy`, not this one. Note that currently there is no `join` /
-    /// `merge` function to bring changes from a fork back to the main
-    /// `TrackingCopy`. this means the current usage requires repeated
-    /// forking, however we recognize this is sub-optimal and will revisit
-    /// in the future.
+    /// `TrackingCopy`, not this one. The changes made in a fork can be brought
+    /// back to this `TrackingCopy` with [`TrackingCopy::merge_journal`].
     pub fn fork(&self) -> TrackingCopy<&TrackingCopy<R>> {
         TrackingCopy::new(self)
     }
@@ -327,6 +324,71 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         Ok(ret)
     }
 
+    /// Gets at most `limit` keys in the state whose tag is `key_tag`, starting after `cursor`.
+    ///
+    /// Keys are returned in the ascending order of their serialized form, which is the order in
+    /// which they are laid out in the trie, so the last key of a page is the cursor for the next
+    /// one, and paging through the same state root always yields the same pages.  Passing `None`
+    /// as the cursor starts from the first key.  Only the trie nodes on the way to the cursor and
+    /// to the keys returned are read.
+    pub fn get_keys_after(
+        &mut self,
+        correlation_id: CorrelationId,
+        key_tag: &KeyTag,
+        cursor: Option<&Key>,
+        limit: usize,
+    ) -> Result<Vec<Key>, R::Error>
+    where
+        R::Error: From<bytesrepr::Error>,
+    {
+        let start_after = match cursor {
+            Some(cursor) => cursor.to_bytes()?,
+            None => Vec::new(),
+        };
+        let mut keys = BTreeMap::new();
+        for key in self.reader.keys_with_prefix_after(
+            correlation_id,
+            &[*key_tag as u8],
+            &start_after,
+            limit,
+        )? {
+            keys.insert(key.to_bytes()?, key);
+        }
+        if let Some(written_keys) = self.cache.get_key_tag_muts_cached(key_tag) {
+            for key in written_keys {
+                let key_bytes = key.to_bytes()?;
+                if key_bytes > start_after {
+                    keys.insert(key_bytes, *key);
+                }
+            }
+        }
+        Ok(keys.into_values().take(limit).collect())
+    }
+
+    /// Gets the set of keys in the state whose serialized form starts with `prefix`, including the
+    /// keys only written to this tracking copy so far.
+    pub fn get_keys_with_prefix(
+        &mut self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<BTreeSet<Key>, R::Error> {
+        let mut ret: BTreeSet<Key> = self
+            .reader
+            .keys_with_prefix(correlation_id, prefix)?
+            .into_iter()
+            .collect();
+        let has_prefix = |key: &&Key| {
+            key.to_bytes()
+                .map_or(false, |bytes| bytes.starts_with(prefix))
+        };
+        for (key_tag, keys) in &self.cache.key_tag_muts_cached {
+            if prefix.first().map_or(true, |tag| *tag == *key_tag as u8) {
+                ret.extend(keys.iter().filter(has_prefix));
+            }
+        }
+        Ok(ret)
+    }
+
     /// Reads the value stored under `key`.
     pub fn read(
         &mut self,
@@ -426,6 +488,50 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         }
     }
 
+    /// Applies the transforms recorded in `journal` to this instance, as if the operations which
+    /// produced them had been executed here.
+    ///
+    /// This is used to bring the changes made in a fork back to the `TrackingCopy` it was forked
+    /// from.  The transforms are recorded as they are, so additions stay additions rather than
+    /// becoming writes of their results.  Applying stops at the first transform which can't be
+    /// applied, and the reason is returned.
+    pub fn merge_journal(
+        &mut self,
+        correlation_id: CorrelationId,
+        journal: ExecutionJournal,
+    ) -> Result<AddResult, R::Error> {
+        for (key, transform) in journal {
+            let new_value = match transform {
+                Transform::Identity => {
+                    self.journal.push((key, transform));
+                    continue;
+                }
+                Transform::Write(value) => {
+                    self.write(key, value);
+                    continue;
+                }
+                _ => {
+                    let current_value = match self.get(correlation_id, &key)? {
+                        Some(current_value) => current_value,
+                        None => return Ok(AddResult::KeyNotFound(key)),
+                    };
+                    match transform.clone().apply(current_value) {
+                        Ok(new_value) => new_value,
+                        Err(transform::Error::TypeMismatch(type_mismatch)) => {
+                            return Ok(AddResult::TypeMismatch(type_mismatch))
+                        }
+                        Err(transform::Error::Serialization(error)) => {
+                            return Ok(AddResult::Serialization(error))
+                        }
+                    }
+                }
+            };
+            self.cache.insert_write(key, new_value);
+            self.journal.push((key, transform));
+        }
+        Ok(AddResult::Success)
+    }
+
     /// Returns the execution effects cached by this instance.
     pub fn effect(&self) -> ExecutionEffect {
         ExecutionEffect::from(self.journal.clone())
@@ -449,13 +555,25 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         config: &EngineConfig,
         base_key: Key,
         path: &[String],
+    ) -> Result<TrackingCopyQueryResult, R::Error> {
+        self.query_with_max_depth(correlation_id, config.max_query_depth, base_key, path)
+    }
+
+    /// Like [`TrackingCopy::query`], but following at most `max_query_depth` keys rather than the
+    /// maximum query depth of the engine config.
+    pub fn query_with_max_depth(
+        &self,
+        correlation_id: CorrelationId,
+        max_query_depth: u64,
+        base_key: Key,
+        path: &[String],
     ) -> Result<TrackingCopyQueryResult, R::Error> {
         let mut query = Query::new(base_key, path);
 
         let mut proofs = Vec::new();
 
         loop {
-            if query.depth >= config.max_query_depth {
+            if query.depth >= max_query_depth {
                 return Ok(query.into_depth_limit_result());
             }
 
@@ -599,6 +717,17 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
     ) -> Result<Vec<Key>, Self::Error> {
         self.reader.keys_with_prefix(correlation_id, prefix)
     }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: &[u8],
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        self.reader
+            .keys_with_prefix_after(correlation_id, prefix, start_after, limit)
+    }
 }
 
 /// Error conditions of a proof validation.
@@ -1,4 +1,4 @@
-use std::{cell::Cell, iter, rc::Rc};
+use std::{cell::Cell, collections::BTreeSet, iter, rc::Rc};
 
 use assert_matches::assert_matches;
 use proptest::prelude::*;
@@ -8,9 +8,10 @@ use casper_types::{
     account::{
         Account, AccountHash, ActionThresholds, AssociatedKeys, Weight, ACCOUNT_HASH_LENGTH,
     },
+    bytesrepr::ToBytes,
     contracts::NamedKeys,
     gens::*,
-    AccessRights, CLValue, Contract, EntryPoints, HashAddr, Key, KeyTag, ProtocolVersion,
+    AccessRights, CLValue, Contract, EntryPoints, EraId, HashAddr, Key, KeyTag, ProtocolVersion,
     StoredValue, URef, U256, U512,
 };
 
@@ -78,6 +79,16 @@ impl StateReader<Key, StoredValue> for CountingDb {
     ) -> Result<Vec<Key>, Self::Error> {
         Ok(Vec::new())
     }
+
+    fn keys_with_prefix_after(
+        &self,
+        _correlation_id: CorrelationId,
+        _prefix: &[u8],
+        _start_after: &[u8],
+        _limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        Ok(Vec::new())
+    }
 }
 
 #[test]
@@ -306,6 +317,36 @@ fn tracking_copy_aw() {
     );
 }
 
+#[test]
+fn tracking_copy_merge_journal() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(0_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([0u8; 32]);
+    let k2 = Key::Hash([1u8; 32]);
+
+    let value = StoredValue::CLValue(CLValue::from_t(3_i32).unwrap());
+    let write_value = StoredValue::CLValue(CLValue::from_t(7_i32).unwrap());
+    let journal = {
+        let mut fork = tc.fork();
+        let _ = fork.read(correlation_id, &k1);
+        let _ = fork.add(correlation_id, k1, value);
+        fork.write(k2, write_value.clone());
+        fork.execution_journal()
+    };
+    assert!(tc.journal.is_empty());
+
+    // merging keeps the transforms of the fork as they are
+    let merge = tc.merge_journal(correlation_id, journal.clone());
+    assert_matches!(merge, Ok(AddResult::Success));
+    assert_eq!(tc.journal, journal);
+    assert_eq!(
+        tc.read(correlation_id, &k1).unwrap(),
+        Some(StoredValue::CLValue(CLValue::from_t(3_i32).unwrap()))
+    );
+    assert_eq!(tc.read(correlation_id, &k2).unwrap(), Some(write_value));
+}
+
 proptest! {
     #[test]
     fn query_empty_path(k in key_arb(), missing_key in key_arb(), v in stored_value_arb()) {
@@ -1031,6 +1072,131 @@ fn get_keys_should_handle_reads_from_empty_trie() {
     assert!(!key_set.contains(&account_key));
 }
 
+#[test]
+fn get_keys_after_should_page_through_keys_in_canonical_order() {
+    let correlation_id = CorrelationId::new();
+    let value = StoredValue::CLValue(CLValue::from_t(U512::zero()).expect("should convert"));
+    // EraInfo keys serialize little-endian, so their canonical order differs from `Ord`.
+    let era_ids = [1u64, 2, 256, 257, 65_536];
+    let pairs: Vec<(Key, StoredValue)> = era_ids
+        .iter()
+        .rev()
+        .map(|era_id| (Key::EraInfo(EraId::new(*era_id)), value.clone()))
+        .chain(iter::once((
+            Key::URef(URef::new([8; 32], AccessRights::READ_ADD_WRITE)),
+            value.clone(),
+        )))
+        .collect();
+    let (global_state, root_hash) =
+        InMemoryGlobalState::from_pairs(correlation_id, &pairs).unwrap();
+
+    let page_through = |limit: usize| {
+        let view = global_state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have view");
+        let mut tracking_copy = TrackingCopy::new(view);
+        let mut keys = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = tracking_copy
+                .get_keys_after(correlation_id, &KeyTag::EraInfo, cursor.as_ref(), limit)
+                .unwrap();
+            assert!(page.len() <= limit);
+            match page.last() {
+                Some(last) => cursor = Some(*last),
+                None => break,
+            }
+            keys.extend(page);
+        }
+        keys
+    };
+
+    let mut expected: Vec<Key> = era_ids
+        .iter()
+        .map(|era_id| Key::EraInfo(EraId::new(*era_id)))
+        .collect();
+    expected.sort_by_key(|key| key.to_bytes().unwrap());
+    assert_eq!(
+        expected,
+        vec![
+            Key::EraInfo(EraId::new(65_536)),
+            Key::EraInfo(EraId::new(256)),
+            Key::EraInfo(EraId::new(1)),
+            Key::EraInfo(EraId::new(257)),
+            Key::EraInfo(EraId::new(2)),
+        ]
+    );
+    assert_eq!(page_through(2), expected);
+    assert_eq!(page_through(1), expected);
+    assert_eq!(page_through(10), expected);
+}
+
+#[test]
+fn get_keys_after_should_include_cached_writes_after_cursor() {
+    let correlation_id = CorrelationId::new();
+    let value = StoredValue::CLValue(CLValue::from_t(U512::zero()).expect("should convert"));
+    let pairs: Vec<(Key, StoredValue)> = [1u64, 3]
+        .iter()
+        .map(|era_id| (Key::EraInfo(EraId::new(*era_id)), value.clone()))
+        .collect();
+    let (global_state, root_hash) =
+        InMemoryGlobalState::from_pairs(correlation_id, &pairs).unwrap();
+    let view = global_state
+        .checkout(root_hash)
+        .expect("should checkout")
+        .expect("should have view");
+    let mut tracking_copy = TrackingCopy::new(view);
+    tracking_copy.write(Key::EraInfo(EraId::new(0)), value.clone());
+    tracking_copy.write(Key::EraInfo(EraId::new(2)), value);
+
+    let cursor = Key::EraInfo(EraId::new(1));
+    let page = tracking_copy
+        .get_keys_after(correlation_id, &KeyTag::EraInfo, Some(&cursor), 1)
+        .unwrap();
+    assert_eq!(page, vec![Key::EraInfo(EraId::new(2))]);
+
+    let page = tracking_copy
+        .get_keys_after(correlation_id, &KeyTag::EraInfo, Some(&cursor), 10)
+        .unwrap();
+    assert_eq!(
+        page,
+        vec![Key::EraInfo(EraId::new(2)), Key::EraInfo(EraId::new(3))]
+    );
+}
+
+#[test]
+fn get_keys_with_prefix_should_include_cached_writes() {
+    let correlation_id = CorrelationId::new();
+    let value = StoredValue::CLValue(CLValue::from_t(U512::zero()).expect("should convert"));
+    let seed_uref = URef::new([8; 32], AccessRights::READ_ADD_WRITE);
+    let other_seed_uref = URef::new([9; 32], AccessRights::READ_ADD_WRITE);
+    let pairs = vec![
+        (Key::sorted_index(seed_uref, 2), value.clone()),
+        (Key::sorted_index(other_seed_uref, 1), value.clone()),
+    ];
+    let (global_state, root_hash) =
+        InMemoryGlobalState::from_pairs(correlation_id, &pairs).unwrap();
+    let view = global_state
+        .checkout(root_hash)
+        .expect("should checkout")
+        .expect("should have view");
+    let mut tracking_copy = TrackingCopy::new(view);
+    tracking_copy.write(Key::sorted_index(seed_uref, 1), value.clone());
+    tracking_copy.write(Key::sorted_index(other_seed_uref, 3), value);
+
+    let keys = tracking_copy
+        .get_keys_with_prefix(correlation_id, &Key::sorted_index_prefix(seed_uref))
+        .unwrap();
+    let expected: BTreeSet<Key> = [
+        Key::sorted_index(seed_uref, 1),
+        Key::sorted_index(seed_uref, 2),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(keys, expected);
+}
+
 fn val_to_hashaddr<T: Into<U256>>(value: T) -> HashAddr {
     let mut addr = HashAddr::default();
     value.into().to_big_endian(&mut addr);
@@ -1091,6 +1257,64 @@ fn query_with_large_depth_with_fixed_path_should_fail() {
     );
 }
 
+#[test]
+fn query_should_respect_given_max_depth() {
+    const MAX_QUERY_DEPTH: u64 = 2;
+
+    let mut pairs = Vec::new();
+    let mut path = Vec::new();
+
+    // create a chain of contracts one longer than the given max query depth.
+    for value in 1..=MAX_QUERY_DEPTH + 1 {
+        let contract_key = Key::Hash(val_to_hashaddr(value));
+        let next_contract_key = Key::Hash(val_to_hashaddr(value + 1));
+        let contract_name = format!("contract{}", value);
+
+        let named_keys = {
+            let mut named_keys = NamedKeys::new();
+            named_keys.insert(contract_name.clone(), next_contract_key);
+            named_keys
+        };
+        let contract = StoredValue::Contract(Contract::new(
+            val_to_hashaddr(1_000 + value).into(),
+            val_to_hashaddr(1_000_000 + value).into(),
+            named_keys,
+            EntryPoints::default(),
+            ProtocolVersion::V1_0_0,
+        ));
+        pairs.push((contract_key, contract));
+        path.push(contract_name);
+    }
+
+    let correlation_id = CorrelationId::new();
+    let (global_state, root_hash) =
+        InMemoryGlobalState::from_pairs(correlation_id, &pairs).unwrap();
+
+    let view = global_state.checkout(root_hash).unwrap().unwrap();
+    let tracking_copy = TrackingCopy::new(view);
+
+    let contract_key = Key::Hash(val_to_hashaddr(1));
+    let result = tracking_copy.query_with_max_depth(
+        correlation_id,
+        MAX_QUERY_DEPTH,
+        contract_key,
+        &path[..MAX_QUERY_DEPTH as usize - 1],
+    );
+    assert!(
+        matches!(result, Ok(TrackingCopyQueryResult::Success { .. })),
+        "{:?}",
+        result
+    );
+
+    let result =
+        tracking_copy.query_with_max_depth(correlation_id, MAX_QUERY_DEPTH, contract_key, &path);
+    assert!(
+        matches!(result, Ok(TrackingCopyQueryResult::DepthLimit { depth }) if depth == MAX_QUERY_DEPTH),
+        "{:?}",
+        result
+    );
+}
+
 #[test]
 fn query_with_large_depth_with_urefs_should_fail() {
     let engine_config = EngineConfig::default();
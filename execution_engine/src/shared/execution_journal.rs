@@ -5,7 +5,8 @@ use std::{iter::IntoIterator, vec::IntoIter};
 use datasize::DataSize;
 
 use casper_types::{
-    ExecutionEffect as JsonExecutionEffect, Key, TransformEntry as JsonTransformEntry,
+    bytesrepr::ToBytes, ExecutionEffect as JsonExecutionEffect, Key,
+    TransformEntry as JsonTransformEntry,
 };
 
 use crate::shared::transform::Transform;
@@ -39,6 +40,14 @@ impl ExecutionJournal {
     pub fn iter(&self) -> impl Iterator<Item = &(Key, Transform)> {
         self.0.iter()
     }
+
+    /// Returns the size in bytes of the journal's keys and transforms once serialized.
+    pub fn serialized_size(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(key, transform)| key.serialized_length() + transform.serialized_size())
+            .sum()
+    }
 }
 
 impl From<&ExecutionJournal> for JsonExecutionEffect {
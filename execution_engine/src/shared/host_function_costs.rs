@@ -78,6 +78,35 @@ const DEFAULT_DICTIONARY_PUT_KEY_BYTES_SIZE_WEIGHT: u32 = 1_800;
 const DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT: u32 = 520;
 const DEFAULT_BLAKE2B_COST: u32 = 1_200_000;
 
+const DEFAULT_EMIT_EVENT_COST: u32 = 20_000;
+const DEFAULT_EMIT_EVENT_NAME_SIZE_WEIGHT: u32 = 980;
+const DEFAULT_EMIT_EVENT_DATA_SIZE_WEIGHT: u32 = 980;
+
+const DEFAULT_SORTED_INDEX_INSERT_COST: u32 = 30_000;
+const DEFAULT_SORTED_INDEX_INSERT_VALUE_SIZE_WEIGHT: u32 = 520;
+const DEFAULT_SORTED_INDEX_REMOVE_COST: u32 = 20_000;
+const DEFAULT_SORTED_INDEX_GET_COST: u32 = 5_500;
+const DEFAULT_SORTED_INDEX_RANGE_COST: u32 = 15_000;
+const DEFAULT_SORTED_INDEX_RANGE_LIMIT_WEIGHT: u32 = 590;
+
+const DEFAULT_NAMESPACE_PUT_COST: u32 = 12_000;
+const DEFAULT_NAMESPACE_PUT_KEY_BYTES_SIZE_WEIGHT: u32 = 1_800;
+const DEFAULT_NAMESPACE_PUT_VALUE_SIZE_WEIGHT: u32 = 520;
+const DEFAULT_NAMESPACE_GET_COST: u32 = 7_000;
+const DEFAULT_NAMESPACE_GET_KEY_SIZE_WEIGHT: u32 = 590;
+const DEFAULT_NAMESPACE_CLEAR_COST: u32 = 14_000;
+
+const DEFAULT_NEW_UNIQUE_ADDRESS_COST: u32 = 2_000;
+const DEFAULT_NEW_UNIQUE_ADDRESS_TAG_SIZE_WEIGHT: u32 = 590;
+
+const DEFAULT_SCHEDULE_CALLBACK_COST: u32 = 50_000_000;
+const DEFAULT_SCHEDULE_CALLBACK_ARGS_SIZE_WEIGHT: u32 = 520;
+const DEFAULT_CANCEL_CALLBACK_COST: u32 = 61_000;
+
+/// Fixed cost of the host functions which have always been charged a hard-coded amount rather
+/// than one taken from the cost table.
+pub(crate) const UNLISTED_HOST_FUNCTION_COST: Cost = 10_000;
+
 /// Representation of a host function cost.
 ///
 /// The total gas cost is equal to `cost` + sum of each argument weight multiplied by the byte size
@@ -287,6 +316,366 @@ pub struct HostFunctionCosts {
     pub random_bytes: HostFunction<[Cost; 2]>,
     /// Cost of calling the `enable_contract_version` host function.
     pub enable_contract_version: HostFunction<[Cost; 4]>,
+    /// Cost of calling the `create_contract_package_at_seed` host function.
+    pub create_contract_package_at_seed: HostFunction<[Cost; 6]>,
+    /// Cost of calling the `emit_event` host function.
+    pub emit_event: HostFunction<[Cost; 4]>,
+    /// Cost of calling the `set_contract_package_paused` host function.
+    pub set_contract_package_paused: HostFunction<[Cost; 3]>,
+    /// Cost of calling the `get_remaining_call_depth` host function.
+    pub get_remaining_call_depth: HostFunction<[Cost; 1]>,
+    /// Cost of calling the `sorted_index_insert` host function.
+    pub sorted_index_insert: HostFunction<[Cost; 6]>,
+    /// Cost of calling the `sorted_index_remove` host function.
+    pub sorted_index_remove: HostFunction<[Cost; 4]>,
+    /// Cost of calling the `sorted_index_get` host function.
+    pub sorted_index_get: HostFunction<[Cost; 5]>,
+    /// Cost of calling the `sorted_index_range` host function.
+    pub sorted_index_range: HostFunction<[Cost; 7]>,
+    /// Cost of calling the `namespace_put` host function.
+    pub namespace_put: HostFunction<[Cost; 6]>,
+    /// Cost of calling the `namespace_get` host function.
+    pub namespace_get: HostFunction<[Cost; 5]>,
+    /// Cost of calling the `namespace_clear` host function.
+    pub namespace_clear: HostFunction<[Cost; 2]>,
+    /// Cost of calling the `new_unique_address` host function.
+    pub new_unique_address: HostFunction<[Cost; 3]>,
+    /// Cost of calling the `schedule_callback` host function.
+    pub schedule_callback: HostFunction<[Cost; 9]>,
+    /// Cost of calling the `cancel_callback` host function.
+    pub cancel_callback: HostFunction<[Cost; 1]>,
+    /// Cost of calling the `transfer_from_purse_to_purse_with_result` host function.
+    pub transfer_from_purse_to_purse_with_result: HostFunction<[Cost; 9]>,
+}
+
+/// The fixed cost and argument weights charged for calling a single host function.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HostFunctionCost {
+    /// How much the user is charged for calling the host function.
+    pub cost: Cost,
+    /// Weights of the function arguments.
+    pub arguments: Vec<Cost>,
+}
+
+impl<T> From<&HostFunction<T>> for HostFunctionCost
+where
+    T: AsRef<[Cost]>,
+{
+    fn from(host_function: &HostFunction<T>) -> Self {
+        HostFunctionCost {
+            cost: host_function.cost(),
+            arguments: host_function.arguments().to_vec(),
+        }
+    }
+}
+
+impl HostFunctionCosts {
+    /// Returns the cost charged for calling the host function imported by Wasm as `name`.
+    ///
+    /// Returns `None` if the host doesn't export such a function, or if calls to it are not
+    /// charged, as is the case for functions reserved to system contracts.
+    pub fn cost_of(&self, name: &str) -> Option<HostFunctionCost> {
+        let cost = match name {
+            "casper_read_value" => HostFunctionCost::from(&self.read_value),
+            "casper_load_named_keys" => HostFunctionCost::from(&self.load_named_keys),
+            "casper_write" => HostFunctionCost::from(&self.write),
+            "casper_add" => HostFunctionCost::from(&self.add),
+            "casper_new_uref" => HostFunctionCost::from(&self.new_uref),
+            "casper_ret" => HostFunctionCost::from(&self.ret),
+            "casper_get_key" => HostFunctionCost::from(&self.get_key),
+            "casper_has_key" => HostFunctionCost::from(&self.has_key),
+            "casper_put_key" => HostFunctionCost::from(&self.put_key),
+            "casper_is_valid_uref" => HostFunctionCost::from(&self.is_valid_uref),
+            "casper_revert" => HostFunctionCost::from(&self.revert),
+            "casper_add_associated_key" => HostFunctionCost::from(&self.add_associated_key),
+            "casper_remove_associated_key" => HostFunctionCost::from(&self.remove_associated_key),
+            "casper_update_associated_key" => HostFunctionCost::from(&self.update_associated_key),
+            "casper_set_action_threshold" => HostFunctionCost::from(&self.set_action_threshold),
+            "casper_remove_key" => HostFunctionCost::from(&self.remove_key),
+            "casper_get_caller" => HostFunctionCost::from(&self.get_caller),
+            "casper_get_blocktime" => HostFunctionCost::from(&self.get_blocktime),
+            "casper_create_purse" => HostFunctionCost::from(&self.create_purse),
+            "casper_transfer_to_account" => HostFunctionCost::from(&self.transfer_to_account),
+            "casper_transfer_from_purse_to_account" => {
+                HostFunctionCost::from(&self.transfer_from_purse_to_account)
+            }
+            "casper_transfer_from_purse_to_purse" => {
+                HostFunctionCost::from(&self.transfer_from_purse_to_purse)
+            }
+            "casper_get_balance" => HostFunctionCost::from(&self.get_balance),
+            "casper_get_phase" => HostFunctionCost::from(&self.get_phase),
+            "casper_get_system_contract" => HostFunctionCost::from(&self.get_system_contract),
+            "casper_get_main_purse" => HostFunctionCost::from(&self.get_main_purse),
+            "casper_read_host_buffer" => HostFunctionCost::from(&self.read_host_buffer),
+            "casper_create_contract_package_at_hash" => {
+                HostFunctionCost::from(&self.create_contract_package_at_hash)
+            }
+            "casper_create_contract_user_group" => {
+                HostFunctionCost::from(&self.create_contract_user_group)
+            }
+            "casper_add_contract_version" => HostFunctionCost::from(&self.add_contract_version),
+            "casper_disable_contract_version" => {
+                HostFunctionCost::from(&self.disable_contract_version)
+            }
+            "casper_call_contract" => HostFunctionCost::from(&self.call_contract),
+            "casper_call_versioned_contract" => {
+                HostFunctionCost::from(&self.call_versioned_contract)
+            }
+            "casper_get_named_arg_size" => HostFunctionCost::from(&self.get_named_arg_size),
+            "casper_get_named_arg" => HostFunctionCost::from(&self.get_named_arg),
+            "casper_remove_contract_user_group" => {
+                HostFunctionCost::from(&self.remove_contract_user_group)
+            }
+            "casper_provision_contract_user_group_uref" => {
+                HostFunctionCost::from(&self.provision_contract_user_group_uref)
+            }
+            "casper_remove_contract_user_group_urefs" => {
+                HostFunctionCost::from(&self.remove_contract_user_group_urefs)
+            }
+            "casper_blake2b" => HostFunctionCost::from(&self.blake2b),
+            "casper_print" => HostFunctionCost::from(&self.print),
+            "casper_dictionary_get" => HostFunctionCost::from(&self.dictionary_get),
+            "casper_dictionary_read" => HostFunctionCost::from(&self.read_value),
+            "casper_dictionary_put" => HostFunctionCost::from(&self.dictionary_put),
+            "casper_new_dictionary" => HostFunctionCost::from(&self.new_uref),
+            "casper_random_bytes" => HostFunctionCost::from(&self.random_bytes),
+            "casper_enable_contract_version" => {
+                HostFunctionCost::from(&self.enable_contract_version)
+            }
+            "casper_create_contract_package_at_seed" => {
+                HostFunctionCost::from(&self.create_contract_package_at_seed)
+            }
+            "casper_emit_event" => HostFunctionCost::from(&self.emit_event),
+            "casper_set_contract_package_paused" => {
+                HostFunctionCost::from(&self.set_contract_package_paused)
+            }
+            "casper_get_remaining_call_depth" => {
+                HostFunctionCost::from(&self.get_remaining_call_depth)
+            }
+            "casper_sorted_index_insert" => HostFunctionCost::from(&self.sorted_index_insert),
+            "casper_sorted_index_remove" => HostFunctionCost::from(&self.sorted_index_remove),
+            "casper_sorted_index_get" => HostFunctionCost::from(&self.sorted_index_get),
+            "casper_sorted_index_range" => HostFunctionCost::from(&self.sorted_index_range),
+            "casper_namespace_put" => HostFunctionCost::from(&self.namespace_put),
+            "casper_namespace_get" => HostFunctionCost::from(&self.namespace_get),
+            "casper_namespace_clear" => HostFunctionCost::from(&self.namespace_clear),
+            "casper_new_unique_address" => HostFunctionCost::from(&self.new_unique_address),
+            "casper_schedule_callback" => HostFunctionCost::from(&self.schedule_callback),
+            "casper_cancel_callback" => HostFunctionCost::from(&self.cancel_callback),
+            "casper_transfer_from_purse_to_purse_with_result" => {
+                HostFunctionCost::from(&self.transfer_from_purse_to_purse_with_result)
+            }
+            "casper_load_call_stack" | "casper_load_authorization_keys" => HostFunctionCost {
+                cost: UNLISTED_HOST_FUNCTION_COST,
+                arguments: vec![NOT_USED; 2],
+            },
+            _ => return None,
+        };
+        Some(cost)
+    }
+
+    /// Serializes the costs in the layout used before the host functions from
+    /// `create_contract_package_at_seed` onwards existed, i.e. without their costs.
+    pub fn to_legacy_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(self.legacy_serialized_length());
+        self.append_legacy_bytes(&mut ret)?;
+        Ok(ret)
+    }
+
+    /// Returns the length of [`HostFunctionCosts::to_legacy_bytes`].
+    pub fn legacy_serialized_length(&self) -> usize {
+        self.read_value.serialized_length()
+            + self.dictionary_get.serialized_length()
+            + self.write.serialized_length()
+            + self.dictionary_put.serialized_length()
+            + self.add.serialized_length()
+            + self.new_uref.serialized_length()
+            + self.load_named_keys.serialized_length()
+            + self.ret.serialized_length()
+            + self.get_key.serialized_length()
+            + self.has_key.serialized_length()
+            + self.put_key.serialized_length()
+            + self.remove_key.serialized_length()
+            + self.revert.serialized_length()
+            + self.is_valid_uref.serialized_length()
+            + self.add_associated_key.serialized_length()
+            + self.remove_associated_key.serialized_length()
+            + self.update_associated_key.serialized_length()
+            + self.set_action_threshold.serialized_length()
+            + self.get_caller.serialized_length()
+            + self.get_blocktime.serialized_length()
+            + self.create_purse.serialized_length()
+            + self.transfer_to_account.serialized_length()
+            + self.transfer_from_purse_to_account.serialized_length()
+            + self.transfer_from_purse_to_purse.serialized_length()
+            + self.get_balance.serialized_length()
+            + self.get_phase.serialized_length()
+            + self.get_system_contract.serialized_length()
+            + self.get_main_purse.serialized_length()
+            + self.read_host_buffer.serialized_length()
+            + self.create_contract_package_at_hash.serialized_length()
+            + self.create_contract_user_group.serialized_length()
+            + self.add_contract_version.serialized_length()
+            + self.disable_contract_version.serialized_length()
+            + self.call_contract.serialized_length()
+            + self.call_versioned_contract.serialized_length()
+            + self.get_named_arg_size.serialized_length()
+            + self.get_named_arg.serialized_length()
+            + self.remove_contract_user_group.serialized_length()
+            + self.provision_contract_user_group_uref.serialized_length()
+            + self.remove_contract_user_group_urefs.serialized_length()
+            + self.print.serialized_length()
+            + self.blake2b.serialized_length()
+            + self.random_bytes.serialized_length()
+            + self.enable_contract_version.serialized_length()
+    }
+
+    /// Deserializes costs written by [`HostFunctionCosts::to_legacy_bytes`], taking the default
+    /// costs of the host functions missing from that layout.
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (read_value, rem) = FromBytes::from_bytes(bytes)?;
+        let (dictionary_get, rem) = FromBytes::from_bytes(rem)?;
+        let (write, rem) = FromBytes::from_bytes(rem)?;
+        let (dictionary_put, rem) = FromBytes::from_bytes(rem)?;
+        let (add, rem) = FromBytes::from_bytes(rem)?;
+        let (new_uref, rem) = FromBytes::from_bytes(rem)?;
+        let (load_named_keys, rem) = FromBytes::from_bytes(rem)?;
+        let (ret, rem) = FromBytes::from_bytes(rem)?;
+        let (get_key, rem) = FromBytes::from_bytes(rem)?;
+        let (has_key, rem) = FromBytes::from_bytes(rem)?;
+        let (put_key, rem) = FromBytes::from_bytes(rem)?;
+        let (remove_key, rem) = FromBytes::from_bytes(rem)?;
+        let (revert, rem) = FromBytes::from_bytes(rem)?;
+        let (is_valid_uref, rem) = FromBytes::from_bytes(rem)?;
+        let (add_associated_key, rem) = FromBytes::from_bytes(rem)?;
+        let (remove_associated_key, rem) = FromBytes::from_bytes(rem)?;
+        let (update_associated_key, rem) = FromBytes::from_bytes(rem)?;
+        let (set_action_threshold, rem) = FromBytes::from_bytes(rem)?;
+        let (get_caller, rem) = FromBytes::from_bytes(rem)?;
+        let (get_blocktime, rem) = FromBytes::from_bytes(rem)?;
+        let (create_purse, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_to_account, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from_purse_to_account, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from_purse_to_purse, rem) = FromBytes::from_bytes(rem)?;
+        let (get_balance, rem) = FromBytes::from_bytes(rem)?;
+        let (get_phase, rem) = FromBytes::from_bytes(rem)?;
+        let (get_system_contract, rem) = FromBytes::from_bytes(rem)?;
+        let (get_main_purse, rem) = FromBytes::from_bytes(rem)?;
+        let (read_host_buffer, rem) = FromBytes::from_bytes(rem)?;
+        let (create_contract_package_at_hash, rem) = FromBytes::from_bytes(rem)?;
+        let (create_contract_user_group, rem) = FromBytes::from_bytes(rem)?;
+        let (add_contract_version, rem) = FromBytes::from_bytes(rem)?;
+        let (disable_contract_version, rem) = FromBytes::from_bytes(rem)?;
+        let (call_contract, rem) = FromBytes::from_bytes(rem)?;
+        let (call_versioned_contract, rem) = FromBytes::from_bytes(rem)?;
+        let (get_named_arg_size, rem) = FromBytes::from_bytes(rem)?;
+        let (get_named_arg, rem) = FromBytes::from_bytes(rem)?;
+        let (remove_contract_user_group, rem) = FromBytes::from_bytes(rem)?;
+        let (provision_contract_user_group_uref, rem) = FromBytes::from_bytes(rem)?;
+        let (remove_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
+        let (print, rem) = FromBytes::from_bytes(rem)?;
+        let (blake2b, rem) = FromBytes::from_bytes(rem)?;
+        let (random_bytes, rem) = FromBytes::from_bytes(rem)?;
+        let (enable_contract_version, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            HostFunctionCosts {
+                read_value,
+                dictionary_get,
+                write,
+                dictionary_put,
+                add,
+                new_uref,
+                load_named_keys,
+                ret,
+                get_key,
+                has_key,
+                put_key,
+                remove_key,
+                revert,
+                is_valid_uref,
+                add_associated_key,
+                remove_associated_key,
+                update_associated_key,
+                set_action_threshold,
+                get_caller,
+                get_blocktime,
+                create_purse,
+                transfer_to_account,
+                transfer_from_purse_to_account,
+                transfer_from_purse_to_purse,
+                get_balance,
+                get_phase,
+                get_system_contract,
+                get_main_purse,
+                read_host_buffer,
+                create_contract_package_at_hash,
+                create_contract_user_group,
+                add_contract_version,
+                disable_contract_version,
+                call_contract,
+                call_versioned_contract,
+                get_named_arg_size,
+                get_named_arg,
+                remove_contract_user_group,
+                provision_contract_user_group_uref,
+                remove_contract_user_group_urefs,
+                print,
+                blake2b,
+                random_bytes,
+                enable_contract_version,
+                ..HostFunctionCosts::default()
+            },
+            rem,
+        ))
+    }
+
+    fn append_legacy_bytes(&self, ret: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        ret.append(&mut self.read_value.to_bytes()?);
+        ret.append(&mut self.dictionary_get.to_bytes()?);
+        ret.append(&mut self.write.to_bytes()?);
+        ret.append(&mut self.dictionary_put.to_bytes()?);
+        ret.append(&mut self.add.to_bytes()?);
+        ret.append(&mut self.new_uref.to_bytes()?);
+        ret.append(&mut self.load_named_keys.to_bytes()?);
+        ret.append(&mut self.ret.to_bytes()?);
+        ret.append(&mut self.get_key.to_bytes()?);
+        ret.append(&mut self.has_key.to_bytes()?);
+        ret.append(&mut self.put_key.to_bytes()?);
+        ret.append(&mut self.remove_key.to_bytes()?);
+        ret.append(&mut self.revert.to_bytes()?);
+        ret.append(&mut self.is_valid_uref.to_bytes()?);
+        ret.append(&mut self.add_associated_key.to_bytes()?);
+        ret.append(&mut self.remove_associated_key.to_bytes()?);
+        ret.append(&mut self.update_associated_key.to_bytes()?);
+        ret.append(&mut self.set_action_threshold.to_bytes()?);
+        ret.append(&mut self.get_caller.to_bytes()?);
+        ret.append(&mut self.get_blocktime.to_bytes()?);
+        ret.append(&mut self.create_purse.to_bytes()?);
+        ret.append(&mut self.transfer_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
+        ret.append(&mut self.get_balance.to_bytes()?);
+        ret.append(&mut self.get_phase.to_bytes()?);
+        ret.append(&mut self.get_system_contract.to_bytes()?);
+        ret.append(&mut self.get_main_purse.to_bytes()?);
+        ret.append(&mut self.read_host_buffer.to_bytes()?);
+        ret.append(&mut self.create_contract_package_at_hash.to_bytes()?);
+        ret.append(&mut self.create_contract_user_group.to_bytes()?);
+        ret.append(&mut self.add_contract_version.to_bytes()?);
+        ret.append(&mut self.disable_contract_version.to_bytes()?);
+        ret.append(&mut self.call_contract.to_bytes()?);
+        ret.append(&mut self.call_versioned_contract.to_bytes()?);
+        ret.append(&mut self.get_named_arg_size.to_bytes()?);
+        ret.append(&mut self.get_named_arg.to_bytes()?);
+        ret.append(&mut self.remove_contract_user_group.to_bytes()?);
+        ret.append(&mut self.provision_contract_user_group_uref.to_bytes()?);
+        ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
+        ret.append(&mut self.print.to_bytes()?);
+        ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.random_bytes.to_bytes()?);
+        ret.append(&mut self.enable_contract_version.to_bytes()?);
+        Ok(())
+    }
 }
 
 impl Default for HostFunctionCosts {
@@ -452,6 +841,91 @@ impl Default for HostFunctionCosts {
             ),
             random_bytes: HostFunction::default(),
             enable_contract_version: HostFunction::default(),
+            create_contract_package_at_seed: HostFunction::default(),
+            emit_event: HostFunction::new(
+                DEFAULT_EMIT_EVENT_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_EMIT_EVENT_NAME_SIZE_WEIGHT,
+                    NOT_USED,
+                    DEFAULT_EMIT_EVENT_DATA_SIZE_WEIGHT,
+                ],
+            ),
+            set_contract_package_paused: HostFunction::default(),
+            get_remaining_call_depth: HostFunction::fixed(DEFAULT_GET_PHASE_COST),
+            sorted_index_insert: HostFunction::new(
+                DEFAULT_SORTED_INDEX_INSERT_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_SORTED_INDEX_INSERT_VALUE_SIZE_WEIGHT,
+                ],
+            ),
+            sorted_index_remove: HostFunction::fixed(DEFAULT_SORTED_INDEX_REMOVE_COST),
+            sorted_index_get: HostFunction::fixed(DEFAULT_SORTED_INDEX_GET_COST),
+            sorted_index_range: HostFunction::new(
+                DEFAULT_SORTED_INDEX_RANGE_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_SORTED_INDEX_RANGE_LIMIT_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            namespace_put: HostFunction::new(
+                DEFAULT_NAMESPACE_PUT_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_NAMESPACE_PUT_KEY_BYTES_SIZE_WEIGHT,
+                    NOT_USED,
+                    DEFAULT_NAMESPACE_PUT_VALUE_SIZE_WEIGHT,
+                ],
+            ),
+            namespace_get: HostFunction::new(
+                DEFAULT_NAMESPACE_GET_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_NAMESPACE_GET_KEY_SIZE_WEIGHT,
+                    NOT_USED,
+                ],
+            ),
+            namespace_clear: HostFunction::fixed(DEFAULT_NAMESPACE_CLEAR_COST),
+            new_unique_address: HostFunction::new(
+                DEFAULT_NEW_UNIQUE_ADDRESS_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_NEW_UNIQUE_ADDRESS_TAG_SIZE_WEIGHT,
+                    NOT_USED,
+                ],
+            ),
+            schedule_callback: HostFunction::new(
+                DEFAULT_SCHEDULE_CALLBACK_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_SCHEDULE_CALLBACK_ARGS_SIZE_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            cancel_callback: HostFunction::fixed(DEFAULT_CANCEL_CALLBACK_COST),
+            transfer_from_purse_to_purse_with_result: HostFunction::fixed(
+                DEFAULT_TRANSFER_FROM_PURSE_TO_PURSE_COST,
+            ),
         }
     }
 }
@@ -459,193 +933,83 @@ impl Default for HostFunctionCosts {
 impl ToBytes for HostFunctionCosts {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-        ret.append(&mut self.read_value.to_bytes()?);
-        ret.append(&mut self.dictionary_get.to_bytes()?);
-        ret.append(&mut self.write.to_bytes()?);
-        ret.append(&mut self.dictionary_put.to_bytes()?);
-        ret.append(&mut self.add.to_bytes()?);
-        ret.append(&mut self.new_uref.to_bytes()?);
-        ret.append(&mut self.load_named_keys.to_bytes()?);
-        ret.append(&mut self.ret.to_bytes()?);
-        ret.append(&mut self.get_key.to_bytes()?);
-        ret.append(&mut self.has_key.to_bytes()?);
-        ret.append(&mut self.put_key.to_bytes()?);
-        ret.append(&mut self.remove_key.to_bytes()?);
-        ret.append(&mut self.revert.to_bytes()?);
-        ret.append(&mut self.is_valid_uref.to_bytes()?);
-        ret.append(&mut self.add_associated_key.to_bytes()?);
-        ret.append(&mut self.remove_associated_key.to_bytes()?);
-        ret.append(&mut self.update_associated_key.to_bytes()?);
-        ret.append(&mut self.set_action_threshold.to_bytes()?);
-        ret.append(&mut self.get_caller.to_bytes()?);
-        ret.append(&mut self.get_blocktime.to_bytes()?);
-        ret.append(&mut self.create_purse.to_bytes()?);
-        ret.append(&mut self.transfer_to_account.to_bytes()?);
-        ret.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
-        ret.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
-        ret.append(&mut self.get_balance.to_bytes()?);
-        ret.append(&mut self.get_phase.to_bytes()?);
-        ret.append(&mut self.get_system_contract.to_bytes()?);
-        ret.append(&mut self.get_main_purse.to_bytes()?);
-        ret.append(&mut self.read_host_buffer.to_bytes()?);
-        ret.append(&mut self.create_contract_package_at_hash.to_bytes()?);
-        ret.append(&mut self.create_contract_user_group.to_bytes()?);
-        ret.append(&mut self.add_contract_version.to_bytes()?);
-        ret.append(&mut self.disable_contract_version.to_bytes()?);
-        ret.append(&mut self.call_contract.to_bytes()?);
-        ret.append(&mut self.call_versioned_contract.to_bytes()?);
-        ret.append(&mut self.get_named_arg_size.to_bytes()?);
-        ret.append(&mut self.get_named_arg.to_bytes()?);
-        ret.append(&mut self.remove_contract_user_group.to_bytes()?);
-        ret.append(&mut self.provision_contract_user_group_uref.to_bytes()?);
-        ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
-        ret.append(&mut self.print.to_bytes()?);
-        ret.append(&mut self.blake2b.to_bytes()?);
-        ret.append(&mut self.random_bytes.to_bytes()?);
-        ret.append(&mut self.enable_contract_version.to_bytes()?);
+        self.append_legacy_bytes(&mut ret)?;
+        ret.append(&mut self.create_contract_package_at_seed.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.set_contract_package_paused.to_bytes()?);
+        ret.append(&mut self.get_remaining_call_depth.to_bytes()?);
+        ret.append(&mut self.sorted_index_insert.to_bytes()?);
+        ret.append(&mut self.sorted_index_remove.to_bytes()?);
+        ret.append(&mut self.sorted_index_get.to_bytes()?);
+        ret.append(&mut self.sorted_index_range.to_bytes()?);
+        ret.append(&mut self.namespace_put.to_bytes()?);
+        ret.append(&mut self.namespace_get.to_bytes()?);
+        ret.append(&mut self.namespace_clear.to_bytes()?);
+        ret.append(&mut self.new_unique_address.to_bytes()?);
+        ret.append(&mut self.schedule_callback.to_bytes()?);
+        ret.append(&mut self.cancel_callback.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_purse_with_result.to_bytes()?);
         Ok(ret)
     }
 
     fn serialized_length(&self) -> usize {
-        self.read_value.serialized_length()
-            + self.dictionary_get.serialized_length()
-            + self.write.serialized_length()
-            + self.dictionary_put.serialized_length()
-            + self.add.serialized_length()
-            + self.new_uref.serialized_length()
-            + self.load_named_keys.serialized_length()
-            + self.ret.serialized_length()
-            + self.get_key.serialized_length()
-            + self.has_key.serialized_length()
-            + self.put_key.serialized_length()
-            + self.remove_key.serialized_length()
-            + self.revert.serialized_length()
-            + self.is_valid_uref.serialized_length()
-            + self.add_associated_key.serialized_length()
-            + self.remove_associated_key.serialized_length()
-            + self.update_associated_key.serialized_length()
-            + self.set_action_threshold.serialized_length()
-            + self.get_caller.serialized_length()
-            + self.get_blocktime.serialized_length()
-            + self.create_purse.serialized_length()
-            + self.transfer_to_account.serialized_length()
-            + self.transfer_from_purse_to_account.serialized_length()
-            + self.transfer_from_purse_to_purse.serialized_length()
-            + self.get_balance.serialized_length()
-            + self.get_phase.serialized_length()
-            + self.get_system_contract.serialized_length()
-            + self.get_main_purse.serialized_length()
-            + self.read_host_buffer.serialized_length()
-            + self.create_contract_package_at_hash.serialized_length()
-            + self.create_contract_user_group.serialized_length()
-            + self.add_contract_version.serialized_length()
-            + self.disable_contract_version.serialized_length()
-            + self.call_contract.serialized_length()
-            + self.call_versioned_contract.serialized_length()
-            + self.get_named_arg_size.serialized_length()
-            + self.get_named_arg.serialized_length()
-            + self.remove_contract_user_group.serialized_length()
-            + self.provision_contract_user_group_uref.serialized_length()
-            + self.remove_contract_user_group_urefs.serialized_length()
-            + self.print.serialized_length()
-            + self.blake2b.serialized_length()
-            + self.random_bytes.serialized_length()
-            + self.enable_contract_version.serialized_length()
+        self.legacy_serialized_length()
+            + self.create_contract_package_at_seed.serialized_length()
+            + self.emit_event.serialized_length()
+            + self.set_contract_package_paused.serialized_length()
+            + self.get_remaining_call_depth.serialized_length()
+            + self.sorted_index_insert.serialized_length()
+            + self.sorted_index_remove.serialized_length()
+            + self.sorted_index_get.serialized_length()
+            + self.sorted_index_range.serialized_length()
+            + self.namespace_put.serialized_length()
+            + self.namespace_get.serialized_length()
+            + self.namespace_clear.serialized_length()
+            + self.new_unique_address.serialized_length()
+            + self.schedule_callback.serialized_length()
+            + self.cancel_callback.serialized_length()
+            + self
+                .transfer_from_purse_to_purse_with_result
+                .serialized_length()
     }
 }
 
 impl FromBytes for HostFunctionCosts {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (read_value, rem) = FromBytes::from_bytes(bytes)?;
-        let (dictionary_get, rem) = FromBytes::from_bytes(rem)?;
-        let (write, rem) = FromBytes::from_bytes(rem)?;
-        let (dictionary_put, rem) = FromBytes::from_bytes(rem)?;
-        let (add, rem) = FromBytes::from_bytes(rem)?;
-        let (new_uref, rem) = FromBytes::from_bytes(rem)?;
-        let (load_named_keys, rem) = FromBytes::from_bytes(rem)?;
-        let (ret, rem) = FromBytes::from_bytes(rem)?;
-        let (get_key, rem) = FromBytes::from_bytes(rem)?;
-        let (has_key, rem) = FromBytes::from_bytes(rem)?;
-        let (put_key, rem) = FromBytes::from_bytes(rem)?;
-        let (remove_key, rem) = FromBytes::from_bytes(rem)?;
-        let (revert, rem) = FromBytes::from_bytes(rem)?;
-        let (is_valid_uref, rem) = FromBytes::from_bytes(rem)?;
-        let (add_associated_key, rem) = FromBytes::from_bytes(rem)?;
-        let (remove_associated_key, rem) = FromBytes::from_bytes(rem)?;
-        let (update_associated_key, rem) = FromBytes::from_bytes(rem)?;
-        let (set_action_threshold, rem) = FromBytes::from_bytes(rem)?;
-        let (get_caller, rem) = FromBytes::from_bytes(rem)?;
-        let (get_blocktime, rem) = FromBytes::from_bytes(rem)?;
-        let (create_purse, rem) = FromBytes::from_bytes(rem)?;
-        let (transfer_to_account, rem) = FromBytes::from_bytes(rem)?;
-        let (transfer_from_purse_to_account, rem) = FromBytes::from_bytes(rem)?;
-        let (transfer_from_purse_to_purse, rem) = FromBytes::from_bytes(rem)?;
-        let (get_balance, rem) = FromBytes::from_bytes(rem)?;
-        let (get_phase, rem) = FromBytes::from_bytes(rem)?;
-        let (get_system_contract, rem) = FromBytes::from_bytes(rem)?;
-        let (get_main_purse, rem) = FromBytes::from_bytes(rem)?;
-        let (read_host_buffer, rem) = FromBytes::from_bytes(rem)?;
-        let (create_contract_package_at_hash, rem) = FromBytes::from_bytes(rem)?;
-        let (create_contract_user_group, rem) = FromBytes::from_bytes(rem)?;
-        let (add_contract_version, rem) = FromBytes::from_bytes(rem)?;
-        let (disable_contract_version, rem) = FromBytes::from_bytes(rem)?;
-        let (call_contract, rem) = FromBytes::from_bytes(rem)?;
-        let (call_versioned_contract, rem) = FromBytes::from_bytes(rem)?;
-        let (get_named_arg_size, rem) = FromBytes::from_bytes(rem)?;
-        let (get_named_arg, rem) = FromBytes::from_bytes(rem)?;
-        let (remove_contract_user_group, rem) = FromBytes::from_bytes(rem)?;
-        let (provision_contract_user_group_uref, rem) = FromBytes::from_bytes(rem)?;
-        let (remove_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
-        let (print, rem) = FromBytes::from_bytes(rem)?;
-        let (blake2b, rem) = FromBytes::from_bytes(rem)?;
-        let (random_bytes, rem) = FromBytes::from_bytes(rem)?;
-        let (enable_contract_version, rem) = FromBytes::from_bytes(rem)?;
+        let (legacy, rem) = Self::from_legacy_bytes(bytes)?;
+        let (create_contract_package_at_seed, rem) = FromBytes::from_bytes(rem)?;
+        let (emit_event, rem) = FromBytes::from_bytes(rem)?;
+        let (set_contract_package_paused, rem) = FromBytes::from_bytes(rem)?;
+        let (get_remaining_call_depth, rem) = FromBytes::from_bytes(rem)?;
+        let (sorted_index_insert, rem) = FromBytes::from_bytes(rem)?;
+        let (sorted_index_remove, rem) = FromBytes::from_bytes(rem)?;
+        let (sorted_index_get, rem) = FromBytes::from_bytes(rem)?;
+        let (sorted_index_range, rem) = FromBytes::from_bytes(rem)?;
+        let (namespace_put, rem) = FromBytes::from_bytes(rem)?;
+        let (namespace_get, rem) = FromBytes::from_bytes(rem)?;
+        let (namespace_clear, rem) = FromBytes::from_bytes(rem)?;
+        let (new_unique_address, rem) = FromBytes::from_bytes(rem)?;
+        let (schedule_callback, rem) = FromBytes::from_bytes(rem)?;
+        let (cancel_callback, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from_purse_to_purse_with_result, rem) = FromBytes::from_bytes(rem)?;
         Ok((
             HostFunctionCosts {
-                read_value,
-                dictionary_get,
-                write,
-                dictionary_put,
-                add,
-                new_uref,
-                load_named_keys,
-                ret,
-                get_key,
-                has_key,
-                put_key,
-                remove_key,
-                revert,
-                is_valid_uref,
-                add_associated_key,
-                remove_associated_key,
-                update_associated_key,
-                set_action_threshold,
-                get_caller,
-                get_blocktime,
-                create_purse,
-                transfer_to_account,
-                transfer_from_purse_to_account,
-                transfer_from_purse_to_purse,
-                get_balance,
-                get_phase,
-                get_system_contract,
-                get_main_purse,
-                read_host_buffer,
-                create_contract_package_at_hash,
-                create_contract_user_group,
-                add_contract_version,
-                disable_contract_version,
-                call_contract,
-                call_versioned_contract,
-                get_named_arg_size,
-                get_named_arg,
-                remove_contract_user_group,
-                provision_contract_user_group_uref,
-                remove_contract_user_group_urefs,
-                print,
-                blake2b,
-                random_bytes,
-                enable_contract_version,
+                create_contract_package_at_seed,
+                emit_event,
+                set_contract_package_paused,
+                get_remaining_call_depth,
+                sorted_index_insert,
+                sorted_index_remove,
+                sorted_index_get,
+                sorted_index_range,
+                namespace_put,
+                namespace_get,
+                namespace_clear,
+                new_unique_address,
+                schedule_callback,
+                cancel_callback,
+                transfer_from_purse_to_purse_with_result,
+                ..legacy
             },
             rem,
         ))
@@ -699,6 +1063,21 @@ impl Distribution<HostFunctionCosts> for Standard {
             blake2b: rng.gen(),
             random_bytes: rng.gen(),
             enable_contract_version: rng.gen(),
+            create_contract_package_at_seed: rng.gen(),
+            emit_event: rng.gen(),
+            set_contract_package_paused: rng.gen(),
+            get_remaining_call_depth: rng.gen(),
+            sorted_index_insert: rng.gen(),
+            sorted_index_remove: rng.gen(),
+            sorted_index_get: rng.gen(),
+            sorted_index_range: rng.gen(),
+            namespace_put: rng.gen(),
+            namespace_get: rng.gen(),
+            namespace_clear: rng.gen(),
+            new_unique_address: rng.gen(),
+            schedule_callback: rng.gen(),
+            cancel_callback: rng.gen(),
+            transfer_from_purse_to_purse_with_result: rng.gen(),
         }
     }
 }
@@ -760,6 +1139,21 @@ pub mod gens {
             blake2b in host_function_cost_arb(),
             random_bytes in host_function_cost_arb(),
             enable_contract_version in host_function_cost_arb(),
+            create_contract_package_at_seed in host_function_cost_arb(),
+            emit_event in host_function_cost_arb(),
+            set_contract_package_paused in host_function_cost_arb(),
+            get_remaining_call_depth in host_function_cost_arb(),
+            sorted_index_insert in host_function_cost_arb(),
+            sorted_index_remove in host_function_cost_arb(),
+            sorted_index_get in host_function_cost_arb(),
+            sorted_index_range in host_function_cost_arb(),
+            namespace_put in host_function_cost_arb(),
+            namespace_get in host_function_cost_arb(),
+            namespace_clear in host_function_cost_arb(),
+            new_unique_address in host_function_cost_arb(),
+            schedule_callback in host_function_cost_arb(),
+            cancel_callback in host_function_cost_arb(),
+            transfer_from_purse_to_purse_with_result in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -806,6 +1200,21 @@ pub mod gens {
                 blake2b,
                 random_bytes,
                 enable_contract_version,
+                create_contract_package_at_seed,
+                emit_event,
+                set_contract_package_paused,
+                get_remaining_call_depth,
+                sorted_index_insert,
+                sorted_index_remove,
+                sorted_index_get,
+                sorted_index_range,
+                namespace_put,
+                namespace_get,
+                namespace_clear,
+                new_unique_address,
+                schedule_callback,
+                cancel_callback,
+                transfer_from_purse_to_purse_with_result,
             }
         }
     }
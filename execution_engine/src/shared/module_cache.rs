@@ -0,0 +1,288 @@
+//! A cache of preprocessed Wasm modules.
+//!
+//! Session and payment code is commonly sent as the same module bytes by many deploys (e.g. the
+//! stock delegation Wasm), so caching the outcome of [`wasm_prep::preprocess`] saves repeating the
+//! validation, metering and stack height instrumentation for each of them.
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+};
+
+use casper_wasm::elements::Module;
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_hashing::Digest;
+
+use super::{
+    wasm_config::WasmConfig,
+    wasm_prep::{self, PreprocessingError},
+};
+
+/// Default capacity of the module cache in bytes of module bytes.
+pub const DEFAULT_MODULE_CACHE_SIZE: usize = 32 * 1024 * 1024;
+
+/// Statistics describing the use of a [`ModuleCache`].
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, DataSize, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct ModuleCacheStats {
+    /// Number of modules served from the cache.
+    pub hits: u64,
+    /// Number of modules which had to be preprocessed.
+    pub misses: u64,
+    /// Number of cached modules.
+    pub entries: u64,
+    /// Total size in bytes of the cached modules' original bytes.
+    pub size: u64,
+    /// Capacity of the cache in bytes, `0` if caching is disabled.
+    pub capacity: u64,
+}
+
+struct CachedModule {
+    wasm_config: WasmConfig,
+    module: Module,
+    size: usize,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CachedModules {
+    modules: HashMap<Digest, CachedModule>,
+    size: usize,
+    clock: u64,
+}
+
+impl CachedModules {
+    fn remove(&mut self, digest: &Digest) {
+        if let Some(cached_module) = self.modules.remove(digest) {
+            self.size -= cached_module.size;
+        }
+    }
+
+    /// Evicts the least recently used modules until `size` more bytes fit into `capacity`.
+    fn make_room(&mut self, size: usize, capacity: usize) {
+        while self.size + size > capacity {
+            let least_recently_used = match self
+                .modules
+                .iter()
+                .min_by_key(|(_, cached_module)| cached_module.last_used)
+            {
+                Some((digest, _)) => *digest,
+                None => return,
+            };
+            self.remove(&least_recently_used);
+        }
+    }
+}
+
+/// A least recently used cache of preprocessed Wasm modules, keyed by the digest of the module
+/// bytes.
+///
+/// A cached module is only served for the [`WasmConfig`] it was preprocessed with.
+pub struct ModuleCache {
+    capacity: usize,
+    cached_modules: Mutex<CachedModules>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ModuleCache {
+    /// Creates an empty cache holding up to `capacity` bytes of modules.  A `capacity` of `0`
+    /// disables caching.
+    pub fn new(capacity: usize) -> Self {
+        ModuleCache {
+            capacity,
+            cached_modules: Mutex::new(CachedModules::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the preprocessed `module_bytes`, preprocessing them with `wasm_config` unless they
+    /// are cached.
+    pub fn preprocess(
+        &self,
+        wasm_config: WasmConfig,
+        module_bytes: &[u8],
+    ) -> Result<Module, PreprocessingError> {
+        let size = module_bytes.len();
+        if size > self.capacity {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return wasm_prep::preprocess(wasm_config, module_bytes);
+        }
+
+        let digest = Digest::hash(module_bytes);
+        {
+            let mut cached_modules = self.lock();
+            cached_modules.clock += 1;
+            let clock = cached_modules.clock;
+            if let Some(cached_module) = cached_modules.modules.get_mut(&digest) {
+                if cached_module.wasm_config == wasm_config {
+                    cached_module.last_used = clock;
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    return Ok(cached_module.module.clone());
+                }
+            }
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let module = wasm_prep::preprocess(wasm_config, module_bytes)?;
+
+        let mut cached_modules = self.lock();
+        cached_modules.remove(&digest);
+        cached_modules.make_room(size, self.capacity);
+        let last_used = cached_modules.clock;
+        cached_modules.modules.insert(
+            digest,
+            CachedModule {
+                wasm_config,
+                module: module.clone(),
+                size,
+                last_used,
+            },
+        );
+        cached_modules.size += size;
+
+        Ok(module)
+    }
+
+    /// Returns the statistics of the cache.
+    pub fn stats(&self) -> ModuleCacheStats {
+        let cached_modules = self.lock();
+        ModuleCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: cached_modules.modules.len() as u64,
+            size: cached_modules.size as u64,
+            capacity: self.capacity as u64,
+        }
+    }
+
+    /// Drops all cached modules, returning how many there were.  The hit and miss counters are
+    /// kept.
+    pub fn flush(&self) -> usize {
+        let mut cached_modules = self.lock();
+        let entries = cached_modules.modules.len();
+        cached_modules.modules.clear();
+        cached_modules.size = 0;
+        entries
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CachedModules> {
+        // A panic while the lock is held can't leave the cache inconsistent in a way which
+        // matters, as every entry is a complete preprocessing result.
+        self.cached_modules
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Debug for ModuleCache {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ModuleCache")
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::contracts::DEFAULT_ENTRY_POINT_NAME;
+    use walrus::{FunctionBuilder, ModuleConfig};
+
+    use super::*;
+
+    fn module_bytes() -> Vec<u8> {
+        let mut module = walrus::Module::with_config(ModuleConfig::new());
+
+        let memory_id = module.memories.add_local(false, 11, None);
+        module.exports.add("memory", memory_id);
+
+        let call_func = FunctionBuilder::new(&mut module.types, &[], &[]);
+        let call = call_func.finish(Vec::new(), &mut module.funcs);
+        module.exports.add(DEFAULT_ENTRY_POINT_NAME, call);
+
+        module.emit_wasm()
+    }
+
+    #[test]
+    fn should_serve_cached_module() {
+        let module_bytes = module_bytes();
+        let cache = ModuleCache::new(DEFAULT_MODULE_CACHE_SIZE);
+
+        let first = cache
+            .preprocess(WasmConfig::default(), &module_bytes)
+            .expect("should preprocess");
+        let second = cache
+            .preprocess(WasmConfig::default(), &module_bytes)
+            .expect("should preprocess");
+        assert_eq!(first, second);
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.size, module_bytes.len() as u64);
+    }
+
+    #[test]
+    fn should_not_serve_module_preprocessed_with_other_config() {
+        let module_bytes = module_bytes();
+        let cache = ModuleCache::new(DEFAULT_MODULE_CACHE_SIZE);
+        let mut other_wasm_config = WasmConfig::default();
+        other_wasm_config.max_stack_height += 1;
+
+        cache
+            .preprocess(WasmConfig::default(), &module_bytes)
+            .expect("should preprocess");
+        cache
+            .preprocess(other_wasm_config, &module_bytes)
+            .expect("should preprocess");
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.entries, 1);
+    }
+
+    #[test]
+    fn should_not_cache_when_disabled() {
+        let module_bytes = module_bytes();
+        let cache = ModuleCache::new(0);
+
+        for _ in 0..2 {
+            cache
+                .preprocess(WasmConfig::default(), &module_bytes)
+                .expect("should preprocess");
+        }
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.entries, 0);
+    }
+
+    #[test]
+    fn should_flush_cached_modules() {
+        let module_bytes = module_bytes();
+        let cache = ModuleCache::new(DEFAULT_MODULE_CACHE_SIZE);
+        cache
+            .preprocess(WasmConfig::default(), &module_bytes)
+            .expect("should preprocess");
+
+        assert_eq!(cache.flush(), 1);
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 0);
+        assert_eq!(stats.size, 0);
+        assert_eq!(stats.misses, 1);
+    }
+}
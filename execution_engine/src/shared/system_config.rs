@@ -83,6 +83,48 @@ impl SystemConfig {
     pub fn standard_payment_costs(&self) -> &StandardPaymentCosts {
         &self.standard_payment_costs
     }
+
+    /// Serializes the config with the auction and mint costs in their legacy layouts, see
+    /// [`AuctionCosts::to_legacy_bytes`] and [`MintCosts::to_legacy_bytes`].
+    pub fn to_legacy_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(self.legacy_serialized_length());
+
+        ret.append(&mut self.wasmless_transfer_cost.to_bytes()?);
+        ret.append(&mut self.auction_costs.to_legacy_bytes()?);
+        ret.append(&mut self.mint_costs.to_legacy_bytes()?);
+        ret.append(&mut self.handle_payment_costs.to_bytes()?);
+        ret.append(&mut self.standard_payment_costs.to_bytes()?);
+
+        Ok(ret)
+    }
+
+    /// Returns the length of [`SystemConfig::to_legacy_bytes`].
+    pub fn legacy_serialized_length(&self) -> usize {
+        self.wasmless_transfer_cost.serialized_length()
+            + self.auction_costs.legacy_serialized_length()
+            + self.mint_costs.legacy_serialized_length()
+            + self.handle_payment_costs.serialized_length()
+            + self.standard_payment_costs.serialized_length()
+    }
+
+    /// Deserializes a config written by [`SystemConfig::to_legacy_bytes`].
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (wasmless_transfer_cost, rem) = FromBytes::from_bytes(bytes)?;
+        let (auction_costs, rem) = AuctionCosts::from_legacy_bytes(rem)?;
+        let (mint_costs, rem) = MintCosts::from_legacy_bytes(rem)?;
+        let (handle_payment_costs, rem) = FromBytes::from_bytes(rem)?;
+        let (standard_payment_costs, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            SystemConfig::new(
+                wasmless_transfer_cost,
+                auction_costs,
+                mint_costs,
+                handle_payment_costs,
+                standard_payment_costs,
+            ),
+            rem,
+        ))
+    }
 }
 
 impl Default for SystemConfig {
@@ -32,6 +32,12 @@ pub const DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_READ_ERA_ID_COST: u32 = 10_000;
 /// Default cost of the `activate_bid` auction entry point.
 pub const DEFAULT_ACTIVATE_BID_COST: u32 = 10_000;
+/// Default cost of the `change_bid_public_key` auction entry point.
+pub const DEFAULT_CHANGE_BID_PUBLIC_KEY_COST: u32 = 2_500_000_000;
+/// Default cost of the `add_reservations` auction entry point.
+pub const DEFAULT_ADD_RESERVATIONS_COST: u32 = 2_500_000_000;
+/// Default cost of the `cancel_reservations` auction entry point.
+pub const DEFAULT_CANCEL_RESERVATIONS_COST: u32 = 2_500_000_000;
 
 /// Description of the costs of calling auction entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -65,6 +71,12 @@ pub struct AuctionCosts {
     pub activate_bid: u32,
     /// Cost of calling the `redelegate` entry point.
     pub redelegate: u32,
+    /// Cost of calling the `change_bid_public_key` entry point.
+    pub change_bid_public_key: u32,
+    /// Cost of calling the `add_reservations` entry point.
+    pub add_reservations: u32,
+    /// Cost of calling the `cancel_reservations` entry point.
+    pub cancel_reservations: u32,
 }
 
 impl Default for AuctionCosts {
@@ -84,50 +96,25 @@ impl Default for AuctionCosts {
             read_era_id: DEFAULT_READ_ERA_ID_COST,
             activate_bid: DEFAULT_ACTIVATE_BID_COST,
             redelegate: DEFAULT_REDELEGATE_COST,
+            change_bid_public_key: DEFAULT_CHANGE_BID_PUBLIC_KEY_COST,
+            add_reservations: DEFAULT_ADD_RESERVATIONS_COST,
+            cancel_reservations: DEFAULT_CANCEL_RESERVATIONS_COST,
         }
     }
 }
 
-impl ToBytes for AuctionCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-
-        let Self {
-            get_era_validators,
-            read_seigniorage_recipients,
-            add_bid,
-            withdraw_bid,
-            delegate,
-            undelegate,
-            run_auction,
-            slash,
-            distribute,
-            withdraw_delegator_reward,
-            withdraw_validator_reward,
-            read_era_id,
-            activate_bid,
-            redelegate,
-        } = self;
-
-        ret.append(&mut get_era_validators.to_bytes()?);
-        ret.append(&mut read_seigniorage_recipients.to_bytes()?);
-        ret.append(&mut add_bid.to_bytes()?);
-        ret.append(&mut withdraw_bid.to_bytes()?);
-        ret.append(&mut delegate.to_bytes()?);
-        ret.append(&mut undelegate.to_bytes()?);
-        ret.append(&mut run_auction.to_bytes()?);
-        ret.append(&mut slash.to_bytes()?);
-        ret.append(&mut distribute.to_bytes()?);
-        ret.append(&mut withdraw_delegator_reward.to_bytes()?);
-        ret.append(&mut withdraw_validator_reward.to_bytes()?);
-        ret.append(&mut read_era_id.to_bytes()?);
-        ret.append(&mut activate_bid.to_bytes()?);
-        ret.append(&mut redelegate.to_bytes()?);
-
+impl AuctionCosts {
+    /// Serializes the costs in the layout used before the `change_bid_public_key`,
+    /// `add_reservations` and `cancel_reservations` entry points existed, i.e. without their
+    /// costs.
+    pub fn to_legacy_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(self.legacy_serialized_length());
+        self.append_legacy_bytes(&mut ret)?;
         Ok(ret)
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the length of [`AuctionCosts::to_legacy_bytes`].
+    pub fn legacy_serialized_length(&self) -> usize {
         let Self {
             get_era_validators,
             read_seigniorage_recipients,
@@ -143,6 +130,9 @@ impl ToBytes for AuctionCosts {
             read_era_id,
             activate_bid,
             redelegate,
+            change_bid_public_key: _,
+            add_reservations: _,
+            cancel_reservations: _,
         } = self;
 
         get_era_validators.serialized_length()
@@ -160,10 +150,10 @@ impl ToBytes for AuctionCosts {
             + activate_bid.serialized_length()
             + redelegate.serialized_length()
     }
-}
 
-impl FromBytes for AuctionCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+    /// Deserializes costs written by [`AuctionCosts::to_legacy_bytes`], taking the default costs
+    /// for the entry points missing from that layout.
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (get_era_validators, rem) = FromBytes::from_bytes(bytes)?;
         let (read_seigniorage_recipients, rem) = FromBytes::from_bytes(rem)?;
         let (add_bid, rem) = FromBytes::from_bytes(rem)?;
@@ -194,6 +184,85 @@ impl FromBytes for AuctionCosts {
                 read_era_id,
                 activate_bid,
                 redelegate,
+                change_bid_public_key: DEFAULT_CHANGE_BID_PUBLIC_KEY_COST,
+                add_reservations: DEFAULT_ADD_RESERVATIONS_COST,
+                cancel_reservations: DEFAULT_CANCEL_RESERVATIONS_COST,
+            },
+            rem,
+        ))
+    }
+
+    fn append_legacy_bytes(&self, ret: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        let Self {
+            get_era_validators,
+            read_seigniorage_recipients,
+            add_bid,
+            withdraw_bid,
+            delegate,
+            undelegate,
+            run_auction,
+            slash,
+            distribute,
+            withdraw_delegator_reward,
+            withdraw_validator_reward,
+            read_era_id,
+            activate_bid,
+            redelegate,
+            change_bid_public_key: _,
+            add_reservations: _,
+            cancel_reservations: _,
+        } = self;
+
+        ret.append(&mut get_era_validators.to_bytes()?);
+        ret.append(&mut read_seigniorage_recipients.to_bytes()?);
+        ret.append(&mut add_bid.to_bytes()?);
+        ret.append(&mut withdraw_bid.to_bytes()?);
+        ret.append(&mut delegate.to_bytes()?);
+        ret.append(&mut undelegate.to_bytes()?);
+        ret.append(&mut run_auction.to_bytes()?);
+        ret.append(&mut slash.to_bytes()?);
+        ret.append(&mut distribute.to_bytes()?);
+        ret.append(&mut withdraw_delegator_reward.to_bytes()?);
+        ret.append(&mut withdraw_validator_reward.to_bytes()?);
+        ret.append(&mut read_era_id.to_bytes()?);
+        ret.append(&mut activate_bid.to_bytes()?);
+        ret.append(&mut redelegate.to_bytes()?);
+        Ok(())
+    }
+}
+
+impl ToBytes for AuctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        self.append_legacy_bytes(&mut ret)?;
+        ret.append(&mut self.change_bid_public_key.to_bytes()?);
+        ret.append(&mut self.add_reservations.to_bytes()?);
+        ret.append(&mut self.cancel_reservations.to_bytes()?);
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_serialized_length()
+            + self.change_bid_public_key.serialized_length()
+            + self.add_reservations.serialized_length()
+            + self.cancel_reservations.serialized_length()
+    }
+}
+
+impl FromBytes for AuctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (legacy, rem) = Self::from_legacy_bytes(bytes)?;
+        let (change_bid_public_key, rem) = FromBytes::from_bytes(rem)?;
+        let (add_reservations, rem) = FromBytes::from_bytes(rem)?;
+        let (cancel_reservations, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            Self {
+                change_bid_public_key,
+                add_reservations,
+                cancel_reservations,
+                ..legacy
             },
             rem,
         ))
@@ -217,6 +286,9 @@ impl Distribution<AuctionCosts> for Standard {
             read_era_id: rng.gen(),
             activate_bid: rng.gen(),
             redelegate: rng.gen(),
+            change_bid_public_key: rng.gen(),
+            add_reservations: rng.gen(),
+            cancel_reservations: rng.gen(),
         }
     }
 }
@@ -244,6 +316,9 @@ pub mod gens {
             read_era_id in num::u32::ANY,
             activate_bid in num::u32::ANY,
             redelegate in num::u32::ANY,
+            change_bid_public_key in num::u32::ANY,
+            add_reservations in num::u32::ANY,
+            cancel_reservations in num::u32::ANY,
         ) -> AuctionCosts {
             AuctionCosts {
                 get_era_validators,
@@ -260,6 +335,9 @@ pub mod gens {
                 read_era_id,
                 activate_bid,
                 redelegate,
+                change_bid_public_key,
+                add_reservations,
+                cancel_reservations,
             }
         }
     }
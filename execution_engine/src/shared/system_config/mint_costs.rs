@@ -18,6 +18,8 @@ pub const DEFAULT_TRANSFER_COST: u32 = 10_000;
 pub const DEFAULT_READ_BASE_ROUND_REWARD_COST: u32 = 10_000;
 /// Default cost of the `mint_into_existing_purse` mint entry point.
 pub const DEFAULT_MINT_INTO_EXISTING_PURSE_COST: u32 = 2_500_000_000;
+/// Default cost of the `update_administrators` mint entry point.
+pub const DEFAULT_UPDATE_ADMINISTRATORS_COST: u32 = 10_000;
 
 /// Description of the costs of calling mint entry points.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -37,6 +39,8 @@ pub struct MintCosts {
     pub read_base_round_reward: u32,
     /// Cost of calling the `mint_into_existing_purse` entry point.
     pub mint_into_existing_purse: u32,
+    /// Cost of calling the `update_administrators` entry point.
+    pub update_administrators: u32,
 }
 
 impl Default for MintCosts {
@@ -49,36 +53,22 @@ impl Default for MintCosts {
             transfer: DEFAULT_TRANSFER_COST,
             read_base_round_reward: DEFAULT_READ_BASE_ROUND_REWARD_COST,
             mint_into_existing_purse: DEFAULT_MINT_INTO_EXISTING_PURSE_COST,
+            update_administrators: DEFAULT_UPDATE_ADMINISTRATORS_COST,
         }
     }
 }
 
-impl ToBytes for MintCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-
-        let Self {
-            mint,
-            reduce_total_supply,
-            create,
-            balance,
-            transfer,
-            read_base_round_reward,
-            mint_into_existing_purse,
-        } = self;
-
-        ret.append(&mut mint.to_bytes()?);
-        ret.append(&mut reduce_total_supply.to_bytes()?);
-        ret.append(&mut create.to_bytes()?);
-        ret.append(&mut balance.to_bytes()?);
-        ret.append(&mut transfer.to_bytes()?);
-        ret.append(&mut read_base_round_reward.to_bytes()?);
-        ret.append(&mut mint_into_existing_purse.to_bytes()?);
-
+impl MintCosts {
+    /// Serializes the costs in the layout used before the `update_administrators` entry point
+    /// existed, i.e. without its cost.
+    pub fn to_legacy_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(self.legacy_serialized_length());
+        self.append_legacy_bytes(&mut ret)?;
         Ok(ret)
     }
 
-    fn serialized_length(&self) -> usize {
+    /// Returns the length of [`MintCosts::to_legacy_bytes`].
+    pub fn legacy_serialized_length(&self) -> usize {
         let Self {
             mint,
             reduce_total_supply,
@@ -87,6 +77,7 @@ impl ToBytes for MintCosts {
             transfer,
             read_base_round_reward,
             mint_into_existing_purse,
+            update_administrators: _,
         } = self;
 
         mint.serialized_length()
@@ -97,10 +88,10 @@ impl ToBytes for MintCosts {
             + read_base_round_reward.serialized_length()
             + mint_into_existing_purse.serialized_length()
     }
-}
 
-impl FromBytes for MintCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+    /// Deserializes costs written by [`MintCosts::to_legacy_bytes`], taking the default cost of
+    /// `update_administrators`.
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (mint, rem) = FromBytes::from_bytes(bytes)?;
         let (reduce_total_supply, rem) = FromBytes::from_bytes(rem)?;
         let (create, rem) = FromBytes::from_bytes(rem)?;
@@ -118,6 +109,59 @@ impl FromBytes for MintCosts {
                 transfer,
                 read_base_round_reward,
                 mint_into_existing_purse,
+                update_administrators: DEFAULT_UPDATE_ADMINISTRATORS_COST,
+            },
+            rem,
+        ))
+    }
+
+    fn append_legacy_bytes(&self, ret: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        let Self {
+            mint,
+            reduce_total_supply,
+            create,
+            balance,
+            transfer,
+            read_base_round_reward,
+            mint_into_existing_purse,
+            update_administrators: _,
+        } = self;
+
+        ret.append(&mut mint.to_bytes()?);
+        ret.append(&mut reduce_total_supply.to_bytes()?);
+        ret.append(&mut create.to_bytes()?);
+        ret.append(&mut balance.to_bytes()?);
+        ret.append(&mut transfer.to_bytes()?);
+        ret.append(&mut read_base_round_reward.to_bytes()?);
+        ret.append(&mut mint_into_existing_purse.to_bytes()?);
+        Ok(())
+    }
+}
+
+impl ToBytes for MintCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        self.append_legacy_bytes(&mut ret)?;
+        ret.append(&mut self.update_administrators.to_bytes()?);
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.legacy_serialized_length() + self.update_administrators.serialized_length()
+    }
+}
+
+impl FromBytes for MintCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (legacy, rem) = Self::from_legacy_bytes(bytes)?;
+        let (update_administrators, rem) = FromBytes::from_bytes(rem)?;
+
+        Ok((
+            Self {
+                update_administrators,
+                ..legacy
             },
             rem,
         ))
@@ -134,6 +178,7 @@ impl Distribution<MintCosts> for Standard {
             transfer: rng.gen(),
             read_base_round_reward: rng.gen(),
             mint_into_existing_purse: rng.gen(),
+            update_administrators: rng.gen(),
         }
     }
 }
@@ -154,6 +199,7 @@ pub mod gens {
             transfer in num::u32::ANY,
             read_base_round_reward in num::u32::ANY,
             mint_into_existing_purse in num::u32::ANY,
+            update_administrators in num::u32::ANY,
         ) -> MintCosts {
             MintCosts {
                 mint,
@@ -163,6 +209,7 @@ pub mod gens {
                 transfer,
                 read_base_round_reward,
                 mint_into_existing_purse,
+                update_administrators,
             }
         }
     }
@@ -11,7 +11,7 @@ use datasize::DataSize;
 use num::traits::{AsPrimitive, WrappingAdd};
 
 use casper_types::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::NamedKeys,
     CLType, CLTyped, CLValue, CLValueError, StoredValue, StoredValueTypeMismatch, U128, U256, U512,
 };
@@ -236,6 +236,22 @@ impl Transform {
             Transform::Failure(error) => Err(error),
         }
     }
+
+    /// Returns the size in bytes of the transformation once serialized, i.e. of a tag along with
+    /// the value it writes or adds.
+    pub fn serialized_size(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                Transform::Identity | Transform::Failure(_) => 0,
+                Transform::Write(stored_value) => stored_value.serialized_length(),
+                Transform::AddInt32(value) => value.serialized_length(),
+                Transform::AddUInt64(value) => value.serialized_length(),
+                Transform::AddUInt128(value) => value.serialized_length(),
+                Transform::AddUInt256(value) => value.serialized_length(),
+                Transform::AddUInt512(value) => value.serialized_length(),
+                Transform::AddKeys(named_keys) => named_keys.serialized_length(),
+            }
+    }
 }
 
 /// Combines numeric `Transform`s into a single `Transform`. This is done by unwrapping the
@@ -863,4 +879,15 @@ mod tests {
         assert_eq!(ZERO_U512, add(MAX_U512, ONE_U512));
         assert_eq!(MAX_U512 - 1, add(MAX_U512, MAX_U512));
     }
+
+    #[test]
+    fn serialized_size_should_count_tag_and_value() {
+        let value = StoredValue::CLValue(CLValue::from_t(ONE_U512).unwrap());
+        assert_eq!(
+            Transform::Write(value.clone()).serialized_size(),
+            1 + value.serialized_length()
+        );
+        assert_eq!(Transform::AddUInt64(ONE_U64).serialized_size(), 9);
+        assert_eq!(Transform::Identity.serialized_size(), 1);
+    }
 }
@@ -65,6 +65,49 @@ impl WasmConfig {
     pub fn take_host_function_costs(self) -> HostFunctionCosts {
         self.host_function_costs
     }
+
+    /// Serializes the config with the host function costs in their legacy layout, see
+    /// [`HostFunctionCosts::to_legacy_bytes`].
+    pub fn to_legacy_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = Vec::with_capacity(self.legacy_serialized_length());
+
+        ret.append(&mut self.max_memory.to_bytes()?);
+        ret.append(&mut self.max_stack_height.to_bytes()?);
+        ret.append(&mut self.opcode_costs.to_bytes()?);
+        ret.append(&mut self.storage_costs.to_bytes()?);
+        ret.append(&mut self.host_function_costs.to_legacy_bytes()?);
+
+        Ok(ret)
+    }
+
+    /// Returns the length of [`WasmConfig::to_legacy_bytes`].
+    pub fn legacy_serialized_length(&self) -> usize {
+        self.max_memory.serialized_length()
+            + self.max_stack_height.serialized_length()
+            + self.opcode_costs.serialized_length()
+            + self.storage_costs.serialized_length()
+            + self.host_function_costs.legacy_serialized_length()
+    }
+
+    /// Deserializes a config written by [`WasmConfig::to_legacy_bytes`].
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (max_memory, rem) = FromBytes::from_bytes(bytes)?;
+        let (max_stack_height, rem) = FromBytes::from_bytes(rem)?;
+        let (opcode_costs, rem) = FromBytes::from_bytes(rem)?;
+        let (storage_costs, rem) = FromBytes::from_bytes(rem)?;
+        let (host_function_costs, rem) = HostFunctionCosts::from_legacy_bytes(rem)?;
+
+        Ok((
+            WasmConfig {
+                max_memory,
+                max_stack_height,
+                opcode_costs,
+                storage_costs,
+                host_function_costs,
+            },
+            rem,
+        ))
+    }
 }
 
 impl Default for WasmConfig {
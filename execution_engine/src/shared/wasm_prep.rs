@@ -2,13 +2,20 @@
 use casper_wasm::elements::{
     self, External, Instruction, Internal, MemorySection, Module, Section, TableType, Type,
 };
-use casper_wasm_utils::{self, stack_height};
+use casper_wasm_utils::{self, rules::Rules, stack_height};
 use thiserror::Error;
 
-use super::wasm_config::WasmConfig;
-use crate::core::execution;
+use casper_types::contracts::DEFAULT_ENTRY_POINT_NAME;
+
+use super::{
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    wasm_config::WasmConfig,
+};
+use crate::core::{execution, resolvers};
 
 const DEFAULT_GAS_MODULE_NAME: &str = "env";
+/// Name of the only memory a Wasm module may import from the host.
+const IMPORTED_MEMORY_NAME: &str = "memory";
 /// Name of the internal gas function injected by [`casper_wasm_utils::inject_gas_counter`].
 const INTERNAL_GAS_FUNCTION_NAME: &str = "gas";
 
@@ -117,6 +124,52 @@ pub enum PreprocessingError {
     WasmValidation(#[from] WasmValidationError),
 }
 
+/// A problem found in a Wasm module by [`diagnose`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WasmDiagnostic {
+    /// The module does not export a function the host expects to call.
+    MissingExport {
+        /// Name of the missing export.
+        name: String,
+    },
+    /// The module imports something the host does not provide.
+    ForbiddenImport {
+        /// Module name of the import.
+        module: String,
+        /// Field name of the import.
+        field: String,
+    },
+    /// The module declares a start function, which the host refuses to run.
+    StartSectionPresent,
+    /// The module neither declares nor imports a memory.
+    MissingMemorySection,
+    /// The module's memory exceeds the allowed number of pages.
+    MemoryLimitExceeded {
+        /// Allowed maximum number of pages.
+        max: u32,
+        /// Number of pages requested by the module.
+        actual: u32,
+    },
+    /// The module uses floating point opcodes, which are forbidden by gas rules.
+    FloatOpcodesUsed {
+        /// Number of floating point instructions found in the code.
+        count: usize,
+    },
+    /// The module fails one of the structural checks applied during preprocessing.
+    Validation(WasmValidationError),
+}
+
+/// A host function imported by a Wasm module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostFunctionImport {
+    /// Name under which the function is imported, e.g. `casper_transfer_to_account`.
+    pub name: String,
+    /// Cost charged for calling the function, or `None` if calls to it are not charged or the
+    /// host doesn't export it.
+    pub cost: Option<HostFunctionCost>,
+}
+
 impl From<elements::Error> for PreprocessingError {
     fn from(error: elements::Error) -> Self {
         PreprocessingError::Deserialize(error.to_string())
@@ -403,6 +456,147 @@ pub fn preprocess(
     Ok(module)
 }
 
+/// Inspects Wasm bytes and reports every problem that would make [`preprocess`] or the host
+/// reject the module.
+///
+/// Unlike [`preprocess`] this does not stop at the first problem, and it does not instrument the
+/// module.  An empty result means no problems were found.  An error is returned only if the bytes
+/// can't be deserialized at all.
+pub fn diagnose(
+    wasm_config: WasmConfig,
+    module_bytes: &[u8],
+) -> Result<Vec<WasmDiagnostic>, PreprocessingError> {
+    let module = deserialize(module_bytes)?;
+    let mut diagnostics = Vec::new();
+
+    let has_default_export = module.export_section().map_or(false, |export_section| {
+        export_section
+            .entries()
+            .iter()
+            .any(|export_entry| export_entry.field() == DEFAULT_ENTRY_POINT_NAME)
+    });
+    if !has_default_export {
+        diagnostics.push(WasmDiagnostic::MissingExport {
+            name: DEFAULT_ENTRY_POINT_NAME.to_string(),
+        });
+    }
+
+    let mut memory_pages = memory_section(&module)
+        .and_then(|memory_section| memory_section.entries().first())
+        .map(|memory_type| memory_type.limits());
+    for import in module
+        .import_section()
+        .map(|import_section| import_section.entries())
+        .unwrap_or(&[])
+    {
+        let allowed = import.module() == DEFAULT_GAS_MODULE_NAME
+            && match import.external() {
+                External::Function(_) => {
+                    import.field() != INTERNAL_GAS_FUNCTION_NAME
+                        && resolvers::is_host_function(import.field())
+                }
+                External::Memory(memory_type) if import.field() == IMPORTED_MEMORY_NAME => {
+                    memory_pages = Some(memory_type.limits());
+                    true
+                }
+                External::Memory(_) | External::Table(_) | External::Global(_) => false,
+            };
+        if !allowed {
+            diagnostics.push(WasmDiagnostic::ForbiddenImport {
+                module: import.module().to_string(),
+                field: import.field().to_string(),
+            });
+        }
+    }
+
+    if module.start_section().is_some() {
+        diagnostics.push(WasmDiagnostic::StartSectionPresent);
+    }
+
+    match memory_pages {
+        None => diagnostics.push(WasmDiagnostic::MissingMemorySection),
+        Some(limits) => {
+            let actual = limits.maximum().unwrap_or_default().max(limits.initial());
+            if actual > wasm_config.max_memory {
+                diagnostics.push(WasmDiagnostic::MemoryLimitExceeded {
+                    max: wasm_config.max_memory,
+                    actual,
+                });
+            }
+        }
+    }
+
+    let opcode_costs = wasm_config.opcode_costs();
+    let float_opcodes = module
+        .code_section()
+        .map(|code_section| {
+            code_section
+                .bodies()
+                .iter()
+                .flat_map(|body| body.code().elements())
+                .filter(|instr| {
+                    // `br_table` is only forbidden when its size overflows the cost computation,
+                    // which is reported by the validation checks below.
+                    !matches!(instr, Instruction::BrTable(_))
+                        && opcode_costs.instruction_cost(instr).is_none()
+                })
+                .count()
+        })
+        .unwrap_or_default();
+    if float_opcodes > 0 {
+        diagnostics.push(WasmDiagnostic::FloatOpcodesUsed {
+            count: float_opcodes,
+        });
+    }
+
+    let validation_results = [
+        ensure_valid_access(&module),
+        ensure_table_size_limit(module.clone(), DEFAULT_MAX_TABLE_SIZE).map(drop),
+        ensure_br_table_size_limit(&module, DEFAULT_BR_TABLE_MAX_SIZE),
+        ensure_global_variable_limit(&module, DEFAULT_MAX_GLOBALS),
+        ensure_parameter_limit(&module, DEFAULT_MAX_PARAMETER_COUNT),
+    ];
+    diagnostics.extend(
+        validation_results
+            .into_iter()
+            .filter_map(Result::err)
+            .map(WasmDiagnostic::Validation),
+    );
+
+    Ok(diagnostics)
+}
+
+/// Returns the host functions imported by the Wasm bytes, annotated with the cost of calling each
+/// of them under the given cost table.
+///
+/// Each function is listed once, in the order in which it is first imported.
+pub fn wasm_imports(
+    host_function_costs: &HostFunctionCosts,
+    module_bytes: &[u8],
+) -> Result<Vec<HostFunctionImport>, PreprocessingError> {
+    let module = deserialize(module_bytes)?;
+    let mut imports: Vec<HostFunctionImport> = Vec::new();
+    for import in module
+        .import_section()
+        .map(|import_section| import_section.entries())
+        .unwrap_or(&[])
+    {
+        if import.module() != DEFAULT_GAS_MODULE_NAME
+            || !matches!(import.external(), External::Function(_))
+            || imports
+                .iter()
+                .any(|existing| existing.name == import.field())
+        {
+            continue;
+        }
+        imports.push(HostFunctionImport {
+            name: import.field().to_string(),
+            cost: host_function_costs.cost_of(import.field()),
+        });
+    }
+    Ok(imports)
+}
+
 /// Returns a parity Module from the given bytes without making modifications or checking limits.
 pub fn deserialize(module_bytes: &[u8]) -> Result<Module, PreprocessingError> {
     casper_wasm::deserialize_buffer::<Module>(module_bytes).map_err(Into::into)
@@ -651,4 +845,119 @@ mod tests {
             error,
         );
     }
+
+    #[test]
+    fn should_diagnose_valid_module_without_problems() {
+        let module_bytes = {
+            let mut module = walrus::Module::with_config(ModuleConfig::new());
+
+            let memory_id = module.memories.add_local(false, 11, None);
+            module.exports.add("memory", memory_id);
+
+            let call_func = FunctionBuilder::new(&mut module.types, &[], &[]);
+            let call = call_func.finish(Vec::new(), &mut module.funcs);
+            module.exports.add(DEFAULT_ENTRY_POINT_NAME, call);
+
+            module.emit_wasm()
+        };
+        let diagnostics =
+            diagnose(WasmConfig::default(), &module_bytes).expect("should deserialize");
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    }
+
+    #[test]
+    fn should_diagnose_every_problem() {
+        let wasm_config = WasmConfig::default();
+        let module_bytes = {
+            let mut module = walrus::Module::with_config(ModuleConfig::new());
+
+            let memory_id = module
+                .memories
+                .add_local(false, wasm_config.max_memory + 1, None);
+            module.exports.add("memory", memory_id);
+
+            let import_type = module.types.add(&[], &[]);
+            let (imported_func, _import_id) =
+                module.add_import_func("env", "casper_nonexistent", import_type);
+
+            let mut float_func = FunctionBuilder::new(&mut module.types, &[], &[]);
+            float_func
+                .func_body()
+                .call(imported_func)
+                .f64_const(1.0)
+                .drop();
+            let float_func = float_func.finish(Vec::new(), &mut module.funcs);
+            module.start = Some(float_func);
+
+            module.emit_wasm()
+        };
+        let diagnostics = diagnose(wasm_config, &module_bytes).expect("should deserialize");
+
+        assert!(diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            WasmDiagnostic::MissingExport { name } if name == DEFAULT_ENTRY_POINT_NAME
+        )));
+        assert!(diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            WasmDiagnostic::ForbiddenImport { module, field }
+            if module == "env" && field == "casper_nonexistent"
+        )));
+        assert!(diagnostics
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, WasmDiagnostic::StartSectionPresent)));
+        assert!(diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            WasmDiagnostic::MemoryLimitExceeded { max, actual }
+            if *max == wasm_config.max_memory && *actual == wasm_config.max_memory + 1
+        )));
+        assert!(diagnostics
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, WasmDiagnostic::FloatOpcodesUsed { count: 1 })));
+    }
+
+    #[test]
+    fn should_list_host_function_imports_with_costs() {
+        let host_function_costs = HostFunctionCosts::default();
+        let module_bytes = {
+            let mut module = walrus::Module::with_config(ModuleConfig::new());
+
+            let memory_id = module.memories.add_local(false, 11, None);
+            module.exports.add("memory", memory_id);
+
+            let transfer_type = module.types.add(&[ValType::I32; 7], &[ValType::I32]);
+            let (transfer, _import_id) =
+                module.add_import_func("env", "casper_transfer_to_account", transfer_type);
+            let revert_type = module.types.add(&[ValType::I32], &[]);
+            let (revert, _import_id) = module.add_import_func("env", "casper_revert", revert_type);
+
+            // Imports are only emitted if they are called.
+            let mut call_func = FunctionBuilder::new(&mut module.types, &[], &[]);
+            let mut body = call_func.func_body();
+            for _ in 0..7 {
+                body.i32_const(0);
+            }
+            body.call(transfer).call(revert);
+            let call = call_func.finish(Vec::new(), &mut module.funcs);
+            module.exports.add(DEFAULT_ENTRY_POINT_NAME, call);
+
+            module.emit_wasm()
+        };
+        let imports = wasm_imports(&host_function_costs, &module_bytes).expect("should list");
+
+        assert_eq!(
+            imports,
+            vec![
+                HostFunctionImport {
+                    name: "casper_transfer_to_account".to_string(),
+                    cost: Some(HostFunctionCost::from(
+                        &host_function_costs.transfer_to_account
+                    )),
+                },
+                HostFunctionImport {
+                    name: "casper_revert".to_string(),
+                    cost: Some(HostFunctionCost::from(&host_function_costs.revert)),
+                },
+            ]
+        );
+    }
 }
@@ -11,7 +11,7 @@ pub mod trie;
 /// Merkle Trie storage.
 pub mod trie_store;
 
-const MAX_DBS: u32 = 2;
+const MAX_DBS: u32 = 3;
 
 #[cfg(test)]
 pub(crate) const DEFAULT_TEST_MAX_DB_SIZE: usize = 52_428_800; // 50 MiB
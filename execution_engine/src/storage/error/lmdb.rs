@@ -26,6 +26,10 @@ pub enum Error {
     /// Error committing to execution engine.
     #[error(transparent)]
     CommitError(#[from] CommitError),
+
+    /// Error compressing or decompressing a stored record.
+    #[error("Compression error: {0}")]
+    Compression(String),
 }
 
 impl casper_wasmi::HostError for Error {}
@@ -21,8 +21,8 @@ use crate::{
         trie_store::{
             in_memory::InMemoryTrieStore,
             operations::{
-                self, delete, keys_with_prefix, missing_children, put_trie, read, read_with_proof,
-                DeleteResult, ReadResult, WriteResult,
+                self, delete, keys_with_prefix, keys_with_prefix_after, missing_children, put_trie,
+                read, read_with_proof, DeleteResult, ReadResult, WriteResult,
             },
         },
     },
@@ -197,6 +197,33 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: &[u8],
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let keys_iter = keys_with_prefix_after::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+            start_after,
+        );
+        let mut ret = Vec::new();
+        for result in keys_iter.take(limit) {
+            match result {
+                Ok(key) => ret.push(key),
+                Err(error) => return Err(error.into()),
+            }
+        }
+        txn.commit()?;
+        Ok(ret)
+    }
 }
 
 impl CommitProvider for InMemoryGlobalState {
@@ -1,4 +1,13 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    ops::Deref,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use lmdb::{Cursor, DatabaseFlags};
+use tracing::info;
 
 use casper_hashing::Digest;
 use casper_types::{Key, StoredValue};
@@ -8,24 +17,33 @@ use crate::{
     storage::{
         error,
         global_state::{
-            commit, put_stored_values, scratch::ScratchGlobalState, CommitProvider, StateProvider,
-            StateReader,
+            commit, put_stored_values, scratch::ScratchGlobalState, CommitError, CommitProvider,
+            StateProvider, StateReader,
         },
         store::Store,
         transaction_source::{lmdb::LmdbEnvironment, Transaction, TransactionSource},
         trie::{
-            merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie, TrieRaw,
+            self, merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie,
+            TrieRaw,
         },
         trie_store::{
+            liveness::{LivenessStats, LivenessViolation, TrieLiveness},
             lmdb::{LmdbTrieStore, ScratchTrieStore},
             operations::{
-                delete, keys_with_prefix, missing_children, put_trie, read, read_with_proof,
-                DeleteResult, ReadResult,
+                delete, keys_with_prefix, keys_with_prefix_after, mark_reachable_tries,
+                missing_children, put_trie, read, read_with_proof, DeleteResult, ReadResult,
+                PROGRESS_REPORT_INTERVAL,
             },
         },
     },
 };
 
+/// Name of the database holding the tries marked as reachable during garbage collection.
+const GC_MARKS_DB_NAME: &str = "GC_MARKS";
+
+/// Maximum number of unreachable tries collected before they are deleted during a sweep.
+const SWEEP_BATCH_SIZE: usize = 10_000;
+
 /// Global state implemented against LMDB as a backing data store.
 pub struct LmdbGlobalState {
     /// Environment for LMDB.
@@ -35,6 +53,34 @@ pub struct LmdbGlobalState {
     // TODO: make this a lazy-static
     /// Empty root hash used for a new trie.
     pub(crate) empty_root_hash: Digest,
+    /// Reference counts of the tries reachable from tracked roots, if tracking is enabled.
+    pub(crate) liveness: Mutex<Option<TrieLiveness>>,
+}
+
+/// Progress of a garbage collection run, as reported by [`LmdbGlobalState::collect_garbage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GarbageCollectionProgress {
+    /// Tries reachable from the retained roots are being marked.
+    Marking {
+        /// Number of tries marked as reachable so far.
+        marked: usize,
+    },
+    /// Tries which are not reachable from the retained roots are being removed.
+    Sweeping {
+        /// Number of tries examined so far.
+        examined: usize,
+        /// Number of tries removed so far.
+        removed: usize,
+    },
+}
+
+/// Summary of a completed garbage collection run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GarbageCollectionStats {
+    /// Number of tries left in the store.
+    pub retained_tries: usize,
+    /// Number of tries removed from the store.
+    pub removed_tries: usize,
 }
 
 /// Represents a "view" of global state at a particular root hash.
@@ -75,6 +121,7 @@ impl LmdbGlobalState {
             environment,
             trie_store,
             empty_root_hash,
+            liveness: Mutex::new(None),
         }
     }
 
@@ -103,6 +150,7 @@ impl LmdbGlobalState {
             stored_values,
         )?;
         scratch_trie.write_root_to_db(new_state_root)?;
+        self.track_root(new_state_root)?;
         Ok(new_state_root)
     }
 
@@ -111,6 +159,286 @@ impl LmdbGlobalState {
         ScratchTrieStore::new(Arc::clone(&self.trie_store), Arc::clone(&self.environment))
     }
 
+    /// Removes every trie which is not reachable from any of the `retained_roots`.
+    ///
+    /// This is a mark-and-sweep collection run in a single read-write transaction: all tries
+    /// reachable from the retained roots (and the empty root) are first marked in a dedicated
+    /// database, then every unmarked trie is deleted from the store, a batch at a time.  Any root
+    /// not in `retained_roots` will no longer be checkout-able once this returns successfully.
+    pub fn collect_garbage<F>(
+        &self,
+        correlation_id: CorrelationId,
+        retained_roots: &[Digest],
+        mut on_progress: F,
+    ) -> Result<GarbageCollectionStats, error::Error>
+    where
+        F: FnMut(GarbageCollectionProgress),
+    {
+        let mut roots = retained_roots.to_vec();
+        roots.push(self.empty_root_hash);
+
+        let marks = self
+            .environment
+            .env()
+            .create_db(Some(GC_MARKS_DB_NAME), DatabaseFlags::empty())?;
+        let mut txn = self.environment.create_read_write_txn()?;
+        txn.clear_db(marks)?;
+
+        mark_reachable_tries::<Key, StoredValue, _, _, error::Error, _>(
+            correlation_id,
+            &mut txn,
+            &*self.trie_store,
+            marks,
+            &roots,
+            |marked| on_progress(GarbageCollectionProgress::Marking { marked }),
+        )?;
+
+        let db = self.trie_store.get_db();
+        let mut examined = 0;
+        let mut removed = 0;
+        let mut resume_after: Option<Vec<u8>> = None;
+        loop {
+            let mut unreachable = Vec::new();
+            let mut exhausted = true;
+            {
+                let mut cursor = lmdb::Transaction::open_ro_cursor(&txn, db)?;
+                let entries = match &resume_after {
+                    Some(trie_key) => cursor.iter_from(trie_key),
+                    None => cursor.iter_start(),
+                };
+                for entry in entries {
+                    let (trie_key, _trie_value) = entry?;
+                    if resume_after.as_deref() == Some(trie_key) {
+                        continue;
+                    }
+                    examined += 1;
+                    let is_reachable = match lmdb::Transaction::get(&txn, marks, &trie_key) {
+                        Ok(_) => true,
+                        // Anything which is not a trie key is left untouched.
+                        Err(lmdb::Error::NotFound) => Digest::try_from(trie_key).is_err(),
+                        Err(error) => return Err(error.into()),
+                    };
+                    if !is_reachable {
+                        unreachable.push(trie_key.to_vec());
+                    }
+                    if examined % PROGRESS_REPORT_INTERVAL == 0 {
+                        on_progress(GarbageCollectionProgress::Sweeping { examined, removed });
+                    }
+                    if unreachable.len() == SWEEP_BATCH_SIZE {
+                        resume_after = Some(trie_key.to_vec());
+                        exhausted = false;
+                        break;
+                    }
+                }
+            }
+            for trie_key in &unreachable {
+                txn.del(db, trie_key, None)?;
+            }
+            removed += unreachable.len();
+            if exhausted {
+                break;
+            }
+        }
+        txn.clear_db(marks)?;
+        txn.commit()?;
+
+        let is_tracking_liveness = self.liveness.lock()?.is_some();
+        if is_tracking_liveness {
+            self.enable_liveness_tracking(retained_roots)?;
+        }
+
+        let stats = GarbageCollectionStats {
+            retained_tries: examined - removed,
+            removed_tries: removed,
+        };
+        on_progress(GarbageCollectionProgress::Sweeping {
+            examined,
+            removed: stats.removed_tries,
+        });
+        info!(
+            retained_roots = retained_roots.len(),
+            retained_tries = stats.retained_tries,
+            removed_tries = stats.removed_tries,
+            "global state garbage collection finished"
+        );
+        Ok(stats)
+    }
+
+    /// Passes every trie reachable from `state_root_hash` to `on_trie`, with the children of a trie
+    /// always passed before the trie itself, so they can be put into another store in the same
+    /// order with all children present.
+    ///
+    /// Returns `false` without calling `on_trie` if `state_root_hash` is not in the store.
+    pub fn export_tries<F, E>(
+        &self,
+        _correlation_id: CorrelationId,
+        state_root_hash: Digest,
+        mut on_trie: F,
+    ) -> Result<bool, E>
+    where
+        F: FnMut(TrieRaw) -> Result<(), E>,
+        E: From<error::Error>,
+    {
+        let txn = self
+            .environment
+            .create_read_txn()
+            .map_err(error::Error::from)?;
+        let get_raw = |trie_hash: &Digest| {
+            Store::<Digest, Trie<Key, StoredValue>>::get_raw(&*self.trie_store, &txn, trie_hash)
+        };
+
+        let root_bytes = match get_raw(&state_root_hash)? {
+            Some(root_bytes) => root_bytes,
+            None => return Ok(false),
+        };
+
+        let mut exported = HashSet::new();
+        // Each trie is pushed twice: first to push its children above it, then, once they are all
+        // exported, to be exported itself.
+        let mut tries_to_visit = vec![(state_root_hash, root_bytes, false)];
+        while let Some((trie_hash, trie_bytes, children_pushed)) = tries_to_visit.pop() {
+            if exported.contains(&trie_hash) {
+                continue;
+            }
+            if children_pushed {
+                exported.insert(trie_hash);
+                on_trie(TrieRaw::new(trie_bytes))?;
+                continue;
+            }
+            let lazy_trie: trie::LazyTrieLeaf<Key, StoredValue> =
+                trie::lazy_trie_deserialize(trie_bytes.clone()).map_err(error::Error::from)?;
+            let children: Vec<Digest> = trie::lazy_trie_iter_children(&lazy_trie)
+                .filter(|child| !exported.contains(child))
+                .collect();
+            tries_to_visit.push((trie_hash, trie_bytes, true));
+            for child in children {
+                let child_bytes = get_raw(&child)?
+                    .ok_or(error::Error::CommitError(CommitError::TrieNotFound(child)))?;
+                tries_to_visit.push((child, child_bytes, false));
+            }
+        }
+        txn.commit().map_err(error::Error::from)?;
+        Ok(true)
+    }
+
+    /// Enables reference counting of tries, starting with `roots` as the tracked state roots.
+    ///
+    /// Once enabled, every root produced by a commit or a prune is tracked as well, and tries can
+    /// be reclaimed incrementally through [`LmdbGlobalState::release_root`].  Every other trie
+    /// already in the store, and every trie put into it later through synchronization, may be
+    /// referenced by a root the tracker doesn't know about, so it is pinned and never reclaimed.
+    /// Enabling tracking again discards the previous reference counts.
+    pub fn enable_liveness_tracking(&self, roots: &[Digest]) -> Result<(), error::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let mut liveness = TrieLiveness::new();
+        for root in roots {
+            liveness.track_root::<Key, StoredValue, _, _, error::Error>(
+                &txn,
+                &*self.trie_store,
+                *root,
+            )?;
+        }
+        {
+            let mut cursor = lmdb::Transaction::open_ro_cursor(&txn, self.trie_store.get_db())?;
+            for entry in cursor.iter_start() {
+                let (trie_key, _trie_value) = entry?;
+                let trie_key = match Digest::try_from(trie_key) {
+                    Ok(trie_key) => trie_key,
+                    Err(_) => continue,
+                };
+                if !liveness.is_live(&trie_key) {
+                    liveness.pin::<Key, StoredValue, _, _, error::Error>(
+                        &txn,
+                        &*self.trie_store,
+                        trie_key,
+                    )?;
+                }
+            }
+        }
+        txn.commit()?;
+        *self.liveness.lock()? = Some(liveness);
+        Ok(())
+    }
+
+    /// Returns the liveness statistics, or `None` if tracking is not enabled.
+    pub fn liveness_stats(&self) -> Result<Option<LivenessStats>, error::Error> {
+        Ok(self.liveness.lock()?.as_ref().map(TrieLiveness::stats))
+    }
+
+    /// Verifies the tracked reference counts against counts recomputed from the tracked roots.
+    ///
+    /// Returns `None` if tracking is not enabled.
+    pub fn check_liveness_invariants(
+        &self,
+    ) -> Result<Option<Vec<LivenessViolation>>, error::Error> {
+        let maybe_liveness = self.liveness.lock()?;
+        let liveness = match maybe_liveness.as_ref() {
+            Some(liveness) => liveness,
+            None => return Ok(None),
+        };
+        let txn = self.environment.create_read_txn()?;
+        let violations = liveness
+            .check_invariants::<Key, StoredValue, _, _, error::Error>(&txn, &*self.trie_store)?;
+        txn.commit()?;
+        Ok(Some(violations))
+    }
+
+    /// Stops tracking `root` and removes every trie which became unreachable from the tracked
+    /// roots and the pinned tries as a result.
+    ///
+    /// Returns the number of removed tries, or `None` if tracking is not enabled or `root` is not
+    /// tracked.
+    pub fn release_root(&self, root: Digest) -> Result<Option<usize>, error::Error> {
+        let mut maybe_liveness = self.liveness.lock()?;
+        let liveness = match maybe_liveness.as_mut() {
+            Some(liveness) => liveness,
+            None => return Ok(None),
+        };
+        let mut txn = self.environment.create_read_write_txn()?;
+        let freed = match liveness.release_root::<Key, StoredValue, _, _, error::Error>(
+            &txn,
+            &*self.trie_store,
+            root,
+        )? {
+            Some(freed) => freed,
+            None => return Ok(None),
+        };
+        let db = self.trie_store.get_db();
+        for trie_key in &freed {
+            txn.del(db, trie_key, None)?;
+        }
+        txn.commit()?;
+        Ok(Some(freed.len()))
+    }
+
+    /// Tracks `root` if liveness tracking is enabled.
+    fn track_root(&self, root: Digest) -> Result<(), error::Error> {
+        if let Some(liveness) = self.liveness.lock()?.as_mut() {
+            let txn = self.environment.create_read_txn()?;
+            liveness.track_root::<Key, StoredValue, _, _, error::Error>(
+                &txn,
+                &*self.trie_store,
+                root,
+            )?;
+            txn.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Pins `trie_key` if liveness tracking is enabled.
+    fn pin_trie(&self, trie_key: Digest) -> Result<(), error::Error> {
+        if let Some(liveness) = self.liveness.lock()?.as_mut() {
+            let txn = self.environment.create_read_txn()?;
+            liveness.pin::<Key, StoredValue, _, _, error::Error>(
+                &txn,
+                &*self.trie_store,
+                trie_key,
+            )?;
+            txn.commit()?;
+        }
+        Ok(())
+    }
+
     /// Get a reference to the lmdb global state's environment.
     #[must_use]
     pub fn environment(&self) -> &LmdbEnvironment {
@@ -163,6 +491,7 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        let start = Instant::now();
         let txn = self.environment.create_read_txn()?;
         let ret = match read_with_proof::<
             Key,
@@ -182,6 +511,9 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
             ReadResult::RootNotFound => panic!("LmdbGlobalState has invalid root"),
         };
         txn.commit()?;
+        self.store
+            .metrics_sink()
+            .record_proof_generation(start.elapsed());
         Ok(ret)
     }
 
@@ -208,6 +540,33 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: &[u8],
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let keys_iter = keys_with_prefix_after::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+            start_after,
+        );
+        let mut ret = Vec::new();
+        for result in keys_iter.take(limit) {
+            match result {
+                Ok(key) => ret.push(key),
+                Err(error) => return Err(error),
+            }
+        }
+        txn.commit()?;
+        Ok(ret)
+    }
 }
 
 impl CommitProvider for LmdbGlobalState {
@@ -217,14 +576,15 @@ impl CommitProvider for LmdbGlobalState {
         prestate_hash: Digest,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<Digest, Self::Error> {
-        commit::<LmdbEnvironment, LmdbTrieStore, _, Self::Error>(
+        let post_state_hash = commit::<LmdbEnvironment, LmdbTrieStore, _, Self::Error>(
             &self.environment,
             &self.trie_store,
             correlation_id,
             prestate_hash,
             effects,
-        )
-        .map_err(Into::into)
+        )?;
+        self.track_root(post_state_hash)?;
+        Ok(post_state_hash)
     }
 }
 
@@ -272,6 +632,7 @@ impl StateProvider for LmdbGlobalState {
             Self::Error,
         >(correlation_id, &mut txn, &self.trie_store, trie)?;
         txn.commit()?;
+        self.pin_trie(trie_hash)?;
         Ok(trie_hash)
     }
 
@@ -300,6 +661,7 @@ impl StateProvider for LmdbGlobalState {
         mut state_root_hash: Digest,
         keys: &[Key],
     ) -> Result<DeleteResult, Self::Error> {
+        let start = Instant::now();
         let scratch_trie_store = self.get_scratch_store();
 
         let mut txn = scratch_trie_store.create_read_write_txn()?;
@@ -323,21 +685,31 @@ impl StateProvider for LmdbGlobalState {
         txn.commit()?;
 
         scratch_trie_store.write_root_to_db(state_root_hash)?;
+        self.track_root(state_root_hash)?;
+        self.trie_store.metrics_sink().record_prune(start.elapsed());
         Ok(DeleteResult::Deleted(state_root_hash))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
     use lmdb::DatabaseFlags;
     use tempfile::tempdir;
 
     use casper_hashing::Digest;
-    use casper_types::{account::AccountHash, CLValue};
+    use casper_types::{account::AccountHash, bytesrepr::ToBytes, CLValue, ContractWasm};
 
     use super::*;
     use crate::storage::{
-        trie_store::operations::{write, WriteResult},
+        trie_store::{
+            metrics::TrieMetricsSink,
+            operations::{write, WriteResult},
+        },
         DEFAULT_TEST_MAX_DB_SIZE, DEFAULT_TEST_MAX_READERS,
     };
 
@@ -505,4 +877,306 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn collect_garbage_removes_unreachable_roots() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state(create_test_pairs);
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+
+        let updated_hash = state.commit(correlation_id, root_hash, effects).unwrap();
+
+        let mut last_progress = None;
+        let stats = state
+            .collect_garbage(correlation_id, &[updated_hash], |progress| {
+                last_progress = Some(progress)
+            })
+            .unwrap();
+
+        assert!(stats.removed_tries > 0);
+        assert_eq!(
+            last_progress,
+            Some(GarbageCollectionProgress::Sweeping {
+                examined: stats.retained_tries + stats.removed_tries,
+                removed: stats.removed_tries,
+            })
+        );
+        assert!(state.checkout(root_hash).unwrap().is_none());
+        assert!(state.checkout(state.empty_root_hash).unwrap().is_some());
+
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        for TestPair { key, value } in test_pairs_updated.iter().cloned() {
+            assert_eq!(
+                Some(value),
+                updated_checkout.read(correlation_id, &key).unwrap()
+            );
+        }
+
+        let stats = state
+            .collect_garbage(correlation_id, &[updated_hash], |_| {})
+            .unwrap();
+        assert_eq!(stats.removed_tries, 0);
+    }
+
+    #[test]
+    fn exported_tries_can_be_put_into_another_store_in_order() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state(create_test_pairs);
+        let (other_state, _) = create_test_state(|| {
+            let [_, two, three] = create_test_pairs_updated();
+            [two, three]
+        });
+        assert!(other_state.checkout(root_hash).unwrap().is_none());
+
+        let mut exported = Vec::new();
+        let found = state
+            .export_tries::<_, error::Error>(correlation_id, root_hash, |trie_raw| {
+                exported.push(trie_raw);
+                Ok(())
+            })
+            .unwrap();
+        assert!(found);
+
+        let mut last_hash = None;
+        for trie_raw in &exported {
+            assert!(other_state
+                .missing_children(correlation_id, trie_raw.inner())
+                .unwrap()
+                .is_empty());
+            last_hash = Some(
+                other_state
+                    .put_trie(correlation_id, trie_raw.inner())
+                    .unwrap(),
+            );
+        }
+        assert_eq!(last_hash, Some(root_hash));
+
+        let checkout = other_state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            assert_eq!(Some(value), checkout.read(correlation_id, &key).unwrap());
+        }
+
+        let missing_root = Digest::hash([1u8; 32]);
+        let found = state
+            .export_tries::<_, error::Error>(correlation_id, missing_root, |_| {
+                panic!("no trie should be exported")
+            })
+            .unwrap();
+        assert!(!found);
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingSink {
+        node_reads: AtomicUsize,
+        node_writes: AtomicUsize,
+        proofs: AtomicUsize,
+        prunes: AtomicUsize,
+    }
+
+    impl TrieMetricsSink for CountingSink {
+        fn record_node_read(&self) {
+            self.node_reads.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn record_node_write(&self) {
+            self.node_writes.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn record_proof_generation(&self, _duration: Duration) {
+            self.proofs.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn record_prune(&self, _duration: Duration) {
+            self.prunes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn trie_operations_are_reported_to_metrics_sink() {
+        let correlation_id = CorrelationId::new();
+        let temp_dir = tempdir().unwrap();
+        let environment = Arc::new(
+            LmdbEnvironment::new(
+                temp_dir.path(),
+                DEFAULT_TEST_MAX_DB_SIZE,
+                DEFAULT_TEST_MAX_READERS,
+                true,
+            )
+            .unwrap(),
+        );
+        let sink = Arc::new(CountingSink::default());
+        let metrics_sink: Arc<dyn TrieMetricsSink> = sink.clone();
+        let trie_store = Arc::new(
+            LmdbTrieStore::new(&environment, None, DatabaseFlags::empty())
+                .unwrap()
+                .with_metrics_sink(metrics_sink),
+        );
+        let state = LmdbGlobalState::empty(environment, trie_store).unwrap();
+        assert_eq!(sink.node_writes.load(Ordering::SeqCst), 1);
+
+        let effects: AdditiveMap<Key, Transform> = create_test_pairs()
+            .iter()
+            .map(|TestPair { key, value }| (*key, Transform::Write(value.clone())))
+            .collect();
+        let root_hash = state
+            .commit(correlation_id, state.empty_root_hash, effects)
+            .unwrap();
+        assert!(sink.node_writes.load(Ordering::SeqCst) > 1);
+
+        let node_reads = sink.node_reads.load(Ordering::SeqCst);
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        let key = create_test_pairs()[0].key;
+        assert!(checkout
+            .read_with_proof(correlation_id, &key)
+            .unwrap()
+            .is_some());
+        assert!(sink.node_reads.load(Ordering::SeqCst) > node_reads);
+        assert_eq!(sink.proofs.load(Ordering::SeqCst), 1);
+
+        let result = state
+            .delete_keys(correlation_id, root_hash, &[key])
+            .unwrap();
+        assert!(matches!(result, DeleteResult::Deleted(_)));
+        assert_eq!(sink.prunes.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn compressed_contract_wasm_is_read_back_unchanged() {
+        let correlation_id = CorrelationId::new();
+        let key = Key::Hash([1u8; 32]);
+        let value = StoredValue::ContractWasm(ContractWasm::new(vec![0u8; 4096]));
+        let leaf_bytes = Trie::Leaf {
+            key,
+            value: value.clone(),
+        }
+        .to_bytes()
+        .unwrap();
+        let leaf_hash = Digest::hash(&leaf_bytes);
+
+        let mut results = Vec::new();
+        for compress_contract_wasm in [false, true] {
+            let temp_dir = tempdir().unwrap();
+            let environment = Arc::new(
+                LmdbEnvironment::new(
+                    temp_dir.path(),
+                    DEFAULT_TEST_MAX_DB_SIZE,
+                    DEFAULT_TEST_MAX_READERS,
+                    true,
+                )
+                .unwrap(),
+            );
+            let trie_store = Arc::new(
+                LmdbTrieStore::new(&environment, None, DatabaseFlags::empty())
+                    .unwrap()
+                    .with_contract_wasm_compression(compress_contract_wasm),
+            );
+            let state = LmdbGlobalState::empty(environment, trie_store).unwrap();
+
+            let mut effects = AdditiveMap::new();
+            effects.insert(key, Transform::Write(value.clone()));
+            let root_hash = state
+                .commit(correlation_id, state.empty_root_hash, effects)
+                .unwrap();
+
+            let checkout = state.checkout(root_hash).unwrap().unwrap();
+            assert_eq!(
+                checkout.read(correlation_id, &key).unwrap(),
+                Some(value.clone())
+            );
+            let trie_raw = state
+                .get_trie_full(correlation_id, &leaf_hash)
+                .unwrap()
+                .expect("should have leaf");
+            assert_eq!(trie_raw.inner().as_slice(), leaf_bytes.as_slice());
+
+            let stored_len = {
+                let txn = state.environment.create_read_txn().unwrap();
+                let stored =
+                    lmdb::Transaction::get(&txn, state.trie_store.get_db(), &leaf_hash).unwrap();
+                stored.len()
+            };
+            results.push((root_hash, stored_len));
+        }
+
+        // The state root hash doesn't depend on how the leaf is stored, but its record shrinks.
+        let (uncompressed_root, uncompressed_len) = results[0];
+        let (compressed_root, compressed_len) = results[1];
+        assert_eq!(uncompressed_root, compressed_root);
+        assert_eq!(uncompressed_len, leaf_bytes.len());
+        assert!(compressed_len < uncompressed_len);
+    }
+
+    #[test]
+    fn release_root_removes_tries_no_longer_reachable() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state(create_test_pairs);
+        assert_eq!(state.liveness_stats().unwrap(), None);
+        state.enable_liveness_tracking(&[root_hash]).unwrap();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+        let updated_hash = state.commit(correlation_id, root_hash, effects).unwrap();
+
+        let stats = state.liveness_stats().unwrap().unwrap();
+        assert_eq!(stats.tracked_roots, 2);
+        assert_eq!(state.check_liveness_invariants().unwrap(), Some(vec![]));
+
+        let removed = state.release_root(root_hash).unwrap().unwrap();
+        assert!(removed > 0);
+        assert_eq!(state.release_root(root_hash).unwrap(), None);
+        assert!(state.checkout(root_hash).unwrap().is_none());
+        assert_eq!(state.check_liveness_invariants().unwrap(), Some(vec![]));
+
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        for TestPair { key, value } in test_pairs_updated.iter().cloned() {
+            assert_eq!(
+                Some(value),
+                updated_checkout.read(correlation_id, &key).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn release_root_keeps_tries_reachable_from_untracked_roots() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state(create_test_pairs);
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+        let updated_hash = state.commit(correlation_id, root_hash, effects).unwrap();
+
+        // Only the updated root is tracked: the original one is still stored, so it gets pinned.
+        state.enable_liveness_tracking(&[updated_hash]).unwrap();
+        assert!(state.liveness_stats().unwrap().unwrap().pinned_tries > 0);
+
+        state.release_root(updated_hash).unwrap().unwrap();
+        assert_eq!(state.check_liveness_invariants().unwrap(), Some(vec![]));
+
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            assert_eq!(Some(value), checkout.read(correlation_id, &key).unwrap());
+        }
+    }
 }
@@ -9,6 +9,9 @@ pub mod lmdb;
 /// Lmdb implementation of global state with cache.
 pub mod scratch;
 
+/// Global state recording the keys read from it.
+pub mod read_recording;
+
 use std::{collections::HashMap, hash::BuildHasher};
 
 use tracing::error;
@@ -55,6 +58,16 @@ pub trait StateReader<K, V> {
         correlation_id: CorrelationId,
         prefix: &[u8],
     ) -> Result<Vec<K>, Self::Error>;
+
+    /// Returns at most `limit` keys in the trie matching `prefix` whose serialized form sorts
+    /// strictly after `start_after`, in ascending order of their serialized form.
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: &[u8],
+        limit: usize,
+    ) -> Result<Vec<K>, Self::Error>;
 }
 
 /// An error emitted by the execution engine on commit
@@ -78,6 +91,9 @@ pub enum CommitError {
     /// Trie not found while attempting to validate cache write.
     #[error("Trie not found in cache {0}")]
     TrieNotFoundInCache(Digest),
+    /// Trie reachable from a root not found in the store.
+    #[error("Trie not found: {0}")]
+    TrieNotFound(Digest),
 }
 
 /// Provides `commit` method.
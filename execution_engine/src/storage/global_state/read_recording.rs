@@ -0,0 +1,217 @@
+use std::{
+    collections::BTreeSet,
+    mem,
+    sync::{Arc, Mutex},
+};
+
+use casper_hashing::Digest;
+use casper_types::{bytesrepr::ToBytes, Key, StoredValue};
+
+use crate::{
+    shared::{additive_map::AdditiveMap, newtypes::CorrelationId, transform::Transform},
+    storage::{
+        global_state::{CommitProvider, StateProvider, StateReader},
+        trie::{merkle_proof::TrieMerkleProof, TrieRaw},
+        trie_store::operations::DeleteResult,
+    },
+};
+
+/// The reads made from global state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReadSet {
+    /// The keys read, including keys with no value.
+    pub keys: BTreeSet<Key>,
+    /// The prefixes of the keys listed, as adding or removing any key under one of them changes
+    /// the listing.
+    pub prefixes: BTreeSet<Vec<u8>>,
+}
+
+impl ReadSet {
+    /// Returns `true` if a write to `key` could change what was read.
+    pub fn contains(&self, key: &Key) -> bool {
+        if self.keys.contains(key) {
+            return true;
+        }
+        if self.prefixes.is_empty() {
+            return false;
+        }
+        match key.to_bytes() {
+            Ok(key_bytes) => self
+                .prefixes
+                .iter()
+                .any(|prefix| key_bytes.starts_with(prefix)),
+            Err(_) => false,
+        }
+    }
+
+    fn record_listing(&mut self, prefix: &[u8], keys: &[Key]) {
+        self.prefixes.insert(prefix.to_vec());
+        self.keys.extend(keys.iter().copied());
+    }
+}
+
+type SharedReadSet = Arc<Mutex<ReadSet>>;
+
+/// Global state which records the keys of the values read through its views, including keys with
+/// no value, and the prefixes of the keys listed.
+pub struct ReadRecordingGlobalState<'a, S> {
+    state: &'a S,
+    reads: SharedReadSet,
+}
+
+/// Represents a "view" of global state at a particular root hash, recording the reads.
+pub struct ReadRecordingGlobalStateView<R> {
+    reader: R,
+    reads: SharedReadSet,
+}
+
+impl<'a, S> ReadRecordingGlobalState<'a, S> {
+    /// Creates a state recording the reads made from `state`.
+    pub fn new(state: &'a S) -> Self {
+        ReadRecordingGlobalState {
+            state,
+            reads: Arc::default(),
+        }
+    }
+
+    /// Returns the reads made so far, and clears them.
+    pub fn take_reads(&self) -> ReadSet {
+        mem::take(&mut *self.reads.lock().unwrap())
+    }
+}
+
+impl<R> StateReader<Key, StoredValue> for ReadRecordingGlobalStateView<R>
+where
+    R: StateReader<Key, StoredValue>,
+{
+    type Error = R::Error;
+
+    fn read(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<StoredValue>, Self::Error> {
+        self.reads.lock().unwrap().keys.insert(*key);
+        self.reader.read(correlation_id, key)
+    }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        self.reads.lock().unwrap().keys.insert(*key);
+        self.reader.read_with_proof(correlation_id, key)
+    }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        let keys = self.reader.keys_with_prefix(correlation_id, prefix)?;
+        self.reads.lock().unwrap().record_listing(prefix, &keys);
+        Ok(keys)
+    }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: &[u8],
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let keys =
+            self.reader
+                .keys_with_prefix_after(correlation_id, prefix, start_after, limit)?;
+        // A page depends on the keys under the prefix before it too, so the whole prefix is
+        // recorded.
+        self.reads.lock().unwrap().record_listing(prefix, &keys);
+        Ok(keys)
+    }
+}
+
+impl<'a, S> CommitProvider for ReadRecordingGlobalState<'a, S>
+where
+    S: CommitProvider,
+{
+    fn commit(
+        &self,
+        correlation_id: CorrelationId,
+        state_hash: Digest,
+        effects: AdditiveMap<Key, Transform>,
+    ) -> Result<Digest, Self::Error> {
+        self.state.commit(correlation_id, state_hash, effects)
+    }
+}
+
+impl<'a, S> StateProvider for ReadRecordingGlobalState<'a, S>
+where
+    S: StateProvider,
+{
+    type Error = S::Error;
+
+    type Reader = ReadRecordingGlobalStateView<S::Reader>;
+
+    fn checkout(&self, state_hash: Digest) -> Result<Option<Self::Reader>, Self::Error> {
+        let maybe_reader = self.state.checkout(state_hash)?;
+        Ok(maybe_reader.map(|reader| ReadRecordingGlobalStateView {
+            reader,
+            reads: Arc::clone(&self.reads),
+        }))
+    }
+
+    fn empty_root(&self) -> Digest {
+        self.state.empty_root()
+    }
+
+    fn get_trie_full(
+        &self,
+        correlation_id: CorrelationId,
+        trie_key: &Digest,
+    ) -> Result<Option<TrieRaw>, Self::Error> {
+        self.state.get_trie_full(correlation_id, trie_key)
+    }
+
+    fn put_trie(&self, correlation_id: CorrelationId, trie: &[u8]) -> Result<Digest, Self::Error> {
+        self.state.put_trie(correlation_id, trie)
+    }
+
+    fn missing_children(
+        &self,
+        correlation_id: CorrelationId,
+        trie_raw: &[u8],
+    ) -> Result<Vec<Digest>, Self::Error> {
+        self.state.missing_children(correlation_id, trie_raw)
+    }
+
+    fn delete_keys(
+        &self,
+        correlation_id: CorrelationId,
+        root: Digest,
+        keys_to_delete: &[Key],
+    ) -> Result<DeleteResult, Self::Error> {
+        self.state.delete_keys(correlation_id, root, keys_to_delete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{account::AccountHash, KeyTag, TransferAddr};
+
+    use super::*;
+
+    #[test]
+    fn should_contain_keys_under_listed_prefixes() {
+        let listed_transfer = Key::Transfer(TransferAddr::new([1; 32]));
+        let mut reads = ReadSet::default();
+        reads.keys.insert(Key::Account(AccountHash::new([2; 32])));
+        reads.record_listing(&[KeyTag::Transfer as u8], &[listed_transfer]);
+
+        assert!(reads.contains(&Key::Account(AccountHash::new([2; 32]))));
+        assert!(!reads.contains(&Key::Account(AccountHash::new([3; 32]))));
+        assert!(reads.contains(&listed_transfer));
+        // A key added under the prefix would have changed the listing.
+        assert!(reads.contains(&Key::Transfer(TransferAddr::new([4; 32]))));
+    }
+}
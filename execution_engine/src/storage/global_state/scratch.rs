@@ -3,6 +3,7 @@ use std::{
     mem,
     ops::Deref,
     sync::{Arc, RwLock},
+    time::Instant,
 };
 
 use tracing::error;
@@ -21,8 +22,8 @@ use crate::{
         trie_store::{
             lmdb::LmdbTrieStore,
             operations::{
-                delete, keys_with_prefix, missing_children, put_trie, read, read_with_proof,
-                DeleteResult, ReadResult,
+                delete, keys_with_prefix, keys_with_prefix_after, missing_children, put_trie, read,
+                read_with_proof, DeleteResult, ReadResult,
             },
         },
     },
@@ -119,6 +120,7 @@ impl StateReader<Key, StoredValue> for ScratchGlobalStateView {
         key: &Key,
     ) -> Result<Option<StoredValue>, Self::Error> {
         if let Some(value) = self.cache.read().unwrap().get(key) {
+            self.trie_store.metrics_sink().record_cache_hit();
             return Ok(Some(value.clone()));
         }
         let txn = self.environment.create_read_txn()?;
@@ -145,6 +147,7 @@ impl StateReader<Key, StoredValue> for ScratchGlobalStateView {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        let start = Instant::now();
         let txn = self.environment.create_read_txn()?;
         let ret = match read_with_proof::<
             Key,
@@ -164,6 +167,9 @@ impl StateReader<Key, StoredValue> for ScratchGlobalStateView {
             ReadResult::RootNotFound => panic!("LmdbWithCacheGlobalState has invalid root"),
         };
         txn.commit()?;
+        self.trie_store
+            .metrics_sink()
+            .record_proof_generation(start.elapsed());
         Ok(ret)
     }
 
@@ -190,6 +196,33 @@ impl StateReader<Key, StoredValue> for ScratchGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        start_after: &[u8],
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let keys_iter = keys_with_prefix_after::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.trie_store.deref(),
+            &self.root_hash,
+            prefix,
+            start_after,
+        );
+        let mut ret = Vec::new();
+        for result in keys_iter.take(limit) {
+            match result {
+                Ok(key) => ret.push(key),
+                Err(error) => return Err(error),
+            }
+        }
+        txn.commit()?;
+        Ok(ret)
+    }
 }
 
 impl CommitProvider for ScratchGlobalState {
@@ -334,6 +367,7 @@ impl StateProvider for ScratchGlobalState {
         mut state_root_hash: Digest,
         keys_to_delete: &[Key],
     ) -> Result<DeleteResult, Self::Error> {
+        let start = Instant::now();
         let mut txn = self.environment.create_read_write_txn()?;
         for key in keys_to_delete {
             let delete_result = delete::<Key, StoredValue, _, _, Self::Error>(
@@ -351,6 +385,7 @@ impl StateProvider for ScratchGlobalState {
             }
         }
         txn.commit()?;
+        self.trie_store.metrics_sink().record_prune(start.elapsed());
         Ok(DeleteResult::Deleted(state_root_hash))
     }
 }
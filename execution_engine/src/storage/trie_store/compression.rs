@@ -0,0 +1,114 @@
+//! Compression of byte code records held in the LMDB trie store.
+//!
+//! Leaves holding a [`StoredValue::ContractWasm`] can be written compressed with zstd, wrapped in
+//! an envelope starting with [`COMPRESSED_LEAF_TAG`].  The tag can't be mistaken for the first byte
+//! of a serialized trie, so compressed and uncompressed records can be told apart on read and
+//! can coexist in the same store.  Only the persisted bytes are affected: tries are hashed, charged
+//! for and exchanged with peers in their uncompressed form.
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes},
+    Key, StoredValue,
+};
+
+use crate::storage::{error, trie::TrieTag};
+
+/// The first byte of a compressed leaf, distinct from all the tags of [`Trie`] variants.
+///
+/// [`Trie`]: crate::storage::trie::Trie
+pub(crate) const COMPRESSED_LEAF_TAG: u8 = u8::MAX;
+
+const COMPRESSION_LEVEL: i32 = zstd::DEFAULT_COMPRESSION_LEVEL;
+
+/// Returns the envelope holding the compressed `trie_bytes` if they are a leaf holding contract
+/// Wasm and compressing them saves space, or `None` if they should be stored as they are.
+pub(crate) fn compress_contract_wasm_leaf(
+    trie_bytes: &[u8],
+) -> Result<Option<Vec<u8>>, error::Error> {
+    if !is_contract_wasm_leaf(trie_bytes) {
+        return Ok(None);
+    }
+    let compressed = zstd::bulk::compress(trie_bytes, COMPRESSION_LEVEL)
+        .map_err(|error| error::Error::Compression(error.to_string()))?;
+    if compressed.len() + 1 >= trie_bytes.len() {
+        return Ok(None);
+    }
+    let mut envelope = Vec::with_capacity(compressed.len() + 1);
+    envelope.push(COMPRESSED_LEAF_TAG);
+    envelope.extend(compressed);
+    Ok(Some(envelope))
+}
+
+/// Returns the serialized trie held by `stored_bytes`, decompressing it if it was stored
+/// compressed.
+pub(crate) fn decompress(stored_bytes: Bytes) -> Result<Bytes, error::Error> {
+    match stored_bytes.split_first() {
+        Some((&COMPRESSED_LEAF_TAG, compressed)) => zstd::stream::decode_all(compressed)
+            .map(Bytes::from)
+            .map_err(|error| error::Error::Compression(error.to_string())),
+        _ => Ok(stored_bytes),
+    }
+}
+
+fn is_contract_wasm_leaf(trie_bytes: &[u8]) -> bool {
+    // Byte code is only ever stored under a `Key::Hash`, so leaves under other keys are skipped
+    // without deserializing their values.
+    match trie_bytes.split_first() {
+        Some((&tag, remainder)) if tag == TrieTag::Leaf as u8 => match Key::from_bytes(remainder) {
+            Ok((Key::Hash(_), remainder)) => matches!(
+                StoredValue::from_bytes(remainder),
+                Ok((StoredValue::ContractWasm(_), _))
+            ),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{bytesrepr::ToBytes, CLValue, ContractWasm};
+
+    use super::*;
+    use crate::storage::trie::Trie;
+
+    fn leaf_bytes(key: Key, value: StoredValue) -> Vec<u8> {
+        Trie::Leaf { key, value }.to_bytes().unwrap()
+    }
+
+    #[test]
+    fn should_round_trip_contract_wasm_leaf() {
+        let wasm = ContractWasm::new(vec![0u8; 4096]);
+        let trie_bytes = leaf_bytes(Key::Hash([1; 32]), StoredValue::ContractWasm(wasm));
+
+        let envelope = compress_contract_wasm_leaf(&trie_bytes)
+            .unwrap()
+            .expect("should compress contract wasm");
+        assert_eq!(envelope[0], COMPRESSED_LEAF_TAG);
+        assert!(envelope.len() < trie_bytes.len());
+
+        let decompressed = decompress(Bytes::from(envelope)).unwrap();
+        assert_eq!(decompressed.as_slice(), trie_bytes.as_slice());
+    }
+
+    #[test]
+    fn should_not_compress_other_values() {
+        let cl_value = CLValue::from_t(Bytes::from(vec![0u8; 4096])).unwrap();
+        let trie_bytes = leaf_bytes(Key::Hash([1; 32]), StoredValue::CLValue(cl_value));
+        assert!(compress_contract_wasm_leaf(&trie_bytes).unwrap().is_none());
+
+        let trie_bytes = Bytes::from(trie_bytes);
+        assert_eq!(decompress(trie_bytes.clone()).unwrap(), trie_bytes);
+    }
+
+    #[test]
+    fn should_not_compress_incompressible_wasm() {
+        // A hash without repeated bytes, so the leaf holds nothing for zstd to compress.
+        let mut hash = [0u8; 32];
+        for (index, byte) in hash.iter_mut().enumerate() {
+            *byte = index as u8;
+        }
+        let wasm = ContractWasm::new(vec![7]);
+        let trie_bytes = leaf_bytes(Key::Hash(hash), StoredValue::ContractWasm(wasm));
+        assert!(compress_contract_wasm_leaf(&trie_bytes).unwrap().is_none());
+    }
+}
@@ -0,0 +1,453 @@
+//! Reference counting of trie nodes.
+//!
+//! A [`TrieLiveness`] tracks a set of state roots and, for every trie reachable from them, the
+//! number of references it has: one for each tracked root handle pointing at it plus one for each
+//! live parent pointing at it.  A trie is live while its reference count is non-zero.  Releasing
+//! a root cascades through its descendants and reports the tries which became unreachable, which
+//! allows them to be removed incrementally rather than via a full mark-and-sweep.
+//!
+//! Tries which may be referenced from outside the tracked roots, such as the ones present in the
+//! store before tracking started or the ones written by synchronization, are pinned: they count
+//! as live for as long as the tracker exists, and so do their descendants.
+use std::collections::{HashMap, HashSet};
+
+use casper_hashing::Digest;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
+use crate::storage::{
+    transaction_source::Readable,
+    trie::{self, DescendantsIterator},
+    trie_store::TrieStore,
+};
+
+/// Statistics describing the tries tracked by a [`TrieLiveness`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LivenessStats {
+    /// Number of distinct state roots being tracked.
+    pub tracked_roots: usize,
+    /// Number of pinned tries.
+    pub pinned_tries: usize,
+    /// Number of tries with a non-zero reference count.
+    pub live_tries: usize,
+    /// Sum of all reference counts.
+    pub total_references: u64,
+    /// Highest reference count of a single trie.
+    pub max_ref_count: u64,
+}
+
+/// A violation of the liveness invariants found by [`TrieLiveness::check_invariants`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LivenessViolation {
+    /// A trie's tracked reference count differs from the count recomputed from the tracked roots.
+    RefCountMismatch {
+        /// Hash of the trie.
+        trie_key: Digest,
+        /// Reference count held by the tracker.
+        tracked: u64,
+        /// Reference count recomputed from scratch.
+        expected: u64,
+    },
+}
+
+/// Reference counts of the tries reachable from a set of tracked state roots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrieLiveness {
+    ref_counts: HashMap<Digest, u64>,
+    roots: HashMap<Digest, u64>,
+    pinned: HashSet<Digest>,
+}
+
+impl TrieLiveness {
+    /// Creates a new, empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the reference count of the given trie.
+    pub fn ref_count(&self, trie_key: &Digest) -> u64 {
+        self.ref_counts.get(trie_key).copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if the given trie is reachable from a tracked root.
+    pub fn is_live(&self, trie_key: &Digest) -> bool {
+        self.ref_count(trie_key) > 0
+    }
+
+    /// Returns `true` if the given state root is tracked.
+    pub fn is_tracked_root(&self, root: &Digest) -> bool {
+        self.roots.contains_key(root)
+    }
+
+    /// Returns `true` if the given trie is pinned.
+    pub fn is_pinned(&self, trie_key: &Digest) -> bool {
+        self.pinned.contains(trie_key)
+    }
+
+    /// Returns the tracked state roots.
+    pub fn tracked_roots(&self) -> impl Iterator<Item = &Digest> {
+        self.roots.keys()
+    }
+
+    /// Returns statistics about the tracked tries.
+    pub fn stats(&self) -> LivenessStats {
+        LivenessStats {
+            tracked_roots: self.roots.len(),
+            pinned_tries: self.pinned.len(),
+            live_tries: self.ref_counts.len(),
+            total_references: self.ref_counts.values().sum(),
+            max_ref_count: self.ref_counts.values().copied().max().unwrap_or_default(),
+        }
+    }
+
+    /// Starts tracking `root`, returning the number of tries which became live as a result.
+    ///
+    /// A root may be tracked more than once; it has to be released as many times.
+    pub fn track_root<K, V, T, S, E>(
+        &mut self,
+        txn: &T,
+        store: &S,
+        root: Digest,
+    ) -> Result<usize, E>
+    where
+        K: ToBytes + FromBytes,
+        V: ToBytes + FromBytes,
+        T: Readable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        *self.roots.entry(root).or_default() += 1;
+        self.increment::<K, V, T, S, E>(txn, store, root)
+    }
+
+    /// Pins `trie_key`, keeping it and all its descendants live for as long as this tracker exists.
+    ///
+    /// Returns the number of tries which became live as a result.  Pinning a trie more than once
+    /// has no further effect.
+    pub fn pin<K, V, T, S, E>(&mut self, txn: &T, store: &S, trie_key: Digest) -> Result<usize, E>
+    where
+        K: ToBytes + FromBytes,
+        V: ToBytes + FromBytes,
+        T: Readable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        if !self.pinned.insert(trie_key) {
+            return Ok(0);
+        }
+        self.increment::<K, V, T, S, E>(txn, store, trie_key)
+    }
+
+    /// Adds a reference to `trie_key`, cascading to its descendants if it became live, and returns
+    /// the number of tries which became live.
+    fn increment<K, V, T, S, E>(&mut self, txn: &T, store: &S, trie_key: Digest) -> Result<usize, E>
+    where
+        K: ToBytes + FromBytes,
+        V: ToBytes + FromBytes,
+        T: Readable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        let mut newly_live = 0;
+        let mut to_increment = vec![trie_key];
+        while let Some(trie_key) = to_increment.pop() {
+            let ref_count = self.ref_counts.entry(trie_key).or_default();
+            *ref_count += 1;
+            if *ref_count == 1 {
+                newly_live += 1;
+                to_increment.extend(children::<K, V, T, S, E>(txn, store, &trie_key)?);
+            }
+        }
+        Ok(newly_live)
+    }
+
+    /// Stops tracking one handle of `root`, returning the tries which became unreachable as a
+    /// result, or `None` if `root` is not tracked.
+    pub fn release_root<K, V, T, S, E>(
+        &mut self,
+        txn: &T,
+        store: &S,
+        root: Digest,
+    ) -> Result<Option<Vec<Digest>>, E>
+    where
+        K: ToBytes + FromBytes,
+        V: ToBytes + FromBytes,
+        T: Readable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        match self.roots.get_mut(&root) {
+            None => return Ok(None),
+            Some(handles) if *handles > 1 => *handles -= 1,
+            Some(_) => {
+                self.roots.remove(&root);
+            }
+        }
+
+        let mut freed = Vec::new();
+        let mut to_decrement = vec![root];
+        while let Some(trie_key) = to_decrement.pop() {
+            let ref_count = match self.ref_counts.get_mut(&trie_key) {
+                Some(ref_count) => ref_count,
+                None => continue,
+            };
+            *ref_count -= 1;
+            if *ref_count == 0 {
+                self.ref_counts.remove(&trie_key);
+                to_decrement.extend(children::<K, V, T, S, E>(txn, store, &trie_key)?);
+                freed.push(trie_key);
+            }
+        }
+        Ok(Some(freed))
+    }
+
+    /// Recomputes all reference counts from the tracked roots and pinned tries and compares them
+    /// with the ones held by this tracker.
+    pub fn check_invariants<K, V, T, S, E>(
+        &self,
+        txn: &T,
+        store: &S,
+    ) -> Result<Vec<LivenessViolation>, E>
+    where
+        K: ToBytes + FromBytes,
+        V: ToBytes + FromBytes,
+        T: Readable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        let mut expected = TrieLiveness::new();
+        for (root, handles) in &self.roots {
+            for _ in 0..*handles {
+                expected.track_root::<K, V, T, S, E>(txn, store, *root)?;
+            }
+        }
+        for trie_key in &self.pinned {
+            expected.pin::<K, V, T, S, E>(txn, store, *trie_key)?;
+        }
+
+        let mut violations: Vec<LivenessViolation> = self
+            .ref_counts
+            .keys()
+            .chain(expected.ref_counts.keys())
+            .filter_map(|trie_key| {
+                let tracked = self.ref_count(trie_key);
+                let expected = expected.ref_count(trie_key);
+                (tracked != expected).then_some(LivenessViolation::RefCountMismatch {
+                    trie_key: *trie_key,
+                    tracked,
+                    expected,
+                })
+            })
+            .collect();
+        violations.sort_by_key(|LivenessViolation::RefCountMismatch { trie_key, .. }| *trie_key);
+        violations.dedup();
+        Ok(violations)
+    }
+}
+
+/// Returns the hashes of the direct descendants of the given trie, or nothing if the trie is not
+/// present in the store.
+fn children<K, V, T, S, E>(txn: &T, store: &S, trie_key: &Digest) -> Result<Vec<Digest>, E>
+where
+    K: ToBytes + FromBytes,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<bytesrepr::Error>,
+{
+    let trie_raw = match store.get_raw(txn, trie_key)? {
+        Some(trie_raw) => trie_raw,
+        None => return Ok(Vec::new()),
+    };
+    let lazy_trie = trie::lazy_trie_deserialize::<K, V>(trie_raw)?;
+    let descendants: DescendantsIterator = trie::lazy_trie_iter_children(&lazy_trie);
+    Ok(descendants.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::bytesrepr::Bytes;
+
+    use super::*;
+    use crate::storage::{
+        error::in_memory,
+        store::{Store, StoreExt},
+        transaction_source::{in_memory::InMemoryEnvironment, Transaction, TransactionSource},
+        trie::{Pointer, Trie},
+        trie_store::in_memory::InMemoryTrieStore,
+    };
+
+    type TestTrie = Trie<Bytes, Bytes>;
+
+    fn leaf(byte: u8) -> (Digest, TestTrie) {
+        let trie = Trie::leaf(Bytes::from(vec![byte]), Bytes::from(vec![byte]));
+        (trie.trie_hash().unwrap(), trie)
+    }
+
+    fn node(children: &[(u8, Pointer)]) -> (Digest, TestTrie) {
+        let trie = Trie::node(children);
+        (trie.trie_hash().unwrap(), trie)
+    }
+
+    #[test]
+    fn should_track_and_release_shared_tries() {
+        let environment = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&environment, None);
+
+        let (leaf_1_hash, leaf_1) = leaf(1);
+        let (leaf_2_hash, leaf_2) = leaf(2);
+        let (leaf_3_hash, leaf_3) = leaf(3);
+        let (root_1_hash, root_1) = node(&[
+            (1, Pointer::LeafPointer(leaf_1_hash)),
+            (2, Pointer::LeafPointer(leaf_2_hash)),
+        ]);
+        let (root_2_hash, root_2) = node(&[
+            (1, Pointer::LeafPointer(leaf_1_hash)),
+            (3, Pointer::LeafPointer(leaf_3_hash)),
+        ]);
+
+        let mut txn = environment.create_read_write_txn().unwrap();
+        let tries = [
+            (leaf_1_hash, leaf_1),
+            (leaf_2_hash, leaf_2),
+            (leaf_3_hash, leaf_3),
+            (root_1_hash, root_1),
+            (root_2_hash, root_2),
+        ];
+        store
+            .put_many(&mut txn, tries.iter().map(|(hash, trie)| (hash, trie)))
+            .unwrap();
+
+        let mut liveness = TrieLiveness::new();
+        let newly_live = liveness
+            .track_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_1_hash)
+            .unwrap();
+        assert_eq!(newly_live, 3);
+        let newly_live = liveness
+            .track_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_2_hash)
+            .unwrap();
+        assert_eq!(newly_live, 2);
+        assert_eq!(liveness.ref_count(&leaf_1_hash), 2);
+        assert_eq!(
+            liveness.stats(),
+            LivenessStats {
+                tracked_roots: 2,
+                pinned_tries: 0,
+                live_tries: 5,
+                total_references: 6,
+                max_ref_count: 2,
+            }
+        );
+        assert!(liveness
+            .check_invariants::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store)
+            .unwrap()
+            .is_empty());
+
+        let mut freed = liveness
+            .release_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_1_hash)
+            .unwrap()
+            .unwrap();
+        freed.sort();
+        let mut expected = vec![root_1_hash, leaf_2_hash];
+        expected.sort();
+        assert_eq!(freed, expected);
+        assert!(liveness.is_live(&leaf_1_hash));
+        assert!(!liveness.is_tracked_root(&root_1_hash));
+        assert!(liveness
+            .release_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_1_hash)
+            .unwrap()
+            .is_none());
+        assert!(liveness
+            .check_invariants::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store)
+            .unwrap()
+            .is_empty());
+
+        txn.commit().unwrap();
+    }
+
+    #[test]
+    fn should_not_release_pinned_tries() {
+        let environment = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&environment, None);
+
+        let (leaf_1_hash, leaf_1) = leaf(1);
+        let (leaf_2_hash, leaf_2) = leaf(2);
+        let (root_1_hash, root_1) = node(&[
+            (1, Pointer::LeafPointer(leaf_1_hash)),
+            (2, Pointer::LeafPointer(leaf_2_hash)),
+        ]);
+        let (root_2_hash, root_2) = node(&[(1, Pointer::LeafPointer(leaf_1_hash))]);
+
+        let mut txn = environment.create_read_write_txn().unwrap();
+        let tries = [
+            (leaf_1_hash, leaf_1),
+            (leaf_2_hash, leaf_2),
+            (root_1_hash, root_1),
+            (root_2_hash, root_2),
+        ];
+        store
+            .put_many(&mut txn, tries.iter().map(|(hash, trie)| (hash, trie)))
+            .unwrap();
+
+        let mut liveness = TrieLiveness::new();
+        liveness
+            .track_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_1_hash)
+            .unwrap();
+        let newly_live = liveness
+            .pin::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_2_hash)
+            .unwrap();
+        assert_eq!(newly_live, 1);
+        assert!(liveness.is_pinned(&root_2_hash));
+        assert_eq!(liveness.stats().pinned_tries, 1);
+
+        let freed = liveness
+            .release_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_1_hash)
+            .unwrap()
+            .unwrap();
+        assert!(!freed.contains(&leaf_1_hash));
+        assert!(liveness.is_live(&leaf_1_hash));
+        assert!(liveness.is_live(&root_2_hash));
+        assert!(liveness
+            .release_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, root_2_hash)
+            .unwrap()
+            .is_none());
+        assert!(liveness
+            .check_invariants::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store)
+            .unwrap()
+            .is_empty());
+
+        txn.commit().unwrap();
+    }
+
+    #[test]
+    fn should_report_ref_count_mismatch() {
+        let environment = InMemoryEnvironment::new();
+        let store = InMemoryTrieStore::new(&environment, None);
+        let (leaf_hash, leaf) = leaf(1);
+
+        let mut txn = environment.create_read_write_txn().unwrap();
+        store.put(&mut txn, &leaf_hash, &leaf).unwrap();
+
+        let mut liveness = TrieLiveness::new();
+        liveness
+            .track_root::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store, leaf_hash)
+            .unwrap();
+        liveness.ref_counts.insert(leaf_hash, 5);
+
+        assert_eq!(
+            liveness
+                .check_invariants::<Bytes, Bytes, _, _, in_memory::Error>(&txn, &store)
+                .unwrap(),
+            vec![LivenessViolation::RefCountMismatch {
+                trie_key: leaf_hash,
+                tracked: 5,
+                expected: 1,
+            }]
+        );
+    }
+}
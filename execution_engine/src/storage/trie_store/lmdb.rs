@@ -123,7 +123,11 @@ use crate::storage::{
     store::Store,
     transaction_source::{lmdb::LmdbEnvironment, Readable, TransactionSource, Writable},
     trie::{self, LazyTrieLeaf, Trie},
-    trie_store::{self, TrieStore},
+    trie_store::{
+        self, compression,
+        metrics::{NoopTrieMetricsSink, TrieMetricsSink},
+        TrieStore,
+    },
 };
 
 /// An LMDB-backed trie store.
@@ -132,6 +136,8 @@ use crate::storage::{
 #[derive(Debug, Clone)]
 pub struct LmdbTrieStore {
     db: Database,
+    metrics: Arc<dyn TrieMetricsSink>,
+    compress_contract_wasm: bool,
 }
 
 impl LmdbTrieStore {
@@ -143,14 +149,43 @@ impl LmdbTrieStore {
     ) -> Result<Self, error::Error> {
         let name = Self::name(maybe_name);
         let db = env.env().create_db(Some(&name), flags)?;
-        Ok(LmdbTrieStore { db })
+        Ok(LmdbTrieStore::from_db(db))
     }
 
     /// Constructor for `LmdbTrieStore` which opens an existing lmdb store file.
     pub fn open(env: &LmdbEnvironment, maybe_name: Option<&str>) -> Result<Self, error::Error> {
         let name = Self::name(maybe_name);
         let db = env.env().open_db(Some(&name))?;
-        Ok(LmdbTrieStore { db })
+        Ok(LmdbTrieStore::from_db(db))
+    }
+
+    fn from_db(db: Database) -> Self {
+        LmdbTrieStore {
+            db,
+            metrics: Arc::new(NoopTrieMetricsSink),
+            compress_contract_wasm: false,
+        }
+    }
+
+    /// Returns the store reporting its operations to `metrics`.
+    pub fn with_metrics_sink(mut self, metrics: Arc<dyn TrieMetricsSink>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Returns the store compressing the leaves holding contract Wasm it writes if
+    /// `compress_contract_wasm` is true.
+    ///
+    /// Compressed leaves are decompressed on read whether or not this is set, so it only affects
+    /// new writes.
+    pub fn with_contract_wasm_compression(mut self, compress_contract_wasm: bool) -> Self {
+        self.compress_contract_wasm = compress_contract_wasm;
+        self
+    }
+
+    /// Returns the sink the operations of this store are reported to.
+    pub fn metrics_sink(&self) -> &dyn TrieMetricsSink {
+        &*self.metrics
     }
 
     fn name(maybe_name: Option<&str>) -> String {
@@ -173,6 +208,41 @@ impl<K, V> Store<Digest, Trie<K, V>> for LmdbTrieStore {
     fn handle(&self) -> Self::Handle {
         self.db
     }
+
+    fn get_raw<T>(&self, txn: &T, key: &Digest) -> Result<Option<Bytes>, Self::Error>
+    where
+        T: Readable<Handle = Self::Handle>,
+        Digest: AsRef<[u8]>,
+        Self::Error: From<T::Error>,
+    {
+        self.metrics.record_node_read();
+        txn.read(self.db, key.as_ref())?
+            .map(compression::decompress)
+            .transpose()
+    }
+
+    fn put_raw<T>(
+        &self,
+        txn: &mut T,
+        key: &Digest,
+        value_bytes: Cow<'_, [u8]>,
+    ) -> Result<(), Self::Error>
+    where
+        T: Writable<Handle = Self::Handle>,
+        Digest: AsRef<[u8]>,
+        Self::Error: From<T::Error>,
+    {
+        self.metrics.record_node_write();
+        if self.compress_contract_wasm {
+            if let Some(envelope) = compression::compress_contract_wasm_leaf(&value_bytes)? {
+                return txn
+                    .write(self.db, key.as_ref(), &envelope)
+                    .map_err(Into::into);
+            }
+        }
+        txn.write(self.db, key.as_ref(), &value_bytes)
+            .map_err(Into::into)
+    }
 }
 
 impl<K, V> TrieStore<K, V> for LmdbTrieStore {}
@@ -272,11 +342,16 @@ impl Store<Digest, Trie<Key, StoredValue>> for ScratchTrieStore {
         let maybe_trie = store.get(key);
 
         match maybe_trie {
-            Some((_, trie_bytes)) => Ok(Some(trie_bytes.clone())),
+            Some((_, trie_bytes)) => {
+                self.store.metrics.record_cache_hit();
+                Ok(Some(trie_bytes.clone()))
+            }
             None => {
+                self.store.metrics.record_node_read();
                 let handle = self.handle();
                 match txn.read(handle, key.as_ref())? {
-                    Some(trie_bytes) => {
+                    Some(stored_bytes) => {
+                        let trie_bytes = compression::decompress(stored_bytes)?;
                         match store.entry(*key) {
                             Entry::Occupied(_) => {}
                             Entry::Vacant(v) => {
@@ -0,0 +1,34 @@
+//! Instrumentation of the operations on a trie store.
+//!
+//! The storage layer doesn't depend on any particular metrics library.  Instead, an implementation
+//! of [`TrieMetricsSink`] can be attached to an [`LmdbTrieStore`](super::lmdb::LmdbTrieStore),
+//! which then reports every trie operation to it.
+use std::{fmt::Debug, time::Duration};
+
+/// A receiver of measurements of trie operations.
+///
+/// All methods are called on the hot path of global state access, so implementations should be
+/// cheap, e.g. incrementing an atomic counter.  Every method defaults to doing nothing.
+pub trait TrieMetricsSink: Debug + Send + Sync {
+    /// Called when a trie node is read from the underlying database.
+    fn record_node_read(&self) {}
+
+    /// Called when a trie node is written to the underlying database.
+    fn record_node_write(&self) {}
+
+    /// Called when a trie node or a value is served from an in-memory cache instead of being read
+    /// from the underlying database.
+    fn record_cache_hit(&self) {}
+
+    /// Called with the time taken to read a value along with its Merkle proof.
+    fn record_proof_generation(&self, _duration: Duration) {}
+
+    /// Called with the time taken to prune keys from global state.
+    fn record_prune(&self, _duration: Duration) {}
+}
+
+/// A [`TrieMetricsSink`] which discards all measurements.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopTrieMetricsSink;
+
+impl TrieMetricsSink for NoopTrieMetricsSink {}
@@ -2,8 +2,11 @@
 //!
 //! See the [in_memory](in_memory/index.html#usage) and
 //! [lmdb](lmdb/index.html#usage) modules for usage examples.
+mod compression;
 pub mod in_memory;
+pub mod liveness;
 pub mod lmdb;
+pub mod metrics;
 pub(crate) mod operations;
 #[cfg(test)]
 mod tests;
@@ -234,6 +234,93 @@ where
     }
 }
 
+/// Number of tries processed between successive progress reports of long running operations.
+pub const PROGRESS_REPORT_INTERVAL: usize = 100_000;
+
+/// Marks every trie reachable from any of the given `roots` by writing its hash to the `marks`
+/// database, returning the number of marked tries.
+///
+/// The marks are written through `txn` rather than collected in memory, so the size of the
+/// reachable set is bounded by the store rather than by the available memory.  Leaves are marked
+/// as reachable without being read from the store.  Roots (or descendants) which are missing from
+/// the store are skipped.  `on_progress` is called with the number of tries marked so far every
+/// [`PROGRESS_REPORT_INTERVAL`] tries.
+pub fn mark_reachable_tries<K, V, T, S, E, F>(
+    _correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    marks: S::Handle,
+    roots: &[Digest],
+    mut on_progress: F,
+) -> Result<usize, E>
+where
+    K: ToBytes + FromBytes + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + std::fmt::Debug,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Handle: Copy,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<bytesrepr::Error>,
+    F: FnMut(usize),
+{
+    let mut marked = 0;
+    let mut mark = |txn: &mut T, trie_key: &Digest| -> Result<bool, E> {
+        if txn
+            .read(marks, trie_key.as_ref())
+            .map_err(S::Error::from)?
+            .is_some()
+        {
+            return Ok(false);
+        }
+        txn.write(marks, trie_key.as_ref(), &[])
+            .map_err(S::Error::from)?;
+        marked += 1;
+        if marked % PROGRESS_REPORT_INTERVAL == 0 {
+            on_progress(marked);
+        }
+        Ok(true)
+    };
+
+    let mut tries_to_visit: Vec<Digest> = roots.to_vec();
+    while let Some(trie_key) = tries_to_visit.pop() {
+        if txn
+            .read(marks, trie_key.as_ref())
+            .map_err(S::Error::from)?
+            .is_some()
+        {
+            continue;
+        }
+
+        let trie_raw = match store.get_raw(txn, &trie_key)? {
+            Some(trie_raw) => trie_raw,
+            // A root which was already collected, or a partially synced trie.
+            None => continue,
+        };
+        mark(txn, &trie_key)?;
+
+        match trie::lazy_trie_deserialize::<K, V>(trie_raw)? {
+            Either::Left(_leaf_bytes) => {}
+            Either::Right(Trie::Node { pointer_block }) => {
+                for (_, pointer) in pointer_block.as_indexed_pointers() {
+                    match pointer {
+                        Pointer::LeafPointer(leaf_hash) => {
+                            mark(txn, &leaf_hash)?;
+                        }
+                        Pointer::NodePointer(node_hash) => tries_to_visit.push(node_hash),
+                    }
+                }
+            }
+            Either::Right(Trie::Extension { pointer, .. }) => {
+                tries_to_visit.push(pointer.into_hash())
+            }
+            Either::Right(Trie::Leaf { .. }) => {}
+        }
+    }
+
+    on_progress(marked);
+    Ok(marked)
+}
+
 /// Given a serialized trie, find any children that are referenced but not present in the database.
 pub fn missing_children<K, V, T, S, E>(
     _correlation_id: CorrelationId,
@@ -1035,6 +1122,9 @@ struct VisitedTrieNode<K, V> {
 
 pub struct KeysIterator<'a, 'b, K, V, T, S: TrieStore<K, V>> {
     initial_descend: VecDeque<u8>,
+    /// Only keys serializing strictly after these bytes are returned; subtries which can't hold
+    /// such keys are never loaded.
+    start_after: Vec<u8>,
     visited: Vec<VisitedTrieNode<K, V>>,
     store: &'a S,
     txn: &'b T,
@@ -1082,7 +1172,7 @@ where
                     debug_assert!(key_bytes.starts_with(&path));
                     // only return the leaf if it matches the initial descend path
                     path.extend(&self.initial_descend);
-                    if key_bytes.starts_with(&path) {
+                    if key_bytes.starts_with(&path) && key_bytes > self.start_after {
                         return Some(Ok(key));
                     }
                 }
@@ -1097,7 +1187,10 @@ where
                         .or(maybe_index)
                         .unwrap_or_default();
                     while index < RADIX {
-                        if let Some(ref pointer) = pointer_block[index] {
+                        let pointer = pointer_block[index].as_ref().filter(|_| {
+                            may_hold_keys_after(&path, &[index as u8], &self.start_after)
+                        });
+                        if let Some(pointer) = pointer {
                             maybe_next_trie = match self.store.get(self.txn, pointer.hash()) {
                                 Ok(trie) => trie,
                                 Err(e) => {
@@ -1135,7 +1228,9 @@ where
                     // matches the descend path
                     // if we are not, the check_prefix will be empty, so we will enter the if
                     // anyway
-                    if affix.starts_with(&check_prefix) {
+                    if affix.starts_with(&check_prefix)
+                        && may_hold_keys_after(&path, &affix, &self.start_after)
+                    {
                         maybe_next_trie = match self.store.get(self.txn, pointer.hash()) {
                             Ok(trie) => trie,
                             Err(e) => {
@@ -1161,15 +1256,55 @@ where
     }
 }
 
+/// Returns `true` if the subtrie at `path` followed by `suffix` may hold a key serializing strictly
+/// after `start_after`.
+fn may_hold_keys_after(path: &[u8], suffix: &[u8], start_after: &[u8]) -> bool {
+    let mut subtrie_path = path.iter().chain(suffix);
+    for cursor_byte in start_after {
+        match subtrie_path.next() {
+            Some(byte) if byte == cursor_byte => continue,
+            Some(byte) => return byte > cursor_byte,
+            None => return true,
+        }
+    }
+    true
+}
+
 /// Returns the iterator over the keys in the subtrie matching `prefix`.
 ///
 /// The root should be the apex of the trie.
 pub fn keys_with_prefix<'a, 'b, K, V, T, S>(
+    correlation_id: CorrelationId,
+    txn: &'b T,
+    store: &'a S,
+    root: &Digest,
+    prefix: &[u8],
+) -> KeysIterator<'a, 'b, K, V, T, S>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+{
+    keys_with_prefix_after(correlation_id, txn, store, root, prefix, &[])
+}
+
+/// Returns the iterator over the keys in the subtrie matching `prefix` whose serialized form sorts
+/// strictly after `start_after`.
+///
+/// Keys are yielded in ascending order of their serialized form.  Subtries holding only keys at or
+/// before `start_after` are skipped without being read, so resuming an enumeration from a cursor
+/// costs no more than the path down to it.
+///
+/// The root should be the apex of the trie.
+pub fn keys_with_prefix_after<'a, 'b, K, V, T, S>(
     _correlation_id: CorrelationId,
     txn: &'b T,
     store: &'a S,
     root: &Digest,
     prefix: &[u8],
+    start_after: &[u8],
 ) -> KeysIterator<'a, 'b, K, V, T, S>
 where
     K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
@@ -1193,6 +1328,7 @@ where
 
     KeysIterator {
         initial_descend: prefix.iter().cloned().collect(),
+        start_after: start_after.to_vec(),
         visited,
         store,
         txn,
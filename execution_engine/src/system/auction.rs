@@ -9,8 +9,9 @@ use num_traits::{CheckedMul, CheckedSub};
 use casper_types::{
     account::AccountHash,
     system::auction::{
-        Bid, DelegationRate, EraInfo, EraValidators, Error, SeigniorageAllocation,
-        SeigniorageRecipients, ValidatorWeights, BLOCK_REWARD, DELEGATION_RATE_DENOMINATOR,
+        Bid, DelegationRate, EraInfo, EraValidators, Error, Reservation, SeigniorageAllocation,
+        SeigniorageRecipients, SeigniorageRecipientsSnapshot, BLOCK_REWARD,
+        DELEGATION_RATE_DENOMINATOR,
     },
     ApiError, EraId, PublicKey, U512,
 };
@@ -57,12 +58,19 @@ pub trait Auction:
     /// Validators cannot create a bid with 0 amount, and the delegation rate can't exceed
     /// [`DELEGATION_RATE_DENOMINATOR`].
     ///
+    /// An existing bid's delegation rate can be lowered at any time, but can only be raised by at
+    /// most `max_delegation_rate_increase` at once, and no sooner than
+    /// `delegation_rate_change_cooldown` eras after it was last raised.  A cooldown of `0`
+    /// disables the latter check.
+    ///
     /// Returns a [`U512`] value indicating total amount of tokens staked for given `public_key`.
     fn add_bid(
         &mut self,
         public_key: PublicKey,
         delegation_rate: DelegationRate,
         amount: U512,
+        delegation_rate_change_cooldown: u64,
+        max_delegation_rate_increase: DelegationRate,
     ) -> Result<U512, ApiError> {
         if !self.allow_auction_bids() {
             // Validation set rotation might be disabled on some private chains and we should not
@@ -91,6 +99,15 @@ pub trait Auction:
         // Update bids or stakes
         let updated_amount = match self.read_bid(&account_hash)? {
             Some(mut bid) => {
+                if delegation_rate > *bid.delegation_rate() {
+                    detail::check_delegation_rate_increase(
+                        self,
+                        &public_key,
+                        delegation_rate - *bid.delegation_rate(),
+                        delegation_rate_change_cooldown,
+                        max_delegation_rate_increase,
+                    )?;
+                }
                 if bid.inactive() {
                     bid.activate();
                 }
@@ -238,14 +255,15 @@ pub trait Auction:
 
         let bid = detail::read_bid_for_validator(self, validator_account_hash)?;
 
-        let delegator_already_exists = bid.delegators().contains_key(&delegator_public_key);
+        let reservations = detail::get_reservations(self, &validator_public_key)?;
 
-        if let Some(max_delegators_per_validator) = max_delegators_per_validator {
-            if bid.delegators().len() >= max_delegators_per_validator as usize
-                && !delegator_already_exists
-            {
-                return Err(Error::ExceededDelegatorSizeLimit.into());
-            }
+        if !detail::has_delegator_slot(
+            &bid,
+            &reservations,
+            &delegator_public_key,
+            max_delegators_per_validator,
+        ) {
+            return Err(Error::ExceededDelegatorSizeLimit.into());
         }
 
         if amount < U512::from(minimum_delegation_amount) {
@@ -389,8 +407,13 @@ pub trait Auction:
         }
 
         let mut burned_amount: U512 = U512::zero();
+        let bridges = detail::get_validator_public_key_bridges(self)?;
 
         for validator_public_key in validator_public_keys {
+            // Consensus may still refer to a validator by a public key its bid has moved away from.
+            let validator_public_key =
+                detail::resolve_validator_public_key(&bridges, validator_public_key);
+
             // Burn stake, deactivate
             let validator_account_hash = AccountHash::from(&validator_public_key);
             if let Some(mut bid) = self.read_bid(&validator_account_hash)? {
@@ -447,6 +470,14 @@ pub trait Auction:
 
         let mut bids = detail::get_bids(self)?;
 
+        // Consensus refers to validators by the public keys they were selected under, which might
+        // have since been changed.
+        let bridges = detail::get_validator_public_key_bridges(self)?;
+        let evicted_validators: Vec<PublicKey> = evicted_validators
+            .into_iter()
+            .map(|public_key| detail::resolve_validator_public_key(&bridges, public_key))
+            .collect();
+
         // Process bids
         let mut bids_modified = false;
         for (validator_public_key, bid) in bids.iter_mut() {
@@ -463,54 +494,12 @@ pub trait Auction:
         }
 
         // Compute next auction winners
-        let winners: ValidatorWeights = {
-            let locked_validators: ValidatorWeights = bids
-                .iter()
-                .filter(|(_public_key, bid)| {
-                    bid.is_locked_with_vesting_schedule(
-                        era_end_timestamp_millis,
-                        vesting_schedule_period_millis,
-                    ) && !bid.inactive()
-                })
-                .map(|(public_key, bid)| {
-                    let total_staked_amount = bid.total_staked_amount()?;
-                    Ok((public_key.clone(), total_staked_amount))
-                })
-                .collect::<Result<ValidatorWeights, Error>>()?;
-
-            // We collect these into a vec for sorting
-            let mut unlocked_validators: Vec<(PublicKey, U512)> = bids
-                .iter()
-                .filter(|(_public_key, bid)| {
-                    !bid.is_locked_with_vesting_schedule(
-                        era_end_timestamp_millis,
-                        vesting_schedule_period_millis,
-                    ) && !bid.inactive()
-                })
-                .map(|(public_key, bid)| {
-                    let total_staked_amount = bid.total_staked_amount()?;
-                    Ok((public_key.clone(), total_staked_amount))
-                })
-                .collect::<Result<Vec<(PublicKey, U512)>, Error>>()?;
-
-            unlocked_validators.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
-
-            // This assumes that amount of founding validators does not exceed configured validator
-            // slots. For a case where there are exactly N validators and the limit is N, only
-            // founding validators will be the in the winning set. It is advised to set
-            // `validator_slots` larger than amount of founding validators in accounts.toml to
-            // accomodate non-genesis validators.
-            let remaining_auction_slots = validator_slots.saturating_sub(locked_validators.len());
-
-            locked_validators
-                .into_iter()
-                .chain(
-                    unlocked_validators
-                        .into_iter()
-                        .take(remaining_auction_slots),
-                )
-                .collect()
-        };
+        let winners = detail::select_auction_winners(
+            &bids,
+            era_end_timestamp_millis,
+            vesting_schedule_period_millis,
+            validator_slots,
+        )?;
 
         // Increment era
         era_id = era_id.checked_add(1).ok_or(Error::ArithmeticOverflow)?;
@@ -536,7 +525,23 @@ pub trait Auction:
             let previous_recipients = snapshot.insert(delayed_era, recipients);
             assert!(previous_recipients.is_none());
 
-            let snapshot = snapshot.into_iter().rev().take(snapshot_size).collect();
+            let snapshot: SeigniorageRecipientsSnapshot =
+                snapshot.into_iter().rev().take(snapshot_size).collect();
+
+            // A bridge is only needed while its previous public key is part of the snapshot.
+            let retained_bridges: BTreeMap<PublicKey, PublicKey> = bridges
+                .iter()
+                .filter(|(public_key, _)| {
+                    snapshot
+                        .values()
+                        .any(|recipients| recipients.contains_key(public_key))
+                })
+                .map(|(public_key, new_public_key)| (public_key.clone(), new_public_key.clone()))
+                .collect();
+            if retained_bridges.len() != bridges.len() {
+                detail::set_validator_public_key_bridges(self, retained_bridges)?;
+            }
+
             detail::set_seigniorage_recipients_snapshot(self, snapshot)?;
         }
 
@@ -559,13 +564,20 @@ pub trait Auction:
 
         let seigniorage_recipients = self.read_seigniorage_recipients()?;
         let base_round_reward = self.read_base_round_reward()?;
+        let bridges = detail::get_validator_public_key_bridges(self)?;
+        let record_delegator_rewards = self.record_delegator_rewards();
+        let mut accrued_rewards = detail::get_delegator_rewards(self)?;
+        let mut rewards_accrued = false;
 
         let mut era_info = EraInfo::new();
         let seigniorage_allocations = era_info.seigniorage_allocations_mut();
 
         for (public_key, reward_factor) in reward_factors {
+            // Rewards earned under a previous public key go to the bid's current public key.
+            let bid_public_key = detail::resolve_validator_public_key(&bridges, public_key.clone());
+
             if reward_factor == 0 {
-                let allocation = SeigniorageAllocation::validator(public_key.clone(), U512::zero());
+                let allocation = SeigniorageAllocation::validator(bid_public_key, U512::zero());
                 seigniorage_allocations.push(allocation);
                 continue;
             }
@@ -619,12 +631,12 @@ pub trait Auction:
             let delegator_payouts = detail::reinvest_delegator_rewards(
                 self,
                 seigniorage_allocations,
-                public_key.clone(),
+                bid_public_key.clone(),
                 delegator_rewards,
             )?;
             let total_delegator_payout: U512 = delegator_payouts
                 .iter()
-                .map(|(_delegator_hash, amount, _bonding_purse)| *amount)
+                .map(|(_delegator_public_key, amount, _bonding_purse)| *amount)
                 .sum();
 
             let validators_part: Ratio<U512> = total_reward - Ratio::from(total_delegator_payout);
@@ -632,19 +644,36 @@ pub trait Auction:
             let validator_bonding_purse = detail::reinvest_validator_reward(
                 self,
                 seigniorage_allocations,
-                public_key.clone(),
+                bid_public_key.clone(),
                 validator_reward,
             )?;
 
             self.mint_into_existing_purse(validator_reward, validator_bonding_purse)
                 .map_err(Error::from)?;
 
-            for (_delegator_account_hash, delegator_payout, bonding_purse) in delegator_payouts {
+            for (delegator_public_key, delegator_payout, bonding_purse) in delegator_payouts {
                 self.mint_into_existing_purse(delegator_payout, bonding_purse)
                     .map_err(Error::from)?;
+
+                // Payouts are still reinvested, but remain withdrawable until claimed.
+                if record_delegator_rewards && !delegator_payout.is_zero() {
+                    let accrued = accrued_rewards
+                        .entry(delegator_public_key)
+                        .or_default()
+                        .entry(bid_public_key.clone())
+                        .or_default();
+                    *accrued = accrued
+                        .checked_add(delegator_payout)
+                        .ok_or(Error::ArithmeticOverflow)?;
+                    rewards_accrued = true;
+                }
             }
         }
 
+        if rewards_accrued {
+            detail::set_delegator_rewards(self, accrued_rewards)?;
+        }
+
         self.record_era_info(EraId::new(u64::MAX), era_info)?;
 
         Ok(())
@@ -676,4 +705,242 @@ pub trait Auction:
 
         Ok(())
     }
+
+    /// Moves the bid of a validator, along with all of its delegations, to a new public key.
+    ///
+    /// An inactive, empty bid is left behind under the previous public key.  Rewards for the eras
+    /// the validator was already selected for under its previous public key are paid to the
+    /// moved bid.
+    fn change_bid_public_key(
+        &mut self,
+        public_key: PublicKey,
+        new_public_key: PublicKey,
+    ) -> Result<(), Error> {
+        if !self.allow_auction_bids() {
+            // Validation set rotation might be disabled on some private chains and we should not
+            // allow bids to change hands.
+            return Err(Error::AuctionBidsDisabled);
+        }
+
+        let validator_account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+
+        if !self.is_allowed_session_caller(&validator_account_hash) {
+            return Err(Error::InvalidContext);
+        }
+
+        let mut bridges = detail::get_validator_public_key_bridges(self)?;
+        if bridges.contains_key(&public_key) {
+            // The bid under this public key has already been moved.
+            return Err(Error::ValidatorNotFound);
+        }
+
+        let mut bid = self
+            .read_bid(&validator_account_hash)?
+            .ok_or(Error::ValidatorNotFound)?;
+
+        let new_validator_account_hash =
+            AccountHash::from_public_key(&new_public_key, |x| self.blake2b(x));
+        if self.read_bid(&new_validator_account_hash)?.is_some() {
+            return Err(Error::ValidatorBidExistsAlready);
+        }
+
+        let previous_bid = Bid::empty(public_key.clone(), *bid.bonding_purse());
+        bid.with_validator_public_key(new_public_key.clone());
+
+        self.write_bid(validator_account_hash, previous_bid)?;
+        self.write_bid(new_validator_account_hash, bid)?;
+
+        // Pending unbonds are held under the validator's account hash, so move them along with the
+        // bid for them to be found by a later slash.
+        let mut unbonding_purses = self.read_unbond(&validator_account_hash)?;
+        if !unbonding_purses.is_empty() {
+            for unbonding_purse in unbonding_purses.iter_mut() {
+                unbonding_purse.with_validator_public_key(new_public_key.clone());
+            }
+            let mut new_unbonding_purses = self.read_unbond(&new_validator_account_hash)?;
+            new_unbonding_purses.append(&mut unbonding_purses);
+            self.write_unbond(new_validator_account_hash, new_unbonding_purses)?;
+            self.write_unbond(validator_account_hash, Vec::new())?;
+        }
+
+        let mut reservations = detail::get_reservations(self, &public_key)?;
+        if !reservations.is_empty() {
+            for reservation in reservations.iter_mut() {
+                reservation.with_validator_public_key(new_public_key.clone());
+            }
+            let mut new_reservations = detail::get_reservations(self, &new_public_key)?;
+            new_reservations.append(&mut reservations);
+            detail::set_reservations(self, &new_public_key, new_reservations)?;
+            detail::set_reservations(self, &public_key, Vec::new())?;
+        }
+
+        let mut accrued_rewards = detail::get_delegator_rewards(self)?;
+        let mut moved_rewards = false;
+        for delegator_rewards in accrued_rewards.values_mut() {
+            if let Some(reward) = delegator_rewards.remove(&public_key) {
+                *delegator_rewards.entry(new_public_key.clone()).or_default() += reward;
+                moved_rewards = true;
+            }
+        }
+        if moved_rewards {
+            detail::set_delegator_rewards(self, accrued_rewards)?;
+        }
+
+        // Keep every bridge pointing directly at the bid's current public key.
+        for bridged_public_key in bridges.values_mut() {
+            if *bridged_public_key == public_key {
+                *bridged_public_key = new_public_key.clone();
+            }
+        }
+        bridges.insert(public_key, new_public_key);
+        detail::set_validator_public_key_bridges(self, bridges)
+    }
+
+    /// Reserves delegator slots of validators for the given delegators.  Only a validator can
+    /// reserve its own slots.
+    ///
+    /// A reserved slot counts against the maximum number of delegators per validator even before
+    /// the delegator delegates, so the reservations of a validator together with its delegators
+    /// occupying unreserved slots can't exceed that maximum.
+    fn add_reservations(
+        &mut self,
+        reservations: Vec<Reservation>,
+        max_delegators_per_validator: Option<u32>,
+    ) -> Result<(), Error> {
+        let mut validators: BTreeMap<PublicKey, (Bid, Vec<Reservation>)> = BTreeMap::new();
+
+        for reservation in reservations {
+            let validator_public_key = reservation.validator_public_key().clone();
+            if !validators.contains_key(&validator_public_key) {
+                let validator_account_hash =
+                    AccountHash::from_public_key(&validator_public_key, |x| self.blake2b(x));
+                if !self.is_allowed_session_caller(&validator_account_hash) {
+                    return Err(Error::InvalidContext);
+                }
+                let bid = self
+                    .read_bid(&validator_account_hash)?
+                    .ok_or(Error::ValidatorNotFound)?;
+                let validator_reservations = detail::get_reservations(self, &validator_public_key)?;
+                validators.insert(validator_public_key.clone(), (bid, validator_reservations));
+            }
+
+            let (_bid, validator_reservations) = validators
+                .get_mut(&validator_public_key)
+                .ok_or(Error::ValidatorNotFound)?;
+            if !validator_reservations.iter().any(|existing| {
+                existing.delegator_public_key() == reservation.delegator_public_key()
+            }) {
+                validator_reservations.push(reservation);
+            }
+        }
+
+        if let Some(max_delegators_per_validator) = max_delegators_per_validator {
+            for (bid, validator_reservations) in validators.values() {
+                if detail::occupied_delegator_slots(bid, validator_reservations)
+                    > max_delegators_per_validator as usize
+                {
+                    return Err(Error::ExceededReservationsLimit);
+                }
+            }
+        }
+
+        for (validator_public_key, (_bid, validator_reservations)) in validators {
+            detail::set_reservations(self, &validator_public_key, validator_reservations)?;
+        }
+        Ok(())
+    }
+
+    /// Releases delegator slots a validator reserved for the given delegators.  Delegators which
+    /// already delegated keep their delegations.
+    fn cancel_reservations(
+        &mut self,
+        validator_public_key: PublicKey,
+        delegators: Vec<PublicKey>,
+    ) -> Result<(), Error> {
+        let validator_account_hash =
+            AccountHash::from_public_key(&validator_public_key, |x| self.blake2b(x));
+        if !self.is_allowed_session_caller(&validator_account_hash) {
+            return Err(Error::InvalidContext);
+        }
+
+        let mut validator_reservations = detail::get_reservations(self, &validator_public_key)?;
+
+        for delegator_public_key in delegators {
+            let index = validator_reservations
+                .iter()
+                .position(|reservation| reservation.delegator_public_key() == &delegator_public_key)
+                .ok_or(Error::ReservationNotFound)?;
+            validator_reservations.remove(index);
+        }
+
+        detail::set_reservations(self, &validator_public_key, validator_reservations)
+    }
+
+    /// Withdraws the rewards a delegator accrued with each of its validators since it last
+    /// withdrew them, by moving them from its stakes into unbonding purses.
+    ///
+    /// Returns the amount withdrawn from each validator.  A reward can only be withdrawn as far as
+    /// it is still staked, so rewards which were undelegated in the meantime are forfeited.
+    fn withdraw_delegator_rewards(
+        &mut self,
+        delegator_public_key: PublicKey,
+    ) -> Result<BTreeMap<PublicKey, U512>, Error> {
+        let provided_account_hash =
+            AccountHash::from_public_key(&delegator_public_key, |x| self.blake2b(x));
+
+        if !self.is_allowed_session_caller(&provided_account_hash) {
+            return Err(Error::InvalidContext);
+        }
+
+        let mut accrued_rewards = detail::get_delegator_rewards(self)?;
+        let delegator_rewards = match accrued_rewards.remove(&delegator_public_key) {
+            Some(delegator_rewards) => delegator_rewards,
+            None => return Ok(BTreeMap::new()),
+        };
+
+        let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
+        let mut withdrawn = BTreeMap::new();
+
+        for (validator_public_key, accrued) in delegator_rewards {
+            let validator_account_hash = AccountHash::from(&validator_public_key);
+
+            let mut bid = match self.read_bid(&validator_account_hash)? {
+                Some(bid) => bid,
+                None => continue,
+            };
+
+            let delegators = bid.delegators_mut();
+            let delegator = match delegators.get_mut(&delegator_public_key) {
+                Some(delegator) => delegator,
+                None => continue,
+            };
+
+            let amount = accrued.min(*delegator.staked_amount());
+            if amount.is_zero() {
+                continue;
+            }
+
+            detail::create_unbonding_purse(
+                self,
+                validator_public_key.clone(),
+                delegator_public_key.clone(),
+                *delegator.bonding_purse(),
+                amount,
+                None,
+            )?;
+
+            let updated_stake = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
+            if updated_stake.is_zero() {
+                delegators.remove(&delegator_public_key);
+            }
+
+            self.write_bid(validator_account_hash, bid)?;
+
+            withdrawn.insert(validator_public_key, amount);
+        }
+
+        detail::set_delegator_rewards(self, accrued_rewards)?;
+
+        Ok(withdrawn)
+    }
 }
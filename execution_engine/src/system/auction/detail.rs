@@ -6,17 +6,18 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{
-        Bids, Delegator, Error, SeigniorageAllocation, SeigniorageRecipientsSnapshot,
-        UnbondingPurse, UnbondingPurses, AUCTION_DELAY_KEY, ERA_END_TIMESTAMP_MILLIS_KEY,
-        ERA_ID_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+        Bids, DelegationRate, DelegationRateChanges, Delegator, DelegatorRewards, Error,
+        Reservation, SeigniorageAllocation, SeigniorageRecipientsSnapshot, UnbondingPurse,
+        UnbondingPurses, ValidatorWeights, AUCTION_DELAY_KEY, DELEGATION_RATE_CHANGES_KEY,
+        DELEGATOR_REWARDS_KEY, ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY,
+        SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, VALIDATOR_PUBLIC_KEY_BRIDGES_KEY,
+        VALIDATOR_SLOTS_KEY,
     },
     ApiError, CLTyped, EraId, Key, KeyTag, PublicKey, URef, U512,
 };
 use tracing::error;
 
-use super::{
-    Auction, Bid, EraValidators, MintProvider, RuntimeProvider, StorageProvider, ValidatorWeights,
-};
+use super::{Auction, Bid, EraValidators, MintProvider, RuntimeProvider, StorageProvider};
 
 fn read_from<P, T>(provider: &mut P, name: &str) -> Result<T, Error>
 where
@@ -160,6 +161,168 @@ where
     write_to(provider, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, snapshot)
 }
 
+/// Returns the validator public key bridges, mapping the previous public key of each validator
+/// which moved its bid to the new public key.
+pub fn get_validator_public_key_bridges<P>(
+    provider: &mut P,
+) -> Result<BTreeMap<PublicKey, PublicKey>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    // The named key is only created once the first bid is moved.
+    if provider
+        .named_keys_get(VALIDATOR_PUBLIC_KEY_BRIDGES_KEY)
+        .is_none()
+    {
+        return Ok(BTreeMap::new());
+    }
+    read_from(provider, VALIDATOR_PUBLIC_KEY_BRIDGES_KEY)
+}
+
+pub fn set_validator_public_key_bridges<P>(
+    provider: &mut P,
+    bridges: BTreeMap<PublicKey, PublicKey>,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if provider
+        .named_keys_get(VALIDATOR_PUBLIC_KEY_BRIDGES_KEY)
+        .is_none()
+    {
+        let uref = provider.new_uref(bridges)?;
+        return provider.put_key(VALIDATOR_PUBLIC_KEY_BRIDGES_KEY, uref.into());
+    }
+    write_to(provider, VALIDATOR_PUBLIC_KEY_BRIDGES_KEY, bridges)
+}
+
+/// Returns the public key the bid of the validator known as `public_key` currently lives under.
+pub(crate) fn resolve_validator_public_key(
+    bridges: &BTreeMap<PublicKey, PublicKey>,
+    public_key: PublicKey,
+) -> PublicKey {
+    match bridges.get(&public_key) {
+        Some(new_public_key) => new_public_key.clone(),
+        None => public_key,
+    }
+}
+
+/// Returns the delegator slots reserved by the given validator.
+pub fn get_reservations<P>(
+    provider: &mut P,
+    validator_public_key: &PublicKey,
+) -> Result<Vec<Reservation>, Error>
+where
+    P: StorageProvider + ?Sized,
+{
+    provider.read_reservations(&AccountHash::from(validator_public_key))
+}
+
+pub fn set_reservations<P>(
+    provider: &mut P,
+    validator_public_key: &PublicKey,
+    reservations: Vec<Reservation>,
+) -> Result<(), Error>
+where
+    P: StorageProvider + ?Sized,
+{
+    provider.write_reservations(AccountHash::from(validator_public_key), reservations)
+}
+
+/// Returns the rewards each delegator accrued and hasn't withdrawn yet.
+pub fn get_delegator_rewards<P>(provider: &mut P) -> Result<DelegatorRewards, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    // The named key is only created once the first reward is distributed to a delegator.
+    if provider.named_keys_get(DELEGATOR_REWARDS_KEY).is_none() {
+        return Ok(BTreeMap::new());
+    }
+    read_from(provider, DELEGATOR_REWARDS_KEY)
+}
+
+pub fn set_delegator_rewards<P>(
+    provider: &mut P,
+    delegator_rewards: DelegatorRewards,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if provider.named_keys_get(DELEGATOR_REWARDS_KEY).is_none() {
+        let uref = provider.new_uref(delegator_rewards)?;
+        return provider.put_key(DELEGATOR_REWARDS_KEY, uref.into());
+    }
+    write_to(provider, DELEGATOR_REWARDS_KEY, delegator_rewards)
+}
+
+/// Returns the era in which each validator last increased its delegation rate.
+pub fn get_delegation_rate_changes<P>(provider: &mut P) -> Result<DelegationRateChanges, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    // The named key is only created once a validator first increases its delegation rate while
+    // a cooldown is configured.
+    if provider
+        .named_keys_get(DELEGATION_RATE_CHANGES_KEY)
+        .is_none()
+    {
+        return Ok(BTreeMap::new());
+    }
+    read_from(provider, DELEGATION_RATE_CHANGES_KEY)
+}
+
+pub fn set_delegation_rate_changes<P>(
+    provider: &mut P,
+    delegation_rate_changes: DelegationRateChanges,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if provider
+        .named_keys_get(DELEGATION_RATE_CHANGES_KEY)
+        .is_none()
+    {
+        let uref = provider.new_uref(delegation_rate_changes)?;
+        return provider.put_key(DELEGATION_RATE_CHANGES_KEY, uref.into());
+    }
+    write_to(
+        provider,
+        DELEGATION_RATE_CHANGES_KEY,
+        delegation_rate_changes,
+    )
+}
+
+/// Checks a validator may raise its delegation rate by `increase`, and if a cooldown is
+/// configured, records the current era as that of its last increase.
+pub fn check_delegation_rate_increase<P>(
+    provider: &mut P,
+    public_key: &PublicKey,
+    increase: DelegationRate,
+    delegation_rate_change_cooldown: u64,
+    max_delegation_rate_increase: DelegationRate,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if increase > max_delegation_rate_increase {
+        return Err(Error::DelegationRateIncreaseTooLarge);
+    }
+
+    if delegation_rate_change_cooldown == 0 {
+        return Ok(());
+    }
+
+    let era_id = get_era_id(provider)?;
+    let mut delegation_rate_changes = get_delegation_rate_changes(provider)?;
+    if let Some(last_increase_era_id) = delegation_rate_changes.get(public_key) {
+        if era_id < last_increase_era_id.saturating_add(delegation_rate_change_cooldown) {
+            return Err(Error::DelegationRateChangeCooldown);
+        }
+    }
+    delegation_rate_changes.insert(public_key.clone(), era_id);
+    set_delegation_rate_changes(provider, delegation_rate_changes)
+}
+
 pub fn get_validator_slots<P>(provider: &mut P) -> Result<usize, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -171,6 +334,63 @@ where
     Ok(validator_slots)
 }
 
+/// Selects the winners of an auction run at `era_end_timestamp_millis`: every active validator
+/// whose stake is still locked by its vesting schedule, followed by the active validators with the
+/// largest total stakes filling the remaining `validator_slots`.
+pub(crate) fn select_auction_winners(
+    bids: &Bids,
+    era_end_timestamp_millis: u64,
+    vesting_schedule_period_millis: u64,
+    validator_slots: usize,
+) -> Result<ValidatorWeights, Error> {
+    let locked_validators: ValidatorWeights = bids
+        .iter()
+        .filter(|(_public_key, bid)| {
+            bid.is_locked_with_vesting_schedule(
+                era_end_timestamp_millis,
+                vesting_schedule_period_millis,
+            ) && !bid.inactive()
+        })
+        .map(|(public_key, bid)| {
+            let total_staked_amount = bid.total_staked_amount()?;
+            Ok((public_key.clone(), total_staked_amount))
+        })
+        .collect::<Result<ValidatorWeights, Error>>()?;
+
+    // We collect these into a vec for sorting
+    let mut unlocked_validators: Vec<(PublicKey, U512)> = bids
+        .iter()
+        .filter(|(_public_key, bid)| {
+            !bid.is_locked_with_vesting_schedule(
+                era_end_timestamp_millis,
+                vesting_schedule_period_millis,
+            ) && !bid.inactive()
+        })
+        .map(|(public_key, bid)| {
+            let total_staked_amount = bid.total_staked_amount()?;
+            Ok((public_key.clone(), total_staked_amount))
+        })
+        .collect::<Result<Vec<(PublicKey, U512)>, Error>>()?;
+
+    unlocked_validators.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
+
+    // This assumes that amount of founding validators does not exceed configured validator
+    // slots. For a case where there are exactly N validators and the limit is N, only
+    // founding validators will be the in the winning set. It is advised to set
+    // `validator_slots` larger than amount of founding validators in accounts.toml to
+    // accomodate non-genesis validators.
+    let remaining_auction_slots = validator_slots.saturating_sub(locked_validators.len());
+
+    Ok(locked_validators
+        .into_iter()
+        .chain(
+            unlocked_validators
+                .into_iter()
+                .take(remaining_auction_slots),
+        )
+        .collect())
+}
+
 pub fn get_auction_delay<P>(provider: &mut P) -> Result<u64, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -205,6 +425,8 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(
 
     let unbonding_delay = get_unbonding_delay(provider)?;
 
+    let bridges = get_validator_public_key_bridges(provider)?;
+
     for unbonding_list in unbonding_purses.values_mut() {
         let mut new_unbonding_list = Vec::new();
         for unbonding_purse in unbonding_list.iter() {
@@ -212,14 +434,22 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(
             // current era id + unbonding delay is equal or greater than the `era_of_creation` that
             // was calculated on `unbond` attempt.
             if current_era_id >= unbonding_purse.era_of_creation() + unbonding_delay {
-                match unbonding_purse.new_validator() {
+                // A redelegation follows the bid of its target if it has moved since.
+                let new_validator = unbonding_purse
+                    .new_validator()
+                    .clone()
+                    .map(|new_validator| resolve_validator_public_key(&bridges, new_validator));
+                match &new_validator {
                     Some(new_validator) => {
                         match provider.read_bid(&new_validator.to_account_hash()) {
                             Ok(Some(new_validator_bid)) => {
                                 if !new_validator_bid.staked_amount().is_zero() {
-                                    if is_under_max_delegator_cap(
+                                    let reservations = get_reservations(provider, new_validator)?;
+                                    if has_delegator_slot(
+                                        &new_validator_bid,
+                                        &reservations,
+                                        unbonding_purse.unbonder_public_key(),
                                         max_delegators_per_validator,
-                                        new_validator_bid.delegators().len(),
                                     ) {
                                         handle_delegation(
                                             provider,
@@ -314,7 +544,7 @@ pub fn reinvest_delegator_rewards<P>(
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     rewards: impl Iterator<Item = (PublicKey, Ratio<U512>)>,
-) -> Result<Vec<(AccountHash, U512, URef)>, Error>
+) -> Result<Vec<(PublicKey, U512, URef)>, Error>
 where
     P: StorageProvider,
 {
@@ -340,7 +570,7 @@ where
         delegator.increase_stake(delegator_reward_trunc)?;
 
         delegator_payouts.push((
-            delegator_key.to_account_hash(),
+            delegator_key.clone(),
             delegator_reward_trunc,
             *delegator.bonding_purse(),
         ));
@@ -494,11 +724,37 @@ pub(crate) fn era_validators_from_snapshot(
         .collect()
 }
 
-fn is_under_max_delegator_cap(
+/// Returns the number of delegator slots of a validator which are taken, counting every reserved
+/// slot along with every delegator without a reservation.
+pub(crate) fn occupied_delegator_slots(bid: &Bid, reservations: &[Reservation]) -> usize {
+    let unreserved_delegators = bid
+        .delegators()
+        .keys()
+        .filter(|delegator_public_key| !is_reserved(reservations, delegator_public_key))
+        .count();
+    unreserved_delegators + reservations.len()
+}
+
+/// Returns `true` if `delegator_public_key` can delegate to the validator without exceeding the
+/// maximum number of delegators per validator.
+pub(crate) fn has_delegator_slot(
+    bid: &Bid,
+    reservations: &[Reservation],
+    delegator_public_key: &PublicKey,
     max_delegators_per_validator: Option<u32>,
-    new_validator_delegator_len: usize,
 ) -> bool {
+    if bid.delegators().contains_key(delegator_public_key)
+        || is_reserved(reservations, delegator_public_key)
+    {
+        return true;
+    }
     max_delegators_per_validator
-        .map(|limit| new_validator_delegator_len < limit as usize)
+        .map(|limit| occupied_delegator_slots(bid, reservations) < limit as usize)
         .unwrap_or(true)
 }
+
+fn is_reserved(reservations: &[Reservation], delegator_public_key: &PublicKey) -> bool {
+    reservations
+        .iter()
+        .any(|reservation| reservation.delegator_public_key() == delegator_public_key)
+}
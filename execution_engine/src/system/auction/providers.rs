@@ -4,7 +4,7 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::{
-        auction::{Bid, EraInfo, Error, UnbondingPurse},
+        auction::{Bid, EraInfo, Error, Reservation, UnbondingPurse},
         mint,
     },
     CLTyped, EraId, Key, KeyTag, URef, BLAKE2B_DIGEST_LENGTH, U512,
@@ -21,6 +21,9 @@ pub trait RuntimeProvider {
     /// Gets named key under a `name`.
     fn named_keys_get(&self, name: &str) -> Option<Key>;
 
+    /// Puts key under a `name`.
+    fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error>;
+
     /// Gets keys in a given keyspace
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error>;
 
@@ -32,10 +35,16 @@ pub trait RuntimeProvider {
 
     /// Check if auction bids are allowed.
     fn allow_auction_bids(&self) -> bool;
+
+    /// Check if rewards paid out to delegators are recorded as withdrawable.
+    fn record_delegator_rewards(&self) -> bool;
 }
 
 /// Provides functionality of a contract storage.
 pub trait StorageProvider {
+    /// Create new [`URef`] initialized with the provided value.
+    fn new_uref<T: ToBytes + CLTyped>(&mut self, init: T) -> Result<URef, Error>;
+
     /// Reads data from [`URef`].
     fn read<T: FromBytes + CLTyped>(&mut self, uref: URef) -> Result<Option<T>, Error>;
 
@@ -58,6 +67,16 @@ pub trait StorageProvider {
         unbonding_purses: Vec<UnbondingPurse>,
     ) -> Result<(), Error>;
 
+    /// Reads the delegator slots reserved by the validator with the given account hash.
+    fn read_reservations(&mut self, account_hash: &AccountHash) -> Result<Vec<Reservation>, Error>;
+
+    /// Writes the delegator slots reserved by the validator with the given account hash.
+    fn write_reservations(
+        &mut self,
+        account_hash: AccountHash,
+        reservations: Vec<Reservation>,
+    ) -> Result<(), Error>;
+
     /// Records era summary.
     fn record_era_info(&mut self, _era_id: EraId, era_summary: EraInfo) -> Result<(), Error>;
 }
@@ -3,7 +3,11 @@ pub(crate) mod mint_provider;
 pub(crate) mod runtime_provider;
 pub(crate) mod storage_provider;
 
-use casper_types::{account::AccountHash, system::handle_payment::Error, AccessRights, URef, U512};
+use std::collections::BTreeSet;
+
+use casper_types::{
+    account::AccountHash, system::handle_payment::Error, AccessRights, EraId, PublicKey, URef, U512,
+};
 
 use crate::system::handle_payment::{
     mint_provider::MintProvider, runtime_provider::RuntimeProvider,
@@ -43,8 +47,13 @@ pub trait HandlePayment: MintProvider + RuntimeProvider + StorageProvider + Size
         internal::finalize_payment(self, amount_spent, account, target)
     }
 
-    /// Distribute fees from an accumulation purse.
-    fn distribute_accumulated_fees(&mut self) -> Result<(), Error> {
-        internal::distribute_accumulated_fees(self)
+    /// Distribute fees from an accumulation purse at the end of the era preceding `next_era_id`,
+    /// which was validated by `validators`.
+    fn distribute_accumulated_fees(
+        &mut self,
+        next_era_id: EraId,
+        validators: BTreeSet<PublicKey>,
+    ) -> Result<(), Error> {
+        internal::distribute_accumulated_fees(self, next_era_id, validators)
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use num::{CheckedMul, One};
 use num_rational::Ratio;
 use tracing::error;
@@ -5,7 +7,7 @@ use tracing::error;
 use casper_types::{
     account::AccountHash,
     system::handle_payment::{Error, ACCUMULATION_PURSE_KEY, PAYMENT_PURSE_KEY, REFUND_PURSE_KEY},
-    Key, Phase, PublicKey, URef, U512,
+    EraId, Key, Phase, PublicKey, SystemTransferKind, URef, U512,
 };
 
 use super::{
@@ -138,7 +140,13 @@ pub(crate) fn finalize_payment<P: MintProvider + RuntimeProvider + StorageProvid
                         // account's main purse
                         match provider.transfer_purse_to_purse(payment_purse, refund_purse, refund)
                         {
-                            Ok(()) => {}
+                            Ok(()) => provider.record_payment_transfer(
+                                SystemTransferKind::PaymentRefund,
+                                None,
+                                payment_purse,
+                                Some(refund_purse),
+                                refund,
+                            )?,
                             Err(error) => {
                                 error!(
                                     %error,
@@ -179,7 +187,13 @@ pub(crate) fn finalize_payment<P: MintProvider + RuntimeProvider + StorageProvid
             // target purse is already resolved based on fee-handling config which is either a
             // proposer or accumulation purse.
             match provider.transfer_purse_to_purse(payment_purse, target, fee) {
-                Ok(()) => {}
+                Ok(()) => provider.record_payment_transfer(
+                    SystemTransferKind::PaymentFee,
+                    None,
+                    payment_purse,
+                    Some(target),
+                    fee,
+                )?,
                 Err(error) => {
                     error!(%error, %fee, %target, "unable to transfer fee");
                     return Err(Error::FailedTransferToRewardsPurse);
@@ -201,14 +215,20 @@ pub(crate) fn finalize_payment<P: MintProvider + RuntimeProvider + StorageProvid
     Ok(())
 }
 
-pub(crate) fn refund_to_account<M: MintProvider>(
+pub(crate) fn refund_to_account<M: MintProvider + RuntimeProvider>(
     mint_provider: &mut M,
     payment_purse: URef,
     account: AccountHash,
     amount: U512,
 ) -> Result<(), Error> {
     match mint_provider.transfer_purse_to_account(payment_purse, account, amount) {
-        Ok(_) => Ok(()),
+        Ok(_) => mint_provider.record_payment_transfer(
+            SystemTransferKind::PaymentRefund,
+            Some(account),
+            payment_purse,
+            None,
+            amount,
+        ),
         Err(error) => {
             error!(%error, %amount, %account, "unable to process refund from payment purse to account");
             Err(Error::FailedTransferToAccountPurse)
@@ -225,8 +245,12 @@ fn get_accumulation_purse<R: RuntimeProvider>(provider: &mut R) -> Result<URef,
     }
 }
 
-/// This function distributes the fees according to the fee handling config.
-pub(crate) fn distribute_accumulated_fees<P>(provider: &mut P) -> Result<(), Error>
+/// This function distributes the fees according to the fee handling and fee distribution config.
+pub(crate) fn distribute_accumulated_fees<P>(
+    provider: &mut P,
+    next_era_id: EraId,
+    validators: BTreeSet<PublicKey>,
+) -> Result<(), Error>
 where
     P: RuntimeProvider + MintProvider,
 {
@@ -234,24 +258,61 @@ where
         return Err(Error::SystemFunctionCalledByUserAccount);
     }
 
-    // Distribute accumulation purse balance into all administrators
     match provider.fee_handling() {
         FeeHandling::PayToProposer | FeeHandling::Burn => return Ok(()),
         FeeHandling::Accumulate => {}
     }
 
-    let administrative_accounts = provider.administrative_accounts().clone();
+    let fee_distribution = provider.fee_distribution().clone();
+    if !fee_distribution.is_distribution_era(next_era_id.value()) {
+        // Fees keep accumulating until the next distribution era.
+        return Ok(());
+    }
+
     let accumulation_purse = get_accumulation_purse(provider)?;
     let accumulated_balance = provider.balance(accumulation_purse)?.unwrap_or_default();
-    let reward_recipients = U512::from(administrative_accounts.len());
+    if accumulated_balance.is_zero()
+        || accumulated_balance < U512::from(fee_distribution.minimum_amount)
+    {
+        // The balance rolls over to the next distribution era.
+        return Ok(());
+    }
+
+    let administrators: Vec<AccountHash> =
+        provider.administrative_accounts().iter().copied().collect();
+    let validators: Vec<AccountHash> = validators.iter().map(PublicKey::to_account_hash).collect();
+    let treasury: Vec<AccountHash> = fee_distribution
+        .treasury
+        .iter()
+        .map(PublicKey::to_account_hash)
+        .collect();
+    let recipient_groups = [
+        (fee_distribution.administrators_weight, administrators),
+        (fee_distribution.validators_weight, validators),
+        (fee_distribution.treasury_weight, treasury),
+    ];
+
+    // Groups without any recipients are left out, so their share goes to the other groups.
+    let total_weight = recipient_groups
+        .iter()
+        .filter(|(_, recipients)| !recipients.is_empty())
+        .fold(U512::zero(), |total, (weight, _)| {
+            total + U512::from(*weight)
+        });
+    if total_weight.is_zero() {
+        return Ok(());
+    }
 
-    if let Some(reward_amount) = accumulated_balance.checked_div(reward_recipients) {
+    for (weight, recipients) in recipient_groups {
+        if recipients.is_empty() {
+            continue;
+        }
+        let group_amount = accumulated_balance * U512::from(weight) / total_weight;
+        let reward_amount = group_amount / U512::from(recipients.len());
         if reward_amount.is_zero() {
-            // There is zero tokens to be paid out which means we can exit early.
-            return Ok(());
+            continue;
         }
-
-        for target in administrative_accounts {
+        for target in recipients {
             provider.transfer_purse_to_account(accumulation_purse, target, reward_amount)?;
         }
     }
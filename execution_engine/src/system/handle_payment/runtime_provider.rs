@@ -1,8 +1,11 @@
 use std::collections::BTreeSet;
 
-use casper_types::{account::AccountHash, system::handle_payment::Error, BlockTime, Key, Phase};
+use casper_types::{
+    account::AccountHash, system::handle_payment::Error, BlockTime, Key, Phase, SystemTransferKind,
+    URef, U512,
+};
 
-use crate::core::engine_state::engine_config::{FeeHandling, RefundHandling};
+use crate::core::engine_state::engine_config::{FeeDistribution, FeeHandling, RefundHandling};
 
 /// Provider of runtime host functionality.
 pub trait RuntimeProvider {
@@ -30,6 +33,20 @@ pub trait RuntimeProvider {
     /// Returns fee handling value.
     fn fee_handling(&self) -> FeeHandling;
 
+    /// Returns the distribution of accumulated fees.
+    fn fee_distribution(&self) -> &FeeDistribution;
+
     /// Returns list of administrative accounts.
     fn administrative_accounts(&self) -> &BTreeSet<AccountHash>;
+
+    /// Records a transfer of `amount` out of the payment purse made while finalizing payment, into
+    /// `target`, or into the main purse of `to` if `target` is `None`.
+    fn record_payment_transfer(
+        &mut self,
+        kind: SystemTransferKind,
+        to: Option<AccountHash>,
+        source: URef,
+        target: Option<URef>,
+        amount: U512,
+    ) -> Result<(), Error>;
 }
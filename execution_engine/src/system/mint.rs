@@ -2,6 +2,8 @@ pub(crate) mod runtime_provider;
 pub(crate) mod storage_provider;
 pub(crate) mod system_provider;
 
+use std::collections::BTreeSet;
+
 use num_rational::Ratio;
 use num_traits::CheckedMul;
 use tracing::warn;
@@ -9,14 +11,14 @@ use tracing::warn;
 use casper_types::{
     account::AccountHash,
     system::{
-        mint::{Error, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_SUPPLY_KEY},
+        mint::{Error, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_BURNED_KEY, TOTAL_SUPPLY_KEY},
         CallStackElement,
     },
     Key, Phase, PublicKey, StoredValue, URef, U512,
 };
 
 use crate::{
-    core::engine_state::SystemContractRegistry,
+    core::engine_state::{engine_config::ExistentialDepositHandling, SystemContractRegistry},
     system::mint::{
         runtime_provider::RuntimeProvider, storage_provider::StorageProvider,
         system_provider::SystemProvider,
@@ -37,6 +39,21 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         let purse_uref: URef = self.new_uref(())?;
         self.write_balance(purse_uref, initial_balance)?;
 
+        if self.enable_purse_ownership_index() {
+            // The purse is owned by whoever called into the mint to create it.
+            let owner = match self.get_immediate_caller() {
+                Some(CallStackElement::StoredContract { contract_hash, .. }) => {
+                    Key::from(*contract_hash)
+                }
+                Some(CallStackElement::Session { account_hash })
+                | Some(CallStackElement::StoredSession { account_hash, .. }) => {
+                    Key::Account(*account_hash)
+                }
+                None => Key::Account(caller),
+            };
+            self.write_purse_owner(purse_uref, owner)?;
+        }
+
         if !is_empty_purse {
             // get total supply uref if exists, otherwise error
             let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
@@ -45,7 +62,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
                     return Err(Error::TotalSupplyNotFound);
                 }
                 Some(Key::URef(uref)) => uref,
-                Some(_) => return Err(Error::MissingKey),
+                Some(_) => return Err(Error::UnexpectedKeyVariant),
             };
             // increase total supply
             self.add(total_supply_uref, initial_balance)?;
@@ -54,7 +71,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         Ok(purse_uref)
     }
 
-    /// Reduce total supply by `amount`. Returns unit on success, otherwise
+    /// Reduce total supply by `amount`, recording it as burned. Returns unit on success, otherwise
     /// an error.
     fn reduce_total_supply(&mut self, amount: U512) -> Result<(), Error> {
         // only system may reduce total supply
@@ -70,7 +87,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         // get total supply or error
         let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
             Some(Key::URef(uref)) => uref,
-            Some(_) => return Err(Error::MissingKey), // TODO
+            Some(_) => return Err(Error::UnexpectedKeyVariant),
             None => return Err(Error::MissingKey),
         };
         let total_supply: U512 = self
@@ -85,6 +102,13 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         // update total supply
         self.write(total_supply_uref, reduced_total_supply)?;
 
+        // record the burn; the counter is created at genesis or on upgrading an existing network
+        match self.get_key(TOTAL_BURNED_KEY) {
+            Some(Key::URef(total_burned_uref)) => self.add(total_burned_uref, amount)?,
+            Some(_) => return Err(Error::UnexpectedKeyVariant),
+            None => return Err(Error::MissingKey),
+        }
+
         Ok(())
     }
 
@@ -243,12 +267,33 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         if self.read_balance(target)?.is_none() {
             return Err(Error::DestNotFound);
         }
+        let mut amount = amount;
         if self.get_caller() != PublicKey::System.to_account_hash()
             && self.get_main_purse().addr() == source.addr()
         {
             if amount > self.get_approved_spending_limit() {
                 return Err(Error::UnapprovedSpendingAmount);
             }
+
+            // System purses are never an account's main purse, and payment is exempt so that an
+            // account can always pay for its deploys.
+            let remaining = source_balance - amount;
+            if self.get_phase() != Phase::Payment
+                && !remaining.is_zero()
+                && remaining < self.existential_deposit()
+            {
+                match self.existential_deposit_handling() {
+                    ExistentialDepositHandling::Sweep
+                        if source_balance <= self.get_approved_spending_limit() =>
+                    {
+                        amount = source_balance
+                    }
+                    // The remainder can only be swept along if the account approved spending it.
+                    ExistentialDepositHandling::Sweep | ExistentialDepositHandling::Reject => {
+                        return Err(Error::BelowExistentialDeposit)
+                    }
+                }
+            }
             self.sub_approved_spending_limit(amount);
         }
         self.write_balance(source, source_balance - amount)?;
@@ -261,7 +306,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
     fn read_base_round_reward(&mut self) -> Result<U512, Error> {
         let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
             Some(Key::URef(uref)) => uref,
-            Some(_) => return Err(Error::MissingKey), // TODO
+            Some(_) => return Err(Error::UnexpectedKeyVariant),
             None => return Err(Error::MissingKey),
         };
         let total_supply: U512 = self
@@ -270,7 +315,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
 
         let round_seigniorage_rate_uref = match self.get_key(ROUND_SEIGNIORAGE_RATE_KEY) {
             Some(Key::URef(uref)) => uref,
-            Some(_) => return Err(Error::MissingKey), // TODO
+            Some(_) => return Err(Error::UnexpectedKeyVariant),
             None => return Err(Error::MissingKey),
         };
         let round_seigniorage_rate: Ratio<U512> = self
@@ -283,6 +328,37 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
             .ok_or(Error::ArithmeticOverflow)
     }
 
+    /// Replaces the administrative accounts with `administrators`, storing them in the
+    /// administrator registry.
+    ///
+    /// Must be called directly by session code of a deploy approved by more than half of the
+    /// current administrators.  The new set applies to deploys executed after this one.
+    fn update_administrators(
+        &mut self,
+        administrators: BTreeSet<AccountHash>,
+    ) -> Result<(), Error> {
+        if !matches!(
+            self.get_immediate_caller(),
+            Some(CallStackElement::Session { .. })
+        ) {
+            return Err(Error::InvalidContext);
+        }
+        if administrators.is_empty() {
+            return Err(Error::EmptyAdministrators);
+        }
+
+        // Without any administrators, as on a public chain, a quorum can never be reached.
+        let current_administrators = self.administrative_accounts();
+        let approvals = current_administrators
+            .intersection(self.get_authorization_keys())
+            .count();
+        if approvals * 2 <= current_administrators.len() {
+            return Err(Error::InsufficientAdministratorApprovals);
+        }
+
+        self.write_administrative_accounts(administrators)
+    }
+
     /// Mint `amount` new token into `existing_purse`.
     /// Returns unit on success, otherwise an error.
     fn mint_into_existing_purse(
@@ -311,7 +387,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
                 return Err(Error::TotalSupplyNotFound);
             }
             Some(Key::URef(uref)) => uref,
-            Some(_) => return Err(Error::MissingKey),
+            Some(_) => return Err(Error::UnexpectedKeyVariant),
         };
         // increase total supply
         self.add(total_supply_uref, amount)?;
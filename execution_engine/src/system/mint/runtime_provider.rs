@@ -1,10 +1,15 @@
+use std::collections::BTreeSet;
+
 use casper_types::{
     account::AccountHash,
     system::{mint::Error, CallStackElement},
     Key, Phase, StoredValue, URef, U512,
 };
 
-use crate::core::{engine_state::SystemContractRegistry, execution};
+use crate::core::{
+    engine_state::{engine_config::ExistentialDepositHandling, SystemContractRegistry},
+    execution,
+};
 
 /// Provider of runtime host functionality.
 pub trait RuntimeProvider {
@@ -14,6 +19,9 @@ pub trait RuntimeProvider {
     /// This method should return the immediate caller of the current context.
     fn get_immediate_caller(&self) -> Option<&CallStackElement>;
 
+    /// Returns the keys which authorized the deploy being executed.
+    fn get_authorization_keys(&self) -> &BTreeSet<AccountHash>;
+
     /// Get system contract registry.
     fn get_system_contract_registry(&self) -> Result<SystemContractRegistry, execution::Error>;
 
@@ -45,7 +53,20 @@ pub trait RuntimeProvider {
     /// Returns `true` if the account hash belongs to an administrator account, otherwise `false`.
     fn is_administrator(&self, account_hash: &AccountHash) -> bool;
 
+    /// Returns the administrative accounts.
+    fn administrative_accounts(&self) -> &BTreeSet<AccountHash>;
+
     /// Checks if users can perform unrestricted transfers. This option is valid only for private
     /// chains.
     fn allow_unrestricted_transfers(&self) -> bool;
+
+    /// Returns the minimum balance an account's main purse has to retain after a transfer, unless
+    /// it is emptied entirely. A value of zero disables the check.
+    fn existential_deposit(&self) -> U512;
+
+    /// Returns how transfers breaching the existential deposit are handled.
+    fn existential_deposit_handling(&self) -> ExistentialDepositHandling;
+
+    /// Returns `true` if the owner of each newly created purse should be recorded.
+    fn enable_purse_ownership_index(&self) -> bool;
 }
@@ -1,7 +1,10 @@
+use std::collections::BTreeSet;
+
 use casper_types::{
+    account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::mint::Error,
-    CLTyped, URef, U512,
+    CLTyped, Key, URef, U512,
 };
 
 /// Provides functionality of a contract storage.
@@ -26,4 +29,13 @@ pub trait StorageProvider {
 
     /// Add amount to an existing balance.
     fn add_balance(&mut self, uref: URef, value: U512) -> Result<(), Error>;
+
+    /// Write the administrative accounts to the administrator registry.
+    fn write_administrative_accounts(
+        &mut self,
+        administrators: BTreeSet<AccountHash>,
+    ) -> Result<(), Error>;
+
+    /// Records `owner` as the owner of `purse` in the purse ownership index.
+    fn write_purse_owner(&mut self, purse: URef, owner: Key) -> Result<(), Error>;
 }
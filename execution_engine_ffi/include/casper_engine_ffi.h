@@ -0,0 +1,83 @@
+/*
+ * C ABI for embedding the Casper execution engine.
+ *
+ * Every function returns a CasperEngineStatus.  When it isn't CASPER_ENGINE_OK, a description of
+ * the error can be retrieved with casper_engine_last_error.  Buffers returned by the library must
+ * be released with casper_engine_buffer_free.  A CasperEngine handle must not be used from more
+ * than one thread at a time.
+ */
+
+#ifndef CASPER_ENGINE_FFI_H
+#define CASPER_ENGINE_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum CasperEngineStatus {
+    CASPER_ENGINE_OK = 0,
+    CASPER_ENGINE_NULL_POINTER = 1,
+    CASPER_ENGINE_INVALID_UTF8 = 2,
+    CASPER_ENGINE_INVALID_REQUEST = 3,
+    CASPER_ENGINE_ROOT_NOT_FOUND = 4,
+    CASPER_ENGINE_VALUE_NOT_FOUND = 5,
+    CASPER_ENGINE_NOTHING_TO_COMMIT = 6,
+    CASPER_ENGINE_ENGINE_ERROR = 7,
+    CASPER_ENGINE_STORAGE_ERROR = 8,
+    CASPER_ENGINE_PANIC = 9,
+} CasperEngineStatus;
+
+typedef struct CasperEngineConfig {
+    uint64_t max_db_size;
+    uint32_t max_readers;
+    bool manual_sync_enabled;
+} CasperEngineConfig;
+
+typedef struct CasperEngineDigest {
+    uint8_t bytes[32];
+} CasperEngineDigest;
+
+typedef struct CasperEngineBuffer {
+    uint8_t *data;
+    size_t len;
+} CasperEngineBuffer;
+
+typedef struct CasperEngine CasperEngine;
+
+CasperEngineStatus casper_engine_create(const char *data_dir,
+                                        const CasperEngineConfig *config,
+                                        CasperEngine **out_engine);
+
+void casper_engine_destroy(CasperEngine *engine);
+
+CasperEngineStatus casper_engine_commit_genesis(CasperEngine *engine,
+                                                const char *genesis_request_json,
+                                                CasperEngineDigest *out_post_state_hash);
+
+CasperEngineStatus casper_engine_execute(CasperEngine *engine,
+                                         const char *execute_request_json,
+                                         CasperEngineBuffer *out_result_json);
+
+CasperEngineStatus casper_engine_commit(CasperEngine *engine,
+                                        CasperEngineDigest *out_post_state_hash);
+
+/* `path_json` may be NULL. */
+CasperEngineStatus casper_engine_query(const CasperEngine *engine,
+                                       const CasperEngineDigest *state_hash,
+                                       const char *key,
+                                       const char *path_json,
+                                       CasperEngineBuffer *out_value_json);
+
+CasperEngineStatus casper_engine_last_error(CasperEngineBuffer *out_message);
+
+void casper_engine_buffer_free(CasperEngineBuffer *buffer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CASPER_ENGINE_FFI_H */
@@ -0,0 +1,177 @@
+use std::{collections::BTreeSet, path::Path, sync::Arc};
+
+use lmdb::DatabaseFlags;
+use serde::Deserialize;
+
+use casper_execution_engine::{
+    core::engine_state::{
+        deploy_item::DeployItem, execute_request::ExecuteRequest as EngineExecuteRequest,
+        EngineConfig, EngineState, Error as EngineError, ExecutableDeployItem, QueryRequest,
+        QueryResult, RunGenesisRequest,
+    },
+    shared::{execution_journal::ExecutionJournal, newtypes::CorrelationId},
+    storage::{
+        global_state::lmdb::LmdbGlobalState, transaction_source::lmdb::LmdbEnvironment,
+        trie_store::lmdb::LmdbTrieStore,
+    },
+};
+use casper_hashing::Digest;
+use casper_types::{
+    account::AccountHash, DeployHash, ExecutionResult, Key, ProtocolVersion, PublicKey, StoredValue,
+};
+
+use crate::{error::Error, CasperEngineConfig};
+
+/// A request to execute a single deploy, as passed across the C ABI in JSON.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExecuteRequest {
+    parent_state_hash: Digest,
+    block_time: u64,
+    protocol_version: ProtocolVersion,
+    proposer: PublicKey,
+    deploy: Deploy,
+}
+
+/// A deploy to execute, as passed across the C ABI in JSON.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Deploy {
+    address: AccountHash,
+    session: ExecutableDeployItem,
+    payment: ExecutableDeployItem,
+    gas_price: u64,
+    authorization_keys: BTreeSet<AccountHash>,
+    deploy_hash: DeployHash,
+}
+
+/// An execution engine over LMDB-backed global state, along with the effects of the last deploy
+/// executed and not yet committed.
+pub(crate) struct Engine {
+    engine_state: EngineState<LmdbGlobalState>,
+    pending: Option<(Digest, ExecutionJournal)>,
+}
+
+impl Engine {
+    /// Opens the global state under `data_dir`, creating it if it doesn't exist.
+    pub(crate) fn open(data_dir: &Path, config: &CasperEngineConfig) -> Result<Self, Error> {
+        let environment = Arc::new(LmdbEnvironment::new(
+            data_dir,
+            config.max_db_size as usize,
+            config.max_readers,
+            config.manual_sync_enabled,
+        )?);
+        let trie_store = Arc::new(LmdbTrieStore::new(
+            &environment,
+            None,
+            DatabaseFlags::empty(),
+        )?);
+        let global_state = LmdbGlobalState::empty(environment, trie_store)?;
+        Ok(Engine {
+            engine_state: EngineState::new(global_state, EngineConfig::default()),
+            pending: None,
+        })
+    }
+
+    /// Runs and commits genesis, returning the genesis state root hash.
+    pub(crate) fn commit_genesis(&mut self, request: RunGenesisRequest) -> Result<Digest, Error> {
+        let genesis_success = self.engine_state.commit_genesis(
+            CorrelationId::new(),
+            request.genesis_config_hash(),
+            request.protocol_version(),
+            request.ee_config(),
+            request.chainspec_registry().clone(),
+        )?;
+        self.flush()?;
+        Ok(genesis_success.post_state_hash)
+    }
+
+    /// Executes a deploy, keeping its effects to be committed by [`Engine::commit`].
+    ///
+    /// The effects of a previously executed deploy which weren't committed are discarded.
+    pub(crate) fn execute(&mut self, request: ExecuteRequest) -> Result<ExecutionResult, Error> {
+        let ExecuteRequest {
+            parent_state_hash,
+            block_time,
+            protocol_version,
+            proposer,
+            deploy,
+        } = request;
+        let deploy_item = DeployItem::new(
+            deploy.address,
+            deploy.session,
+            deploy.payment,
+            deploy.gas_price,
+            deploy.authorization_keys,
+            deploy.deploy_hash,
+        );
+        let execute_request = EngineExecuteRequest::new(
+            parent_state_hash,
+            block_time,
+            vec![deploy_item],
+            protocol_version,
+            proposer,
+        );
+
+        self.pending = None;
+        let execution_result = match self
+            .engine_state
+            .run_execute(CorrelationId::new(), execute_request)
+        {
+            Ok(mut execution_results) => execution_results
+                .pop_front()
+                .ok_or_else(|| Error::InvalidRequest("no deploy executed".to_string()))?,
+            Err(EngineError::RootNotFound(root)) => return Err(Error::RootNotFound(root)),
+            Err(error) => return Err(error.into()),
+        };
+        self.pending = Some((
+            parent_state_hash,
+            execution_result.execution_journal().clone(),
+        ));
+        Ok(ExecutionResult::from(execution_result))
+    }
+
+    /// Commits the effects of the last executed deploy onto the state it was executed against,
+    /// returning the new state root hash.
+    pub(crate) fn commit(&mut self) -> Result<Digest, Error> {
+        let (pre_state_hash, execution_journal) =
+            self.pending.take().ok_or(Error::NothingToCommit)?;
+        let post_state_hash = self.engine_state.apply_effect(
+            CorrelationId::new(),
+            pre_state_hash,
+            execution_journal.into(),
+        )?;
+        self.flush()?;
+        Ok(post_state_hash)
+    }
+
+    /// Returns the value under `key`, or under `path` relative to it, at `state_hash`.
+    pub(crate) fn query(
+        &self,
+        state_hash: Digest,
+        key: Key,
+        path: Vec<String>,
+    ) -> Result<StoredValue, Error> {
+        let query_request = QueryRequest::new(state_hash, key, path);
+        match self
+            .engine_state
+            .run_query(CorrelationId::new(), query_request)?
+        {
+            QueryResult::Success { value, .. } => Ok(*value),
+            QueryResult::RootNotFound => Err(Error::RootNotFound(state_hash)),
+            QueryResult::ValueNotFound(message) | QueryResult::CircularReference(message) => {
+                Err(Error::ValueNotFound(message))
+            }
+            QueryResult::DepthLimitExceeded { limit } => Err(Error::ValueNotFound(format!(
+                "query depth limit of {} reached",
+                limit
+            ))),
+        }
+    }
+
+    fn flush(&self) -> Result<(), Error> {
+        self.engine_state
+            .flush_environment()
+            .map_err(|error| Error::Storage(error.into()))
+    }
+}
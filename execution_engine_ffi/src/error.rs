@@ -0,0 +1,75 @@
+use thiserror::Error as ThisError;
+
+use casper_execution_engine::{core::engine_state, storage};
+use casper_hashing::Digest;
+
+/// The status returned by every function of the C ABI.
+///
+/// The values are part of the ABI, so existing variants must never be renumbered.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasperEngineStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A string argument wasn't valid UTF-8.
+    InvalidUtf8 = 2,
+    /// A request couldn't be parsed.
+    InvalidRequest = 3,
+    /// The given state root hash isn't in global state.
+    RootNotFound = 4,
+    /// A query found no value.
+    ValueNotFound = 5,
+    /// There are no pending effects to commit.
+    NothingToCommit = 6,
+    /// The execution engine returned an error.
+    EngineError = 7,
+    /// Global state couldn't be opened or written.
+    StorageError = 8,
+    /// The engine panicked.  The handle must not be used again.
+    Panic = 9,
+}
+
+/// An error returned by the embedded engine.
+#[derive(Debug, ThisError)]
+pub(crate) enum Error {
+    #[error("null pointer passed as {0}")]
+    NullPointer(&'static str),
+    #[error("{0} is not valid UTF-8")]
+    InvalidUtf8(&'static str),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("state root {0} not found")]
+    RootNotFound(Digest),
+    #[error("value not found: {0}")]
+    ValueNotFound(String),
+    #[error("no executed effects to commit")]
+    NothingToCommit,
+    #[error(transparent)]
+    Engine(#[from] engine_state::Error),
+    #[error(transparent)]
+    Storage(#[from] storage::error::Error),
+}
+
+impl Error {
+    /// Returns the status reported for the error across the C ABI.
+    pub(crate) fn status(&self) -> CasperEngineStatus {
+        match self {
+            Error::NullPointer(_) => CasperEngineStatus::NullPointer,
+            Error::InvalidUtf8(_) => CasperEngineStatus::InvalidUtf8,
+            Error::InvalidRequest(_) => CasperEngineStatus::InvalidRequest,
+            Error::RootNotFound(_) => CasperEngineStatus::RootNotFound,
+            Error::ValueNotFound(_) => CasperEngineStatus::ValueNotFound,
+            Error::NothingToCommit => CasperEngineStatus::NothingToCommit,
+            Error::Engine(_) => CasperEngineStatus::EngineError,
+            Error::Storage(_) => CasperEngineStatus::StorageError,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::InvalidRequest(error.to_string())
+    }
+}
@@ -0,0 +1,441 @@
+//! A C ABI for embedding the Casper execution engine.
+//!
+//! The engine is created over LMDB-backed global state in a given directory and driven through a
+//! small set of functions: committing genesis, executing a deploy, committing the effects of the
+//! executed deploy, and querying global state.  Requests and results which are more than a state
+//! root hash are passed as JSON, in the same format as the node's JSON-RPC API.
+//!
+//! Every function returns a [`CasperEngineStatus`].  When it isn't [`CasperEngineStatus::Ok`], a
+//! description of the error can be retrieved with [`casper_engine_last_error`].
+//!
+//! Buffers returned by the library are owned by the caller and must be released with
+//! [`casper_engine_buffer_free`].  A [`CasperEngine`] handle must not be used from more than one
+//! thread at a time.
+//!
+//! The C declarations of this module are in `include/casper_engine_ffi.h`.
+
+#![doc(html_root_url = "https://docs.rs/casper-engine-ffi/0.1.0")]
+#![warn(
+    missing_docs,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_qualifications
+)]
+
+mod engine;
+mod error;
+
+use std::{
+    cell::RefCell,
+    ffi::CStr,
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    ptr,
+};
+
+use serde::Serialize;
+
+use casper_hashing::Digest;
+use casper_types::Key;
+
+use engine::Engine;
+pub use error::CasperEngineStatus;
+use error::Error;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// The configuration of the global state opened by [`casper_engine_create`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CasperEngineConfig {
+    /// The maximum size of the LMDB database in bytes.
+    pub max_db_size: u64,
+    /// The maximum number of concurrent LMDB readers.
+    pub max_readers: u32,
+    /// Whether LMDB only syncs to disk when global state is committed.
+    pub manual_sync_enabled: bool,
+}
+
+/// A state root hash.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CasperEngineDigest {
+    /// The bytes of the hash.
+    pub bytes: [u8; Digest::LENGTH],
+}
+
+impl From<Digest> for CasperEngineDigest {
+    fn from(digest: Digest) -> Self {
+        CasperEngineDigest {
+            bytes: digest.value(),
+        }
+    }
+}
+
+impl From<CasperEngineDigest> for Digest {
+    fn from(digest: CasperEngineDigest) -> Self {
+        Digest::from(digest.bytes)
+    }
+}
+
+/// A buffer allocated by the library, to be released with [`casper_engine_buffer_free`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct CasperEngineBuffer {
+    /// The start of the buffer, or null if it's empty.
+    pub data: *mut u8,
+    /// The length of the buffer in bytes.
+    pub len: usize,
+}
+
+impl CasperEngineBuffer {
+    fn new(bytes: Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return CasperEngineBuffer::empty();
+        }
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+        CasperEngineBuffer { data, len }
+    }
+
+    fn empty() -> Self {
+        CasperEngineBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+}
+
+/// An opaque handle to an embedded execution engine.
+pub struct CasperEngine {
+    engine: Engine,
+}
+
+/// Runs `f`, recording its error or panic as the last error of the calling thread.
+fn run<F>(f: F) -> CasperEngineStatus
+where
+    F: FnOnce() -> Result<(), Error>,
+{
+    let (status, message) = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => (CasperEngineStatus::Ok, None),
+        Ok(Err(error)) => (error.status(), Some(error.to_string())),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            (CasperEngineStatus::Panic, Some(message))
+        }
+    };
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+    status
+}
+
+unsafe fn as_ref<'a, T>(pointer: *const T, name: &'static str) -> Result<&'a T, Error> {
+    pointer.as_ref().ok_or(Error::NullPointer(name))
+}
+
+unsafe fn as_mut<'a, T>(pointer: *mut T, name: &'static str) -> Result<&'a mut T, Error> {
+    pointer.as_mut().ok_or(Error::NullPointer(name))
+}
+
+unsafe fn as_str<'a>(pointer: *const c_char, name: &'static str) -> Result<&'a str, Error> {
+    if pointer.is_null() {
+        return Err(Error::NullPointer(name));
+    }
+    CStr::from_ptr(pointer)
+        .to_str()
+        .map_err(|_| Error::InvalidUtf8(name))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<CasperEngineBuffer, Error> {
+    Ok(CasperEngineBuffer::new(serde_json::to_vec(value)?))
+}
+
+/// Opens the global state in the directory `data_dir`, creating it if it doesn't exist, and
+/// writes a handle to an engine over it to `out_engine`.
+///
+/// The handle must be released with [`casper_engine_destroy`].
+///
+/// # Safety
+///
+/// `data_dir` must be a valid nul-terminated string, `config` must point to a valid
+/// [`CasperEngineConfig`] and `out_engine` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_create(
+    data_dir: *const c_char,
+    config: *const CasperEngineConfig,
+    out_engine: *mut *mut CasperEngine,
+) -> CasperEngineStatus {
+    run(|| {
+        let out_engine = as_mut(out_engine, "out_engine")?;
+        let data_dir = as_str(data_dir, "data_dir")?;
+        let config = as_ref(config, "config")?;
+        let engine = Engine::open(Path::new(data_dir), config)?;
+        *out_engine = Box::into_raw(Box::new(CasperEngine { engine }));
+        Ok(())
+    })
+}
+
+/// Releases an engine created by [`casper_engine_create`].  Passing null does nothing.
+///
+/// # Safety
+///
+/// `engine` must be null or a handle returned by [`casper_engine_create`] which hasn't already
+/// been destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_destroy(engine: *mut CasperEngine) {
+    if !engine.is_null() {
+        drop(Box::from_raw(engine));
+    }
+}
+
+/// Runs and commits genesis, writing the genesis state root hash to `out_post_state_hash`.
+///
+/// `genesis_request_json` is a JSON-encoded `RunGenesisRequest`.
+///
+/// # Safety
+///
+/// `engine` must be a valid handle, `genesis_request_json` a valid nul-terminated string and
+/// `out_post_state_hash` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_commit_genesis(
+    engine: *mut CasperEngine,
+    genesis_request_json: *const c_char,
+    out_post_state_hash: *mut CasperEngineDigest,
+) -> CasperEngineStatus {
+    run(|| {
+        let engine = as_mut(engine, "engine")?;
+        let genesis_request = as_str(genesis_request_json, "genesis_request_json")?;
+        let out_post_state_hash = as_mut(out_post_state_hash, "out_post_state_hash")?;
+        let post_state_hash = engine
+            .engine
+            .commit_genesis(serde_json::from_str(genesis_request)?)?;
+        *out_post_state_hash = post_state_hash.into();
+        Ok(())
+    })
+}
+
+/// Executes a single deploy, writing its JSON-encoded `ExecutionResult` to `out_result_json`.
+///
+/// The effects of the deploy are kept by the engine until committed by [`casper_engine_commit`],
+/// and are discarded by the next call to this function.
+///
+/// # Safety
+///
+/// `engine` must be a valid handle, `execute_request_json` a valid nul-terminated string and
+/// `out_result_json` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_execute(
+    engine: *mut CasperEngine,
+    execute_request_json: *const c_char,
+    out_result_json: *mut CasperEngineBuffer,
+) -> CasperEngineStatus {
+    run(|| {
+        let engine = as_mut(engine, "engine")?;
+        let execute_request = as_str(execute_request_json, "execute_request_json")?;
+        let out_result_json = as_mut(out_result_json, "out_result_json")?;
+        let execution_result = engine
+            .engine
+            .execute(serde_json::from_str(execute_request)?)?;
+        *out_result_json = to_json(&execution_result)?;
+        Ok(())
+    })
+}
+
+/// Commits the effects of the last deploy executed by [`casper_engine_execute`], writing the new
+/// state root hash to `out_post_state_hash`.
+///
+/// # Safety
+///
+/// `engine` must be a valid handle and `out_post_state_hash` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_commit(
+    engine: *mut CasperEngine,
+    out_post_state_hash: *mut CasperEngineDigest,
+) -> CasperEngineStatus {
+    run(|| {
+        let engine = as_mut(engine, "engine")?;
+        let out_post_state_hash = as_mut(out_post_state_hash, "out_post_state_hash")?;
+        *out_post_state_hash = engine.engine.commit()?.into();
+        Ok(())
+    })
+}
+
+/// Queries global state at `state_hash`, writing the JSON-encoded `StoredValue` found to
+/// `out_value_json`.
+///
+/// `key` is a formatted key such as `"account-hash-..."`, and `path_json` is null or a JSON array
+/// of the named keys to follow from it.
+///
+/// # Safety
+///
+/// `engine` and `state_hash` must be valid pointers, `key` a valid nul-terminated string,
+/// `path_json` null or a valid nul-terminated string and `out_value_json` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_query(
+    engine: *const CasperEngine,
+    state_hash: *const CasperEngineDigest,
+    key: *const c_char,
+    path_json: *const c_char,
+    out_value_json: *mut CasperEngineBuffer,
+) -> CasperEngineStatus {
+    run(|| {
+        let engine = as_ref(engine, "engine")?;
+        let state_hash = as_ref(state_hash, "state_hash")?;
+        let key = Key::from_formatted_str(as_str(key, "key")?)
+            .map_err(|error| Error::InvalidRequest(error.to_string()))?;
+        let path = if path_json.is_null() {
+            Vec::new()
+        } else {
+            serde_json::from_str(as_str(path_json, "path_json")?)?
+        };
+        let out_value_json = as_mut(out_value_json, "out_value_json")?;
+        let value = engine.engine.query((*state_hash).into(), key, path)?;
+        *out_value_json = to_json(&value)?;
+        Ok(())
+    })
+}
+
+/// Writes the description of the last error on the calling thread to `out_message` as UTF-8, or
+/// an empty buffer if the last call succeeded.
+///
+/// # Safety
+///
+/// `out_message` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_last_error(
+    out_message: *mut CasperEngineBuffer,
+) -> CasperEngineStatus {
+    let out_message = match out_message.as_mut() {
+        Some(out_message) => out_message,
+        None => return CasperEngineStatus::NullPointer,
+    };
+    *out_message = LAST_ERROR.with(|last_error| match &*last_error.borrow() {
+        Some(message) => CasperEngineBuffer::new(message.clone().into_bytes()),
+        None => CasperEngineBuffer::empty(),
+    });
+    CasperEngineStatus::Ok
+}
+
+/// Releases a buffer returned by the library, leaving it empty.  Passing null does nothing.
+///
+/// # Safety
+///
+/// `buffer` must be null or point to a buffer returned by the library which hasn't already been
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn casper_engine_buffer_free(buffer: *mut CasperEngineBuffer) {
+    if let Some(buffer) = buffer.as_mut() {
+        if !buffer.data.is_null() {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                buffer.data,
+                buffer.len,
+            )));
+        }
+        *buffer = CasperEngineBuffer::empty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use super::*;
+
+    const CONFIG: CasperEngineConfig = CasperEngineConfig {
+        max_db_size: 52_428_800,
+        max_readers: 512,
+        manual_sync_enabled: true,
+    };
+
+    fn last_error() -> String {
+        let mut buffer = CasperEngineBuffer::empty();
+        unsafe {
+            assert_eq!(
+                casper_engine_last_error(&mut buffer),
+                CasperEngineStatus::Ok
+            );
+            let message = if buffer.data.is_null() {
+                String::new()
+            } else {
+                String::from_utf8(std::slice::from_raw_parts(buffer.data, buffer.len).to_vec())
+                    .unwrap()
+            };
+            casper_engine_buffer_free(&mut buffer);
+            message
+        }
+    }
+
+    fn create(data_dir: &Path) -> *mut CasperEngine {
+        let data_dir = CString::new(data_dir.to_str().unwrap()).unwrap();
+        let mut engine = ptr::null_mut();
+        let status = unsafe { casper_engine_create(data_dir.as_ptr(), &CONFIG, &mut engine) };
+        assert_eq!(status, CasperEngineStatus::Ok, "{}", last_error());
+        assert!(!engine.is_null());
+        engine
+    }
+
+    #[test]
+    fn should_report_null_pointer() {
+        let mut engine = ptr::null_mut();
+        let status = unsafe { casper_engine_create(ptr::null(), &CONFIG, &mut engine) };
+        assert_eq!(status, CasperEngineStatus::NullPointer);
+        assert!(engine.is_null());
+        assert_eq!(last_error(), "null pointer passed as data_dir");
+
+        let mut post_state_hash = CasperEngineDigest { bytes: [0; 32] };
+        let status = unsafe { casper_engine_commit(ptr::null_mut(), &mut post_state_hash) };
+        assert_eq!(status, CasperEngineStatus::NullPointer);
+    }
+
+    #[test]
+    fn should_not_commit_without_execute() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let engine = create(temp_dir.path());
+
+        let mut post_state_hash = CasperEngineDigest { bytes: [0; 32] };
+        let status = unsafe { casper_engine_commit(engine, &mut post_state_hash) };
+        assert_eq!(status, CasperEngineStatus::NothingToCommit);
+
+        unsafe { casper_engine_destroy(engine) };
+    }
+
+    #[test]
+    fn should_not_query_unknown_root() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let engine = create(temp_dir.path());
+
+        let state_hash = CasperEngineDigest { bytes: [1; 32] };
+        let key = CString::new(Key::Hash([2; 32]).to_formatted_string()).unwrap();
+        let mut value = CasperEngineBuffer::empty();
+        let status = unsafe {
+            casper_engine_query(engine, &state_hash, key.as_ptr(), ptr::null(), &mut value)
+        };
+        assert_eq!(status, CasperEngineStatus::RootNotFound);
+        assert!(value.data.is_null());
+        assert!(!last_error().is_empty());
+
+        unsafe { casper_engine_destroy(engine) };
+    }
+
+    #[test]
+    fn should_reject_invalid_key() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let engine = create(temp_dir.path());
+
+        let state_hash = CasperEngineDigest { bytes: [1; 32] };
+        let key = CString::new("not-a-key").unwrap();
+        let mut value = CasperEngineBuffer::empty();
+        let status = unsafe {
+            casper_engine_query(engine, &state_hash, key.as_ptr(), ptr::null(), &mut value)
+        };
+        assert_eq!(status, CasperEngineStatus::InvalidRequest);
+
+        unsafe { casper_engine_destroy(engine) };
+    }
+}
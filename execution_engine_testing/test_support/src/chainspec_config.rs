@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeSet,
     convert::TryFrom,
     fs, io,
     path::{Path, PathBuf},
@@ -12,7 +13,8 @@ use serde::{Deserialize, Serialize};
 use casper_execution_engine::{
     core::engine_state::{
         engine_config::{
-            EngineConfig, EngineConfigBuilder, FeeHandling, RefundHandling, DEFAULT_MAX_QUERY_DEPTH,
+            EngineConfig, EngineConfigBuilder, ExistentialDepositHandling, FeeDistribution,
+            FeeHandling, RefundHandling, StorageRefund, DEFAULT_MAX_QUERY_DEPTH,
         },
         genesis::ExecConfigBuilder,
         run_genesis_request::RunGenesisRequest,
@@ -20,7 +22,10 @@ use casper_execution_engine::{
     },
     shared::{system_config::SystemConfig, wasm_config::WasmConfig},
 };
-use casper_types::{system::auction::VESTING_SCHEDULE_LENGTH_MILLIS, ProtocolVersion, TimeDiff};
+use casper_types::{
+    system::auction::{DelegationRate, VESTING_SCHEDULE_LENGTH_MILLIS},
+    ProtocolVersion, TimeDiff,
+};
 
 use crate::{
     DEFAULT_ACCOUNTS, DEFAULT_CHAINSPEC_REGISTRY, DEFAULT_GENESIS_CONFIG_HASH,
@@ -70,6 +75,10 @@ pub struct CoreConfig {
     pub(crate) max_associated_keys: u32,
     /// Maximum height of contract runtime call stack.
     pub(crate) max_runtime_call_stack_height: u32,
+    /// Maximum number of stored contract frames on the contract runtime call stack.
+    pub(crate) max_contract_call_stack_height: u32,
+    /// Maximum serialized size in bytes of the effects of a single deploy.
+    pub(crate) max_effects_size_bytes: u32,
     /// The minimum bound of motes that can be delegated to a validator.
     pub(crate) minimum_delegation_amount: u64,
     /// Enables strict arguments checking when calling a contract.
@@ -80,6 +89,27 @@ pub struct CoreConfig {
     pub(crate) refund_handling: RefundHandling,
     /// Fee handling.
     pub(crate) fee_handling: FeeHandling,
+    /// Distribution of accumulated fees.
+    pub(crate) fee_distribution: FeeDistribution,
+    /// Existential deposit in motes.
+    pub(crate) existential_deposit: u64,
+    /// Existential deposit handling.
+    pub(crate) existential_deposit_handling: ExistentialDepositHandling,
+    /// Refund for freed storage.
+    pub(crate) storage_refund: StorageRefund,
+    /// Number of eras a validator has to wait between increases of its delegation rate.
+    pub(crate) delegation_rate_change_cooldown: u64,
+    /// Maximum amount by which a validator can increase its delegation rate at once.
+    pub(crate) max_delegation_rate_increase: DelegationRate,
+    /// Names of the auction entry points which fail when called.
+    #[serde(default)]
+    pub(crate) disabled_auction_entry_points: BTreeSet<String>,
+    /// Whether the mint records the owner of each purse it creates.
+    #[serde(default)]
+    pub(crate) enable_purse_ownership_index: bool,
+    /// Whether byte code is compressed when written to global state storage.
+    #[serde(default)]
+    pub(crate) compress_contract_wasm: bool,
 }
 
 /// This struct can be parsed from a TOML-encoded chainspec file.  It means that as the
@@ -158,11 +188,22 @@ impl ChainspecConfig {
             round_seigniorage_rate,
             max_associated_keys: _,
             max_runtime_call_stack_height: _,
+            max_contract_call_stack_height: _,
+            max_effects_size_bytes: _,
             minimum_delegation_amount: _,
             strict_argument_checking: _,
             max_delegators_per_validator: _,
             refund_handling: _,
             fee_handling: _,
+            fee_distribution: _,
+            existential_deposit: _,
+            existential_deposit_handling: _,
+            storage_refund: _,
+            delegation_rate_change_cooldown: _,
+            max_delegation_rate_increase: _,
+            disabled_auction_entry_points: _,
+            enable_purse_ownership_index: _,
+            compress_contract_wasm: _,
         } = core_config;
 
         let exec_config = ExecConfigBuilder::new()
@@ -211,6 +252,10 @@ impl From<ChainspecConfig> for EngineConfig {
             .with_max_runtime_call_stack_height(
                 chainspec_config.core_config.max_runtime_call_stack_height,
             )
+            .with_max_contract_call_stack_height(
+                chainspec_config.core_config.max_contract_call_stack_height,
+            )
+            .with_max_effects_size_bytes(chainspec_config.core_config.max_effects_size_bytes)
             .with_minimum_delegation_amount(chainspec_config.core_config.minimum_delegation_amount)
             .with_strict_argument_checking(chainspec_config.core_config.strict_argument_checking)
             .with_vesting_schedule_period_millis(
@@ -224,6 +269,23 @@ impl From<ChainspecConfig> for EngineConfig {
             )
             .with_wasm_config(chainspec_config.wasm_config)
             .with_system_config(chainspec_config.system_costs_config)
+            .with_existential_deposit(chainspec_config.core_config.existential_deposit)
+            .with_existential_deposit_handling(
+                chainspec_config.core_config.existential_deposit_handling,
+            )
+            .with_storage_refund(chainspec_config.core_config.storage_refund)
+            .with_delegation_rate_change_cooldown(
+                chainspec_config.core_config.delegation_rate_change_cooldown,
+            )
+            .with_max_delegation_rate_increase(
+                chainspec_config.core_config.max_delegation_rate_increase,
+            )
+            .with_disabled_auction_entry_points(
+                chainspec_config.core_config.disabled_auction_entry_points,
+            )
+            .with_enable_purse_ownership_index(
+                chainspec_config.core_config.enable_purse_ownership_index,
+            )
             .build()
     }
 }
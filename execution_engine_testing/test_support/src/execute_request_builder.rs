@@ -4,8 +4,10 @@ use casper_execution_engine::core::engine_state::{
     deploy_item::DeployItem, execute_request::ExecuteRequest,
 };
 use casper_types::{
-    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, ContractVersion,
-    ProtocolVersion, RuntimeArgs,
+    account::AccountHash,
+    runtime_args,
+    system::auction::{self, Reservation},
+    ContractHash, ContractPackageHash, ContractVersion, ProtocolVersion, PublicKey, RuntimeArgs,
 };
 
 use crate::{DeployItemBuilder, DEFAULT_BLOCK_TIME, DEFAULT_PAYMENT, DEFAULT_PROPOSER_PUBLIC_KEY};
@@ -129,6 +131,56 @@ impl ExecuteRequestBuilder {
         ExecuteRequestBuilder::new().push_deploy(deploy)
     }
 
+    /// Returns an [`ExecuteRequest`] that will call the auction to reserve delegator slots.
+    pub fn add_reservations(
+        sender: AccountHash,
+        auction_hash: ContractHash,
+        reservations: Vec<Reservation>,
+    ) -> Self {
+        let args = runtime_args! {
+            auction::ARG_RESERVATIONS => reservations,
+        };
+        Self::contract_call_by_hash(sender, auction_hash, auction::METHOD_ADD_RESERVATIONS, args)
+    }
+
+    /// Returns an [`ExecuteRequest`] that will call the auction to release reserved delegator
+    /// slots.
+    pub fn cancel_reservations(
+        sender: AccountHash,
+        auction_hash: ContractHash,
+        validator: PublicKey,
+        delegators: Vec<PublicKey>,
+    ) -> Self {
+        let args = runtime_args! {
+            auction::ARG_VALIDATOR => validator,
+            auction::ARG_DELEGATORS => delegators,
+        };
+        Self::contract_call_by_hash(
+            sender,
+            auction_hash,
+            auction::METHOD_CANCEL_RESERVATIONS,
+            args,
+        )
+    }
+
+    /// Returns an [`ExecuteRequest`] that will call the auction to withdraw the rewards a
+    /// delegator accrued with all of its validators.
+    pub fn withdraw_delegator_rewards(
+        sender: AccountHash,
+        auction_hash: ContractHash,
+        delegator: PublicKey,
+    ) -> Self {
+        let args = runtime_args! {
+            auction::ARG_DELEGATOR => delegator,
+        };
+        Self::contract_call_by_hash(
+            sender,
+            auction_hash,
+            auction::METHOD_WITHDRAW_DELEGATOR_REWARDS,
+            args,
+        )
+    }
+
     /// Returns an [`ExecuteRequest`] that will call a stored contract by named key.
     pub fn contract_call_by_name(
         sender: AccountHash,
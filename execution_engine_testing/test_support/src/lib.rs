@@ -26,7 +26,8 @@ use once_cell::sync::Lazy;
 #[doc(inline)]
 #[allow(deprecated)]
 pub use casper_execution_engine::core::engine_state::engine_config::{
-    DEFAULT_MAX_ASSOCIATED_KEYS, DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT,
+    DEFAULT_MAX_ASSOCIATED_KEYS, DEFAULT_MAX_CONTRACT_CALL_STACK_HEIGHT,
+    DEFAULT_MAX_EFFECTS_SIZE_BYTES, DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT,
     DEFAULT_MAX_STORED_VALUE_SIZE, DEFAULT_MINIMUM_DELEGATION_AMOUNT,
 };
 use casper_execution_engine::{
@@ -81,6 +82,8 @@ pub const DEFAULT_GAS_PRICE: u64 = 1;
 pub const ARG_AMOUNT: &str = "amount";
 /// Timestamp increment in milliseconds.
 pub const TIMESTAMP_MILLIS_INCREMENT: u64 = 30_000; // 30 seconds
+/// Default era duration in milliseconds, as in the production chainspec.
+pub const DEFAULT_ERA_DURATION_MILLIS: u64 = 7_200_000; // 120 minutes
 
 /// Default genesis config hash.
 pub static DEFAULT_GENESIS_CONFIG_HASH: Lazy<Digest> = Lazy::new(|| [42; 32].into());
@@ -227,6 +230,14 @@ mod tests {
             production.core_config.max_runtime_call_stack_height,
             DEFAULT_MAX_RUNTIME_CALL_STACK_HEIGHT
         );
+        assert_eq!(
+            production.core_config.max_contract_call_stack_height,
+            DEFAULT_MAX_CONTRACT_CALL_STACK_HEIGHT
+        );
+        assert_eq!(
+            production.core_config.max_effects_size_bytes,
+            DEFAULT_MAX_EFFECTS_SIZE_BYTES
+        );
         assert_eq!(
             production.core_config.minimum_delegation_amount,
             DEFAULT_MINIMUM_DELEGATION_AMOUNT
@@ -4,7 +4,7 @@ use num_rational::Ratio;
 
 use casper_execution_engine::core::engine_state::{ChainspecRegistry, UpgradeConfig};
 use casper_hashing::Digest;
-use casper_types::{EraId, Key, ProtocolVersion, StoredValue};
+use casper_types::{ContractHash, EraId, Key, ProtocolVersion, StoredValue};
 
 /// Builds an `UpgradeConfig`.
 pub struct UpgradeRequestBuilder {
@@ -19,6 +19,8 @@ pub struct UpgradeRequestBuilder {
     new_unbonding_delay: Option<u64>,
     global_state_update: BTreeMap<Key, StoredValue>,
     chainspec_registry: ChainspecRegistry,
+    allow_dangerous_overrides: bool,
+    system_contract_extensions: BTreeMap<String, ContractHash>,
 }
 
 impl UpgradeRequestBuilder {
@@ -99,9 +101,24 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    /// Sets `allow_dangerous_overrides`.
+    pub fn with_allow_dangerous_overrides(mut self, allow_dangerous_overrides: bool) -> Self {
+        self.allow_dangerous_overrides = allow_dangerous_overrides;
+        self
+    }
+
+    /// Sets `system_contract_extensions`.
+    pub fn with_system_contract_extensions(
+        mut self,
+        system_contract_extensions: BTreeMap<String, ContractHash>,
+    ) -> Self {
+        self.system_contract_extensions = system_contract_extensions;
+        self
+    }
+
     /// Consumes the `UpgradeRequestBuilder` and returns an [`UpgradeConfig`].
     pub fn build(self) -> UpgradeConfig {
-        UpgradeConfig::new(
+        let mut upgrade_config = UpgradeConfig::new(
             self.pre_state_hash,
             self.current_protocol_version,
             self.new_protocol_version,
@@ -113,7 +130,10 @@ impl UpgradeRequestBuilder {
             self.new_unbonding_delay,
             self.global_state_update,
             self.chainspec_registry,
-        )
+        );
+        upgrade_config.with_allow_dangerous_overrides(self.allow_dangerous_overrides);
+        upgrade_config.with_system_contract_extensions(self.system_contract_extensions);
+        upgrade_config
     }
 }
 
@@ -131,6 +151,8 @@ impl Default for UpgradeRequestBuilder {
             new_unbonding_delay: None,
             global_state_update: Default::default(),
             chainspec_registry: ChainspecRegistry::new_with_optional_global_state(&[], None),
+            allow_dangerous_overrides: false,
+            system_contract_extensions: BTreeMap::new(),
         }
     }
 }
@@ -23,12 +23,25 @@ use casper_execution_engine::{
             era_validators::GetEraValidatorsRequest,
             execute_request::ExecuteRequest,
             execution_result::ExecutionResult,
+            get_contract_wasm::{
+                GetContractWasmRequest, GetContractWasmResult, StoredContractIdentifier,
+            },
             run_genesis_request::RunGenesisRequest,
             step::{EvictItem, StepRequest, StepSuccess},
-            BalanceResult, EngineConfig, EngineConfigBuilder, EngineState, Error, GenesisSuccess,
-            GetBidsRequest, PruneConfig, PruneResult, QueryRequest, QueryResult, RewardItem,
-            StepError, SystemContractRegistry, UpgradeConfig, UpgradeSuccess,
-            DEFAULT_MAX_QUERY_DEPTH,
+            BalanceIdentifier, BalanceResult, BalancesRequest, BlockGasUtilization,
+            CheckInvariantsRequest, CurrentEraInfo, DeployItem, EngineConfig, EngineConfigBuilder,
+            EngineState, EraSummaryStatus, Error, ForecastValidatorsRequest, GenesisPlan,
+            GenesisSuccess, GetBidsRequest, GetBlockEventsRequest, GetBlockGasUtilizationRequest,
+            GetDelegationRateRequest, GetDictionaryItemRequest, GetDictionaryItemResult,
+            GetEraInfoRequest, GetPaymentInfoRequest, GetPurseOwnerRequest, GetReservationsRequest,
+            GetSupplyRequest, IdentifiedBalance, IdentifiedBalanceRequest, InvariantViolation,
+            KeyManagementOp, MaintenanceTask, PaymentInfo, PruneConfig, PruneResult, QueryRequest,
+            QueryResult, RewardItem, SimulateKeyManagementRequest, SimulateKeyManagementResult,
+            StateStats, StateStatsRequest, StepError, SupplyBreakdown,
+            SystemContractExtensionCallRequest, SystemContractExtensionCallResult,
+            SystemContractRegistry, SystemEntityRegistry, UpgradeConfig, UpgradeSuccess,
+            ValidateBlockPayloadRequest, ValidateBlockPayloadResult, ValidatorDelegationRate,
+            ValidatorsForecast, DEFAULT_MAX_QUERY_DEPTH,
         },
         execution,
     },
@@ -54,29 +67,29 @@ use casper_execution_engine::{
         trie_store::lmdb::LmdbTrieStore,
     },
 };
-use casper_hashing::Digest;
+use casper_hashing::{Digest, TrieMultiProof};
 use casper_types::{
     account::{Account, AccountHash},
     bytesrepr::{self, FromBytes},
     runtime_args,
     system::{
         auction::{
-            Bids, EraValidators, UnbondingPurse, UnbondingPurses, ValidatorWeights, WithdrawPurses,
-            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS, AUCTION_DELAY_KEY, ERA_ID_KEY,
-            METHOD_RUN_AUCTION, UNBONDING_DELAY_KEY,
+            Bids, EraValidators, Reservations, UnbondingPurse, UnbondingPurses, ValidatorWeights,
+            WithdrawPurses, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
+            AUCTION_DELAY_KEY, BLOCK_REWARD, ERA_ID_KEY, METHOD_RUN_AUCTION, UNBONDING_DELAY_KEY,
         },
         mint::{ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_SUPPLY_KEY},
         AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
     },
-    CLTyped, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
-    DeployHash, DeployInfo, EraId, Gas, Key, KeyTag, Motes, ProtocolVersion, PublicKey,
-    RuntimeArgs, StoredValue, Transfer, TransferAddr, URef, U512,
+    BlockTime, CLTyped, CLValue, Contract, ContractEvent, ContractHash, ContractPackage,
+    ContractPackageHash, ContractWasm, DeployHash, DeployInfo, EraId, Gas, Key, KeyTag, Motes,
+    ProtocolVersion, PublicKey, RuntimeArgs, StoredValue, Transfer, TransferAddr, URef, U512,
 };
 
 use crate::{
     chainspec_config::{ChainspecConfig, CoreConfig, PRODUCTION_CHAINSPEC_PATH},
-    utils, ExecuteRequestBuilder, StepRequestBuilder, DEFAULT_GAS_PRICE, DEFAULT_PROPOSER_ADDR,
-    DEFAULT_PROTOCOL_VERSION, SYSTEM_ADDR,
+    utils, ExecuteRequestBuilder, StepRequestBuilder, DEFAULT_ERA_DURATION_MILLIS,
+    DEFAULT_GAS_PRICE, DEFAULT_PROPOSER_ADDR, DEFAULT_PROTOCOL_VERSION, SYSTEM_ADDR,
 };
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
@@ -229,17 +242,30 @@ impl InMemoryWasmTestBuilder {
             round_seigniorage_rate: _,
             max_associated_keys,
             max_runtime_call_stack_height,
+            max_contract_call_stack_height,
+            max_effects_size_bytes,
             minimum_delegation_amount,
             strict_argument_checking,
             max_delegators_per_validator,
             refund_handling,
             fee_handling,
+            fee_distribution,
+            existential_deposit,
+            existential_deposit_handling,
+            storage_refund,
+            delegation_rate_change_cooldown,
+            max_delegation_rate_increase,
+            disabled_auction_entry_points,
+            enable_purse_ownership_index,
+            compress_contract_wasm: _,
         } = core_config;
 
         let engine_config = EngineConfigBuilder::new()
             .with_max_query_depth(DEFAULT_MAX_QUERY_DEPTH)
             .with_max_associated_keys(max_associated_keys)
             .with_max_runtime_call_stack_height(max_runtime_call_stack_height)
+            .with_max_contract_call_stack_height(max_contract_call_stack_height)
+            .with_max_effects_size_bytes(max_effects_size_bytes)
             .with_minimum_delegation_amount(minimum_delegation_amount)
             .with_strict_argument_checking(strict_argument_checking)
             .with_vesting_schedule_period_millis(vesting_schedule_period.millis())
@@ -248,6 +274,14 @@ impl InMemoryWasmTestBuilder {
             .with_system_config(system_costs_config)
             .with_refund_handling(refund_handling)
             .with_fee_handling(fee_handling)
+            .with_fee_distribution(fee_distribution)
+            .with_existential_deposit(existential_deposit)
+            .with_existential_deposit_handling(existential_deposit_handling)
+            .with_storage_refund(storage_refund)
+            .with_delegation_rate_change_cooldown(delegation_rate_change_cooldown)
+            .with_max_delegation_rate_increase(max_delegation_rate_increase)
+            .with_disabled_auction_entry_points(disabled_auction_entry_points)
+            .with_enable_purse_ownership_index(enable_purse_ownership_index)
             .build();
 
         let global_state = InMemoryGlobalState::empty().expect("should create global state");
@@ -292,8 +326,8 @@ impl LmdbWasmTestBuilder {
             if let Ok(StoredValue::CLValue(cl_registry)) =
                 self.query(self.post_state_hash, Key::SystemContractRegistry, &[])
             {
-                let registry = CLValue::into_t::<SystemContractRegistry>(cl_registry).unwrap();
-                self.system_contract_registry = Some(registry);
+                let registry = SystemEntityRegistry::from_cl_value(cl_registry).unwrap();
+                self.system_contract_registry = Some(registry.into());
             }
         }
 
@@ -535,6 +569,20 @@ where
     engine_state::Error: From<S::Error>,
     S::Error: Into<execution::Error>,
 {
+    /// Takes a [`RunGenesisRequest`] and plans it without committing, returning the
+    /// [`GenesisPlan`].
+    pub fn plan_genesis(&self, run_genesis_request: &RunGenesisRequest) -> GenesisPlan {
+        self.engine_state
+            .plan_genesis(
+                CorrelationId::new(),
+                run_genesis_request.genesis_config_hash(),
+                run_genesis_request.protocol_version(),
+                run_genesis_request.ee_config(),
+                run_genesis_request.chainspec_registry().clone(),
+            )
+            .expect("Unable to plan genesis")
+    }
+
     /// Takes a [`RunGenesisRequest`], executes the request and returns Self.
     pub fn run_genesis(&mut self, run_genesis_request: &RunGenesisRequest) -> &mut Self {
         let GenesisSuccess {
@@ -568,9 +616,9 @@ where
     ) -> Option<SystemContractRegistry> {
         match self.query(post_state_hash, Key::SystemContractRegistry, &[]) {
             Ok(StoredValue::CLValue(cl_registry)) => {
-                let system_contract_registry =
-                    CLValue::into_t::<SystemContractRegistry>(cl_registry).unwrap();
-                Some(system_contract_registry)
+                let system_entity_registry =
+                    SystemEntityRegistry::from_cl_value(cl_registry).unwrap();
+                Some(system_entity_registry.into())
             }
             Ok(_) => None,
             Err(_) => None,
@@ -826,6 +874,15 @@ where
         self.exec(run_request).commit().expect_success()
     }
 
+    /// Registers a maintenance task to be run by every subsequent step.
+    pub fn register_maintenance_task(&mut self, task: MaintenanceTask) -> &mut Self {
+        Rc::get_mut(&mut self.engine_state)
+            .expect("should have unique ownership")
+            .register_maintenance_task(task)
+            .expect("should register maintenance task");
+        self
+    }
+
     /// Increments engine state.
     pub fn step(&mut self, step_request: StepRequest) -> Result<StepSuccess, StepError> {
         let step_result = self
@@ -842,6 +899,38 @@ where
         step_result
     }
 
+    /// Calls an entry point of a system contract extension, moving to the resulting state root if
+    /// the call succeeds.
+    pub fn call_system_contract_extension(
+        &mut self,
+        protocol_version: ProtocolVersion,
+        contract_name: &str,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> SystemContractExtensionCallResult {
+        let request = SystemContractExtensionCallRequest::new(
+            self.get_post_state_hash(),
+            protocol_version,
+            contract_name.to_string(),
+            entry_point.to_string(),
+            args,
+            BlockTime::default(),
+        );
+        let result = self
+            .engine_state
+            .commit_system_contract_extension_call(CorrelationId::new(), request)
+            .expect("should call system contract extension");
+
+        if let SystemContractExtensionCallResult::Success {
+            post_state_hash, ..
+        } = &result
+        {
+            self.post_state_hash = Some(*post_state_hash);
+        }
+
+        result
+    }
+
     /// Expects a successful run
     pub fn expect_success(&mut self) -> &mut Self {
         // Check first result, as only first result is interesting for a simple test
@@ -940,6 +1029,13 @@ where
             .and_then(|registry| registry.get(contract_name))
     }
 
+    /// Returns the system contract registry at the current post state hash.
+    pub fn get_system_contract_registry(&self) -> SystemContractRegistry {
+        self.engine_state
+            .get_system_contract_registry(CorrelationId::new(), self.get_post_state_hash())
+            .expect("should have system contract registry")
+    }
+
     /// Returns the [`ContractHash`] of the "auction" contract, panics if it can't be found.
     pub fn get_auction_contract_hash(&self) -> ContractHash {
         self.get_system_contract_hash(AUCTION)
@@ -970,6 +1066,15 @@ where
         &self.engine_state
     }
 
+    /// Applies the engine config changes staged on the engine state, as at a block boundary.
+    ///
+    /// Returns `true` if there were any.
+    pub fn apply_pending_config_delta(&mut self) -> bool {
+        Rc::get_mut(&mut self.engine_state)
+            .expect("should have unique ownership")
+            .apply_pending_config_delta()
+    }
+
     /// Returns the last results execs.
     pub fn get_last_exec_results(&self) -> Option<Vec<Rc<ExecutionResult>>> {
         let exec_results = self.exec_results.last()?;
@@ -1050,6 +1155,32 @@ where
             .expect("should get purse balance using public key")
     }
 
+    /// Returns a `BalanceResult` for the purse identified by `identifier`.
+    pub fn get_balance_result(&self, identifier: BalanceIdentifier) -> BalanceResult {
+        let correlation_id = CorrelationId::new();
+        let state_root_hash: Digest = self.post_state_hash.expect("should have post_state_hash");
+        self.engine_state
+            .identified_balance(
+                correlation_id,
+                IdentifiedBalanceRequest::new(state_root_hash, identifier),
+            )
+            .expect("should get balance")
+    }
+
+    /// Returns the balances of the purses identified by `identifiers`, in the same order.
+    pub fn get_balances(&self, identifiers: Vec<BalanceIdentifier>) -> Vec<IdentifiedBalance> {
+        let correlation_id = CorrelationId::new();
+        let state_root_hash: Digest = self.post_state_hash.expect("should have post_state_hash");
+        self.engine_state
+            .get_balances(
+                correlation_id,
+                BalancesRequest::new(state_root_hash, identifiers),
+            )
+            .expect("should get balances")
+            .into_success()
+            .expect("should have state root")
+    }
+
     /// Gets the purse balance of a proposer.
     pub fn get_proposer_purse_balance(&self) -> U512 {
         let proposer_account = self
@@ -1217,6 +1348,229 @@ where
         get_bids_result.into_success().unwrap()
     }
 
+    /// Gets [`Reservations`].
+    pub fn get_reservations(&mut self) -> Reservations {
+        let get_reservations_request = GetReservationsRequest::new(self.get_post_state_hash());
+
+        let get_reservations_result = self
+            .engine_state
+            .get_reservations(CorrelationId::new(), get_reservations_request)
+            .unwrap();
+
+        get_reservations_result.into_success().unwrap()
+    }
+
+    /// Gets the [`ValidatorDelegationRate`] of a validator, or `None` if it has no bid.
+    pub fn get_delegation_rate(
+        &mut self,
+        public_key: PublicKey,
+    ) -> Option<ValidatorDelegationRate> {
+        let get_delegation_rate_request =
+            GetDelegationRateRequest::new(self.get_post_state_hash(), public_key);
+
+        let get_delegation_rate_result = self
+            .engine_state
+            .get_delegation_rate(CorrelationId::new(), get_delegation_rate_request)
+            .unwrap();
+
+        get_delegation_rate_result.into_success()
+    }
+
+    /// Gets the auction's [`CurrentEraInfo`].
+    pub fn get_era_info(&mut self) -> CurrentEraInfo {
+        let get_era_info_request = GetEraInfoRequest::new(self.get_post_state_hash());
+
+        let get_era_info_result = self
+            .engine_state
+            .get_era_info(CorrelationId::new(), get_era_info_request)
+            .unwrap();
+
+        get_era_info_result.into_success().unwrap()
+    }
+
+    /// Gets the [`ValidatorsForecast`] of the next auction.
+    pub fn forecast_next_era_validators(&mut self) -> ValidatorsForecast {
+        let forecast_validators_request =
+            ForecastValidatorsRequest::new(self.get_post_state_hash());
+
+        let forecast_validators_result = self
+            .engine_state
+            .forecast_next_era_validators(CorrelationId::new(), forecast_validators_request)
+            .unwrap();
+
+        forecast_validators_result.into_success().unwrap()
+    }
+
+    /// Gets the [`StateStats`] of the global state under the post state hash.
+    pub fn get_state_stats(&self) -> StateStats {
+        let state_stats_request = StateStatsRequest::new(self.get_post_state_hash());
+
+        let state_stats_result = self
+            .engine_state
+            .state_stats(CorrelationId::new(), state_stats_request)
+            .unwrap();
+
+        state_stats_result.into_success().unwrap()
+    }
+
+    /// Gets a single Merkle proof of the values of `keys` under the post state hash.
+    pub fn get_multi_proof(&self, keys: Vec<Key>) -> TrieMultiProof<Key, StoredValue> {
+        let get_multi_proof_result = self
+            .engine_state
+            .get_multi_proof(CorrelationId::new(), self.get_post_state_hash(), keys)
+            .unwrap();
+
+        get_multi_proof_result.into_success().unwrap()
+    }
+
+    /// Gets a Merkle proof that `key` has no value under the post state hash, or `None` if it has
+    /// one.
+    pub fn prove_absence(&self, key: Key) -> Option<TrieMultiProof<Key, StoredValue>> {
+        let prove_absence_result = self
+            .engine_state
+            .prove_absence(CorrelationId::new(), self.get_post_state_hash(), key)
+            .unwrap();
+
+        prove_absence_result.into_success()
+    }
+
+    /// Gets the item stored under `dictionary_item_key` in the dictionary seeded by `seed_uref`,
+    /// along with its proof.
+    pub fn get_dictionary_item(
+        &self,
+        seed_uref: URef,
+        dictionary_item_key: &[u8],
+    ) -> GetDictionaryItemResult {
+        let get_dictionary_item_request = GetDictionaryItemRequest::new(
+            self.get_post_state_hash(),
+            seed_uref,
+            dictionary_item_key.to_vec(),
+        );
+
+        self.engine_state
+            .get_dictionary_item(CorrelationId::new(), get_dictionary_item_request)
+            .unwrap()
+    }
+
+    /// Simulates applying key management `operations` to the given account.
+    pub fn simulate_key_management(
+        &self,
+        account_hash: AccountHash,
+        operations: Vec<KeyManagementOp>,
+    ) -> SimulateKeyManagementResult {
+        let simulate_key_management_request =
+            SimulateKeyManagementRequest::new(self.get_post_state_hash(), account_hash, operations);
+
+        self.engine_state
+            .simulate_key_management(CorrelationId::new(), simulate_key_management_request)
+            .unwrap()
+    }
+
+    /// Validates the deploys of a proposed block against the current post state, without
+    /// executing them.
+    pub fn validate_block_payload(
+        &self,
+        deploy_items: Vec<DeployItem>,
+    ) -> ValidateBlockPayloadResult {
+        let validate_block_payload_request =
+            ValidateBlockPayloadRequest::new(self.get_post_state_hash(), deploy_items);
+
+        self.engine_state
+            .validate_block_payload(CorrelationId::new(), validate_block_payload_request)
+            .unwrap()
+    }
+
+    /// Gets the mint's [`SupplyBreakdown`].
+    pub fn get_supply(&mut self) -> SupplyBreakdown {
+        let get_supply_request = GetSupplyRequest::new(self.get_post_state_hash());
+
+        let get_supply_result = self
+            .engine_state
+            .get_supply(CorrelationId::new(), get_supply_request)
+            .unwrap();
+
+        get_supply_result.into_success().unwrap()
+    }
+
+    /// Gets the purses of the handle payment contract and how fees are handled, under the post
+    /// state hash.
+    pub fn get_payment_info(&mut self) -> PaymentInfo {
+        let get_payment_info_request = GetPaymentInfoRequest::new(self.get_post_state_hash());
+
+        let get_payment_info_result = self
+            .engine_state
+            .get_payment_info(CorrelationId::new(), get_payment_info_request)
+            .unwrap();
+
+        get_payment_info_result.into_success().unwrap()
+    }
+
+    /// Gets the [`ContractEvent`]s recorded under the post state hash.
+    pub fn get_block_events(&mut self) -> Vec<ContractEvent> {
+        let get_block_events_request = GetBlockEventsRequest::new(self.get_post_state_hash());
+
+        let get_block_events_result = self
+            .engine_state
+            .get_block_events(CorrelationId::new(), get_block_events_request)
+            .unwrap();
+
+        get_block_events_result.into_success().unwrap()
+    }
+
+    /// Gets the [`BlockGasUtilization`] recorded under the post state hash, if any.
+    pub fn get_block_gas_utilization(&mut self) -> Option<BlockGasUtilization> {
+        let get_block_gas_utilization_request =
+            GetBlockGasUtilizationRequest::new(self.get_post_state_hash());
+
+        let get_block_gas_utilization_result = self
+            .engine_state
+            .get_block_gas_utilization(CorrelationId::new(), get_block_gas_utilization_request)
+            .unwrap();
+
+        get_block_gas_utilization_result.into_success().unwrap()
+    }
+
+    /// Gets the owner of `purse` recorded in the purse ownership index under the post state hash,
+    /// if any.
+    pub fn get_purse_owner(&mut self, purse: URef) -> Option<Key> {
+        let get_purse_owner_request =
+            GetPurseOwnerRequest::new(self.get_post_state_hash(), purse.addr());
+
+        let get_purse_owner_result = self
+            .engine_state
+            .get_purse_owner(CorrelationId::new(), get_purse_owner_request)
+            .unwrap();
+
+        get_purse_owner_result.into_success().unwrap()
+    }
+
+    /// Checks the cross-contract invariants of the global state under the post state hash,
+    /// returning the violated ones.
+    pub fn check_invariants(&mut self) -> Vec<InvariantViolation> {
+        let check_invariants_request = CheckInvariantsRequest::new(self.get_post_state_hash());
+
+        let check_invariants_result = self
+            .engine_state
+            .check_invariants(CorrelationId::new(), check_invariants_request)
+            .unwrap();
+
+        check_invariants_result.into_success().unwrap()
+    }
+
+    /// Gets the Wasm of the contract identified by `contract_identifier` under the post state
+    /// hash.
+    pub fn query_contract_wasm(
+        &mut self,
+        contract_identifier: StoredContractIdentifier,
+    ) -> GetContractWasmResult {
+        let get_contract_wasm_request =
+            GetContractWasmRequest::new(self.get_post_state_hash(), contract_identifier);
+
+        self.engine_state
+            .get_contract_wasm(CorrelationId::new(), get_contract_wasm_request)
+            .unwrap()
+    }
+
     /// Gets [`UnbondingPurses`].
     pub fn get_unbonds(&mut self) -> UnbondingPurses {
         let correlation_id = CorrelationId::new();
@@ -1429,6 +1783,68 @@ where
         self.advance_eras_by(1, reward_items, evict_items);
     }
 
+    /// Advances `num_eras` eras the way the node does at the end of each era.
+    ///
+    /// Each era is ended by a step into the era after the auction's current one, which ends
+    /// [`DEFAULT_ERA_DURATION_MILLIS`] after the era started.  Every validator of the ending era is
+    /// rewarded for full participation, no validator is evicted, and the step runs under the
+    /// protocol version of the installed system contracts.
+    pub fn advance_eras(&mut self, num_eras: u64) -> &mut Self {
+        self.advance_eras_with_prune_batch_size(num_eras, 0)
+    }
+
+    /// Advances `num_eras` eras like [`advance_eras`](Self::advance_eras), also pruning up to
+    /// `prune_batch_size` legacy [`Key::EraInfo`] records after each era, as the node does after
+    /// executing a block.
+    pub fn advance_eras_with_prune_batch_size(
+        &mut self,
+        num_eras: u64,
+        prune_batch_size: u64,
+    ) -> &mut Self {
+        for _ in 0..num_eras {
+            let era_info = self.get_era_info();
+            let era_id = era_info.era_id();
+            let reward_items = self
+                .get_era_validators()
+                .remove(&era_id)
+                .unwrap_or_default()
+                .into_keys()
+                .map(|validator| RewardItem::new(validator, BLOCK_REWARD));
+            let protocol_version = self
+                .get_contract(self.get_system_auction_hash())
+                .expect("should have auction contract")
+                .protocol_version();
+
+            let step_request = StepRequestBuilder::new()
+                .with_parent_state_hash(self.get_post_state_hash())
+                .with_protocol_version(protocol_version)
+                .with_reward_items(reward_items)
+                .with_next_era_id(era_id.successor())
+                .with_era_end_timestamp_millis(
+                    era_info.era_start_timestamp_millis() + DEFAULT_ERA_DURATION_MILLIS,
+                )
+                .with_run_auction(true)
+                .build();
+            self.step(step_request)
+                .expect("failed to execute step request");
+
+            if prune_batch_size == 0 {
+                continue;
+            }
+            let keys_to_prune: Vec<Key> = self
+                .get_keys(KeyTag::EraInfo)
+                .unwrap_or_else(|_| panic!("should get era info keys"))
+                .into_iter()
+                .take(prune_batch_size as usize)
+                .collect();
+            if !keys_to_prune.is_empty() {
+                let prune_config = PruneConfig::new(self.get_post_state_hash(), keys_to_prune);
+                self.commit_prune(prune_config).expect_prune_success();
+            }
+        }
+        self
+    }
+
     /// Returns a trie by hash.
     pub fn get_trie(&mut self, state_hash: Digest) -> Option<Trie<Key, StoredValue>> {
         self.engine_state
@@ -1470,6 +1886,65 @@ where
         self
     }
 
+    /// Commits a prune of the `Key::DeployInfo` records of the given deploys.
+    pub fn commit_prune_deploy_infos(&mut self, deploy_hashes: &[DeployHash]) -> &mut Self {
+        let result = self.engine_state.commit_prune_deploy_infos(
+            CorrelationId::new(),
+            self.get_post_state_hash(),
+            deploy_hashes,
+        );
+
+        if let Ok(PruneResult::Success { post_state_hash }) = &result {
+            self.post_state_hash = Some(*post_state_hash);
+        }
+
+        self.prune_results.push(result);
+        self
+    }
+
+    /// Commits a prune of at most `max_keys` records of retired namespace generations.
+    pub fn commit_prune_namespaces(&mut self, max_keys: usize) -> &mut Self {
+        let result = self.engine_state.commit_prune_namespaces(
+            CorrelationId::new(),
+            self.get_post_state_hash(),
+            max_keys,
+        );
+
+        if let Ok(PruneResult::Success { post_state_hash }) = &result {
+            self.post_state_hash = Some(*post_state_hash);
+        }
+
+        self.prune_results.push(result);
+        self
+    }
+
+    /// Commits a move of era summaries to `Key::EraSummary`, pruning at most `max_keys` legacy
+    /// `Key::EraInfo` records.
+    pub fn commit_era_summary_migration(&mut self, max_keys: usize) -> &mut Self {
+        let result = self.engine_state.commit_era_summary_migration(
+            CorrelationId::new(),
+            self.get_post_state_hash(),
+            max_keys,
+        );
+
+        if let Ok(PruneResult::Success { post_state_hash }) = &result {
+            self.post_state_hash = Some(*post_state_hash);
+        }
+
+        self.prune_results.push(result);
+        self
+    }
+
+    /// Returns the progress of the move of era summaries to `Key::EraSummary` under the post state
+    /// hash.
+    pub fn get_era_summary_status(&self) -> EraSummaryStatus {
+        self.engine_state
+            .era_summary_status(CorrelationId::new(), self.get_post_state_hash())
+            .expect("should get era summary status")
+            .into_success()
+            .expect("should have era summary status")
+    }
+
     /// Returns a `Result` containing a [`PruneResult`].
     pub fn get_prune_result(
         &self,
@@ -0,0 +1,118 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PROTOCOL_VERSION,
+    MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{
+            engine_config::{CompatibilityMode, EngineConfig},
+            EngineConfigBuilder, Error,
+        },
+        execution,
+    },
+    shared::system_config::{SystemConfig, DEFAULT_WASMLESS_TRANSFER_COST},
+};
+use casper_types::{
+    account::AccountHash, runtime_args, system::mint, Gas, ProtocolVersion, RuntimeArgs,
+};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const HOST_FUNCTION_EMIT_EVENT: &str = "casper_emit_event";
+const LEGACY_WASMLESS_TRANSFER_COST: u32 = DEFAULT_WASMLESS_TRANSFER_COST / 2;
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1; 32]);
+
+fn setup(engine_config: EngineConfig) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder
+}
+
+fn legacy_transfer_cost_mode() -> CompatibilityMode {
+    let system_config = SystemConfig::default();
+    CompatibilityMode::new().with_system_config(SystemConfig::new(
+        LEGACY_WASMLESS_TRANSFER_COST,
+        *system_config.auction_costs(),
+        *system_config.mint_costs(),
+        *system_config.handle_payment_costs(),
+        *system_config.standard_payment_costs(),
+    ))
+}
+
+fn transfer(builder: &mut InMemoryWasmTestBuilder) {
+    let request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => MINIMUM_ACCOUNT_CREATION_BALANCE,
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_resolve_host_function_unavailable_in_compatibility_mode() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_compatibility_mode(
+            *DEFAULT_PROTOCOL_VERSION,
+            CompatibilityMode::new().with_unavailable_host_function(HOST_FUNCTION_EMIT_EVENT),
+        )
+        .build();
+    let mut builder = setup(engine_config);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            &error,
+            Error::Exec(execution::Error::Interpreter(message))
+            if message.contains(HOST_FUNCTION_EMIT_EVENT)
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_charge_costs_of_compatibility_mode() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_compatibility_mode(*DEFAULT_PROTOCOL_VERSION, legacy_transfer_cost_mode())
+        .build();
+    let mut builder = setup(engine_config);
+
+    transfer(&mut builder);
+
+    assert_eq!(
+        builder.last_exec_gas_cost(),
+        Gas::from(LEGACY_WASMLESS_TRANSFER_COST)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_apply_compatibility_mode_to_newer_protocol_versions() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_compatibility_mode(
+            ProtocolVersion::from_parts(0, 9, 0),
+            legacy_transfer_cost_mode(),
+        )
+        .build();
+    let mut builder = setup(engine_config);
+
+    transfer(&mut builder);
+
+    assert_eq!(
+        builder.last_exec_gas_cost(),
+        Gas::from(DEFAULT_WASMLESS_TRANSFER_COST)
+    );
+}
@@ -0,0 +1,127 @@
+use casper_engine_test_support::{
+    DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, MINIMUM_ACCOUNT_CREATION_BALANCE,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::{EngineConfig, EngineConfigBuilder, Error};
+use casper_types::{
+    account::{AccountHash, Weight},
+    runtime_args,
+    system::mint,
+    RuntimeArgs,
+};
+
+const CONTRACT_ADD_ASSOCIATED_KEY: &str = "add_associated_key.wasm";
+const CONTRACT_AUTHORIZATION_CONTRACT: &str = "authorization_contract.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const ARG_ACCOUNT: &str = "account";
+const ARG_WEIGHT: &str = "weight";
+const ARG_REQUIRED_APPROVAL: &str = "required_approval";
+const SECOND_FACTOR: AccountHash = AccountHash::new([42; 32]);
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1; 32]);
+
+fn setup(engine_config: EngineConfig) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let add_key_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_ASSOCIATED_KEY,
+        runtime_args! {
+            ARG_ACCOUNT => SECOND_FACTOR,
+            ARG_WEIGHT => Weight::new(1),
+        },
+    )
+    .build();
+    builder.exec(add_key_request).expect_success().commit();
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUTHORIZATION_CONTRACT,
+        runtime_args! {
+            ARG_REQUIRED_APPROVAL => SECOND_FACTOR,
+        },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    assert!(builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .authorization_contract()
+        .is_some());
+
+    builder
+}
+
+fn exec_do_nothing(builder: &mut InMemoryWasmTestBuilder, authorization_keys: &[AccountHash]) {
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT, })
+        .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::new())
+        .with_deploy_hash([2; 32])
+        .with_authorization_keys(authorization_keys)
+        .build();
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy).build();
+    builder.exec(exec_request).commit();
+}
+
+fn assert_authorization_failure(builder: &InMemoryWasmTestBuilder) {
+    let exec_results = builder
+        .get_last_exec_results()
+        .expect("should have exec results");
+    let deploy_result = exec_results.first().expect("should have exec result");
+    assert!(deploy_result.has_precondition_failure());
+    assert!(
+        matches!(deploy_result.as_error(), Some(Error::Authorization)),
+        "{:?}",
+        deploy_result
+    );
+}
+
+#[ignore]
+#[test]
+fn should_deploy_with_approval_required_by_authorization_contract() {
+    let mut builder = setup(EngineConfig::default());
+
+    exec_do_nothing(&mut builder, &[*DEFAULT_ACCOUNT_ADDR, SECOND_FACTOR]);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_deploy_without_approval_required_by_authorization_contract() {
+    let mut builder = setup(EngineConfig::default());
+
+    exec_do_nothing(&mut builder, &[*DEFAULT_ACCOUNT_ADDR]);
+    assert_authorization_failure(&builder);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_without_approval_required_by_authorization_contract() {
+    let mut builder = setup(EngineConfig::default());
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => MINIMUM_ACCOUNT_CREATION_BALANCE,
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+    assert_authorization_failure(&builder);
+}
+
+#[ignore]
+#[test]
+fn should_not_deploy_when_authorization_contract_runs_out_of_gas() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_authorization_gas_limit(1)
+        .build();
+    let mut builder = setup(engine_config);
+
+    exec_do_nothing(&mut builder, &[*DEFAULT_ACCOUNT_ADDR, SECOND_FACTOR]);
+    assert_authorization_failure(&builder);
+}
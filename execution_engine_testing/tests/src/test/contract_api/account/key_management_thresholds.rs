@@ -2,7 +2,13 @@ use casper_engine_test_support::{
     DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
     DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{account::AccountHash, runtime_args, RuntimeArgs};
+use casper_execution_engine::core::engine_state::{
+    KeyManagementOp, KeyManagementOpFailure, SimulateKeyManagementResult,
+};
+use casper_types::{
+    account::{AccountHash, RemoveKeyFailure, Weight},
+    runtime_args, RuntimeArgs,
+};
 
 const CONTRACT_KEY_MANAGEMENT_THRESHOLDS: &str = "key_management_thresholds.wasm";
 
@@ -69,3 +75,58 @@ fn should_verify_key_management_permission_with_sufficient_weight() {
         .expect_success()
         .commit();
 }
+
+#[ignore]
+#[test]
+fn should_simulate_key_management_without_committing() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let new_key = AccountHash::new([42; 32]);
+
+    let rotation = vec![
+        KeyManagementOp::AddKey {
+            account_hash: new_key,
+            weight: Weight::new(1),
+        },
+        KeyManagementOp::RemoveKey {
+            account_hash: *DEFAULT_ACCOUNT_ADDR,
+        },
+    ];
+    match builder.simulate_key_management(*DEFAULT_ACCOUNT_ADDR, rotation) {
+        SimulateKeyManagementResult::Success {
+            associated_keys, ..
+        } => {
+            assert!(associated_keys.contains_key(&new_key));
+            assert!(!associated_keys.contains_key(&DEFAULT_ACCOUNT_ADDR));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let lockout = vec![
+        KeyManagementOp::AddKey {
+            account_hash: new_key,
+            weight: Weight::new(1),
+        },
+        KeyManagementOp::SetKeyManagementThreshold(Weight::new(2)),
+        KeyManagementOp::RemoveKey {
+            account_hash: *DEFAULT_ACCOUNT_ADDR,
+        },
+    ];
+    match builder.simulate_key_management(*DEFAULT_ACCOUNT_ADDR, lockout) {
+        SimulateKeyManagementResult::OperationFailed { index, failure } => {
+            assert_eq!(index, 2);
+            assert_eq!(
+                failure,
+                KeyManagementOpFailure::RemoveKey(RemoveKeyFailure::ThresholdViolation)
+            );
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Nothing is written to global state.
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert!(!account.associated_keys().contains_key(&new_key));
+}
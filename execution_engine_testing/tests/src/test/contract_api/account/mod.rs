@@ -1,4 +1,5 @@
 mod associated_keys;
+mod authorization_contract;
 mod authorized_keys;
 mod key_management_thresholds;
 mod named_keys;
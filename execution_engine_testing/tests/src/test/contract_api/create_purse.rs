@@ -4,7 +4,10 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+use casper_execution_engine::core::engine_state::{
+    engine_config::EngineConfigBuilder, PurseChanges,
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U512};
 
 const CONTRACT_CREATE_PURSE_01: &str = "create_purse_01.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
@@ -92,3 +95,103 @@ fn should_create_usable_purse() {
         "when created directly a purse has 0 balance"
     );
 }
+
+#[ignore]
+#[test]
+fn should_report_created_purse_in_purse_changes() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_results = builder
+        .get_last_exec_results()
+        .expect("should have exec results");
+    let purse_changes = PurseChanges::from_execution_journal(exec_results[0].execution_journal());
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let test_purse = account
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .and_then(Key::as_uref)
+        .expect("should have test purse");
+    assert!(purse_changes.created().contains(&test_purse.addr()));
+    assert!(!purse_changes
+        .created()
+        .contains(&account.main_purse().addr()));
+}
+
+#[ignore]
+#[test]
+fn should_record_purse_owner_when_index_enabled() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+    )
+    .build();
+
+    let engine_config = EngineConfigBuilder::new()
+        .with_enable_purse_ownership_index(true)
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let test_purse = account
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .and_then(Key::as_uref)
+        .expect("should have test purse");
+
+    assert_eq!(
+        builder.get_purse_owner(*test_purse),
+        Some(Key::Account(*DEFAULT_ACCOUNT_ADDR))
+    );
+    // Purses created by genesis aren't created through the mint, so aren't indexed.
+    assert_eq!(builder.get_purse_owner(account.main_purse()), None);
+}
+
+#[ignore]
+#[test]
+fn should_not_record_purse_owner_by_default() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let test_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have test purse");
+
+    assert_eq!(builder.get_purse_owner(test_purse), None);
+}
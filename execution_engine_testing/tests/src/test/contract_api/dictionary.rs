@@ -5,7 +5,10 @@ use casper_engine_test_support::{
     DEFAULT_PAYMENT, MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST,
 };
 use casper_execution_engine::core::{
-    engine_state::{run_genesis_request::RunGenesisRequest, Error as EngineError, GenesisAccount},
+    engine_state::{
+        run_genesis_request::RunGenesisRequest, Error as EngineError, GenesisAccount,
+        GetDictionaryItemResult,
+    },
     execution::Error,
 };
 use casper_types::{
@@ -650,6 +653,39 @@ fn should_query_dictionary_items_with_test_builder() {
         let value: String = value.into_t().expect("should be string");
         assert_eq!(value, dictionary::DEFAULT_DICTIONARY_VALUE);
     }
+
+    {
+        // Query by seed URef and item key, with proof
+        let dictionary_item_name = dictionary::DEFAULT_DICTIONARY_NAME.as_bytes();
+        let (dictionary_key, value, proof) =
+            match builder.get_dictionary_item(dictionary_uref, dictionary_item_name) {
+                GetDictionaryItemResult::Success {
+                    dictionary_key,
+                    value,
+                    proof,
+                } => (dictionary_key, value, proof),
+                other => panic!("unexpected result: {:?}", other),
+            };
+        assert_eq!(
+            dictionary_key,
+            Key::dictionary(dictionary_uref, dictionary_item_name)
+        );
+        assert_eq!(*proof.key(), dictionary_key);
+        assert_eq!(
+            proof
+                .compute_state_hash()
+                .expect("should compute state hash"),
+            builder.get_post_state_hash()
+        );
+        let value = CLValue::try_from(*value).expect("should have cl value");
+        let value: String = value.into_t().expect("should be string");
+        assert_eq!(value, dictionary::DEFAULT_DICTIONARY_VALUE);
+
+        assert!(matches!(
+            builder.get_dictionary_item(dictionary_uref, b"missing"),
+            GetDictionaryItemResult::ItemNotFound { .. }
+        ));
+    }
 }
 
 #[ignore]
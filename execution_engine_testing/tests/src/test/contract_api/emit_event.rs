@@ -0,0 +1,134 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::{engine_state::Error, execution},
+    shared::{additive_map::AdditiveMap, transform::Transform},
+};
+use casper_types::{
+    runtime_args, CLValue, ContractEvent, ContractHash, Key, RuntimeArgs, StoredValue,
+};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const HASH_KEY_NAME: &str = "emit_event_hash";
+const METHOD_EMIT: &str = "emit";
+const METHOD_EMIT_AND_REVERT: &str = "emit_and_revert";
+const METHOD_EMIT_FROM_SESSION: &str = "emit_from_session";
+const ARG_NAME: &str = "name";
+const ARG_VALUE: &str = "value";
+const EVENT_NAME: &str = "transferred";
+const EVENT_VALUE: u64 = 42;
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let contract_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    (builder, contract_hash)
+}
+
+fn call_contract(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_hash: ContractHash,
+    entry_point: &str,
+) -> Vec<ContractEvent> {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        entry_point,
+        runtime_args! {
+            ARG_NAME => EVENT_NAME,
+            ARG_VALUE => EVENT_VALUE,
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+
+    let exec_results = builder
+        .get_last_exec_results()
+        .expect("should have exec results");
+    exec_results
+        .last()
+        .expect("should have exec result")
+        .events()
+        .to_vec()
+}
+
+#[ignore]
+#[test]
+fn should_record_events_emitted_by_stored_contract() {
+    let (mut builder, contract_hash) = setup();
+
+    let events = call_contract(&mut builder, contract_hash, METHOD_EMIT);
+    builder.expect_success();
+
+    let expected = ContractEvent::new(
+        contract_hash,
+        EVENT_NAME.to_string(),
+        CLValue::from_t(EVENT_VALUE).unwrap(),
+    );
+    assert_eq!(events, vec![expected]);
+}
+
+#[ignore]
+#[test]
+fn should_discard_events_of_failed_execution() {
+    let (mut builder, contract_hash) = setup();
+
+    let events = call_contract(&mut builder, contract_hash, METHOD_EMIT_AND_REVERT);
+    builder.expect_failure();
+
+    assert!(events.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_refuse_events_from_session_code() {
+    let (mut builder, contract_hash) = setup();
+
+    call_contract(&mut builder, contract_hash, METHOD_EMIT_FROM_SESSION);
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, Error::Exec(execution::Error::InvalidContext)),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_read_block_events_from_global_state() {
+    let (mut builder, contract_hash) = setup();
+
+    assert!(builder.get_block_events().is_empty());
+
+    let events = call_contract(&mut builder, contract_hash, METHOD_EMIT);
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        Key::BlockEvents,
+        Transform::Write(StoredValue::CLValue(
+            CLValue::from_t(events.clone()).unwrap(),
+        )),
+    );
+    let pre_state_hash = builder.get_post_state_hash();
+    builder.commit_transforms(pre_state_hash, effects);
+
+    assert_eq!(builder.get_block_events(), events);
+}
@@ -2,6 +2,7 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
+use casper_execution_engine::core::engine_state::engine_config::EngineConfigBuilder;
 use casper_types::{runtime_args, RuntimeArgs};
 
 const CONTRACT_GET_BLOCKTIME: &str = "get_blocktime.wasm";
@@ -25,3 +26,24 @@ fn should_run_get_blocktime_contract() {
         .commit()
         .expect_success();
 }
+
+#[ignore]
+#[test]
+fn should_hide_blocktime_from_deterministic_execution() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_BLOCKTIME,
+        runtime_args! { ARG_KNOWN_BLOCK_TIME => 0u64 },
+    )
+    .with_block_time(42)
+    .build();
+
+    let engine_config = EngineConfigBuilder::new()
+        .with_deterministic_execution(true)
+        .build();
+    InMemoryWasmTestBuilder::new_with_config(engine_config)
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
@@ -0,0 +1,158 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::engine_state::{GetContractWasmRequest, GetContractWasmResult, StoredContractIdentifier},
+    shared::newtypes::CorrelationId,
+};
+use casper_hashing::Digest;
+use casper_types::{ContractHash, ContractPackageHash, RuntimeArgs};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const HASH_KEY_NAME: &str = "emit_event_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "emit_event_package_hash";
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash, ContractPackageHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let named_keys = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .clone();
+    let contract_hash = named_keys
+        .get(HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    let contract_package_hash = named_keys
+        .get(PACKAGE_HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+
+    (builder, contract_hash, contract_package_hash)
+}
+
+#[ignore]
+#[test]
+fn should_get_contract_wasm_by_hash_and_by_package() {
+    let (mut builder, contract_hash, contract_package_hash) = setup();
+
+    let contract = builder
+        .get_contract(contract_hash)
+        .expect("should have contract");
+    let expected_wasm = builder
+        .get_contract_wasm(ContractHash::new(contract.contract_wasm_hash().value()))
+        .expect("should have contract wasm");
+    let expected_digest = Digest::hash(expected_wasm.bytes());
+
+    let identifiers = [
+        StoredContractIdentifier::Hash(contract_hash),
+        StoredContractIdentifier::Package {
+            contract_package_hash,
+            version: None,
+        },
+        StoredContractIdentifier::Package {
+            contract_package_hash,
+            version: Some(1),
+        },
+    ];
+    for identifier in identifiers {
+        match builder.query_contract_wasm(identifier) {
+            GetContractWasmResult::Success {
+                contract_hash: actual_contract_hash,
+                contract_wasm_hash,
+                contract_wasm,
+                digest,
+            } => {
+                assert_eq!(actual_contract_hash, contract_hash);
+                assert_eq!(contract_wasm_hash, contract.contract_wasm_hash());
+                assert_eq!(contract_wasm, expected_wasm);
+                assert_eq!(digest, expected_digest);
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
+
+#[ignore]
+#[test]
+fn should_not_get_contract_wasm_with_unexpected_digest() {
+    let (builder, contract_hash, _) = setup();
+
+    let unexpected_digest = Digest::hash([1; 32]);
+    let request = GetContractWasmRequest::new(
+        builder.get_post_state_hash(),
+        StoredContractIdentifier::Hash(contract_hash),
+    )
+    .with_expected_digest(unexpected_digest);
+    let result = builder
+        .get_engine_state()
+        .get_contract_wasm(CorrelationId::new(), request)
+        .expect("should query contract wasm");
+    assert!(
+        matches!(
+            result,
+            GetContractWasmResult::DigestMismatch { expected, .. } if expected == unexpected_digest
+        ),
+        "{:?}",
+        result
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_get_contract_wasm_of_missing_contract() {
+    let (mut builder, _, contract_package_hash) = setup();
+
+    let missing_package_hash = ContractPackageHash::new([255; 32]);
+    let result = builder.query_contract_wasm(StoredContractIdentifier::Package {
+        contract_package_hash: missing_package_hash,
+        version: None,
+    });
+    assert!(
+        matches!(
+            result,
+            GetContractWasmResult::ContractPackageNotFound(hash) if hash == missing_package_hash
+        ),
+        "{:?}",
+        result
+    );
+
+    let result = builder.query_contract_wasm(StoredContractIdentifier::Package {
+        contract_package_hash,
+        version: Some(2),
+    });
+    assert!(
+        matches!(
+            result,
+            GetContractWasmResult::ContractVersionNotFound {
+                version: Some(2),
+                ..
+            }
+        ),
+        "{:?}",
+        result
+    );
+
+    let missing_contract_hash = ContractHash::new([255; 32]);
+    let result = builder.query_contract_wasm(StoredContractIdentifier::Hash(missing_contract_hash));
+    assert!(
+        matches!(
+            result,
+            GetContractWasmResult::ContractNotFound(hash) if hash == missing_contract_hash
+        ),
+        "{:?}",
+        result
+    );
+}
@@ -0,0 +1,92 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{
+    contracts::INSTALL_SEED_LENGTH, runtime_args, ContractHash, ContractPackageHash, RuntimeArgs,
+};
+
+const CONTRACT_INSTALL_WITH_SEED: &str = "install_with_seed.wasm";
+const ARG_INSTALL_SEED: &str = "install_seed";
+const HASH_KEY_NAME: &str = "seeded_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "seeded_package_hash";
+const INSTALL_SEED: [u8; INSTALL_SEED_LENGTH] = [42; INSTALL_SEED_LENGTH];
+const OTHER_INSTALL_SEED: [u8; INSTALL_SEED_LENGTH] = [43; INSTALL_SEED_LENGTH];
+
+fn install(builder: &mut InMemoryWasmTestBuilder, install_seed: [u8; INSTALL_SEED_LENGTH]) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_INSTALL_WITH_SEED,
+        runtime_args! { ARG_INSTALL_SEED => install_seed },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn installed_hashes(builder: &InMemoryWasmTestBuilder) -> (ContractPackageHash, ContractHash) {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let package_hash = account.named_keys()[PACKAGE_HASH_KEY_NAME]
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .expect("should have package hash");
+    let contract_hash = account.named_keys()[HASH_KEY_NAME]
+        .into_hash()
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    (package_hash, contract_hash)
+}
+
+#[ignore]
+#[test]
+fn should_install_at_hash_derived_from_seed() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    install(&mut builder, INSTALL_SEED);
+
+    let (package_hash, _contract_hash) = installed_hashes(&builder);
+    assert_eq!(
+        package_hash,
+        ContractPackageHash::from_install_seed(*DEFAULT_ACCOUNT_ADDR, INSTALL_SEED)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_install_twice_with_same_seed() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    install(&mut builder, INSTALL_SEED);
+    let (package_hash, contract_hash) = installed_hashes(&builder);
+
+    install(&mut builder, INSTALL_SEED);
+    assert_eq!(installed_hashes(&builder), (package_hash, contract_hash));
+
+    let contract_package = builder
+        .get_contract_package(package_hash)
+        .expect("should have contract package");
+    assert_eq!(contract_package.versions().len(), 1);
+}
+
+#[ignore]
+#[test]
+fn should_install_separate_packages_for_different_seeds() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    install(&mut builder, INSTALL_SEED);
+    let (first_package_hash, _) = installed_hashes(&builder);
+
+    install(&mut builder, OTHER_INSTALL_SEED);
+    let (second_package_hash, _) = installed_hashes(&builder);
+
+    assert_ne!(first_package_hash, second_package_hash);
+    assert!(builder.get_contract_package(first_package_hash).is_some());
+    assert_eq!(
+        second_package_hash,
+        ContractPackageHash::from_install_seed(*DEFAULT_ACCOUNT_ADDR, OTHER_INSTALL_SEED)
+    );
+}
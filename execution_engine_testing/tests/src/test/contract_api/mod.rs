@@ -1,21 +1,31 @@
 mod account;
 mod create_purse;
 mod dictionary;
+mod emit_event;
 mod get_arg;
 mod get_blocktime;
 mod get_call_stack;
 mod get_caller;
+mod get_contract_wasm;
 mod get_phase;
+mod install_with_seed;
 mod list_authorization_keys;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
 mod multisig_authorization;
 mod named_dictionaries;
+mod namespace;
+mod new_unique_address;
+mod non_reentrant;
+mod pause;
 mod revert;
 mod runtime;
+mod scheduled_callback;
+mod sorted_index;
 mod subcall;
 mod transfer;
 mod transfer_cached;
 mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
+mod transfer_purse_to_purse_with_result;
@@ -0,0 +1,109 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{runtime_args, Key, KeyTag, RuntimeArgs, StoredValue};
+
+const CONTRACT_NAMESPACES: &str = "namespaces.wasm";
+const ARG_PUTS: &str = "puts";
+const ARG_CLEAR: &str = "clear";
+const ARG_GETS: &str = "gets";
+const VALUES_KEY_NAME: &str = "values";
+
+fn exec_namespaces(
+    builder: &mut InMemoryWasmTestBuilder,
+    puts: &[(&str, u64)],
+    clear: bool,
+    gets: &[&str],
+) -> Vec<Option<u64>> {
+    let puts: Vec<(String, u64)> = puts
+        .iter()
+        .map(|(item_key, value)| (item_key.to_string(), *value))
+        .collect();
+    let gets: Vec<String> = gets.iter().map(|item_key| item_key.to_string()).collect();
+    let request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_NAMESPACES,
+        runtime_args! {
+            ARG_PUTS => puts,
+            ARG_CLEAR => clear,
+            ARG_GETS => gets,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+
+    match builder
+        .query(
+            None,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &[VALUES_KEY_NAME.to_string()],
+        )
+        .expect("should query values")
+    {
+        StoredValue::CLValue(cl_value) => cl_value.into_t().expect("should convert"),
+        stored_value => panic!("unexpected stored value {:?}", stored_value),
+    }
+}
+
+fn namespace_record_count(builder: &InMemoryWasmTestBuilder) -> usize {
+    builder
+        .get_keys(KeyTag::Namespace)
+        .expect("should get namespace keys")
+        .len()
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_put_and_get_namespace_items() {
+    let mut builder = setup();
+
+    let values = exec_namespaces(&mut builder, &[("a", 1), ("b", 2)], false, &["a", "b", "c"]);
+    assert_eq!(values, vec![Some(1), Some(2), None]);
+
+    let values = exec_namespaces(&mut builder, &[("a", 3)], false, &["a", "b"]);
+    assert_eq!(values, vec![Some(3), Some(2)]);
+    assert_eq!(namespace_record_count(&builder), 2);
+}
+
+#[ignore]
+#[test]
+fn should_not_read_items_of_cleared_namespace() {
+    let mut builder = setup();
+
+    let values = exec_namespaces(&mut builder, &[("a", 1), ("b", 2)], true, &["a", "b"]);
+    assert_eq!(values, vec![None, None]);
+
+    let values = exec_namespaces(&mut builder, &[("b", 3)], false, &["a", "b"]);
+    assert_eq!(values, vec![None, Some(3)]);
+}
+
+#[ignore]
+#[test]
+fn should_prune_items_of_cleared_namespace() {
+    let mut builder = setup();
+
+    exec_namespaces(&mut builder, &[("a", 1), ("b", 2), ("c", 3)], true, &[]);
+    exec_namespaces(&mut builder, &[("d", 4)], false, &[]);
+    // Three retired items, their retirement marker and the item of the current generation.
+    assert_eq!(namespace_record_count(&builder), 5);
+
+    builder.commit_prune_namespaces(2).expect_prune_success();
+    assert_eq!(namespace_record_count(&builder), 3);
+
+    builder.commit_prune_namespaces(2).expect_prune_success();
+    assert_eq!(namespace_record_count(&builder), 1);
+
+    // Nothing is left to prune.
+    builder.commit_prune_namespaces(2).expect_prune_success();
+    assert_eq!(namespace_record_count(&builder), 1);
+
+    let values = exec_namespaces(&mut builder, &[], false, &["a", "d"]);
+    assert_eq!(values, vec![None, Some(4)]);
+}
@@ -0,0 +1,78 @@
+use casper_engine_test_support::{
+    DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{runtime_args, Key, RuntimeArgs, StoredValue};
+
+const CONTRACT_NEW_UNIQUE_ADDRESS: &str = "new_unique_address.wasm";
+const ARG_TAGS: &str = "tags";
+const ADDRESSES_KEY_NAME: &str = "addresses";
+const DEPLOY_HASH_1: [u8; 32] = [1; 32];
+const DEPLOY_HASH_2: [u8; 32] = [2; 32];
+
+fn exec_new_unique_address(
+    builder: &mut InMemoryWasmTestBuilder,
+    deploy_hash: [u8; 32],
+    tags: &[&str],
+) -> Vec<[u8; 32]> {
+    let tags: Vec<String> = tags.iter().map(|tag| tag.to_string()).collect();
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_NEW_UNIQUE_ADDRESS,
+            runtime_args! { ARG_TAGS => tags },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+    let request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+    builder.exec(request).expect_success().commit();
+
+    match builder
+        .query(
+            None,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &[ADDRESSES_KEY_NAME.to_string()],
+        )
+        .expect("should query addresses")
+    {
+        StoredValue::CLValue(cl_value) => cl_value.into_t().expect("should convert"),
+        stored_value => panic!("unexpected stored value {:?}", stored_value),
+    }
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_create_distinct_addresses_within_deploy() {
+    let mut builder = setup();
+
+    let addresses = exec_new_unique_address(&mut builder, DEPLOY_HASH_1, &["a", "a", "b"]);
+    assert_ne!(addresses[0], addresses[1]);
+    assert_ne!(addresses[0], addresses[2]);
+    assert_ne!(addresses[1], addresses[2]);
+}
+
+#[ignore]
+#[test]
+fn should_create_distinct_addresses_for_distinct_deploys() {
+    let mut builder = setup();
+
+    let addresses_1 = exec_new_unique_address(&mut builder, DEPLOY_HASH_1, &["a"]);
+    let addresses_2 = exec_new_unique_address(&mut builder, DEPLOY_HASH_2, &["a"]);
+    assert_ne!(addresses_1, addresses_2);
+}
+
+#[ignore]
+#[test]
+fn should_create_same_addresses_when_deploy_is_reexecuted() {
+    let addresses_1 = exec_new_unique_address(&mut setup(), DEPLOY_HASH_1, &["a", "b"]);
+    let addresses_2 = exec_new_unique_address(&mut setup(), DEPLOY_HASH_1, &["a", "b"]);
+    assert_eq!(addresses_1, addresses_2);
+}
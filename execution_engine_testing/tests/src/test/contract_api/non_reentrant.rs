@@ -0,0 +1,89 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{engine_state::Error, execution};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs};
+
+const CONTRACT_NON_REENTRANT: &str = "non_reentrant.wasm";
+const HASH_KEY_NAME: &str = "non_reentrant_hash";
+const METHOD_RECURSE: &str = "recurse";
+const METHOD_RECURSE_REENTRANT: &str = "recurse_reentrant";
+const ARG_DEPTH: &str = "depth";
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_NON_REENTRANT,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let contract_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    (builder, contract_hash)
+}
+
+fn call_contract<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    contract_hash: ContractHash,
+    entry_point_name: &str,
+    depth: u32,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        entry_point_name,
+        runtime_args! { ARG_DEPTH => depth },
+    )
+    .build();
+    builder.exec(request).commit()
+}
+
+#[ignore]
+#[test]
+fn should_call_non_reentrant_entry_point() {
+    let (mut builder, contract_hash) = setup();
+
+    call_contract(&mut builder, contract_hash, METHOD_RECURSE, 0).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_refuse_reentrant_call_to_non_reentrant_entry_point() {
+    let (mut builder, contract_hash) = setup();
+
+    let error = call_contract(&mut builder, contract_hash, METHOD_RECURSE, 1)
+        .expect_failure()
+        .get_error()
+        .expect("should have error");
+    assert!(
+        matches!(
+            &error,
+            Error::Exec(execution::Error::ReentrantCall {
+                contract_hash: reentered_contract_hash,
+                entry_point_name,
+            }) if *reentered_contract_hash == contract_hash && entry_point_name == METHOD_RECURSE
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_reentrant_call_to_contract_entry_point() {
+    let (mut builder, contract_hash) = setup();
+
+    call_contract(&mut builder, contract_hash, METHOD_RECURSE_REENTRANT, 2).expect_success();
+}
@@ -0,0 +1,110 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{engine_state::Error, execution};
+use casper_types::{runtime_args, ContractPackageHash, RuntimeArgs};
+
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const CONTRACT_SET_CONTRACT_PACKAGE_PAUSED: &str = "set_contract_package_paused.wasm";
+const PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const ARG_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+const ARG_PAUSED: &str = "paused";
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractPackageHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let contract_package_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(PACKAGE_HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have contract package hash");
+
+    (builder, contract_package_hash)
+}
+
+fn set_paused(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    paused: bool,
+) {
+    let request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SET_CONTRACT_PACKAGE_PAUSED,
+        runtime_args! {
+            ARG_CONTRACT_PACKAGE_HASH => contract_package_hash,
+            ARG_PAUSED => paused,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
+fn call_contract(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+) -> &mut InMemoryWasmTestBuilder {
+    let request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_package_hash,
+        None,
+        ENTRY_FUNCTION_NAME,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(request).commit()
+}
+
+#[ignore]
+#[test]
+fn should_refuse_calls_to_paused_contract_package() {
+    let (mut builder, contract_package_hash) = setup();
+
+    set_paused(&mut builder, contract_package_hash, true);
+
+    let contract_package = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have contract package");
+    assert!(contract_package.is_paused());
+
+    let error = call_contract(&mut builder, contract_package_hash)
+        .expect_failure()
+        .get_error()
+        .expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(execution::Error::EntityPaused(hash)) if hash == contract_package_hash
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_calls_to_unpaused_contract_package() {
+    let (mut builder, contract_package_hash) = setup();
+
+    set_paused(&mut builder, contract_package_hash, true);
+    set_paused(&mut builder, contract_package_hash, false);
+
+    let contract_package = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have contract package");
+    assert!(!contract_package.is_paused());
+
+    call_contract(&mut builder, contract_package_hash).expect_success();
+}
@@ -0,0 +1,277 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, StepRequestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        engine_config::DEFAULT_MAX_SCHEDULED_CALLBACK_GAS, EngineConfigBuilder, Error as CoreError,
+        StepReport,
+    },
+    execution::Error as ExecError,
+};
+use casper_types::{
+    runtime_args, system::handle_payment::ACCUMULATION_PURSE_KEY, ApiError, ContractHash, EraId,
+    Key, ProtocolVersion, RuntimeArgs, StoredValue, U512,
+};
+
+const CONTRACT_SCHEDULED_CALLBACK: &str = "scheduled_callback.wasm";
+const HASH_KEY_NAME: &str = "scheduled_callback_hash";
+const PURSE_KEY_NAME: &str = "purse";
+const COUNTER_KEY_NAME: &str = "counter";
+const METHOD_CANCEL: &str = "cancel";
+const METHOD_TICK: &str = "tick";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ERA_INTERVAL: &str = "era_interval";
+const ARG_GAS_BUDGET: &str = "gas_budget";
+const ARG_DRAIN: &str = "drain";
+const GAS_BUDGET: u64 = 10_000_000_000;
+
+fn setup(amount: u64, era_interval: u64, drain: bool) -> (InMemoryWasmTestBuilder, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    let contract_hash = install(&mut builder, amount, era_interval, drain);
+    (builder, contract_hash)
+}
+
+fn install(
+    builder: &mut InMemoryWasmTestBuilder,
+    amount: u64,
+    era_interval: u64,
+    drain: bool,
+) -> ContractHash {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULED_CALLBACK,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(amount),
+            ARG_ERA_INTERVAL => era_interval,
+            ARG_GAS_BUDGET => GAS_BUDGET,
+            ARG_DRAIN => drain,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .copied()
+        .and_then(Key::into_hash)
+        .map(ContractHash::new)
+        .expect("should have contract hash")
+}
+
+fn step(builder: &mut InMemoryWasmTestBuilder, next_era_id: u64) -> StepReport {
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(ProtocolVersion::V1_0_0)
+        .with_next_era_id(EraId::from(next_era_id))
+        .build();
+    builder.step(step_request).expect("should step").step_report
+}
+
+fn get_counter(builder: &mut InMemoryWasmTestBuilder, contract_hash: ContractHash) -> u64 {
+    match builder.query(
+        None,
+        Key::from(contract_hash),
+        &[COUNTER_KEY_NAME.to_string()],
+    ) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should be a u64"),
+        result => panic!("unexpected query result {:?}", result),
+    }
+}
+
+fn get_purse_balance(builder: &mut InMemoryWasmTestBuilder, contract_hash: ContractHash) -> U512 {
+    let purse = builder
+        .get_contract(contract_hash)
+        .expect("should have contract")
+        .named_keys()
+        .get(PURSE_KEY_NAME)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have purse");
+    builder.get_purse_balance(purse)
+}
+
+#[ignore]
+#[test]
+fn should_call_scheduled_callback_every_interval() {
+    let amount = 3 * GAS_BUDGET;
+    let (mut builder, contract_hash) = setup(amount, 1, false);
+    assert_eq!(get_counter(&mut builder, contract_hash), 0);
+
+    let accumulation_purse = builder
+        .get_handle_payment_contract()
+        .named_keys()
+        .get(ACCUMULATION_PURSE_KEY)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have accumulation purse");
+    let accumulated_before = builder.get_purse_balance(accumulation_purse);
+
+    let step_report = step(&mut builder, 1);
+    assert_eq!(step_report.scheduled_callbacks.len(), 1);
+    let result = &step_report.scheduled_callbacks[0];
+    assert_eq!(result.entry_point, METHOD_TICK);
+    assert_eq!(result.error, None);
+    assert!(!result.cost.is_zero());
+    assert!(result.cost <= U512::from(GAS_BUDGET));
+    assert_eq!(get_counter(&mut builder, contract_hash), 1);
+
+    // The gas used is moved from the callback's purse to the accumulation purse.
+    assert_eq!(
+        get_purse_balance(&mut builder, contract_hash),
+        U512::from(amount) - result.cost
+    );
+    assert_eq!(
+        builder.get_purse_balance(accumulation_purse),
+        accumulated_before + result.cost
+    );
+
+    step(&mut builder, 2);
+    assert_eq!(get_counter(&mut builder, contract_hash), 2);
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        METHOD_CANCEL,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let step_report = step(&mut builder, 3);
+    assert!(step_report.scheduled_callbacks.is_empty());
+    assert_eq!(get_counter(&mut builder, contract_hash), 2);
+}
+
+#[ignore]
+#[test]
+fn should_not_call_scheduled_callback_before_it_is_due() {
+    let (mut builder, contract_hash) = setup(GAS_BUDGET, 2, false);
+
+    let step_report = step(&mut builder, 1);
+    assert!(step_report.scheduled_callbacks.is_empty());
+    assert_eq!(get_counter(&mut builder, contract_hash), 0);
+
+    let step_report = step(&mut builder, 2);
+    assert_eq!(step_report.scheduled_callbacks.len(), 1);
+    assert_eq!(get_counter(&mut builder, contract_hash), 1);
+}
+
+#[ignore]
+#[test]
+fn should_charge_scheduled_callback_which_drains_its_purse() {
+    let amount = 3 * GAS_BUDGET;
+    let (mut builder, contract_hash) = setup(amount, 1, true);
+
+    let accumulation_purse = builder
+        .get_handle_payment_contract()
+        .named_keys()
+        .get(ACCUMULATION_PURSE_KEY)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have accumulation purse");
+    let accumulated_before = builder.get_purse_balance(accumulation_purse);
+
+    let step_report = step(&mut builder, 1);
+    assert_eq!(step_report.scheduled_callbacks.len(), 1);
+    let result = &step_report.scheduled_callbacks[0];
+    assert_eq!(result.error, None);
+    assert!(!result.cost.is_zero());
+    assert_eq!(get_counter(&mut builder, contract_hash), 1);
+
+    // The gas budget was set aside before the callback ran, so only the rest could be drained,
+    // and the unused part of the budget is refunded afterwards.
+    assert_eq!(
+        get_purse_balance(&mut builder, contract_hash),
+        U512::from(GAS_BUDGET) - result.cost
+    );
+    assert_eq!(
+        builder.get_purse_balance(accumulation_purse),
+        accumulated_before + result.cost
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_call_scheduled_callback_with_insufficient_funds() {
+    let (mut builder, contract_hash) = setup(GAS_BUDGET - 1, 1, false);
+
+    let step_report = step(&mut builder, 1);
+    assert_eq!(step_report.scheduled_callbacks.len(), 1);
+    assert!(step_report.scheduled_callbacks[0].error.is_some());
+    assert!(step_report.scheduled_callbacks[0].cost.is_zero());
+    assert_eq!(get_counter(&mut builder, contract_hash), 0);
+    assert_eq!(
+        get_purse_balance(&mut builder, contract_hash),
+        U512::from(GAS_BUDGET - 1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_call_every_scheduled_callback_over_the_cap_in_later_steps() {
+    const MAX_CALLBACKS: u32 = 2;
+    const CALLBACK_COUNT: usize = 5;
+
+    let engine_config = EngineConfigBuilder::default()
+        .with_max_scheduled_callbacks_per_step(MAX_CALLBACKS)
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let contract_hashes: Vec<ContractHash> = (0..CALLBACK_COUNT)
+        .map(|_| install(&mut builder, 10 * GAS_BUDGET, 1, false))
+        .collect();
+
+    // Every callback is due at each step, but only two are called per step.  The ones left out
+    // are called ahead of those already called, so none is called twice before the others.
+    for next_era_id in 1..=2 {
+        let step_report = step(&mut builder, next_era_id);
+        assert_eq!(
+            step_report.scheduled_callbacks.len(),
+            MAX_CALLBACKS as usize
+        );
+        for contract_hash in &contract_hashes {
+            assert!(get_counter(&mut builder, *contract_hash) <= 1);
+        }
+    }
+
+    // The third step calls the last callback left out, so every callback was called.
+    let step_report = step(&mut builder, 3);
+    assert_eq!(
+        step_report.scheduled_callbacks.len(),
+        MAX_CALLBACKS as usize
+    );
+    let counters: Vec<u64> = contract_hashes
+        .iter()
+        .map(|contract_hash| get_counter(&mut builder, *contract_hash))
+        .collect();
+    assert!(counters.iter().all(|counter| *counter >= 1));
+    assert_eq!(counters.iter().sum::<u64>(), 3 * MAX_CALLBACKS as u64);
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_callback_over_max_gas() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULED_CALLBACK,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(GAS_BUDGET),
+            ARG_ERA_INTERVAL => 1u64,
+            ARG_GAS_BUDGET => DEFAULT_MAX_SCHEDULED_CALLBACK_GAS + 1,
+            ARG_DRAIN => false,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+    builder.assert_error(CoreError::Exec(ExecError::Revert(
+        ApiError::InvalidArgument,
+    )));
+}
@@ -0,0 +1,136 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{bytesrepr::FromBytes, runtime_args, CLTyped, Key, RuntimeArgs, StoredValue};
+
+const CONTRACT_SORTED_INDEX: &str = "sorted_index.wasm";
+const ARG_INSERTS: &str = "inserts";
+const ARG_REMOVALS: &str = "removals";
+const ARG_START: &str = "start";
+const ARG_LIMIT: &str = "limit";
+
+fn exec_sorted_index(
+    builder: &mut InMemoryWasmTestBuilder,
+    inserts: Vec<(u64, String)>,
+    removals: Vec<u64>,
+    start: Option<u64>,
+    limit: u32,
+) {
+    let request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SORTED_INDEX,
+        runtime_args! {
+            ARG_INSERTS => inserts,
+            ARG_REMOVALS => removals,
+            ARG_START => start,
+            ARG_LIMIT => limit,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+}
+
+fn query_named_value<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, name: &str) -> T {
+    match builder
+        .query(
+            None,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &[name.to_string()],
+        )
+        .expect("should query named value")
+    {
+        StoredValue::CLValue(cl_value) => cl_value.into_t().expect("should convert"),
+        stored_value => panic!("unexpected stored value {:?}", stored_value),
+    }
+}
+
+fn entries(entries: &[(u64, &str)]) -> Vec<(u64, String)> {
+    entries
+        .iter()
+        .map(|(entry_key, value)| (*entry_key, value.to_string()))
+        .collect()
+}
+
+#[ignore]
+#[test]
+fn should_read_sorted_index_in_order_of_entry_keys() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    exec_sorted_index(
+        &mut builder,
+        entries(&[(300, "c"), (1, "a"), (u64::MAX, "max"), (256, "b")]),
+        Vec::new(),
+        None,
+        10,
+    );
+
+    assert_eq!(
+        query_named_value::<Vec<u64>>(&builder, "ascending"),
+        vec![1, 256, 300, u64::MAX]
+    );
+    assert_eq!(
+        query_named_value::<Vec<u64>>(&builder, "descending"),
+        vec![u64::MAX, 300, 256, 1]
+    );
+    assert_eq!(
+        query_named_value::<Vec<String>>(&builder, "values"),
+        vec!["a", "b", "c", "max"]
+    );
+    assert_eq!(query_named_value::<Option<u64>>(&builder, "min"), Some(1));
+    assert_eq!(
+        query_named_value::<Option<u64>>(&builder, "max"),
+        Some(u64::MAX)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_skip_removed_entries_and_page_from_start_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    exec_sorted_index(
+        &mut builder,
+        entries(&[(10, "a"), (20, "b"), (30, "c"), (40, "d")]),
+        Vec::new(),
+        None,
+        10,
+    );
+
+    // Replace the value of an existing entry, and remove an existing and a missing entry.
+    exec_sorted_index(
+        &mut builder,
+        entries(&[(20, "B")]),
+        vec![10, 99],
+        Some(20),
+        2,
+    );
+
+    assert_eq!(
+        query_named_value::<Vec<bool>>(&builder, "removed"),
+        vec![true, false]
+    );
+    assert_eq!(
+        query_named_value::<Vec<u64>>(&builder, "ascending"),
+        vec![20, 30]
+    );
+    assert_eq!(
+        query_named_value::<Vec<u64>>(&builder, "descending"),
+        vec![20]
+    );
+    assert_eq!(
+        query_named_value::<Vec<String>>(&builder, "values"),
+        vec!["B", "c"]
+    );
+    assert_eq!(query_named_value::<Option<u64>>(&builder, "min"), Some(20));
+    assert_eq!(query_named_value::<Option<u64>>(&builder, "max"), Some(40));
+
+    // Removing every entry leaves the index empty.
+    exec_sorted_index(&mut builder, Vec::new(), vec![20, 30, 40], None, 10);
+
+    assert!(query_named_value::<Vec<u64>>(&builder, "ascending").is_empty());
+    assert_eq!(query_named_value::<Option<u64>>(&builder, "min"), None);
+    assert_eq!(query_named_value::<Option<u64>>(&builder, "max"), None);
+}
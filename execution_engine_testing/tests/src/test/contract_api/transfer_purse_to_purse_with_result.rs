@@ -0,0 +1,69 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{runtime_args, Key, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_PURSE_WITH_RESULT: &str =
+    "transfer_purse_to_purse_with_result.wasm";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ID: &str = "id";
+const TARGET_PURSE: &str = "purse:secondary";
+const TRANSFER_KEY: &str = "transfer";
+const TRANSFER_AMOUNT: u64 = 42;
+const TRANSFER_ID: u64 = 1234;
+
+#[ignore]
+#[test]
+fn should_return_address_of_recorded_transfer() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_PURSE_WITH_RESULT,
+        runtime_args! {
+            ARG_TARGET => TARGET_PURSE,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            ARG_ID => Some(TRANSFER_ID),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+
+    let transfer_addr = match default_account.named_keys().get(TRANSFER_KEY) {
+        Some(Key::Transfer(transfer_addr)) => *transfer_addr,
+        other => panic!("expected transfer key, got {:?}", other),
+    };
+
+    // The returned address is the one under which the execution recorded the transfer.
+    let exec_result = builder
+        .get_exec_result_owned(0)
+        .expect("should have exec result");
+    let recorded_transfers = exec_result[0].transfers();
+    assert_eq!(recorded_transfers, &[transfer_addr]);
+
+    let transfer = builder
+        .get_transfer(transfer_addr)
+        .expect("should have transfer record");
+    assert_eq!(transfer.id, Some(TRANSFER_ID));
+    assert_eq!(transfer.amount, U512::from(TRANSFER_AMOUNT));
+    assert_eq!(transfer.source, default_account.main_purse());
+
+    let target_purse = default_account.named_keys()[TARGET_PURSE]
+        .into_uref()
+        .expect("should be uref");
+    assert_eq!(transfer.target, target_purse);
+    assert_eq!(
+        builder.get_purse_balance(target_purse),
+        U512::from(TRANSFER_AMOUNT)
+    );
+}
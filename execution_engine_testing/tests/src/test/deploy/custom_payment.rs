@@ -0,0 +1,122 @@
+use casper_engine_test_support::{
+    DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_GAS_PRICE, DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::{
+    custom_payment::{ARG_AMOUNT, ARG_GAS_BUDGET},
+    Error, MAX_PAYMENT,
+};
+use casper_types::{runtime_args, ApiError, Gas, RuntimeArgs, U512};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const TRANSFER_MAIN_PURSE_TO_NEW_PURSE_WASM: &str = "transfer_main_purse_to_new_purse.wasm";
+const NAMED_PURSE_PAYMENT_WASM: &str = "named_purse_payment.wasm";
+const ARG_PURSE_NAME: &str = "purse_name";
+const ARG_DESTINATION: &str = "destination";
+const PAYMENT_PURSE_NAME: &str = "payment-purse";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let create_purse_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_MAIN_PURSE_TO_NEW_PURSE_WASM,
+        runtime_args! { ARG_DESTINATION => PAYMENT_PURSE_NAME, ARG_AMOUNT => *DEFAULT_PAYMENT },
+    )
+    .build();
+    builder.exec(create_purse_request).expect_success().commit();
+    builder
+}
+
+/// Pays for a deploy doing nothing with the named purse payment, returning the error the deploy
+/// failed with and the amount the proposer was paid.
+fn pay(builder: &mut InMemoryWasmTestBuilder, payment_args: RuntimeArgs) -> (Error, U512) {
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+        .with_payment_code(NAMED_PURSE_PAYMENT_WASM, payment_args)
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([42; 32])
+        .build();
+    let request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let proposer_starting_balance = builder.get_proposer_purse_balance();
+    builder.exec(request).expect_failure().commit();
+    let error = builder.get_error().expect("should have error");
+    let fee = builder.get_proposer_purse_balance() - proposer_starting_balance;
+    (error, fee)
+}
+
+#[ignore]
+#[test]
+fn should_report_custom_payment_exceeding_gas_budget() {
+    let mut builder = setup();
+
+    let gas_budget = 1_000u64;
+    let (error, fee) = pay(
+        &mut builder,
+        runtime_args! {
+            ARG_PURSE_NAME => PAYMENT_PURSE_NAME,
+            ARG_AMOUNT => *DEFAULT_PAYMENT,
+            ARG_GAS_BUDGET => gas_budget,
+        },
+    );
+
+    assert!(
+        matches!(error, Error::CustomPaymentGasBudgetExceeded(budget) if budget == Gas::from(gas_budget)),
+        "{:?}",
+        error
+    );
+    // The account is charged no more than its gas budget.
+    assert_eq!(fee, U512::from(gas_budget * DEFAULT_GAS_PRICE));
+}
+
+#[ignore]
+#[test]
+fn should_report_custom_payment_depositing_too_little() {
+    let mut builder = setup();
+
+    let (error, fee) = pay(
+        &mut builder,
+        runtime_args! {
+            ARG_PURSE_NAME => PAYMENT_PURSE_NAME,
+            ARG_AMOUNT => U512::one(),
+        },
+    );
+
+    assert!(
+        matches!(
+            error,
+            Error::CustomPaymentInsufficientDeposit { required, deposited }
+                if deposited.value() == U512::one() && required > deposited
+        ),
+        "{:?}",
+        error
+    );
+    assert_eq!(fee, *MAX_PAYMENT);
+}
+
+#[ignore]
+#[test]
+fn should_report_custom_payment_reverting() {
+    let mut builder = setup();
+
+    let (error, fee) = pay(
+        &mut builder,
+        runtime_args! {
+            ARG_PURSE_NAME => "missing-purse",
+            ARG_AMOUNT => *DEFAULT_PAYMENT,
+        },
+    );
+
+    assert!(
+        matches!(
+            error,
+            Error::CustomPaymentReverted(ApiError::InvalidPurseName)
+        ),
+        "{:?}",
+        error
+    );
+    assert_eq!(fee, *MAX_PAYMENT);
+}
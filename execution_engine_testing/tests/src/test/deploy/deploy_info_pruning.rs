@@ -0,0 +1,77 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{account::AccountHash, runtime_args, DeployHash, Key, RuntimeArgs, U512};
+
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1; 32]);
+const TRANSFER_AMOUNT: u64 = 2_500_000_000;
+
+fn transfer(builder: &mut InMemoryWasmTestBuilder, id: u64) -> DeployHash {
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => ACCOUNT_1_ADDR,
+            TRANSFER_ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            TRANSFER_ARG_ID => Some(id),
+        },
+    )
+    .build();
+    let deploy_hash = transfer_request.deploys()[0].deploy_hash;
+
+    builder.exec(transfer_request).commit().expect_success();
+    deploy_hash
+}
+
+fn has_deploy_info(builder: &InMemoryWasmTestBuilder, deploy_hash: DeployHash) -> bool {
+    builder
+        .query(None, Key::DeployInfo(deploy_hash), &[])
+        .is_ok()
+}
+
+#[ignore]
+#[test]
+fn should_prune_deploy_infos_of_given_deploys_only() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let expired_deploy_hash = transfer(&mut builder, 0);
+    let recent_deploy_hash = transfer(&mut builder, 1);
+    assert!(has_deploy_info(&builder, expired_deploy_hash));
+    assert!(has_deploy_info(&builder, recent_deploy_hash));
+
+    let pre_state_hash = builder.get_post_state_hash();
+    builder
+        .commit_prune_deploy_infos(&[expired_deploy_hash])
+        .expect_prune_success();
+
+    assert_ne!(builder.get_post_state_hash(), pre_state_hash);
+    assert!(!has_deploy_info(&builder, expired_deploy_hash));
+    assert!(has_deploy_info(&builder, recent_deploy_hash));
+}
+
+#[ignore]
+#[test]
+fn should_skip_deploys_without_deploy_info_when_pruning() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let deploy_hash = transfer(&mut builder, 0);
+    let unknown_deploy_hash = DeployHash::new([42; 32]);
+
+    builder
+        .commit_prune_deploy_infos(&[deploy_hash, unknown_deploy_hash, deploy_hash])
+        .expect_prune_success();
+    assert!(!has_deploy_info(&builder, deploy_hash));
+
+    // Pruning already pruned deploys leaves the state untouched.
+    let pre_state_hash = builder.get_post_state_hash();
+    builder
+        .commit_prune_deploy_infos(&[deploy_hash])
+        .expect_prune_success();
+    assert_eq!(builder.get_post_state_hash(), pre_state_hash);
+}
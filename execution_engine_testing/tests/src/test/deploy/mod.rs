@@ -1,5 +1,8 @@
 mod context_association;
+mod custom_payment;
+mod deploy_info_pruning;
 mod non_standard_payment;
 mod preconditions;
 mod receipts;
 mod stored_contracts;
+mod validate_block_payload;
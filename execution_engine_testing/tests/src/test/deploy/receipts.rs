@@ -3,13 +3,20 @@ use std::collections::{BTreeMap, BTreeSet};
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
-    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PROTOCOL_VERSION,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_execution_engine::shared::system_config::DEFAULT_WASMLESS_TRANSFER_COST;
+use casper_execution_engine::{
+    core::engine_state::{
+        engine_config::{CompatibilityMode, Quirk},
+        EngineConfigBuilder,
+    },
+    shared::system_config::DEFAULT_WASMLESS_TRANSFER_COST,
+};
 use casper_types::{
     account::AccountHash, runtime_args, system::mint, AccessRights, Contract, ContractHash,
-    DeployHash, PublicKey, RuntimeArgs, SecretKey, Transfer, TransferAddr, U512,
+    DeployHash, Key, PublicKey, RuntimeArgs, SecretKey, SystemTransferKind, Transfer, TransferAddr,
+    U512,
 };
 
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
@@ -24,6 +31,8 @@ const TRANSFER_ARG_TARGETS: &str = "targets";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNTS_STORED: &str = "transfer_purse_to_accounts_stored.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNTS_SUBCALL: &str = "transfer_purse_to_accounts_subcall.wasm";
 
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+
 const HASH_KEY_NAME: &str = "transfer_purse_to_accounts_hash";
 const PURSE_NAME: &str = "purse";
 
@@ -605,3 +614,91 @@ fn should_record_wasm_transfers_with_subcall() {
         );
     }
 }
+
+fn exec_do_nothing_system_transfers(builder: &mut InMemoryWasmTestBuilder) -> Vec<Transfer> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_result = builder
+        .get_last_exec_results()
+        .expect("should have results")
+        .pop()
+        .expect("should have one result");
+    // System transfers are only recorded in global state, not in the deploy's own transfers.
+    assert!(exec_result.transfers().is_empty());
+    exec_result
+        .execution_journal()
+        .iter()
+        .filter_map(|(key, _transform)| match key {
+            Key::Transfer(transfer_addr) => Some(
+                builder
+                    .get_transfer(*transfer_addr)
+                    .expect("should have transfer"),
+            ),
+            _ => None,
+        })
+        .filter(Transfer::is_system)
+        .collect()
+}
+
+#[ignore]
+#[test]
+fn should_record_payment_refund_and_fee_as_system_transfers() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account");
+
+    let transfers = exec_do_nothing_system_transfers(&mut builder);
+    assert_eq!(transfers.len(), 2, "{:?}", transfers);
+
+    let refund = transfers
+        .iter()
+        .find(|transfer| transfer.system_kind() == Some(SystemTransferKind::PaymentRefund))
+        .expect("should record refund");
+    assert_eq!(refund.from, PublicKey::System.to_account_hash());
+    assert_eq!(refund.to, Some(*DEFAULT_ACCOUNT_ADDR));
+    assert_eq!(
+        refund.target,
+        default_account
+            .main_purse()
+            .with_access_rights(AccessRights::ADD)
+    );
+    assert!(!refund.amount.is_zero());
+
+    let fee = transfers
+        .iter()
+        .find(|transfer| transfer.system_kind() == Some(SystemTransferKind::PaymentFee))
+        .expect("should record fee");
+    assert_eq!(fee.source, refund.source);
+    assert!(!fee.amount.is_zero());
+
+    // System transfers aren't transfers made by the deploy.
+    let deploy_info = builder
+        .get_deploy_info(refund.deploy_hash)
+        .expect("should have deploy info");
+    assert!(deploy_info.transfers.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_record_system_transfers_with_quirk() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_compatibility_mode(
+            *DEFAULT_PROTOCOL_VERSION,
+            CompatibilityMode::new().with_quirk(Quirk::UnrecordedPaymentTransfers),
+        )
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let transfers = exec_do_nothing_system_transfers(&mut builder);
+    assert!(transfers.is_empty(), "{:?}", transfers);
+}
@@ -0,0 +1,101 @@
+use casper_engine_test_support::{
+    DeployItemBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_ACCOUNT_INITIAL_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::{
+    deploy_item::DeployItem, InvalidDeploy, InvalidDeployReason, ValidateBlockPayloadResult,
+    MAX_PAYMENT,
+};
+use casper_types::{
+    account::{AccountHash, ApprovalsFailure},
+    runtime_args, DeployHash, Motes, RuntimeArgs, U512,
+};
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+const ARG_AMOUNT: &str = "amount";
+
+fn deploy_item(
+    deploy_hash: [u8; 32],
+    authorization_keys: &[AccountHash],
+    payment_amount: U512,
+) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_code("do_nothing.wasm", RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => payment_amount })
+        .with_authorization_keys(authorization_keys)
+        .with_deploy_hash(deploy_hash)
+        .build()
+}
+
+fn invalid_deploys(result: ValidateBlockPayloadResult) -> Vec<InvalidDeploy> {
+    match result {
+        ValidateBlockPayloadResult::Success { invalid_deploys } => invalid_deploys,
+        ValidateBlockPayloadResult::RootNotFound => panic!("should find state root"),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_accept_valid_block_payload() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let result = builder.validate_block_payload(vec![
+        deploy_item([1; 32], &[*DEFAULT_ACCOUNT_ADDR], *MAX_PAYMENT),
+        deploy_item([2; 32], &[*DEFAULT_ACCOUNT_ADDR], *MAX_PAYMENT),
+    ]);
+    assert!(result.is_valid(), "{:?}", result);
+}
+
+#[ignore]
+#[test]
+fn should_report_invalid_deploys_of_block_payload() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let nonexistent_account = DeployItemBuilder::new()
+        .with_address(ACCOUNT_1_ADDR)
+        .with_session_code("do_nothing.wasm", RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *MAX_PAYMENT })
+        .with_authorization_keys(&[ACCOUNT_1_ADDR])
+        .with_deploy_hash([3; 32])
+        .build();
+    // The first deploy leaves too little of the balance for the last one.
+    let half_balance = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE / 2);
+
+    let result = builder.validate_block_payload(vec![
+        deploy_item([1; 32], &[*DEFAULT_ACCOUNT_ADDR], half_balance),
+        deploy_item([1; 32], &[*DEFAULT_ACCOUNT_ADDR], *MAX_PAYMENT),
+        deploy_item([2; 32], &[ACCOUNT_1_ADDR], *MAX_PAYMENT),
+        nonexistent_account,
+        deploy_item([4; 32], &[*DEFAULT_ACCOUNT_ADDR], half_balance + 1),
+    ]);
+    assert!(!result.is_valid());
+    assert_eq!(
+        invalid_deploys(result),
+        vec![
+            InvalidDeploy {
+                deploy_hash: DeployHash::new([1; 32]),
+                reason: InvalidDeployReason::Duplicate,
+            },
+            InvalidDeploy {
+                deploy_hash: DeployHash::new([2; 32]),
+                reason: InvalidDeployReason::Authorization(ApprovalsFailure::UnassociatedKey(
+                    ACCOUNT_1_ADDR
+                )),
+            },
+            InvalidDeploy {
+                deploy_hash: DeployHash::new([3; 32]),
+                reason: InvalidDeployReason::AccountNotFound(ACCOUNT_1_ADDR),
+            },
+            InvalidDeploy {
+                deploy_hash: DeployHash::new([4; 32]),
+                reason: InvalidDeployReason::InsufficientBalance {
+                    required: Motes::new(half_balance + 1),
+                    available: Motes::new(half_balance),
+                },
+            },
+        ]
+    );
+}
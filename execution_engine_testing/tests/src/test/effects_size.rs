@@ -0,0 +1,99 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PROTOCOL_VERSION,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        engine_config::{CompatibilityMode, Quirk, DEFAULT_MAX_EFFECTS_SIZE_BYTES},
+        EngineConfigBuilder, Error,
+    },
+    execution::Error as ExecError,
+};
+use casper_types::RuntimeArgs;
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+
+fn exec_do_nothing(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_WASM,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+#[ignore]
+#[test]
+fn should_expose_effects_size_of_execution_result() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    exec_do_nothing(&mut builder);
+    builder.expect_success();
+
+    let exec_result = builder
+        .get_last_exec_results()
+        .expect("should have results")
+        .pop()
+        .expect("should have one result");
+    let effects_size = exec_result.effects_size();
+    assert!(effects_size > 0);
+    assert!(effects_size <= DEFAULT_MAX_EFFECTS_SIZE_BYTES as usize);
+}
+
+#[ignore]
+#[test]
+fn should_fail_session_with_effects_exceeding_max_size() {
+    let max_effects_size_bytes = 1;
+    let engine_config = EngineConfigBuilder::new()
+        .with_max_effects_size_bytes(max_effects_size_bytes)
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let balance_before = builder.get_purse_balance(
+        builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .main_purse(),
+    );
+
+    exec_do_nothing(&mut builder);
+    builder.expect_failure();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(ExecError::EffectsSizeExceeded { size, max })
+                if size > max && max == u64::from(max_effects_size_bytes)
+        ),
+        "{:?}",
+        error
+    );
+
+    // The failed session is still charged for.
+    let balance_after = builder.get_purse_balance(
+        builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .main_purse(),
+    );
+    assert!(balance_after < balance_before);
+}
+
+#[ignore]
+#[test]
+fn should_not_limit_effects_size_with_quirk() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_max_effects_size_bytes(1)
+        .with_compatibility_mode(
+            *DEFAULT_PROTOCOL_VERSION,
+            CompatibilityMode::new().with_quirk(Quirk::UnlimitedEffectsSize),
+        )
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    exec_do_nothing(&mut builder);
+    builder.expect_success();
+}
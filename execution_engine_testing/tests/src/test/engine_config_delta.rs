@@ -0,0 +1,88 @@
+use casper_engine_test_support::{InMemoryWasmTestBuilder, PRODUCTION_RUN_GENESIS_REQUEST};
+use casper_execution_engine::core::engine_state::engine_config::{
+    EngineConfigDelta, EngineConfigDeltaError,
+};
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_defer_config_delta_to_block_boundary() {
+    let mut builder = setup();
+    let module_cache_size = builder.get_engine_state().config().module_cache_size();
+    assert_ne!(module_cache_size, 0);
+
+    builder
+        .get_engine_state()
+        .stage_config_delta(EngineConfigDelta::new().with_module_cache_size(0), false)
+        .expect("should stage runtime option");
+    assert_eq!(
+        builder.get_engine_state().config().module_cache_size(),
+        module_cache_size
+    );
+
+    assert!(builder.apply_pending_config_delta());
+    assert_eq!(builder.get_engine_state().config().module_cache_size(), 0);
+    assert_eq!(builder.get_engine_state().module_cache_stats().capacity, 0);
+
+    assert!(!builder.apply_pending_config_delta());
+}
+
+#[ignore]
+#[test]
+fn should_only_stage_consensus_affecting_delta_during_upgrade() {
+    let mut builder = setup();
+    let delta = EngineConfigDelta::new().with_max_associated_keys(5);
+
+    assert_eq!(
+        builder
+            .get_engine_state()
+            .stage_config_delta(delta.clone(), false),
+        Err(EngineConfigDeltaError::ConsensusAffecting(
+            "max_associated_keys"
+        ))
+    );
+    assert!(!builder.apply_pending_config_delta());
+
+    builder
+        .get_engine_state()
+        .stage_config_delta(delta, true)
+        .expect("should stage during upgrade");
+    assert!(builder.apply_pending_config_delta());
+    assert_eq!(builder.get_engine_state().config().max_associated_keys(), 5);
+}
+
+#[ignore]
+#[test]
+fn should_validate_against_previously_staged_delta() {
+    let builder = setup();
+    let max_contract_call_stack_height = builder
+        .get_engine_state()
+        .config()
+        .max_contract_call_stack_height();
+
+    builder
+        .get_engine_state()
+        .stage_config_delta(
+            EngineConfigDelta::new()
+                .with_max_contract_call_stack_height(max_contract_call_stack_height + 1)
+                .with_max_runtime_call_stack_height(max_contract_call_stack_height + 1),
+            true,
+        )
+        .expect("should stage during upgrade");
+
+    assert_eq!(
+        builder.get_engine_state().stage_config_delta(
+            EngineConfigDelta::new().with_max_runtime_call_stack_height(1),
+            true
+        ),
+        Err(EngineConfigDeltaError::InvalidMaxContractCallStackHeight {
+            max_contract_call_stack_height: max_contract_call_stack_height + 1,
+            max_runtime_call_stack_height: 1,
+        })
+    );
+}
@@ -0,0 +1,81 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::engine_state::{ExecuteRequest, ExecutionStats},
+    shared::newtypes::CorrelationId,
+};
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const WASM_PAGE_SIZE: u64 = 65_536;
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+const ALICE_ADDR: AccountHash = AccountHash::new([3; 32]);
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+
+fn execute_with_stats(
+    builder: &InMemoryWasmTestBuilder,
+    mut exec_request: ExecuteRequest,
+) -> ExecutionStats {
+    exec_request.parent_state_hash = builder.get_post_state_hash();
+    let (execution_results, execution_stats) = builder
+        .get_engine_state()
+        .run_execute_with_stats(CorrelationId::new(), exec_request)
+        .expect("should execute");
+    assert_eq!(execution_results.len(), 1);
+    assert!(execution_results[0].as_error().is_none());
+    assert_eq!(execution_stats.len(), 1);
+    execution_stats[0]
+}
+
+#[ignore]
+#[test]
+fn should_measure_memory_of_wasm_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, RuntimeArgs::new())
+            .build();
+    let execution_stats = execute_with_stats(&builder, exec_request);
+
+    assert!(execution_stats.memory_high_water_mark() > 0);
+    assert_eq!(execution_stats.memory_high_water_mark() % WASM_PAGE_SIZE, 0);
+}
+
+#[ignore]
+#[test]
+fn should_measure_effects_size_of_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, RuntimeArgs::new())
+            .build();
+    let execution_stats = execute_with_stats(&builder, exec_request);
+
+    assert!(execution_stats.effects_size() > 0);
+}
+
+#[ignore]
+#[test]
+fn should_not_measure_memory_of_native_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    let execution_stats = execute_with_stats(&builder, exec_request);
+
+    assert_eq!(execution_stats.memory_high_water_mark(), 0);
+}
@@ -0,0 +1,92 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecutionWarning;
+use casper_types::{ContractHash, RuntimeArgs};
+
+const DO_NOTHING_STORED_WASM: &str = "do_nothing_stored.wasm";
+const HASH_KEY_NAME: &str = "do_nothing_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_STORED_WASM,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let contract_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+    (builder, contract_hash)
+}
+
+fn last_warnings(builder: &InMemoryWasmTestBuilder) -> Vec<ExecutionWarning> {
+    builder
+        .get_last_exec_results()
+        .expect("should have results")
+        .pop()
+        .expect("should have one result")
+        .warnings()
+        .to_vec()
+}
+
+#[ignore]
+#[test]
+fn should_warn_of_unversioned_contract_call() {
+    let (mut builder, contract_hash) = setup();
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        HASH_KEY_NAME,
+        ENTRY_FUNCTION_NAME,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert!(last_warnings(&builder)
+        .contains(&ExecutionWarning::UnversionedContractCall { contract_hash }));
+}
+
+#[ignore]
+#[test]
+fn should_not_warn_of_versioned_contract_call() {
+    let (mut builder, _) = setup();
+
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        PACKAGE_HASH_KEY_NAME,
+        None,
+        ENTRY_FUNCTION_NAME,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert!(!last_warnings(&builder)
+        .iter()
+        .any(|warning| matches!(warning, ExecutionWarning::UnversionedContractCall { .. })));
+}
+
+#[ignore]
+#[test]
+fn should_warn_of_excessive_payment() {
+    // The default payment is far more than storing a contract doing nothing costs.
+    let (builder, _) = setup();
+
+    assert!(last_warnings(&builder)
+        .iter()
+        .any(|warning| matches!(warning, ExecutionWarning::ExcessivePayment { .. })));
+}
@@ -0,0 +1,188 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        engine_config::{EngineConfigBuilder, ExistentialDepositHandling},
+        Error, ExecuteRequest,
+    },
+    execution,
+};
+use casper_types::{account::AccountHash, runtime_args, system::mint, Key, RuntimeArgs, U512};
+
+const EXISTENTIAL_DEPOSIT: u64 = 10_000_000_000;
+const ACCOUNT_1_INITIAL_BALANCE: u64 = 100_000_000_000;
+/// Leaves roughly half the existential deposit behind, after the transfer fee has been paid.
+const TRANSFER_AMOUNT: u64 = ACCOUNT_1_INITIAL_BALANCE - EXISTENTIAL_DEPOSIT / 2;
+
+const TRANSFER_MAIN_PURSE_TO_TWO_PURSES_WASM: &str = "transfer_main_purse_to_two_purses.wasm";
+const ARG_AMOUNT: &str = "amount";
+const ARG_DESTINATION_PURSE_ONE: &str = "destination_purse_one";
+const ARG_DESTINATION_PURSE_TWO: &str = "destination_purse_two";
+const ARG_TRANSFER_AMOUNT_ONE: &str = "transfer_amount_one";
+const ARG_TRANSFER_AMOUNT_TWO: &str = "transfer_amount_two";
+const DESTINATION_PURSE_ONE: &str = "swept_purse";
+const DESTINATION_PURSE_TWO: &str = "untouched_purse";
+
+static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([42; 32]));
+static ACCOUNT_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([43; 32]));
+
+fn transfer_request(sender: AccountHash, target: AccountHash, amount: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::transfer(
+        sender,
+        runtime_args! {
+            mint::ARG_TARGET => target,
+            mint::ARG_AMOUNT => U512::from(amount),
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build()
+}
+
+fn setup(existential_deposit_handling: ExistentialDepositHandling) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfigBuilder::new()
+        .with_existential_deposit(EXISTENTIAL_DEPOSIT)
+        .with_existential_deposit_handling(existential_deposit_handling)
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(transfer_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            *ACCOUNT_1_ADDR,
+            ACCOUNT_1_INITIAL_BALANCE,
+        ))
+        .expect_success()
+        .commit();
+
+    builder
+}
+
+fn main_purse_balance(builder: &InMemoryWasmTestBuilder, account_hash: AccountHash) -> U512 {
+    let main_purse = builder.get_expected_account(account_hash).main_purse();
+    builder.get_purse_balance(main_purse)
+}
+
+#[ignore]
+#[test]
+fn should_reject_transfer_leaving_balance_below_existential_deposit() {
+    let mut builder = setup(ExistentialDepositHandling::Reject);
+
+    builder
+        .exec(transfer_request(
+            *ACCOUNT_1_ADDR,
+            *ACCOUNT_2_ADDR,
+            TRANSFER_AMOUNT,
+        ))
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(execution::Error::Revert(revert)) if revert == mint::Error::BelowExistentialDeposit.into()
+        ),
+        "expected BelowExistentialDeposit error, found {:?}",
+        error
+    );
+    assert!(main_purse_balance(&builder, *ACCOUNT_1_ADDR) > U512::from(TRANSFER_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_sweep_remainder_below_existential_deposit() {
+    let mut builder = setup(ExistentialDepositHandling::Sweep);
+
+    let main_purse_balance_after_payment =
+        main_purse_balance(&builder, *DEFAULT_ACCOUNT_ADDR) - *DEFAULT_PAYMENT;
+    let transfer_amount = main_purse_balance_after_payment - EXISTENTIAL_DEPOSIT / 2;
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_MAIN_PURSE_TO_TWO_PURSES_WASM,
+        runtime_args! {
+            // Approves spending the whole main purse, remainder included.
+            ARG_AMOUNT => main_purse_balance_after_payment,
+            ARG_DESTINATION_PURSE_ONE => DESTINATION_PURSE_ONE,
+            ARG_TRANSFER_AMOUNT_ONE => transfer_amount,
+            ARG_DESTINATION_PURSE_TWO => DESTINATION_PURSE_TWO,
+            ARG_TRANSFER_AMOUNT_TWO => U512::zero(),
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let destination_purse = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(DESTINATION_PURSE_ONE)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have destination purse");
+    assert_eq!(
+        builder.get_purse_balance(destination_purse),
+        main_purse_balance_after_payment
+    );
+
+    let transfer_addr = builder
+        .get_last_exec_results()
+        .expect("should have results")[0]
+        .transfers()
+        .first()
+        .copied()
+        .expect("should have transfer");
+    let transfer = builder
+        .get_transfer(transfer_addr)
+        .expect("should have transfer record");
+    assert_eq!(transfer.target, destination_purse);
+    assert_eq!(transfer.amount, main_purse_balance_after_payment);
+}
+
+#[ignore]
+#[test]
+fn should_not_sweep_remainder_beyond_approved_spending_limit() {
+    let mut builder = setup(ExistentialDepositHandling::Sweep);
+
+    // A native transfer only approves spending the amount being transferred.
+    builder
+        .exec(transfer_request(
+            *ACCOUNT_1_ADDR,
+            *ACCOUNT_2_ADDR,
+            TRANSFER_AMOUNT,
+        ))
+        .expect_failure()
+        .commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(execution::Error::Revert(revert)) if revert == mint::Error::BelowExistentialDeposit.into()
+        ),
+        "expected BelowExistentialDeposit error, found {:?}",
+        error
+    );
+    assert!(main_purse_balance(&builder, *ACCOUNT_1_ADDR) > U512::from(TRANSFER_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_allow_transfer_retaining_existential_deposit() {
+    let mut builder = setup(ExistentialDepositHandling::Reject);
+
+    let amount = ACCOUNT_1_INITIAL_BALANCE - 2 * EXISTENTIAL_DEPOSIT;
+    builder
+        .exec(transfer_request(*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR, amount))
+        .expect_success()
+        .commit();
+
+    assert!(main_purse_balance(&builder, *ACCOUNT_1_ADDR) >= U512::from(EXISTENTIAL_DEPOSIT));
+    assert_eq!(
+        main_purse_balance(&builder, *ACCOUNT_2_ADDR),
+        U512::from(amount)
+    );
+}
@@ -4,11 +4,15 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_execution_engine::{core, core::ValidationError};
+use casper_execution_engine::{
+    core,
+    core::{engine_state::BalanceIdentifier, ValidationError},
+};
 use casper_hashing::Digest;
 use casper_types::{
-    account::AccountHash, runtime_args, AccessRights, Key, PublicKey, RuntimeArgs, SecretKey, URef,
-    U512,
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, system::handle_payment,
+    verify_merkle_proof, AccessRights, Key, MerkleProofError, PublicKey, RuntimeArgs, SecretKey,
+    URef, U512,
 };
 
 const TRANSFER_ARG_TARGET: &str = "target";
@@ -192,3 +196,130 @@ fn get_balance_using_public_key_should_work() {
         Err(ValidationError::UnexpectedValue)
     );
 }
+
+#[ignore]
+#[test]
+fn get_balance_by_identifier_should_be_consistent() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => *ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => *TRANSFER_AMOUNT_1,
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).commit().expect_success();
+
+    let alice_main_purse = builder
+        .get_account(*ALICE_ADDR)
+        .expect("should have Alice's account")
+        .main_purse();
+
+    let identifiers = [
+        BalanceIdentifier::Purse(alice_main_purse),
+        BalanceIdentifier::Account(*ALICE_ADDR),
+        BalanceIdentifier::PublicKey(ALICE_KEY.clone()),
+    ];
+    for identifier in identifiers {
+        let balance_result = builder.get_balance_result(identifier);
+        assert_eq!(balance_result.motes(), Some(&*TRANSFER_AMOUNT_1));
+        assert!(core::validate_balance_proof(
+            &builder.get_post_state_hash(),
+            &balance_result.proof().expect("should have proof"),
+            alice_main_purse.into(),
+            &TRANSFER_AMOUNT_1,
+        )
+        .is_ok());
+    }
+
+    let handle_payment = builder.get_handle_payment_contract();
+    let payment_purse = handle_payment.named_keys()[handle_payment::PAYMENT_PURSE_KEY]
+        .into_uref()
+        .expect("should be a uref");
+    assert_eq!(
+        builder
+            .get_balance_result(BalanceIdentifier::Payment)
+            .motes()
+            .cloned(),
+        Some(builder.get_purse_balance(payment_purse))
+    );
+    assert!(builder
+        .get_balance_result(BalanceIdentifier::Accumulate)
+        .motes()
+        .is_some());
+}
+
+#[ignore]
+#[test]
+fn get_balances_should_match_individual_balances() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => *ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => *TRANSFER_AMOUNT_1,
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).commit().expect_success();
+
+    let unknown_account = AccountHash::new([42; 32]);
+    let identifiers = vec![
+        BalanceIdentifier::Account(*ALICE_ADDR),
+        BalanceIdentifier::Account(unknown_account),
+        BalanceIdentifier::Account(*DEFAULT_ACCOUNT_ADDR),
+        BalanceIdentifier::Payment,
+    ];
+    let balances = builder.get_balances(identifiers.clone());
+    assert_eq!(balances.len(), identifiers.len());
+
+    for (identifier, balance) in identifiers.into_iter().zip(balances) {
+        assert_eq!(balance.identifier(), &identifier);
+        if identifier == BalanceIdentifier::Account(unknown_account) {
+            assert!(balance.motes().is_none());
+            assert!(balance.error().is_some());
+            continue;
+        }
+        let expected = builder.get_balance_result(identifier);
+        assert_eq!(balance.motes(), expected.motes());
+        assert_eq!(balance.proof(), expected.proof());
+    }
+}
+
+#[ignore]
+#[test]
+fn get_balance_proof_should_verify_without_the_engine() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let main_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let balance_proof = builder
+        .get_purse_balance_result(main_purse)
+        .proof()
+        .expect("should have proof");
+    let proof_bytes = balance_proof.to_bytes().expect("should serialize proof");
+
+    let state_root_hash = builder.get_post_state_hash();
+    let (key, value) =
+        verify_merkle_proof(&state_root_hash.value(), &proof_bytes).expect("should verify proof");
+    assert_eq!(key, *balance_proof.key());
+    assert_eq!(&value, balance_proof.value());
+
+    let bogus_hash = Digest::hash([5u8; 32]);
+    assert_eq!(
+        verify_merkle_proof(&bogus_hash.value(), &proof_bytes),
+        Err(MerkleProofError::UnexpectedRootHash)
+    );
+}
@@ -0,0 +1,74 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::engine_state::InvariantViolation,
+    shared::{additive_map::AdditiveMap, transform::Transform},
+};
+use casper_types::{
+    account::AccountHash, runtime_args, CLValue, Key, RuntimeArgs, StoredValue, U512,
+};
+
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+const ALICE_ADDR: AccountHash = AccountHash::new([3; 32]);
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+const ORPHAN_PURSE_ADDR: [u8; 32] = [42; 32];
+const ORPHAN_BALANCE: u64 = 1_000;
+
+#[ignore]
+#[test]
+fn should_hold_invariants_after_genesis_and_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    assert_eq!(builder.check_invariants(), vec![]);
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(builder.check_invariants(), vec![]);
+}
+
+#[ignore]
+#[test]
+fn should_report_balance_without_purse() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let total_supply = builder.total_supply(None);
+
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        Key::Balance(ORPHAN_PURSE_ADDR),
+        Transform::Write(StoredValue::CLValue(
+            CLValue::from_t(U512::from(ORPHAN_BALANCE)).unwrap(),
+        )),
+    );
+    let pre_state_hash = builder.get_post_state_hash();
+    builder.commit_transforms(pre_state_hash, effects);
+
+    let violations = builder.check_invariants();
+    assert_eq!(
+        violations,
+        vec![
+            InvariantViolation::BalanceWithoutPurse {
+                purse_addr: ORPHAN_PURSE_ADDR
+            },
+            InvariantViolation::SupplyMismatch {
+                total_supply,
+                total_balances: total_supply + ORPHAN_BALANCE,
+            },
+        ]
+    );
+}
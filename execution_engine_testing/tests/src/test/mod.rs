@@ -1,20 +1,32 @@
 mod bulk_update_with_scratch_trie;
 mod chainspec_registry;
 mod check_transfer_success;
+mod compatibility_mode;
 mod contract_api;
 mod contract_context;
 mod deploy;
+mod effects_size;
+mod engine_config_delta;
+mod execution_stats;
+mod execution_warnings;
+mod existential_deposit;
 mod explorer;
 mod gas_counter;
 mod get_balance;
 mod groups;
 mod host_function_costs;
+mod invariants;
 mod manage_groups;
+mod multi_proof;
 mod private_chain;
+mod read_keys;
 mod regression;
+mod speculative_execution;
 mod stack_overflow;
+mod state_stats;
 mod step;
 mod storage_costs;
+mod storage_refund;
 mod system_contracts;
 mod system_costs;
 mod tutorial;
@@ -0,0 +1,131 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_hashing::TrieMultiProofError;
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, Key, KeyTag, RuntimeArgs, U512,
+};
+
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+const ALICE_ADDR: AccountHash = AccountHash::new([3; 32]);
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_prove_present_and_absent_keys() {
+    let builder = setup();
+
+    let present_keys = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::SystemContractRegistry,
+        builder.get_mint_contract_hash().into(),
+    ];
+    let absent_key = Key::Account(ALICE_ADDR);
+    let mut keys = present_keys.clone();
+    keys.push(absent_key);
+
+    let proof = builder.get_multi_proof(keys);
+    proof
+        .verify(&builder.get_post_state_hash())
+        .expect("should verify proof");
+
+    for key in &present_keys {
+        let value = builder.query(None, *key, &[]).expect("should query value");
+        assert_eq!(
+            proof.get(key).expect("key should be proven"),
+            Some(&value),
+            "{}",
+            key
+        );
+    }
+    assert_eq!(proof.get(&absent_key), Ok(None));
+}
+
+#[ignore]
+#[test]
+fn should_prove_absence_of_keys() {
+    let builder = setup();
+    let state_root_hash = builder.get_post_state_hash();
+
+    let absent_key = Key::Account(ALICE_ADDR);
+    let proof = builder
+        .prove_absence(absent_key)
+        .expect("should prove absence");
+    proof
+        .verify_absence(&state_root_hash, &absent_key)
+        .expect("should verify absence");
+
+    // The proof says nothing about keys whose paths it doesn't cover.
+    let present_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    assert!(proof
+        .verify_absence(&state_root_hash, &present_key)
+        .is_err());
+
+    assert!(builder.prove_absence(present_key).is_none());
+}
+
+#[ignore]
+#[test]
+fn should_be_smaller_than_independent_proofs() {
+    let builder = setup();
+
+    let keys: Vec<Key> = builder
+        .get_keys(KeyTag::Balance)
+        .expect("should get keys")
+        .into_iter()
+        .take(4)
+        .collect();
+    assert!(keys.len() > 1);
+
+    let multi_proof = builder.get_multi_proof(keys.clone());
+    let independent_proofs_size: usize = keys
+        .iter()
+        .map(|key| builder.get_multi_proof(vec![*key]).serialized_length())
+        .sum();
+    assert!(multi_proof.serialized_length() < independent_proofs_size);
+
+    // Keys whose paths were pruned are not covered by the proof.
+    let other_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(
+        multi_proof.get(&other_key),
+        Err(TrieMultiProofError::KeyNotProven)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_verify_against_another_state_root() {
+    let mut builder = setup();
+
+    let key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let proof = builder.get_multi_proof(vec![key]);
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        proof.verify(&builder.get_post_state_hash()),
+        Err(TrieMultiProofError::UnexpectedRootHash)
+    );
+    builder
+        .get_multi_proof(vec![key])
+        .verify(&builder.get_post_state_hash())
+        .expect("should verify new proof");
+}
@@ -1,3 +1,4 @@
+mod administrators;
 mod burn_fees_and_refund;
 mod fees_accumulation;
 pub mod management;
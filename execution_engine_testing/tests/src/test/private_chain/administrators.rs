@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+
+use casper_engine_test_support::{
+    DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+    MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::core::{engine_state::Error, execution};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::{mint, standard_payment::ARG_AMOUNT},
+    ApiError, CLValue, Key, RuntimeArgs, StoredValue, U512,
+};
+
+use super::{ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ADMIN_1_ACCOUNT_ADDR, DEFAULT_ADMIN_ACCOUNT_ADDR};
+
+fn update_administrators(
+    builder: &mut InMemoryWasmTestBuilder,
+    administrators: BTreeSet<AccountHash>,
+    authorization_keys: &[AccountHash],
+    deploy_hash: [u8; 32],
+) {
+    let mint_hash = builder.get_mint_contract_hash();
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ADMIN_ACCOUNT_ADDR)
+        .with_stored_session_hash(
+            mint_hash,
+            mint::METHOD_UPDATE_ADMINISTRATORS,
+            runtime_args! {
+                mint::ARG_ADMINISTRATORS => administrators,
+            },
+        )
+        .with_empty_payment_bytes(runtime_args! {
+            ARG_AMOUNT => *DEFAULT_PAYMENT
+        })
+        .with_authorization_keys(authorization_keys)
+        .with_deploy_hash(deploy_hash)
+        .build();
+
+    builder.exec(ExecuteRequestBuilder::new().push_deploy(deploy).build());
+}
+
+fn assert_mint_error(builder: &InMemoryWasmTestBuilder, expected: mint::Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(execution::Error::Revert(api_error)) if api_error == ApiError::from(expected)
+        ),
+        "expected {:?} error, found {:?}",
+        expected,
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_update_administrators_with_quorum_of_administrators() {
+    let mut builder = super::private_chain_setup();
+
+    assert!(builder
+        .query(None, Key::AdministratorRegistry, &[])
+        .is_err());
+
+    let new_administrators = BTreeSet::from([*DEFAULT_ADMIN_ACCOUNT_ADDR, *ACCOUNT_1_ADDR]);
+
+    // Only one of the two administrators approves.
+    update_administrators(
+        &mut builder,
+        new_administrators.clone(),
+        &[*DEFAULT_ADMIN_ACCOUNT_ADDR],
+        [1; 32],
+    );
+    builder.expect_failure().commit();
+    assert_mint_error(&builder, mint::Error::InsufficientAdministratorApprovals);
+
+    update_administrators(
+        &mut builder,
+        BTreeSet::new(),
+        &[*DEFAULT_ADMIN_ACCOUNT_ADDR, *ADMIN_1_ACCOUNT_ADDR],
+        [2; 32],
+    );
+    builder.expect_failure().commit();
+    assert_mint_error(&builder, mint::Error::EmptyAdministrators);
+
+    update_administrators(
+        &mut builder,
+        new_administrators.clone(),
+        &[*DEFAULT_ADMIN_ACCOUNT_ADDR, *ADMIN_1_ACCOUNT_ADDR],
+        [3; 32],
+    );
+    builder.expect_success().commit();
+
+    let stored_administrators = builder
+        .query(None, Key::AdministratorRegistry, &[])
+        .expect("should have administrator registry");
+    assert_eq!(
+        stored_administrators,
+        StoredValue::CLValue(CLValue::from_t(new_administrators).unwrap())
+    );
+
+    // The stored administrators take precedence over the configured ones, so the former
+    // administrator can't create accounts any more, while the new one can.
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *ADMIN_1_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => *ACCOUNT_2_ADDR,
+            mint::ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE),
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_failure().commit();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(execution::Error::DisabledUnrestrictedTransfers)
+        ),
+        "expected DisabledUnrestrictedTransfers error, found {:?}",
+        error
+    );
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *ACCOUNT_1_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => *ACCOUNT_2_ADDR,
+            mint::ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE),
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+}
@@ -1,19 +1,24 @@
 use casper_engine_test_support::{
-    ExecuteRequestBuilder, DEFAULT_PAYMENT, MINIMUM_ACCOUNT_CREATION_BALANCE,
+    ExecuteRequestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT,
+    DEFAULT_PROTOCOL_VERSION, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
 use casper_execution_engine::{
-    core::engine_state::engine_config::{FeeHandling, RefundHandling},
+    core::engine_state::{
+        engine_config::{FeeHandling, RefundHandling},
+        EngineConfigBuilder, SupplyBreakdown,
+    },
     shared::{opcode_costs::DEFAULT_NOP_COST, system_config::DEFAULT_WASMLESS_TRANSFER_COST},
 };
 use casper_types::{
     runtime_args,
     system::{handle_payment::ACCUMULATION_PURSE_KEY, mint},
-    RuntimeArgs, U512,
+    EraId, ProtocolVersion, RuntimeArgs, U512,
 };
 use num_rational::Ratio;
 use num_traits::{One, Zero};
 
 use crate::{
+    lmdb_fixture,
     test::private_chain::{
         self, ACCOUNT_1_ADDR, DEFAULT_ADMIN_ACCOUNT_ADDR, PRIVATE_CHAIN_ALLOW_AUCTION_BIDS,
         PRIVATE_CHAIN_ALLOW_UNRESTRICTED_TRANSFERS,
@@ -98,6 +103,63 @@ fn should_burn_full_refund_and_burn_fees() {
     test_burning_fees(full_refund_handling, fee_handling, expected_fee_amount);
 }
 
+#[ignore]
+#[test]
+fn should_record_burned_fees_after_upgrade() {
+    let (mut builder, _lmdb_fixture_state, _temp_dir) =
+        lmdb_fixture::builder_from_global_state_fixture(lmdb_fixture::RELEASE_1_4_5);
+
+    let mint_contract = builder
+        .get_contract(builder.get_mint_contract_hash())
+        .expect("should have mint contract");
+    assert!(
+        !mint_contract
+            .named_keys()
+            .contains_key(mint::TOTAL_BURNED_KEY),
+        "should not have total burned in a persisted state"
+    );
+    assert_eq!(builder.get_supply().total_burned(), None);
+
+    let old_protocol_version = *DEFAULT_PROTOCOL_VERSION;
+    let new_protocol_version = ProtocolVersion::from_parts(
+        old_protocol_version.value().major,
+        old_protocol_version.value().minor,
+        old_protocol_version.value().patch + 1,
+    );
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(old_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(EraId::default())
+        .build();
+    let engine_config = EngineConfigBuilder::default()
+        .with_fee_handling(FeeHandling::Burn)
+        .build();
+    builder
+        .upgrade_with_upgrade_request_and_config(Some(engine_config), &mut upgrade_request)
+        .expect_upgrade_success();
+
+    assert_eq!(builder.get_supply().total_burned(), Some(U512::zero()));
+
+    let exec_request = ExecuteRequestBuilder::module_bytes(
+        *DEFAULT_ACCOUNT_ADDR,
+        wasm_utils::do_minimum_bytes(),
+        RuntimeArgs::default(),
+    )
+    .with_protocol_version(new_protocol_version)
+    .build();
+    let total_supply_before = builder.total_supply(None);
+    builder.exec(exec_request).expect_success().commit();
+    let total_supply_after = builder.total_supply(None);
+
+    let burned_amount = total_supply_before - total_supply_after;
+    assert!(!burned_amount.is_zero(), "the fees should be burned");
+    assert_eq!(
+        builder.get_supply(),
+        SupplyBreakdown::new(total_supply_after, Some(burned_amount)),
+        "burned amount should be recorded by the mint created on upgrade"
+    );
+}
+
 fn test_burning_fees(
     refund_handling: RefundHandling,
     fee_handling: FeeHandling,
@@ -126,6 +188,8 @@ fn test_burning_fees(
     )
     .build();
     let total_supply_before = builder.total_supply(None);
+    let total_burned_before = builder.get_supply().total_burned();
+    assert_eq!(total_burned_before, Some(U512::zero()));
     let exec_request_1_proposer = exec_request_1.proposer.clone();
     let proposer_account_1 = builder
         .get_account(exec_request_1_proposer.to_account_hash())
@@ -142,6 +206,11 @@ fn test_burning_fees(
         expected_burn_amount,
         "total supply should be burned exactly by the amount of calculated fee after refund"
     );
+    assert_eq!(
+        builder.get_supply(),
+        SupplyBreakdown::new(total_supply_after, Some(expected_burn_amount)),
+        "burned amount should be recorded by the mint"
+    );
     let exec_request_2 = {
         let transfer_args = runtime_args! {
             mint::ARG_TARGET => *ACCOUNT_1_ADDR,
@@ -4,19 +4,22 @@ use casper_engine_test_support::{
     MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST, TIMESTAMP_MILLIS_INCREMENT,
 };
 use casper_execution_engine::core::engine_state::{
-    engine_config::FeeHandling, EngineConfigBuilder, RewardItem,
+    engine_config::{FeeDistribution, FeeHandling},
+    EngineConfigBuilder, RewardItem,
 };
 use casper_types::{
+    account::AccountHash,
     runtime_args,
     system::{handle_payment::ACCUMULATION_PURSE_KEY, mint},
-    EraId, ProtocolVersion, RuntimeArgs, U512,
+    EraId, ProtocolVersion, RuntimeArgs, URef, U512,
 };
 use once_cell::sync::Lazy;
 
 use crate::{
     lmdb_fixture,
     test::private_chain::{
-        self, ACCOUNT_1_ADDR, DEFAULT_ADMIN_ACCOUNT_ADDR, VALIDATOR_1_PUBLIC_KEY,
+        self, ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, DEFAULT_ADMIN_ACCOUNT_ADDR,
+        VALIDATOR_1_PUBLIC_KEY,
     },
     wasm_utils,
 };
@@ -374,3 +377,146 @@ fn should_accumulate_fees_after_upgrade() {
         "proposer should not receive any more funds after switching to accumulation"
     );
 }
+
+fn setup_with_fee_distribution(
+    fee_distribution: FeeDistribution,
+) -> (InMemoryWasmTestBuilder, URef) {
+    let engine_config = EngineConfigBuilder::default()
+        .with_administrative_accounts(private_chain::PRIVATE_CHAIN_GENESIS_ADMIN_SET.clone())
+        .with_allow_auction_bids(private_chain::PRIVATE_CHAIN_ALLOW_AUCTION_BIDS)
+        .with_allow_unrestricted_transfers(
+            private_chain::PRIVATE_CHAIN_ALLOW_UNRESTRICTED_TRANSFERS,
+        )
+        .with_refund_handling(private_chain::PRIVATE_CHAIN_REFUND_HANDLING)
+        .with_fee_handling(private_chain::PRIVATE_CHAIN_FEE_HANDLING)
+        .with_fee_distribution(fee_distribution)
+        .with_wasm_config(private_chain::make_wasm_config())
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&private_chain::DEFAULT_PRIVATE_CHAIN_GENESIS);
+
+    let exec_request = ExecuteRequestBuilder::module_bytes(
+        *DEFAULT_ADMIN_ACCOUNT_ADDR,
+        wasm_utils::do_minimum_bytes(),
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let handle_payment_hash = builder.get_handle_payment_contract_hash();
+    let handle_payment = builder
+        .get_contract(handle_payment_hash)
+        .expect("should have handle payment contract");
+    let accumulation_purse = handle_payment.named_keys()[ACCUMULATION_PURSE_KEY]
+        .into_uref()
+        .expect("should be uref");
+    assert!(!builder.get_purse_balance(accumulation_purse).is_zero());
+
+    (builder, accumulation_purse)
+}
+
+fn run_step(builder: &mut InMemoryWasmTestBuilder) {
+    let era_id = builder.get_era();
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_next_era_id(era_id.successor())
+        .with_era_end_timestamp_millis(era_id.value() * TIMESTAMP_MILLIS_INCREMENT)
+        .with_run_auction(true)
+        .with_reward_item(RewardItem::new(
+            VALIDATOR_1_PUBLIC_KEY.clone(),
+            VALIDATOR_1_REWARD_FACTOR,
+        ))
+        .build();
+    builder.step(step_request).expect("should execute step");
+}
+
+fn main_purse_balance(builder: &InMemoryWasmTestBuilder, account_hash: AccountHash) -> U512 {
+    let account = builder.get_expected_account(account_hash);
+    builder.get_purse_balance(account.main_purse())
+}
+
+#[ignore]
+#[test]
+fn should_distribute_accumulated_fees_every_nth_era() {
+    let fee_distribution = FeeDistribution {
+        frequency: 2,
+        ..FeeDistribution::default()
+    };
+    let (mut builder, accumulation_purse) = setup_with_fee_distribution(fee_distribution);
+    let accumulated_balance = builder.get_purse_balance(accumulation_purse);
+    let admin_balance_before = main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR);
+
+    // Era 1 isn't a distribution era, so the fees roll over.
+    run_step(&mut builder);
+    assert_eq!(builder.get_era(), EraId::new(1));
+    assert_eq!(
+        builder.get_purse_balance(accumulation_purse),
+        accumulated_balance
+    );
+    assert_eq!(
+        main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR),
+        admin_balance_before
+    );
+
+    run_step(&mut builder);
+    assert_eq!(builder.get_era(), EraId::new(2));
+    assert!(builder.get_purse_balance(accumulation_purse) < accumulated_balance);
+    assert!(main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR) > admin_balance_before);
+}
+
+#[ignore]
+#[test]
+fn should_roll_over_accumulated_fees_below_minimum_amount() {
+    let fee_distribution = FeeDistribution {
+        minimum_amount: u64::MAX,
+        ..FeeDistribution::default()
+    };
+    let (mut builder, accumulation_purse) = setup_with_fee_distribution(fee_distribution);
+    let accumulated_balance = builder.get_purse_balance(accumulation_purse);
+    let admin_balance_before = main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR);
+
+    run_step(&mut builder);
+
+    assert_eq!(
+        builder.get_purse_balance(accumulation_purse),
+        accumulated_balance
+    );
+    assert_eq!(
+        main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR),
+        admin_balance_before
+    );
+}
+
+#[ignore]
+#[test]
+fn should_distribute_accumulated_fees_to_validators_and_treasury_by_weight() {
+    let fee_distribution = FeeDistribution {
+        administrators_weight: 0,
+        validators_weight: 1,
+        treasury_weight: 3,
+        treasury: Some(ACCOUNT_1_PUBLIC_KEY.clone()),
+        ..FeeDistribution::default()
+    };
+    let (mut builder, accumulation_purse) = setup_with_fee_distribution(fee_distribution);
+    let validator_1_addr = VALIDATOR_1_PUBLIC_KEY.to_account_hash();
+    let accumulated_balance = builder.get_purse_balance(accumulation_purse);
+    let admin_balance_before = main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR);
+    let validator_balance_before = main_purse_balance(&builder, validator_1_addr);
+    let treasury_balance_before = main_purse_balance(&builder, *ACCOUNT_1_ADDR);
+
+    run_step(&mut builder);
+
+    let validator_share = main_purse_balance(&builder, validator_1_addr) - validator_balance_before;
+    let treasury_share = main_purse_balance(&builder, *ACCOUNT_1_ADDR) - treasury_balance_before;
+    assert_eq!(validator_share, accumulated_balance / 4);
+    assert_eq!(treasury_share, accumulated_balance * 3 / 4);
+    assert_eq!(
+        main_purse_balance(&builder, *DEFAULT_ADMIN_ACCOUNT_ADDR),
+        admin_balance_before
+    );
+    assert_eq!(
+        builder.get_purse_balance(accumulation_purse),
+        accumulated_balance - validator_share - treasury_share
+    );
+}
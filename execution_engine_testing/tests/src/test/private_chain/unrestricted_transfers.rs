@@ -393,6 +393,49 @@ fn should_allow_admin_to_transfer_to_own_purse_via_native_transfer() {
     builder.exec(transfer_request).expect_success().commit();
 }
 
+#[ignore]
+#[test]
+fn should_disallow_transfer_to_own_named_purse_via_native_transfer() {
+    let mut builder = super::private_chain_setup();
+
+    let session_args = runtime_args! {
+        ARG_PURSE_NAME => TEST_PURSE,
+        ARG_AMOUNT => U512::zero(), // we can't transfer in private chain mode, so we'll just create empty valid purse
+    };
+    let create_purse_request = ExecuteRequestBuilder::standard(
+        *ACCOUNT_1_ADDR,
+        TRANSFER_TO_NAMED_PURSE_CONTRACT,
+        session_args,
+    )
+    .build();
+    builder.exec(create_purse_request).expect_success().commit();
+
+    let amount: U512 = U512::one();
+    let id: Option<u64> = None;
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *ACCOUNT_1_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => TEST_PURSE,
+            mint::ARG_AMOUNT => amount,
+            mint::ARG_ID => id,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_failure().commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            Error::Exec(execution::Error::DisabledUnrestrictedTransfers)
+        ),
+        "expected DisabledUnrestrictedTransfers error, found {:?}",
+        error
+    );
+}
+
 #[ignore]
 #[test]
 fn should_disallow_wasm_payment_to_purse() {
@@ -0,0 +1,64 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::{EngineConfigBuilder, ExecuteRequest};
+use casper_types::{account::AccountHash, runtime_args, system::mint, Key, RuntimeArgs, U512};
+
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+
+static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([42; 32]));
+
+fn transfer_request() -> ExecuteRequest {
+    ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => *ACCOUNT_1_ADDR,
+            mint::ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_not_record_read_keys_by_default() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder.exec(transfer_request()).expect_success().commit();
+
+    let exec_result = builder
+        .get_last_exec_results()
+        .expect("should have results")
+        .pop()
+        .expect("should have one result");
+    assert!(exec_result.read_keys().is_none());
+}
+
+#[ignore]
+#[test]
+fn should_record_read_keys_in_execution_result() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_record_read_keys(true)
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder.exec(transfer_request()).expect_success().commit();
+
+    let exec_result = builder
+        .get_last_exec_results()
+        .expect("should have results")
+        .pop()
+        .expect("should have one result");
+    let read_keys = exec_result
+        .read_keys()
+        .expect("should have recorded read keys");
+    assert!(read_keys.contains(&Key::Account(*DEFAULT_ACCOUNT_ADDR)));
+    // The target account doesn't exist yet, but looking it up is still a read.
+    assert!(read_keys.contains(&Key::Account(*ACCOUNT_1_ADDR)));
+}
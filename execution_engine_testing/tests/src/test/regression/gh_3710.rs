@@ -132,6 +132,85 @@ fn gh_3710_commit_prune_should_delete_values() {
     assert_eq!(keys_after_batch_2_prune.len(), 0);
 }
 
+#[ignore]
+#[test]
+fn gh_3710_should_report_era_summary_status() {
+    let (builder, _lmdb_fixture_state, _temp_dir) =
+        lmdb_fixture::builder_from_global_state_fixture(GH_3710_FIXTURE);
+
+    let era_info_keys = builder
+        .get_keys(KeyTag::EraInfo)
+        .expect("should obtain all given keys");
+
+    let status = builder.get_era_summary_status();
+    assert!(!status.has_era_summary());
+    assert!(status.has_legacy_era_infos());
+    assert_eq!(status.legacy_era_ids().len(), era_info_keys.len());
+    assert_eq!(status.mapped_era_id(), None);
+    assert_eq!(status.prunable_count(), era_info_keys.len() - 1);
+    assert!(!status.is_complete());
+}
+
+#[ignore]
+#[test]
+fn gh_3710_era_summary_migration_should_complete_in_batches() {
+    let (mut builder, _lmdb_fixture_state, _temp_dir) =
+        lmdb_fixture::builder_from_global_state_fixture(GH_3710_FIXTURE);
+
+    let latest_era_id = *builder
+        .get_era_summary_status()
+        .legacy_era_ids()
+        .last()
+        .expect("should have legacy era infos");
+    let latest_era_info = builder
+        .query(None, Key::EraInfo(latest_era_id), &[])
+        .expect("should query latest era info");
+
+    builder
+        .commit_era_summary_migration(FIXTURE_N_ERAS)
+        .expect_prune_success();
+
+    let status = builder.get_era_summary_status();
+    assert!(status.has_era_summary());
+    assert_eq!(status.mapped_era_id(), Some(latest_era_id));
+    assert_eq!(status.prunable_count(), status.legacy_era_ids().len());
+    let era_summary = builder
+        .query(None, Key::EraSummary, &[])
+        .expect("should query era summary");
+    assert_eq!(era_summary, latest_era_info);
+
+    while !builder.get_era_summary_status().is_complete() {
+        builder
+            .commit_era_summary_migration(FIXTURE_N_ERAS)
+            .expect_prune_success();
+    }
+
+    assert_eq!(builder.get_keys(KeyTag::EraInfo).unwrap(), Vec::new());
+    let era_summary = builder
+        .query(None, Key::EraSummary, &[])
+        .expect("should query era summary");
+    assert_eq!(era_summary, latest_era_info);
+}
+
+#[ignore]
+#[test]
+fn gh_3710_era_summary_migration_should_be_idempotent() {
+    let (mut builder, _lmdb_fixture_state, _temp_dir) =
+        lmdb_fixture::builder_from_global_state_fixture(GH_3710_FIXTURE);
+
+    let era_info_count = builder.get_keys(KeyTag::EraInfo).unwrap().len();
+    builder
+        .commit_era_summary_migration(era_info_count)
+        .expect_prune_success();
+    assert!(builder.get_era_summary_status().is_complete());
+
+    let post_state_hash = builder.get_post_state_hash();
+    builder
+        .commit_era_summary_migration(era_info_count)
+        .expect_prune_success();
+    assert_eq!(builder.get_post_state_hash(), post_state_hash);
+}
+
 const DEFAULT_REWARD_AMOUNT: u64 = 1_000_000;
 
 fn add_validator_and_wait_for_rotation<S>(builder: &mut WasmTestBuilder<S>)
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::engine_state::{Error, ExecuteRequest},
+    shared::newtypes::CorrelationId,
+};
+use casper_types::{account::AccountHash, runtime_args, system::mint, Key, RuntimeArgs, U512};
+
+const VIEW_TTL: Duration = Duration::from_secs(60);
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+
+static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([42; 32]));
+static ACCOUNT_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([43; 32]));
+
+fn transfer_request(sender: AccountHash, target: AccountHash, amount: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::transfer(
+        sender,
+        runtime_args! {
+            mint::ARG_TARGET => target,
+            mint::ARG_AMOUNT => U512::from(amount),
+            mint::ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_execute_speculatively_against_historical_state_root() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let genesis_state_root = builder.get_post_state_hash();
+
+    builder
+        .exec(transfer_request(
+            *DEFAULT_ACCOUNT_ADDR,
+            *ACCOUNT_1_ADDR,
+            TRANSFER_AMOUNT,
+        ))
+        .expect_success()
+        .commit();
+    let latest_state_root = builder.get_post_state_hash();
+
+    let engine_state = builder.get_engine_state();
+
+    // The transfer's sender only exists from the latest state root on.
+    let latest_view = engine_state
+        .pin_view(latest_state_root, VIEW_TTL)
+        .unwrap()
+        .expect("should pin latest state root");
+    let results = engine_state
+        .run_execute_in_view(
+            CorrelationId::new(),
+            &latest_view,
+            transfer_request(*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR, TRANSFER_AMOUNT / 2),
+        )
+        .expect("should execute");
+    assert!(results.front().unwrap().is_success());
+
+    let historical_view = engine_state
+        .pin_view(genesis_state_root, VIEW_TTL)
+        .unwrap()
+        .expect("should pin historical state root");
+    let results = engine_state
+        .run_execute_in_view(
+            CorrelationId::new(),
+            &historical_view,
+            transfer_request(*ACCOUNT_1_ADDR, *ACCOUNT_2_ADDR, TRANSFER_AMOUNT / 2),
+        )
+        .expect("should execute");
+    assert!(results.front().unwrap().is_failure());
+
+    // Nothing executed in a view is committed.
+    assert_eq!(builder.get_post_state_hash(), latest_state_root);
+    assert!(builder.get_account(*ACCOUNT_2_ADDR).is_none());
+
+    assert!(engine_state.release_view(&historical_view));
+    let error = engine_state
+        .run_execute_in_view(
+            CorrelationId::new(),
+            &historical_view,
+            transfer_request(*DEFAULT_ACCOUNT_ADDR, *ACCOUNT_2_ADDR, TRANSFER_AMOUNT),
+        )
+        .expect_err("should not execute in released view");
+    assert!(matches!(error, Error::ViewNotFound(view_id) if view_id == historical_view.id()));
+}
+
+#[ignore]
+#[test]
+fn should_record_keys_read_by_speculative_execution() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    let state_root = builder.get_post_state_hash();
+
+    let engine_state = builder.get_engine_state();
+    let view = engine_state
+        .pin_view(state_root, VIEW_TTL)
+        .unwrap()
+        .expect("should pin state root");
+    let (results, reads) = engine_state
+        .run_execute_in_view_recording_reads(
+            CorrelationId::new(),
+            &view,
+            transfer_request(*DEFAULT_ACCOUNT_ADDR, *ACCOUNT_1_ADDR, TRANSFER_AMOUNT),
+        )
+        .expect("should execute");
+    assert!(results.front().unwrap().is_success());
+
+    assert!(reads.keys.contains(&Key::Account(*DEFAULT_ACCOUNT_ADDR)));
+    // The target account doesn't exist yet, but looking it up is still a read.
+    assert!(reads.keys.contains(&Key::Account(*ACCOUNT_1_ADDR)));
+
+    // Nothing executed in a view is committed.
+    assert_eq!(builder.get_post_state_hash(), state_root);
+    assert!(builder.get_account(*ACCOUNT_1_ADDR).is_none());
+}
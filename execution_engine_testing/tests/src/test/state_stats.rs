@@ -0,0 +1,87 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, KeyTag, RuntimeArgs, U512,
+};
+
+const TRANSFER_ARG_TARGET: &str = "target";
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+const TRANSFER_ARG_ID: &str = "id";
+const ALICE_ADDR: AccountHash = AccountHash::new([3; 32]);
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+const KEY_TAGS: [KeyTag; 6] = [
+    KeyTag::Account,
+    KeyTag::Hash,
+    KeyTag::URef,
+    KeyTag::Balance,
+    KeyTag::Bid,
+    KeyTag::SystemContractRegistry,
+];
+
+#[ignore]
+#[test]
+fn should_count_and_size_entries_per_key_tag() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let state_stats = builder.get_state_stats();
+
+    for key_tag in KEY_TAGS {
+        let keys = builder.get_keys(key_tag).expect("should get keys");
+        let key_tag_stats = state_stats.get(key_tag);
+        assert_eq!(key_tag_stats.count(), keys.len() as u64, "{:?}", key_tag);
+        assert!(key_tag_stats.count() > 0, "{:?}", key_tag);
+
+        let keys_size: usize = keys.iter().map(ToBytes::serialized_length).sum();
+        let values_size: usize = keys
+            .iter()
+            .map(|key| {
+                builder
+                    .query(None, *key, &[])
+                    .expect("should query value")
+                    .serialized_length()
+            })
+            .sum();
+        assert_eq!(key_tag_stats.keys_size(), keys_size as u64);
+        assert_eq!(key_tag_stats.values_size(), values_size as u64);
+    }
+
+    let total = state_stats.total();
+    assert_eq!(
+        total.count(),
+        state_stats
+            .iter()
+            .map(|(_, key_tag_stats)| key_tag_stats.count())
+            .sum::<u64>()
+    );
+    assert_eq!(total.total_size(), total.keys_size() + total.values_size());
+}
+
+#[ignore]
+#[test]
+fn should_reflect_new_entries_in_state_stats() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let genesis_stats = builder.get_state_stats();
+
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            TRANSFER_ARG_TARGET => ALICE_ADDR,
+            TRANSFER_ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            TRANSFER_ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let state_stats = builder.get_state_stats();
+    assert_eq!(
+        state_stats.get(KeyTag::Account).count(),
+        genesis_stats.get(KeyTag::Account).count() + 1
+    );
+    assert!(state_stats.total().total_size() > genesis_stats.total().total_size());
+}
@@ -3,23 +3,27 @@ use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     utils, InMemoryWasmTestBuilder, StepRequestBuilder, WasmTestBuilder, DEFAULT_ACCOUNTS,
+    DEFAULT_ERA_DURATION_MILLIS,
 };
 use casper_execution_engine::{
     core::engine_state::{
         genesis::{GenesisAccount, GenesisValidator},
-        RewardItem, SlashItem,
+        step::EvictItem,
+        Error, MaintenanceContext, MaintenanceTask, RewardItem, SlashItem,
     },
+    shared::{additive_map::AdditiveMap, transform::Transform},
     storage::global_state::in_memory::InMemoryGlobalState,
 };
 use casper_types::{
     system::{
         auction::{
-            Bids, DelegationRate, SeigniorageRecipientsSnapshot, BLOCK_REWARD,
+            Bids, DelegationRate, EraInfo, SeigniorageRecipientsSnapshot, BLOCK_REWARD,
             SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
         },
         mint::TOTAL_SUPPLY_KEY,
     },
-    CLValue, ContractHash, EraId, Key, Motes, ProtocolVersion, PublicKey, SecretKey, U512,
+    AccessRights, ApiError, CLValue, ContractHash, EraId, Gas, Key, KeyTag, Motes, ProtocolVersion,
+    PublicKey, SecretKey, StoredValue, URef, U512,
 };
 
 static ACCOUNT_1_PK: Lazy<PublicKey> = Lazy::new(|| {
@@ -36,6 +40,9 @@ static ACCOUNT_2_PK: Lazy<PublicKey> = Lazy::new(|| {
 const ACCOUNT_2_BALANCE: u64 = 200_000_000;
 const ACCOUNT_2_BOND: u64 = 200_000_000;
 
+const MAINTENANCE_LOG_KEY: Key = Key::URef(URef::new([7; 32], AccessRights::READ_ADD_WRITE));
+const DISCARDED_KEY: Key = Key::URef(URef::new([8; 32], AccessRights::READ_ADD_WRITE));
+
 fn get_named_key(
     builder: &mut InMemoryWasmTestBuilder,
     contract_hash: ContractHash,
@@ -113,7 +120,9 @@ fn should_step() {
         bids_before_slashing
     );
 
-    builder.step(step_request).unwrap();
+    let step_report = builder.step(step_request).unwrap().step_report;
+    assert_eq!(step_report.slashed, vec![ACCOUNT_1_PK.clone()]);
+    assert!(step_report.evicted.is_empty());
 
     let bids_after_slashing: Bids = builder.get_bids();
     let account_1_bid = bids_after_slashing.get(&ACCOUNT_1_PK).unwrap();
@@ -135,6 +144,57 @@ fn should_step() {
             .all(|key| after_auction_seigniorage.contains_key(key)),
         "run auction should have changed seigniorage keys"
     );
+
+    let new_snapshot_era = step_report
+        .new_snapshot_era
+        .expect("should add snapshot era");
+    assert!(!before_auction_seigniorage.contains_key(&new_snapshot_era));
+    assert_eq!(
+        after_auction_seigniorage.keys().next_back(),
+        Some(&new_snapshot_era)
+    );
+
+    let account_1_weight_change = step_report
+        .validator_weight_changes
+        .get(&ACCOUNT_1_PK)
+        .expect("slashed validator's weight should change");
+    assert_eq!(
+        account_1_weight_change.before,
+        Some(U512::from(ACCOUNT_1_BOND))
+    );
+    assert_eq!(account_1_weight_change.after, None);
+}
+
+#[ignore]
+#[test]
+fn should_report_evicted_validators() {
+    let mut builder = initialize_builder();
+
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(ProtocolVersion::V1_0_0)
+        .with_evict_item(EvictItem::new(ACCOUNT_2_PK.clone()))
+        .with_next_era_id(EraId::from(1))
+        .build();
+
+    let step_report = builder.step(step_request).unwrap().step_report;
+
+    assert!(step_report.slashed.is_empty());
+    assert_eq!(step_report.evicted, vec![ACCOUNT_2_PK.clone()]);
+    assert!(builder.get_bids().get(&ACCOUNT_2_PK).unwrap().inactive());
+
+    let account_2_weight_change = step_report
+        .validator_weight_changes
+        .get(&ACCOUNT_2_PK)
+        .expect("evicted validator's weight should change");
+    assert_eq!(
+        account_2_weight_change.before,
+        Some(U512::from(ACCOUNT_2_BOND))
+    );
+    assert_eq!(account_2_weight_change.after, None);
+    assert!(!step_report
+        .validator_weight_changes
+        .contains_key(&ACCOUNT_1_PK));
 }
 
 /// Should be able to step slashing, rewards, and run auction.
@@ -190,3 +250,114 @@ fn should_adjust_total_supply() {
         "total supply should be reduced due to slashing"
     );
 }
+
+fn append_to_maintenance_log(context: &mut MaintenanceContext, entry: &str) -> Result<(), Error> {
+    let mut log: Vec<String> = match context.read(&MAINTENANCE_LOG_KEY)? {
+        Some(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should be a log"),
+        _ => Vec::new(),
+    };
+    log.push(format!("{} in {}", entry, context.next_era_id()));
+    let cl_value = CLValue::from_t(log).expect("should create CLValue");
+    context.write(MAINTENANCE_LOG_KEY, StoredValue::CLValue(cl_value))
+}
+
+#[ignore]
+#[test]
+fn should_run_maintenance_tasks_in_order() {
+    let mut builder = initialize_builder();
+    let unlimited = Gas::new(U512::from(u64::MAX));
+
+    builder
+        .register_maintenance_task(MaintenanceTask::new("second", 1, unlimited, |context| {
+            append_to_maintenance_log(context, "second")
+        }))
+        .register_maintenance_task(MaintenanceTask::new("first", 0, unlimited, |context| {
+            append_to_maintenance_log(context, "first")
+        }))
+        .register_maintenance_task(MaintenanceTask::new(
+            "over_budget",
+            2,
+            Gas::new(U512::one()),
+            |context| {
+                let cl_value = CLValue::from_t(1u64).expect("should create CLValue");
+                context.write(DISCARDED_KEY, StoredValue::CLValue(cl_value))
+            },
+        ))
+        .register_maintenance_task(MaintenanceTask::new("failing", 3, unlimited, |context| {
+            append_to_maintenance_log(context, "failing")?;
+            Err(Error::reverter(ApiError::User(1)))
+        }));
+
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(ProtocolVersion::V1_0_0)
+        .with_next_era_id(EraId::from(1))
+        .build();
+
+    let step_report = builder.step(step_request).unwrap().step_report;
+
+    // Failed tasks are reported, and their writes discarded without failing the step.
+    assert_eq!(
+        step_report.failed_maintenance_tasks,
+        vec!["over_budget".to_string(), "failing".to_string()]
+    );
+    assert!(builder.query(None, DISCARDED_KEY, &[]).is_err());
+
+    let log: Vec<String> = match builder.query(None, MAINTENANCE_LOG_KEY, &[]) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should be a log"),
+        result => panic!("unexpected query result {:?}", result),
+    };
+    assert_eq!(log, vec!["first in era 1", "second in era 1"]);
+}
+
+#[ignore]
+#[test]
+fn should_advance_eras() {
+    let mut builder = initialize_builder();
+    let account_1_stake_before = *builder
+        .get_bids()
+        .get(&ACCOUNT_1_PK)
+        .expect("should have bid")
+        .staked_amount();
+
+    builder.advance_eras(3);
+
+    let era_info = builder.get_era_info();
+    assert_eq!(era_info.era_id(), EraId::from(3));
+    assert_eq!(
+        era_info.era_start_timestamp_millis(),
+        3 * DEFAULT_ERA_DURATION_MILLIS
+    );
+
+    // Every validator is rewarded for full participation.
+    let account_1_stake_after = *builder
+        .get_bids()
+        .get(&ACCOUNT_1_PK)
+        .expect("should have bid")
+        .staked_amount();
+    assert!(account_1_stake_after > account_1_stake_before);
+}
+
+#[ignore]
+#[test]
+fn should_prune_legacy_era_infos_while_advancing_eras() {
+    let mut builder = initialize_builder();
+
+    let mut effects = AdditiveMap::new();
+    for era_id in 0..5 {
+        effects.insert(
+            Key::EraInfo(EraId::from(era_id)),
+            Transform::Write(StoredValue::EraInfo(EraInfo::new())),
+        );
+    }
+    let pre_state_hash = builder.get_post_state_hash();
+    builder.commit_transforms(pre_state_hash, effects);
+    assert_eq!(builder.get_keys(KeyTag::EraInfo).unwrap().len(), 5);
+
+    builder.advance_eras_with_prune_batch_size(1, 2);
+    assert_eq!(builder.get_keys(KeyTag::EraInfo).unwrap().len(), 3);
+
+    builder.advance_eras_with_prune_batch_size(2, 2);
+    assert!(builder.get_keys(KeyTag::EraInfo).unwrap().is_empty());
+    assert_eq!(builder.get_era(), EraId::from(3));
+}
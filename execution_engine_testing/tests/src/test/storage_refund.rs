@@ -0,0 +1,85 @@
+use num_rational::Ratio;
+
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::{
+    core::engine_state::engine_config::{EngineConfigBuilder, StorageRefund},
+    shared::wasm_config::WasmConfig,
+};
+use casper_types::{bytesrepr::ToBytes, runtime_args, Gas, Key, RuntimeArgs, URef, U512};
+
+const CONTRACT_NAMED_KEYS: &str = "named_keys.wasm";
+const KEY1: &str = "hello-world";
+const COMMAND_CREATE_UREF1: &str = "create-uref1";
+const COMMAND_REMOVE_UREF1: &str = "remove-uref1";
+const ARG_COMMAND: &str = "command";
+
+fn setup(storage_refund: StorageRefund) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfigBuilder::new()
+        .with_storage_refund(storage_refund)
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder
+}
+
+/// Creates and then removes a named key, returning the cost of the removal.
+fn remove_named_key_cost(builder: &mut InMemoryWasmTestBuilder) -> Gas {
+    for command in [COMMAND_CREATE_UREF1, COMMAND_REMOVE_UREF1] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_NAMED_KEYS,
+            runtime_args! { ARG_COMMAND => command },
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+    assert!(!builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .contains_key(KEY1));
+    builder.last_exec_gas_cost()
+}
+
+fn freed_storage_cost() -> U512 {
+    let freed_bytes = KEY1.serialized_length() + Key::URef(URef::default()).serialized_length();
+    WasmConfig::default()
+        .storage_costs()
+        .calculate_gas_cost(freed_bytes)
+        .value()
+}
+
+#[ignore]
+#[test]
+fn should_refund_removed_named_key() {
+    let cost_without_refund = remove_named_key_cost(&mut setup(StorageRefund::default()));
+    let cost_with_refund = remove_named_key_cost(&mut setup(StorageRefund {
+        refund_ratio: Ratio::new(1, 2),
+        max_refund: u64::MAX,
+    }));
+
+    assert_eq!(
+        cost_without_refund.value() - cost_with_refund.value(),
+        freed_storage_cost() / 2
+    );
+}
+
+#[ignore]
+#[test]
+fn should_cap_storage_refund_per_deploy() {
+    const MAX_REFUND: u64 = 1;
+    assert!(freed_storage_cost() > U512::from(MAX_REFUND));
+
+    let cost_without_refund = remove_named_key_cost(&mut setup(StorageRefund::default()));
+    let cost_with_refund = remove_named_key_cost(&mut setup(StorageRefund {
+        refund_ratio: Ratio::new(1, 1),
+        max_refund: MAX_REFUND,
+    }));
+
+    assert_eq!(
+        cost_without_refund.value() - cost_with_refund.value(),
+        U512::from(MAX_REFUND)
+    );
+}
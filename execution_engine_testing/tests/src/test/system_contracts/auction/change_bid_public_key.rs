@@ -0,0 +1,304 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, StepRequestBuilder, DEFAULT_ACCOUNTS,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        self,
+        engine_config::DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+        genesis::GenesisValidator,
+        step::{RewardItem, SlashItem},
+        GenesisAccount,
+    },
+    execution,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{self, DelegationRate, BLOCK_REWARD, VALIDATOR_PUBLIC_KEY_BRIDGES_KEY},
+    ApiError, Motes, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const VALIDATOR_1_STAKE: u64 = 1_000_000;
+const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 10;
+const DELEGATOR_1_STAKE: u64 = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+const ACCOUNT_BALANCE: u64 = 100_000_000_000_000;
+
+static VALIDATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([3; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1));
+static NEW_VALIDATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([4; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([204; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1));
+static NEW_VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*NEW_VALIDATOR_1));
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::account(
+            VALIDATOR_1.clone(),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(VALIDATOR_1_STAKE.into()),
+                VALIDATOR_1_DELEGATION_RATE,
+            )),
+        ));
+        tmp.push(GenesisAccount::account(
+            DELEGATOR_1.clone(),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            None,
+        ));
+        tmp
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&utils::create_run_genesis_request(accounts));
+
+    let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DELEGATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_DELEGATE,
+        runtime_args! {
+            auction::ARG_DELEGATOR => DELEGATOR_1.clone(),
+            auction::ARG_VALIDATOR => VALIDATOR_1.clone(),
+            auction::ARG_AMOUNT => U512::from(DELEGATOR_1_STAKE),
+        },
+    )
+    .build();
+    builder.exec(delegate_request).expect_success().commit();
+
+    builder
+}
+
+fn change_bid_public_key(
+    builder: &mut InMemoryWasmTestBuilder,
+    sender: AccountHash,
+    public_key: &PublicKey,
+    new_public_key: &PublicKey,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_CHANGE_BID_PUBLIC_KEY,
+        runtime_args! {
+            auction::ARG_PUBLIC_KEY => public_key.clone(),
+            auction::ARG_NEW_PUBLIC_KEY => new_public_key.clone(),
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn assert_auction_error(builder: &InMemoryWasmTestBuilder, expected: auction::Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == expected as u8
+        ),
+        "{:?}",
+        error
+    );
+}
+
+fn step_with_reward(builder: &mut InMemoryWasmTestBuilder, public_key: &PublicKey) {
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(ProtocolVersion::V1_0_0)
+        .with_reward_item(RewardItem::new(public_key.clone(), BLOCK_REWARD))
+        .with_next_era_id(builder.get_era().successor())
+        .with_run_auction(true)
+        .build();
+    builder
+        .step(step_request)
+        .expect("must execute step successfully");
+}
+
+#[ignore]
+#[test]
+fn should_move_bid_and_delegations_to_new_public_key() {
+    let mut builder = setup();
+
+    change_bid_public_key(
+        &mut builder,
+        *VALIDATOR_1_ADDR,
+        &VALIDATOR_1,
+        &NEW_VALIDATOR_1,
+    );
+    builder.expect_success();
+
+    let expected_call_cost = U512::from(
+        builder
+            .get_engine_state()
+            .config()
+            .system_config()
+            .auction_costs()
+            .change_bid_public_key,
+    );
+    assert_eq!(builder.last_exec_gas_cost().value(), expected_call_cost);
+
+    let bids = builder.get_bids();
+
+    let previous_bid = bids.get(&VALIDATOR_1).expect("should leave a bid behind");
+    assert!(previous_bid.inactive());
+    assert!(previous_bid.staked_amount().is_zero());
+    assert!(previous_bid.delegators().is_empty());
+
+    let new_bid = bids.get(&NEW_VALIDATOR_1).expect("should have moved bid");
+    assert!(!new_bid.inactive());
+    assert_eq!(new_bid.validator_public_key(), &*NEW_VALIDATOR_1);
+    assert_eq!(new_bid.staked_amount(), &U512::from(VALIDATOR_1_STAKE));
+    assert_eq!(new_bid.bonding_purse(), previous_bid.bonding_purse());
+
+    let delegator = new_bid
+        .delegators()
+        .get(&DELEGATOR_1)
+        .expect("should have moved delegator");
+    assert_eq!(delegator.validator_public_key(), &*NEW_VALIDATOR_1);
+    assert_eq!(delegator.staked_amount(), &U512::from(DELEGATOR_1_STAKE));
+}
+
+#[ignore]
+#[test]
+fn should_not_move_bid_twice_or_onto_existing_bid() {
+    let mut builder = setup();
+
+    change_bid_public_key(
+        &mut builder,
+        *DELEGATOR_1_ADDR,
+        &VALIDATOR_1,
+        &NEW_VALIDATOR_1,
+    );
+    assert_auction_error(&builder, auction::Error::InvalidContext);
+
+    change_bid_public_key(&mut builder, *VALIDATOR_1_ADDR, &VALIDATOR_1, &VALIDATOR_1);
+    assert_auction_error(&builder, auction::Error::ValidatorBidExistsAlready);
+
+    change_bid_public_key(
+        &mut builder,
+        *VALIDATOR_1_ADDR,
+        &VALIDATOR_1,
+        &NEW_VALIDATOR_1,
+    );
+    builder.expect_success();
+
+    change_bid_public_key(&mut builder, *VALIDATOR_1_ADDR, &VALIDATOR_1, &DELEGATOR_1);
+    assert_auction_error(&builder, auction::Error::ValidatorNotFound);
+}
+
+#[ignore]
+#[test]
+fn should_pay_rewards_for_previous_public_key_to_moved_bid() {
+    let mut builder = setup();
+
+    change_bid_public_key(
+        &mut builder,
+        *VALIDATOR_1_ADDR,
+        &VALIDATOR_1,
+        &NEW_VALIDATOR_1,
+    );
+    builder.expect_success();
+
+    // The validator was selected for the current and the next era under its previous key.
+    for _ in 0..2 {
+        let bid_before = builder.get_bids().remove(&NEW_VALIDATOR_1).unwrap();
+
+        step_with_reward(&mut builder, &VALIDATOR_1);
+
+        let bids = builder.get_bids();
+        let previous_bid = bids.get(&VALIDATOR_1).unwrap();
+        assert!(previous_bid.staked_amount().is_zero());
+
+        let bid_after = bids.get(&NEW_VALIDATOR_1).unwrap();
+        assert!(bid_after.staked_amount() > bid_before.staked_amount());
+        assert!(bid_after.delegators().contains_key(&DELEGATOR_1));
+    }
+
+    // Once the previous key has left the snapshot, so has its bridge.
+    let era_validators = builder.get_era_validators();
+    assert!(era_validators.values().all(
+        |weights| !weights.contains_key(&VALIDATOR_1) && weights.contains_key(&NEW_VALIDATOR_1)
+    ));
+
+    let bridges: BTreeMap<PublicKey, PublicKey> = builder.get_value(
+        builder.get_auction_contract_hash(),
+        VALIDATOR_PUBLIC_KEY_BRIDGES_KEY,
+    );
+    assert!(bridges.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_slash_moved_bid_and_unbonds_under_previous_public_key() {
+    let mut builder = setup();
+
+    let undelegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DELEGATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_UNDELEGATE,
+        runtime_args! {
+            auction::ARG_DELEGATOR => DELEGATOR_1.clone(),
+            auction::ARG_VALIDATOR => VALIDATOR_1.clone(),
+            auction::ARG_AMOUNT => U512::from(DELEGATOR_1_STAKE),
+        },
+    )
+    .build();
+    builder.exec(undelegate_request).expect_success().commit();
+
+    change_bid_public_key(
+        &mut builder,
+        *VALIDATOR_1_ADDR,
+        &VALIDATOR_1,
+        &NEW_VALIDATOR_1,
+    );
+    builder.expect_success();
+
+    let unbonds = builder.get_unbonds();
+    assert!(unbonds
+        .get(&*VALIDATOR_1_ADDR)
+        .map_or(true, |unbonding_purses| unbonding_purses.is_empty()));
+    let moved_unbonds = unbonds
+        .get(&*NEW_VALIDATOR_1_ADDR)
+        .expect("should have moved unbonds");
+    assert_eq!(moved_unbonds.len(), 1);
+    assert_eq!(moved_unbonds[0].validator_public_key(), &*NEW_VALIDATOR_1);
+    assert_eq!(moved_unbonds[0].unbonder_public_key(), &*DELEGATOR_1);
+
+    let supply_before = builder.total_supply(None);
+
+    // Consensus still refers to the validator by its previous key.
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(ProtocolVersion::V1_0_0)
+        .with_slash_item(SlashItem::new(VALIDATOR_1.clone()))
+        .with_next_era_id(builder.get_era().successor())
+        .build();
+    builder
+        .step(step_request)
+        .expect("must execute step successfully");
+
+    let bids = builder.get_bids();
+    let new_bid = bids.get(&NEW_VALIDATOR_1).unwrap();
+    assert!(new_bid.inactive());
+    assert!(new_bid.staked_amount().is_zero());
+
+    let unbonds = builder.get_unbonds();
+    assert!(unbonds
+        .get(&*NEW_VALIDATOR_1_ADDR)
+        .map_or(true, |unbonding_purses| unbonding_purses.is_empty()));
+
+    let expected_burned = U512::from(VALIDATOR_1_STAKE) + U512::from(DELEGATOR_1_STAKE);
+    assert_eq!(builder.total_supply(None), supply_before - expected_burned);
+}
@@ -0,0 +1,155 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        self, engine_config::EngineConfigBuilder, genesis::GenesisValidator, GenesisAccount,
+    },
+    execution,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{self, DelegationRate},
+    ApiError, EraId, Motes, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const VALIDATOR_1_STAKE: u64 = 1_000_000;
+const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 10;
+const ACCOUNT_BALANCE: u64 = 100_000_000_000_000;
+const BID_AMOUNT: u64 = 1_000;
+const DELEGATION_RATE_CHANGE_COOLDOWN: u64 = 2;
+const MAX_DELEGATION_RATE_INCREASE: DelegationRate = 5;
+
+static VALIDATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([3; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1));
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::account(
+            VALIDATOR_1.clone(),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(VALIDATOR_1_STAKE.into()),
+                VALIDATOR_1_DELEGATION_RATE,
+            )),
+        ));
+        tmp
+    };
+
+    let engine_config = EngineConfigBuilder::new()
+        .with_delegation_rate_change_cooldown(DELEGATION_RATE_CHANGE_COOLDOWN)
+        .with_max_delegation_rate_increase(MAX_DELEGATION_RATE_INCREASE)
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&utils::create_run_genesis_request(accounts));
+    builder
+}
+
+fn add_bid(builder: &mut InMemoryWasmTestBuilder, delegation_rate: DelegationRate) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *VALIDATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_ADD_BID,
+        runtime_args! {
+            auction::ARG_PUBLIC_KEY => VALIDATOR_1.clone(),
+            auction::ARG_AMOUNT => U512::from(BID_AMOUNT),
+            auction::ARG_DELEGATION_RATE => delegation_rate,
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn assert_auction_error(builder: &InMemoryWasmTestBuilder, expected: auction::Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == expected as u8
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_limit_delegation_rate_increases() {
+    let mut builder = setup();
+
+    let delegation_rate = builder
+        .get_delegation_rate(VALIDATOR_1.clone())
+        .expect("should have delegation rate");
+    assert_eq!(delegation_rate.pending(), VALIDATOR_1_DELEGATION_RATE);
+    assert_eq!(
+        delegation_rate.effective(),
+        Some(VALIDATOR_1_DELEGATION_RATE)
+    );
+    assert_eq!(delegation_rate.last_increase_era_id(), None);
+    assert_eq!(delegation_rate.next_increase_era_id(), None);
+
+    add_bid(
+        &mut builder,
+        VALIDATOR_1_DELEGATION_RATE + MAX_DELEGATION_RATE_INCREASE + 1,
+    );
+    assert_auction_error(&builder, auction::Error::DelegationRateIncreaseTooLarge);
+
+    let increased_rate = VALIDATOR_1_DELEGATION_RATE + MAX_DELEGATION_RATE_INCREASE;
+    add_bid(&mut builder, increased_rate);
+    builder.expect_success();
+
+    let delegation_rate = builder
+        .get_delegation_rate(VALIDATOR_1.clone())
+        .expect("should have delegation rate");
+    assert_eq!(delegation_rate.pending(), increased_rate);
+    assert_eq!(
+        delegation_rate.effective(),
+        Some(VALIDATOR_1_DELEGATION_RATE)
+    );
+    assert_eq!(delegation_rate.last_increase_era_id(), Some(EraId::new(0)));
+    assert_eq!(
+        delegation_rate.next_increase_era_id(),
+        Some(EraId::new(DELEGATION_RATE_CHANGE_COOLDOWN))
+    );
+
+    add_bid(&mut builder, increased_rate + 1);
+    assert_auction_error(&builder, auction::Error::DelegationRateChangeCooldown);
+
+    // Lowering the delegation rate is allowed during the cooldown.
+    add_bid(&mut builder, VALIDATOR_1_DELEGATION_RATE);
+    builder.expect_success();
+
+    builder.advance_eras_by(DELEGATION_RATE_CHANGE_COOLDOWN, vec![], vec![]);
+
+    add_bid(&mut builder, increased_rate);
+    builder.expect_success();
+
+    let delegation_rate = builder
+        .get_delegation_rate(VALIDATOR_1.clone())
+        .expect("should have delegation rate");
+    assert_eq!(delegation_rate.pending(), increased_rate);
+    assert_eq!(
+        delegation_rate.last_increase_era_id(),
+        Some(EraId::new(DELEGATION_RATE_CHANGE_COOLDOWN))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_get_delegation_rate_without_bid() {
+    let mut builder = setup();
+
+    let secret_key = SecretKey::ed25519_from_bytes([4; SecretKey::ED25519_LENGTH]).unwrap();
+    assert!(builder
+        .get_delegation_rate(PublicKey::from(&secret_key))
+        .is_none());
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        self,
+        engine_config::{EngineConfigBuilder, DEFAULT_MINIMUM_DELEGATION_AMOUNT},
+        genesis::GenesisValidator,
+        GenesisAccount,
+    },
+    execution,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{self, DelegationRate},
+    ApiError, Motes, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const VALIDATOR_1_STAKE: u64 = 1_000_000;
+const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 10;
+const DELEGATOR_STAKE: u64 = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+const ACCOUNT_BALANCE: u64 = 100_000_000_000_000;
+const BID_AMOUNT: u64 = 1_000;
+
+static VALIDATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([3; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1));
+static DELEGATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([204; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1));
+
+fn setup(disabled_auction_entry_points: &[&str]) -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::account(
+            VALIDATOR_1.clone(),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(VALIDATOR_1_STAKE.into()),
+                VALIDATOR_1_DELEGATION_RATE,
+            )),
+        ));
+        tmp.push(GenesisAccount::account(
+            DELEGATOR_1.clone(),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            None,
+        ));
+        tmp
+    };
+
+    let disabled_auction_entry_points: BTreeSet<String> = disabled_auction_entry_points
+        .iter()
+        .map(|entry_point_name| entry_point_name.to_string())
+        .collect();
+    let engine_config = EngineConfigBuilder::new()
+        .with_disabled_auction_entry_points(disabled_auction_entry_points)
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&utils::create_run_genesis_request(accounts));
+    builder
+}
+
+fn add_bid(builder: &mut InMemoryWasmTestBuilder) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *VALIDATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_ADD_BID,
+        runtime_args! {
+            auction::ARG_PUBLIC_KEY => VALIDATOR_1.clone(),
+            auction::ARG_AMOUNT => U512::from(BID_AMOUNT),
+            auction::ARG_DELEGATION_RATE => VALIDATOR_1_DELEGATION_RATE,
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn delegate(builder: &mut InMemoryWasmTestBuilder) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DELEGATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_DELEGATE,
+        runtime_args! {
+            auction::ARG_DELEGATOR => DELEGATOR_1.clone(),
+            auction::ARG_VALIDATOR => VALIDATOR_1.clone(),
+            auction::ARG_AMOUNT => U512::from(DELEGATOR_STAKE),
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn assert_entry_point_disabled(builder: &InMemoryWasmTestBuilder) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == auction::Error::EntryPointDisabled as u8
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_reject_calls_to_disabled_auction_entry_points() {
+    let mut builder = setup(&[auction::METHOD_ADD_BID, auction::METHOD_REDELEGATE]);
+
+    add_bid(&mut builder);
+    assert_entry_point_disabled(&builder);
+
+    // Entry points which aren't disabled keep working.
+    delegate(&mut builder);
+    builder.expect_success();
+
+    // The step doesn't rely on the disabled entry points.
+    builder.advance_eras_by(1, vec![], vec![]);
+}
+
+#[ignore]
+#[test]
+fn should_allow_calls_to_auction_entry_points_by_default() {
+    let mut builder = setup(&[]);
+
+    add_bid(&mut builder);
+    builder.expect_success();
+
+    delegate(&mut builder);
+    builder.expect_success();
+}
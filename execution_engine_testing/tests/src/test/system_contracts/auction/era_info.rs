@@ -0,0 +1,33 @@
+use casper_engine_test_support::{
+    InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_types::system::auction::{INITIAL_ERA_END_TIMESTAMP_MILLIS, INITIAL_ERA_ID};
+
+const ERA_END_TIMESTAMP_MILLIS: u64 = DEFAULT_GENESIS_TIMESTAMP_MILLIS + 60_000;
+
+#[ignore]
+#[test]
+fn should_get_era_info_from_global_state() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let era_info = builder.get_era_info();
+    assert_eq!(era_info.era_id(), INITIAL_ERA_ID);
+    assert_eq!(
+        era_info.era_start_timestamp_millis(),
+        INITIAL_ERA_END_TIMESTAMP_MILLIS
+    );
+    assert_eq!(era_info.auction_delay(), DEFAULT_AUCTION_DELAY);
+
+    builder.run_auction(ERA_END_TIMESTAMP_MILLIS, Vec::new());
+
+    let era_info = builder.get_era_info();
+    assert_eq!(era_info.era_id(), INITIAL_ERA_ID.successor());
+    assert_eq!(era_info.era_id(), builder.get_era());
+    assert_eq!(
+        era_info.era_start_timestamp_millis(),
+        ERA_END_TIMESTAMP_MILLIS
+    );
+    assert_eq!(era_info.auction_delay(), builder.get_auction_delay());
+}
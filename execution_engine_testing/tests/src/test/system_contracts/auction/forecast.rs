@@ -0,0 +1,75 @@
+use num_traits::Zero;
+
+use casper_engine_test_support::{
+    utils, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    DEFAULT_VALIDATOR_SLOTS, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::core::engine_state::genesis::{GenesisAccount, GenesisValidator};
+use casper_types::{system::auction::DelegationRate, EraId, Motes, PublicKey, SecretKey, U512};
+
+const VALIDATOR_COUNT: u8 = DEFAULT_VALIDATOR_SLOTS as u8 + 1;
+const BOND_INCREMENT: u64 = 100_000;
+const ERA_END_TIMESTAMP_MILLIS: u64 = DEFAULT_GENESIS_TIMESTAMP_MILLIS + 60_000;
+
+fn validator_public_key(index: u8) -> PublicKey {
+    let secret_key = SecretKey::ed25519_from_bytes([index; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut accounts = DEFAULT_ACCOUNTS.clone();
+    for index in 1..=VALIDATOR_COUNT {
+        accounts.push(GenesisAccount::account(
+            validator_public_key(index),
+            Motes::new(MINIMUM_ACCOUNT_CREATION_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new((BOND_INCREMENT * u64::from(index)).into()),
+                DelegationRate::zero(),
+            )),
+        ));
+    }
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&utils::create_run_genesis_request(accounts));
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_forecast_next_era_validators_without_committing() {
+    let mut builder = setup();
+    let pre_state_hash = builder.get_post_state_hash();
+
+    let forecast = builder.forecast_next_era_validators();
+    assert_eq!(builder.get_post_state_hash(), pre_state_hash);
+
+    assert_eq!(forecast.validator_slots(), DEFAULT_VALIDATOR_SLOTS as usize);
+    assert!(forecast.all_slots_taken());
+    assert_eq!(
+        forecast.minimum_winning_bid(),
+        Some(U512::from(2 * BOND_INCREMENT))
+    );
+    assert!(!forecast
+        .validator_weights()
+        .contains_key(&validator_public_key(1)));
+    assert_eq!(
+        forecast.validator_weights().len(),
+        DEFAULT_VALIDATOR_SLOTS as usize
+    );
+}
+
+#[ignore]
+#[test]
+fn should_forecast_the_validators_selected_by_the_next_auction() {
+    let mut builder = setup();
+
+    let forecast = builder.forecast_next_era_validators();
+
+    builder.run_auction(ERA_END_TIMESTAMP_MILLIS, Vec::new());
+    let delayed_era: EraId = builder.get_era() + builder.get_auction_delay();
+    let validator_weights = builder
+        .get_validator_weights(delayed_era)
+        .expect("should have validator weights");
+
+    assert_eq!(&validator_weights, forecast.validator_weights());
+}
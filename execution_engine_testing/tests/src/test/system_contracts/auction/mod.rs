@@ -1,2 +1,9 @@
 mod bids;
+mod change_bid_public_key;
+mod delegation_rate_changes;
+mod disabled_entry_points;
 mod distribute;
+mod era_info;
+mod forecast;
+mod reservations;
+mod withdraw_delegator_rewards;
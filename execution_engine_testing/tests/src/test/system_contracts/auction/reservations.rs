@@ -0,0 +1,228 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        self,
+        engine_config::{EngineConfigBuilder, DEFAULT_MINIMUM_DELEGATION_AMOUNT},
+        genesis::GenesisValidator,
+        GenesisAccount,
+    },
+    execution,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{self, DelegationRate, Reservation},
+    ApiError, Key, Motes, PublicKey, RuntimeArgs, SecretKey, StoredValue, U512,
+};
+
+const VALIDATOR_1_STAKE: u64 = 1_000_000;
+const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 10;
+const DELEGATOR_STAKE: u64 = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+const ACCOUNT_BALANCE: u64 = 100_000_000_000_000;
+const MAX_DELEGATORS_PER_VALIDATOR: u32 = 2;
+
+static VALIDATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([3; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1));
+static DELEGATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([204; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1));
+static DELEGATOR_2: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([205; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_2));
+static DELEGATOR_3: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([206; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_3_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_3));
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::account(
+            VALIDATOR_1.clone(),
+            Motes::new(ACCOUNT_BALANCE.into()),
+            Some(GenesisValidator::new(
+                Motes::new(VALIDATOR_1_STAKE.into()),
+                VALIDATOR_1_DELEGATION_RATE,
+            )),
+        ));
+        for delegator in [&*DELEGATOR_1, &*DELEGATOR_2, &*DELEGATOR_3] {
+            tmp.push(GenesisAccount::account(
+                delegator.clone(),
+                Motes::new(ACCOUNT_BALANCE.into()),
+                None,
+            ));
+        }
+        tmp
+    };
+
+    let engine_config = EngineConfigBuilder::new()
+        .with_max_delegators_per_validator(Some(MAX_DELEGATORS_PER_VALIDATOR))
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&utils::create_run_genesis_request(accounts));
+    builder
+}
+
+fn delegate(builder: &mut InMemoryWasmTestBuilder, sender: AccountHash, delegator: &PublicKey) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        builder.get_auction_contract_hash(),
+        auction::METHOD_DELEGATE,
+        runtime_args! {
+            auction::ARG_DELEGATOR => delegator.clone(),
+            auction::ARG_VALIDATOR => VALIDATOR_1.clone(),
+            auction::ARG_AMOUNT => U512::from(DELEGATOR_STAKE),
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn add_reservations(
+    builder: &mut InMemoryWasmTestBuilder,
+    sender: AccountHash,
+    delegators: &[&PublicKey],
+) {
+    let reservations = delegators
+        .iter()
+        .map(|delegator| Reservation::new((*delegator).clone(), VALIDATOR_1.clone()))
+        .collect();
+    let request = ExecuteRequestBuilder::add_reservations(
+        sender,
+        builder.get_auction_contract_hash(),
+        reservations,
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn cancel_reservations(builder: &mut InMemoryWasmTestBuilder, delegators: &[&PublicKey]) {
+    let request = ExecuteRequestBuilder::cancel_reservations(
+        *VALIDATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        VALIDATOR_1.clone(),
+        delegators
+            .iter()
+            .map(|delegator| (*delegator).clone())
+            .collect(),
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+fn assert_auction_error(builder: &InMemoryWasmTestBuilder, expected: auction::Error) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == expected as u8
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_add_and_cancel_reservations() {
+    let mut builder = setup();
+
+    add_reservations(&mut builder, *VALIDATOR_1_ADDR, &[&DELEGATOR_1]);
+    builder.expect_success();
+
+    let expected_call_cost = U512::from(
+        builder
+            .get_engine_state()
+            .config()
+            .system_config()
+            .auction_costs()
+            .add_reservations,
+    );
+    assert_eq!(builder.last_exec_gas_cost().value(), expected_call_cost);
+
+    let reservations = builder.get_reservations();
+    assert_eq!(
+        reservations.get(&VALIDATOR_1),
+        Some(&vec![Reservation::new(
+            DELEGATOR_1.clone(),
+            VALIDATOR_1.clone()
+        )])
+    );
+    // Each validator's reservations are stored under a key of their own.
+    let stored_reservations = builder
+        .query(None, Key::Reservations(*VALIDATOR_1_ADDR), &[])
+        .expect("should have stored reservations");
+    assert!(matches!(stored_reservations, StoredValue::CLValue(_)));
+
+    cancel_reservations(&mut builder, &[&DELEGATOR_2]);
+    assert_auction_error(&builder, auction::Error::ReservationNotFound);
+
+    cancel_reservations(&mut builder, &[&DELEGATOR_1]);
+    builder.expect_success();
+
+    let expected_call_cost = U512::from(
+        builder
+            .get_engine_state()
+            .config()
+            .system_config()
+            .auction_costs()
+            .cancel_reservations,
+    );
+    assert_eq!(builder.last_exec_gas_cost().value(), expected_call_cost);
+
+    assert!(builder.get_reservations().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_add_reservations_for_other_validator() {
+    let mut builder = setup();
+
+    add_reservations(&mut builder, *DELEGATOR_1_ADDR, &[&DELEGATOR_1]);
+    assert_auction_error(&builder, auction::Error::InvalidContext);
+
+    assert!(builder.get_reservations().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_keep_reserved_slots_for_reserved_delegators() {
+    let mut builder = setup();
+
+    add_reservations(&mut builder, *VALIDATOR_1_ADDR, &[&DELEGATOR_1]);
+    builder.expect_success();
+
+    delegate(&mut builder, *DELEGATOR_2_ADDR, &DELEGATOR_2);
+    builder.expect_success();
+
+    // The only unreserved slot is taken.
+    delegate(&mut builder, *DELEGATOR_3_ADDR, &DELEGATOR_3);
+    assert_auction_error(&builder, auction::Error::ExceededDelegatorSizeLimit);
+
+    delegate(&mut builder, *DELEGATOR_1_ADDR, &DELEGATOR_1);
+    builder.expect_success();
+
+    let bids = builder.get_bids();
+    let delegators = bids.get(&VALIDATOR_1).unwrap().delegators();
+    assert!(delegators.contains_key(&DELEGATOR_1));
+    assert!(delegators.contains_key(&DELEGATOR_2));
+    assert!(!delegators.contains_key(&DELEGATOR_3));
+
+    // A reservation can't claim a slot that's already taken by an unreserved delegator.
+    add_reservations(&mut builder, *VALIDATOR_1_ADDR, &[&DELEGATOR_3]);
+    assert_auction_error(&builder, auction::Error::ExceededReservationsLimit);
+}
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, StepRequestBuilder, DEFAULT_ACCOUNTS,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        self, engine_config::DEFAULT_MINIMUM_DELEGATION_AMOUNT, genesis::GenesisValidator,
+        step::RewardItem, GenesisAccount,
+    },
+    execution,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        self, DelegationRate, DelegatorRewards, BLOCK_REWARD, DELEGATOR_REWARDS_KEY,
+        EVENT_DELEGATOR_REWARD_WITHDRAWN,
+    },
+    ApiError, ContractEvent, Motes, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const VALIDATOR_STAKE: u64 = 1_000_000_000;
+const VALIDATOR_DELEGATION_RATE: DelegationRate = 10;
+const DELEGATOR_STAKE: u64 = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+const ACCOUNT_BALANCE: u64 = 100_000_000_000_000;
+
+static VALIDATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([3; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static VALIDATOR_2: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([4; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_1: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([204; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1));
+static DELEGATOR_2: Lazy<PublicKey> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([205; SecretKey::ED25519_LENGTH]).unwrap();
+    PublicKey::from(&secret_key)
+});
+static DELEGATOR_2_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_2));
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for validator in [&*VALIDATOR_1, &*VALIDATOR_2] {
+            tmp.push(GenesisAccount::account(
+                validator.clone(),
+                Motes::new(ACCOUNT_BALANCE.into()),
+                Some(GenesisValidator::new(
+                    Motes::new(VALIDATOR_STAKE.into()),
+                    VALIDATOR_DELEGATION_RATE,
+                )),
+            ));
+        }
+        for delegator in [&*DELEGATOR_1, &*DELEGATOR_2] {
+            tmp.push(GenesisAccount::account(
+                delegator.clone(),
+                Motes::new(ACCOUNT_BALANCE.into()),
+                None,
+            ));
+        }
+        tmp
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&utils::create_run_genesis_request(accounts));
+
+    for validator in [&*VALIDATOR_1, &*VALIDATOR_2] {
+        let delegate_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DELEGATOR_1_ADDR,
+            builder.get_auction_contract_hash(),
+            auction::METHOD_DELEGATE,
+            runtime_args! {
+                auction::ARG_DELEGATOR => DELEGATOR_1.clone(),
+                auction::ARG_VALIDATOR => validator.clone(),
+                auction::ARG_AMOUNT => U512::from(DELEGATOR_STAKE),
+            },
+        )
+        .build();
+        builder.exec(delegate_request).expect_success().commit();
+    }
+
+    // Let the delegations enter the seigniorage recipients of the current era.
+    builder.advance_eras_by_default_auction_delay(vec![], vec![]);
+
+    builder
+}
+
+fn step_with_rewards(builder: &mut InMemoryWasmTestBuilder) {
+    let step_request = StepRequestBuilder::new()
+        .with_parent_state_hash(builder.get_post_state_hash())
+        .with_protocol_version(ProtocolVersion::V1_0_0)
+        .with_reward_item(RewardItem::new(VALIDATOR_1.clone(), BLOCK_REWARD))
+        .with_reward_item(RewardItem::new(VALIDATOR_2.clone(), BLOCK_REWARD))
+        .with_next_era_id(builder.get_era().successor())
+        .with_run_auction(true)
+        .build();
+    builder
+        .step(step_request)
+        .expect("must execute step successfully");
+}
+
+fn withdraw_delegator_rewards(
+    builder: &mut InMemoryWasmTestBuilder,
+    sender: AccountHash,
+    delegator: &PublicKey,
+) -> Vec<ContractEvent> {
+    let request = ExecuteRequestBuilder::withdraw_delegator_rewards(
+        sender,
+        builder.get_auction_contract_hash(),
+        delegator.clone(),
+    )
+    .build();
+    builder.exec(request).commit();
+
+    let exec_results = builder
+        .get_last_exec_results()
+        .expect("should have exec results");
+    exec_results
+        .last()
+        .expect("should have exec result")
+        .events()
+        .to_vec()
+}
+
+fn get_delegator_rewards(builder: &mut InMemoryWasmTestBuilder) -> DelegatorRewards {
+    builder.get_value(builder.get_auction_contract_hash(), DELEGATOR_REWARDS_KEY)
+}
+
+fn get_delegator_stake(
+    builder: &mut InMemoryWasmTestBuilder,
+    validator: &PublicKey,
+    delegator: &PublicKey,
+) -> U512 {
+    *builder
+        .get_bids()
+        .get(validator)
+        .and_then(|bid| bid.delegators().get(delegator))
+        .expect("should have delegator")
+        .staked_amount()
+}
+
+#[ignore]
+#[test]
+fn should_withdraw_rewards_accrued_with_every_validator() {
+    let mut builder = setup();
+
+    step_with_rewards(&mut builder);
+
+    let accrued = get_delegator_rewards(&mut builder)
+        .remove(&*DELEGATOR_1)
+        .expect("should have accrued rewards");
+    assert_eq!(accrued.len(), 2);
+    for validator in [&*VALIDATOR_1, &*VALIDATOR_2] {
+        let reward = accrued.get(validator).expect("should have accrued reward");
+        assert!(!reward.is_zero());
+        assert_eq!(
+            get_delegator_stake(&mut builder, validator, &DELEGATOR_1),
+            U512::from(DELEGATOR_STAKE) + *reward
+        );
+    }
+
+    let events = withdraw_delegator_rewards(&mut builder, *DELEGATOR_1_ADDR, &DELEGATOR_1);
+    builder.expect_success();
+
+    let expected_call_cost = U512::from(builder.get_auction_costs().withdraw_delegator_reward);
+    assert_eq!(builder.last_exec_gas_cost().value(), expected_call_cost);
+
+    let withdrawn: BTreeMap<PublicKey, U512> = events
+        .iter()
+        .map(|event| {
+            assert_eq!(event.contract_hash(), builder.get_auction_contract_hash());
+            assert_eq!(event.name(), EVENT_DELEGATOR_REWARD_WITHDRAWN);
+            let (delegator, validator, amount): (PublicKey, PublicKey, U512) = event
+                .data()
+                .clone()
+                .into_t()
+                .expect("should have withdrawal");
+            assert_eq!(delegator, *DELEGATOR_1);
+            (validator, amount)
+        })
+        .collect();
+    assert_eq!(withdrawn, accrued);
+
+    let unbonds = builder.get_unbonds();
+    for validator in [&*VALIDATOR_1, &*VALIDATOR_2] {
+        assert_eq!(
+            get_delegator_stake(&mut builder, validator, &DELEGATOR_1),
+            U512::from(DELEGATOR_STAKE)
+        );
+
+        let unbonding_purse = unbonds
+            .get(&validator.to_account_hash())
+            .and_then(|unbonding_purses| {
+                unbonding_purses
+                    .iter()
+                    .find(|purse| purse.unbonder_public_key() == &*DELEGATOR_1)
+            })
+            .expect("should have unbonding purse");
+        assert_eq!(unbonding_purse.amount(), &accrued[validator]);
+    }
+
+    assert!(!get_delegator_rewards(&mut builder).contains_key(&*DELEGATOR_1));
+}
+
+#[ignore]
+#[test]
+fn should_withdraw_nothing_without_accrued_rewards() {
+    let mut builder = setup();
+
+    let events = withdraw_delegator_rewards(&mut builder, *DELEGATOR_1_ADDR, &DELEGATOR_1);
+    builder.expect_success();
+    assert!(events.is_empty());
+
+    for validator in [&*VALIDATOR_1, &*VALIDATOR_2] {
+        assert_eq!(
+            get_delegator_stake(&mut builder, validator, &DELEGATOR_1),
+            U512::from(DELEGATOR_STAKE)
+        );
+    }
+}
+
+#[ignore]
+#[test]
+fn should_not_withdraw_rewards_of_other_delegator() {
+    let mut builder = setup();
+
+    step_with_rewards(&mut builder);
+
+    withdraw_delegator_rewards(&mut builder, *DELEGATOR_2_ADDR, &DELEGATOR_1);
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == auction::Error::InvalidContext as u8
+        ),
+        "{:?}",
+        error
+    );
+
+    assert!(get_delegator_rewards(&mut builder).contains_key(&*DELEGATOR_1));
+}
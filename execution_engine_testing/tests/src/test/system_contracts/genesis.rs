@@ -5,7 +5,7 @@ use casper_engine_test_support::{
     ChainspecConfig, InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_CHAINSPEC_REGISTRY,
     DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
     DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY,
-    DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+    DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG, SYSTEM_ADDR,
 };
 use casper_execution_engine::core::engine_state::{
     engine_config::{DEFAULT_FEE_HANDLING, DEFAULT_REFUND_HANDLING},
@@ -110,6 +110,35 @@ fn should_run_genesis() {
     }
 }
 
+#[ignore]
+#[test]
+fn should_plan_genesis_without_committing() {
+    let run_genesis_request = ChainspecConfig::create_genesis_request_from_production_chainspec(
+        GENESIS_CUSTOM_ACCOUNTS.clone(),
+        ProtocolVersion::V1_0_0,
+    )
+    .expect("must create genesis request");
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let genesis_plan = builder.plan_genesis(&run_genesis_request);
+
+    builder.run_genesis(&run_genesis_request);
+
+    assert_eq!(genesis_plan.system_account_hash(), *SYSTEM_ADDR);
+    assert_eq!(genesis_plan.mint(), builder.get_mint_contract_hash());
+    assert_eq!(genesis_plan.auction(), builder.get_auction_contract_hash());
+    assert_eq!(
+        genesis_plan.handle_payment(),
+        builder.get_handle_payment_contract_hash()
+    );
+    assert_eq!(
+        genesis_plan.standard_payment(),
+        builder.get_standard_payment_contract_hash()
+    );
+    assert_eq!(genesis_plan.era_validators(), &builder.get_era_validators());
+}
+
 #[ignore]
 #[test]
 fn should_track_total_token_supply_in_mint() {
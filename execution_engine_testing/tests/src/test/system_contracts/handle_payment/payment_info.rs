@@ -0,0 +1,135 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNTS,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_CHAINSPEC_REGISTRY, DEFAULT_GENESIS_CONFIG_HASH,
+    DEFAULT_PROTOCOL_VERSION, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::{
+    engine_config::{FeeHandling, DEFAULT_FEE_HANDLING, DEFAULT_REFUND_HANDLING},
+    genesis::ExecConfigBuilder,
+    EngineConfigBuilder, RunGenesisRequest,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, system::handle_payment::PAYMENT_PURSE_KEY, EraId,
+    ProtocolVersion, RuntimeArgs, U512,
+};
+
+use crate::lmdb_fixture;
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 100_000_000_000;
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ID: &str = "id";
+
+#[ignore]
+#[test]
+fn should_get_payment_info_after_genesis() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let handle_payment_hash = builder.get_handle_payment_contract_hash();
+    let handle_payment_contract = builder
+        .get_contract(handle_payment_hash)
+        .expect("should have handle payment contract");
+    let payment_purse = handle_payment_contract
+        .named_keys()
+        .get(PAYMENT_PURSE_KEY)
+        .and_then(|key| key.into_uref())
+        .expect("should have payment purse");
+
+    let payment_info = builder.get_payment_info();
+    assert_eq!(payment_info.payment_purse(), payment_purse);
+    assert_eq!(payment_info.payment_purse_balance(), U512::zero());
+    assert!(payment_info.accumulation_purse().is_some());
+    assert_eq!(
+        payment_info.accumulation_purse_balance(),
+        Some(U512::zero())
+    );
+    assert_eq!(
+        payment_info.refund_handling(),
+        Some(DEFAULT_REFUND_HANDLING)
+    );
+    assert_eq!(payment_info.fee_handling(), Some(DEFAULT_FEE_HANDLING));
+}
+
+#[ignore]
+#[test]
+fn should_get_accumulated_fees_in_payment_info() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_fee_handling(FeeHandling::Accumulate)
+        .build();
+    let exec_config = ExecConfigBuilder::default()
+        .with_accounts(DEFAULT_ACCOUNTS.clone())
+        .with_fee_handling(FeeHandling::Accumulate)
+        .build();
+    let run_genesis_request = RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+        DEFAULT_CHAINSPEC_REGISTRY.clone(),
+    );
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&run_genesis_request);
+
+    let exec_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            ARG_ID => <Option<u64>>::None,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let payment_info = builder.get_payment_info();
+    assert_eq!(payment_info.fee_handling(), Some(FeeHandling::Accumulate));
+    assert_eq!(payment_info.payment_purse_balance(), U512::zero());
+    let accumulation_purse = payment_info
+        .accumulation_purse()
+        .expect("should have accumulation purse");
+    let accumulation_purse_balance = payment_info
+        .accumulation_purse_balance()
+        .expect("should have accumulation purse balance");
+    assert!(!accumulation_purse_balance.is_zero());
+    assert_eq!(
+        accumulation_purse_balance,
+        builder.get_purse_balance(accumulation_purse)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_record_payment_handling_at_upgrade() {
+    let (mut builder, lmdb_fixture_state, _temp_dir) =
+        lmdb_fixture::builder_from_global_state_fixture(lmdb_fixture::RELEASE_1_4_5);
+
+    let payment_info = builder.get_payment_info();
+    assert_eq!(payment_info.refund_handling(), None);
+    assert_eq!(payment_info.fee_handling(), None);
+
+    let current_protocol_version = lmdb_fixture_state.genesis_protocol_version();
+    let new_protocol_version = ProtocolVersion::from_parts(
+        current_protocol_version.value().major,
+        current_protocol_version.value().minor,
+        current_protocol_version.value().patch + 1,
+    );
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(current_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(EraId::default())
+        .build();
+    let engine_config = EngineConfigBuilder::default()
+        .with_fee_handling(FeeHandling::Accumulate)
+        .build();
+    builder
+        .upgrade_with_upgrade_request_and_config(Some(engine_config), &mut upgrade_request)
+        .expect_upgrade_success();
+
+    let payment_info = builder.get_payment_info();
+    assert_eq!(
+        payment_info.refund_handling(),
+        Some(DEFAULT_REFUND_HANDLING)
+    );
+    assert_eq!(payment_info.fee_handling(), Some(FeeHandling::Accumulate));
+}
@@ -8,7 +8,10 @@ use casper_engine_test_support::{
 };
 
 use casper_execution_engine::{
-    core::engine_state::EngineConfigBuilder,
+    core::engine_state::{
+        self, system_entity_registry, upgrade::ProtocolUpgradeError, EngineConfigBuilder,
+        SystemContractExtensionCallResult, SystemEntityRegistry,
+    },
     shared::{
         host_function_costs::HostFunctionCosts,
         opcode_costs::{
@@ -37,9 +40,10 @@ use casper_types::{
         auction::{
             AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
-        mint::ROUND_SEIGNIORAGE_RATE_KEY,
+        mint::{ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_SUPPLY_KEY},
+        MINT,
     },
-    CLValue, EraId, ProtocolVersion, RuntimeArgs, StoredValue, U256, U512,
+    CLValue, ContractHash, EraId, Key, ProtocolVersion, RuntimeArgs, StoredValue, U256, U512,
 };
 
 const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
@@ -280,6 +284,105 @@ fn should_not_skip_major_versions() {
     assert!(maybe_upgrade_result.is_err(), "expected failure");
 }
 
+#[ignore]
+#[test]
+fn should_refuse_global_state_upgrade_overriding_balances_and_total_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let main_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let total_supply_key = builder
+        .get_contract(builder.get_mint_contract_hash())
+        .expect("mint should exist")
+        .named_keys()[TOTAL_SUPPLY_KEY];
+
+    for (index, key) in [Key::Balance(main_purse.addr()), total_supply_key]
+        .into_iter()
+        .enumerate()
+    {
+        let mut update_map = BTreeMap::new();
+        update_map.insert(
+            key,
+            StoredValue::from(CLValue::from_t(U512::one()).expect("should create a CLValue")),
+        );
+
+        let mut upgrade_request = {
+            UpgradeRequestBuilder::new()
+                .with_current_protocol_version(PROTOCOL_VERSION)
+                .with_new_protocol_version(new_protocol_version)
+                .with_activation_point(DEFAULT_ACTIVATION_POINT)
+                .with_global_state_update(update_map)
+                .build()
+        };
+
+        builder.upgrade_with_upgrade_request_and_config(None, &mut upgrade_request);
+
+        let upgrade_result = builder
+            .get_upgrade_result(index)
+            .expect("should have response");
+
+        assert!(
+            matches!(
+                upgrade_result,
+                Err(engine_state::Error::ProtocolUpgrade(
+                    ProtocolUpgradeError::DangerousOverride(protected_key)
+                )) if *protected_key == key
+            ),
+            "{:?}",
+            upgrade_result
+        );
+    }
+
+    assert_ne!(builder.get_purse_balance(main_purse), U512::one());
+}
+
+#[ignore]
+#[test]
+fn should_apply_global_state_upgrade_overriding_balance_when_allowed() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let main_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::Balance(main_purse.addr()),
+        StoredValue::from(CLValue::from_t(U512::one()).expect("should create a CLValue")),
+    );
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_global_state_update(update_map)
+            .with_allow_dangerous_overrides(true)
+            .build()
+    };
+
+    builder
+        .upgrade_with_upgrade_request_and_config(None, &mut upgrade_request)
+        .expect_upgrade_success();
+
+    assert_eq!(builder.get_purse_balance(main_purse), U512::one());
+}
+
 #[ignore]
 #[test]
 fn should_allow_skip_minor_versions() {
@@ -716,3 +819,154 @@ fn should_increase_max_associated_keys_after_upgrade() {
         new_engine_config.max_associated_keys() as usize
     );
 }
+
+#[ignore]
+#[test]
+fn should_register_and_call_system_contract_extension() {
+    const EXTENSION_NAME: &str = "event_emitter";
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        "emit_event.wasm",
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let contract_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get("emit_event_hash")
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    // The name of a core system contract can't be taken over.
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_system_contract_extensions(BTreeMap::from([(MINT.to_string(), contract_hash)]))
+        .build();
+    builder.upgrade_with_upgrade_request_and_config(None, &mut upgrade_request);
+    assert!(matches!(
+        builder.get_upgrade_result(0).expect("should have response"),
+        Err(engine_state::Error::ProtocolUpgrade(
+            ProtocolUpgradeError::ReservedSystemContractName(_)
+        ))
+    ));
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_system_contract_extensions(BTreeMap::from([(
+            EXTENSION_NAME.to_string(),
+            contract_hash,
+        )]))
+        .build();
+    builder
+        .upgrade_with_upgrade_request_and_config(None, &mut upgrade_request)
+        .expect_upgrade_success();
+
+    let registry = builder.get_system_contract_registry();
+    assert_eq!(registry.get(EXTENSION_NAME), Some(&contract_hash));
+    assert_eq!(registry.extensions().count(), 1);
+
+    let args = runtime_args! { "name" => "ping", "value" => 1u64 };
+    let result = builder.call_system_contract_extension(
+        new_protocol_version,
+        EXTENSION_NAME,
+        "emit",
+        args.clone(),
+    );
+    assert!(
+        matches!(result, SystemContractExtensionCallResult::Success { .. }),
+        "{:?}",
+        result
+    );
+
+    let pre_state_hash = builder.get_post_state_hash();
+    let result = builder.call_system_contract_extension(
+        new_protocol_version,
+        EXTENSION_NAME,
+        "emit_and_revert",
+        args.clone(),
+    );
+    assert!(
+        matches!(result, SystemContractExtensionCallResult::Failure(_)),
+        "{:?}",
+        result
+    );
+    assert_eq!(builder.get_post_state_hash(), pre_state_hash);
+
+    let result =
+        builder.call_system_contract_extension(new_protocol_version, MINT, "balance", args);
+    assert!(
+        matches!(result, SystemContractExtensionCallResult::NotRegistered(_)),
+        "{:?}",
+        result
+    );
+}
+
+#[ignore]
+#[test]
+fn should_migrate_legacy_system_contract_registry() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let query_registry = |builder: &InMemoryWasmTestBuilder| {
+        builder
+            .query(None, Key::SystemContractRegistry, &[])
+            .expect("should have registry")
+            .as_cl_value()
+            .expect("should be a CLValue")
+            .clone()
+    };
+    assert!(!system_entity_registry::is_legacy_registry(
+        &query_registry(&builder)
+    ));
+
+    let legacy_registry = builder.get_system_contract_registry();
+    let mint_hash = builder.get_mint_contract_hash();
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    // Put the registry back into the legacy format, as found on networks predating the migration.
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::SystemContractRegistry,
+        StoredValue::from(CLValue::from_t(legacy_registry).expect("should create a CLValue")),
+    );
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_global_state_update(update_map)
+        .with_allow_dangerous_overrides(true)
+        .build();
+    builder
+        .upgrade_with_upgrade_request_and_config(None, &mut upgrade_request)
+        .expect_upgrade_success();
+
+    let cl_registry = query_registry(&builder);
+    assert!(!system_entity_registry::is_legacy_registry(&cl_registry));
+    let registry =
+        SystemEntityRegistry::from_cl_value(cl_registry).expect("should be a system registry");
+    assert_eq!(registry.get(MINT), Some(mint_hash.value()));
+    assert_eq!(
+        builder.get_system_contract_registry().get(MINT),
+        Some(&mint_hash)
+    );
+}
@@ -979,6 +979,21 @@ fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
         blake2b: HostFunction::fixed(0),
         random_bytes: HostFunction::fixed(0),
         enable_contract_version: HostFunction::fixed(0),
+        create_contract_package_at_seed: HostFunction::fixed(0),
+        emit_event: HostFunction::fixed(0),
+        set_contract_package_paused: HostFunction::fixed(0),
+        get_remaining_call_depth: HostFunction::fixed(0),
+        sorted_index_insert: HostFunction::fixed(0),
+        sorted_index_remove: HostFunction::fixed(0),
+        sorted_index_get: HostFunction::fixed(0),
+        sorted_index_range: HostFunction::fixed(0),
+        namespace_put: HostFunction::fixed(0),
+        namespace_get: HostFunction::fixed(0),
+        namespace_clear: HostFunction::fixed(0),
+        new_unique_address: HostFunction::fixed(0),
+        schedule_callback: HostFunction::fixed(0),
+        cancel_callback: HostFunction::fixed(0),
+        transfer_from_purse_to_purse_with_result: HostFunction::fixed(0),
     };
 
     let new_wasm_config = WasmConfig::new(
@@ -8,8 +8,8 @@ use casper_engine_test_support::{
 use casper_execution_engine::{
     core::{
         engine_state::{
-            engine_config::DEFAULT_MAX_ASSOCIATED_KEYS, EngineConfigBuilder, Error as CoreError,
-            WASMLESS_TRANSFER_FIXED_GAS_PRICE,
+            engine_config::{CompatibilityMode, Quirk, DEFAULT_MAX_ASSOCIATED_KEYS},
+            EngineConfigBuilder, Error as CoreError, WASMLESS_TRANSFER_FIXED_GAS_PRICE,
         },
         execution::Error as ExecError,
     },
@@ -300,6 +300,18 @@ fn should_not_transfer_wasmless_other_purse_to_self_purse() {
     invalid_transfer_wasmless(InvalidWasmlessTransfer::OtherPurseToSelfPurse);
 }
 
+#[ignore]
+#[test]
+fn should_not_transfer_wasmless_target_name_missing() {
+    invalid_transfer_wasmless(InvalidWasmlessTransfer::TargetNameMissing);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_wasmless_target_name_not_purse() {
+    invalid_transfer_wasmless(InvalidWasmlessTransfer::TargetNameNotPurse);
+}
+
 enum InvalidWasmlessTransfer {
     TransferToSelfByAddr,
     TransferToSelfByKey,
@@ -314,6 +326,8 @@ enum InvalidWasmlessTransfer {
     SourceURefNonexistent,
     TargetURefNonexistent,
     OtherPurseToSelfPurse,
+    TargetNameMissing,
+    TargetNameNotPurse,
 }
 
 fn invalid_transfer_wasmless(invalid_wasmless_transfer: InvalidWasmlessTransfer) {
@@ -506,6 +520,30 @@ fn invalid_transfer_wasmless(invalid_wasmless_transfer: InvalidWasmlessTransfer)
                 CoreError::Exec(ExecError::ForgedReference(account_2_purse)),
             )
         }
+        InvalidWasmlessTransfer::TargetNameMissing => {
+            // passes a name which isn't in the caller's named keys as target
+            (
+                *ACCOUNT_1_ADDR,
+                runtime_args! {
+                    mint::ARG_TARGET => TEST_PURSE_NAME,
+                    mint::ARG_AMOUNT => transfer_amount,
+                    mint::ARG_ID => id
+                },
+                CoreError::Exec(ExecError::Revert(ApiError::MissingKey)),
+            )
+        }
+        InvalidWasmlessTransfer::TargetNameNotPurse => {
+            // passes the name of a named uref that is not a purse uref as target
+            (
+                *DEFAULT_ACCOUNT_ADDR,
+                runtime_args! {
+                    mint::ARG_TARGET => NON_UREF_NAMED_KEY,
+                    mint::ARG_AMOUNT => transfer_amount,
+                    mint::ARG_ID => id
+                },
+                CoreError::Exec(ExecError::Revert(ApiError::InvalidPurse)),
+            )
+        }
     };
 
     let no_wasm_transfer_request = {
@@ -959,6 +997,131 @@ fn transfer_wasmless_should_fail_with_secondary_purse_insufficient_funds() {
     );
 }
 
+#[ignore]
+#[test]
+fn transfer_wasmless_should_transfer_to_named_purse() {
+    let create_account_2: bool = false;
+    let mut builder = init_wasmless_transform_builder(create_account_2);
+    let transfer_amount: U512 = U512::from(1000);
+
+    let create_purse_request = ExecuteRequestBuilder::standard(
+        *ACCOUNT_1_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+    )
+    .build();
+    builder.exec(create_purse_request).commit().expect_success();
+
+    let account_1 = builder
+        .get_account(*ACCOUNT_1_ADDR)
+        .expect("should get account 1");
+    let main_purse = account_1.main_purse();
+    let named_purse = account_1
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .expect("should have purse")
+        .into_uref()
+        .expect("should have purse uref");
+
+    let main_purse_starting_balance = builder.get_purse_balance(main_purse);
+    assert_eq!(builder.get_purse_balance(named_purse), U512::zero());
+
+    let runtime_args = runtime_args! {
+       mint::ARG_TARGET => TEST_PURSE_NAME,
+       mint::ARG_AMOUNT => transfer_amount,
+       mint::ARG_ID => <Option<u64>>::None
+    };
+
+    let no_wasm_transfer_request = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(*ACCOUNT_1_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args)
+            .with_authorization_keys(&[*ACCOUNT_1_ADDR])
+            .with_deploy_hash([42; 32])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+
+    builder
+        .exec(no_wasm_transfer_request)
+        .expect_success()
+        .commit();
+
+    let wasmless_transfer_gas_cost = Gas::from(DEFAULT_WASMLESS_TRANSFER_COST);
+    let wasmless_transfer_cost = Motes::from_gas(
+        wasmless_transfer_gas_cost,
+        WASMLESS_TRANSFER_FIXED_GAS_PRICE,
+    )
+    .expect("gas overflow");
+
+    assert_eq!(
+        builder.get_purse_balance(main_purse),
+        main_purse_starting_balance - transfer_amount - wasmless_transfer_cost.value(),
+    );
+    assert_eq!(builder.get_purse_balance(named_purse), transfer_amount);
+}
+
+#[ignore]
+#[test]
+fn transfer_wasmless_should_not_transfer_to_named_purse_with_quirk() {
+    let engine_config = EngineConfigBuilder::new()
+        .with_compatibility_mode(
+            *DEFAULT_PROTOCOL_VERSION,
+            CompatibilityMode::new().with_quirk(Quirk::NoNamedPurseTransferTargets),
+        )
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let create_purse_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+    )
+    .build();
+    builder.exec(create_purse_request).commit().expect_success();
+
+    let named_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should get default account")
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .expect("should have purse")
+        .into_uref()
+        .expect("should have purse uref");
+
+    let runtime_args = runtime_args! {
+       mint::ARG_TARGET => TEST_PURSE_NAME,
+       mint::ARG_AMOUNT => U512::from(1000),
+       mint::ARG_ID => <Option<u64>>::None
+    };
+
+    let no_wasm_transfer_request = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args)
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_deploy_hash([42; 32])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+
+    builder.exec(no_wasm_transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            CoreError::Exec(ExecError::Revert(ApiError::InvalidArgument))
+        ),
+        "{:?}",
+        error
+    );
+    assert_eq!(builder.get_purse_balance(named_purse), U512::zero());
+}
+
 #[ignore]
 #[test]
 fn transfer_wasmless_should_observe_upgraded_cost() {
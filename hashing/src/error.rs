@@ -105,3 +105,30 @@ pub enum MerkleConstructionError {
         count: String,
     },
 }
+
+/// Error verifying or reading a Merkle multi-proof of trie entries.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrieMultiProofError {
+    /// The root hash computed from the proof doesn't match the expected state root hash.
+    #[error("Trie multi-proof has an unexpected root hash")]
+    UnexpectedRootHash,
+    /// The children of a node of the proof are not in strictly ascending order of index.
+    #[error("Trie multi-proof has a node with unordered or duplicate children")]
+    UnorderedChildren,
+    /// The path to the key leads to a pruned part of the trie, so the proof says nothing about it.
+    #[error("Trie multi-proof doesn't cover the requested key")]
+    KeyNotProven,
+    /// The proof shows the key has a value, so it doesn't prove the key's absence.
+    #[error("Trie multi-proof shows the key is present")]
+    KeyPresent,
+    /// Bytesrepr error.
+    #[error("Bytesrepr error computing trie multi-proof hash: {0}")]
+    Bytesrepr(bytesrepr::Error),
+}
+
+impl From<bytesrepr::Error> for TrieMultiProofError {
+    fn from(error: bytesrepr::Error) -> Self {
+        TrieMultiProofError::Bytesrepr(error)
+    }
+}
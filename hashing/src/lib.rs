@@ -10,6 +10,7 @@
 mod chunk_with_proof;
 mod error;
 mod indexed_merkle_proof;
+mod trie_multi_proof;
 
 use std::{
     array::TryFromSliceError,
@@ -38,8 +39,10 @@ use casper_types::{
 pub use chunk_with_proof::ChunkWithProof;
 pub use error::{
     ChunkWithProofVerificationError, Error, MerkleConstructionError, MerkleVerificationError,
+    TrieMultiProofError,
 };
 pub use indexed_merkle_proof::IndexedMerkleProof;
+pub use trie_multi_proof::{TrieMultiProof, TrieMultiProofNode};
 
 /// The output of the hash function.
 #[derive(Copy, Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Default, JsonSchema)]
@@ -0,0 +1,520 @@
+//! Merkle proofs of several entries of a Merkle trie at once.
+use casper_types::bytesrepr::{
+    self, Bytes, FromBytes, ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, U8_SERIALIZED_LENGTH,
+};
+
+use crate::{error::TrieMultiProofError, Digest};
+
+/// The tags of the trie nodes, and of the pointers to them, as serialized for hashing.
+const TRIE_LEAF_TAG: u8 = 0;
+const TRIE_NODE_TAG: u8 = 1;
+const TRIE_EXTENSION_TAG: u8 = 2;
+const LEAF_POINTER_TAG: u8 = 0;
+const NODE_POINTER_TAG: u8 = 1;
+const RADIX: usize = 256;
+
+const LEAF_TAG: u8 = 0;
+const NODE_TAG: u8 = 1;
+const EXTENSION_TAG: u8 = 2;
+const PRUNED_LEAF_TAG: u8 = 3;
+const PRUNED_NODE_TAG: u8 = 4;
+
+/// Every node of a trie consumes at least one byte of a key's path, so no valid proof is deeper
+/// than this.
+const MAX_DEPTH: usize = 256;
+
+/// A node of a [`TrieMultiProof`].
+///
+/// The nodes on the path to any of the proven keys are included in full, while every other
+/// subtree is pruned down to the hash of its root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrieMultiProofNode<K, V> {
+    /// A leaf on the path to one of the proven keys.
+    Leaf {
+        /// Leaf key.
+        key: K,
+        /// Leaf value.
+        value: V,
+    },
+    /// A branch node on the path to one of the proven keys.
+    Node {
+        /// The children of the node, in ascending order of index.
+        children: Vec<(u8, TrieMultiProofNode<K, V>)>,
+    },
+    /// An extension node on the path to one of the proven keys.
+    Extension {
+        /// Extension node affix bytes.
+        affix: Bytes,
+        /// The child of the extension node.
+        child: Box<TrieMultiProofNode<K, V>>,
+    },
+    /// A pruned leaf, represented by its hash.
+    PrunedLeaf(Digest),
+    /// A pruned branch or extension node, represented by its hash.
+    PrunedNode(Digest),
+}
+
+impl<K, V> TrieMultiProofNode<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    /// Computes the hash of the trie node represented by `self`.
+    pub fn hash(&self) -> Result<Digest, TrieMultiProofError> {
+        let bytes = match self {
+            TrieMultiProofNode::Leaf { key, value } => {
+                let mut bytes = Vec::with_capacity(
+                    U8_SERIALIZED_LENGTH + key.serialized_length() + value.serialized_length(),
+                );
+                bytes.push(TRIE_LEAF_TAG);
+                key.write_bytes(&mut bytes)?;
+                value.write_bytes(&mut bytes)?;
+                bytes
+            }
+            TrieMultiProofNode::Node { children } => {
+                let mut pointers = [None; RADIX];
+                let mut previous_index = None;
+                for (index, child) in children {
+                    if previous_index.map_or(false, |previous| previous >= *index) {
+                        return Err(TrieMultiProofError::UnorderedChildren);
+                    }
+                    previous_index = Some(*index);
+                    pointers[usize::from(*index)] = Some(child.pointer()?);
+                }
+                let mut bytes = Vec::with_capacity(
+                    U8_SERIALIZED_LENGTH + RADIX * (2 * U8_SERIALIZED_LENGTH + Digest::LENGTH),
+                );
+                bytes.push(TRIE_NODE_TAG);
+                for pointer in pointers.iter() {
+                    match pointer {
+                        Some((pointer_tag, hash)) => {
+                            bytes.push(OPTION_SOME_TAG);
+                            bytes.push(*pointer_tag);
+                            bytes.extend_from_slice(hash.as_ref());
+                        }
+                        None => bytes.push(OPTION_NONE_TAG),
+                    }
+                }
+                bytes
+            }
+            TrieMultiProofNode::Extension { affix, child } => {
+                let (pointer_tag, hash) = child.pointer()?;
+                let mut bytes = Vec::with_capacity(
+                    2 * U8_SERIALIZED_LENGTH + affix.serialized_length() + Digest::LENGTH,
+                );
+                bytes.push(TRIE_EXTENSION_TAG);
+                affix.write_bytes(&mut bytes)?;
+                bytes.push(pointer_tag);
+                bytes.extend_from_slice(hash.as_ref());
+                bytes
+            }
+            TrieMultiProofNode::PrunedLeaf(hash) | TrieMultiProofNode::PrunedNode(hash) => {
+                return Ok(*hash)
+            }
+        };
+        Ok(Digest::hash_into_chunks_if_necessary(&bytes))
+    }
+
+    /// Returns the tag and hash of a pointer to the trie node represented by `self`.
+    fn pointer(&self) -> Result<(u8, Digest), TrieMultiProofError> {
+        let pointer_tag = match self {
+            TrieMultiProofNode::Leaf { .. } | TrieMultiProofNode::PrunedLeaf(_) => LEAF_POINTER_TAG,
+            TrieMultiProofNode::Node { .. }
+            | TrieMultiProofNode::Extension { .. }
+            | TrieMultiProofNode::PrunedNode(_) => NODE_POINTER_TAG,
+        };
+        Ok((pointer_tag, self.hash()?))
+    }
+}
+
+impl<K, V> TrieMultiProofNode<K, V> {
+    fn tag(&self) -> u8 {
+        match self {
+            TrieMultiProofNode::Leaf { .. } => LEAF_TAG,
+            TrieMultiProofNode::Node { .. } => NODE_TAG,
+            TrieMultiProofNode::Extension { .. } => EXTENSION_TAG,
+            TrieMultiProofNode::PrunedLeaf(_) => PRUNED_LEAF_TAG,
+            TrieMultiProofNode::PrunedNode(_) => PRUNED_NODE_TAG,
+        }
+    }
+
+    fn from_bytes_at_depth(bytes: &[u8], depth: usize) -> Result<(Self, &[u8]), bytesrepr::Error>
+    where
+        K: FromBytes,
+        V: FromBytes,
+    {
+        if depth > MAX_DEPTH {
+            return Err(bytesrepr::Error::ExceededRecursionDepth);
+        }
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            LEAF_TAG => {
+                let (key, remainder) = K::from_bytes(remainder)?;
+                let (value, remainder) = V::from_bytes(remainder)?;
+                Ok((TrieMultiProofNode::Leaf { key, value }, remainder))
+            }
+            NODE_TAG => {
+                let (count, mut remainder) = u32::from_bytes(remainder)?;
+                if count as usize > RADIX {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                let mut children = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    let (index, rem) = u8::from_bytes(remainder)?;
+                    let (child, rem) = Self::from_bytes_at_depth(rem, depth + 1)?;
+                    children.push((index, child));
+                    remainder = rem;
+                }
+                Ok((TrieMultiProofNode::Node { children }, remainder))
+            }
+            EXTENSION_TAG => {
+                let (affix, remainder) = Bytes::from_bytes(remainder)?;
+                let (child, remainder) = Self::from_bytes_at_depth(remainder, depth + 1)?;
+                Ok((
+                    TrieMultiProofNode::Extension {
+                        affix,
+                        child: Box::new(child),
+                    },
+                    remainder,
+                ))
+            }
+            PRUNED_LEAF_TAG => {
+                let (hash, remainder) = Digest::from_bytes(remainder)?;
+                Ok((TrieMultiProofNode::PrunedLeaf(hash), remainder))
+            }
+            PRUNED_NODE_TAG => {
+                let (hash, remainder) = Digest::from_bytes(remainder)?;
+                Ok((TrieMultiProofNode::PrunedNode(hash), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl<K, V> ToBytes for TrieMultiProofNode<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut result)?;
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                TrieMultiProofNode::Leaf { key, value } => {
+                    key.serialized_length() + value.serialized_length()
+                }
+                TrieMultiProofNode::Node { children } => {
+                    // The length prefix of the children has the size of a `u32`.
+                    4 + children
+                        .iter()
+                        .map(|(_, child)| U8_SERIALIZED_LENGTH + child.serialized_length())
+                        .sum::<usize>()
+                }
+                TrieMultiProofNode::Extension { affix, child } => {
+                    affix.serialized_length() + child.serialized_length()
+                }
+                TrieMultiProofNode::PrunedLeaf(hash) | TrieMultiProofNode::PrunedNode(hash) => {
+                    hash.serialized_length()
+                }
+            }
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        writer.push(self.tag());
+        match self {
+            TrieMultiProofNode::Leaf { key, value } => {
+                key.write_bytes(writer)?;
+                value.write_bytes(writer)?;
+            }
+            TrieMultiProofNode::Node { children } => {
+                let count = u32::try_from(children.len())
+                    .map_err(|_| bytesrepr::Error::NotRepresentable)?;
+                count.write_bytes(writer)?;
+                for (index, child) in children {
+                    writer.push(*index);
+                    child.write_bytes(writer)?;
+                }
+            }
+            TrieMultiProofNode::Extension { affix, child } => {
+                affix.write_bytes(writer)?;
+                child.write_bytes(writer)?;
+            }
+            TrieMultiProofNode::PrunedLeaf(hash) | TrieMultiProofNode::PrunedNode(hash) => {
+                writer.extend_from_slice(hash.as_ref());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K, V> FromBytes for TrieMultiProofNode<K, V>
+where
+    K: FromBytes,
+    V: FromBytes,
+{
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Self::from_bytes_at_depth(bytes, 0)
+    }
+}
+
+/// A Merkle proof of the values of several keys of a trie, or of their absence.
+///
+/// The proof is the trie with every subtree off the paths to the proven keys pruned down to the
+/// hash of its root, so the nodes shared by the paths are only included once.  After checking the
+/// proof against a trusted state root hash with [`TrieMultiProof::verify`], the value of any of the
+/// proven keys can be read with [`TrieMultiProof::get`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMultiProof<K, V> {
+    root: TrieMultiProofNode<K, V>,
+}
+
+impl<K, V> TrieMultiProof<K, V> {
+    /// Constructs a new `TrieMultiProof` from the root of a pruned trie.
+    pub fn new(root: TrieMultiProofNode<K, V>) -> Self {
+        TrieMultiProof { root }
+    }
+
+    /// Returns the root of the pruned trie.
+    pub fn root(&self) -> &TrieMultiProofNode<K, V> {
+        &self.root
+    }
+
+    /// Returns the value stored under `key`, or `None` if the proof shows there is no such entry.
+    ///
+    /// This doesn't check the proof, which should first be verified against a trusted state root
+    /// hash.  Fails with [`TrieMultiProofError::KeyNotProven`] if the path to `key` was pruned.
+    pub fn get(&self, key: &K) -> Result<Option<&V>, TrieMultiProofError>
+    where
+        K: ToBytes + PartialEq,
+    {
+        let path = key.to_bytes()?;
+        let mut depth = 0;
+        let mut node = &self.root;
+        loop {
+            match node {
+                TrieMultiProofNode::Leaf {
+                    key: leaf_key,
+                    value,
+                } => return Ok(if leaf_key == key { Some(value) } else { None }),
+                TrieMultiProofNode::Node { children } => {
+                    let index = path.get(depth).ok_or(TrieMultiProofError::KeyNotProven)?;
+                    match children
+                        .iter()
+                        .find(|(child_index, _)| child_index == index)
+                    {
+                        Some((_, child)) => node = child,
+                        None => return Ok(None),
+                    }
+                    depth += 1;
+                }
+                TrieMultiProofNode::Extension { affix, child } => {
+                    let on_path = path
+                        .get(depth..)
+                        .map_or(false, |remaining_path| remaining_path.starts_with(affix));
+                    if !on_path {
+                        return Ok(None);
+                    }
+                    depth += affix.len();
+                    node = child;
+                }
+                TrieMultiProofNode::PrunedLeaf(_) | TrieMultiProofNode::PrunedNode(_) => {
+                    return Err(TrieMultiProofError::KeyNotProven)
+                }
+            }
+        }
+    }
+}
+
+impl<K, V> TrieMultiProof<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    /// Computes the hash of the root of the trie.
+    pub fn root_hash(&self) -> Result<Digest, TrieMultiProofError> {
+        self.root.hash()
+    }
+
+    /// Checks that the proof is of the trie with root hash `state_root_hash`.
+    pub fn verify(&self, state_root_hash: &Digest) -> Result<(), TrieMultiProofError> {
+        if self.root_hash()? != *state_root_hash {
+            return Err(TrieMultiProofError::UnexpectedRootHash);
+        }
+        Ok(())
+    }
+
+    /// Checks that the proof is of the trie with root hash `state_root_hash`, and that it shows
+    /// there is no entry under `key` in that trie.
+    pub fn verify_absence(
+        &self,
+        state_root_hash: &Digest,
+        key: &K,
+    ) -> Result<(), TrieMultiProofError>
+    where
+        K: PartialEq,
+    {
+        self.verify(state_root_hash)?;
+        match self.get(key)? {
+            Some(_) => Err(TrieMultiProofError::KeyPresent),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K, V> ToBytes for TrieMultiProof<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.root.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.root.serialized_length()
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.root.write_bytes(writer)
+    }
+}
+
+impl<K, V> FromBytes for TrieMultiProof<K, V>
+where
+    K: FromBytes,
+    V: FromBytes,
+{
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (root, remainder) = TrieMultiProofNode::from_bytes(bytes)?;
+        Ok((TrieMultiProof { root }, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestNode = TrieMultiProofNode<Bytes, u64>;
+
+    fn leaf(key: &[u8], value: u64) -> TestNode {
+        TrieMultiProofNode::Leaf {
+            key: Bytes::from(key.to_vec()),
+            value,
+        }
+    }
+
+    /// A leaf which is pruned from the proof.
+    fn pruned_leaf() -> TestNode {
+        leaf(&[2, 0, 0, 0], 3)
+    }
+
+    /// A proof of the keys `[1, 1, 1]` and `[1, 2, 0]` in a trie which also holds `[2, 0, 0, 0]`.
+    ///
+    /// Keys of type `Bytes` are prefixed with their length, so the first two share the path
+    /// `[3, 0, 0, 0, 1]`, which is split between the root and an extension.
+    fn proof() -> TrieMultiProof<Bytes, u64> {
+        TrieMultiProof::new(TrieMultiProofNode::Node {
+            children: vec![
+                (
+                    3,
+                    TrieMultiProofNode::Extension {
+                        affix: Bytes::from(vec![0, 0, 0, 1]),
+                        child: Box::new(TrieMultiProofNode::Node {
+                            children: vec![(1, leaf(&[1, 1, 1], 1)), (2, leaf(&[1, 2, 0], 2))],
+                        }),
+                    },
+                ),
+                (
+                    4,
+                    TrieMultiProofNode::PrunedLeaf(pruned_leaf().hash().unwrap()),
+                ),
+            ],
+        })
+    }
+
+    #[test]
+    fn should_read_proven_keys() {
+        let proof = proof();
+        assert_eq!(proof.get(&Bytes::from(vec![1, 1, 1])), Ok(Some(&1)));
+        assert_eq!(proof.get(&Bytes::from(vec![1, 2, 0])), Ok(Some(&2)));
+        // Absent keys diverging at the root, the extension, the inner node and the leaf.
+        assert_eq!(proof.get(&Bytes::from(vec![0, 1])), Ok(None));
+        assert_eq!(proof.get(&Bytes::from(vec![2, 0, 0])), Ok(None));
+        assert_eq!(proof.get(&Bytes::from(vec![1, 3, 0])), Ok(None));
+        assert_eq!(proof.get(&Bytes::from(vec![1, 1, 2])), Ok(None));
+    }
+
+    #[test]
+    fn should_not_read_pruned_keys() {
+        assert_eq!(
+            proof().get(&Bytes::from(vec![2, 0, 0, 0])),
+            Err(TrieMultiProofError::KeyNotProven)
+        );
+    }
+
+    #[test]
+    fn should_hash_pruned_nodes_like_expanded_ones() {
+        let expanded = TrieMultiProofNode::Node {
+            children: vec![(4, pruned_leaf())],
+        };
+        let pruned: TestNode = TrieMultiProofNode::Node {
+            children: vec![(
+                4,
+                TrieMultiProofNode::PrunedLeaf(pruned_leaf().hash().unwrap()),
+            )],
+        };
+        assert_eq!(expanded.hash(), pruned.hash());
+
+        let proof = proof();
+        let root_hash = proof.root_hash().unwrap();
+        assert!(proof.verify(&root_hash).is_ok());
+        assert!(TrieMultiProof::new(TestNode::PrunedNode(root_hash))
+            .verify(&root_hash)
+            .is_ok());
+        assert_eq!(
+            proof.verify(&Digest::hash([0u8])),
+            Err(TrieMultiProofError::UnexpectedRootHash)
+        );
+    }
+
+    #[test]
+    fn should_verify_absence_of_keys() {
+        let proof = proof();
+        let root_hash = proof.root_hash().unwrap();
+        assert!(proof
+            .verify_absence(&root_hash, &Bytes::from(vec![1, 3, 0]))
+            .is_ok());
+        assert_eq!(
+            proof.verify_absence(&root_hash, &Bytes::from(vec![1, 1, 1])),
+            Err(TrieMultiProofError::KeyPresent)
+        );
+        assert_eq!(
+            proof.verify_absence(&root_hash, &Bytes::from(vec![2, 0, 0, 0])),
+            Err(TrieMultiProofError::KeyNotProven)
+        );
+        assert_eq!(
+            proof.verify_absence(&Digest::hash([0u8]), &Bytes::from(vec![1, 3, 0])),
+            Err(TrieMultiProofError::UnexpectedRootHash)
+        );
+    }
+
+    #[test]
+    fn should_reject_unordered_children() {
+        let node = TrieMultiProofNode::Node {
+            children: vec![(2, leaf(&[2], 2)), (1, leaf(&[1], 1))],
+        };
+        assert_eq!(node.hash(), Err(TrieMultiProofError::UnorderedChildren));
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&proof());
+        bytesrepr::test_serialization_roundtrip(&pruned_leaf());
+        bytesrepr::test_serialization_roundtrip(&TestNode::PrunedNode(Digest::hash([1u8])));
+    }
+}
@@ -7,6 +7,7 @@ mod deploy_acquisition;
 mod error;
 mod event;
 mod execution_results_acquisition;
+mod execution_results_backfill;
 mod global_state_synchronizer;
 mod metrics;
 mod need_next;
@@ -54,8 +55,9 @@ use crate::{
     types::{
         sync_leap_validation_metadata::SyncLeapValidationMetaData, ApprovalsHashes, Block,
         BlockExecutionResultsOrChunk, BlockHash, BlockHeader, BlockSignatures, Chainspec, Deploy,
-        FinalitySignature, FinalitySignatureId, FinalizedBlock, LegacyDeploy, MetaBlock,
-        MetaBlockState, NodeId, SyncLeap, SyncLeapIdentifier, TrieOrChunk, ValidatorMatrix,
+        DeployValidationMetadata, FinalitySignature, FinalitySignatureId, FinalizedBlock,
+        LegacyDeploy, MetaBlock, MetaBlockState, NodeId, SyncLeap, SyncLeapIdentifier, TrieOrChunk,
+        ValidatorMatrix,
     },
     NodeRng,
 };
@@ -67,6 +69,7 @@ pub(crate) use error::BlockAcquisitionError;
 pub(crate) use event::Event;
 use execution_results_acquisition::ExecutionResultsAcquisition;
 pub(crate) use execution_results_acquisition::ExecutionResultsChecksum;
+pub(crate) use execution_results_backfill::backfill_execution_results;
 use global_state_synchronizer::GlobalStateSynchronizer;
 pub(crate) use global_state_synchronizer::{
     Error as GlobalStateSynchronizerError, Event as GlobalStateSynchronizerEvent,
@@ -668,7 +671,13 @@ impl BlockSynchronizer {
                     builder.latch_by(peers.len());
                     results.extend(peers.into_iter().flat_map(|node_id| {
                         effect_builder
-                            .fetch::<Deploy>(deploy_id, node_id, Box::new(EmptyValidationMetadata))
+                            .fetch::<Deploy>(
+                                deploy_id,
+                                node_id,
+                                Box::new(DeployValidationMetadata {
+                                    chain_id: chainspec.network_config.chain_id,
+                                }),
+                            )
                             .event(move |result| Event::DeployFetched {
                                 block_hash,
                                 result: Either::Right(result),
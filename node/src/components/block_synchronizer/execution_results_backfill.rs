@@ -0,0 +1,103 @@
+//! On-demand backfill of execution results which weren't retained by the storage component.
+
+use thiserror::Error;
+use tracing::{debug, error};
+
+use casper_execution_engine::core::engine_state;
+
+use super::{ExecutionResultsAcquisition, ExecutionResultsChecksum};
+use crate::{
+    components::fetcher::FetchedData,
+    effect::{
+        requests::{ContractRuntimeRequest, FetcherRequest, NetworkInfoRequest, StorageRequest},
+        EffectBuilder,
+    },
+    types::{BlockExecutionResultsOrChunk, BlockHash, DeployHash},
+};
+
+/// Maximum number of peers asked for the execution results of a block.
+const MAX_PEERS: usize = 5;
+
+/// Error backfilling the execution results of a block.
+#[derive(Debug, Error)]
+pub(crate) enum ExecutionResultsBackfillError {
+    /// The block isn't in storage.
+    #[error("block {0} not found in storage")]
+    BlockNotFound(BlockHash),
+    /// None of the peers asked provided the execution results.
+    #[error("no peer provided the execution results of block {0}")]
+    NotAcquired(BlockHash),
+}
+
+/// Fetches the execution results of a stored block from peers, and stores them.
+///
+/// The results are checked against the execution results checksum of the block's global state,
+/// where available.
+pub(crate) async fn backfill_execution_results<REv>(
+    effect_builder: EffectBuilder<REv>,
+    block_hash: BlockHash,
+) -> Result<(), ExecutionResultsBackfillError>
+where
+    REv: From<StorageRequest>
+        + From<ContractRuntimeRequest>
+        + From<NetworkInfoRequest>
+        + From<FetcherRequest<BlockExecutionResultsOrChunk>>,
+{
+    let block = effect_builder
+        .get_block_from_storage(block_hash)
+        .await
+        .ok_or(ExecutionResultsBackfillError::BlockNotFound(block_hash))?;
+    let checksum = match effect_builder
+        .get_execution_results_checksum(*block.state_root_hash())
+        .await
+    {
+        Ok(Some(digest)) => ExecutionResultsChecksum::Checkable(digest),
+        // The registry will not exist for legacy blocks.
+        Ok(None) | Err(engine_state::Error::MissingChecksumRegistry) => {
+            ExecutionResultsChecksum::Uncheckable
+        }
+        Err(error) => {
+            error!(%block_hash, %error, "unexpected error getting checksum registry");
+            ExecutionResultsChecksum::Uncheckable
+        }
+    };
+    let deploy_hashes: Vec<DeployHash> = block.deploy_and_transfer_hashes().copied().collect();
+
+    'peers: for peer in effect_builder.get_fully_connected_peers(MAX_PEERS).await {
+        let mut acquisition = ExecutionResultsAcquisition::Pending {
+            block_hash,
+            checksum,
+        };
+        while let Some((id, checksum)) = acquisition.needs_value_or_chunk() {
+            let item = match effect_builder
+                .fetch::<BlockExecutionResultsOrChunk>(id, peer, Box::new(checksum))
+                .await
+            {
+                Ok(FetchedData::FromPeer { item, .. }) | Ok(FetchedData::FromStorage { item }) => {
+                    *item
+                }
+                Err(error) => {
+                    debug!(%block_hash, %peer, %error, "failed to fetch execution results");
+                    continue 'peers;
+                }
+            };
+            acquisition = match acquisition
+                .apply_block_execution_results_or_chunk(item, deploy_hashes.clone())
+            {
+                Ok((acquisition, _acceptance)) => acquisition,
+                Err(error) => {
+                    debug!(%block_hash, %peer, %error, "failed to apply execution results");
+                    continue 'peers;
+                }
+            };
+        }
+        if let ExecutionResultsAcquisition::Complete { results, .. } = acquisition {
+            effect_builder
+                .put_execution_results_to_storage(block_hash, results)
+                .await;
+            return Ok(());
+        }
+    }
+
+    Err(ExecutionResultsBackfillError::NotAcquired(block_hash))
+}
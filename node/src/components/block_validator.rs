@@ -23,7 +23,7 @@ use casper_types::Timestamp;
 use crate::{
     components::{
         consensus::{ClContext, ProposedBlock},
-        fetcher::{self, EmptyValidationMetadata, FetchResult, FetchedData},
+        fetcher::{self, FetchResult, FetchedData},
         Component,
     },
     effect::{
@@ -32,7 +32,7 @@ use crate::{
     },
     types::{
         ApprovalsHash, Chainspec, Deploy, DeployHashWithApprovals, DeployId, DeployOrTransferHash,
-        NodeId,
+        DeployValidationMetadata, NodeId,
     },
     NodeRng,
 };
@@ -117,7 +117,12 @@ impl BlockValidator {
                 MaybeStartFetching::Start {
                     holder,
                     missing_deploys,
-                } => fetch_deploys(effect_builder, holder, missing_deploys),
+                } => fetch_deploys(
+                    effect_builder,
+                    self.chainspec.network_config.chain_id,
+                    holder,
+                    missing_deploys,
+                ),
                 MaybeStartFetching::Ongoing => {
                     debug!("ongoing fetches while validating proposed block - noop");
                     Effects::new()
@@ -159,7 +164,12 @@ impl BlockValidator {
             MaybeStartFetching::Start {
                 holder,
                 missing_deploys,
-            } => fetch_deploys(effect_builder, holder, missing_deploys),
+            } => fetch_deploys(
+                effect_builder,
+                self.chainspec.network_config.chain_id,
+                holder,
+                missing_deploys,
+            ),
             MaybeStartFetching::ValidationSucceeded => {
                 debug!("no deploys - block validation complete");
                 debug_assert!(maybe_responder.is_some());
@@ -244,6 +254,20 @@ impl BlockValidator {
                         .flat_map(|state| state.try_mark_invalid(&dt_hash));
                     return respond(false, responders);
                 }
+                if item.header().chain_name() != self.chainspec.network_config.name {
+                    warn!(
+                        deploy = %item,
+                        chain_name = %item.header().chain_name(),
+                        expected_chain_name = %self.chainspec.network_config.name,
+                        "deploy is for a different chain"
+                    );
+                    // Hard failure - change state to Invalid.
+                    let responders = self
+                        .validation_states
+                        .values_mut()
+                        .flat_map(|state| state.try_mark_invalid(&dt_hash));
+                    return respond(false, responders);
+                }
                 let deploy_footprint = match item.footprint() {
                     Ok(footprint) => footprint,
                     Err(error) => {
@@ -280,6 +304,7 @@ impl BlockValidator {
                         // Soft failure - just mark the holder as failed and see if we can start
                         // fetching using a different holder.
                         let mut effects = Effects::new();
+                        let chain_id = self.chainspec.network_config.chain_id;
                         self.validation_states.values_mut().for_each(|state| {
                             state.try_mark_holder_failed(&peer);
                             match state.start_fetching() {
@@ -294,6 +319,7 @@ impl BlockValidator {
                                     );
                                     effects.extend(fetch_deploys(
                                         effect_builder,
+                                        chain_id,
                                         holder,
                                         missing_deploys,
                                     ))
@@ -365,6 +391,7 @@ where
 
 fn fetch_deploys<REv>(
     effect_builder: EffectBuilder<REv>,
+    chain_id: u32,
     holder: NodeId,
     missing_deploys: HashMap<DeployOrTransferHash, ApprovalsHash>,
 ) -> Effects<Event>
@@ -376,7 +403,11 @@ where
         .flat_map(|(dt_hash, approvals_hash)| {
             let deploy_id = DeployId::new(dt_hash.into(), approvals_hash);
             effect_builder
-                .fetch::<Deploy>(deploy_id, holder, Box::new(EmptyValidationMetadata))
+                .fetch::<Deploy>(
+                    deploy_id,
+                    holder,
+                    Box::new(DeployValidationMetadata { chain_id }),
+                )
                 .event(move |result| Event::DeployFetched { dt_hash, result })
         })
         .collect()
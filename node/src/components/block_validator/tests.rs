@@ -113,9 +113,21 @@ pub(super) fn new_proposed_block(
     ProposedBlock::new(Arc::new(block_payload), block_context)
 }
 
+/// The name of the network in the local chainspec, which the tests validate blocks against.
+const CHAIN_NAME: &str = "casper-example";
+
 pub(super) fn new_deploy(rng: &mut TestRng, timestamp: Timestamp, ttl: TimeDiff) -> Deploy {
+    new_deploy_for_chain(rng, timestamp, ttl, CHAIN_NAME)
+}
+
+fn new_deploy_for_chain(
+    rng: &mut TestRng,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    chain_name: &str,
+) -> Deploy {
     let secret_key = SecretKey::random(rng);
-    let chain_name = "chain".to_string();
+    let chain_name = chain_name.to_string();
     let payment = ExecutableDeployItem::ModuleBytes {
         module_bytes: Bytes::new(),
         args: runtime_args! { ARG_AMOUNT => U512::from(1) },
@@ -142,7 +154,7 @@ pub(super) fn new_deploy(rng: &mut TestRng, timestamp: Timestamp, ttl: TimeDiff)
 
 pub(super) fn new_transfer(rng: &mut TestRng, timestamp: Timestamp, ttl: TimeDiff) -> Deploy {
     let secret_key = SecretKey::random(rng);
-    let chain_name = "chain".to_string();
+    let chain_name = CHAIN_NAME.to_string();
     let payment = ExecutableDeployItem::ModuleBytes {
         module_bytes: Bytes::new(),
         args: runtime_args! { ARG_AMOUNT => U512::from(1) },
@@ -316,6 +328,19 @@ async fn transfer_deploy_mixup_and_replay() {
     assert!(!validate_block(&mut rng, timestamp, deploys, transfers).await);
 }
 
+/// Verifies that a block is invalid if it contains a deploy for a different chain.
+#[tokio::test]
+async fn deploy_for_different_chain() {
+    let mut rng = TestRng::new();
+    let ttl = TimeDiff::from_millis(200);
+    let timestamp = Timestamp::from(1000);
+    let deploy = new_deploy(&mut rng, timestamp, ttl);
+    let foreign_deploy = new_deploy_for_chain(&mut rng, timestamp, ttl, "casper-example-2");
+
+    assert!(validate_block(&mut rng, timestamp, vec![deploy.clone()], vec![]).await);
+    assert!(!validate_block(&mut rng, timestamp, vec![deploy, foreign_deploy], vec![]).await);
+}
+
 /// Verifies that the block validator fetches from multiple peers.
 #[tokio::test]
 async fn should_fetch_from_multiple_peers() {
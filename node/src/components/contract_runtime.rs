@@ -1,9 +1,14 @@
 //! Contract Runtime component.
 
+mod artifact_stream;
 mod config;
 mod error;
 mod metrics;
 mod operations;
+pub mod replay;
+#[cfg(any(feature = "testing", test))]
+pub mod simulation;
+mod state_snapshot;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -21,6 +26,7 @@ use std::{
 use datasize::DataSize;
 use derive_more::From;
 use lmdb::DatabaseFlags;
+use num::rational::Ratio;
 use once_cell::sync::Lazy;
 use prometheus::Registry;
 use serde::Serialize;
@@ -30,19 +36,26 @@ use tracing::{debug, error, info, trace};
 use casper_execution_engine::{
     core::engine_state::{
         self,
-        engine_config::{FeeHandling, RefundHandling},
+        engine_config::{
+            CompatibilityModeConfig, ExistentialDepositHandling, FeeDistribution, FeeHandling,
+            RefundHandling, StorageRefund,
+        },
         genesis::GenesisError,
-        ChainspecRegistry, DeployItem, EngineConfigBuilder, EngineState, GenesisSuccess,
-        SystemContractRegistry, UpgradeConfig, UpgradeSuccess,
+        ChainspecRegistry, DeployItem, EngineConfigBuilder, EngineState, ExecutionWarning,
+        GenesisSuccess, SystemContractRegistry, UpgradeConfig, UpgradeSuccess,
     },
     shared::{newtypes::CorrelationId, system_config::SystemConfig, wasm_config::WasmConfig},
     storage::{
-        global_state::lmdb::LmdbGlobalState, transaction_source::lmdb::LmdbEnvironment,
+        global_state::{lmdb::LmdbGlobalState, read_recording::ReadSet},
+        transaction_source::lmdb::LmdbEnvironment,
         trie_store::lmdb::LmdbTrieStore,
     },
 };
 use casper_hashing::Digest;
-use casper_types::{bytesrepr::Bytes, EraId, ProtocolVersion, PublicKey, Timestamp};
+use casper_types::{
+    bytesrepr::Bytes, system::auction::DelegationRate, EraId, ExecutionResult, ProtocolVersion,
+    PublicKey, Timestamp,
+};
 
 use crate::{
     components::{fetcher::FetchResponse, Component, ComponentState},
@@ -58,16 +71,21 @@ use crate::{
     fatal,
     protocol::Message,
     types::{
-        ActivationPoint, BlockHash, BlockHeader, Chainspec, ChainspecRawBytes, ChunkingError,
-        Deploy, FinalizedBlock, MetaBlock, MetaBlockState, TrieOrChunk, TrieOrChunkId,
+        chainspec::DeprecatedDeployItems, ActivationPoint, BlockHash, BlockHeader, Chainspec,
+        ChainspecRawBytes, ChunkingError, Deploy, FinalizedBlock, MetaBlock, MetaBlockState,
+        TrieOrChunk, TrieOrChunkId,
     },
+    utils::WithDir,
     NodeRng,
 };
+use artifact_stream::ArtifactPublisher;
+pub(crate) use artifact_stream::Config as ArtifactStreamConfig;
 pub(crate) use config::Config;
 pub(crate) use error::{BlockExecutionError, ConfigError};
-use metrics::Metrics;
+use metrics::{Metrics, TrieMetrics};
 pub use operations::execute_finalized_block;
 use operations::execute_only;
+pub(crate) use state_snapshot::{export_state_snapshot, StateSnapshotError, StateSnapshotHeader};
 pub(crate) use types::{
     BlockAndExecutionResults, EraValidatorsRequest, StepEffectAndUpcomingEraValidators,
 };
@@ -95,6 +113,7 @@ const MAX_PARALLEL_INTENSIVE_TASKS: usize = 4;
 
 pub(crate) const APPROVALS_CHECKSUM_NAME: &str = "approvals_checksum";
 pub(crate) const EXECUTION_RESULTS_CHECKSUM_NAME: &str = "execution_results_checksum";
+pub(crate) const EVENTS_CHECKSUM_NAME: &str = "events_checksum";
 
 /// Semaphore enforcing maximum number of parallel resource intensive tasks.
 static INTENSIVE_TASKS_SEMAPHORE: Lazy<tokio::sync::Semaphore> =
@@ -128,6 +147,19 @@ pub struct SpeculativeExecutionState {
     pub protocol_version: ProtocolVersion,
 }
 
+/// The result of speculatively executing a deploy.
+#[derive(Debug)]
+pub struct SpeculativeExecutionResult {
+    /// Result of the execution.
+    pub execution_result: ExecutionResult,
+    /// The numeric code of the error the execution failed with, if it failed.
+    pub error_code: Option<u32>,
+    /// The warnings raised while executing the deploy.
+    pub warnings: Vec<ExecutionWarning>,
+    /// The reads the deploy made from global state, if they were recorded.
+    pub reads: Option<ReadSet>,
+}
+
 /// State to use to construct the next block in the blockchain. Includes the state root hash for the
 /// execution engine as well as certain values the next header will be based on.
 #[derive(DataSize, Debug, Clone, Serialize)]
@@ -212,6 +244,19 @@ pub(crate) struct ContractRuntime {
     system_contract_registry: Option<SystemContractRegistry>,
     activation_point: ActivationPoint,
     prune_batch_size: u64,
+    deprecated_deploy_items: DeprecatedDeployItems,
+    chain_id: u32,
+    chain_bound_approvals: Option<EraId>,
+    block_gas_limit: u64,
+    block_max_gas_per_account: u64,
+    /// Whether to execute each block a second time committing directly to LMDB, to check it
+    /// leads to the same state root as executing through the scratch global state.
+    cross_check_execution: bool,
+    /// Whether to check the global state invariants after executing each block.
+    check_invariants: bool,
+    /// Publisher of executed blocks' artifacts, if the artifact stream is enabled.
+    #[data_size(skip)]
+    artifact_publisher: Option<ArtifactPublisher>,
 }
 
 impl Debug for ContractRuntime {
@@ -366,11 +411,7 @@ impl ContractRuntime {
                 async move {
                     let correlation_id = CorrelationId::new();
                     let start = Instant::now();
-                    let result = engine_state.get_purse_balance(
-                        correlation_id,
-                        balance_request.state_hash(),
-                        balance_request.purse_uref(),
-                    );
+                    let result = engine_state.balance(correlation_id, *balance_request);
                     metrics.get_balance.observe(start.elapsed().as_secs_f64());
                     trace!(?result, "balance result");
                     responder.respond(result).await
@@ -494,6 +535,14 @@ impl ContractRuntime {
                         let shared_pre_state = Arc::clone(&self.execution_pre_state);
                         let activation_point = self.activation_point;
                         let prune_batch_size = self.prune_batch_size;
+                        let deprecated_deploy_items = self.deprecated_deploy_items;
+                        let chain_id = self.chain_id;
+                        let chain_bound_approvals = self.chain_bound_approvals;
+                        let block_gas_limit = self.block_gas_limit;
+                        let block_max_gas_per_account = self.block_max_gas_per_account;
+                        let cross_check_execution = self.cross_check_execution;
+                        let check_invariants = self.check_invariants;
+                        let artifact_publisher = self.artifact_publisher.clone();
                         effects.extend(
                             Self::execute_finalized_block_or_requeue(
                                 engine_state,
@@ -508,7 +557,15 @@ impl ContractRuntime {
                                 activation_point,
                                 key_block_height_for_activation_point,
                                 prune_batch_size,
+                                deprecated_deploy_items,
+                                chain_id,
+                                chain_bound_approvals,
+                                block_gas_limit,
+                                block_max_gas_per_account,
+                                cross_check_execution,
+                                check_invariants,
                                 meta_block_state,
+                                artifact_publisher,
                             )
                             .ignore(),
                         )
@@ -569,6 +626,7 @@ impl ContractRuntime {
             ContractRuntimeRequest::SpeculativeDeployExecution {
                 execution_prestate,
                 deploy,
+                record_reads,
                 responder,
             } => {
                 let engine_state = Arc::clone(&self.engine_state);
@@ -578,6 +636,7 @@ impl ContractRuntime {
                             engine_state.as_ref(),
                             execution_prestate,
                             DeployItem::from((*deploy).clone()),
+                            record_reads,
                         )
                     })
                     .await;
@@ -585,6 +644,30 @@ impl ContractRuntime {
                 }
                 .ignore()
             }
+            ContractRuntimeRequest::GetModuleCacheStats { responder } => {
+                let stats = self.engine_state.module_cache_stats();
+                responder.respond(stats).ignore()
+            }
+            ContractRuntimeRequest::FlushModuleCache { responder } => {
+                let flushed = self.engine_state.flush_module_cache();
+                info!(flushed, "flushed module cache");
+                responder.respond(flushed).ignore()
+            }
+            ContractRuntimeRequest::WriteStateSnapshot {
+                header,
+                path,
+                responder,
+            } => {
+                let engine_state = Arc::clone(&self.engine_state);
+                async move {
+                    let result = run_intensive_task(move || {
+                        state_snapshot::write_state_snapshot(&engine_state, &header, &path)
+                    })
+                    .await;
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
         }
     }
 }
@@ -599,9 +682,16 @@ impl ContractRuntime {
         system_config: SystemConfig,
         max_associated_keys: u32,
         max_runtime_call_stack_height: u32,
+        max_contract_call_stack_height: u32,
+        max_effects_size_bytes: u32,
         minimum_delegation_amount: u64,
         activation_point: ActivationPoint,
         prune_batch_size: u64,
+        deprecated_deploy_items: DeprecatedDeployItems,
+        chain_id: u32,
+        chain_bound_approvals: Option<EraId>,
+        block_gas_limit: u64,
+        block_max_gas_per_account: u64,
         strict_argument_checking: bool,
         vesting_schedule_period_millis: u64,
         max_delegators_per_validator: Option<u32>,
@@ -611,6 +701,16 @@ impl ContractRuntime {
         allow_unrestricted_transfers: bool,
         refund_handling: RefundHandling,
         fee_handling: FeeHandling,
+        fee_distribution: FeeDistribution,
+        existential_deposit: u64,
+        existential_deposit_handling: ExistentialDepositHandling,
+        storage_refund: StorageRefund,
+        delegation_rate_change_cooldown: u64,
+        max_delegation_rate_increase: DelegationRate,
+        disabled_auction_entry_points: BTreeSet<String>,
+        enable_purse_ownership_index: bool,
+        compress_contract_wasm: bool,
+        compatibility_modes: &[CompatibilityModeConfig],
     ) -> Result<Self, ConfigError> {
         // TODO: This is bogus, get rid of this
         let execution_pre_state = Arc::new(Mutex::new(ExecutionPreState {
@@ -627,17 +727,20 @@ impl ContractRuntime {
             contract_runtime_config.manual_sync_enabled_or_default(),
         )?);
 
-        let trie_store = Arc::new(LmdbTrieStore::new(
-            &environment,
-            None,
-            DatabaseFlags::empty(),
-        )?);
+        let trie_metrics = Arc::new(TrieMetrics::new(registry)?);
+        let trie_store = Arc::new(
+            LmdbTrieStore::new(&environment, None, DatabaseFlags::empty())?
+                .with_metrics_sink(trie_metrics)
+                .with_contract_wasm_compression(compress_contract_wasm),
+        );
 
         let global_state = LmdbGlobalState::empty(environment, trie_store)?;
-        let engine_config = EngineConfigBuilder::new()
+        let mut engine_config_builder = EngineConfigBuilder::new()
             .with_max_query_depth(contract_runtime_config.max_query_depth_or_default())
             .with_max_associated_keys(max_associated_keys)
             .with_max_runtime_call_stack_height(max_runtime_call_stack_height)
+            .with_max_contract_call_stack_height(max_contract_call_stack_height)
+            .with_max_effects_size_bytes(max_effects_size_bytes)
             .with_minimum_delegation_amount(minimum_delegation_amount)
             .with_strict_argument_checking(strict_argument_checking)
             .with_vesting_schedule_period_millis(vesting_schedule_period_millis)
@@ -649,7 +752,22 @@ impl ContractRuntime {
             .with_allow_unrestricted_transfers(allow_unrestricted_transfers)
             .with_refund_handling(refund_handling)
             .with_fee_handling(fee_handling)
-            .build();
+            .with_fee_distribution(fee_distribution)
+            .with_existential_deposit(existential_deposit)
+            .with_existential_deposit_handling(existential_deposit_handling)
+            .with_storage_refund(storage_refund)
+            .with_delegation_rate_change_cooldown(delegation_rate_change_cooldown)
+            .with_max_delegation_rate_increase(max_delegation_rate_increase)
+            .with_disabled_auction_entry_points(disabled_auction_entry_points)
+            .with_enable_purse_ownership_index(enable_purse_ownership_index)
+            .with_module_cache_size(contract_runtime_config.module_cache_size_or_default());
+        for compatibility_mode in compatibility_modes {
+            engine_config_builder = engine_config_builder.with_compatibility_mode(
+                compatibility_mode.protocol_version,
+                compatibility_mode.compatibility_mode(),
+            );
+        }
+        let engine_config = engine_config_builder.build();
 
         let engine_state = Arc::new(EngineState::new(global_state, engine_config));
 
@@ -665,9 +783,43 @@ impl ContractRuntime {
             system_contract_registry: None,
             activation_point,
             prune_batch_size,
+            deprecated_deploy_items,
+            chain_id,
+            chain_bound_approvals,
+            block_gas_limit,
+            block_max_gas_per_account,
+            cross_check_execution: contract_runtime_config.cross_check_execution_or_default(),
+            check_invariants: contract_runtime_config.check_invariants_or_default(),
+            artifact_publisher: None,
         })
     }
 
+    /// Imports the global state of the state snapshot at `path`, after checking it is a snapshot of
+    /// the block of `trusted_hash` whose finality is proven by its signatures.
+    pub(crate) fn import_state_snapshot(
+        &self,
+        path: &Path,
+        trusted_hash: BlockHash,
+        finality_threshold_fraction: Ratio<u64>,
+    ) -> Result<StateSnapshotHeader, StateSnapshotError> {
+        state_snapshot::read_state_snapshot(
+            &self.engine_state,
+            path,
+            trusted_hash,
+            finality_threshold_fraction,
+        )
+    }
+
+    /// Starts streaming the artifacts of executed blocks, if enabled in `config`.
+    pub(crate) fn start_artifact_stream(
+        &mut self,
+        config: &WithDir<ArtifactStreamConfig>,
+    ) -> Result<(), ConfigError> {
+        self.artifact_publisher =
+            ArtifactPublisher::start(config).map_err(ConfigError::ArtifactStream)?;
+        Ok(())
+    }
+
     /// Commits a genesis request.
     pub(crate) fn commit_genesis(
         &self,
@@ -756,7 +908,15 @@ impl ContractRuntime {
         activation_point: ActivationPoint,
         key_block_height_for_activation_point: u64,
         prune_batch_size: u64,
+        deprecated_deploy_items: DeprecatedDeployItems,
+        chain_id: u32,
+        chain_bound_approvals: Option<EraId>,
+        block_gas_limit: u64,
+        block_max_gas_per_account: u64,
+        cross_check_execution: bool,
+        check_invariants: bool,
         mut meta_block_state: MetaBlockState,
+        artifact_publisher: Option<ArtifactPublisher>,
     ) where
         REv: From<ContractRuntimeRequest>
             + From<ContractRuntimeAnnouncement>
@@ -771,6 +931,9 @@ impl ContractRuntime {
             block,
             approvals_hashes,
             execution_results,
+            execution_stats,
+            execution_warnings,
+            purse_changes,
             maybe_step_effect_and_upcoming_era_validators,
         } = match run_intensive_task(move || {
             debug!("ContractRuntime: execute_finalized_block");
@@ -784,6 +947,13 @@ impl ContractRuntime {
                 activation_point.era_id(),
                 key_block_height_for_activation_point,
                 prune_batch_size,
+                deprecated_deploy_items,
+                chain_id,
+                chain_bound_approvals,
+                block_gas_limit,
+                block_max_gas_per_account,
+                cross_check_execution,
+                check_invariants,
             )
         })
         .await
@@ -795,6 +965,15 @@ impl ContractRuntime {
             }
         };
 
+        if !purse_changes.is_empty() {
+            debug!(
+                block_hash = %block.hash(),
+                created = purse_changes.created().len(),
+                removed = purse_changes.removed().len(),
+                "ContractRuntime: block changed purses"
+            );
+        }
+
         let new_execution_pre_state = ExecutionPreState::from_block_header(block.header());
         {
             // The `shared_pre_state` could have been set to a block we just fully synced after
@@ -821,11 +1000,21 @@ impl ContractRuntime {
 
         if let Some(StepEffectAndUpcomingEraValidators {
             step_execution_journal,
+            step_report,
             mut upcoming_era_validators,
         }) = maybe_step_effect_and_upcoming_era_validators
         {
+            info!(
+                era_id = %current_era_id,
+                slashed = ?step_report.slashed,
+                evicted = ?step_report.evicted,
+                new_snapshot_era = ?step_report.new_snapshot_era,
+                weight_changes = step_report.validator_weight_changes.len(),
+                scheduled_callbacks = step_report.scheduled_callbacks.len(),
+                "ContractRuntime: committed step"
+            );
             effect_builder
-                .announce_commit_step_success(current_era_id, step_execution_journal)
+                .announce_commit_step_success(current_era_id, step_execution_journal, step_report)
                 .await;
 
             if current_era_id.is_genesis() {
@@ -889,7 +1078,13 @@ impl ContractRuntime {
             );
         }
 
-        let meta_block = MetaBlock::new(block, execution_results, meta_block_state);
+        if let Some(artifact_publisher) = artifact_publisher {
+            artifact_publisher.publish(&block, &execution_results, &purse_changes);
+        }
+
+        let meta_block = MetaBlock::new(block, execution_results, meta_block_state)
+            .with_execution_stats(execution_stats)
+            .with_execution_warnings(execution_warnings);
         effect_builder.announce_meta_block(meta_block).await;
 
         // If the child is already finalized, start execution.
@@ -980,7 +1175,11 @@ impl ContractRuntime {
 #[cfg(test)]
 mod trie_chunking_tests {
     use casper_execution_engine::{
-        core::engine_state::engine_config::{DEFAULT_FEE_HANDLING, DEFAULT_REFUND_HANDLING},
+        core::engine_state::engine_config::{
+            DEFAULT_DELEGATION_RATE_CHANGE_COOLDOWN, DEFAULT_ENABLE_PURSE_OWNERSHIP_INDEX,
+            DEFAULT_EXISTENTIAL_DEPOSIT, DEFAULT_EXISTENTIAL_DEPOSIT_HANDLING,
+            DEFAULT_FEE_HANDLING, DEFAULT_MAX_DELEGATION_RATE_INCREASE, DEFAULT_REFUND_HANDLING,
+        },
         shared::{
             additive_map::AdditiveMap, newtypes::CorrelationId, system_config::SystemConfig,
             transform::Transform, wasm_config::WasmConfig,
@@ -1060,8 +1259,15 @@ mod trie_chunking_tests {
             10,
             10,
             10,
+            10,
+            10,
             ActivationPoint::EraId(EraId::from(2)),
             5,
+            Default::default(),
+            0,
+            None,
+            10,
+            10,
             true,
             1,
             None,
@@ -1071,6 +1277,16 @@ mod trie_chunking_tests {
             true,
             DEFAULT_REFUND_HANDLING,
             DEFAULT_FEE_HANDLING,
+            Default::default(),
+            DEFAULT_EXISTENTIAL_DEPOSIT,
+            DEFAULT_EXISTENTIAL_DEPOSIT_HANDLING,
+            Default::default(),
+            DEFAULT_DELEGATION_RATE_CHANGE_COOLDOWN,
+            DEFAULT_MAX_DELEGATION_RATE_INCREASE,
+            Default::default(),
+            DEFAULT_ENABLE_PURSE_OWNERSHIP_INDEX,
+            false,
+            &[],
         )
         .unwrap();
         let empty_state_root = contract_runtime
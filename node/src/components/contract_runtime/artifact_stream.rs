@@ -0,0 +1,379 @@
+//! Streaming of executed blocks' artifacts over a unix socket.
+//!
+//! When enabled, the contract runtime publishes the artifacts of every block it executes to
+//! clients of a unix socket, as a lighter-weight alternative to the JSON event stream for
+//! high-volume indexers.
+//!
+//! A client connects and sends the height of the first block it wants as a little-endian `u64`.
+//! The server then sends one frame per block, in order of height.  A frame is a little-endian
+//! `u32` length followed by that many bytes: the block height as a little-endian `u64`, the
+//! `bytesrepr`-serialized [`Block`], the `bytesrepr`-serialized execution results of its deploys
+//! as a `Vec<(DeployHash, ExecutionResult)>`, and the `bytesrepr`-serialized [`PurseChanges`] of
+//! its deploys.
+//!
+//! The most recent `buffer_length` artifacts are retained in memory.  A client asking for a height
+//! older than the oldest retained artifact is sent the retained artifacts, and can tell from the
+//! heights which ones it missed.  A client falling more than `buffer_length` artifacts behind the
+//! contract runtime is disconnected, and can reconnect to resume from the height it last received.
+//! Execution never waits for clients.
+
+use std::{
+    collections::VecDeque,
+    io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{UnixListener, UnixStream},
+    sync::{
+        broadcast::{self, error::RecvError},
+        watch,
+    },
+};
+use tracing::{debug, error, info, warn};
+
+use casper_execution_engine::core::engine_state::PurseChanges;
+use casper_types::{bytesrepr::ToBytes, ExecutionResult};
+
+use crate::{
+    components::diagnostics_port,
+    types::{Block, DeployHash, DeployHeader},
+    WithDir,
+};
+
+const DEFAULT_SOCKET_PATH: &str = "artifacts.socket";
+const DEFAULT_SOCKET_UMASK: u16 = 0o077;
+const DEFAULT_BUFFER_LENGTH: u32 = 1000;
+
+/// Configuration of the stream of executed blocks' artifacts.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize, PartialEq, Eq)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Whether the artifacts are streamed.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Path of the unix socket the stream is served on.
+    #[serde(default = "default_socket_path")]
+    pub socket_path: PathBuf,
+    /// `umask` to apply before creating the socket.
+    #[serde(default = "default_socket_umask")]
+    pub socket_umask: u16,
+    /// Number of the most recent artifacts retained for clients resuming from an earlier height,
+    /// and by which a client may lag behind before being disconnected.
+    #[serde(default = "default_buffer_length")]
+    pub buffer_length: u32,
+}
+
+fn default_socket_path() -> PathBuf {
+    DEFAULT_SOCKET_PATH.into()
+}
+
+fn default_socket_umask() -> u16 {
+    DEFAULT_SOCKET_UMASK
+}
+
+fn default_buffer_length() -> u32 {
+    DEFAULT_BUFFER_LENGTH
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            enabled: false,
+            socket_path: default_socket_path(),
+            socket_umask: default_socket_umask(),
+            buffer_length: default_buffer_length(),
+        }
+    }
+}
+
+/// The framed artifacts of an executed block.
+#[derive(Debug)]
+struct ArtifactFrame {
+    block_height: u64,
+    bytes: Vec<u8>,
+}
+
+impl ArtifactFrame {
+    fn new(
+        block: &Block,
+        execution_results: &[(DeployHash, DeployHeader, ExecutionResult)],
+        purse_changes: &PurseChanges,
+    ) -> Result<Self, casper_types::bytesrepr::Error> {
+        let block_height = block.height();
+        let execution_results: Vec<(DeployHash, ExecutionResult)> = execution_results
+            .iter()
+            .map(|(deploy_hash, _, execution_result)| (*deploy_hash, execution_result.clone()))
+            .collect();
+
+        let mut payload = block_height.to_le_bytes().to_vec();
+        block.write_bytes(&mut payload)?;
+        execution_results.write_bytes(&mut payload)?;
+        purse_changes.write_bytes(&mut payload)?;
+
+        let length = u32::try_from(payload.len())
+            .map_err(|_| casper_types::bytesrepr::Error::OutOfMemory)?;
+        let mut bytes = Vec::with_capacity(4 + payload.len());
+        bytes.extend_from_slice(&length.to_le_bytes());
+        bytes.extend(payload);
+        Ok(ArtifactFrame {
+            block_height,
+            bytes,
+        })
+    }
+}
+
+/// Publishes the artifacts of executed blocks to the clients of the artifact stream.
+///
+/// Cloning the publisher is cheap; the socket is closed once all clones are dropped.
+#[derive(Clone, Debug)]
+pub(crate) struct ArtifactPublisher {
+    sender: broadcast::Sender<Arc<ArtifactFrame>>,
+    buffer: Arc<Mutex<VecDeque<Arc<ArtifactFrame>>>>,
+    buffer_length: usize,
+    /// Sender which will cause the server and client connections to exit when dropped.
+    _shutdown_sender: Arc<watch::Sender<()>>,
+}
+
+impl ArtifactPublisher {
+    /// Starts serving the artifact stream on the configured socket, or returns `None` if the
+    /// stream is disabled.
+    pub(crate) fn start(config: &WithDir<Config>) -> Result<Option<Self>, io::Error> {
+        let cfg = config.value();
+        if !cfg.enabled {
+            return Ok(None);
+        }
+
+        let socket_path = config.with_dir(cfg.socket_path.clone());
+        let listener = diagnostics_port::setup_listener(
+            &socket_path,
+            // Mac OS X / Linux use different types for the mask, so we need to call .into() here.
+            #[allow(clippy::useless_conversion)]
+            cfg.socket_umask.into(),
+        )?;
+
+        let buffer_length = (cfg.buffer_length as usize).max(1);
+        let (sender, _) = broadcast::channel(buffer_length);
+        let (shutdown_sender, shutdown_receiver) = watch::channel(());
+        let publisher = ArtifactPublisher {
+            sender,
+            buffer: Arc::new(Mutex::new(VecDeque::with_capacity(buffer_length))),
+            buffer_length,
+            _shutdown_sender: Arc::new(shutdown_sender),
+        };
+
+        info!(socket_path = %socket_path.display(), "serving artifact stream");
+        tokio::spawn(server(publisher.clone(), listener, shutdown_receiver));
+        Ok(Some(publisher))
+    }
+
+    /// Publishes the artifacts of an executed block.
+    pub(crate) fn publish(
+        &self,
+        block: &Block,
+        execution_results: &[(DeployHash, DeployHeader, ExecutionResult)],
+        purse_changes: &PurseChanges,
+    ) {
+        let frame = match ArtifactFrame::new(block, execution_results, purse_changes) {
+            Ok(frame) => Arc::new(frame),
+            Err(error) => {
+                error!(block_hash = %block.hash(), %error, "failed to frame block artifacts");
+                return;
+            }
+        };
+
+        // The frame is buffered before being sent, so a client subscribing in between sees it in
+        // both places and skips the duplicate.
+        {
+            let mut buffer = self
+                .buffer
+                .lock()
+                .expect("artifact stream buffer mutex poisoned");
+            if buffer.len() == self.buffer_length {
+                buffer.pop_front();
+            }
+            buffer.push_back(Arc::clone(&frame));
+        }
+        // An error only means that there are no connected clients.
+        let _ = self.sender.send(frame);
+    }
+
+    /// Subscribes to newly published frames, returning the retained frames starting at
+    /// `from_height`.
+    fn subscribe(
+        &self,
+        from_height: u64,
+    ) -> (
+        Vec<Arc<ArtifactFrame>>,
+        broadcast::Receiver<Arc<ArtifactFrame>>,
+    ) {
+        let buffer = self
+            .buffer
+            .lock()
+            .expect("artifact stream buffer mutex poisoned");
+        // Subscribing while holding the lock ensures no frame is published in between.
+        let receiver = self.sender.subscribe();
+        let retained = buffer
+            .iter()
+            .filter(|frame| frame.block_height >= from_height)
+            .cloned()
+            .collect();
+        (retained, receiver)
+    }
+}
+
+/// Accepts clients of the artifact stream until shut down.
+async fn server(
+    publisher: ArtifactPublisher,
+    listener: UnixListener,
+    mut shutdown_receiver: watch::Receiver<()>,
+) {
+    loop {
+        tokio::select! {
+            _ = shutdown_receiver.changed() => {
+                info!("shutting down artifact stream");
+                return;
+            }
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    debug!("accepted artifact stream client");
+                    let publisher = publisher.clone();
+                    let shutdown_receiver = shutdown_receiver.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) = handler(publisher, stream, shutdown_receiver).await {
+                            debug!(%error, "artifact stream client disconnected");
+                        }
+                    });
+                }
+                Err(error) => {
+                    warn!(%error, "failed to accept artifact stream client");
+                }
+            }
+        }
+    }
+}
+
+/// Streams the artifacts starting at the height requested by the client.
+async fn handler(
+    publisher: ArtifactPublisher,
+    mut stream: UnixStream,
+    mut shutdown_receiver: watch::Receiver<()>,
+) -> io::Result<()> {
+    let from_height = stream.read_u64_le().await?;
+    let (retained, mut receiver) = publisher.subscribe(from_height);
+
+    let mut next_height = from_height;
+    for frame in retained {
+        stream.write_all(&frame.bytes).await?;
+        next_height = frame.block_height + 1;
+    }
+
+    loop {
+        let frame = tokio::select! {
+            _ = shutdown_receiver.changed() => return Ok(()),
+            received = receiver.recv() => match received {
+                Ok(frame) => frame,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        next_height, "disconnecting artifact stream client which fell behind"
+                    );
+                    return Ok(());
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            },
+        };
+        if frame.block_height < next_height {
+            continue;
+        }
+        stream.write_all(&frame.bytes).await?;
+        next_height = frame.block_height + 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::testing::TestRng;
+
+    use super::*;
+    use crate::types::TestBlockBuilder;
+
+    fn publisher(buffer_length: usize) -> ArtifactPublisher {
+        let (sender, _) = broadcast::channel(buffer_length);
+        let (shutdown_sender, _) = watch::channel(());
+        ArtifactPublisher {
+            sender,
+            buffer: Arc::new(Mutex::new(VecDeque::new())),
+            buffer_length,
+            _shutdown_sender: Arc::new(shutdown_sender),
+        }
+    }
+
+    fn block_at(rng: &mut TestRng, height: u64) -> Block {
+        TestBlockBuilder::new().height(height).build(rng)
+    }
+
+    async fn read_frame_height(stream: &mut UnixStream) -> u64 {
+        let length = stream.read_u32_le().await.unwrap();
+        let mut payload = vec![0; length as usize];
+        stream.read_exact(&mut payload).await.unwrap();
+        u64::from_le_bytes(payload[..8].try_into().unwrap())
+    }
+
+    #[test]
+    fn should_frame_block_artifacts() {
+        let mut rng = TestRng::new();
+        let block = block_at(&mut rng, 7);
+        let purse_changes = PurseChanges::default();
+        let frame = ArtifactFrame::new(&block, &[], &purse_changes).unwrap();
+
+        let length = u32::from_le_bytes(frame.bytes[..4].try_into().unwrap()) as usize;
+        assert_eq!(length, frame.bytes.len() - 4);
+        assert_eq!(frame.bytes[4..12], 7u64.to_le_bytes());
+        let block_bytes = block.to_bytes().unwrap();
+        assert_eq!(frame.bytes[12..12 + block_bytes.len()], block_bytes[..]);
+        let purse_changes_bytes = purse_changes.to_bytes().unwrap();
+        assert!(frame.bytes.ends_with(&purse_changes_bytes));
+    }
+
+    #[test]
+    fn should_retain_most_recent_artifacts() {
+        let mut rng = TestRng::new();
+        let publisher = publisher(2);
+        for height in 0..3 {
+            publisher.publish(&block_at(&mut rng, height), &[], &PurseChanges::default());
+        }
+
+        let (retained, _) = publisher.subscribe(0);
+        let heights: Vec<u64> = retained.iter().map(|frame| frame.block_height).collect();
+        assert_eq!(heights, vec![1, 2]);
+
+        let (retained, _) = publisher.subscribe(2);
+        assert_eq!(retained.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_resume_stream_from_requested_height() {
+        let mut rng = TestRng::new();
+        let publisher = publisher(10);
+        for height in 0..3 {
+            publisher.publish(&block_at(&mut rng, height), &[], &PurseChanges::default());
+        }
+
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let (_shutdown_sender, shutdown_receiver) = watch::channel(());
+        tokio::spawn(handler(publisher.clone(), server, shutdown_receiver));
+        client.write_u64_le(1).await.unwrap();
+
+        assert_eq!(read_frame_height(&mut client).await, 1);
+        assert_eq!(read_frame_height(&mut client).await, 2);
+
+        publisher.publish(&block_at(&mut rng, 3), &[], &PurseChanges::default());
+        assert_eq!(read_frame_height(&mut client).await, 3);
+    }
+}
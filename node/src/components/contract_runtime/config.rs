@@ -1,15 +1,19 @@
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::shared::utils;
+use casper_execution_engine::shared::{module_cache::DEFAULT_MODULE_CACHE_SIZE, utils};
+
+use super::ArtifactStreamConfig;
 
 const DEFAULT_MAX_GLOBAL_STATE_SIZE: usize = 805_306_368_000; // 750 GiB
 const DEFAULT_MAX_READERS: u32 = 512;
 const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
 const DEFAULT_MANUAL_SYNC_ENABLED: bool = true;
+const DEFAULT_CROSS_CHECK_EXECUTION: bool = false;
+const DEFAULT_CHECK_INVARIANTS: bool = false;
 
 /// Contract runtime configuration.
-#[derive(Clone, Copy, DataSize, Debug, Deserialize, Serialize)]
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -31,6 +35,27 @@ pub struct Config {
     ///
     /// Defaults to `true`.
     pub enable_manual_sync: Option<bool>,
+    /// The capacity in bytes of the cache of preprocessed session and payment Wasm.  `0` disables
+    /// the cache.
+    ///
+    /// Defaults to 33,554,432 == 32 MiB.
+    pub module_cache_size: Option<usize>,
+    /// Execute each block a second time, committing directly to the global state store rather
+    /// than through the scratch global state, and fail if the post-state roots differ.  Intended
+    /// for release validation only, as it roughly doubles the cost of executing a block.
+    ///
+    /// Defaults to `false`.
+    pub cross_check_execution: Option<bool>,
+    /// After executing each block, check that the resulting global state satisfies invariants
+    /// spanning the system contracts, e.g. that the total supply equals the sum of all balances,
+    /// and fail if it doesn't.  Intended for debugging on devnets only, as it reads every balance,
+    /// bid and account in global state.
+    ///
+    /// Defaults to `false`.
+    pub check_invariants: Option<bool>,
+    /// Streaming of executed blocks' artifacts over a unix socket.
+    #[serde(default)]
+    pub artifact_stream: ArtifactStreamConfig,
 }
 
 impl Config {
@@ -58,6 +83,22 @@ impl Config {
         self.enable_manual_sync
             .unwrap_or(DEFAULT_MANUAL_SYNC_ENABLED)
     }
+
+    /// Capacity of the module cache in bytes.
+    pub fn module_cache_size_or_default(&self) -> usize {
+        self.module_cache_size.unwrap_or(DEFAULT_MODULE_CACHE_SIZE)
+    }
+
+    /// Is cross-checking of block execution enabled.
+    pub fn cross_check_execution_or_default(&self) -> bool {
+        self.cross_check_execution
+            .unwrap_or(DEFAULT_CROSS_CHECK_EXECUTION)
+    }
+
+    /// Is checking of global state invariants after each block enabled.
+    pub fn check_invariants_or_default(&self) -> bool {
+        self.check_invariants.unwrap_or(DEFAULT_CHECK_INVARIANTS)
+    }
 }
 
 impl Default for Config {
@@ -67,6 +108,10 @@ impl Default for Config {
             max_readers: Some(DEFAULT_MAX_READERS),
             max_query_depth: Some(DEFAULT_MAX_QUERY_DEPTH),
             enable_manual_sync: Some(DEFAULT_MANUAL_SYNC_ENABLED),
+            module_cache_size: Some(DEFAULT_MODULE_CACHE_SIZE),
+            cross_check_execution: Some(DEFAULT_CROSS_CHECK_EXECUTION),
+            check_invariants: Some(DEFAULT_CHECK_INVARIANTS),
+            artifact_stream: ArtifactStreamConfig::default(),
         }
     }
 }
@@ -4,9 +4,11 @@ use serde::Serialize;
 use thiserror::Error;
 
 use casper_execution_engine::{
-    core::engine_state::{Error as EngineStateError, StepError},
+    core::engine_state::{Error as EngineStateError, InvariantViolation, StepError},
     storage::error::lmdb::Error as StorageLmdbError,
 };
+use casper_hashing::Digest;
+use casper_types::Key;
 
 use crate::{
     components::contract_runtime::ExecutionPreState,
@@ -16,13 +18,16 @@ use casper_execution_engine::core::engine_state::GetEraValidatorsError;
 
 /// An error returned from mis-configuring the contract runtime component.
 #[derive(Debug, Error)]
-pub(crate) enum ConfigError {
+pub enum ConfigError {
     /// Error initializing the LMDB environment.
     #[error("failed to initialize LMDB environment for contract runtime: {0}")]
     Lmdb(#[from] StorageLmdbError),
     /// Error initializing metrics.
     #[error("failed to initialize metrics for contract runtime: {0}")]
     Prometheus(#[from] prometheus::Error),
+    /// Error setting up the socket of the artifact stream.
+    #[error("failed to set up artifact stream socket: {0}")]
+    ArtifactStream(std::io::Error),
 }
 
 /// An error during block execution.
@@ -76,4 +81,34 @@ pub enum BlockExecutionError {
         #[serde(skip_serializing)]
         GetEraValidatorsError,
     ),
+    /// Executing the block through the scratch global state and committing directly to LMDB led
+    /// to different state roots.
+    #[error(
+        "cross-checked block execution diverged: scratch state root {scratch_state_root_hash}, \
+         direct state root {direct_state_root_hash}, first differing key {first_differing_key:?}"
+    )]
+    CrossCheckDivergence {
+        /// The state root hash from executing through the scratch global state.
+        scratch_state_root_hash: Digest,
+        /// The state root hash from committing directly to LMDB.
+        direct_state_root_hash: Digest,
+        /// The first key written by the block whose value differs between the two, if any.
+        first_differing_key: Option<Key>,
+    },
+    /// The global state produced by executing the block violates cross-contract invariants.
+    #[error(
+        "global state after block {block_height} with state root {state_root_hash} violates \
+         {} invariant(s), first: {}",
+        violations.len(),
+        violations.first().map(ToString::to_string).unwrap_or_default()
+    )]
+    InvariantViolations {
+        /// The height of the executed block.
+        block_height: u64,
+        /// The state root hash produced by executing the block.
+        state_root_hash: Digest,
+        /// The violated invariants.
+        #[serde(skip_serializing)]
+        violations: Vec<InvariantViolation>,
+    },
 }
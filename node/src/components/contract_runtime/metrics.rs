@@ -1,4 +1,8 @@
-use prometheus::{self, Gauge, Histogram, IntGauge, Registry};
+use std::time::Duration;
+
+use prometheus::{self, Gauge, Histogram, IntCounter, IntGauge, Registry};
+
+use casper_execution_engine::storage::trie_store::metrics::TrieMetricsSink;
 
 use crate::{unregister_metric, utils};
 
@@ -48,6 +52,10 @@ const GET_TRIE_HELP: &str = "time in seconds to get a trie";
 const EXEC_BLOCK_NAME: &str = "contract_runtime_execute_block";
 const EXEC_BLOCK_HELP: &str = "time in seconds to execute all deploys in a block";
 
+const DEPLOY_MEMORY_HIGH_WATER_MARK_NAME: &str = "contract_runtime_deploy_memory_high_water_mark";
+const DEPLOY_MEMORY_HIGH_WATER_MARK_HELP: &str =
+    "size in bytes of the largest wasm memory used by an executed deploy";
+
 const LATEST_COMMIT_STEP_NAME: &str = "contract_runtime_latest_commit_step";
 const LATEST_COMMIT_STEP_HELP: &str = "duration in seconds of latest commit step at era end";
 
@@ -55,6 +63,23 @@ const EXEC_QUEUE_SIZE_NAME: &str = "execution_queue_size";
 const EXEC_QUEUE_SIZE_HELP: &str =
     "number of blocks that are currently enqueued and waiting for execution";
 
+const TRIE_NODE_READS_NAME: &str = "global_state_trie_node_reads";
+const TRIE_NODE_READS_HELP: &str = "number of trie nodes read from the global state database";
+
+const TRIE_NODE_WRITES_NAME: &str = "global_state_trie_node_writes";
+const TRIE_NODE_WRITES_HELP: &str = "number of trie nodes written to the global state database";
+
+const TRIE_CACHE_HITS_NAME: &str = "global_state_cache_hits";
+const TRIE_CACHE_HITS_HELP: &str =
+    "number of global state reads served from an in-memory cache instead of the database";
+
+const TRIE_PROOF_GENERATION_NAME: &str = "global_state_proof_generation";
+const TRIE_PROOF_GENERATION_HELP: &str =
+    "time in seconds to read a value from global state along with its merkle proof";
+
+const TRIE_PRUNE_NAME: &str = "global_state_prune";
+const TRIE_PRUNE_HELP: &str = "time in seconds to prune keys from global state";
+
 /// Metrics for the contract runtime component.
 #[derive(Debug)]
 pub struct Metrics {
@@ -69,6 +94,7 @@ pub struct Metrics {
     pub(super) put_trie: Histogram,
     pub(super) get_trie: Histogram,
     pub(super) exec_block: Histogram,
+    pub(super) deploy_memory_high_water_mark: Histogram,
     pub(super) latest_commit_step: Gauge,
     pub(super) exec_queue_size: IntGauge,
     registry: Registry,
@@ -89,6 +115,9 @@ impl Metrics {
         // Anything above that should be a warning signal.
         let tiny_buckets = prometheus::exponential_buckets(0.001, 2.0, 10)?;
 
+        // Start from a single 64 KiB Wasm page, doubling up to 32 MiB.
+        let memory_buckets = prometheus::exponential_buckets(65_536.0, 2.0, 10)?;
+
         let latest_commit_step = Gauge::new(LATEST_COMMIT_STEP_NAME, LATEST_COMMIT_STEP_HELP)?;
         registry.register(Box::new(latest_commit_step.clone()))?;
 
@@ -162,6 +191,12 @@ impl Metrics {
                 EXEC_BLOCK_HELP,
                 common_buckets,
             )?,
+            deploy_memory_high_water_mark: utils::register_histogram_metric(
+                registry,
+                DEPLOY_MEMORY_HIGH_WATER_MARK_NAME,
+                DEPLOY_MEMORY_HIGH_WATER_MARK_HELP,
+                memory_buckets,
+            )?,
             latest_commit_step,
             exec_queue_size,
             registry: registry.clone(),
@@ -182,7 +217,91 @@ impl Drop for Metrics {
         unregister_metric!(self.registry, self.put_trie);
         unregister_metric!(self.registry, self.get_trie);
         unregister_metric!(self.registry, self.exec_block);
+        unregister_metric!(self.registry, self.deploy_memory_high_water_mark);
         unregister_metric!(self.registry, self.latest_commit_step);
         unregister_metric!(self.registry, self.exec_queue_size);
     }
 }
+
+/// Metrics of the trie operations of global state, reported by the trie store.
+#[derive(Debug)]
+pub(super) struct TrieMetrics {
+    node_reads: IntCounter,
+    node_writes: IntCounter,
+    cache_hits: IntCounter,
+    proof_generation: Histogram,
+    prune: Histogram,
+    registry: Registry,
+}
+
+impl TrieMetrics {
+    /// Constructor of trie metrics which creates and registers metrics objects for use.
+    pub(super) fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+        let node_reads = IntCounter::new(TRIE_NODE_READS_NAME, TRIE_NODE_READS_HELP)?;
+        registry.register(Box::new(node_reads.clone()))?;
+
+        let node_writes = IntCounter::new(TRIE_NODE_WRITES_NAME, TRIE_NODE_WRITES_HELP)?;
+        registry.register(Box::new(node_writes.clone()))?;
+
+        let cache_hits = IntCounter::new(TRIE_CACHE_HITS_NAME, TRIE_CACHE_HITS_HELP)?;
+        registry.register(Box::new(cache_hits.clone()))?;
+
+        let tiny_buckets = prometheus::exponential_buckets(0.001, 2.0, 10)?;
+        let common_buckets = prometheus::exponential_buckets(
+            EXPONENTIAL_BUCKET_START,
+            EXPONENTIAL_BUCKET_FACTOR,
+            EXPONENTIAL_BUCKET_COUNT,
+        )?;
+
+        Ok(TrieMetrics {
+            node_reads,
+            node_writes,
+            cache_hits,
+            proof_generation: utils::register_histogram_metric(
+                registry,
+                TRIE_PROOF_GENERATION_NAME,
+                TRIE_PROOF_GENERATION_HELP,
+                tiny_buckets,
+            )?,
+            prune: utils::register_histogram_metric(
+                registry,
+                TRIE_PRUNE_NAME,
+                TRIE_PRUNE_HELP,
+                common_buckets,
+            )?,
+            registry: registry.clone(),
+        })
+    }
+}
+
+impl TrieMetricsSink for TrieMetrics {
+    fn record_node_read(&self) {
+        self.node_reads.inc();
+    }
+
+    fn record_node_write(&self) {
+        self.node_writes.inc();
+    }
+
+    fn record_cache_hit(&self) {
+        self.cache_hits.inc();
+    }
+
+    fn record_proof_generation(&self, duration: Duration) {
+        self.proof_generation.observe(duration.as_secs_f64());
+    }
+
+    fn record_prune(&self, duration: Duration) {
+        self.prune.observe(duration.as_secs_f64());
+    }
+}
+
+impl Drop for TrieMetrics {
+    fn drop(&mut self) {
+        unregister_metric!(self.registry, self.node_reads);
+        unregister_metric!(self.registry, self.node_writes);
+        unregister_metric!(self.registry, self.cache_hits);
+        unregister_metric!(self.registry, self.proof_generation);
+        unregister_metric!(self.registry, self.prune);
+    }
+}
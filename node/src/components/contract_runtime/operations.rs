@@ -1,24 +1,35 @@
-use std::{cmp, collections::BTreeMap, ops::Range, sync::Arc, time::Instant};
+use std::{
+    cmp,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::Range,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use itertools::Itertools;
-use tracing::{debug, error, info, trace, warn};
+use tracing::{debug, error, info, info_span, trace, warn};
 
 use casper_execution_engine::{
     core::{
         engine_state::{
-            self, execution_result::ExecutionResults, step::EvictItem, ChecksumRegistry,
+            self, engine_config::Quirk, execution_result::ExecutionResults, step::EvictItem,
+            BlockGasUtilization, CheckInvariantsRequest, CheckInvariantsResult, ChecksumRegistry,
             DeployItem, EngineState, ExecuteRequest, ExecutionResult as EngineExecutionResult,
-            GetEraValidatorsRequest, PruneConfig, PruneResult, RewardItem, StepError, StepRequest,
-            StepSuccess,
+            ExecutionStats, GetEraValidatorsRequest, PruneConfig, PruneResult, PurseChanges,
+            RewardItem, StepError, StepRequest, StepSuccess,
         },
         execution,
     },
-    shared::{additive_map::AdditiveMap, newtypes::CorrelationId, transform::Transform},
+    shared::{
+        additive_map::AdditiveMap, execution_journal::ExecutionJournal, newtypes::CorrelationId,
+        transform::Transform,
+    },
     storage::global_state::{lmdb::LmdbGlobalState, CommitProvider, StateProvider},
 };
 use casper_hashing::Digest;
 use casper_types::{
-    CLValue, DeployHash, EraId, ExecutionResult, Key, ProtocolVersion, PublicKey, U512,
+    CLValue, ContractEvent, DeployHash, EraId, ExecutionResult, Gas, Key, ProtocolVersion,
+    PublicKey, U512,
 };
 
 use crate::{
@@ -26,14 +37,15 @@ use crate::{
         consensus::EraReport,
         contract_runtime::{
             error::BlockExecutionError, types::StepEffectAndUpcomingEraValidators,
-            BlockAndExecutionResults, ExecutionPreState, Metrics, SpeculativeExecutionState,
-            APPROVALS_CHECKSUM_NAME, EXECUTION_RESULTS_CHECKSUM_NAME,
+            BlockAndExecutionResults, ExecutionPreState, Metrics, SpeculativeExecutionResult,
+            SpeculativeExecutionState, APPROVALS_CHECKSUM_NAME, EVENTS_CHECKSUM_NAME,
+            EXECUTION_RESULTS_CHECKSUM_NAME,
         },
         fetcher::FetchItem,
     },
     types::{
-        self, error::BlockCreationError, ApprovalsHashes, Block, Chunkable, Deploy, DeployHeader,
-        FinalizedBlock,
+        self, chainspec::DeprecatedDeployItems, error::BlockCreationError, ApprovalsHashes, Block,
+        Chunkable, Deploy, DeployExecutionStats, DeployHeader, FinalizedBlock,
     },
 };
 
@@ -99,6 +111,13 @@ pub fn execute_finalized_block(
     activation_point_era_id: EraId,
     key_block_height_for_activation_point: u64,
     prune_batch_size: u64,
+    deprecated_deploy_items: DeprecatedDeployItems,
+    chain_id: u32,
+    chain_bound_approvals: Option<EraId>,
+    block_gas_limit: u64,
+    block_max_gas_per_account: u64,
+    cross_check_execution: bool,
+    check_invariants: bool,
 ) -> Result<BlockAndExecutionResults, BlockExecutionError> {
     if finalized_block.height() != execution_pre_state.next_block_height {
         return Err(BlockExecutionError::WrongBlockHeight {
@@ -115,6 +134,13 @@ pub fn execute_finalized_block(
     let mut state_root_hash = pre_state_root_hash;
     let mut execution_results: Vec<(_, DeployHeader, ExecutionResult)> =
         Vec::with_capacity(deploys.len());
+    let mut execution_stats = BTreeMap::new();
+    let mut execution_warnings = BTreeMap::new();
+    let mut block_events: Vec<ContractEvent> = vec![];
+    let mut purse_changes = PurseChanges::default();
+    let mut gas_utilization = BlockGasUtilization::new(block_gas_limit);
+    let mut gas_per_account: HashMap<PublicKey, Gas> = HashMap::new();
+    let mut cross_check = cross_check_execution.then(CrossCheck::default);
     // Run any deploys that must be executed
     let block_time = finalized_block.timestamp().millis();
     let start = Instant::now();
@@ -128,11 +154,84 @@ pub fn execute_finalized_block(
     // WARNING: Do not change the order of `deploys` as it will result in a different root hash.
     for deploy in deploys {
         let deploy_hash = *deploy.hash();
+        let _span = info_span!(
+            "execute_deploy",
+            deploy_hash = ?deploy_hash.inner(),
+            block_height = finalized_block.height()
+        )
+        .entered();
         let deploy_header = deploy.header().clone();
+        // Deploys accepted before a form of deploy item they use was deprecated, or before
+        // approvals had to be bound to the chain, can still end up in a block; they fail without
+        // being executed.
+        if let Err(error) = deploy
+            .is_deprecation_compliant(&deprecated_deploy_items, finalized_block.era_id())
+            .and_then(|()| {
+                deploy.is_approval_binding_compliant(
+                    chain_id,
+                    chain_bound_approvals,
+                    finalized_block.era_id(),
+                )
+            })
+        {
+            debug!(?deploy_hash, %error, "not executing non-compliant deploy");
+            let execution_result = ExecutionResult::Failure {
+                effect: Default::default(),
+                transfers: vec![],
+                cost: U512::zero(),
+                error_message: error.to_string(),
+            };
+            execution_results.push((deploy_hash, deploy_header, execution_result));
+            continue;
+        }
+        // The proposer keeps the summed gas limits of each account's deploys within the cap, and
+        // so do the validators of the proposed block.  Should a block exceed it nonetheless, the
+        // deploys over the cap fail without being executed.  A deploy whose payment amount can't
+        // be determined might use up to the whole block, so it's counted at the block gas limit.
+        if !deploy.session().is_transfer() {
+            let gas_limit = deploy
+                .payment()
+                .payment_amount(deploy_header.gas_price())
+                .unwrap_or_else(|| Gas::from(block_gas_limit));
+            let account_gas = gas_per_account
+                .entry(deploy_header.account().clone())
+                .or_default();
+            match account_gas
+                .checked_add(gas_limit)
+                .filter(|gas| *gas <= Gas::from(block_max_gas_per_account))
+            {
+                Some(new_account_gas) => *account_gas = new_account_gas,
+                None => {
+                    warn!(
+                        ?deploy_hash,
+                        account = %deploy_header.account(),
+                        block_max_gas_per_account,
+                        "not executing deploy exceeding the gas per account of the block"
+                    );
+                    let execution_result = ExecutionResult::Failure {
+                        effect: Default::default(),
+                        transfers: vec![],
+                        cost: U512::zero(),
+                        error_message: format!(
+                            "deploys from account {} exceed the maximum gas of {} per account per \
+                            block",
+                            deploy_header.account(),
+                            block_max_gas_per_account
+                        ),
+                    };
+                    execution_results.push((deploy_hash, deploy_header, execution_result));
+                    continue;
+                }
+            }
+        }
+        let deploy_item = DeployItem::from(deploy);
+        if let Some(cross_check) = cross_check.as_mut() {
+            cross_check.deploy_items.push(deploy_item.clone());
+        }
         let execute_request = ExecuteRequest::new(
             state_root_hash,
             block_time,
-            vec![DeployItem::from(deploy)],
+            vec![deploy_item],
             protocol_version,
             *finalized_block.proposer(),
         );
@@ -142,9 +241,29 @@ pub fn execute_finalized_block(
         // mapping between deploy_hash and execution result, and this outer logic is
         // enriching it with the deploy hash. If we were passing multiple deploys per exec
         // the relation between the deploy and the execution results would be lost.
-        let result = execute(&scratch_state, metrics.clone(), execute_request)?;
+        let (result, deploy_execution_stats) =
+            execute(&scratch_state, metrics.clone(), execute_request)?;
+        for deploy_execution_stats in deploy_execution_stats {
+            execution_stats.insert(
+                deploy_hash,
+                DeployExecutionStats::from(deploy_execution_stats),
+            );
+        }
 
         trace!(?deploy_hash, ?result, "deploy execution result");
+        for ee_execution_result in &result {
+            block_events.extend_from_slice(ee_execution_result.events());
+            purse_changes.extend(PurseChanges::from_execution_journal(
+                ee_execution_result.execution_journal(),
+            ));
+            gas_utilization.record(ee_execution_result.cost());
+            if let Some(cross_check) = cross_check.as_mut() {
+                cross_check.record_keys(ee_execution_result.execution_journal());
+            }
+            if !ee_execution_result.warnings().is_empty() {
+                execution_warnings.insert(deploy_hash, ee_execution_result.warnings().to_vec());
+            }
+        }
         // As for now a given state is expected to exist.
         let (state_hash, execution_result) = commit_execution_results(
             &scratch_state,
@@ -157,8 +276,8 @@ pub fn execute_finalized_block(
         state_root_hash = state_hash;
     }
 
-    // Write the deploy approvals and execution results Merkle root hashes to global state if there
-    // were any deploys.
+    // Write the deploy approvals, execution results and contract events Merkle root hashes to
+    // global state if there were any deploys.
     let execution_results_checksum = compute_execution_results_checksum(
         &execution_results
             .iter()
@@ -166,11 +285,19 @@ pub fn execute_finalized_block(
             .cloned()
             .collect(),
     )?;
+    // Blocks of protocol versions predating the events don't record them.
+    let record_block_events = !engine_state
+        .config()
+        .has_quirk_at(protocol_version, Quirk::UnrecordedBlockEvents);
 
     let mut effects = AdditiveMap::new();
     let mut checksum_registry = ChecksumRegistry::new();
     checksum_registry.insert(APPROVALS_CHECKSUM_NAME, approvals_checksum);
     checksum_registry.insert(EXECUTION_RESULTS_CHECKSUM_NAME, execution_results_checksum);
+    if record_block_events {
+        let events_checksum = compute_events_checksum(&block_events)?;
+        checksum_registry.insert(EVENTS_CHECKSUM_NAME, events_checksum);
+    }
     let _ = effects.insert(
         Key::ChecksumRegistry,
         Transform::Write(
@@ -179,6 +306,42 @@ pub fn execute_finalized_block(
                 .into(),
         ),
     );
+    // The events are always overwritten so that querying a block's state root yields only the
+    // events emitted in that block.
+    if record_block_events {
+        let _ = effects.insert(
+            Key::BlockEvents,
+            Transform::Write(
+                CLValue::from_t(block_events)
+                    .map_err(BlockCreationError::CLValue)?
+                    .into(),
+            ),
+        );
+    }
+    // The gas utilization is recorded in global state so that it's the same for every node, from
+    // the protocol version introducing it on.
+    debug!(
+        gas_consumed = %gas_utilization.gas_consumed(),
+        block_gas_limit,
+        "block gas utilization"
+    );
+    if !engine_state
+        .config()
+        .has_quirk_at(protocol_version, Quirk::UnrecordedBlockGasUtilization)
+    {
+        let _ = effects.insert(
+            Key::BlockGasUtilization,
+            Transform::Write(
+                CLValue::from_t(gas_utilization)
+                    .map_err(BlockCreationError::CLValue)?
+                    .into(),
+            ),
+        );
+    }
+    if let Some(cross_check) = cross_check.as_mut() {
+        cross_check.touched_keys.extend(effects.keys().copied());
+        cross_check.block_effects = effects.clone();
+    }
     scratch_state.apply_effect(CorrelationId::new(), state_root_hash, effects)?;
 
     if let Some(metrics) = metrics.as_ref() {
@@ -192,6 +355,7 @@ pub fn execute_finalized_block(
             let StepSuccess {
                 post_state_hash: _, // ignore the post-state-hash returned from scratch
                 execution_journal: step_execution_journal,
+                step_report,
             } = commit_step(
                 &scratch_state, // engine_state
                 metrics,
@@ -216,6 +380,7 @@ pub fn execute_finalized_block(
             )?;
             Some(StepEffectAndUpcomingEraValidators {
                 step_execution_journal,
+                step_report,
                 upcoming_era_validators,
             })
         } else {
@@ -226,6 +391,27 @@ pub fn execute_finalized_block(
             None
         };
 
+    if let Some(mut cross_check) = cross_check {
+        if let Some(StepEffectAndUpcomingEraValidators {
+            step_execution_journal,
+            ..
+        }) = maybe_step_effect_and_upcoming_era_validators.as_ref()
+        {
+            cross_check.record_keys(step_execution_journal);
+        }
+        cross_check.run(
+            engine_state,
+            protocol_version,
+            pre_state_root_hash,
+            &finalized_block,
+            state_root_hash,
+        )?;
+    }
+
+    if check_invariants {
+        check_block_invariants(engine_state, finalized_block.height(), state_root_hash)?;
+    }
+
     // Flush once, after all deploys have been executed.
     engine_state.flush_environment()?;
 
@@ -292,6 +478,40 @@ pub fn execute_finalized_block(
         }
     }
 
+    // Items of cleared contract namespaces are pruned gradually, sharing the prune batch size.
+    if prune_batch_size > 0 {
+        match engine_state.commit_prune_namespaces(
+            CorrelationId::new(),
+            state_root_hash,
+            prune_batch_size as usize,
+        ) {
+            Ok(PruneResult::Success { post_state_hash }) => {
+                if post_state_hash != state_root_hash {
+                    debug!(
+                        %state_root_hash,
+                        %post_state_hash,
+                        "commit prune: pruned items of cleared namespaces"
+                    );
+                }
+                state_root_hash = post_state_hash;
+            }
+            Ok(PruneResult::RootNotFound) => {
+                error!(%state_root_hash, "commit prune namespaces: root not found");
+                panic!(
+                    "Root {} not found while pruning namespaces.",
+                    state_root_hash
+                );
+            }
+            Ok(PruneResult::DoesNotExist) => {
+                warn!(%state_root_hash, "commit prune namespaces: key does not exist");
+            }
+            Err(error) => {
+                error!(%error, "commit prune namespaces: commit prune error");
+                return Err(error.into());
+            }
+        }
+    }
+
     let next_era_validator_weights: Option<BTreeMap<PublicKey, U512>> =
         maybe_step_effect_and_upcoming_era_validators
             .as_ref()
@@ -331,10 +551,179 @@ pub fn execute_finalized_block(
         block,
         approvals_hashes,
         execution_results,
+        execution_stats,
+        execution_warnings,
+        purse_changes,
         maybe_step_effect_and_upcoming_era_validators,
     })
 }
 
+/// The inputs needed to execute a block a second time, committing directly to LMDB, and to compare
+/// the outcome with that of executing it through the scratch global state.
+#[derive(Default)]
+struct CrossCheck {
+    /// The deploy items executed, in order.
+    deploy_items: Vec<DeployItem>,
+    /// The effects written after executing the deploys, such as the checksum registry.
+    block_effects: AdditiveMap<Key, Transform>,
+    /// The keys written by executing the block through the scratch global state.
+    touched_keys: BTreeSet<Key>,
+}
+
+impl CrossCheck {
+    fn record_keys(&mut self, execution_journal: &ExecutionJournal) {
+        self.touched_keys
+            .extend(execution_journal.iter().map(|(key, _)| *key));
+    }
+
+    /// Executes the block again committing directly to LMDB, and returns an error naming the first
+    /// differing key if the post-state root differs from `scratch_state_root_hash`.
+    fn run(
+        mut self,
+        engine_state: &EngineState<LmdbGlobalState>,
+        protocol_version: ProtocolVersion,
+        pre_state_root_hash: Digest,
+        finalized_block: &FinalizedBlock,
+        scratch_state_root_hash: Digest,
+    ) -> Result<(), BlockExecutionError> {
+        let start = Instant::now();
+        let mut state_root_hash = pre_state_root_hash;
+        for deploy_item in self.deploy_items {
+            let deploy_hash = deploy_item.deploy_hash;
+            let execute_request = ExecuteRequest::new(
+                state_root_hash,
+                finalized_block.timestamp().millis(),
+                vec![deploy_item],
+                protocol_version,
+                *finalized_block.proposer(),
+            );
+            let (result, _) = execute(engine_state, None, execute_request)?;
+            for ee_execution_result in &result {
+                self.touched_keys.extend(
+                    ee_execution_result
+                        .execution_journal()
+                        .iter()
+                        .map(|(key, _)| *key),
+                );
+            }
+            state_root_hash =
+                commit_execution_results(engine_state, None, state_root_hash, deploy_hash, result)?
+                    .0;
+        }
+        state_root_hash =
+            commit_transforms(engine_state, None, state_root_hash, self.block_effects)?;
+        if let Some(era_report) = finalized_block.era_report() {
+            let step_success = commit_step(
+                engine_state,
+                None,
+                protocol_version,
+                state_root_hash,
+                era_report,
+                finalized_block.timestamp().millis(),
+                finalized_block.era_id().successor(),
+            )?;
+            self.touched_keys
+                .extend(step_success.execution_journal.iter().map(|(key, _)| *key));
+            state_root_hash = step_success.post_state_hash;
+        }
+
+        if state_root_hash == scratch_state_root_hash {
+            debug!(
+                block_height = finalized_block.height(),
+                %state_root_hash,
+                elapsed = ?start.elapsed(),
+                "cross-checked block execution"
+            );
+            return Ok(());
+        }
+
+        let first_differing_key = first_differing_key(
+            engine_state,
+            &self.touched_keys,
+            scratch_state_root_hash,
+            state_root_hash,
+        )?;
+        error!(
+            block_height = finalized_block.height(),
+            %scratch_state_root_hash,
+            direct_state_root_hash = %state_root_hash,
+            ?first_differing_key,
+            "executing block through scratch global state and directly diverged"
+        );
+        Err(BlockExecutionError::CrossCheckDivergence {
+            scratch_state_root_hash,
+            direct_state_root_hash: state_root_hash,
+            first_differing_key,
+        })
+    }
+}
+
+/// Returns the first of `keys` whose value differs between the two state roots.
+fn first_differing_key(
+    engine_state: &EngineState<LmdbGlobalState>,
+    keys: &BTreeSet<Key>,
+    state_root_hash: Digest,
+    other_state_root_hash: Digest,
+) -> Result<Option<Key>, engine_state::Error> {
+    let correlation_id = CorrelationId::new();
+    let (mut tracking_copy, mut other_tracking_copy) = match (
+        engine_state.tracking_copy(state_root_hash)?,
+        engine_state.tracking_copy(other_state_root_hash)?,
+    ) {
+        (Some(tracking_copy), Some(other_tracking_copy)) => (tracking_copy, other_tracking_copy),
+        _ => return Ok(None),
+    };
+    for key in keys {
+        let value = tracking_copy
+            .read(correlation_id, key)
+            .map_err(execution::Error::from)?;
+        let other_value = other_tracking_copy
+            .read(correlation_id, key)
+            .map_err(execution::Error::from)?;
+        if value != other_value {
+            return Ok(Some(*key));
+        }
+    }
+    Ok(None)
+}
+
+/// Checks the cross-contract invariants of the global state produced by executing a block.
+fn check_block_invariants(
+    engine_state: &EngineState<LmdbGlobalState>,
+    block_height: u64,
+    state_root_hash: Digest,
+) -> Result<(), BlockExecutionError> {
+    let start = Instant::now();
+    let violations = match engine_state.check_invariants(
+        CorrelationId::new(),
+        CheckInvariantsRequest::new(state_root_hash),
+    )? {
+        CheckInvariantsResult::Success { violations } => violations,
+        CheckInvariantsResult::RootNotFound => {
+            return Err(engine_state::Error::RootNotFound(state_root_hash).into());
+        }
+    };
+
+    if violations.is_empty() {
+        debug!(
+            block_height,
+            %state_root_hash,
+            elapsed = ?start.elapsed(),
+            "checked global state invariants"
+        );
+        return Ok(());
+    }
+
+    for violation in &violations {
+        error!(block_height, %state_root_hash, %violation, "global state invariant violated");
+    }
+    Err(BlockExecutionError::InvariantViolations {
+        block_height,
+        state_root_hash,
+        violations,
+    })
+}
+
 /// Commits the execution results.
 fn commit_execution_results<S>(
     engine_state: &EngineState<S>,
@@ -352,6 +741,7 @@ where
         .exactly_one()
         .map_err(|_| BlockExecutionError::MoreThanOneExecutionResult)?;
     let json_execution_result = ExecutionResult::from(&ee_execution_result);
+    let effects_size = ee_execution_result.effects_size();
 
     let execution_effect: AdditiveMap<Key, Transform> = match ee_execution_result {
         EngineExecutionResult::Success {
@@ -361,7 +751,7 @@ where
         } => {
             // We do want to see the deploy hash and cost in the logs.
             // We don't need to see the effects in the logs.
-            debug!(?deploy_hash, %cost, "execution succeeded");
+            debug!(?deploy_hash, %cost, effects_size, "execution succeeded");
             execution_journal
         }
         EngineExecutionResult::Failure {
@@ -373,7 +763,7 @@ where
             // Failure to execute a contract is a user error, not a system error.
             // We do want to see the deploy hash, error, and cost in the logs.
             // We don't need to see the effects in the logs.
-            debug!(?deploy_hash, ?error, %cost, "execution failure");
+            debug!(?deploy_hash, ?error, %cost, effects_size, "execution failure");
             execution_journal
         }
     }
@@ -404,15 +794,22 @@ where
     result.map(Digest::from)
 }
 
+/// How long the state root of a speculative execution stays pinned if it isn't released.
+const SPECULATIVE_EXECUTION_VIEW_TTL: Duration = Duration::from_secs(60);
+
 /// Execute the transaction without commiting the effects.
 /// Intended to be used for discovery operations on read-only nodes.
 ///
+/// The pre-state may be that of any historical block whose state root is still retained; it is
+/// pinned for the duration of the execution.
+///
 /// Returns effects of the execution.
 pub fn execute_only<S>(
     engine_state: &EngineState<S>,
     execution_state: SpeculativeExecutionState,
     deploy: DeployItem,
-) -> Result<Option<ExecutionResult>, engine_state::Error>
+    record_reads: bool,
+) -> Result<Option<SpeculativeExecutionResult>, engine_state::Error>
 where
     S: StateProvider + CommitProvider,
     S::Error: Into<execution::Error>,
@@ -430,8 +827,22 @@ where
         protocol_version,
         PublicKey::System,
     );
-    let results = execute(engine_state, None, execute_request);
-    results.map(|mut execution_results| {
+    let view = engine_state
+        .pin_view(state_root_hash, SPECULATIVE_EXECUTION_VIEW_TTL)?
+        .ok_or(engine_state::Error::RootNotFound(state_root_hash))?;
+    trace!(?execute_request, %state_root_hash, "speculative execute");
+    let results = if record_reads {
+        engine_state
+            .run_execute_in_view_recording_reads(CorrelationId::new(), &view, execute_request)
+            .map(|(execution_results, reads)| (execution_results, Some(reads)))
+    } else {
+        engine_state
+            .run_execute_in_view(CorrelationId::new(), &view, execute_request)
+            .map(|execution_results| (execution_results, None))
+    };
+    engine_state.release_view(&view);
+    trace!(?results, "speculative execute result");
+    results.map(|(mut execution_results, reads)| {
         let len = execution_results.len();
         if len != 1 {
             warn!(
@@ -444,7 +855,14 @@ where
             // with `Some(_)` but `pop_front` already returns an `Option`.
             // We need to transform the `engine_state::ExecutionResult` into
             // `casper_types::ExecutionResult` as well.
-            execution_results.pop_front().map(Into::into)
+            execution_results
+                .pop_front()
+                .map(|execution_result| SpeculativeExecutionResult {
+                    error_code: execution_result.error_code(),
+                    warnings: execution_result.warnings().to_vec(),
+                    execution_result: execution_result.into(),
+                    reads,
+                })
         }
     })
 }
@@ -453,7 +871,7 @@ fn execute<S>(
     engine_state: &EngineState<S>,
     metrics: Option<Arc<Metrics>>,
     execute_request: ExecuteRequest,
-) -> Result<ExecutionResults, engine_state::Error>
+) -> Result<(ExecutionResults, Vec<ExecutionStats>), engine_state::Error>
 where
     S: StateProvider + CommitProvider,
     S::Error: Into<execution::Error>,
@@ -461,9 +879,16 @@ where
     trace!(?execute_request, "execute");
     let correlation_id = CorrelationId::new();
     let start = Instant::now();
-    let result = engine_state.run_execute(correlation_id, execute_request);
+    let result = engine_state.run_execute_with_stats(correlation_id, execute_request);
     if let Some(metrics) = metrics {
         metrics.run_execute.observe(start.elapsed().as_secs_f64());
+        if let Ok((_, execution_stats)) = &result {
+            for execution_stats in execution_stats {
+                metrics
+                    .deploy_memory_high_water_mark
+                    .observe(execution_stats.memory_high_water_mark() as f64);
+            }
+        }
     }
     trace!(?result, "execute result");
     result
@@ -530,7 +955,7 @@ where
 ///
 /// NOTE: We're hashing vector of execution results, instead of just their hashes, b/c when a joiner
 /// node receives the chunks of *full data* it has to be able to verify it against the Merkle root.
-fn compute_execution_results_checksum(
+pub(super) fn compute_execution_results_checksum(
     execution_results: &Vec<ExecutionResult>,
 ) -> Result<Digest, BlockCreationError> {
     execution_results
@@ -538,6 +963,15 @@ fn compute_execution_results_checksum(
         .map_err(BlockCreationError::BytesRepr)
 }
 
+fn compute_events_checksum(events: &[ContractEvent]) -> Result<Digest, BlockCreationError> {
+    let leaves = events
+        .iter()
+        .map(|event| event.hash().map(Digest::from))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(BlockCreationError::BytesRepr)?;
+    Ok(Digest::hash_merkle_tree(leaves))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
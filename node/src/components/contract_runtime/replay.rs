@@ -0,0 +1,370 @@
+//! Deterministic replay of a range of finalized blocks, verifying what the chain recorded.
+//!
+//! Starting from a trusted pre-state, every block is re-executed exactly as the contract runtime
+//! executes it, and the resulting state root hash and execution results checksum are compared
+//! against the recorded ones.  This lets a third party audit a range of blocks with nothing but a
+//! copy of global state at the start of the range.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use casper_execution_engine::{
+    core::engine_state::EngineState, storage::global_state::lmdb::LmdbGlobalState,
+};
+use casper_hashing::Digest;
+use casper_types::ExecutionResult;
+
+use super::{
+    execute_finalized_block, operations, BlockAndExecutionResults, BlockExecutionError,
+    ExecutionPreState,
+};
+use crate::types::{
+    error::BlockCreationError, Block, BlockHash, Chainspec, Deploy, DeployHash, FinalizedBlock,
+};
+
+/// A finalized block along with what the chain recorded for it.
+#[derive(Clone, Debug)]
+pub struct RecordedBlock {
+    /// The block.
+    pub block: Block,
+    /// The deploys and transfers of the block, in any order.
+    pub deploys: Vec<Deploy>,
+    /// The recorded result of executing each deploy and transfer of the block.
+    pub execution_results: HashMap<DeployHash, ExecutionResult>,
+}
+
+/// The first difference between a replayed block and the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Divergence {
+    /// Replaying the block produced different execution results.
+    #[error(
+        "block {block_height} ({block_hash}) has execution results checksum {recorded}, replayed \
+        {replayed}"
+    )]
+    ExecutionResultsChecksum {
+        /// The height of the block.
+        block_height: u64,
+        /// The hash of the recorded block.
+        block_hash: BlockHash,
+        /// The checksum of the recorded execution results.
+        recorded: Digest,
+        /// The checksum of the replayed execution results.
+        replayed: Digest,
+    },
+    /// Replaying the block produced a different global state.
+    #[error(
+        "block {block_height} ({block_hash}) has state root hash {recorded}, replayed {replayed}"
+    )]
+    StateRootHash {
+        /// The height of the block.
+        block_height: u64,
+        /// The hash of the recorded block.
+        block_hash: BlockHash,
+        /// The recorded state root hash.
+        recorded: Digest,
+        /// The state root hash after replaying the block.
+        replayed: Digest,
+    },
+}
+
+/// An error preventing a range of blocks from being replayed.
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    /// A block isn't the child of the block before it.
+    #[error("block {block_height} ({block_hash}) isn't a child of {expected_parent_hash}")]
+    NotAChild {
+        /// The height of the block.
+        block_height: u64,
+        /// The hash of the block.
+        block_hash: BlockHash,
+        /// The hash of the block before it.
+        expected_parent_hash: BlockHash,
+    },
+    /// A deploy of a block wasn't provided.
+    #[error("deploy {deploy_hash} of block {block_height} is missing")]
+    MissingDeploy {
+        /// The height of the block.
+        block_height: u64,
+        /// The hash of the missing deploy.
+        deploy_hash: DeployHash,
+    },
+    /// The recorded execution result of a deploy of a block wasn't provided.
+    #[error("execution result of deploy {deploy_hash} of block {block_height} is missing")]
+    MissingExecutionResult {
+        /// The height of the block.
+        block_height: u64,
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+    },
+    /// Computing the checksum of the recorded execution results failed.
+    #[error("failed to compute execution results checksum of block {block_height}: {error}")]
+    Checksum {
+        /// The height of the block.
+        block_height: u64,
+        /// The underlying error.
+        error: BlockCreationError,
+    },
+    /// Executing a block failed.
+    #[error("failed to execute block {block_height}: {error}")]
+    BlockExecution {
+        /// The height of the block.
+        block_height: u64,
+        /// The underlying error.
+        error: BlockExecutionError,
+    },
+}
+
+/// Replays `blocks` on top of `trusted_pre_state` and returns the first [`Divergence`] from what
+/// the chain recorded, or `None` if every block replays identically.
+///
+/// The blocks have to be consecutive and belong to the protocol version of `chainspec`, and
+/// `engine_state` has to hold the global state under the trusted pre-state root.  Replaying
+/// commits the replayed global state to `engine_state`, the same as executing the blocks would.
+///
+/// `key_block_height_for_activation_point` is the height of the switch block at the activation
+/// point of the current protocol version, which determines what is pruned from global state.
+pub fn verify_chain<I>(
+    engine_state: &EngineState<LmdbGlobalState>,
+    chainspec: &Chainspec,
+    key_block_height_for_activation_point: u64,
+    trusted_pre_state: ExecutionPreState,
+    blocks: I,
+) -> Result<Option<Divergence>, ReplayError>
+where
+    I: IntoIterator<Item = RecordedBlock>,
+{
+    let mut execution_pre_state = trusted_pre_state;
+    for RecordedBlock {
+        block,
+        deploys,
+        execution_results,
+    } in blocks
+    {
+        let block_height = block.height();
+        let block_hash = *block.hash();
+        if *block.header().parent_hash() != execution_pre_state.parent_hash {
+            return Err(ReplayError::NotAChild {
+                block_height,
+                block_hash,
+                expected_parent_hash: execution_pre_state.parent_hash,
+            });
+        }
+
+        // The deploys are executed in the order the block lists them.
+        let mut deploys_by_hash: HashMap<DeployHash, Deploy> = deploys
+            .into_iter()
+            .map(|deploy| (*deploy.hash(), deploy))
+            .collect();
+        let mut ordered_deploys = Vec::new();
+        let mut recorded_results = Vec::new();
+        for deploy_hash in block.deploy_and_transfer_hashes() {
+            let deploy = deploys_by_hash
+                .remove(deploy_hash)
+                .ok_or(ReplayError::MissingDeploy {
+                    block_height,
+                    deploy_hash: *deploy_hash,
+                })?;
+            let execution_result = execution_results.get(deploy_hash).cloned().ok_or(
+                ReplayError::MissingExecutionResult {
+                    block_height,
+                    deploy_hash: *deploy_hash,
+                },
+            )?;
+            ordered_deploys.push(deploy);
+            recorded_results.push(execution_result);
+        }
+        let recorded_checksum = operations::compute_execution_results_checksum(&recorded_results)
+            .map_err(|error| ReplayError::Checksum {
+            block_height,
+            error,
+        })?;
+
+        let BlockAndExecutionResults {
+            block: replayed_block,
+            execution_results: replayed_results,
+            ..
+        } = execute_finalized_block(
+            engine_state,
+            None,
+            chainspec.protocol_version(),
+            execution_pre_state,
+            FinalizedBlock::from(block.clone()),
+            ordered_deploys,
+            chainspec.protocol_config.activation_point.era_id(),
+            key_block_height_for_activation_point,
+            chainspec.core_config.prune_batch_size,
+            chainspec.deploy_config.deprecated_items,
+            chainspec.network_config.chain_id,
+            chainspec.network_config.chain_bound_approvals,
+            chainspec.deploy_config.block_gas_limit,
+            chainspec.deploy_config.block_max_gas_per_account,
+            false,
+            false,
+        )
+        .map_err(|error| ReplayError::BlockExecution {
+            block_height,
+            error,
+        })?;
+
+        let replayed_results = replayed_results
+            .into_iter()
+            .map(|(_, _, execution_result)| execution_result)
+            .collect();
+        let replayed_checksum = operations::compute_execution_results_checksum(&replayed_results)
+            .map_err(|error| ReplayError::Checksum {
+            block_height,
+            error,
+        })?;
+        if replayed_checksum != recorded_checksum {
+            return Ok(Some(Divergence::ExecutionResultsChecksum {
+                block_height,
+                block_hash,
+                recorded: recorded_checksum,
+                replayed: replayed_checksum,
+            }));
+        }
+        if replayed_block.state_root_hash() != block.state_root_hash() {
+            return Ok(Some(Divergence::StateRootHash {
+                block_height,
+                block_hash,
+                recorded: *block.state_root_hash(),
+                replayed: *replayed_block.state_root_hash(),
+            }));
+        }
+
+        execution_pre_state = ExecutionPreState::from_block_header(block.header());
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use casper_types::U512;
+
+    use super::*;
+    use crate::{
+        components::contract_runtime::simulation::{tests::transfers, NetworkSimulation},
+        types::ChainspecRawBytes,
+        utils::Loadable,
+    };
+
+    const BLOCKS_PER_ERA: u64 = 3;
+
+    fn record_blocks(
+        chainspec: Arc<Chainspec>,
+        chainspec_raw_bytes: &ChainspecRawBytes,
+    ) -> Vec<RecordedBlock> {
+        let mut simulation = NetworkSimulation::new(1, Arc::clone(&chainspec), chainspec_raw_bytes)
+            .unwrap()
+            .with_blocks_per_era(BLOCKS_PER_ERA);
+        (0..(2 * BLOCKS_PER_ERA))
+            .map(|_| {
+                let deploys = transfers(&chainspec, simulation.next_block_timestamp(), 3);
+                let block = simulation.execute_block(deploys.clone()).unwrap();
+                let execution_results = simulation.execution_results(&block);
+                RecordedBlock {
+                    block: (*block).clone(),
+                    deploys,
+                    execution_results,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_replay_recorded_blocks() {
+        let (chainspec, chainspec_raw_bytes) =
+            <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+        let chainspec = Arc::new(chainspec);
+        let recorded_blocks = record_blocks(Arc::clone(&chainspec), &chainspec_raw_bytes);
+
+        let replay =
+            NetworkSimulation::new(1, Arc::clone(&chainspec), &chainspec_raw_bytes).unwrap();
+        let divergence = verify_chain(
+            replay.engine_state(),
+            &chainspec,
+            0,
+            replay.execution_pre_state(),
+            recorded_blocks,
+        )
+        .unwrap();
+        assert_eq!(divergence, None);
+    }
+
+    #[test]
+    fn should_report_first_divergence() {
+        let (chainspec, chainspec_raw_bytes) =
+            <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+        let chainspec = Arc::new(chainspec);
+        let mut recorded_blocks = record_blocks(Arc::clone(&chainspec), &chainspec_raw_bytes);
+
+        // Tamper with the recorded result of a deploy of the second block.
+        let tampered_block = &mut recorded_blocks[1];
+        let deploy_hash = *tampered_block
+            .block
+            .deploy_and_transfer_hashes()
+            .next()
+            .unwrap();
+        tampered_block.execution_results.insert(
+            deploy_hash,
+            ExecutionResult::Failure {
+                effect: Default::default(),
+                transfers: vec![],
+                cost: U512::MAX,
+                error_message: "tampered".to_string(),
+            },
+        );
+        let tampered_height = tampered_block.block.height();
+        let tampered_hash = *tampered_block.block.hash();
+
+        let replay =
+            NetworkSimulation::new(1, Arc::clone(&chainspec), &chainspec_raw_bytes).unwrap();
+        let divergence = verify_chain(
+            replay.engine_state(),
+            &chainspec,
+            0,
+            replay.execution_pre_state(),
+            recorded_blocks,
+        )
+        .unwrap();
+        assert!(
+            matches!(
+                divergence,
+                Some(Divergence::ExecutionResultsChecksum {
+                    block_height,
+                    block_hash,
+                    ..
+                }) if block_height == tampered_height && block_hash == tampered_hash
+            ),
+            "{:?}",
+            divergence
+        );
+    }
+
+    #[test]
+    fn should_reject_blocks_which_are_not_consecutive() {
+        let (chainspec, chainspec_raw_bytes) =
+            <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+        let chainspec = Arc::new(chainspec);
+        let mut recorded_blocks = record_blocks(Arc::clone(&chainspec), &chainspec_raw_bytes);
+        recorded_blocks.remove(0);
+
+        let replay =
+            NetworkSimulation::new(1, Arc::clone(&chainspec), &chainspec_raw_bytes).unwrap();
+        let result = verify_chain(
+            replay.engine_state(),
+            &chainspec,
+            0,
+            replay.execution_pre_state(),
+            recorded_blocks,
+        );
+        assert!(
+            matches!(result, Err(ReplayError::NotAChild { .. })),
+            "{:?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,551 @@
+//! A deterministic simulation of a network of nodes executing the same finalized blocks.
+//!
+//! Each simulated node has its own storage and contract runtime, backed by its own LMDB
+//! environments.  A simulated consensus feeds every node identical finalized blocks, and after each
+//! block the resulting state root hashes are compared, so any nondeterminism in execution (e.g.
+//! dependence on iteration order or on the wall clock) is caught as soon as it affects global
+//! state.
+
+use std::{collections::HashMap, io, sync::Arc};
+
+use prometheus::Registry;
+use tempfile::TempDir;
+use thiserror::Error;
+
+use casper_execution_engine::{
+    core::engine_state::{EngineState, Error as EngineStateError},
+    storage::global_state::lmdb::LmdbGlobalState,
+};
+use casper_hashing::Digest;
+use casper_types::{EraId, ExecutionResult, PublicKey, Timestamp};
+
+use super::{
+    execute_finalized_block, BlockAndExecutionResults, BlockExecutionError, Config, ConfigError,
+    ContractRuntime, ExecutionPreState,
+};
+use crate::{
+    components::{
+        consensus::EraReport,
+        storage::{self, FatalStorageError, Storage},
+    },
+    types::{
+        Block, BlockHash, BlockPayload, Chainspec, ChainspecRawBytes, Deploy, DeployHash,
+        DeployHashWithApprovals, FinalizedBlock,
+    },
+    utils::WithDir,
+};
+
+/// The maximum size of the global state store of each simulated node.
+const MAX_GLOBAL_STATE_SIZE: usize = 100 * 1024 * 1024;
+
+/// An error raised by a [`NetworkSimulation`].
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    /// Creating a node's temporary directory failed.
+    #[error("failed to create temporary directory for node {node}: {error}")]
+    TempDir {
+        /// The index of the node.
+        node: usize,
+        /// The underlying error.
+        error: io::Error,
+    },
+    /// Creating a node's storage failed.
+    #[error("failed to create storage for node {node}: {error}")]
+    Storage {
+        /// The index of the node.
+        node: usize,
+        /// The underlying error.
+        error: FatalStorageError,
+    },
+    /// Creating a node's contract runtime failed.
+    #[error("failed to create contract runtime for node {node}: {error}")]
+    ContractRuntime {
+        /// The index of the node.
+        node: usize,
+        /// The underlying error.
+        error: ConfigError,
+    },
+    /// Committing genesis on a node failed.
+    #[error("failed to commit genesis on node {node}: {error}")]
+    Genesis {
+        /// The index of the node.
+        node: usize,
+        /// The underlying error.
+        error: EngineStateError,
+    },
+    /// Executing a block on a node failed.
+    #[error("failed to execute block {height} on node {node}: {error}")]
+    BlockExecution {
+        /// The index of the node.
+        node: usize,
+        /// The height of the block.
+        height: u64,
+        /// The underlying error.
+        error: BlockExecutionError,
+    },
+    /// Storing an executed block on a node failed.
+    #[error("failed to store block {height} on node {node}: {error}")]
+    StoreBlock {
+        /// The index of the node.
+        node: usize,
+        /// The height of the block.
+        height: u64,
+        /// The underlying error.
+        error: FatalStorageError,
+    },
+    /// The nodes disagree on the global state after executing the same block.
+    #[error("nodes diverged at block height {height:?}: {state_root_hashes:?}")]
+    Divergence {
+        /// The height of the block after which the nodes diverged; `None` for genesis.
+        height: Option<u64>,
+        /// The state root hash of each node, in node order.
+        state_root_hashes: Vec<Digest>,
+    },
+}
+
+/// A single simulated node.
+struct SimulatedNode {
+    storage: Storage,
+    config: Config,
+    contract_runtime: ContractRuntime,
+    execution_pre_state: ExecutionPreState,
+    _tempdir: TempDir,
+}
+
+impl SimulatedNode {
+    fn new(node: usize, chainspec: &Chainspec) -> Result<Self, SimulationError> {
+        let tempdir =
+            tempfile::tempdir().map_err(|error| SimulationError::TempDir { node, error })?;
+        // Each node registers its metrics separately, as the names would collide otherwise.
+        let registry = Registry::new();
+
+        let storage_config = storage::Config {
+            path: tempdir.path().join("lmdb"),
+            ..Default::default()
+        };
+        let storage = Storage::new(
+            &WithDir::new(tempdir.path(), storage_config),
+            None,
+            chainspec.protocol_version(),
+            chainspec.protocol_config.activation_point.era_id(),
+            &chainspec.network_config.name,
+            chainspec.deploy_config.max_ttl.into(),
+            chainspec.core_config.recent_era_count(),
+            Some(&registry),
+            false,
+        )
+        .map_err(|error| SimulationError::Storage { node, error })?;
+
+        let config = Config {
+            max_global_state_size: Some(MAX_GLOBAL_STATE_SIZE),
+            ..Config::default()
+        };
+        let max_delegators_per_validator =
+            if chainspec.core_config.max_delegators_per_validator == 0 {
+                None
+            } else {
+                Some(chainspec.core_config.max_delegators_per_validator)
+            };
+        let contract_runtime = ContractRuntime::new(
+            chainspec.protocol_version(),
+            storage.root_path(),
+            &config,
+            chainspec.wasm_config,
+            chainspec.system_costs_config,
+            chainspec.core_config.max_associated_keys,
+            chainspec.core_config.max_runtime_call_stack_height,
+            chainspec.core_config.max_contract_call_stack_height,
+            chainspec.core_config.max_effects_size_bytes,
+            chainspec.core_config.minimum_delegation_amount,
+            chainspec.protocol_config.activation_point,
+            chainspec.core_config.prune_batch_size,
+            chainspec.deploy_config.deprecated_items,
+            chainspec.network_config.chain_id,
+            chainspec.network_config.chain_bound_approvals,
+            chainspec.deploy_config.block_gas_limit,
+            chainspec.deploy_config.block_max_gas_per_account,
+            chainspec.core_config.strict_argument_checking,
+            chainspec.core_config.vesting_schedule_period.millis(),
+            max_delegators_per_validator,
+            &registry,
+            chainspec.core_config.administrators.clone(),
+            chainspec.core_config.allow_auction_bids,
+            chainspec.core_config.allow_unrestricted_transfers,
+            chainspec.core_config.refund_handling,
+            chainspec.core_config.fee_handling,
+            chainspec.core_config.fee_distribution.clone(),
+            chainspec.core_config.existential_deposit,
+            chainspec.core_config.existential_deposit_handling,
+            chainspec.core_config.storage_refund,
+            chainspec.core_config.delegation_rate_change_cooldown,
+            chainspec.core_config.max_delegation_rate_increase,
+            chainspec.core_config.disabled_auction_entry_points.clone(),
+            chainspec.core_config.enable_purse_ownership_index,
+            chainspec.core_config.compress_contract_wasm,
+            &chainspec.core_config.compatibility_modes,
+        )
+        .map_err(|error| SimulationError::ContractRuntime { node, error })?;
+
+        Ok(SimulatedNode {
+            storage,
+            config,
+            contract_runtime,
+            execution_pre_state: ExecutionPreState::new(
+                0,
+                Digest::default(),
+                BlockHash::default(),
+                Digest::default(),
+            ),
+            _tempdir: tempdir,
+        })
+    }
+
+    fn execute_block(
+        &mut self,
+        node: usize,
+        chainspec: &Chainspec,
+        finalized_block: FinalizedBlock,
+        deploys: Vec<Deploy>,
+    ) -> Result<Arc<Block>, SimulationError> {
+        let height = finalized_block.height();
+        let BlockAndExecutionResults {
+            block,
+            approvals_hashes,
+            execution_results,
+            execution_stats: _,
+            execution_warnings: _,
+            purse_changes: _,
+            maybe_step_effect_and_upcoming_era_validators: _,
+        } = execute_finalized_block(
+            self.contract_runtime.engine_state(),
+            None,
+            chainspec.protocol_version(),
+            self.execution_pre_state.clone(),
+            finalized_block,
+            deploys,
+            chainspec.protocol_config.activation_point.era_id(),
+            0,
+            chainspec.core_config.prune_batch_size,
+            chainspec.deploy_config.deprecated_items,
+            chainspec.network_config.chain_id,
+            chainspec.network_config.chain_bound_approvals,
+            chainspec.deploy_config.block_gas_limit,
+            chainspec.deploy_config.block_max_gas_per_account,
+            self.config.cross_check_execution_or_default(),
+            self.config.check_invariants_or_default(),
+        )
+        .map_err(|error| SimulationError::BlockExecution {
+            node,
+            height,
+            error,
+        })?;
+
+        let execution_results = execution_results
+            .into_iter()
+            .map(|(deploy_hash, _, execution_result)| (deploy_hash, execution_result))
+            .collect::<HashMap<_, _>>();
+        self.storage
+            .put_executed_block(&block, &approvals_hashes, execution_results)
+            .map_err(|error| SimulationError::StoreBlock {
+                node,
+                height,
+                error,
+            })?;
+
+        self.execution_pre_state = ExecutionPreState::from_block_header(block.header());
+        Ok(block)
+    }
+}
+
+/// A network of simulated nodes, all executing the blocks finalized by a simulated consensus.
+///
+/// The simulated consensus is deterministic: every block is proposed by the system, block
+/// timestamps advance by the chainspec's minimum block time starting at the genesis timestamp, and
+/// each era ends after a fixed number of blocks.
+pub struct NetworkSimulation {
+    chainspec: Arc<Chainspec>,
+    nodes: Vec<SimulatedNode>,
+    genesis_timestamp: Timestamp,
+    blocks_per_era: u64,
+    next_block_height: u64,
+    era_id: EraId,
+}
+
+impl NetworkSimulation {
+    /// Creates `node_count` nodes, commits genesis on each of them and executes the immediate
+    /// switch block of era 0.
+    ///
+    /// Each era consists of the chainspec's minimum era height of blocks.
+    pub fn new(
+        node_count: usize,
+        chainspec: Arc<Chainspec>,
+        chainspec_raw_bytes: &ChainspecRawBytes,
+    ) -> Result<Self, SimulationError> {
+        let mut nodes = Vec::with_capacity(node_count);
+        for node in 0..node_count {
+            let mut simulated_node = SimulatedNode::new(node, &chainspec)?;
+            let post_state_hash = simulated_node
+                .contract_runtime
+                .commit_genesis(&chainspec, chainspec_raw_bytes)
+                .map_err(|error| SimulationError::Genesis { node, error })?
+                .post_state_hash;
+            simulated_node.execution_pre_state =
+                ExecutionPreState::new(0, post_state_hash, BlockHash::default(), Digest::default());
+            nodes.push(simulated_node);
+        }
+
+        let genesis_timestamp = chainspec
+            .protocol_config
+            .activation_point
+            .genesis_timestamp()
+            .unwrap_or_else(Timestamp::zero);
+        let blocks_per_era = chainspec.core_config.minimum_era_height.max(1);
+        let mut simulation = NetworkSimulation {
+            chainspec,
+            nodes,
+            genesis_timestamp,
+            blocks_per_era,
+            next_block_height: 0,
+            era_id: EraId::new(0),
+        };
+        simulation.check_state_root_hashes(None)?;
+
+        // Era 0 consists of the immediate switch block only.
+        let block_0 = FinalizedBlock::new(
+            BlockPayload::default(),
+            Some(EraReport::default()),
+            simulation.genesis_timestamp,
+            simulation.era_id,
+            0,
+            PublicKey::System,
+        );
+        simulation.execute_on_all_nodes(block_0, vec![])?;
+        Ok(simulation)
+    }
+
+    /// Sets the number of blocks in each era after era 0.
+    pub fn with_blocks_per_era(mut self, blocks_per_era: u64) -> Self {
+        self.blocks_per_era = blocks_per_era.max(1);
+        self
+    }
+
+    /// Returns the number of simulated nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the height of the next block to be executed.
+    pub fn next_block_height(&self) -> u64 {
+        self.next_block_height
+    }
+
+    /// Returns the era of the next block to be executed.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns the state root hash all nodes agree on.
+    pub fn state_root_hash(&self) -> Digest {
+        self.nodes
+            .first()
+            .map(|node| node.execution_pre_state.pre_state_root_hash)
+            .unwrap_or_default()
+    }
+
+    /// Returns the pre-state the next block will be executed with.
+    pub fn execution_pre_state(&self) -> ExecutionPreState {
+        self.first_node().execution_pre_state.clone()
+    }
+
+    /// Returns the engine state of the first node.
+    pub fn engine_state(&self) -> &EngineState<LmdbGlobalState> {
+        self.first_node().contract_runtime.engine_state()
+    }
+
+    /// Returns the results of executing the deploys of `block`, as stored by the first node.
+    pub fn execution_results(&self, block: &Block) -> HashMap<DeployHash, ExecutionResult> {
+        let storage = &self.first_node().storage;
+        block
+            .deploy_and_transfer_hashes()
+            .filter_map(|deploy_hash| {
+                let execution_result = storage
+                    .get_deploy_metadata_by_hash(deploy_hash)?
+                    .execution_results
+                    .remove(block.hash())?;
+                Some((*deploy_hash, execution_result))
+            })
+            .collect()
+    }
+
+    /// Returns the timestamp the next block will be finalized with.
+    pub fn next_block_timestamp(&self) -> Timestamp {
+        self.genesis_timestamp
+            .saturating_add(self.chainspec.core_config.minimum_block_time * self.next_block_height)
+    }
+
+    /// Finalizes the next block containing `deploys`, executes it on every node and checks the
+    /// nodes agree on the resulting global state.
+    ///
+    /// The block is a switch block if it is the last block of its era.
+    pub fn execute_block(&mut self, deploys: Vec<Deploy>) -> Result<Arc<Block>, SimulationError> {
+        let height = self.next_block_height;
+        let height_in_era = (height - 1) % self.blocks_per_era;
+        let era_report = if height_in_era + 1 == self.blocks_per_era {
+            Some(EraReport::default())
+        } else {
+            None
+        };
+
+        let (transfers, deploys_without_transfers): (Vec<_>, Vec<_>) = deploys
+            .iter()
+            .map(|deploy| (deploy.session().is_transfer(), deploy))
+            .partition(|(is_transfer, _)| *is_transfer);
+        let block_payload = BlockPayload::new(
+            deploys_without_transfers
+                .into_iter()
+                .map(|(_, deploy)| DeployHashWithApprovals::from(deploy))
+                .collect(),
+            transfers
+                .into_iter()
+                .map(|(_, deploy)| DeployHashWithApprovals::from(deploy))
+                .collect(),
+            vec![],
+            height % 2 == 0,
+        );
+        let finalized_block = FinalizedBlock::new(
+            block_payload,
+            era_report,
+            self.next_block_timestamp(),
+            self.era_id,
+            height,
+            PublicKey::System,
+        );
+        self.execute_on_all_nodes(finalized_block, deploys)
+    }
+
+    fn execute_on_all_nodes(
+        &mut self,
+        finalized_block: FinalizedBlock,
+        deploys: Vec<Deploy>,
+    ) -> Result<Arc<Block>, SimulationError> {
+        let height = finalized_block.height();
+        let mut executed_block = None;
+        for (node, simulated_node) in self.nodes.iter_mut().enumerate() {
+            let block = simulated_node.execute_block(
+                node,
+                &self.chainspec,
+                finalized_block.clone(),
+                deploys.clone(),
+            )?;
+            executed_block = Some(block);
+        }
+        self.check_state_root_hashes(Some(height))?;
+
+        let block = executed_block.expect("a network simulation should have at least one node");
+        self.next_block_height = height + 1;
+        if block.header().is_switch_block() {
+            self.era_id = self.era_id.successor();
+        }
+        Ok(block)
+    }
+
+    fn first_node(&self) -> &SimulatedNode {
+        self.nodes
+            .first()
+            .expect("a network simulation should have at least one node")
+    }
+
+    fn check_state_root_hashes(&self, height: Option<u64>) -> Result<(), SimulationError> {
+        let state_root_hashes: Vec<Digest> = self
+            .nodes
+            .iter()
+            .map(|node| node.execution_pre_state.pre_state_root_hash)
+            .collect();
+        if state_root_hashes.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err(SimulationError::Divergence {
+                height,
+                state_root_hashes,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{bytesrepr::Bytes, runtime_args, RuntimeArgs, SecretKey, TimeDiff, U512};
+
+    use super::*;
+    use crate::utils::{Loadable, RESOURCES_PATH};
+
+    const NODE_COUNT: usize = 3;
+    const BLOCKS_PER_ERA: u64 = 3;
+
+    pub(crate) fn transfers(
+        chainspec: &Chainspec,
+        timestamp: Timestamp,
+        count: u64,
+    ) -> Vec<Deploy> {
+        let secret_key = SecretKey::from_file(
+            RESOURCES_PATH
+                .join("local")
+                .join("secret_keys")
+                .join("node-1.pem"),
+        )
+        .unwrap();
+        let payment = ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: runtime_args! {
+              "amount" => U512::from(chainspec.system_costs_config.wasmless_transfer_cost()),
+            },
+        };
+        (0..count)
+            .map(|id| {
+                // Derive the targets from the id, so every run creates the same deploys.
+                let target_secret_key = SecretKey::ed25519_from_bytes([id as u8 + 1; 32]).unwrap();
+                let session = ExecutableDeployItem::Transfer {
+                    args: runtime_args! {
+                      "amount" => U512::from(chainspec.deploy_config.native_transfer_minimum_motes),
+                      "target" => PublicKey::from(&target_secret_key),
+                      "id" => Some(id),
+                    },
+                };
+                Deploy::new(
+                    timestamp,
+                    TimeDiff::from_seconds(100),
+                    1,
+                    vec![],
+                    chainspec.network_config.name.clone(),
+                    payment.clone(),
+                    session,
+                    &secret_key,
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn nodes_should_agree_on_state_across_eras() {
+        let (chainspec, chainspec_raw_bytes) =
+            <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+        let chainspec = Arc::new(chainspec);
+        let mut simulation =
+            NetworkSimulation::new(NODE_COUNT, Arc::clone(&chainspec), &chainspec_raw_bytes)
+                .unwrap()
+                .with_blocks_per_era(BLOCKS_PER_ERA);
+        assert_eq!(simulation.node_count(), NODE_COUNT);
+        assert_eq!(simulation.next_block_height(), 1);
+        assert_eq!(simulation.era_id(), EraId::new(1));
+
+        for _ in 0..(2 * BLOCKS_PER_ERA) {
+            let timestamp = simulation.next_block_timestamp();
+            let deploys = transfers(&chainspec, timestamp, 5);
+            let block = simulation.execute_block(deploys).unwrap();
+            assert_eq!(*block.state_root_hash(), simulation.state_root_hash());
+        }
+        assert_eq!(simulation.next_block_height(), 2 * BLOCKS_PER_ERA + 1);
+        assert_eq!(simulation.era_id(), EraId::new(3));
+    }
+}
@@ -0,0 +1,379 @@
+//! Snapshots of global state at a finalized block.
+//!
+//! A snapshot bundles the header of a block, the finality signatures of the block and the
+//! validator weights of its era with every trie of the global state under the block's state root
+//! hash.  A joining node given a snapshot of the block of its trusted hash imports the global state
+//! from the snapshot instead of fetching it trie by trie from its peers.
+//!
+//! A snapshot file holds the bincode-encoded [`StateSnapshotHeader`], followed by the tries, each
+//! encoded as `Some(trie)` and with the children of a trie always preceding it, and terminated by
+//! an encoded `None`.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use num::rational::Ratio;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::info;
+
+use casper_execution_engine::{
+    core::engine_state::{self, EngineState, GetEraValidatorsError, GetEraValidatorsRequest},
+    shared::newtypes::CorrelationId,
+    storage::{error::Error as StorageError, global_state::lmdb::LmdbGlobalState, trie::TrieRaw},
+};
+use casper_hashing::Digest;
+use casper_types::{crypto, EraId, PublicKey, U512};
+
+use crate::{
+    effect::{
+        requests::{ContractRuntimeRequest, StorageRequest},
+        EffectBuilder,
+    },
+    types::{BlockHash, BlockHeader, BlockSignatures},
+    utils::{self, BlockSignatureError},
+};
+
+use super::EraValidatorsRequest;
+
+/// An error creating, verifying or importing a state snapshot.
+#[derive(Debug, Error)]
+pub(crate) enum StateSnapshotError {
+    /// Error reading or writing the snapshot file.
+    #[error("i/o error: {0}")]
+    Io(#[from] io::Error),
+    /// Error encoding or decoding the snapshot file.
+    #[error("encoding error: {0}")]
+    Encoding(#[from] bincode::Error),
+    /// Error reading global state.
+    #[error("global state error: {0}")]
+    Storage(#[from] StorageError),
+    /// Error putting a trie of the snapshot into global state.
+    #[error("engine state error: {0}")]
+    EngineState(#[from] engine_state::Error),
+    /// Error reading the era validators from global state.
+    #[error("era validators error: {0}")]
+    EraValidators(#[from] GetEraValidatorsError),
+    /// A snapshot was given without a trusted hash to verify it against.
+    #[error("a trusted hash is required to import a state snapshot")]
+    NoTrustedHash,
+    /// There is no complete block in storage to create a snapshot of.
+    #[error("no complete block available")]
+    NoCompleteBlock,
+    /// The global state of the block is not in the trie store.
+    #[error("state root hash {0} not found")]
+    RootNotFound(Digest),
+    /// The snapshot is of a different block than the trusted one.
+    #[error("snapshot of block {block_hash} does not match trusted hash {trusted_hash}")]
+    UntrustedBlock {
+        /// The trusted hash.
+        trusted_hash: BlockHash,
+        /// The hash of the block of the snapshot.
+        block_hash: BlockHash,
+    },
+    /// The signatures of the snapshot are not of its block.
+    #[error("finality signatures do not belong to block {0}")]
+    SignaturesMismatch(BlockHash),
+    /// A signature of the snapshot is invalid.
+    #[error("invalid finality signature: {0}")]
+    InvalidSignature(crypto::Error),
+    /// The signatures of the snapshot don't prove the finality of its block.
+    #[error(transparent)]
+    InsufficientFinality(#[from] BlockSignatureError),
+    /// The imported global state has no validator weights for the era of the block.
+    #[error("no validator weights for era {0} in global state")]
+    ValidatorWeightsNotFound(EraId),
+    /// The validator weights of the snapshot differ from the ones in the imported global state.
+    #[error("validator weights for era {0} do not match global state")]
+    ValidatorWeightsMismatch(EraId),
+}
+
+/// The metadata of a state snapshot, proving the finality of its block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct StateSnapshotHeader {
+    block_header: BlockHeader,
+    block_signatures: BlockSignatures,
+    era_validator_weights: BTreeMap<PublicKey, U512>,
+}
+
+impl StateSnapshotHeader {
+    /// Creates a new snapshot header.
+    pub(crate) fn new(
+        block_header: BlockHeader,
+        block_signatures: BlockSignatures,
+        era_validator_weights: BTreeMap<PublicKey, U512>,
+    ) -> Self {
+        StateSnapshotHeader {
+            block_header,
+            block_signatures,
+            era_validator_weights,
+        }
+    }
+
+    /// Returns the header of the block of the snapshot.
+    pub(crate) fn block_header(&self) -> &BlockHeader {
+        &self.block_header
+    }
+
+    /// Checks that the snapshot is of the trusted block, and that its signatures are valid and
+    /// carry enough weight of the era's validators to prove the finality of the block.
+    ///
+    /// The validator weights themselves can only be checked against global state, once it is
+    /// imported.
+    pub(crate) fn verify(
+        &self,
+        trusted_hash: BlockHash,
+        finality_threshold_fraction: Ratio<u64>,
+    ) -> Result<(), StateSnapshotError> {
+        let block_hash = self.block_header.block_hash();
+        if block_hash != trusted_hash {
+            return Err(StateSnapshotError::UntrustedBlock {
+                trusted_hash,
+                block_hash,
+            });
+        }
+        if self.block_signatures.block_hash != block_hash
+            || self.block_signatures.era_id != self.block_header.era_id()
+        {
+            return Err(StateSnapshotError::SignaturesMismatch(block_hash));
+        }
+        self.block_signatures
+            .verify()
+            .map_err(StateSnapshotError::InvalidSignature)?;
+        utils::check_sufficient_block_signatures(
+            &self.era_validator_weights,
+            finality_threshold_fraction,
+            Some(&self.block_signatures),
+        )?;
+        Ok(())
+    }
+}
+
+/// Writes a snapshot of the global state under the state root hash of `header`'s block to `path`,
+/// returning the number of tries written.
+pub(super) fn write_state_snapshot(
+    engine_state: &EngineState<LmdbGlobalState>,
+    header: &StateSnapshotHeader,
+    path: &Path,
+) -> Result<usize, StateSnapshotError> {
+    let state_root_hash = *header.block_header.state_root_hash();
+    let mut writer = BufWriter::new(File::create(path)?);
+    bincode::serialize_into(&mut writer, header)?;
+
+    let mut trie_count = 0;
+    let found = engine_state.get_state().export_tries(
+        CorrelationId::new(),
+        state_root_hash,
+        |trie_raw| {
+            bincode::serialize_into(&mut writer, &Some(trie_raw))?;
+            trie_count += 1;
+            Ok::<_, StateSnapshotError>(())
+        },
+    )?;
+    if !found {
+        return Err(StateSnapshotError::RootNotFound(state_root_hash));
+    }
+    bincode::serialize_into(&mut writer, &None::<TrieRaw>)?;
+    writer.flush()?;
+
+    info!(
+        block_hash = %header.block_header.block_hash(),
+        %state_root_hash,
+        trie_count,
+        path = %path.display(),
+        "wrote state snapshot"
+    );
+    Ok(trie_count)
+}
+
+/// Imports the global state of the snapshot at `path` after checking it is of the trusted block
+/// and proven final.
+///
+/// Tries are only put into global state once all their children are present, so the state root
+/// hash of the block being present afterwards means its global state is complete.  The validator
+/// weights of the snapshot are then checked against the imported global state.
+pub(super) fn read_state_snapshot(
+    engine_state: &EngineState<LmdbGlobalState>,
+    path: &Path,
+    trusted_hash: BlockHash,
+    finality_threshold_fraction: Ratio<u64>,
+) -> Result<StateSnapshotHeader, StateSnapshotError> {
+    let correlation_id = CorrelationId::new();
+    let mut reader = BufReader::new(File::open(path)?);
+    let header: StateSnapshotHeader = bincode::deserialize_from(&mut reader)?;
+    header.verify(trusted_hash, finality_threshold_fraction)?;
+
+    let mut trie_count = 0;
+    while let Some(trie_raw) = bincode::deserialize_from::<_, Option<TrieRaw>>(&mut reader)? {
+        engine_state.put_trie_if_all_children_present(correlation_id, trie_raw.inner())?;
+        trie_count += 1;
+    }
+
+    let state_root_hash = *header.block_header.state_root_hash();
+    if engine_state
+        .get_trie_full(correlation_id, state_root_hash)?
+        .is_none()
+    {
+        return Err(StateSnapshotError::RootNotFound(state_root_hash));
+    }
+
+    let era_id = header.block_header.era_id();
+    let request =
+        GetEraValidatorsRequest::new(state_root_hash, header.block_header.protocol_version());
+    let era_validators = engine_state.get_era_validators(correlation_id, None, request)?;
+    match era_validators.get(&era_id) {
+        Some(weights) if *weights == header.era_validator_weights => {}
+        Some(_) => return Err(StateSnapshotError::ValidatorWeightsMismatch(era_id)),
+        None => return Err(StateSnapshotError::ValidatorWeightsNotFound(era_id)),
+    }
+
+    info!(
+        block_hash = %trusted_hash,
+        %state_root_hash,
+        trie_count,
+        path = %path.display(),
+        "imported state snapshot"
+    );
+    Ok(header)
+}
+
+/// Writes a snapshot of the global state of the highest complete block to `path` on the node's
+/// host, returning the header of the snapshot.
+///
+/// A switch block's global state no longer holds the validator weights of its own era, so the
+/// parent of a switch block is used instead.
+pub(crate) async fn export_state_snapshot<REv>(
+    effect_builder: EffectBuilder<REv>,
+    path: PathBuf,
+) -> Result<StateSnapshotHeader, StateSnapshotError>
+where
+    REv: From<StorageRequest> + From<ContractRuntimeRequest>,
+{
+    let mut block_with_metadata = effect_builder
+        .get_highest_block_with_metadata_from_storage(true)
+        .await
+        .ok_or(StateSnapshotError::NoCompleteBlock)?;
+    if block_with_metadata.block.header().is_switch_block() {
+        let parent_hash = *block_with_metadata.block.header().parent_hash();
+        block_with_metadata = effect_builder
+            .get_block_with_metadata_from_storage(parent_hash, true)
+            .await
+            .ok_or(StateSnapshotError::NoCompleteBlock)?;
+    }
+    let block_header = block_with_metadata.block.header().clone();
+
+    let request = EraValidatorsRequest::new(
+        *block_header.state_root_hash(),
+        block_header.protocol_version(),
+    );
+    let era_id = block_header.era_id();
+    let era_validator_weights = effect_builder
+        .get_era_validators_from_contract_runtime(request)
+        .await?
+        .remove(&era_id)
+        .ok_or(StateSnapshotError::ValidatorWeightsNotFound(era_id))?;
+
+    let header = StateSnapshotHeader::new(
+        block_header,
+        block_with_metadata.block_signatures,
+        era_validator_weights,
+    );
+    effect_builder
+        .write_state_snapshot(header.clone(), path)
+        .await?;
+    Ok(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{testing::TestRng, SecretKey};
+
+    use super::*;
+    use crate::types::{Block, FinalitySignature};
+
+    const VALIDATOR_COUNT: usize = 4;
+
+    fn validators(rng: &mut TestRng) -> Vec<(SecretKey, PublicKey)> {
+        (0..VALIDATOR_COUNT)
+            .map(|_| {
+                let secret_key = SecretKey::random(rng);
+                let public_key = PublicKey::from(&secret_key);
+                (secret_key, public_key)
+            })
+            .collect()
+    }
+
+    fn snapshot_header(
+        block: &Block,
+        validators: &[(SecretKey, PublicKey)],
+        signer_count: usize,
+    ) -> StateSnapshotHeader {
+        let era_id = block.header().era_id();
+        let mut block_signatures = BlockSignatures::new(*block.hash(), era_id);
+        for (secret_key, public_key) in validators.iter().take(signer_count) {
+            let signature =
+                FinalitySignature::create(*block.hash(), era_id, secret_key, public_key.clone());
+            block_signatures.insert_proof(public_key.clone(), signature.signature);
+        }
+        let era_validator_weights = validators
+            .iter()
+            .map(|(_, public_key)| (public_key.clone(), U512::from(10)))
+            .collect();
+        StateSnapshotHeader::new(
+            block.header().clone(),
+            block_signatures,
+            era_validator_weights,
+        )
+    }
+
+    #[test]
+    fn should_verify_snapshot_of_trusted_final_block() {
+        let mut rng = TestRng::new();
+        let validators = validators(&mut rng);
+        let block = Block::random(&mut rng);
+        let fault_tolerance_fraction = Ratio::new(1, 3);
+
+        let header = snapshot_header(&block, &validators, VALIDATOR_COUNT);
+        assert!(header
+            .verify(*block.hash(), fault_tolerance_fraction)
+            .is_ok());
+
+        let other_hash = *Block::random(&mut rng).hash();
+        assert!(matches!(
+            header.verify(other_hash, fault_tolerance_fraction),
+            Err(StateSnapshotError::UntrustedBlock { .. })
+        ));
+    }
+
+    #[test]
+    fn should_reject_snapshot_without_finality() {
+        let mut rng = TestRng::new();
+        let validators = validators(&mut rng);
+        let block = Block::random(&mut rng);
+
+        let header = snapshot_header(&block, &validators, 1);
+        assert!(matches!(
+            header.verify(*block.hash(), Ratio::new(1, 3)),
+            Err(StateSnapshotError::InsufficientFinality(_))
+        ));
+    }
+
+    #[test]
+    fn should_reject_snapshot_with_signatures_of_other_block() {
+        let mut rng = TestRng::new();
+        let validators = validators(&mut rng);
+        let block = Block::random(&mut rng);
+
+        let mut header = snapshot_header(&block, &validators, VALIDATOR_COUNT);
+        header.block_signatures =
+            snapshot_header(&Block::random(&mut rng), &validators, 4).block_signatures;
+        assert!(matches!(
+            header.verify(*block.hash(), Ratio::new(1, 3)),
+            Err(StateSnapshotError::SignaturesMismatch(_))
+        ));
+    }
+}
@@ -6,7 +6,9 @@ use rand::RngCore;
 use serde::Serialize;
 use tempfile::TempDir;
 
-use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_execution_engine::core::engine_state::{
+    engine_config::Quirk, BlockGasUtilization, ExecutableDeployItem, GetBlockGasUtilizationRequest,
+};
 use casper_types::{runtime_args, EraId, PublicKey, RuntimeArgs, SecretKey, TimeDiff, U512};
 
 use super::*;
@@ -113,9 +115,16 @@ impl reactor::Reactor for Reactor {
             chainspec.system_costs_config,
             chainspec.core_config.max_associated_keys,
             chainspec.core_config.max_runtime_call_stack_height,
+            chainspec.core_config.max_contract_call_stack_height,
+            chainspec.core_config.max_effects_size_bytes,
             chainspec.core_config.minimum_delegation_amount,
             chainspec.protocol_config.activation_point,
             chainspec.core_config.prune_batch_size,
+            chainspec.deploy_config.deprecated_items,
+            chainspec.network_config.chain_id,
+            chainspec.network_config.chain_bound_approvals,
+            chainspec.deploy_config.block_gas_limit,
+            chainspec.deploy_config.block_max_gas_per_account,
             chainspec.core_config.strict_argument_checking,
             chainspec.core_config.vesting_schedule_period.millis(),
             Some(chainspec.core_config.max_delegators_per_validator),
@@ -125,6 +134,16 @@ impl reactor::Reactor for Reactor {
             chainspec.core_config.allow_unrestricted_transfers,
             chainspec.core_config.refund_handling,
             chainspec.core_config.fee_handling,
+            chainspec.core_config.fee_distribution.clone(),
+            chainspec.core_config.existential_deposit,
+            chainspec.core_config.existential_deposit_handling,
+            chainspec.core_config.storage_refund,
+            chainspec.core_config.delegation_rate_change_cooldown,
+            chainspec.core_config.max_delegation_rate_increase,
+            chainspec.core_config.disabled_auction_entry_points.clone(),
+            chainspec.core_config.enable_purse_ownership_index,
+            chainspec.core_config.compress_contract_wasm,
+            &chainspec.core_config.compatibility_modes,
         )?;
 
         let reactor = Reactor {
@@ -392,3 +411,230 @@ async fn should_not_set_shared_pre_state_to_lower_block_height() {
         next_block_height
     );
 }
+
+/// Creates a deploy from node-1's main account, paying with the given payment arguments.
+fn node_1_deploy(
+    chainspec: &Chainspec,
+    payment_args: RuntimeArgs,
+    session: ExecutableDeployItem,
+) -> Deploy {
+    let node_1_secret_key = SecretKey::from_file(
+        RESOURCES_PATH
+            .join("local")
+            .join("secret_keys")
+            .join("node-1.pem"),
+    )
+    .unwrap();
+    let payment = ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: payment_args,
+    };
+    Deploy::new(
+        Timestamp::now(),
+        TimeDiff::from_seconds(100),
+        1,
+        vec![],
+        chainspec.network_config.name.clone(),
+        payment,
+        session,
+        &node_1_secret_key,
+        None,
+    )
+}
+
+/// Creates a transfer from node-1's main account to a random public key.
+fn node_1_transfer(chainspec: &Chainspec, rng: &mut NodeRng) -> Deploy {
+    let payment_args = runtime_args! {
+      "amount" => U512::from(chainspec.system_costs_config.wasmless_transfer_cost()),
+    };
+    let session = ExecutableDeployItem::Transfer {
+        args: runtime_args! {
+          "amount" => U512::from(chainspec.deploy_config.native_transfer_minimum_motes),
+          "target" => PublicKey::random(rng),
+          "id" => Some(9_u64),
+        },
+    };
+    node_1_deploy(chainspec, payment_args, session)
+}
+
+/// Commits genesis and executes the first block holding the deploys built by `make_deploys`,
+/// returning the deploys' execution results and the gas utilization recorded under the block's
+/// state root hash.
+async fn execute_first_block(
+    chainspec: Chainspec,
+    make_deploys: impl FnOnce(&Chainspec, &mut NodeRng) -> Vec<Deploy>,
+) -> (Vec<ExecutionResult>, Option<BlockGasUtilization>) {
+    let (_, chainspec_raw_bytes) = <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+    let chainspec = Arc::new(chainspec);
+    let chainspec_raw_bytes = Arc::new(chainspec_raw_bytes);
+
+    let mut rng = crate::new_rng();
+    let rng = &mut rng;
+
+    let runner: Runner<ConditionCheckReactor<Reactor>> = Runner::new(
+        Config::default(),
+        Arc::clone(&chainspec),
+        Arc::clone(&chainspec_raw_bytes),
+        rng,
+    )
+    .await
+    .unwrap();
+    let contract_runtime = &runner.reactor().inner().contract_runtime;
+
+    let post_commit_genesis_state_hash = contract_runtime
+        .commit_genesis(chainspec.as_ref(), chainspec_raw_bytes.as_ref())
+        .unwrap()
+        .post_state_hash;
+
+    let deploys = make_deploys(chainspec.as_ref(), rng);
+    let block_payload = BlockPayload::new(
+        vec![],
+        deploys.iter().map(DeployHashWithApprovals::from).collect(),
+        vec![],
+        true,
+    );
+    let finalized_block = FinalizedBlock::new(
+        block_payload,
+        None,
+        Timestamp::now(),
+        EraId::new(0),
+        0,
+        PublicKey::System,
+    );
+
+    let BlockAndExecutionResults {
+        block,
+        execution_results,
+        ..
+    } = execute_finalized_block(
+        contract_runtime.engine_state.as_ref(),
+        None,
+        chainspec.protocol_version(),
+        ExecutionPreState::new(
+            0,
+            post_commit_genesis_state_hash,
+            BlockHash::default(),
+            Digest::default(),
+        ),
+        finalized_block,
+        deploys,
+        chainspec.protocol_config.activation_point.era_id(),
+        0,
+        chainspec.core_config.prune_batch_size,
+        chainspec.deploy_config.deprecated_items,
+        chainspec.network_config.chain_id,
+        chainspec.network_config.chain_bound_approvals,
+        chainspec.deploy_config.block_gas_limit,
+        chainspec.deploy_config.block_max_gas_per_account,
+        false,
+        false,
+    )
+    .unwrap();
+
+    let gas_utilization = contract_runtime
+        .engine_state
+        .get_block_gas_utilization(
+            CorrelationId::new(),
+            GetBlockGasUtilizationRequest::new(*block.state_root_hash()),
+        )
+        .unwrap()
+        .into_success()
+        .unwrap();
+
+    let execution_results = execution_results
+        .into_iter()
+        .map(|(_, _, execution_result)| execution_result)
+        .collect();
+    (execution_results, gas_utilization)
+}
+
+/// Executes a block of transfers, returning the summed cost of the deploys and the recorded gas
+/// utilization.
+async fn execute_transfers_and_get_gas_utilization(
+    unrecorded_block_gas_utilization: bool,
+) -> (U512, Option<BlockGasUtilization>) {
+    let (mut chainspec, _) = <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+    if unrecorded_block_gas_utilization {
+        chainspec
+            .core_config
+            .compatibility_modes
+            .push(CompatibilityModeConfig {
+                protocol_version: chainspec.protocol_version(),
+                unavailable_host_functions: Default::default(),
+                quirks: [Quirk::UnrecordedBlockGasUtilization].into_iter().collect(),
+            });
+    }
+
+    let (execution_results, gas_utilization) = execute_first_block(chainspec, |chainspec, rng| {
+        std::iter::repeat_with(|| node_1_transfer(chainspec, rng))
+            .take(3)
+            .collect()
+    })
+    .await;
+
+    let summed_cost = execution_results
+        .into_iter()
+        .map(|execution_result| match execution_result {
+            ExecutionResult::Success { cost, .. } => cost,
+            ExecutionResult::Failure { error_message, .. } => {
+                panic!("deploy failed: {}", error_message)
+            }
+        })
+        .fold(U512::zero(), |summed_cost, cost| summed_cost + cost);
+
+    (summed_cost, gas_utilization)
+}
+
+#[tokio::test]
+async fn should_record_block_gas_utilization() {
+    testing::init_logging();
+
+    let (summed_cost, gas_utilization) = execute_transfers_and_get_gas_utilization(false).await;
+
+    assert!(!summed_cost.is_zero());
+    let gas_utilization = gas_utilization.expect("should record the block gas utilization");
+    assert_eq!(gas_utilization.gas_consumed(), summed_cost);
+}
+
+#[tokio::test]
+async fn should_not_record_block_gas_utilization_under_quirk() {
+    testing::init_logging();
+
+    let (summed_cost, gas_utilization) = execute_transfers_and_get_gas_utilization(true).await;
+
+    assert!(!summed_cost.is_zero());
+    assert_eq!(gas_utilization, None);
+}
+
+#[tokio::test]
+async fn should_count_deploy_without_payment_amount_at_block_gas_limit() {
+    testing::init_logging();
+
+    let (mut chainspec, _) = <(Chainspec, ChainspecRawBytes)>::from_resources("local");
+    chainspec.deploy_config.block_max_gas_per_account = chainspec.deploy_config.block_gas_limit - 1;
+
+    let (execution_results, _) = execute_first_block(chainspec, |chainspec, _rng| {
+        let session = ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: RuntimeArgs::new(),
+        };
+        vec![node_1_deploy(chainspec, RuntimeArgs::new(), session)]
+    })
+    .await;
+
+    match execution_results.as_slice() {
+        [ExecutionResult::Failure {
+            cost,
+            error_message,
+            ..
+        }] => {
+            assert!(cost.is_zero());
+            assert!(
+                error_message.contains("exceed the maximum gas"),
+                "{}",
+                error_message
+            );
+        }
+        other => panic!("unexpected execution results: {:?}", other),
+    }
+}
@@ -3,12 +3,13 @@ use std::{collections::BTreeMap, sync::Arc};
 use datasize::DataSize;
 
 use casper_execution_engine::{
-    core::engine_state::GetEraValidatorsRequest, shared::execution_journal::ExecutionJournal,
+    core::engine_state::{ExecutionWarning, GetEraValidatorsRequest, PurseChanges, StepReport},
+    shared::execution_journal::ExecutionJournal,
 };
 use casper_hashing::Digest;
 use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey, U512};
 
-use crate::types::{ApprovalsHashes, Block, DeployHash, DeployHeader};
+use crate::types::{ApprovalsHashes, Block, DeployExecutionStats, DeployHash, DeployHeader};
 
 /// Request for validator weights for a specific era.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -90,6 +91,8 @@ pub(crate) struct StepEffectAndUpcomingEraValidators {
     pub(crate) upcoming_era_validators: BTreeMap<EraId, BTreeMap<PublicKey, U512>>,
     /// An [`ExecutionJournal`] created by an era ending.
     pub(crate) step_execution_journal: ExecutionJournal,
+    /// What the step did to the auction.
+    pub(crate) step_report: StepReport,
 }
 
 #[doc(hidden)]
@@ -103,6 +106,15 @@ pub struct BlockAndExecutionResults {
     pub(crate) approvals_hashes: Box<ApprovalsHashes>,
     /// The results from executing the deploys in the block.
     pub(crate) execution_results: Vec<(DeployHash, DeployHeader, ExecutionResult)>,
+    /// How long the executed deploys took and how much Wasm memory they used.  Not covered by the
+    /// block's checksums, as they differ between nodes.
+    pub(crate) execution_stats: BTreeMap<DeployHash, DeployExecutionStats>,
+    /// The warnings raised while executing the deploys which raised any.  Not covered by the
+    /// block's checksums.
+    pub(crate) execution_warnings: BTreeMap<DeployHash, Vec<ExecutionWarning>>,
+    /// The purses created and removed by the deploys in this block.  Not covered by the block's
+    /// checksums, nor recorded in global state; published with the block's artifacts instead.
+    pub(crate) purse_changes: PurseChanges,
     /// The [`ExecutionJournal`] and the upcoming validator sets determined by the `step`
     pub(crate) maybe_step_effect_and_upcoming_era_validators:
         Option<StepEffectAndUpcomingEraValidators>,
@@ -11,7 +11,7 @@ use datasize::DataSize;
 use prometheus::Registry;
 use serde::Serialize;
 use thiserror::Error;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, info_span, trace};
 
 use casper_execution_engine::core::engine_state::{
     executable_deploy_item::{
@@ -21,10 +21,10 @@ use casper_execution_engine::core::engine_state::{
 };
 use casper_hashing::Digest;
 use casper_types::{
-    account::{Account, AccountHash},
+    account::{check_approvals, Account, AccountHash, ActionType, ApprovalsFailure},
     system::auction::ARG_AMOUNT,
     Contract, ContractHash, ContractPackage, ContractPackageHash, ContractVersion,
-    ContractVersionKey, Key, ProtocolVersion, Timestamp, U512,
+    ContractVersionKey, EraId, Key, ProtocolVersion, Timestamp, U512,
 };
 
 use crate::{
@@ -207,6 +207,8 @@ impl<REv> ReactorEventT for REv where
 pub struct DeployAcceptor {
     acceptor_config: Config,
     chain_name: String,
+    chain_id: u32,
+    chain_bound_approvals: Option<EraId>,
     protocol_version: ProtocolVersion,
     deploy_config: DeployConfig,
     core_config: CoreConfig,
@@ -224,6 +226,8 @@ impl DeployAcceptor {
         Ok(DeployAcceptor {
             acceptor_config,
             chain_name: chainspec.network_config.name.clone(),
+            chain_id: chainspec.network_config.chain_id,
+            chain_bound_approvals: chainspec.network_config.chain_bound_approvals,
             protocol_version: chainspec.protocol_version(),
             deploy_config: chainspec.deploy_config,
             core_config: chainspec.core_config.clone(),
@@ -406,26 +410,20 @@ impl DeployAcceptor {
                         });
                 }
 
-                if !account.can_authorize(&authorization_keys) {
-                    let error = Error::parameter_failure(
-                        &block_header,
-                        DeployParameterFailure::InvalidAssociatedKeys,
-                    );
-                    debug!(?authorization_keys, "account authorization invalid");
-                    return self.handle_invalid_deploy_result(
-                        effect_builder,
-                        event_metadata,
-                        error,
-                        verification_start_timestamp,
-                    );
-                }
-
-                if !account.can_deploy_with(&authorization_keys) {
-                    let error = Error::parameter_failure(
-                        &block_header,
-                        DeployParameterFailure::InsufficientDeploySignatureWeight,
-                    );
-                    debug!(?authorization_keys, "insufficient deploy signature weight");
+                if let Err(failure) =
+                    check_approvals(&account, &authorization_keys, ActionType::Deployment)
+                {
+                    let parameter_failure = match failure {
+                        ApprovalsFailure::InsufficientWeight { .. } => {
+                            debug!(?authorization_keys, "insufficient deploy signature weight");
+                            DeployParameterFailure::InsufficientDeploySignatureWeight
+                        }
+                        _ => {
+                            debug!(?authorization_keys, %failure, "account authorization invalid");
+                            DeployParameterFailure::InvalidAssociatedKeys
+                        }
+                    };
+                    let error = Error::parameter_failure(&block_header, parameter_failure);
                     return self.handle_invalid_deploy_result(
                         effect_builder,
                         event_metadata,
@@ -512,6 +510,35 @@ impl DeployAcceptor {
         block_header: Box<BlockHeader>,
         verification_start_timestamp: Timestamp,
     ) -> Effects<Event> {
+        // Deprecated deploy items are checked against the era the deploy could be executed in at
+        // the earliest.
+        if let Err(error) = event_metadata.deploy.is_deprecation_compliant(
+            &self.deploy_config.deprecated_items,
+            block_header.next_block_era_id(),
+        ) {
+            debug!(%error, "deprecated deploy item in deploy");
+            return self.handle_invalid_deploy_result(
+                effect_builder,
+                event_metadata,
+                Error::InvalidDeployConfiguration(error),
+                verification_start_timestamp,
+            );
+        }
+        // So are approvals which aren't bound to the chain.
+        if let Err(error) = event_metadata.deploy.is_approval_binding_compliant(
+            self.chain_id,
+            self.chain_bound_approvals,
+            block_header.next_block_era_id(),
+        ) {
+            debug!(%error, "approval not bound to the chain in deploy");
+            return self.handle_invalid_deploy_result(
+                effect_builder,
+                event_metadata,
+                Error::InvalidDeployConfiguration(error),
+                verification_start_timestamp,
+            );
+        }
+
         let payment = event_metadata.deploy.payment();
         match payment {
             ExecutableDeployItem::Transfer { .. } => {
@@ -895,7 +922,7 @@ impl DeployAcceptor {
         event_metadata: Box<EventMetadata>,
         verification_start_timestamp: Timestamp,
     ) -> Effects<Event> {
-        if let Err(deploy_configuration_failure) = event_metadata.deploy.is_valid() {
+        if let Err(deploy_configuration_failure) = event_metadata.deploy.is_valid(self.chain_id) {
             // The client has submitted a deploy with one or more invalid signatures.
             // Return an error to the RPC component via the responder.
             debug!("deploy is cryptographically invalid");
@@ -1042,6 +1069,12 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
         event: Self::Event,
     ) -> Effects<Self::Event> {
         trace!(?event, "DeployAcceptor: handling event");
+        let _span = info_span!(
+            "accept_deploy",
+            deploy_hash = ?event.deploy_hash().inner(),
+            step = event.step()
+        )
+        .entered();
         match event {
             Event::Accept {
                 deploy,
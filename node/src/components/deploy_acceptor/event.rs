@@ -14,7 +14,7 @@ use super::Source;
 use crate::{
     components::deploy_acceptor::Error,
     effect::Responder,
-    types::{BlockHeader, Deploy},
+    types::{BlockHeader, Deploy, DeployHash},
 };
 
 /// A utility struct to hold duplicated information across events.
@@ -103,6 +103,38 @@ pub(crate) enum Event {
     },
 }
 
+impl Event {
+    /// Returns the hash of the deploy being accepted.
+    pub(crate) fn deploy_hash(&self) -> DeployHash {
+        match self {
+            Event::Accept { deploy, .. } => *deploy.hash(),
+            Event::PutToStorageResult { event_metadata, .. }
+            | Event::StoredFinalizedApprovals { event_metadata, .. }
+            | Event::GetBlockHeaderResult { event_metadata, .. }
+            | Event::GetAccountResult { event_metadata, .. }
+            | Event::GetBalanceResult { event_metadata, .. }
+            | Event::GetContractResult { event_metadata, .. }
+            | Event::GetContractPackageResult { event_metadata, .. } => {
+                *event_metadata.deploy.hash()
+            }
+        }
+    }
+
+    /// Returns the name of the step of the acceptance the event is handled in.
+    pub(crate) fn step(&self) -> &'static str {
+        match self {
+            Event::Accept { .. } => "accept",
+            Event::PutToStorageResult { .. } => "put_to_storage",
+            Event::StoredFinalizedApprovals { .. } => "store_finalized_approvals",
+            Event::GetBlockHeaderResult { .. } => "verify_block_header",
+            Event::GetAccountResult { .. } => "verify_account",
+            Event::GetBalanceResult { .. } => "verify_balance",
+            Event::GetContractResult { .. } => "verify_contract",
+            Event::GetContractPackageResult { .. } => "verify_contract_package",
+        }
+    }
+}
+
 impl Display for Event {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -16,10 +16,10 @@ use futures::FutureExt;
 use itertools::Itertools;
 use prometheus::Registry;
 use smallvec::smallvec;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, info_span, warn};
 
 use casper_hashing::Digest;
-use casper_types::Timestamp;
+use casper_types::{PublicKey, Timestamp};
 
 use crate::{
     components::{
@@ -52,6 +52,83 @@ const COMPONENT_NAME: &str = "deploy_buffer";
 
 type FootprintAndApprovals = (DeployFootprint, BTreeSet<Approval>);
 
+/// A deploy which can be proposed, along with its footprint.
+type ProposableDeploy = (DeployHashWithApprovals, DeployFootprint);
+
+/// A lane, i.e. whether a deploy is a transfer, along with the account which sent the deploy.
+type LaneAndSender = (bool, PublicKey);
+
+/// The proposable deploys sent by one account in one lane.
+///
+/// The deploys are grouped by body hash, and the groups take turns, so that a burst of identical
+/// deploys doesn't crowd out the sender's other deploys.
+#[derive(Default)]
+struct SenderQueue {
+    buckets: HashMap<Digest, Vec<(DeployHashWithApprovals, DeployFootprint)>>,
+    body_hashes: VecDeque<Digest>,
+}
+
+impl SenderQueue {
+    fn push(&mut self, body_hash: Digest, deploy: (DeployHashWithApprovals, DeployFootprint)) {
+        let bucket = self.buckets.entry(body_hash).or_default();
+        if bucket.is_empty() {
+            self.body_hashes.push_back(body_hash);
+        }
+        bucket.push(deploy);
+    }
+
+    fn pop(&mut self) -> Option<(DeployHashWithApprovals, DeployFootprint)> {
+        while let Some(body_hash) = self.body_hashes.pop_front() {
+            let Some(deploy) = self.buckets.get_mut(&body_hash).and_then(Vec::pop) else {
+                continue;
+            };
+            // bucket wasn't empty - push the hash back into the queue to be processed again on
+            // the next pass
+            self.body_hashes.push_back(body_hash);
+            return Some(deploy);
+        }
+        None
+    }
+}
+
+/// Splits the given deploys into those making up the most deploys and transfers which fit into
+/// the block's payload size limit, and the rest.
+///
+/// With every deploy counting the same, taking the smallest ones first solves the knapsack, while
+/// the count limits of the lanes and the size limit of the deploy lane bound which ones are
+/// taken.  This keeps a few large deploys from using up the space which could hold many transfers.
+fn select_by_payload_size(
+    mut deploys: Vec<ProposableDeploy>,
+    deploy_config: &DeployConfig,
+    max_payload_size: u32,
+) -> (Vec<ProposableDeploy>, Vec<ProposableDeploy>) {
+    deploys.sort_by_key(|(_, footprint)| footprint.size_estimate);
+    let mut remaining_payload_size = max_payload_size as usize;
+    let mut remaining_deploys_size = deploy_config.max_block_size as usize;
+    let mut remaining_transfer_count = deploy_config.block_max_transfer_count;
+    let mut remaining_deploy_count = deploy_config.block_max_deploy_count;
+    deploys.into_iter().partition(|(_, footprint)| {
+        let size = footprint.size_estimate;
+        if size > remaining_payload_size {
+            return false;
+        }
+        if footprint.is_transfer {
+            if remaining_transfer_count == 0 {
+                return false;
+            }
+            remaining_transfer_count -= 1;
+        } else {
+            if remaining_deploy_count == 0 || size > remaining_deploys_size {
+                return false;
+            }
+            remaining_deploy_count -= 1;
+            remaining_deploys_size -= size;
+        }
+        remaining_payload_size -= size;
+        true
+    })
+}
+
 #[derive(DataSize, Debug)]
 pub(crate) struct DeployBuffer {
     state: ComponentState,
@@ -323,9 +400,15 @@ impl DeployBuffer {
             .collect()
     }
 
+    #[cfg(test)]
     fn buckets(&mut self) -> HashMap<Digest, Vec<(DeployHashWithApprovals, DeployFootprint)>> {
-        let proposable = self.proposable();
+        Self::group_by_body_hash(self.proposable())
+    }
 
+    /// Groups the given deploys by body hash.
+    fn group_by_body_hash(
+        proposable: Vec<(DeployHashWithApprovals, DeployFootprint)>,
+    ) -> HashMap<Digest, Vec<(DeployHashWithApprovals, DeployFootprint)>> {
         let mut buckets: HashMap<Digest, Vec<(DeployHashWithApprovals, DeployFootprint)>> =
             HashMap::new();
 
@@ -339,7 +422,29 @@ impl DeployBuffer {
         buckets
     }
 
+    /// Returns the given deploys grouped by lane and sending account.
+    fn sender_queues(
+        proposable: Vec<(DeployHashWithApprovals, DeployFootprint)>,
+    ) -> HashMap<LaneAndSender, SenderQueue> {
+        let mut sender_queues: HashMap<LaneAndSender, SenderQueue> = HashMap::new();
+        for (body_hash, bucket) in Self::group_by_body_hash(proposable) {
+            for (with_approvals, footprint) in bucket {
+                let lane_and_sender = (footprint.is_transfer, footprint.header.account().clone());
+                sender_queues
+                    .entry(lane_and_sender)
+                    .or_default()
+                    .push(body_hash, (with_approvals, footprint));
+            }
+        }
+        sender_queues
+    }
+
     /// Returns a right-sized payload of deploys that can be proposed.
+    ///
+    /// If the block's payload size is limited, the deploys making up the most deploys and
+    /// transfers within that limit are tried first, and the rest only fill up what is left of the
+    /// block.  Either way, the senders within each lane take turns, so that a burst of deploys from
+    /// one account can't starve the deploys of other accounts.
     fn appendable_block(
         &mut self,
         timestamp: Timestamp,
@@ -350,20 +455,74 @@ impl DeployBuffer {
             return ret;
         }
         let mut holds = HashSet::new();
+
+        let proposable = self.proposable();
+        let passes = match self.deploy_config.block_max_payload_size {
+            Some(max_payload_size) => {
+                let (selected, rest) =
+                    select_by_payload_size(proposable, &self.deploy_config, max_payload_size);
+                vec![selected, rest]
+            }
+            None => vec![proposable],
+        };
+        for deploys in passes {
+            if !self.fill_appendable_block(&mut ret, &mut holds, deploys, request_expiry) {
+                break;
+            }
+        }
+
+        // put a hold on all proposed deploys / transfers and update metrics
+        match self.hold.entry(timestamp) {
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(holds);
+            }
+            btree_map::Entry::Occupied(mut entry) => {
+                entry.get_mut().extend(holds);
+            }
+        }
+        self.update_all_metrics();
+
+        info!(
+            "produced {}, buffer has {} held, {} dead, {} total",
+            ret,
+            self.hold
+                .values()
+                .map(|deploys| deploys.len())
+                .sum::<usize>(),
+            self.dead.len(),
+            self.buffer.len()
+        );
+
+        ret
+    }
+
+    /// Adds as many of the given deploys to the block as fit, with the senders within each lane
+    /// taking turns.
+    ///
+    /// Returns `false` if the block is full or the request has expired, i.e. no further deploys
+    /// should be tried.
+    fn fill_appendable_block(
+        &mut self,
+        appendable_block: &mut AppendableBlock,
+        holds: &mut HashSet<DeployHash>,
+        deploys: Vec<(DeployHashWithApprovals, DeployFootprint)>,
+        request_expiry: Timestamp,
+    ) -> bool {
+        let timestamp = appendable_block.timestamp();
         let mut have_hit_transfer_limit = false;
         let mut have_hit_deploy_limit = false;
 
-        let mut buckets = self.buckets();
-        let mut body_hashes_queue: VecDeque<_> = buckets.keys().cloned().collect();
-
         #[cfg(test)]
         let mut iter_counter = 0;
         #[cfg(test)]
-        let iter_limit = self.buffer.len() * 4;
+        let iter_limit = deploys.len() * 4;
+
+        let mut sender_queues = Self::sender_queues(deploys);
+        let mut senders_queue: VecDeque<_> = sender_queues.keys().cloned().collect();
 
-        while let Some(body_hash) = body_hashes_queue.pop_front() {
+        while let Some(lane_and_sender) = senders_queue.pop_front() {
             if Timestamp::now() > request_expiry {
-                break;
+                return false;
             }
             #[cfg(test)]
             {
@@ -374,14 +533,15 @@ impl DeployBuffer {
                 );
             }
 
-            let Some((with_approvals, footprint)) =
-                buckets.get_mut(&body_hash).and_then(Vec::<_>::pop)
+            let Some((with_approvals, footprint)) = sender_queues
+                .get_mut(&lane_and_sender)
+                .and_then(SenderQueue::pop)
             else {
                 continue;
             };
-            // bucket wasn't empty - push the hash back into the queue to be processed again on the
-            // next pass
-            body_hashes_queue.push_back(body_hash);
+            // sender's queue wasn't empty - push the sender back into the queue to be processed
+            // again on the next pass
+            senders_queue.push_back(lane_and_sender.clone());
             if footprint.is_transfer && have_hit_transfer_limit {
                 continue;
             }
@@ -389,8 +549,14 @@ impl DeployBuffer {
                 continue;
             }
             let deploy_hash = *with_approvals.deploy_hash();
-            let has_multiple_approvals = with_approvals.approvals().len() > 1;
-            match ret.add(with_approvals, &footprint) {
+            let _span = info_span!(
+                "propose_deploy",
+                deploy_hash = ?deploy_hash.inner(),
+                block_timestamp = %timestamp
+            )
+            .entered();
+            let has_multiple_approvals = footprint.approvals_count > 1;
+            match appendable_block.add(with_approvals, &footprint) {
                 Ok(_) => {
                     debug!(%deploy_hash, "DeployBuffer: proposing deploy");
                     holds.insert(deploy_hash);
@@ -431,7 +597,7 @@ impl DeployBuffer {
                                     ?deploy_hash,
                                     "DeployBuffer: block filled with transfers and deploys"
                                 );
-                                break;
+                                return false;
                             }
                             have_hit_transfer_limit = true;
                         }
@@ -441,50 +607,51 @@ impl DeployBuffer {
                                     ?deploy_hash,
                                     "DeployBuffer: block filled with deploys and transfers"
                                 );
-                                break;
+                                return false;
                             }
                             have_hit_deploy_limit = true;
                         }
+                        AddError::AccountTransferCount | AddError::AccountDeployCount => {
+                            // The sender has used up its share of the lane, but other senders
+                            // might not have.
+                            debug!(
+                                ?deploy_hash,
+                                %error,
+                                "DeployBuffer: sender has reached its limit for this block"
+                            );
+                            sender_queues.remove(&lane_and_sender);
+                        }
                         AddError::ApprovalCount if has_multiple_approvals => {
                             // keep iterating, we can maybe fit in a deploy with fewer approvals
                         }
-                        AddError::ApprovalCount | AddError::GasLimit | AddError::BlockSize => {
+                        AddError::GasLimit
+                        | AddError::AccountGasLimit
+                        | AddError::BlockSize
+                        | AddError::PayloadSize => {
+                            // The appendable block only charges deploys against the size and gas
+                            // limits, so transfers might still fit, as might smaller or cheaper
+                            // deploys, or deploys from other accounts.  Keep iterating so a single
+                            // large deploy can't crowd out the rest of the block.
+                            debug!(
+                                ?deploy_hash,
+                                %error,
+                                "DeployBuffer: deploy doesn't fit into the remaining block limits"
+                            );
+                        }
+                        AddError::ApprovalCount => {
                             info!(
                                 ?deploy_hash,
                                 %error,
                                 "DeployBuffer: a block limit has been reached"
                             );
                             // a block limit has been reached
-                            break;
+                            return false;
                         }
                     }
                 }
             }
         }
-
-        // put a hold on all proposed deploys / transfers and update metrics
-        match self.hold.entry(timestamp) {
-            btree_map::Entry::Vacant(entry) => {
-                entry.insert(holds);
-            }
-            btree_map::Entry::Occupied(mut entry) => {
-                entry.get_mut().extend(holds);
-            }
-        }
-        self.update_all_metrics();
-
-        info!(
-            "produced {}, buffer has {} held, {} dead, {} total",
-            ret,
-            self.hold
-                .values()
-                .map(|deploys| deploys.len())
-                .sum::<usize>(),
-            self.dead.len(),
-            self.buffer.len()
-        );
-
-        ret
+        true
     }
 
     /// Updates all deploy count metrics based on the size of the internal structs.
@@ -51,7 +51,7 @@ fn create_valid_deploys(
                     unimplemented!();
                 }
                 let deploy = Deploy::random_with_valid_session_package_by_name(rng);
-                assert!(deploy.is_valid().is_ok());
+                assert!(deploy.is_valid(0).is_ok());
                 deploys.push(deploy);
             }
             DeployType::Random => {
@@ -292,6 +292,138 @@ fn get_appendable_block_with_random_deploys() {
     );
 }
 
+#[test]
+fn should_propose_transfers_when_deploys_exceed_block_size() {
+    let mut rng = TestRng::new();
+    let deploy_config = DeployConfig {
+        // No standard deploy fits, while transfers don't count towards the block size.
+        max_block_size: 0,
+        block_max_deploy_count: 10,
+        block_max_transfer_count: 20,
+        block_max_approval_count: 30,
+        ..Default::default()
+    };
+    let mut deploy_buffer =
+        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+
+    let deploys = create_valid_deploys(&mut rng, 10, DeployType::Standard, None, None);
+    let transfers = create_valid_deploys(&mut rng, 10, DeployType::Transfer, None, None);
+    deploys
+        .iter()
+        .chain(transfers.iter())
+        .for_each(|deploy| deploy_buffer.register_deploy(deploy.clone()));
+
+    let timestamp = Timestamp::now();
+    let expiry = timestamp.saturating_add(TimeDiff::from_seconds(1));
+    let appendable_block = deploy_buffer.appendable_block(timestamp, expiry);
+
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert_eq!(proposed.len(), transfers.len());
+    assert!(transfers
+        .iter()
+        .all(|transfer| proposed.contains(transfer.hash())));
+    assert_container_sizes(
+        &deploy_buffer,
+        deploys.len() + transfers.len(),
+        0,
+        transfers.len(),
+    );
+}
+
+#[test]
+fn should_not_let_large_deploys_crowd_out_transfers() {
+    let mut rng = TestRng::new();
+    let deploys = create_valid_deploys(&mut rng, 5, DeployType::Standard, None, None);
+    let transfers = create_valid_deploys(&mut rng, 10, DeployType::Transfer, None, None);
+    let transfers_size = transfers
+        .iter()
+        .map(|transfer| transfer.footprint().unwrap().size_estimate)
+        .sum::<usize>();
+    // Each large deploy takes as much space as all the transfers together.
+    let large_deploy_size = transfers_size;
+    let deploy_config = DeployConfig {
+        // Room for all the transfers and one of the large deploys, or for two large deploys.
+        block_max_payload_size: Some((transfers_size + large_deploy_size) as u32),
+        block_max_deploy_count: 10,
+        block_max_transfer_count: 20,
+        block_max_approval_count: 30,
+        ..Default::default()
+    };
+    let mut deploy_buffer =
+        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+
+    deploys
+        .iter()
+        .chain(transfers.iter())
+        .for_each(|deploy| deploy_buffer.register_deploy(deploy.clone()));
+    for deploy in &deploys {
+        let (_, maybe_data) = deploy_buffer.buffer.get_mut(deploy.hash()).unwrap();
+        let (footprint, _) = maybe_data.as_mut().unwrap();
+        footprint.size_estimate = large_deploy_size;
+    }
+
+    let timestamp = Timestamp::now();
+    let expiry = timestamp.saturating_add(TimeDiff::from_seconds(1));
+    let appendable_block = deploy_buffer.appendable_block(timestamp, expiry);
+
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert!(transfers
+        .iter()
+        .all(|transfer| proposed.contains(transfer.hash())));
+    assert_eq!(proposed.len(), transfers.len() + 1);
+}
+
+#[test]
+fn should_select_most_deploys_within_payload_size() {
+    let mut rng = TestRng::new();
+    let deploy_config = DeployConfig {
+        block_max_deploy_count: 2,
+        block_max_transfer_count: 3,
+        ..Default::default()
+    };
+    let deploy_with_size = |rng: &mut TestRng, deploy_type: DeployType, size: usize| {
+        let deploy = create_valid_deploys(rng, 1, deploy_type, None, None).remove(0);
+        let mut footprint = deploy.footprint().unwrap();
+        footprint.size_estimate = size;
+        (
+            DeployHashWithApprovals::new(*deploy.hash(), deploy.approvals().clone()),
+            footprint,
+        )
+    };
+    let small_deploys: Vec<_> =
+        iter::repeat_with(|| deploy_with_size(&mut rng, DeployType::Standard, 10))
+            .take(3)
+            .collect();
+    let small_transfers: Vec<_> =
+        iter::repeat_with(|| deploy_with_size(&mut rng, DeployType::Transfer, 10))
+            .take(4)
+            .collect();
+    let large_transfer = deploy_with_size(&mut rng, DeployType::Transfer, 30);
+
+    let deploys = small_deploys
+        .iter()
+        .chain(small_transfers.iter())
+        .chain(iter::once(&large_transfer))
+        .cloned()
+        .collect();
+    let (selected, rest) = select_by_payload_size(deploys, &deploy_config, 60);
+
+    // The lane counts bound the selection to 2 deploys and 3 transfers, and the large transfer
+    // would take the space of 3 small ones.
+    assert_eq!(selected.len(), 5);
+    assert_eq!(rest.len(), 3);
+    assert_eq!(
+        selected
+            .iter()
+            .filter(|(_, footprint)| footprint.is_transfer)
+            .count(),
+        3
+    );
+    assert!(selected
+        .iter()
+        .all(|(_, footprint)| footprint.size_estimate == 10));
+}
+
 fn get_appendable_block(
     rng: &mut TestRng,
     deploy_buffer: &mut DeployBuffer,
@@ -510,6 +642,94 @@ fn should_have_one_bucket_per_distinct_body_hash() {
     assert!(buckets.len() == 3, "should be 3 buckets");
 }
 
+fn register_deploys_from(
+    deploy_buffer: &mut DeployBuffer,
+    rng: &mut TestRng,
+    secret_key: &SecretKey,
+    count: usize,
+) -> Vec<DeployHash> {
+    (0..count)
+        .map(|_| {
+            // Distinct contract names, so every deploy has its own body hash.
+            let contract_name = format!("{}", rng.gen::<u64>());
+            let deploy = Deploy::random_contract_by_name(
+                rng,
+                Some(
+                    SecretKey::from_pem(secret_key.to_pem().expect("should pemify"))
+                        .expect("should un-pemify"),
+                ),
+                Some(contract_name),
+                None,
+                Some(Timestamp::now()),
+                Some(TimeDiff::from_seconds(30)),
+            );
+            let deploy_hash = *deploy.hash();
+            deploy_buffer.register_deploy(deploy);
+            deploy_hash
+        })
+        .collect()
+}
+
+#[test]
+fn should_take_turns_between_senders() {
+    let mut rng = TestRng::new();
+    let max_deploy_count = 4;
+    let deploy_config = DeployConfig {
+        block_max_deploy_count: max_deploy_count,
+        block_max_transfer_count: 0,
+        block_max_approval_count: max_deploy_count,
+        ..Default::default()
+    };
+    let mut deploy_buffer =
+        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+
+    // One sender bursts with many more deploys than fit into a block.
+    let bursting_key = SecretKey::random(&mut rng);
+    register_deploys_from(&mut deploy_buffer, &mut rng, &bursting_key, 50);
+    let mut other_deploys = vec![];
+    for _ in 0..2 {
+        let secret_key = SecretKey::random(&mut rng);
+        other_deploys.extend(register_deploys_from(
+            &mut deploy_buffer,
+            &mut rng,
+            &secret_key,
+            1,
+        ));
+    }
+
+    let timestamp = Timestamp::now();
+    let expiry = timestamp.saturating_add(TimeDiff::from_seconds(1));
+    let appendable_block = deploy_buffer.appendable_block(timestamp, expiry);
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert_eq!(proposed.len(), max_deploy_count as usize);
+    assert!(other_deploys
+        .iter()
+        .all(|deploy_hash| proposed.contains(deploy_hash)));
+}
+
+#[test]
+fn should_limit_deploys_per_sender() {
+    let mut rng = TestRng::new();
+    let max_deploy_count = 10;
+    let deploy_config = DeployConfig {
+        block_max_deploy_count: max_deploy_count,
+        block_max_transfer_count: 0,
+        block_max_approval_count: max_deploy_count,
+        block_max_deploys_per_account: Some(3),
+        ..Default::default()
+    };
+    let mut deploy_buffer =
+        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+
+    let secret_key = SecretKey::random(&mut rng);
+    register_deploys_from(&mut deploy_buffer, &mut rng, &secret_key, 20);
+
+    let timestamp = Timestamp::now();
+    let expiry = timestamp.saturating_add(TimeDiff::from_seconds(1));
+    let appendable_block = deploy_buffer.appendable_block(timestamp, expiry);
+    assert_eq!(appendable_block.deploy_and_transfer_set().len(), 3);
+}
+
 #[test]
 fn should_be_empty_if_no_time_until_expiry() {
     let mut rng = TestRng::new();
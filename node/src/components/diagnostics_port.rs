@@ -25,11 +25,14 @@ use crate::{
     effect::{
         announcements::ControlAnnouncement,
         diagnostics_port::DumpConsensusStateRequest,
-        requests::{NetworkInfoRequest, SetNodeStopRequest},
+        requests::{
+            ContractRuntimeRequest, FetcherRequest, NetworkInfoRequest, SetNodeStopRequest,
+            StorageRequest,
+        },
         EffectBuilder, EffectExt, Effects,
     },
     reactor::main_reactor::MainEvent,
-    types::NodeRng,
+    types::{BlockExecutionResultsOrChunk, NodeRng},
     utils::umask,
     WithDir,
 };
@@ -108,6 +111,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<ContractRuntimeRequest>
+        + From<StorageRequest>
+        + From<FetcherRequest<BlockExecutionResultsOrChunk>>
         + Send,
 {
     type Event = Event;
@@ -162,6 +168,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<ContractRuntimeRequest>
+        + From<StorageRequest>
+        + From<FetcherRequest<BlockExecutionResultsOrChunk>>
         + Send,
 {
     fn state(&self) -> &ComponentState {
@@ -186,6 +195,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<ContractRuntimeRequest>
+        + From<StorageRequest>
+        + From<FetcherRequest<BlockExecutionResultsOrChunk>>
         + Send,
 {
     type Error = Error;
@@ -217,7 +229,10 @@ where
 ///
 /// If the socket already exists, an attempt to delete it is made. Errors during deletion are
 /// ignored, but may cause the subsequent socket opening to fail.
-fn setup_listener<P: AsRef<Path>>(path: P, socket_umask: umask::Mode) -> io::Result<UnixListener> {
+pub(crate) fn setup_listener<P: AsRef<Path>>(
+    path: P,
+    socket_umask: umask::Mode,
+) -> io::Result<UnixListener> {
     let socket_path = path.as_ref();
 
     // This would be racy, but no one is racing us for the socket, so we'll just do a naive
@@ -243,7 +258,7 @@ fn setup_listener<P: AsRef<Path>>(path: P, socket_umask: umask::Mode) -> io::Res
     let listener = UnixListener::bind(socket_path)?;
     drop(umask_guard);
 
-    debug!(local_addr=%ShowUnixAddr(&listener.local_addr()?), "diagnostics port listening");
+    debug!(local_addr=%ShowUnixAddr(&listener.local_addr()?), "unix socket listening");
 
     Ok(listener)
 }
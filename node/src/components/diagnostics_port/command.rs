@@ -1,5 +1,6 @@
 use std::{
     fmt::{self, Display, Formatter},
+    path::PathBuf,
     str::FromStr,
 };
 
@@ -131,6 +132,33 @@ pub(super) enum Action {
         /// The failpoint activation/deactivation.
         activation: String,
     },
+    /// Show the tracing spans recorded for a deploy, from its acceptance to its execution.
+    ///
+    /// Spans are only kept for the most recently traced deploys.
+    TraceDeploy {
+        /// Hex-encoded hash of the deploy.
+        deploy_hash: String,
+    },
+    /// Fetch the execution results of a stored block from peers and store them.
+    ///
+    /// Used to restore results pruned under the storage's execution results retention policy.
+    BackfillExecutionResults {
+        /// Hex-encoded hash of the block.
+        block_hash: String,
+    },
+    /// Drop all preprocessed Wasm modules cached by the contract runtime.
+    ///
+    /// Returns the number of modules which were dropped.
+    FlushModuleCache,
+    /// Write a snapshot of the global state of the highest complete block to a file on the node's
+    /// host.
+    ///
+    /// The snapshot can be imported by a joining node through its `state_snapshot_path` setting,
+    /// with the snapshot's block as its trusted hash.
+    ExportStateSnapshot {
+        /// Path of the file to write.
+        path: PathBuf,
+    },
     /// Close connection server-side.
     Quit,
 }
@@ -162,5 +190,16 @@ mod tests {
 
         let cmd = Command::from_line("dump-queues").expect("command parsing failed");
         assert!(matches!(cmd.action, Action::DumpQueues));
+
+        let cmd = Command::from_line("trace-deploy 0123").expect("command parsing failed");
+        assert!(
+            matches!(cmd.action, Action::TraceDeploy { ref deploy_hash } if deploy_hash == "0123")
+        );
+
+        let cmd =
+            Command::from_line("backfill-execution-results 0123").expect("command parsing failed");
+        assert!(
+            matches!(cmd.action, Action::BackfillExecutionResults { ref block_hash } if block_hash == "0123")
+        );
     }
 }
@@ -22,6 +22,7 @@ use tokio::{
 };
 use tracing::{debug, info, info_span, warn, Instrument};
 
+use casper_hashing::Digest;
 use casper_types::EraId;
 use tracing_subscriber::{filter::ParseError, EnvFilter};
 
@@ -30,15 +31,19 @@ use super::{
     util::ShowUnixAddr,
 };
 use crate::{
-    components::consensus::EraDump,
+    components::{block_synchronizer, consensus::EraDump, contract_runtime},
     effect::{
         announcements::{ControlAnnouncement, QueueDumpFormat},
         diagnostics_port::DumpConsensusStateRequest,
-        requests::{NetworkInfoRequest, SetNodeStopRequest},
+        requests::{
+            ContractRuntimeRequest, FetcherRequest, NetworkInfoRequest, SetNodeStopRequest,
+            StorageRequest,
+        },
         EffectBuilder,
     },
     failpoints::FailpointActivation,
     logging,
+    types::{BlockExecutionResultsOrChunk, BlockHash, DeployHash},
     utils::{display_error, opt_display::OptDisplay},
 };
 
@@ -204,6 +209,9 @@ impl Session {
             + From<ControlAnnouncement>
             + From<NetworkInfoRequest>
             + From<SetNodeStopRequest>
+            + From<ContractRuntimeRequest>
+            + From<StorageRequest>
+            + From<FetcherRequest<BlockExecutionResultsOrChunk>>
             + Send,
     {
         debug!(%line, "line received");
@@ -361,6 +369,104 @@ impl Session {
                             }
                         }
                     }
+                    Action::TraceDeploy { ref deploy_hash } => {
+                        match Digest::from_hex(deploy_hash) {
+                            Ok(digest) => match logging::deploy_trace(DeployHash::new(digest)) {
+                                Some(deploy_trace) => {
+                                    self.send_outcome(
+                                        writer,
+                                        &Outcome::success("showing deploy trace"),
+                                    )
+                                    .await?;
+                                    self.send_to_client(writer, &deploy_trace).await?;
+                                }
+                                None => {
+                                    self.send_outcome(
+                                        writer,
+                                        &Outcome::failed("no spans recorded for deploy"),
+                                    )
+                                    .await?;
+                                }
+                            },
+                            Err(ref err) => {
+                                self.send_outcome(
+                                    writer,
+                                    &Outcome::failed(format!(
+                                        "invalid deploy hash: {}",
+                                        display_error(err)
+                                    )),
+                                )
+                                .await?;
+                            }
+                        }
+                    }
+                    Action::BackfillExecutionResults { ref block_hash } => {
+                        match Digest::from_hex(block_hash) {
+                            Ok(digest) => {
+                                match block_synchronizer::backfill_execution_results(
+                                    effect_builder,
+                                    BlockHash::new(digest),
+                                )
+                                .await
+                                {
+                                    Ok(()) => {
+                                        self.send_outcome(
+                                            writer,
+                                            &Outcome::success("backfilled execution results"),
+                                        )
+                                        .await?;
+                                    }
+                                    Err(ref err) => {
+                                        self.send_outcome(writer, &Outcome::failed(err)).await?;
+                                    }
+                                }
+                            }
+                            Err(ref err) => {
+                                self.send_outcome(
+                                    writer,
+                                    &Outcome::failed(format!(
+                                        "invalid block hash: {}",
+                                        display_error(err)
+                                    )),
+                                )
+                                .await?;
+                            }
+                        }
+                    }
+                    Action::FlushModuleCache => {
+                        let flushed = effect_builder.flush_module_cache().await;
+                        self.send_outcome(
+                            writer,
+                            &Outcome::success(format!("flushed {} cached modules", flushed)),
+                        )
+                        .await?;
+                    }
+                    Action::ExportStateSnapshot { ref path } => {
+                        match contract_runtime::export_state_snapshot(effect_builder, path.clone())
+                            .await
+                        {
+                            Ok(header) => {
+                                self.send_outcome(
+                                    writer,
+                                    &Outcome::success(format!(
+                                        "exported state snapshot of block {}",
+                                        header.block_header().block_hash()
+                                    )),
+                                )
+                                .await?;
+                            }
+                            Err(ref err) => {
+                                self.send_outcome(
+                                    writer,
+                                    &Outcome::failed(format!(
+                                        "failed to export state snapshot: {}",
+                                        display_error(err)
+                                    )),
+                                )
+                                .await?;
+                            }
+                        }
+                    }
                     Action::Quit => {
                         self.send_outcome(writer, &Outcome::success("goodbye!"))
                             .await?;
@@ -503,6 +609,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<ContractRuntimeRequest>
+        + From<StorageRequest>
+        + From<FetcherRequest<BlockExecutionResultsOrChunk>>
         + Send,
 {
     debug!("accepted new connection on diagnostics port");
@@ -547,6 +656,9 @@ pub(super) async fn server<REv>(
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<ContractRuntimeRequest>
+        + From<StorageRequest>
+        + From<FetcherRequest<BlockExecutionResultsOrChunk>>
         + Send,
 {
     let handling_shutdown_receiver = shutdown_receiver.clone();
@@ -626,7 +738,10 @@ mod tests {
         effect::{
             announcements::ControlAnnouncement,
             diagnostics_port::DumpConsensusStateRequest,
-            requests::{NetworkInfoRequest, SetNodeStopRequest},
+            requests::{
+                ContractRuntimeRequest, FetcherRequest, NetworkInfoRequest, SetNodeStopRequest,
+                StorageRequest,
+            },
             EffectBuilder, EffectExt, Effects,
         },
         reactor::{
@@ -637,7 +752,7 @@ mod tests {
             self,
             network::{NetworkedReactor, TestingNetwork},
         },
-        types::{Chainspec, ChainspecRawBytes},
+        types::{BlockExecutionResultsOrChunk, Chainspec, ChainspecRawBytes},
         utils::WeightedRoundRobin,
         NodeRng, WithDir,
     };
@@ -686,6 +801,12 @@ mod tests {
         NetworkInfoRequest(NetworkInfoRequest),
         #[from]
         SetNodeStopRequest(SetNodeStopRequest),
+        #[from]
+        ContractRuntimeRequest(ContractRuntimeRequest),
+        #[from]
+        StorageRequest(StorageRequest),
+        #[from]
+        BlockExecutionResultsOrChunkFetcherRequest(FetcherRequest<BlockExecutionResultsOrChunk>),
     }
 
     impl Display for Event {
@@ -732,7 +853,10 @@ mod tests {
                 Event::DumpConsensusStateRequest(_)
                 | Event::SetNodeStopRequest(_)
                 | Event::ControlAnnouncement(_)
-                | Event::NetworkInfoRequest(_) => {
+                | Event::NetworkInfoRequest(_)
+                | Event::ContractRuntimeRequest(_)
+                | Event::StorageRequest(_)
+                | Event::BlockExecutionResultsOrChunkFetcherRequest(_) => {
                     panic!("unexpected: {}", event)
                 }
             }
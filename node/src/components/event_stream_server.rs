@@ -295,6 +295,8 @@ where
                     deploy_header,
                     block_hash,
                     execution_result,
+                    execution_stats,
+                    warnings,
                 } => self.broadcast(SseData::DeployProcessed {
                     deploy_hash: Box::new(deploy_hash),
                     account: Box::new(deploy_header.account().clone()),
@@ -303,6 +305,8 @@ where
                     dependencies: deploy_header.dependencies().clone(),
                     block_hash: Box::new(block_hash),
                     execution_result,
+                    execution_stats,
+                    warnings,
                 }),
                 Event::DeploysExpired(deploy_hashes) => deploy_hashes
                     .into_iter()
@@ -321,9 +325,11 @@ where
                 Event::Step {
                     era_id,
                     execution_effect,
+                    step_effects,
                 } => self.broadcast(SseData::Step {
                     era_id,
                     execution_effect,
+                    step_effects,
                 }),
             },
         }
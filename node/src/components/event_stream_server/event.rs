@@ -3,10 +3,14 @@ use std::{
     sync::Arc,
 };
 
+use casper_execution_engine::core::engine_state::ExecutionWarning;
 use casper_types::{EraId, ExecutionEffect, ExecutionResult, PublicKey, Timestamp};
 use itertools::Itertools;
 
-use crate::types::{Block, BlockHash, Deploy, DeployHash, DeployHeader, FinalitySignature};
+use crate::types::{
+    Block, BlockHash, Deploy, DeployExecutionStats, DeployHash, DeployHeader, FinalitySignature,
+    StepEffect,
+};
 
 #[derive(Debug)]
 pub enum Event {
@@ -18,6 +22,8 @@ pub enum Event {
         deploy_header: Box<DeployHeader>,
         block_hash: BlockHash,
         execution_result: Box<ExecutionResult>,
+        execution_stats: Option<DeployExecutionStats>,
+        warnings: Vec<ExecutionWarning>,
     },
     DeploysExpired(Vec<DeployHash>),
     Fault {
@@ -29,6 +35,7 @@ pub enum Event {
     Step {
         era_id: EraId,
         execution_effect: ExecutionEffect,
+        step_effects: Vec<StepEffect>,
     },
 }
 
@@ -32,13 +32,16 @@ use warp::{
     Filter, Reply,
 };
 
+use casper_execution_engine::core::engine_state::ExecutionWarning;
 #[cfg(test)]
 use casper_types::testing::TestRng;
 use casper_types::{
     EraId, ExecutionEffect, ExecutionResult, ProtocolVersion, PublicKey, TimeDiff, Timestamp,
 };
 
-use crate::types::{BlockHash, Deploy, DeployHash, FinalitySignature, JsonBlock};
+use crate::types::{
+    BlockHash, Deploy, DeployExecutionStats, DeployHash, FinalitySignature, JsonBlock, StepEffect,
+};
 #[cfg(test)]
 use crate::{testing, types::Block};
 
@@ -95,6 +98,14 @@ pub enum SseData {
         ttl: TimeDiff,
         dependencies: Vec<DeployHash>,
         block_hash: Box<BlockHash>,
+        /// Measured by the node which executed the deploy; not part of the consensus-relevant
+        /// execution result.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        execution_stats: Option<DeployExecutionStats>,
+        /// Raised by the node which executed the deploy; not part of the consensus-relevant
+        /// execution result.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        warnings: Vec<ExecutionWarning>,
         #[data_size(skip)]
         execution_result: Box<ExecutionResult>,
     },
@@ -111,6 +122,8 @@ pub enum SseData {
     /// The execution effects produced by a `StepRequest`.
     Step {
         era_id: EraId,
+        /// The effects of the step on the auction, by category.
+        step_effects: Vec<StepEffect>,
         #[data_size(skip)]
         execution_effect: ExecutionEffect,
     },
@@ -174,6 +187,15 @@ impl SseData {
             dependencies: deploy.header().dependencies().clone(),
             block_hash: Box::new(BlockHash::random(rng)),
             execution_result: Box::new(rng.gen()),
+            execution_stats: Some(DeployExecutionStats {
+                duration: TimeDiff::from_millis(rng.gen_range(0..1_000)),
+                memory_high_water_mark: rng.gen(),
+                effects_size: rng.gen(),
+            }),
+            warnings: vec![ExecutionWarning::ExcessivePayment {
+                payment: rng.gen::<u64>().into(),
+                cost: rng.gen::<u64>().into(),
+            }],
         }
     }
 
@@ -212,6 +234,9 @@ impl SseData {
         SseData::Step {
             era_id: EraId::new(rng.gen()),
             execution_effect,
+            step_effects: vec![StepEffect::SnapshotRotated {
+                era_ids: vec![EraId::new(rng.gen())],
+            }],
         }
     }
 }
@@ -40,8 +40,8 @@ use crate::{
         ConditionCheckReactor, FakeDeployAcceptor,
     },
     types::{
-        Block, Chainspec, ChainspecRawBytes, Deploy, DeployHash, DeployId, FinalitySignature,
-        NodeId,
+        Block, Chainspec, ChainspecRawBytes, Deploy, DeployHash, DeployId,
+        DeployValidationMetadata, FinalitySignature, NodeId,
     },
     utils::WithDir,
 };
@@ -391,7 +391,11 @@ fn fetch_deploy(
 ) -> impl FnOnce(EffectBuilder<Event>) -> Effects<Event> {
     move |effect_builder: EffectBuilder<Event>| {
         effect_builder
-            .fetch::<Deploy>(deploy_id, node_id, Box::new(EmptyValidationMetadata))
+            .fetch::<Deploy>(
+                deploy_id,
+                node_id,
+                Box::new(DeployValidationMetadata { chain_id: 0 }),
+            )
             .then(move |deploy| async move {
                 let mut result = fetched.lock().unwrap();
                 result.0 = true;
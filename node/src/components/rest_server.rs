@@ -41,9 +41,9 @@ use crate::{
     },
     effect::{
         requests::{
-            BlockSynchronizerRequest, ChainspecRawBytesRequest, ConsensusRequest, MetricsRequest,
-            NetworkInfoRequest, ReactorStatusRequest, RestRequest, StorageRequest,
-            UpgradeWatcherRequest,
+            BlockSynchronizerRequest, ChainspecRawBytesRequest, ConsensusRequest,
+            ContractRuntimeRequest, MetricsRequest, NetworkInfoRequest, ReactorStatusRequest,
+            RestRequest, StorageRequest, UpgradeWatcherRequest,
         },
         EffectBuilder, EffectExt, Effects,
     },
@@ -65,6 +65,7 @@ pub(crate) trait ReactorEventT:
     + From<StorageRequest>
     + From<ChainspecRawBytesRequest>
     + From<UpgradeWatcherRequest>
+    + From<ContractRuntimeRequest>
     + From<ConsensusRequest>
     + From<MetricsRequest>
     + From<ReactorStatusRequest>
@@ -80,6 +81,7 @@ impl<REv> ReactorEventT for REv where
         + From<StorageRequest>
         + From<ChainspecRawBytesRequest>
         + From<UpgradeWatcherRequest>
+        + From<ContractRuntimeRequest>
         + From<ConsensusRequest>
         + From<MetricsRequest>
         + From<ReactorStatusRequest>
@@ -199,6 +201,7 @@ where
                             (reactor_state, last_progress),
                             available_block_range,
                             block_sync,
+                            module_cache,
                         ) = join!(
                             effect_builder.get_highest_complete_block_from_storage(),
                             effect_builder.network_peers(),
@@ -207,6 +210,7 @@ where
                             effect_builder.get_reactor_status(),
                             effect_builder.get_available_block_range_from_storage(),
                             effect_builder.get_block_synchronizer_status(),
+                            effect_builder.get_module_cache_stats(),
                         );
                         let starting_state_root_hash = effect_builder
                             .get_block_header_at_height_from_storage(
@@ -227,6 +231,7 @@ where
                             available_block_range,
                             block_sync,
                             starting_state_root_hash,
+                            module_cache,
                         );
                         responder.respond(status_feed).await;
                     }
@@ -29,7 +29,7 @@ use casper_execution_engine::core::engine_state::{
     QueryResult,
 };
 use casper_hashing::Digest;
-use casper_types::{system::auction::EraValidators, Key, ProtocolVersion, URef};
+use casper_types::{system::auction::EraValidators, Key, ProtocolVersion, TimeDiff, URef};
 
 use super::Component;
 use crate::{
@@ -106,6 +106,8 @@ pub(crate) struct RpcServer {
     network_name: String,
     /// The uptime start.
     node_startup_instant: Instant,
+    /// How long past its expiry a deploy's hash remains replay-protected.
+    replay_protection_window: TimeDiff,
     /// Inner speculative execution JSON-RPC server is present only when enabled
     /// in the speculative execution JSON-RPC server config.
     /// The inner speculative execution JSON-RPC server as a struct would have
@@ -121,6 +123,7 @@ impl RpcServer {
         api_version: ProtocolVersion,
         network_name: String,
         node_startup_instant: Instant,
+        replay_protection_window: TimeDiff,
     ) -> Self {
         RpcServer {
             state: ComponentState::Uninitialized,
@@ -129,6 +132,7 @@ impl RpcServer {
             api_version,
             network_name,
             node_startup_instant,
+            replay_protection_window,
             speculative_exec: None,
         }
     }
@@ -311,6 +315,12 @@ where
                         }),
                         main_responder: responder,
                     }),
+                Event::RpcRequest(RpcRequest::GetDeployStatus { hash, responder }) => async move {
+                    responder
+                        .respond(effect_builder.get_deploy_status(hash).await)
+                        .await
+                }
+                .ignore(),
                 Event::RpcRequest(RpcRequest::GetPeers { responder }) => effect_builder
                     .network_peers()
                     .event(move |peers| Event::GetPeersResult {
@@ -329,6 +339,7 @@ where
                             (reactor_state, last_progress),
                             available_block_range,
                             block_sync,
+                            module_cache,
                         ) = join!(
                             effect_builder.get_highest_complete_block_from_storage(),
                             effect_builder.network_peers(),
@@ -337,6 +348,7 @@ where
                             effect_builder.get_reactor_status(),
                             effect_builder.get_available_block_range_from_storage(),
                             effect_builder.get_block_synchronizer_status(),
+                            effect_builder.get_module_cache_stats(),
                         );
                         let starting_state_root_hash = effect_builder
                             .get_block_header_at_height_from_storage(
@@ -357,6 +369,7 @@ where
                             available_block_range,
                             block_sync,
                             starting_state_root_hash,
+                            module_cache,
                         );
                         responder.respond(status_feed).await;
                     }
@@ -372,6 +385,9 @@ where
                         .await
                 }
                 .ignore(),
+                Event::RpcRequest(RpcRequest::GetReplayProtectionWindow { responder }) => {
+                    responder.respond(self.replay_protection_window).ignore()
+                }
                 Event::GetBlockTransfersResult {
                     block_hash: _,
                     result,
@@ -21,6 +21,7 @@ use super::{
     Error, ErrorCode, ReactorEventT, ReservedErrorCode, RpcRequest, RpcWithOptionalParams,
 };
 use crate::{
+    components::storage::StateIdentifier,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{common, state},
@@ -80,6 +81,15 @@ pub enum BlockIdentifier {
     Height(u64),
 }
 
+impl From<BlockIdentifier> for StateIdentifier {
+    fn from(block_identifier: BlockIdentifier) -> Self {
+        match block_identifier {
+            BlockIdentifier::Hash(block_hash) => StateIdentifier::BlockHash(block_hash),
+            BlockIdentifier::Height(height) => StateIdentifier::BlockHeight(height),
+        }
+    }
+}
+
 impl str::FromStr for BlockIdentifier {
     type Err = ParseBlockIdentifierError;
 
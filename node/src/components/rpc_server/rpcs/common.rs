@@ -10,9 +10,10 @@ use casper_types::{bytesrepr::ToBytes, Key};
 
 use super::{
     chain::{self, BlockIdentifier},
-    state, Error, ReactorEventT, RpcRequest,
+    state, Error, ErrorCode, ReactorEventT, RpcRequest,
 };
 use crate::{
+    components::storage::{ResolvedStateIdentifier, StateIdentifier, StateIdentifierError},
     effect::EffectBuilder,
     reactor::QueueKind,
     types::{json_compatibility::StoredValue, AvailableBlockRange, Block},
@@ -133,3 +134,30 @@ pub(super) async fn get_block<REv: ReactorEventT>(
         .await
         .map(|block_with_metadata| block_with_metadata.block)
 }
+
+/// Resolves the given state identifier to a state root hash, along with the header of the block it
+/// belongs to, if any.
+///
+/// On error, the returned `Error` includes the height range of fully available blocks as the
+/// additional `data` field.
+pub(super) async fn resolve_state_identifier<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    state_identifier: StateIdentifier,
+) -> Result<ResolvedStateIdentifier, Error> {
+    match effect_builder
+        .resolve_state_identifier(state_identifier)
+        .await
+    {
+        Ok(resolved_state_identifier) => Ok(resolved_state_identifier),
+        Err(error) => {
+            let error_code = match error {
+                StateIdentifierError::NoSuchBlock(_) => ErrorCode::NoSuchBlock,
+                StateIdentifierError::RootPruned { .. } => ErrorCode::StateRootPruned,
+            };
+            Err(
+                missing_block_or_state_root_error(effect_builder, error_code, error.to_string())
+                    .await,
+            )
+        }
+    }
+}
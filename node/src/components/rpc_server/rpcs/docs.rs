@@ -20,7 +20,10 @@ use super::{
     chain::{
         GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetEraSummary, GetStateRootHash,
     },
-    info::{GetChainspec, GetDeploy, GetPeers, GetStatus, GetValidatorChanges},
+    info::{
+        GetChainspec, GetDeploy, GetDeployStatus, GetNativeCost, GetPeers, GetStatus,
+        GetValidatorChanges,
+    },
     state::{
         GetAccountInfo, GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem, QueryBalance,
         QueryGlobalState,
@@ -30,7 +33,7 @@ use super::{
 use crate::effect::EffectBuilder;
 
 pub(crate) const DOCS_EXAMPLE_PROTOCOL_VERSION: ProtocolVersion =
-    ProtocolVersion::from_parts(1, 5, 6);
+    ProtocolVersion::from_parts(1, 6, 0);
 
 const DEFINITIONS_PATH: &str = "#/components/schemas/";
 
@@ -70,6 +73,9 @@ pub(crate) static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
 
     schema.push_with_params::<PutDeploy>("receives a Deploy to be executed by the network");
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
+    schema.push_with_params::<GetDeployStatus>(
+        "returns the lifecycle status of a Deploy, as known to this node",
+    );
     schema.push_with_params::<GetAccountInfo>("returns an Account from the network");
     schema.push_with_params::<GetDictionaryItem>("returns an item from a Dictionary");
     schema.push_with_params::<QueryGlobalState>(
@@ -86,6 +92,9 @@ pub(crate) static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
         "returns the raw bytes of the chainspec.toml, genesis accounts.toml, and \
         global_state.toml files",
     );
+    schema.push_with_params::<GetNativeCost>(
+        "returns the gas cost the chainspec fixes for a native operation",
+    );
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
@@ -35,6 +35,10 @@ pub enum ErrorCode {
     FailedToGetTrie = -32011,
     /// The requested state root hash was not found.
     NoSuchStateRoot = -32012,
+    /// The global state of the requested block has been pruned.
+    StateRootPruned = -32013,
+    /// The requested entry point isn't a native operation.
+    NoSuchNativeOperation = -32014,
 }
 
 impl From<ErrorCode> for (i64, &'static str) {
@@ -59,6 +63,8 @@ impl From<ErrorCode> for (i64, &'static str) {
             }
             ErrorCode::FailedToGetTrie => (error_code as i64, "Failed to get trie"),
             ErrorCode::NoSuchStateRoot => (error_code as i64, "No such state root"),
+            ErrorCode::StateRootPruned => (error_code as i64, "State root pruned"),
+            ErrorCode::NoSuchNativeOperation => (error_code as i64, "No such native operation"),
         }
     }
 }
@@ -11,7 +11,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey};
+use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey, TimeDiff, Timestamp, U512};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
@@ -23,7 +23,7 @@ use crate::{
     reactor::QueueKind,
     types::{
         Block, BlockHash, BlockHashAndHeight, ChainspecRawBytes, Deploy, DeployHash,
-        DeployMetadataExt, GetStatusResult, PeersMap,
+        DeployMetadataExt, DeployStatus, GetStatusResult, PeersMap,
     },
 };
 
@@ -39,7 +39,25 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
         result: ExecutionResult::example().clone(),
     }],
     block_hash_and_height: None,
+    replay_protection_expiry: Deploy::doc_example()
+        .header()
+        .replay_protection_expiry(TimeDiff::from_seconds(86_400)),
 });
+static GET_DEPLOY_STATUS_PARAMS: Lazy<GetDeployStatusParams> =
+    Lazy::new(|| GetDeployStatusParams {
+        deploy_hash: *Deploy::doc_example().hash(),
+    });
+static GET_DEPLOY_STATUS_RESULT: Lazy<GetDeployStatusResult> =
+    Lazy::new(|| GetDeployStatusResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        deploy_hash: *Deploy::doc_example().hash(),
+        status: DeployStatus::Executed {
+            block_hash: *Block::doc_example().hash(),
+            block_height: Block::doc_example().height(),
+            success: true,
+            cost: U512::from(123_456),
+        },
+    });
 static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     peers: GetStatusResult::doc_example().peers.clone(),
@@ -53,6 +71,14 @@ static GET_VALIDATOR_CHANGES_RESULT: Lazy<GetValidatorChangesResult> = Lazy::new
         changes,
     }
 });
+static GET_NATIVE_COST_PARAMS: Lazy<GetNativeCostParams> = Lazy::new(|| GetNativeCostParams {
+    entry_point: "delegate".to_string(),
+});
+static GET_NATIVE_COST_RESULT: Lazy<GetNativeCostResult> = Lazy::new(|| GetNativeCostResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    entry_point: "delegate".to_string(),
+    cost: 2_500_000_000,
+});
 static GET_CHAINSPEC_RESULT: Lazy<GetChainspecResult> = Lazy::new(|| GetChainspecResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     chainspec_bytes: ChainspecRawBytes::new(vec![42, 42].into(), None, None),
@@ -106,6 +132,9 @@ pub struct GetDeployResult {
     /// only provided if the full execution results are not know on this node.
     #[serde(skip_serializing_if = "Option::is_none", flatten)]
     pub block_hash_and_height: Option<BlockHashAndHeight>,
+    /// The time until which the deploy's hash remains replay-protected, i.e. the deploy's expiry
+    /// plus the chainspec's replay protection window.
+    pub replay_protection_expiry: Timestamp,
 }
 
 impl DocExample for GetDeployResult {
@@ -167,11 +196,88 @@ impl RpcWithParams for GetDeploy {
             DeployMetadataExt::Empty => (Vec::new(), None),
         };
 
+        let replay_protection_window = effect_builder
+            .make_request(
+                |responder| RpcRequest::GetReplayProtectionWindow { responder },
+                QueueKind::Api,
+            )
+            .await;
+        let replay_protection_expiry = deploy
+            .header()
+            .replay_protection_expiry(replay_protection_window);
+
         let result = Self::ResponseResult {
             api_version,
             deploy,
             execution_results,
             block_hash_and_height,
+            replay_protection_expiry,
+        };
+        Ok(result)
+    }
+}
+
+/// Params for "info_get_deploy_status" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployStatusParams {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+}
+
+impl DocExample for GetDeployStatusParams {
+    fn doc_example() -> &'static Self {
+        &GET_DEPLOY_STATUS_PARAMS
+    }
+}
+
+/// Result for "info_get_deploy_status" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployStatusResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The lifecycle status of the deploy, as known to this node.
+    pub status: DeployStatus,
+}
+
+impl DocExample for GetDeployStatusResult {
+    fn doc_example() -> &'static Self {
+        &GET_DEPLOY_STATUS_RESULT
+    }
+}
+
+/// "info_get_deploy_status" RPC.
+pub struct GetDeployStatus {}
+
+#[async_trait]
+impl RpcWithParams for GetDeployStatus {
+    const METHOD: &'static str = "info_get_deploy_status";
+    type RequestParams = GetDeployStatusParams;
+    type ResponseResult = GetDeployStatusResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        let status = effect_builder
+            .make_request(
+                |responder| RpcRequest::GetDeployStatus {
+                    hash: params.deploy_hash,
+                    responder,
+                },
+                QueueKind::Api,
+            )
+            .await;
+
+        let result = Self::ResponseResult {
+            api_version,
+            deploy_hash: params.deploy_hash,
+            status,
         };
         Ok(result)
     }
@@ -390,3 +496,72 @@ impl RpcWithoutParams for GetChainspec {
         Ok(result)
     }
 }
+
+/// Params for "info_get_native_cost" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetNativeCostParams {
+    /// The entry point of the native operation, e.g. "transfer" or "delegate".
+    pub entry_point: String,
+}
+
+impl DocExample for GetNativeCostParams {
+    fn doc_example() -> &'static Self {
+        &GET_NATIVE_COST_PARAMS
+    }
+}
+
+/// Result for "info_get_native_cost" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetNativeCostResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The entry point of the native operation.
+    pub entry_point: String,
+    /// The gas cost the chainspec fixes for the native operation.  For entry points other than
+    /// "transfer", this excludes the cost of the payment code and of any session code.
+    pub cost: u64,
+}
+
+impl DocExample for GetNativeCostResult {
+    fn doc_example() -> &'static Self {
+        &GET_NATIVE_COST_RESULT
+    }
+}
+
+/// "info_get_native_cost" RPC.
+pub struct GetNativeCost {}
+
+#[async_trait]
+impl RpcWithParams for GetNativeCost {
+    const METHOD: &'static str = "info_get_native_cost";
+    type RequestParams = GetNativeCostParams;
+    type ResponseResult = GetNativeCostResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        let cost = match effect_builder
+            .get_native_cost(params.entry_point.clone())
+            .await
+        {
+            Some(cost) => cost,
+            None => {
+                let message = format!("{} is not a native operation", params.entry_point);
+                info!("{}", message);
+                return Err(Error::new(ErrorCode::NoSuchNativeOperation, message));
+            }
+        };
+
+        let result = Self::ResponseResult {
+            api_version,
+            entry_point: params.entry_point,
+            cost,
+        };
+        Ok(result)
+    }
+}
@@ -3,16 +3,23 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use std::{str, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashSet},
+    str,
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::core::engine_state::Error as EngineStateError;
+use casper_execution_engine::{
+    core::engine_state::{Error as EngineStateError, ExecutionWarning},
+    storage::global_state::read_recording::ReadSet,
+};
 use casper_json_rpc::ReservedErrorCode;
-use casper_types::{ExecutionResult, ProtocolVersion};
+use casper_types::{ExecutionEffect, ExecutionResult, Key, ProtocolVersion, Transform};
 
 use super::{
     chain::BlockIdentifier,
@@ -21,29 +28,41 @@ use super::{
     Error, ErrorCode, ReactorEventT, RpcWithParams,
 };
 use crate::{
-    components::contract_runtime::SpeculativeExecutionState,
+    components::{
+        contract_runtime::{SpeculativeExecutionResult, SpeculativeExecutionState},
+        storage::StateIdentifier,
+    },
     effect::EffectBuilder,
-    types::{Block, BlockHash, Deploy},
+    types::{Block, BlockHash, Deploy, DeployHash, DeployHeader},
 };
 
 static SPECULATIVE_EXEC_PARAMS: Lazy<SpeculativeExecParams> = Lazy::new(|| SpeculativeExecParams {
     block_identifier: Some(BlockIdentifier::Hash(*Block::doc_example().hash())),
     deploy: Deploy::doc_example().clone(),
+    report_conflicts: false,
 });
 static SPECULATIVE_EXEC_RESULT: Lazy<SpeculativeExecResult> = Lazy::new(|| SpeculativeExecResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     block_hash: *Block::doc_example().hash(),
     execution_result: ExecutionResult::example().clone(),
+    error_code: None,
+    warnings: Vec::new(),
+    conflict_report: None,
 });
 
 /// Params for "speculative_exec" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpeculativeExecParams {
-    /// Block hash on top of which to execute the deploy.
+    /// Hash or height of the block on top of which to execute the deploy, defaulting to the
+    /// highest block.  The global state of historical blocks is only available while retained.
     pub block_identifier: Option<BlockIdentifier>,
     /// Deploy to execute.
     pub deploy: Deploy,
+    /// Whether to report which of the keys the deploy touches were also touched by the deploys of
+    /// the block on top of which it is executed.
+    #[serde(default)]
+    pub report_conflicts: bool,
 }
 
 impl DocExample for SpeculativeExecParams {
@@ -63,6 +82,19 @@ pub struct SpeculativeExecResult {
     pub block_hash: BlockHash,
     /// Result of the execution.
     pub execution_result: ExecutionResult,
+    /// The numeric code of the error the execution failed with, if it failed.
+    ///
+    /// Unlike the error message, the code of an error is stable across versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<u32>,
+    /// The warnings raised while executing the deploy, about behaviors which are legacy or likely
+    /// to be a mistake.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ExecutionWarning>,
+    /// The keys the deploy touches which the deploys of the block were also touching, if
+    /// requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict_report: Option<ConflictReport>,
 }
 
 impl DocExample for SpeculativeExecResult {
@@ -71,6 +103,74 @@ impl DocExample for SpeculativeExecResult {
     }
 }
 
+/// The keys touched by a speculatively executed deploy which the deploys of the block on top of
+/// which it was executed also touched, and so are likely to be contended.
+///
+/// Deploys which aren't in a block yet haven't been executed, so the keys they touch are unknown.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConflictReport {
+    /// The formatted keys read by the deploy and written by the block's deploys.
+    pub hot_reads: Vec<String>,
+    /// The formatted keys written by the deploy and read or written by the block's deploys.
+    pub hot_writes: Vec<String>,
+}
+
+impl ConflictReport {
+    fn new(
+        execution_result: &ExecutionResult,
+        reads: &ReadSet,
+        block_execution_results: &[(DeployHash, DeployHeader, ExecutionResult)],
+    ) -> Self {
+        let mut block_reads = HashSet::new();
+        let mut block_writes = HashSet::new();
+        for (_, _, block_execution_result) in block_execution_results {
+            for entry in &execution_effect(block_execution_result).transforms {
+                if entry.transform == Transform::Identity {
+                    block_reads.insert(entry.key.as_str());
+                } else {
+                    block_writes.insert(entry.key.as_str());
+                }
+            }
+        }
+
+        // Written keys are matched against the reads rather than the other way round, so that
+        // writes under a prefix the deploy listed are found too.
+        let hot_reads = block_writes
+            .iter()
+            .filter(|key| {
+                Key::from_formatted_str(key)
+                    .map(|key| reads.contains(&key))
+                    .unwrap_or(false)
+            })
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let hot_writes = execution_effect(execution_result)
+            .transforms
+            .iter()
+            .filter(|entry| entry.transform != Transform::Identity)
+            .map(|entry| entry.key.as_str())
+            .filter(|key| block_writes.contains(key) || block_reads.contains(key))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        ConflictReport {
+            hot_reads,
+            hot_writes,
+        }
+    }
+}
+
+fn execution_effect(execution_result: &ExecutionResult) -> &ExecutionEffect {
+    match execution_result {
+        ExecutionResult::Success { effect, .. } | ExecutionResult::Failure { effect, .. } => effect,
+    }
+}
+
 /// "speculative_exec" RPC
 pub struct SpeculativeExec {}
 
@@ -88,25 +188,31 @@ impl RpcWithParams for SpeculativeExec {
         let SpeculativeExecParams {
             block_identifier: maybe_block_id,
             deploy,
+            report_conflicts,
         } = params;
         let deploy = Arc::new(deploy);
-        let only_from_available_block_range = true;
-
-        let block = common::get_block(
-            maybe_block_id,
-            only_from_available_block_range,
-            effect_builder,
-        )
-        .await?;
-        let block_hash = *block.hash();
+
+        let state_identifier =
+            maybe_block_id.map_or(StateIdentifier::HighestCompleteBlock, StateIdentifier::from);
+        let resolved = common::resolve_state_identifier(effect_builder, state_identifier).await?;
+        let block_header = match resolved.block_header {
+            Some(block_header) => block_header,
+            None => {
+                return Err(Error::new(
+                    ReservedErrorCode::InternalError,
+                    format!("{} resolved without a block header", state_identifier),
+                ))
+            }
+        };
+        let block_hash = block_header.block_hash();
         let execution_prestate = SpeculativeExecutionState {
-            state_root_hash: *block.state_root_hash(),
-            block_time: block.timestamp(),
-            protocol_version: block.protocol_version(),
+            state_root_hash: resolved.state_root_hash,
+            block_time: block_header.timestamp(),
+            protocol_version: block_header.protocol_version(),
         };
 
         let accept_deploy_result = effect_builder
-            .try_accept_deploy(Arc::clone(&deploy), Some(Box::new(block.take_header())))
+            .try_accept_deploy(Arc::clone(&deploy), Some(Box::new(block_header)))
             .await;
 
         if let Err(error) = accept_deploy_result {
@@ -114,15 +220,37 @@ impl RpcWithParams for SpeculativeExec {
         }
 
         let result = effect_builder
-            .speculative_execute_deploy(execution_prestate, Arc::clone(&deploy))
+            .speculative_execute_deploy(execution_prestate, Arc::clone(&deploy), report_conflicts)
             .await;
 
         match result {
-            Ok(Some(execution_result)) => {
+            Ok(Some(SpeculativeExecutionResult {
+                execution_result,
+                error_code,
+                warnings,
+                reads,
+            })) => {
+                let conflict_report = match reads {
+                    Some(reads) => {
+                        let block_execution_results = effect_builder
+                            .get_execution_results_from_storage(block_hash)
+                            .await
+                            .unwrap_or_default();
+                        Some(ConflictReport::new(
+                            &execution_result,
+                            &reads,
+                            &block_execution_results,
+                        ))
+                    }
+                    None => None,
+                };
                 let result = Self::ResponseResult {
                     api_version,
                     block_hash,
                     execution_result,
+                    error_code,
+                    warnings,
+                    conflict_report,
                 };
                 Ok(result)
             }
@@ -132,7 +260,13 @@ impl RpcWithParams for SpeculativeExec {
             )),
             Err(error) => {
                 let rpc_error = match error {
-                    EngineStateError::RootNotFound(_) => Error::new(ErrorCode::NoSuchStateRoot, ""),
+                    EngineStateError::RootNotFound(state_root_hash) => Error::new(
+                        ErrorCode::NoSuchStateRoot,
+                        format!(
+                            "global state root {} of block {} is not retained",
+                            state_root_hash, block_hash
+                        ),
+                    ),
                     EngineStateError::WasmPreprocessing(error) => {
                         Error::new(ErrorCode::InvalidDeploy, error.to_string())
                     }
@@ -164,12 +298,17 @@ impl RpcWithParams for SpeculativeExec {
                     | EngineStateError::FailedToGetStoredWithdraws
                     | EngineStateError::FailedToGetWithdrawPurses
                     | EngineStateError::FailedToRetrieveUnbondingDelay
-                    | EngineStateError::FailedToRetrieveEraId => {
-                        Error::new(ReservedErrorCode::InternalError, error.to_string())
-                    }
+                    | EngineStateError::FailedToRetrieveEraId => Error::new(
+                        ReservedErrorCode::InternalError,
+                        format!("{} (error code {})", error, error.error_code()),
+                    ),
                     _ => Error::new(
                         ReservedErrorCode::InternalError,
-                        format!("Unhandled engine state error: {}", error),
+                        format!(
+                            "Unhandled engine state error: {} (error code {})",
+                            error,
+                            error.error_code()
+                        ),
                     ),
                 };
                 Err(rpc_error)
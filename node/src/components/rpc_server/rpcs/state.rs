@@ -25,6 +25,7 @@ use casper_types::{
 };
 
 use crate::{
+    components::storage::StateIdentifier,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{
@@ -785,6 +786,18 @@ pub enum GlobalStateIdentifier {
     StateRootHash(Digest),
 }
 
+impl From<GlobalStateIdentifier> for StateIdentifier {
+    fn from(state_identifier: GlobalStateIdentifier) -> Self {
+        match state_identifier {
+            GlobalStateIdentifier::BlockHash(block_hash) => StateIdentifier::BlockHash(block_hash),
+            GlobalStateIdentifier::BlockHeight(height) => StateIdentifier::BlockHeight(height),
+            GlobalStateIdentifier::StateRootHash(state_root_hash) => {
+                StateIdentifier::StateRootHash(state_root_hash)
+            }
+        }
+    }
+}
+
 /// Params for "query_global_state" RPC
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -840,29 +853,9 @@ impl RpcWithParams for QueryGlobalState {
         api_version: ProtocolVersion,
         params: Self::RequestParams,
     ) -> Result<Self::ResponseResult, Error> {
-        let (state_root_hash, maybe_block_header) = match params.state_identifier {
-            None => match effect_builder
-                .get_highest_complete_block_header_from_storage()
-                .await
-            {
-                None => {
-                    return Err(Error::new(
-                        ErrorCode::NoSuchBlock,
-                        "query-global-state failed to retrieve highest block header",
-                    ))
-                }
-                Some(block_header) => (
-                    *block_header.state_root_hash(),
-                    Some(JsonBlockHeader::from(block_header.clone())),
-                ),
-            },
-            Some(state_identifier) => {
-                let (state_root_hash, maybe_block_header) =
-                    get_state_root_hash_and_optional_header(effect_builder, state_identifier)
-                        .await?;
-                (state_root_hash, maybe_block_header)
-            }
-        };
+        let (state_root_hash, maybe_block_header) =
+            get_state_root_hash_and_optional_header(effect_builder, params.state_identifier)
+                .await?;
 
         let base_key = match Key::from_formatted_str(&params.key)
             .map_err(|error| format!("failed to parse key: {}", error))
@@ -946,26 +939,9 @@ impl RpcWithParams for QueryBalance {
         api_version: ProtocolVersion,
         params: Self::RequestParams,
     ) -> Result<Self::ResponseResult, Error> {
-        let state_root_hash = match params.state_identifier {
-            None => match effect_builder
-                .get_highest_complete_block_header_from_storage()
-                .await
-            {
-                None => {
-                    return Err(Error::new(
-                        ErrorCode::NoSuchBlock,
-                        "query-balance failed to retrieve highest block header",
-                    ))
-                }
-                Some(block_header) => *block_header.state_root_hash(),
-            },
-            Some(state_identifier) => {
-                let (state_root_hash, _) =
-                    get_state_root_hash_and_optional_header(effect_builder, state_identifier)
-                        .await?;
-                state_root_hash
-            }
-        };
+        let (state_root_hash, _) =
+            get_state_root_hash_and_optional_header(effect_builder, params.state_identifier)
+                .await?;
 
         let purse_uref = match params.purse_identifier {
             PurseIdentifier::MainPurseUnderPublicKey(account_public_key) => {
@@ -1186,48 +1162,17 @@ async fn get_account<REv: ReactorEventT>(
     }
 }
 
+/// Resolves the given state identifier, or the highest complete block if `None`, to a state root
+/// hash along with the header of the block it belongs to, if any.
 pub(super) async fn get_state_root_hash_and_optional_header<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
-    state_identifier: GlobalStateIdentifier,
+    maybe_state_identifier: Option<GlobalStateIdentifier>,
 ) -> Result<(Digest, Option<JsonBlockHeader>), Error> {
-    // This RPC request is restricted by the block availability index.
-    let only_from_available_block_range = true;
-    match state_identifier {
-        GlobalStateIdentifier::BlockHash(block_hash) => {
-            match effect_builder
-                .get_block_header_from_storage(block_hash, only_from_available_block_range)
-                .await
-            {
-                None => {
-                    let error_msg =
-                        format!("failed to retrieve specified block header {}", block_hash);
-                    Err(Error::new(ErrorCode::NoSuchBlock, error_msg))
-                }
-                Some(block_header) => {
-                    let json_block_header = JsonBlockHeader::from(block_header.clone());
-                    Ok((*block_header.state_root_hash(), Some(json_block_header)))
-                }
-            }
-        }
-        GlobalStateIdentifier::BlockHeight(block_height) => {
-            match effect_builder
-                .get_block_header_at_height_from_storage(
-                    block_height,
-                    only_from_available_block_range,
-                )
-                .await
-            {
-                None => {
-                    let error_msg =
-                        format!("failed to retrieve block header at height {}", block_height);
-                    Err(Error::new(ErrorCode::NoSuchBlock, error_msg))
-                }
-                Some(block_header) => {
-                    let json_block_header = JsonBlockHeader::from(block_header.clone());
-                    Ok((*block_header.state_root_hash(), Some(json_block_header)))
-                }
-            }
-        }
-        GlobalStateIdentifier::StateRootHash(state_root_hash) => Ok((state_root_hash, None)),
-    }
+    let state_identifier =
+        maybe_state_identifier.map_or(StateIdentifier::HighestCompleteBlock, StateIdentifier::from);
+    let resolved = common::resolve_state_identifier(effect_builder, state_identifier).await?;
+    Ok((
+        resolved.state_root_hash,
+        resolved.block_header.map(JsonBlockHeader::from),
+    ))
 }
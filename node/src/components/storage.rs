@@ -32,9 +32,11 @@
 
 pub(crate) mod disjoint_sequences;
 mod error;
+mod execution_results_retention;
 mod lmdb_ext;
 mod metrics;
 mod object_pool;
+mod state_identifier;
 #[cfg(test)]
 mod tests;
 
@@ -66,7 +68,7 @@ use smallvec::SmallVec;
 use static_assertions::const_assert;
 #[cfg(test)]
 use tempfile::TempDir;
-use tracing::{debug, error, info, trace, warn};
+use tracing::{debug, error, info, info_span, trace, warn};
 
 use casper_hashing::Digest;
 use casper_types::{
@@ -94,9 +96,9 @@ use crate::{
         BlockExecutionResultsOrChunk, BlockExecutionResultsOrChunkId, BlockHash,
         BlockHashAndHeight, BlockHashHeightAndEra, BlockHeader, BlockHeaderWithMetadata,
         BlockSignatures, BlockWithMetadata, Deploy, DeployHash, DeployHeader, DeployId,
-        DeployMetadata, DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature,
-        FinalizedApprovals, FinalizedBlock, LegacyDeploy, MaxTtl, NodeId, SyncLeap,
-        SyncLeapIdentifier, ValueOrChunk,
+        DeployMetadata, DeployMetadataExt, DeployStatus, DeployWithFinalizedApprovals,
+        FinalitySignature, FinalizedApprovals, FinalizedBlock, LegacyDeploy, MaxTtl, NodeId,
+        SyncLeap, SyncLeapIdentifier, ValueOrChunk,
     },
     utils::{display_error, WithDir},
     NodeRng,
@@ -104,9 +106,11 @@ use crate::{
 use disjoint_sequences::{DisjointSequences, Sequence};
 pub use error::FatalStorageError;
 use error::GetRequestError;
+pub use execution_results_retention::ExecutionResultsRetention;
 use lmdb_ext::{BytesreprError, LmdbExtError, TransactionExt, WriteTransactionExt};
 use metrics::Metrics;
 use object_pool::ObjectPool;
+pub(crate) use state_identifier::{ResolvedStateIdentifier, StateIdentifier, StateIdentifierError};
 
 const COMPONENT_NAME: &str = "storage";
 
@@ -132,6 +136,10 @@ const DEFAULT_MAX_STATE_STORE_SIZE: usize = 10 * GIB;
 const MAX_DB_COUNT: u32 = 9;
 /// Key under which completed blocks are to be stored.
 const COMPLETED_BLOCKS_STORAGE_KEY: &[u8] = b"completed_blocks_disjoint_sequences";
+/// Key under which the height below which execution results have been pruned is to be stored.
+const EXECUTION_RESULTS_PRUNED_BELOW_STORAGE_KEY: &[u8] = b"execution_results_pruned_below";
+/// Maximum number of blocks whose execution results are pruned each time a block is stored.
+const EXECUTION_RESULTS_PRUNE_BATCH_SIZE: u64 = 100;
 /// Name of the file created when initializing a force resync.
 const FORCE_RESYNC_FILE_NAME: &str = "force_resync";
 
@@ -217,6 +225,10 @@ pub struct Storage {
     metrics: Option<Metrics>,
     /// The maximum TTL of a deploy.
     max_ttl: MaxTtl,
+    /// Policy on which execution results are retained.
+    execution_results_retention: ExecutionResultsRetention,
+    /// The height below which execution results of eras which aren't retained have been pruned.
+    execution_results_pruned_below: u64,
 }
 
 /// A storage component event.
@@ -519,8 +531,18 @@ impl Storage {
             recent_era_count,
             max_ttl,
             metrics,
+            execution_results_retention: config.execution_results_retention,
+            execution_results_pruned_below: 0,
         };
 
+        if let Some(raw) = component
+            .read_state_store(&Cow::Borrowed(EXECUTION_RESULTS_PRUNED_BELOW_STORAGE_KEY))?
+        {
+            let (pruned_below, _) = u64::from_bytes(&raw)
+                .map_err(FatalStorageError::UnexpectedDeserializationFailure)?;
+            component.execution_results_pruned_below = pruned_below;
+        }
+
         if force_resync {
             let force_resync_file_path = component.root_path().join(FORCE_RESYNC_FILE_NAME);
             // Check if resync is already in progress. Force resync will kick
@@ -924,7 +946,7 @@ impl Storage {
             } => {
                 let env = Rc::clone(&self.env);
                 let mut txn = env.begin_rw_txn()?;
-                self.write_execution_results(&mut txn, &block_hash, execution_results)?;
+                self.write_execution_results(&mut txn, &block_hash, execution_results, &[])?;
                 txn.commit()?;
                 responder.respond(()).ignore()
             }
@@ -959,6 +981,12 @@ impl Storage {
 
                 responder.respond(Some((deploy, metadata_ext))).ignore()
             }
+            StorageRequest::GetDeployStatus {
+                deploy_hash,
+                responder,
+            } => responder
+                .respond(self.get_deploy_status(&deploy_hash, Timestamp::now())?)
+                .ignore(),
             StorageRequest::GetBlockAndMetadataByHash {
                 block_hash,
                 only_from_available_block_range,
@@ -1137,6 +1165,12 @@ impl Storage {
                     .read_block_header_by_height(block_height, only_from_available_block_range)?;
                 responder.respond(maybe_header).ignore()
             }
+            StorageRequest::ResolveStateIdentifier {
+                state_identifier,
+                responder,
+            } => responder
+                .respond(self.resolve_state_identifier(state_identifier)?)
+                .ignore(),
             StorageRequest::PutBlockHeader {
                 block_header,
                 responder,
@@ -1250,8 +1284,9 @@ impl Storage {
 
     /// Put a single deploy into storage.
     pub fn put_deploy(&self, deploy: &Deploy) -> Result<bool, FatalStorageError> {
-        let mut txn = self.env.begin_rw_txn()?;
         let deploy_hash = deploy.hash();
+        let _span = info_span!("put_deploy", deploy_hash = ?deploy_hash.inner()).entered();
+        let mut txn = self.env.begin_rw_txn()?;
         let outcome = txn.put_value(self.deploy_db, deploy_hash, deploy, false)?;
         if outcome {
             debug!(%deploy_hash, "Storage: new deploy stored");
@@ -1262,7 +1297,7 @@ impl Storage {
         Ok(outcome)
     }
 
-    fn put_executed_block(
+    pub(crate) fn put_executed_block(
         &mut self,
         block: &Block,
         approvals_hashes: &ApprovalsHashes,
@@ -1276,7 +1311,19 @@ impl Storage {
         }
 
         let _ = self.write_approvals_hashes(&mut txn, approvals_hashes)?;
-        let _ = self.write_execution_results(&mut txn, block.hash(), execution_results)?;
+        let unretained_deploy_hashes: &[DeployHash] =
+            if self.execution_results_retention.keep_transfers {
+                &[]
+            } else {
+                block.transfer_hashes()
+            };
+        let _ = self.write_execution_results(
+            &mut txn,
+            block.hash(),
+            execution_results,
+            unretained_deploy_hashes,
+        )?;
+        self.prune_execution_results(&mut txn, block.header().era_id())?;
         txn.commit()?;
 
         Ok(true)
@@ -1464,15 +1511,28 @@ impl Storage {
         Ok(wrote)
     }
 
+    /// Writes the execution results of a block, other than those of `unretained_deploy_hashes`.
+    ///
+    /// The transfers of all the execution results are written.
     fn write_execution_results(
         &mut self,
         txn: &mut RwTransaction,
         block_hash: &BlockHash,
         execution_results: HashMap<DeployHash, ExecutionResult>,
+        unretained_deploy_hashes: &[DeployHash],
     ) -> Result<bool, FatalStorageError> {
         let mut transfers: Vec<Transfer> = vec![];
         for (deploy_hash, execution_result) in execution_results {
+            let _span = info_span!(
+                "write_execution_result",
+                deploy_hash = ?deploy_hash.inner(),
+                %block_hash
+            )
+            .entered();
             transfers.extend(successful_transfers(&execution_result));
+            if unretained_deploy_hashes.contains(&deploy_hash) {
+                continue;
+            }
 
             let mut metadata = self
                 .get_deploy_metadata(txn, &deploy_hash)?
@@ -1507,6 +1567,88 @@ impl Storage {
         Ok(was_written)
     }
 
+    /// Prunes the execution results of up to `EXECUTION_RESULTS_PRUNE_BATCH_SIZE` blocks from eras
+    /// which are no longer retained once a block of `era_id` is stored.
+    fn prune_execution_results(
+        &mut self,
+        txn: &mut RwTransaction,
+        era_id: EraId,
+    ) -> Result<(), FatalStorageError> {
+        let last_pruned_era = match self
+            .execution_results_retention
+            .oldest_retained_era(era_id)
+            .and_then(EraId::predecessor)
+        {
+            Some(last_pruned_era) => last_pruned_era,
+            None => return Ok(()),
+        };
+        // The switch block of the last pruned era is the highest block whose results are pruned.
+        let last_pruned_height = match self.switch_block_era_id_index.get(&last_pruned_era) {
+            Some(switch_block_hash) => {
+                match self.get_single_block_header(txn, switch_block_hash)? {
+                    Some(switch_block_header) => switch_block_header.height(),
+                    None => return Ok(()),
+                }
+            }
+            None => return Ok(()),
+        };
+        let prune_below = last_pruned_height.saturating_add(1).min(
+            self.execution_results_pruned_below
+                .saturating_add(EXECUTION_RESULTS_PRUNE_BATCH_SIZE),
+        );
+        if prune_below <= self.execution_results_pruned_below {
+            return Ok(());
+        }
+
+        for height in self.execution_results_pruned_below..prune_below {
+            let block_hash = match self.block_height_index.get(&height) {
+                Some(block_hash) => *block_hash,
+                None => continue,
+            };
+            let block_header = match self.get_single_block_header(txn, &block_hash)? {
+                Some(block_header) => block_header,
+                None => continue,
+            };
+            let block_body =
+                match get_body_for_block_header(txn, block_header.body_hash(), self.block_body_db)?
+                {
+                    Some(block_body) => block_body,
+                    None => continue,
+                };
+            for deploy_hash in block_body.deploy_and_transfer_hashes() {
+                let mut metadata = match self.get_deploy_metadata(txn, deploy_hash)? {
+                    Some(metadata) => metadata,
+                    None => continue,
+                };
+                if metadata.execution_results.remove(&block_hash).is_none() {
+                    continue;
+                }
+                if metadata.execution_results.is_empty() {
+                    txn.del(self.deploy_metadata_db, deploy_hash, None)?;
+                } else {
+                    let _ = txn.put_value(self.deploy_metadata_db, deploy_hash, &metadata, true)?;
+                }
+            }
+        }
+        debug!(
+            from = self.execution_results_pruned_below,
+            to = prune_below,
+            "pruned execution results"
+        );
+
+        self.execution_results_pruned_below = prune_below;
+        let serialized = prune_below
+            .to_bytes()
+            .map_err(FatalStorageError::UnexpectedSerializationFailure)?;
+        txn.put(
+            self.state_store_db,
+            &EXECUTION_RESULTS_PRUNED_BELOW_STORAGE_KEY,
+            &serialized,
+            WriteFlags::default(),
+        )?;
+        Ok(())
+    }
+
     /// Writes approvals hashes to storage.
     fn write_approvals_hashes(
         &mut self,
@@ -1679,6 +1821,56 @@ impl Storage {
         res
     }
 
+    /// Resolves the given state identifier to a state root hash.
+    ///
+    /// Blocks are only considered if they lie in the available block range.  A known block lying
+    /// below it is reported as having had its global state pruned.
+    pub(crate) fn resolve_state_identifier(
+        &self,
+        state_identifier: StateIdentifier,
+    ) -> Result<Result<ResolvedStateIdentifier, StateIdentifierError>, FatalStorageError> {
+        let mut txn = self.env.begin_ro_txn()?;
+        let maybe_block_header = match state_identifier {
+            StateIdentifier::HighestCompleteBlock => {
+                self.get_highest_complete_block_header(&mut txn)?
+            }
+            StateIdentifier::BlockHash(block_hash) => {
+                self.get_single_block_header(&mut txn, &block_hash)?
+            }
+            StateIdentifier::BlockHeight(height) => match self.block_height_index.get(&height) {
+                Some(block_hash) => self.get_single_block_header(&mut txn, block_hash)?,
+                None => None,
+            },
+            StateIdentifier::StateRootHash(state_root_hash) => {
+                return Ok(Ok(ResolvedStateIdentifier {
+                    state_root_hash,
+                    block_header: None,
+                }));
+            }
+        };
+        let block_header = match maybe_block_header {
+            Some(block_header) => block_header,
+            None => return Ok(Err(StateIdentifierError::NoSuchBlock(state_identifier))),
+        };
+
+        let available_block_range = self.get_available_block_range();
+        if block_header.height() < available_block_range.low() {
+            return Ok(Err(StateIdentifierError::RootPruned {
+                state_root_hash: *block_header.state_root_hash(),
+                block_height: block_header.height(),
+                pruned_at_height: available_block_range.low(),
+            }));
+        }
+        if !available_block_range.contains(block_header.height()) {
+            return Ok(Err(StateIdentifierError::NoSuchBlock(state_identifier)));
+        }
+
+        Ok(Ok(ResolvedStateIdentifier {
+            state_root_hash: *block_header.state_root_hash(),
+            block_header: Some(block_header),
+        }))
+    }
+
     /// Retrieves a single block header by hash.
     pub fn read_block_header(
         &self,
@@ -1809,6 +2001,45 @@ impl Storage {
             .map(BlockHashAndHeight::from))
     }
 
+    /// Retrieves the lifecycle status of the given deploy as of `now`.
+    ///
+    /// A deploy is only reported as executed once its execution results are stored and the block
+    /// in which it was executed is complete, i.e. has sufficient finality signatures.
+    fn get_deploy_status(
+        &self,
+        deploy_hash: &DeployHash,
+        now: Timestamp,
+    ) -> Result<DeployStatus, FatalStorageError> {
+        let mut txn = self.env.begin_ro_txn()?;
+        let deploy: Deploy = match txn.get_value(self.deploy_db, deploy_hash)? {
+            Some(deploy) => deploy,
+            None => return Ok(DeployStatus::Unknown),
+        };
+
+        let BlockHashHeightAndEra {
+            block_hash,
+            block_height,
+            ..
+        } = match self.deploy_hash_index.get(deploy_hash) {
+            Some(block_hash_height_and_era) => *block_hash_height_and_era,
+            None if deploy.header().expired(now) => return Ok(DeployStatus::Expired),
+            None => return Ok(DeployStatus::Pending),
+        };
+
+        let maybe_execution_result = self
+            .get_deploy_metadata(&mut txn, deploy_hash)?
+            .and_then(|mut metadata| metadata.execution_results.remove(&block_hash));
+        match maybe_execution_result {
+            Some(execution_result) if self.completed_blocks.contains(block_height) => Ok(
+                DeployStatus::executed(block_hash, block_height, &execution_result),
+            ),
+            _ => Ok(DeployStatus::Included {
+                block_hash,
+                block_height,
+            }),
+        }
+    }
+
     /// Retrieves the highest block from storage, if one exists. May return an LMDB error.
     fn get_highest_block<Tx: Transaction>(
         &self,
@@ -2813,6 +3044,9 @@ pub struct Config {
     pub enable_mem_deduplication: bool,
     /// How many loads before memory duplication checks for dead references.
     pub mem_pool_prune_interval: u16,
+    /// Policy on which execution results are retained.
+    #[serde(default)]
+    pub execution_results_retention: ExecutionResultsRetention,
 }
 
 impl Default for Config {
@@ -2826,6 +3060,7 @@ impl Default for Config {
             max_state_store_size: DEFAULT_MAX_STATE_STORE_SIZE,
             enable_mem_deduplication: true,
             mem_pool_prune_interval: 4096,
+            execution_results_retention: ExecutionResultsRetention::default(),
         }
     }
 }
@@ -186,6 +186,13 @@ impl DisjointSequences {
         &self.sequences
     }
 
+    /// Returns `true` if `value` exists in the disjoint sequences.
+    pub(super) fn contains(&self, value: u64) -> bool {
+        self.sequences
+            .iter()
+            .any(|sequence| value >= sequence.low && value <= sequence.high)
+    }
+
     /// Reduces the sequence(s), keeping all entries below and including `max_value`.  If
     /// `max_value` is not already included in a sequence, it will not be added.
     ///
@@ -217,13 +224,6 @@ impl DisjointSequences {
             self.insert(height);
         })
     }
-
-    /// Returns `true` if `value` exists in the disjoint sequences.
-    fn contains(&self, value: u64) -> bool {
-        self.sequences
-            .iter()
-            .any(|sequence| value >= sequence.low && value <= sequence.high)
-    }
 }
 
 impl FromBytes for Sequence {
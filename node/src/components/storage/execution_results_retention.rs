@@ -0,0 +1,80 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use casper_types::EraId;
+
+/// Policy on which execution results are retained by the storage component.
+///
+/// Execution results which aren't retained can no longer be served to peers or clients, but can be
+/// backfilled on demand from peers which still hold them.
+#[derive(Clone, Copy, DataSize, Debug, Deserialize, Serialize, PartialEq, Eq)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct ExecutionResultsRetention {
+    /// The number of most recent eras whose blocks' execution results are retained.  Results of
+    /// blocks in older eras are pruned gradually as new blocks are stored.
+    ///
+    /// `0` retains the execution results of all eras.
+    #[serde(default)]
+    pub era_count: u64,
+    /// Whether the execution results of native transfers are stored.
+    #[serde(default = "default_keep_transfers")]
+    pub keep_transfers: bool,
+}
+
+fn default_keep_transfers() -> bool {
+    true
+}
+
+impl ExecutionResultsRetention {
+    /// Returns the oldest era whose execution results are retained once a block of `era_id` is
+    /// stored, or `None` if the results of all eras are retained.
+    pub(super) fn oldest_retained_era(&self, era_id: EraId) -> Option<EraId> {
+        if self.era_count == 0 {
+            return None;
+        }
+        Some(EraId::from(
+            era_id
+                .value()
+                .saturating_add(1)
+                .saturating_sub(self.era_count),
+        ))
+    }
+}
+
+impl Default for ExecutionResultsRetention {
+    fn default() -> Self {
+        ExecutionResultsRetention {
+            era_count: 0,
+            keep_transfers: default_keep_transfers(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_retain_all_eras_by_default() {
+        let retention = ExecutionResultsRetention::default();
+        assert!(retention.keep_transfers);
+        assert_eq!(retention.oldest_retained_era(EraId::from(100)), None);
+    }
+
+    #[test]
+    fn should_retain_most_recent_eras() {
+        let retention = ExecutionResultsRetention {
+            era_count: 3,
+            ..ExecutionResultsRetention::default()
+        };
+        assert_eq!(
+            retention.oldest_retained_era(EraId::from(10)),
+            Some(EraId::from(8))
+        );
+        assert_eq!(
+            retention.oldest_retained_era(EraId::from(1)),
+            Some(EraId::from(0))
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use casper_hashing::Digest;
+
+use crate::types::{BlockHash, BlockHeader};
+
+/// Identifies the global state to be resolved to a state root hash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub(crate) enum StateIdentifier {
+    /// The state after the highest complete block.
+    HighestCompleteBlock,
+    /// The state after the block with the given hash.
+    BlockHash(BlockHash),
+    /// The state after the block at the given height.
+    BlockHeight(u64),
+    /// The state under the given root, which isn't checked against any block.
+    StateRootHash(Digest),
+}
+
+impl Display for StateIdentifier {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StateIdentifier::HighestCompleteBlock => write!(formatter, "highest complete block"),
+            StateIdentifier::BlockHash(block_hash) => write!(formatter, "block {}", block_hash),
+            StateIdentifier::BlockHeight(height) => write!(formatter, "block at height {}", height),
+            StateIdentifier::StateRootHash(state_root_hash) => {
+                write!(formatter, "state root {}", state_root_hash)
+            }
+        }
+    }
+}
+
+/// A state identifier resolved to a state root hash.
+#[derive(Clone, Debug)]
+pub(crate) struct ResolvedStateIdentifier {
+    /// The state root hash.
+    pub(crate) state_root_hash: Digest,
+    /// The header of the block the state root belongs to, unless identified by its hash directly.
+    pub(crate) block_header: Option<BlockHeader>,
+}
+
+/// An error resolving a state identifier to a state root hash.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub(crate) enum StateIdentifierError {
+    /// The identified block isn't stored, or isn't complete.
+    #[error("no complete block found for {0}")]
+    NoSuchBlock(StateIdentifier),
+    /// The identified block is known, but its global state is no longer retained.
+    #[error(
+        "global state root {state_root_hash} of block at height {block_height} is not retained \
+        as global state below height {pruned_at_height} has been pruned"
+    )]
+    RootPruned {
+        /// The state root hash of the block.
+        state_root_hash: Digest,
+        /// The height of the block.
+        block_height: u64,
+        /// The lowest height for which global state is retained.
+        pruned_at_height: u64,
+    },
+}
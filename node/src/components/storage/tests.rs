@@ -1,7 +1,7 @@
 //! Unit tests for the storage component.
 
 use std::{
-    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     fs::{self, File},
     iter::{self, FromIterator},
     rc::Rc,
@@ -13,17 +13,19 @@ use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use smallvec::smallvec;
 
+use casper_execution_engine::storage::trie::merkle_proof::TrieMerkleProof;
 use casper_types::{
     generate_ed25519_keypair, system::auction::UnbondingPurse, testing::TestRng, AccessRights,
-    EraId, ExecutionEffect, ExecutionResult, Key, ProtocolVersion, PublicKey, SecretKey, TimeDiff,
-    Transfer, Transform, TransformEntry, URef, U512,
+    CLValue, EraId, ExecutionEffect, ExecutionResult, Key, ProtocolVersion, PublicKey, SecretKey,
+    StoredValue, TimeDiff, Transfer, Transform, TransformEntry, URef, U512,
 };
 
 use super::{
     initialize_block_metadata_db,
     lmdb_ext::{deserialize_internal, serialize_internal, TransactionExt, WriteTransactionExt},
     move_storage_files_to_network_subdir, should_move_storage_files_to_network_subdir, Config,
-    Storage, FORCE_RESYNC_FILE_NAME,
+    ExecutionResultsRetention, StateIdentifier, StateIdentifierError, Storage,
+    FORCE_RESYNC_FILE_NAME,
 };
 use crate::{
     components::fetcher::{FetchItem, FetchResponse},
@@ -33,11 +35,12 @@ use crate::{
     },
     testing::{ComponentHarness, UnitTestEvent},
     types::{
-        sync_leap_validation_metadata::SyncLeapValidationMetaData, AvailableBlockRange, Block,
-        BlockHash, BlockHashAndHeight, BlockHashHeightAndEra, BlockHeader, BlockHeaderWithMetadata,
-        BlockSignatures, Chainspec, ChainspecRawBytes, Deploy, DeployHash, DeployMetadata,
-        DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature, LegacyDeploy,
-        SyncLeapIdentifier, TestBlockBuilder,
+        sync_leap_validation_metadata::SyncLeapValidationMetaData, ApprovalsHashes,
+        AvailableBlockRange, Block, BlockHash, BlockHashAndHeight, BlockHashHeightAndEra,
+        BlockHeader, BlockHeaderWithMetadata, BlockSignatures, Chainspec, ChainspecRawBytes,
+        Deploy, DeployHash, DeployMetadata, DeployMetadataExt, DeployStatus,
+        DeployWithFinalizedApprovals, FinalitySignature, LegacyDeploy, SyncLeapIdentifier,
+        TestBlockBuilder,
     },
     utils::{Loadable, WithDir},
 };
@@ -57,6 +60,7 @@ fn new_config(harness: &ComponentHarness<UnitTestEvent>) -> Config {
         max_state_store_size: 50 * MIB,
         enable_mem_deduplication: true,
         mem_pool_prune_interval: 4,
+        execution_results_retention: Default::default(),
     }
 }
 
@@ -1045,6 +1049,84 @@ fn store_execution_results_for_two_blocks() {
     );
 }
 
+#[test]
+fn should_track_deploy_status() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let deploy = Deploy::random(&mut harness.rng);
+    let deploy_hash = *deploy.hash();
+    let before_expiry = deploy.header().timestamp();
+    let after_expiry = deploy.header().expires() + TimeDiff::from_millis(1);
+
+    // Unknown until stored, then pending until expired.
+    assert_eq!(
+        storage
+            .get_deploy_status(&deploy_hash, before_expiry)
+            .unwrap(),
+        DeployStatus::Unknown
+    );
+    put_deploy(&mut harness, &mut storage, Arc::new(deploy.clone()));
+    assert_eq!(
+        storage
+            .get_deploy_status(&deploy_hash, before_expiry)
+            .unwrap(),
+        DeployStatus::Pending
+    );
+    assert_eq!(
+        storage
+            .get_deploy_status(&deploy_hash, after_expiry)
+            .unwrap(),
+        DeployStatus::Expired
+    );
+
+    // Included once stored in a block, even without execution results or finality.
+    let block = TestBlockBuilder::new()
+        .deploys(iter::once(&deploy))
+        .build(&mut harness.rng);
+    storage.write_block(&block).unwrap();
+    let included = DeployStatus::Included {
+        block_hash: *block.hash(),
+        block_height: block.height(),
+    };
+    assert_eq!(
+        storage
+            .get_deploy_status(&deploy_hash, after_expiry)
+            .unwrap(),
+        included
+    );
+
+    // Still only included while the block lacks sufficient finality signatures.
+    let execution_result: ExecutionResult = harness.rng.gen();
+    let mut execution_results = HashMap::new();
+    execution_results.insert(deploy_hash, execution_result.clone());
+    put_execution_results(&mut harness, &mut storage, *block.hash(), execution_results);
+    assert_eq!(
+        storage
+            .get_deploy_status(&deploy_hash, before_expiry)
+            .unwrap(),
+        included
+    );
+
+    // Executed once the block is complete.
+    storage.mark_block_complete(block.height()).unwrap();
+    let (success, cost) = match execution_result {
+        ExecutionResult::Failure { cost, .. } => (false, cost),
+        ExecutionResult::Success { cost, .. } => (true, cost),
+    };
+    assert_eq!(
+        storage
+            .get_deploy_status(&deploy_hash, before_expiry)
+            .unwrap(),
+        DeployStatus::Executed {
+            block_hash: *block.hash(),
+            block_height: block.height(),
+            success,
+            cost,
+        }
+    );
+}
+
 #[test]
 fn store_random_execution_results() {
     let mut harness = ComponentHarness::default();
@@ -1967,6 +2049,81 @@ fn should_restrict_returned_blocks() {
         .expect("should return block failed"));
 }
 
+#[test]
+fn should_resolve_state_identifier() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    // Create the following disjoint sequences: 1-2 4-5, plus an incomplete block 6.
+    let blocks: BTreeMap<u64, Block> = [1, 2, 4, 5, 6]
+        .iter()
+        .map(|height| {
+            let block = Block::random_with_specifics(
+                &mut harness.rng,
+                EraId::from(1),
+                *height,
+                ProtocolVersion::from_parts(1, 5, 0),
+                false,
+                None,
+            );
+            storage.write_block(&block).unwrap();
+            if *height != 6 {
+                storage.completed_blocks.insert(*height);
+            }
+            (*height, block)
+        })
+        .collect();
+
+    let resolved = storage
+        .resolve_state_identifier(StateIdentifier::BlockHeight(4))
+        .unwrap()
+        .expect("should resolve block in the available range");
+    assert_eq!(resolved.state_root_hash, *blocks[&4].state_root_hash());
+    assert_eq!(resolved.block_header.as_ref(), Some(blocks[&4].header()));
+
+    let resolved = storage
+        .resolve_state_identifier(StateIdentifier::HighestCompleteBlock)
+        .unwrap()
+        .expect("should resolve highest complete block");
+    assert_eq!(resolved.state_root_hash, *blocks[&5].state_root_hash());
+
+    let state_root_hash = *blocks[&1].state_root_hash();
+    let resolved = storage
+        .resolve_state_identifier(StateIdentifier::StateRootHash(state_root_hash))
+        .unwrap()
+        .expect("should pass state root hash through");
+    assert_eq!(resolved.state_root_hash, state_root_hash);
+    assert!(resolved.block_header.is_none());
+
+    // Blocks below the available range have had their global state pruned.
+    assert_eq!(
+        storage
+            .resolve_state_identifier(StateIdentifier::BlockHash(*blocks[&1].hash()))
+            .unwrap()
+            .unwrap_err(),
+        StateIdentifierError::RootPruned {
+            state_root_hash,
+            block_height: 1,
+            pruned_at_height: 4,
+        }
+    );
+
+    // Unknown and incomplete blocks are reported as missing.
+    for state_identifier in [
+        StateIdentifier::BlockHeight(3),
+        StateIdentifier::BlockHeight(6),
+        StateIdentifier::BlockHash(*blocks[&6].hash()),
+    ] {
+        assert_eq!(
+            storage
+                .resolve_state_identifier(state_identifier)
+                .unwrap()
+                .unwrap_err(),
+            StateIdentifierError::NoSuchBlock(state_identifier)
+        );
+    }
+}
+
 #[test]
 fn should_get_block_header_by_height() {
     let mut harness = ComponentHarness::default();
@@ -2235,3 +2392,139 @@ fn should_initialize_block_metadata_db() {
     assert_signatures(&storage, *block_3.hash(), vec![]);
     assert_signatures(&storage, *block_4.hash(), vec![]);
 }
+
+fn storage_fixture_with_retention(
+    harness: &ComponentHarness<UnitTestEvent>,
+    execution_results_retention: ExecutionResultsRetention,
+) -> Storage {
+    let cfg = Config {
+        execution_results_retention,
+        ..new_config(harness)
+    };
+    Storage::new(
+        &WithDir::new(harness.tmp.path(), cfg),
+        None,
+        ProtocolVersion::from_parts(1, 0, 0),
+        EraId::default(),
+        "test",
+        MAX_TTL.into(),
+        RECENT_ERA_COUNT,
+        None,
+        false,
+    )
+    .expect("could not create storage component fixture")
+}
+
+/// Stores `block` the way the contract runtime does once it has been executed.
+fn put_executed_block(
+    storage: &mut Storage,
+    block: &Block,
+    execution_results: HashMap<DeployHash, ExecutionResult>,
+) {
+    let merkle_proof_approvals = TrieMerkleProof::new(
+        Key::ChecksumRegistry,
+        StoredValue::CLValue(CLValue::from_t(()).unwrap()),
+        VecDeque::new(),
+    );
+    let approvals_hashes = ApprovalsHashes::new(block.hash(), vec![], merkle_proof_approvals);
+    assert!(storage
+        .put_executed_block(block, &approvals_hashes, execution_results)
+        .unwrap());
+}
+
+#[test]
+fn should_not_retain_transfer_results_if_configured() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture_with_retention(
+        &harness,
+        ExecutionResultsRetention {
+            keep_transfers: false,
+            ..ExecutionResultsRetention::default()
+        },
+    );
+
+    let deploy = Deploy::random(&mut harness.rng);
+    let transfer = Deploy::random_valid_native_transfer(&mut harness.rng);
+    let block = TestBlockBuilder::new()
+        .deploys(iter::once(&deploy))
+        .transfers(iter::once(&transfer))
+        .build(&mut harness.rng);
+
+    let deploy_result: ExecutionResult = harness.rng.gen();
+    let (transfer_result, expected_transfer) =
+        prepare_exec_result_with_transfer(&mut harness.rng, transfer.hash());
+    let execution_results = HashMap::from([
+        (*deploy.hash(), deploy_result.clone()),
+        (*transfer.hash(), transfer_result),
+    ]);
+    put_executed_block(&mut storage, &block, execution_results);
+
+    let deploy_metadata = storage
+        .get_deploy_metadata_by_hash(deploy.hash())
+        .expect("should have results of the deploy");
+    assert_eq!(
+        deploy_metadata.execution_results,
+        HashMap::from([(*block.hash(), deploy_result)])
+    );
+    assert!(storage
+        .get_deploy_metadata_by_hash(transfer.hash())
+        .is_none());
+
+    // The transfers themselves are still stored.
+    let retrieved_transfers = storage
+        .get_transfers(block.hash())
+        .expect("should execute get")
+        .expect("should return Some");
+    assert_eq!(retrieved_transfers, vec![expected_transfer]);
+}
+
+#[test]
+fn should_prune_results_of_unretained_eras() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture_with_retention(
+        &harness,
+        ExecutionResultsRetention {
+            era_count: 1,
+            ..ExecutionResultsRetention::default()
+        },
+    );
+
+    // Era 0 consists of blocks 0 and 1, era 1 starts with block 2.
+    let deploys: Vec<Deploy> = iter::repeat_with(|| Deploy::random(&mut harness.rng))
+        .take(3)
+        .collect();
+    for (height, deploy) in deploys.iter().enumerate() {
+        let height = height as u64;
+        let block = TestBlockBuilder::new()
+            .era(height / 2)
+            .height(height)
+            .switch_block(height == 1)
+            .deploys(iter::once(deploy))
+            .build(&mut harness.rng);
+        let execution_result: ExecutionResult = harness.rng.gen();
+        put_executed_block(
+            &mut storage,
+            &block,
+            HashMap::from([(*deploy.hash(), execution_result)]),
+        );
+
+        // Nothing is pruned until the first block of era 1 is stored.
+        if height < 2 {
+            for deploy in &deploys[..=height as usize] {
+                assert!(storage.get_deploy_metadata_by_hash(deploy.hash()).is_some());
+            }
+            assert_eq!(storage.execution_results_pruned_below, 0);
+        }
+    }
+    // Storing block 2 pruned the results of era 0.
+    assert!(storage
+        .get_deploy_metadata_by_hash(deploys[0].hash())
+        .is_none());
+    assert!(storage
+        .get_deploy_metadata_by_hash(deploys[1].hash())
+        .is_none());
+    assert!(storage
+        .get_deploy_metadata_by_hash(deploys[2].hash())
+        .is_some());
+    assert_eq!(storage.execution_results_pruned_below, 2);
+}
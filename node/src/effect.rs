@@ -102,6 +102,7 @@ use std::{
     fmt::{self, Debug, Display, Formatter},
     future::Future,
     mem,
+    path::PathBuf,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -117,9 +118,9 @@ use tracing::{debug, error, warn};
 use casper_execution_engine::{
     core::engine_state::{
         self, era_validators::GetEraValidatorsError, BalanceRequest, BalanceResult, GetBidsRequest,
-        GetBidsResult, QueryRequest, QueryResult,
+        GetBidsResult, QueryRequest, QueryResult, StepReport,
     },
-    shared::execution_journal::ExecutionJournal,
+    shared::{execution_journal::ExecutionJournal, module_cache::ModuleCacheStats},
     storage::trie::TrieRaw,
 };
 use casper_hashing::Digest;
@@ -136,24 +137,27 @@ use crate::{
             TrieAccumulatorError, TrieAccumulatorResponse,
         },
         consensus::{ClContext, EraDump, ProposedBlock, ValidatorChange},
-        contract_runtime::{ContractRuntimeError, EraValidatorsRequest},
+        contract_runtime::{
+            ContractRuntimeError, EraValidatorsRequest, StateSnapshotError, StateSnapshotHeader,
+        },
         deploy_acceptor,
         diagnostics_port::StopAtSpec,
         fetcher::{FetchItem, FetchResult},
         gossiper::GossipItem,
         network::{blocklist::BlocklistJustification, FromIncoming, NetworkInsights},
+        storage::{ResolvedStateIdentifier, StateIdentifier, StateIdentifierError},
         upgrade_watcher::NextUpgrade,
     },
-    contract_runtime::SpeculativeExecutionState,
+    contract_runtime::{SpeculativeExecutionResult, SpeculativeExecutionState},
     failpoints::FailpointActivation,
     reactor::{main_reactor::ReactorState, EventQueueHandle, QueueKind},
     types::{
         appendable_block::AppendableBlock, ApprovalsHashes, AvailableBlockRange, Block,
         BlockExecutionResultsOrChunk, BlockExecutionResultsOrChunkId, BlockHash, BlockHeader,
         BlockSignatures, BlockWithMetadata, ChainspecRawBytes, Deploy, DeployHash, DeployHeader,
-        DeployId, DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature,
+        DeployId, DeployMetadataExt, DeployStatus, DeployWithFinalizedApprovals, FinalitySignature,
         FinalitySignatureId, FinalizedApprovals, FinalizedBlock, LegacyDeploy, MetaBlock,
-        MetaBlockState, NodeId, TrieOrChunk, TrieOrChunkId,
+        MetaBlockState, NodeId, StepEffect, TrieOrChunk, TrieOrChunkId,
     },
     utils::{fmt_limit::FmtLimit, SharedFlag, Source},
 };
@@ -1010,6 +1014,7 @@ impl<REv> EffectBuilder<REv> {
         self,
         era_id: EraId,
         execution_journal: ExecutionJournal,
+        step_report: StepReport,
     ) where
         REv: From<ContractRuntimeAnnouncement>,
     {
@@ -1018,6 +1023,8 @@ impl<REv> EffectBuilder<REv> {
                 ContractRuntimeAnnouncement::CommitStepSuccess {
                     era_id,
                     execution_effect: ExecutionEffect::from(&execution_journal),
+                    step_effects: StepEffect::categorize(&execution_journal),
+                    step_report: Box::new(step_report),
                 },
                 QueueKind::ContractRuntime,
             )
@@ -1199,6 +1206,25 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Resolves the given state identifier to a state root hash, along with the header of the
+    /// block it belongs to, if any.
+    pub(crate) async fn resolve_state_identifier(
+        self,
+        state_identifier: StateIdentifier,
+    ) -> Result<ResolvedStateIdentifier, StateIdentifierError>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::ResolveStateIdentifier {
+                state_identifier,
+                responder,
+            },
+            QueueKind::FromStorage,
+        )
+        .await
+    }
+
     /// Gets the requested signature for a given block hash.
     pub(crate) async fn get_signature_from_storage(
         self,
@@ -1578,6 +1604,21 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the lifecycle status of the requested deploy from storage.
+    pub(crate) async fn get_deploy_status(self, deploy_hash: DeployHash) -> DeployStatus
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetDeployStatus {
+                deploy_hash,
+                responder,
+            },
+            QueueKind::FromStorage,
+        )
+        .await
+    }
+
     /// Gets the requested block and its finality signatures.
     pub(crate) async fn get_block_at_height_with_metadata_from_storage(
         self,
@@ -1986,7 +2027,7 @@ impl<REv> EffectBuilder<REv> {
     {
         self.make_request(
             |responder| ContractRuntimeRequest::GetBalance {
-                balance_request,
+                balance_request: Box::new(balance_request),
                 responder,
             },
             QueueKind::ContractRuntime,
@@ -2029,6 +2070,52 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Returns the statistics of the contract runtime's cache of preprocessed Wasm modules.
+    pub(crate) async fn get_module_cache_stats(self) -> ModuleCacheStats
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::GetModuleCacheStats { responder },
+            QueueKind::ContractRuntime,
+        )
+        .await
+    }
+
+    /// Drops all modules from the contract runtime's cache of preprocessed Wasm modules, returning
+    /// how many there were.
+    pub(crate) async fn flush_module_cache(self) -> usize
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::FlushModuleCache { responder },
+            QueueKind::ContractRuntime,
+        )
+        .await
+    }
+
+    /// Writes a snapshot of the global state of the block of `header` to `path`, returning the
+    /// number of tries written.
+    pub(crate) async fn write_state_snapshot(
+        self,
+        header: StateSnapshotHeader,
+        path: PathBuf,
+    ) -> Result<usize, StateSnapshotError>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::WriteStateSnapshot {
+                header: Box::new(header),
+                path,
+                responder,
+            },
+            QueueKind::ContractRuntime,
+        )
+        .await
+    }
+
     /// Returns the value of the execution results checksum stored in the ChecksumRegistry for the
     /// given state root hash.
     pub(crate) async fn get_execution_results_checksum(
@@ -2177,6 +2264,22 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the gas cost the chainspec fixes for the native operation `entry_point`, or `None` if
+    /// it isn't one.
+    pub(crate) async fn get_native_cost(self, entry_point: String) -> Option<u64>
+    where
+        REv: From<ChainspecRawBytesRequest> + Send,
+    {
+        self.make_request(
+            |responder| ChainspecRawBytesRequest::GetNativeCost {
+                entry_point,
+                responder,
+            },
+            QueueKind::NetworkInfo,
+        )
+        .await
+    }
+
     /// Stores a set of given finalized approvals in storage.
     ///
     /// Any previously stored finalized approvals for the given hash are quietly overwritten
@@ -2205,7 +2308,8 @@ impl<REv> EffectBuilder<REv> {
         self,
         execution_prestate: SpeculativeExecutionState,
         deploy: Arc<Deploy>,
-    ) -> Result<Option<ExecutionResult>, engine_state::Error>
+        record_reads: bool,
+    ) -> Result<Option<SpeculativeExecutionResult>, engine_state::Error>
     where
         REv: From<ContractRuntimeRequest>,
     {
@@ -2213,6 +2317,7 @@ impl<REv> EffectBuilder<REv> {
             |responder| ContractRuntimeRequest::SpeculativeDeployExecution {
                 execution_prestate,
                 deploy,
+                record_reads,
                 responder,
             },
             QueueKind::ContractRuntime,
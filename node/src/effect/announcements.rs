@@ -14,6 +14,7 @@ use datasize::DataSize;
 use itertools::Itertools;
 use serde::Serialize;
 
+use casper_execution_engine::core::engine_state::StepReport;
 use casper_types::{EraId, ExecutionEffect, PublicKey, Timestamp, U512};
 
 use crate::{
@@ -27,7 +28,9 @@ use crate::{
     },
     effect::Responder,
     failpoints::FailpointActivation,
-    types::{Block, Deploy, DeployHash, FinalitySignature, FinalizedBlock, MetaBlock, NodeId},
+    types::{
+        Block, Deploy, DeployHash, FinalitySignature, FinalizedBlock, MetaBlock, NodeId, StepEffect,
+    },
     utils::Source,
 };
 
@@ -354,6 +357,10 @@ pub(crate) enum ContractRuntimeAnnouncement {
         era_id: EraId,
         /// The operations and transforms committed to global state.
         execution_effect: ExecutionEffect,
+        /// The categorized effects of the step on the auction.
+        step_effects: Vec<StepEffect>,
+        /// What the step did to the auction.
+        step_report: Box<StepReport>,
     },
     /// New era validators.
     UpcomingEraValidators {
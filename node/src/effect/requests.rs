@@ -7,6 +7,7 @@ use std::{
     collections::{BTreeMap, HashMap, HashSet},
     fmt::{self, Debug, Display, Formatter},
     mem,
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -23,6 +24,7 @@ use casper_execution_engine::{
         get_bids::{GetBidsRequest, GetBidsResult},
         query::{QueryRequest, QueryResult},
     },
+    shared::module_cache::ModuleCacheStats,
     storage::trie::TrieRaw,
 };
 use casper_hashing::Digest;
@@ -38,15 +40,18 @@ use crate::{
             TrieAccumulatorError, TrieAccumulatorResponse,
         },
         consensus::{ClContext, ProposedBlock, ValidatorChange},
-        contract_runtime::EraValidatorsRequest,
+        contract_runtime::{EraValidatorsRequest, StateSnapshotError, StateSnapshotHeader},
         deploy_acceptor,
         diagnostics_port::StopAtSpec,
         fetcher::{FetchItem, FetchResult},
         gossiper::GossipItem,
         network::NetworkInsights,
+        storage::{ResolvedStateIdentifier, StateIdentifier, StateIdentifierError},
         upgrade_watcher::NextUpgrade,
     },
-    contract_runtime::{ContractRuntimeError, SpeculativeExecutionState},
+    contract_runtime::{
+        ContractRuntimeError, SpeculativeExecutionResult, SpeculativeExecutionState,
+    },
     effect::{AutoClosingResponder, Responder},
     reactor::main_reactor::ReactorState,
     rpcs::docs::OpenRpcSchema,
@@ -54,7 +59,7 @@ use crate::{
         appendable_block::AppendableBlock, ApprovalsHashes, AvailableBlockRange, Block,
         BlockExecutionResultsOrChunk, BlockExecutionResultsOrChunkId, BlockHash, BlockHeader,
         BlockSignatures, BlockWithMetadata, ChainspecRawBytes, Deploy, DeployHash, DeployHeader,
-        DeployId, DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature,
+        DeployId, DeployMetadataExt, DeployStatus, DeployWithFinalizedApprovals, FinalitySignature,
         FinalitySignatureId, FinalizedApprovals, FinalizedBlock, LegacyDeploy, MetaBlockState,
         NodeId, StatusFeed, TrieOrChunk, TrieOrChunkId,
     },
@@ -344,6 +349,13 @@ pub(crate) enum StorageRequest {
         /// local storage.
         responder: Responder<Option<BlockHeader>>,
     },
+    /// Resolve a state identifier to a state root hash, restricted to the available block range.
+    ResolveStateIdentifier {
+        /// The identifier to resolve.
+        state_identifier: StateIdentifier,
+        /// Responder to call with the result.
+        responder: Responder<Result<ResolvedStateIdentifier, StateIdentifierError>>,
+    },
     /// Retrieve all transfers in a block with given hash.
     GetBlockTransfers {
         /// Hash of block to get transfers of.
@@ -414,6 +426,13 @@ pub(crate) enum StorageRequest {
         /// Responder to call with the results.
         responder: Responder<Option<(DeployWithFinalizedApprovals, DeployMetadataExt)>>,
     },
+    /// Retrieve the lifecycle status of a deploy.
+    GetDeployStatus {
+        /// Hash of deploy whose status is to be retrieved.
+        deploy_hash: DeployHash,
+        /// Responder to call with the result.
+        responder: Responder<DeployStatus>,
+    },
     /// Retrieve block and its metadata by its hash.
     GetBlockAndMetadataByHash {
         /// The hash of the block.
@@ -533,6 +552,11 @@ impl Display for StorageRequest {
             StorageRequest::GetBlockHeaderByHeight { block_height, .. } => {
                 write!(formatter, "get header for height {}", block_height)
             }
+            StorageRequest::ResolveStateIdentifier {
+                state_identifier, ..
+            } => {
+                write!(formatter, "resolve state of {}", state_identifier)
+            }
             StorageRequest::GetBlockTransfers { block_hash, .. } => {
                 write!(formatter, "get transfers for {}", block_hash)
             }
@@ -562,6 +586,9 @@ impl Display for StorageRequest {
             StorageRequest::GetDeployAndMetadata { deploy_hash, .. } => {
                 write!(formatter, "get deploy and metadata for {}", deploy_hash)
             }
+            StorageRequest::GetDeployStatus { deploy_hash, .. } => {
+                write!(formatter, "get deploy status for {}", deploy_hash)
+            }
             StorageRequest::GetFinalitySignature { id, .. } => {
                 write!(formatter, "get finality signature {}", id)
             }
@@ -745,6 +772,13 @@ pub(crate) enum RpcRequest {
         /// Responder to call with the result.
         responder: Responder<Option<Box<(Deploy, DeployMetadataExt)>>>,
     },
+    /// Return the lifecycle status of the specified deploy.
+    GetDeployStatus {
+        /// The hash of the deploy whose status is to be retrieved.
+        hash: DeployHash,
+        /// Responder to call with the result.
+        responder: Responder<DeployStatus>,
+    },
     /// Return the connected peers.
     GetPeers {
         /// Responder to call with the result.
@@ -760,6 +794,11 @@ pub(crate) enum RpcRequest {
         /// Responder to call with the result.
         responder: Responder<AvailableBlockRange>,
     },
+    /// Return how long past its expiry a deploy's hash remains replay-protected.
+    GetReplayProtectionWindow {
+        /// Responder to call with the result.
+        responder: Responder<TimeDiff>,
+    },
 }
 
 impl Display for RpcRequest {
@@ -805,11 +844,15 @@ impl Display for RpcRequest {
                 "get {} (finalized approvals: {})",
                 hash, finalized_approvals
             ),
+            RpcRequest::GetDeployStatus { hash, .. } => write!(formatter, "get status of {}", hash),
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
             RpcRequest::GetAvailableBlockRange { .. } => {
                 write!(formatter, "get available block range")
             }
+            RpcRequest::GetReplayProtectionWindow { .. } => {
+                write!(formatter, "get replay protection window")
+            }
         }
     }
 }
@@ -874,7 +917,7 @@ pub(crate) enum ContractRuntimeRequest {
     GetBalance {
         /// Balance request.
         #[serde(skip_serializing)]
-        balance_request: BalanceRequest,
+        balance_request: Box<BalanceRequest>,
         /// Responder to call with the balance result.
         responder: Responder<Result<BalanceResult, engine_state::Error>>,
     },
@@ -927,8 +970,29 @@ pub(crate) enum ContractRuntimeRequest {
         execution_prestate: SpeculativeExecutionState,
         /// Deploy to execute.
         deploy: Arc<Deploy>,
+        /// Whether to record the keys the deploy reads from global state.
+        record_reads: bool,
         /// Results
-        responder: Responder<Result<Option<ExecutionResult>, engine_state::Error>>,
+        responder: Responder<Result<Option<SpeculativeExecutionResult>, engine_state::Error>>,
+    },
+    /// Returns the statistics of the cache of preprocessed Wasm modules.
+    GetModuleCacheStats {
+        /// Responder to call with the result.
+        responder: Responder<ModuleCacheStats>,
+    },
+    /// Drops all modules from the cache of preprocessed Wasm modules.
+    FlushModuleCache {
+        /// Responder to call with the number of dropped modules.
+        responder: Responder<usize>,
+    },
+    /// Writes a snapshot of the global state of a block to a file.
+    WriteStateSnapshot {
+        /// The header of the snapshot, identifying its block.
+        header: Box<StateSnapshotHeader>,
+        /// The path of the file to write.
+        path: PathBuf,
+        /// Responder to call with the number of tries written.
+        responder: Responder<Result<usize, StateSnapshotError>>,
     },
 }
 
@@ -984,6 +1048,20 @@ impl Display for ContractRuntimeRequest {
                     execution_prestate.state_root_hash
                 )
             }
+            ContractRuntimeRequest::GetModuleCacheStats { .. } => {
+                write!(formatter, "get module cache stats")
+            }
+            ContractRuntimeRequest::FlushModuleCache { .. } => {
+                write!(formatter, "flush module cache")
+            }
+            ContractRuntimeRequest::WriteStateSnapshot { header, path, .. } => {
+                write!(
+                    formatter,
+                    "write state snapshot of block {} to {}",
+                    header.block_header().block_hash(),
+                    path.display()
+                )
+            }
         }
     }
 }
@@ -1084,6 +1162,13 @@ pub(crate) enum ChainspecRawBytesRequest {
     /// Request for the chainspec file bytes with the genesis_accounts and global_state bytes, if
     /// they are present.
     GetChainspecRawBytes(Responder<Arc<ChainspecRawBytes>>),
+    /// Request for the gas cost the chainspec fixes for a native operation.
+    GetNativeCost {
+        /// The entry point of the native operation.
+        entry_point: String,
+        /// Responder to call with the cost, or `None` if the entry point isn't a native operation.
+        responder: Responder<Option<u64>>,
+    },
 }
 
 impl Display for ChainspecRawBytesRequest {
@@ -1092,6 +1177,9 @@ impl Display for ChainspecRawBytesRequest {
             ChainspecRawBytesRequest::GetChainspecRawBytes(_) => {
                 write!(f, "get chainspec raw bytes")
             }
+            ChainspecRawBytesRequest::GetNativeCost { entry_point, .. } => {
+                write!(f, "get native cost of {}", entry_point)
+            }
         }
     }
 }
@@ -1,5 +1,7 @@
 //! Logging via the tracing crate.
 
+mod deploy_trace;
+
 use std::{env, fmt, io};
 
 use ansi_term::{Color, Style};
@@ -19,11 +21,15 @@ use tracing_subscriber::{
         FmtContext, FormatEvent, FormatFields, FormattedFields, Layer,
     },
     layer::Layered,
+    prelude::*,
     registry::LookupSpan,
     reload::{self, Handle},
     EnvFilter, Registry,
 };
 
+use deploy_trace::DeployTraceLayer;
+pub use deploy_trace::{deploy_trace, DeployTrace};
+
 const LOG_CONFIGURATION_ENVVAR: &str = "RUST_LOG";
 
 const LOG_FIELD_MESSAGE: &str = "message";
@@ -330,6 +336,8 @@ fn format_into_debug_writer(
 /// This function should only be called once during the lifetime of the application. Do not call
 /// this outside of the application or testing code, the installed logger is global.
 ///
+/// Besides logging, spans belonging to a deploy are recorded, see [`deploy_trace`].
+///
 /// See the `README.md` for hints on how to configure logging at runtime.
 // The `io::stdout as fn()...` casts are necessary, as is the `FormatDebugFn` cast.
 #[allow(trivial_casts)]
@@ -352,7 +360,11 @@ pub fn init_with_config(config: &LoggingConfig) -> anyhow::Result<()> {
                 .event_format(FmtEvent::new(config.color, config.abbreviate_modules))
                 .with_filter_reloading();
             let handle = ReloadHandle::Text(builder.reload_handle());
-            builder.try_init().map_err(|error| anyhow!(error))?;
+            builder
+                .finish()
+                .with(DeployTraceLayer)
+                .try_init()
+                .map_err(|error| anyhow!(error))?;
             drop(RELOAD_HANDLE.set(handle));
             Ok(())
         }
@@ -365,7 +377,11 @@ pub fn init_with_config(config: &LoggingConfig) -> anyhow::Result<()> {
                 .json()
                 .with_filter_reloading();
             let handle = ReloadHandle::Json(builder.reload_handle());
-            builder.try_init().map_err(|error| anyhow!(error))?;
+            builder
+                .finish()
+                .with(DeployTraceLayer)
+                .try_init()
+                .map_err(|error| anyhow!(error))?;
             drop(RELOAD_HANDLE.set(handle));
             Ok(())
         }
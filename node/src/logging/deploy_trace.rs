@@ -0,0 +1,269 @@
+//! Recording of the tracing spans belonging to individual deploys.
+//!
+//! Spans carrying a `deploy_hash` field, holding the hex-encoded hash of a deploy (which is what
+//! recording it as `deploy_hash = ?deploy_hash.inner()` produces), are recorded when they close.
+//! The spans recorded for a deploy, across all components handling it, can then be retrieved via
+//! [`deploy_trace`] to follow the deploy from its acceptance through to its execution results
+//! being stored.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt::{self, Debug, Display, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, Layer},
+    registry::LookupSpan,
+};
+
+use casper_hashing::Digest;
+use casper_types::{TimeDiff, Timestamp};
+
+use crate::types::DeployHash;
+
+/// Name of the span field identifying the deploy a span belongs to.
+const DEPLOY_HASH_FIELD: &str = "deploy_hash";
+
+/// Maximum number of deploys for which spans are kept, the oldest deploy is dropped first.
+const MAX_TRACED_DEPLOYS: usize = 4096;
+
+/// Maximum number of spans kept per deploy, further spans are dropped.
+const MAX_SPANS_PER_DEPLOY: usize = 64;
+
+/// The spans recorded so far.
+static DEPLOY_TRACES: Lazy<Mutex<DeployTraces>> = Lazy::new(Default::default);
+
+/// Sequence number of the next deploy span created, to order spans created within the same
+/// millisecond.
+static NEXT_SPAN_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// A closed span belonging to a deploy.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecordedSpan {
+    /// Name of the span.
+    name: &'static str,
+    /// Target of the span, i.e. the module it was created in.
+    target: &'static str,
+    /// When the span was created.
+    started: Timestamp,
+    /// How long the span was open for.
+    duration: TimeDiff,
+    /// All fields of the span other than the deploy hash.
+    fields: BTreeMap<&'static str, String>,
+    #[serde(skip)]
+    sequence: u64,
+}
+
+/// The spans recorded for a deploy, ordered by creation.
+#[derive(Clone, Debug, Serialize)]
+pub struct DeployTrace {
+    deploy_hash: DeployHash,
+    spans: Vec<RecordedSpan>,
+}
+
+impl Display for DeployTrace {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "{}", self.deploy_hash)?;
+        let first_started = match self.spans.first() {
+            Some(span) => span.started,
+            None => return write!(formatter, "no spans recorded"),
+        };
+        for span in &self.spans {
+            write!(
+                formatter,
+                "+{} {}::{} took {}",
+                span.started.saturating_diff(first_started),
+                span.target,
+                span.name,
+                span.duration
+            )?;
+            for (name, value) in &span.fields {
+                write!(formatter, "; {}={}", name, value)?;
+            }
+            writeln!(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct DeployTraces {
+    spans: HashMap<DeployHash, Vec<RecordedSpan>>,
+    /// Traced deploys, in the order of their first recorded span.
+    deploy_hashes: VecDeque<DeployHash>,
+}
+
+impl DeployTraces {
+    fn record(&mut self, deploy_hash: DeployHash, span: RecordedSpan) {
+        if let Some(spans) = self.spans.get_mut(&deploy_hash) {
+            if spans.len() < MAX_SPANS_PER_DEPLOY {
+                spans.push(span);
+            }
+            return;
+        }
+        if self.deploy_hashes.len() >= MAX_TRACED_DEPLOYS {
+            if let Some(oldest) = self.deploy_hashes.pop_front() {
+                self.spans.remove(&oldest);
+            }
+        }
+        self.deploy_hashes.push_back(deploy_hash);
+        self.spans.insert(deploy_hash, vec![span]);
+    }
+}
+
+/// Returns the spans recorded for the given deploy, if any.
+pub fn deploy_trace(deploy_hash: DeployHash) -> Option<DeployTrace> {
+    let deploy_traces = DEPLOY_TRACES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut spans = deploy_traces.spans.get(&deploy_hash)?.clone();
+    // Spans are recorded on closing, so nested spans are recorded before their parents.
+    spans.sort_by_key(|span| span.sequence);
+    Some(DeployTrace { deploy_hash, spans })
+}
+
+/// A deploy span which hasn't closed yet, kept in the span's extensions.
+struct OpenSpan {
+    deploy_hash: DeployHash,
+    fields: BTreeMap<&'static str, String>,
+    started: Timestamp,
+    start_instant: Instant,
+    sequence: u64,
+}
+
+/// Collects the fields of a span.
+#[derive(Default)]
+struct SpanFieldVisitor {
+    deploy_hash: Option<DeployHash>,
+    fields: BTreeMap<&'static str, String>,
+}
+
+impl Visit for SpanFieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == DEPLOY_HASH_FIELD {
+            self.deploy_hash = Digest::from_hex(value).ok().map(DeployHash::new);
+        } else {
+            self.fields.insert(field.name(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        let value = format!("{:?}", value);
+        if field.name() == DEPLOY_HASH_FIELD {
+            self.deploy_hash = Digest::from_hex(&value).ok().map(DeployHash::new);
+        } else {
+            self.fields.insert(field.name(), value);
+        }
+    }
+}
+
+/// A tracing layer recording the spans which belong to a deploy.
+pub struct DeployTraceLayer;
+
+impl<S> Layer<S> for DeployTraceLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = SpanFieldVisitor::default();
+        attrs.record(&mut visitor);
+        let deploy_hash = match visitor.deploy_hash {
+            Some(deploy_hash) => deploy_hash,
+            None => return,
+        };
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(OpenSpan {
+                deploy_hash,
+                fields: visitor.fields,
+                started: Timestamp::now(),
+                start_instant: Instant::now(),
+                sequence: NEXT_SPAN_SEQUENCE.fetch_add(1, Ordering::Relaxed),
+            });
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(open_span) = span.extensions_mut().get_mut::<OpenSpan>() {
+                let mut visitor = SpanFieldVisitor::default();
+                values.record(&mut visitor);
+                open_span.fields.extend(visitor.fields);
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(&id) {
+            Some(span) => span,
+            None => return,
+        };
+        let open_span = match span.extensions_mut().remove::<OpenSpan>() {
+            Some(open_span) => open_span,
+            None => return,
+        };
+        let recorded_span = RecordedSpan {
+            name: span.metadata().name(),
+            target: span.metadata().target(),
+            started: open_span.started,
+            duration: open_span.start_instant.elapsed().into(),
+            fields: open_span.fields,
+            sequence: open_span.sequence,
+        };
+        DEPLOY_TRACES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .record(open_span.deploy_hash, recorded_span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    #[test]
+    fn should_record_spans_of_deploy() {
+        let deploy_hash = DeployHash::new(Digest::hash([1; 32]));
+        let other_deploy_hash = DeployHash::new(Digest::hash([2; 32]));
+        let subscriber = tracing_subscriber::registry().with(DeployTraceLayer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let outer = info_span!("accept", deploy_hash = ?deploy_hash.inner(), step = "verify");
+            let _outer = outer.enter();
+            {
+                let inner = info_span!("execute", deploy_hash = ?deploy_hash.inner());
+                let _inner = inner.enter();
+            }
+            let _other = info_span!("execute", deploy_hash = ?other_deploy_hash.inner());
+            let _untraced = info_span!("untraced", block_height = 1);
+        });
+
+        let trace = deploy_trace(deploy_hash).expect("should have trace");
+        let names: Vec<_> = trace.spans.iter().map(|span| span.name).collect();
+        assert_eq!(names, vec!["accept", "execute"]);
+        assert_eq!(trace.spans[0].fields.get("step").unwrap(), "verify");
+        assert!(trace.spans[1].fields.is_empty());
+
+        assert_eq!(
+            deploy_trace(other_deploy_hash)
+                .expect("should have trace")
+                .spans
+                .len(),
+            1
+        );
+    }
+}
@@ -35,7 +35,7 @@ use crate::{
         block_synchronizer::{self, BlockSynchronizer},
         block_validator::{self, BlockValidator},
         consensus::{self, EraSupervisor},
-        contract_runtime::ContractRuntime,
+        contract_runtime::{ContractRuntime, StateSnapshotError},
         deploy_acceptor::{self, DeployAcceptor},
         deploy_buffer::{self, DeployBuffer},
         diagnostics_port::DiagnosticsPort,
@@ -324,6 +324,12 @@ impl reactor::Reactor for MainReactor {
             MainEvent::ChainspecRawBytesRequest(
                 ChainspecRawBytesRequest::GetChainspecRawBytes(responder),
             ) => responder.respond(self.chainspec_raw_bytes.clone()).ignore(),
+            MainEvent::ChainspecRawBytesRequest(ChainspecRawBytesRequest::GetNativeCost {
+                entry_point,
+                responder,
+            }) => responder
+                .respond(self.chainspec.native_cost_of(&entry_point))
+                .ignore(),
             MainEvent::EventStreamServer(event) => reactor::wrap_effects(
                 MainEvent::EventStreamServer,
                 self.event_stream_server
@@ -827,12 +833,15 @@ impl reactor::Reactor for MainReactor {
                 ContractRuntimeAnnouncement::CommitStepSuccess {
                     era_id,
                     execution_effect,
+                    step_effects,
+                    ..
                 },
             ) => {
                 let reactor_event =
                     MainEvent::EventStreamServer(event_stream_server::Event::Step {
                         era_id,
                         execution_effect,
+                        step_effects,
                     });
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
@@ -1045,7 +1054,7 @@ impl reactor::Reactor for MainReactor {
                 Some(chainspec.core_config.max_delegators_per_validator)
             };
 
-        let contract_runtime = ContractRuntime::new(
+        let mut contract_runtime = ContractRuntime::new(
             protocol_version,
             storage.root_path(),
             &config.contract_runtime,
@@ -1053,9 +1062,16 @@ impl reactor::Reactor for MainReactor {
             chainspec.system_costs_config,
             chainspec.core_config.max_associated_keys,
             chainspec.core_config.max_runtime_call_stack_height,
+            chainspec.core_config.max_contract_call_stack_height,
+            chainspec.core_config.max_effects_size_bytes,
             chainspec.core_config.minimum_delegation_amount,
             chainspec.protocol_config.activation_point,
             chainspec.core_config.prune_batch_size,
+            chainspec.deploy_config.deprecated_items,
+            chainspec.network_config.chain_id,
+            chainspec.network_config.chain_bound_approvals,
+            chainspec.deploy_config.block_gas_limit,
+            chainspec.deploy_config.block_max_gas_per_account,
             chainspec.core_config.strict_argument_checking,
             chainspec.core_config.vesting_schedule_period.millis(),
             max_delegators_per_validator,
@@ -1065,7 +1081,29 @@ impl reactor::Reactor for MainReactor {
             chainspec.core_config.allow_unrestricted_transfers,
             chainspec.core_config.refund_handling,
             chainspec.core_config.fee_handling,
+            chainspec.core_config.fee_distribution.clone(),
+            chainspec.core_config.existential_deposit,
+            chainspec.core_config.existential_deposit_handling,
+            chainspec.core_config.storage_refund,
+            chainspec.core_config.delegation_rate_change_cooldown,
+            chainspec.core_config.max_delegation_rate_increase,
+            chainspec.core_config.disabled_auction_entry_points.clone(),
+            chainspec.core_config.enable_purse_ownership_index,
+            chainspec.core_config.compress_contract_wasm,
+            &chainspec.core_config.compatibility_modes,
         )?;
+        contract_runtime.start_artifact_stream(&WithDir::new(
+            &root_dir,
+            config.contract_runtime.artifact_stream.clone(),
+        ))?;
+        if let Some(state_snapshot_path) = config.node.state_snapshot_path.as_ref() {
+            let trusted_hash = trusted_hash.ok_or(StateSnapshotError::NoTrustedHash)?;
+            contract_runtime.import_state_snapshot(
+                &root_dir.join(state_snapshot_path),
+                trusted_hash,
+                chainspec.core_config.finality_threshold_fraction,
+            )?;
+        }
 
         let network = Network::new(
             config.network.clone(),
@@ -1088,6 +1126,7 @@ impl reactor::Reactor for MainReactor {
             protocol_version,
             chainspec.network_config.name.clone(),
             node_startup_instant,
+            chainspec.deploy_config.replay_protection_window,
         );
         let rest_server = RestServer::new(
             config.rest_server.clone(),
@@ -1274,6 +1313,8 @@ impl MainReactor {
         MetaBlock {
             block,
             execution_results,
+            mut execution_stats,
+            mut execution_warnings,
             mut state,
         }: MetaBlock,
     ) -> Effects<MainEvent> {
@@ -1430,6 +1471,8 @@ impl MainReactor {
             let meta_block = MetaBlock {
                 block,
                 execution_results,
+                execution_stats,
+                execution_warnings,
                 state,
             };
             effects.extend(reactor::wrap_effects(
@@ -1532,6 +1575,8 @@ impl MainReactor {
                 deploy_header: Box::new(deploy_header),
                 block_hash: *block.hash(),
                 execution_result: Box::new(execution_result),
+                execution_stats: execution_stats.remove(&deploy_hash),
+                warnings: execution_warnings.remove(&deploy_hash).unwrap_or_default(),
             };
             effects.extend(reactor::wrap_effects(
                 MainEvent::EventStreamServer,
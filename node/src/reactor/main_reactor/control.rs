@@ -425,6 +425,7 @@ impl MainReactor {
         info!("{:?}: attempting commit upgrade", self.state);
         match chainspec.ee_upgrade_config(
             *header.state_root_hash(),
+            header.era_id(),
             header.protocol_version(),
             chainspec.protocol_config.activation_point.era_id(),
             chainspec_raw,
@@ -5,8 +5,9 @@ use casper_types::{bytesrepr, crypto::ErrorExt as CryptoError};
 
 use crate::{
     components::{
-        contract_runtime, contract_runtime::BlockExecutionError, diagnostics_port, network,
-        storage, upgrade_watcher,
+        contract_runtime,
+        contract_runtime::{BlockExecutionError, StateSnapshotError},
+        diagnostics_port, network, storage, upgrade_watcher,
     },
     utils::{ListeningError, LoadError},
 };
@@ -46,6 +47,10 @@ pub(crate) enum Error {
     #[error(transparent)]
     BlockExecution(#[from] BlockExecutionError),
 
+    /// Error importing a state snapshot.
+    #[error("state snapshot error: {0}")]
+    StateSnapshot(#[from] StateSnapshotError),
+
     /// Engine state error.
     #[error(transparent)]
     EngineState(#[from] engine_state::Error),
@@ -7,6 +7,7 @@ mod block_hash_height_and_era;
 pub mod chainspec;
 mod chunkable;
 mod deploy;
+mod deploy_execution_stats;
 pub mod error;
 mod exit_code;
 pub mod json_compatibility;
@@ -16,6 +17,7 @@ mod node_id;
 /// Peers map.
 pub mod peers_map;
 mod status_feed;
+mod step_effect;
 mod sync_leap;
 pub(crate) mod sync_leap_validation_metadata;
 mod validator_matrix;
@@ -44,12 +46,14 @@ pub use chunkable::Chunkable;
 pub use datasize::DataSize;
 pub use deploy::{
     Approval, ApprovalsHash, Deploy, DeployConfigurationFailure, DeployError, DeployHash,
-    DeployHeader, DeployOrTransferHash, ExcessiveSizeError as ExcessiveSizeDeployError,
+    DeployHeader, DeployOrTransferHash, DeployStatus,
+    ExcessiveSizeError as ExcessiveSizeDeployError,
 };
 pub(crate) use deploy::{
     DeployFootprint, DeployHashWithApprovals, DeployId, DeployMetadata, DeployMetadataExt,
-    DeployWithFinalizedApprovals, FinalizedApprovals, LegacyDeploy,
+    DeployValidationMetadata, DeployWithFinalizedApprovals, FinalizedApprovals, LegacyDeploy,
 };
+pub use deploy_execution_stats::DeployExecutionStats;
 pub use error::BlockValidationError;
 pub use exit_code::ExitCode;
 pub(crate) use max_ttl::MaxTtl;
@@ -57,6 +61,7 @@ pub use node_config::{NodeConfig, SyncHandling};
 pub(crate) use node_id::NodeId;
 pub use peers_map::PeersMap;
 pub use status_feed::{ChainspecInfo, GetStatusResult, StatusFeed};
+pub(crate) use step_effect::StepEffect;
 pub(crate) use sync_leap::{GlobalStatesMetadata, SyncLeap, SyncLeapIdentifier};
 pub(crate) use validator_matrix::{EraValidatorWeights, SignatureWeight, ValidatorMatrix};
 pub use value_or_chunk::{
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fmt::{self, Display, Formatter},
 };
 
@@ -25,8 +25,16 @@ pub(crate) enum AddError {
     ApprovalCount,
     #[error("would exceed maximum gas per block")]
     GasLimit,
+    #[error("would exceed maximum gas per account per block")]
+    AccountGasLimit,
+    #[error("would exceed maximum transfer count per account per block")]
+    AccountTransferCount,
+    #[error("would exceed maximum deploy count per account per block")]
+    AccountDeployCount,
     #[error("would exceed maximum block size")]
     BlockSize,
+    #[error("would exceed maximum payload size per block")]
+    PayloadSize,
     #[error("duplicate deploy")]
     Duplicate,
     #[error("deploy has expired")]
@@ -45,7 +53,16 @@ pub(crate) struct AppendableBlock {
     timestamp: Timestamp,
     #[data_size(skip)]
     total_gas: Gas,
+    /// The summed gas of the deploys in the block, by the account which sent them.
+    #[data_size(skip)]
+    gas_per_account: HashMap<PublicKey, Gas>,
+    /// The number of transfers in the block, by the account which sent them.
+    transfers_per_account: HashMap<PublicKey, u32>,
+    /// The number of deploys in the block, by the account which sent them.
+    deploys_per_account: HashMap<PublicKey, u32>,
     total_size: usize,
+    /// The summed size of the deploys and transfers in the block.
+    total_payload_size: usize,
     total_approvals: usize,
 }
 
@@ -59,7 +76,11 @@ impl AppendableBlock {
             timestamp,
             deploy_and_transfer_set: HashSet::new(),
             total_gas: Gas::zero(),
+            gas_per_account: HashMap::new(),
+            transfers_per_account: HashMap::new(),
+            deploys_per_account: HashMap::new(),
             total_size: 0,
+            total_payload_size: 0,
             total_approvals: 0,
         }
     }
@@ -111,10 +132,23 @@ impl AppendableBlock {
         if self.has_max_transfer_count() {
             return Err(AddError::TransferCount);
         }
+        let account = footprint.header.account();
+        let account_transfer_count = self
+            .transfers_per_account
+            .get(account)
+            .copied()
+            .unwrap_or(0);
+        if account_transfer_count >= self.deploy_config.max_transfers_per_account() {
+            return Err(AddError::AccountTransferCount);
+        }
         if self.would_exceed_approval_limits(transfer.approvals().len()) {
             return Err(AddError::ApprovalCount);
         }
+        let new_total_payload_size = self.new_total_payload_size(footprint)?;
+        self.transfers_per_account
+            .insert(account.clone(), account_transfer_count + 1);
         self.deploy_and_transfer_set.insert(*transfer.deploy_hash());
+        self.total_payload_size = new_total_payload_size;
         self.total_approvals += transfer.approvals().len();
         self.transfers.push(transfer);
         Ok(())
@@ -151,9 +185,15 @@ impl AppendableBlock {
         if self.has_max_deploy_count() {
             return Err(AddError::DeployCount);
         }
+        let account = footprint.header.account();
+        let account_deploy_count = self.deploys_per_account.get(account).copied().unwrap_or(0);
+        if account_deploy_count >= self.deploy_config.max_deploys_per_account() {
+            return Err(AddError::AccountDeployCount);
+        }
         if self.would_exceed_approval_limits(deploy.approvals().len()) {
             return Err(AddError::ApprovalCount);
         }
+        let new_total_payload_size = self.new_total_payload_size(footprint)?;
         // Only deploys count towards the size and gas limits.
         let new_total_size = self
             .total_size
@@ -168,8 +208,21 @@ impl AppendableBlock {
         if new_total_gas > Gas::from(self.deploy_config.block_gas_limit) {
             return Err(AddError::GasLimit);
         }
+        let new_account_gas = self
+            .gas_per_account
+            .get(account)
+            .copied()
+            .unwrap_or_else(Gas::zero)
+            .checked_add(gas_estimate)
+            .filter(|gas| *gas <= Gas::from(self.deploy_config.block_max_gas_per_account))
+            .ok_or(AddError::AccountGasLimit)?;
         self.total_gas = new_total_gas;
+        self.gas_per_account
+            .insert(account.clone(), new_account_gas);
+        self.deploys_per_account
+            .insert(account.clone(), account_deploy_count + 1);
         self.total_size = new_total_size;
+        self.total_payload_size = new_total_payload_size;
         self.total_approvals += deploy.approvals().len();
         self.deploy_and_transfer_set.insert(*deploy.deploy_hash());
         self.deploys.push(deploy);
@@ -193,6 +246,21 @@ impl AppendableBlock {
         self.timestamp
     }
 
+    /// Returns the summed size of the deploys and transfers in the block with the deploy of the
+    /// given footprint added, or an error if that would exceed the payload size limit.
+    fn new_total_payload_size(&self, footprint: &DeployFootprint) -> Result<usize, AddError> {
+        let new_total_payload_size = self
+            .total_payload_size
+            .checked_add(footprint.size_estimate)
+            .ok_or(AddError::PayloadSize)?;
+        match self.deploy_config.block_max_payload_size {
+            Some(max_payload_size) if new_total_payload_size > max_payload_size as usize => {
+                Err(AddError::PayloadSize)
+            }
+            _ => Ok(new_total_payload_size),
+        }
+    }
+
     /// Returns `true` if the number of transfers is already the maximum allowed count, i.e. no
     /// more transfers can be added to this block.
     fn has_max_transfer_count(&self) -> bool {
@@ -236,7 +304,7 @@ impl Display for AppendableBlock {
         write!(
             formatter,
             "AppendableBlock(timestamp-{}: {} non-transfers with {} approvals, {} transfers with {} approvals, \
-            total of {} deploys with {} approvals, total gas {}, total size {})",
+            total of {} deploys with {} approvals, total gas {}, total size {}, total payload size {})",
             self.timestamp,
             self.deploys.len(),
             deploy_approvals_count,
@@ -246,17 +314,158 @@ impl Display for AppendableBlock {
             self.total_approvals,
             self.total_gas,
             self.total_size,
+            self.total_payload_size,
         )
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
+    use casper_types::{testing::TestRng, U512};
+
     use super::*;
+    use crate::types::Deploy;
 
     impl AppendableBlock {
         pub(crate) fn deploy_and_transfer_set(&self) -> &HashSet<DeployHash> {
             &self.deploy_and_transfer_set
         }
     }
+
+    #[test]
+    fn should_limit_gas_per_account() {
+        let mut rng = TestRng::new();
+        let timestamp = Timestamp::now();
+        let deploy_config = DeployConfig {
+            block_max_gas_per_account: 100,
+            ..Default::default()
+        };
+        let mut appendable_block = AppendableBlock::new(deploy_config, timestamp);
+
+        let mut footprint_with_gas = |gas: u64| {
+            let deploy = Deploy::random_with_timestamp_and_ttl(
+                &mut rng,
+                timestamp,
+                TimeDiff::from_seconds(60),
+            );
+            let mut footprint = deploy.footprint().unwrap();
+            footprint.gas_estimate = Gas::new(U512::from(gas));
+            footprint
+        };
+        let account_1_footprint = footprint_with_gas(60);
+        let account_2_footprint = footprint_with_gas(60);
+        let mut new_deploy =
+            || DeployHashWithApprovals::new(DeployHash::random(&mut rng), BTreeSet::new());
+
+        appendable_block
+            .add_deploy(new_deploy(), &account_1_footprint)
+            .unwrap();
+        assert!(matches!(
+            appendable_block.add_deploy(new_deploy(), &account_1_footprint),
+            Err(AddError::AccountGasLimit)
+        ));
+        // Other accounts still have their full allowance.
+        appendable_block
+            .add_deploy(new_deploy(), &account_2_footprint)
+            .unwrap();
+    }
+
+    #[test]
+    fn should_limit_payload_size() {
+        let mut rng = TestRng::new();
+        let timestamp = Timestamp::now();
+        let ttl = TimeDiff::from_seconds(60);
+
+        let mut footprint_with_size = |size: usize, is_transfer: bool| {
+            let deploy = if is_transfer {
+                Deploy::random_valid_native_transfer_with_timestamp_and_ttl(
+                    &mut rng, timestamp, ttl,
+                )
+            } else {
+                Deploy::random_with_timestamp_and_ttl(&mut rng, timestamp, ttl)
+            };
+            let mut footprint = deploy.footprint().unwrap();
+            footprint.size_estimate = size;
+            footprint
+        };
+        let transfer_footprint = footprint_with_size(60, true);
+        let large_transfer_footprint = footprint_with_size(41, true);
+        let deploy_footprint = footprint_with_size(40, false);
+        let large_deploy_footprint = footprint_with_size(41, false);
+
+        let deploy_config = DeployConfig {
+            block_max_payload_size: Some(100),
+            ..Default::default()
+        };
+        let mut appendable_block = AppendableBlock::new(deploy_config, timestamp);
+        let mut new_deploy =
+            || DeployHashWithApprovals::new(DeployHash::random(&mut rng), BTreeSet::new());
+
+        // Transfers count towards the payload size, unlike towards the block size.
+        appendable_block
+            .add_transfer(new_deploy(), &transfer_footprint)
+            .unwrap();
+        assert!(matches!(
+            appendable_block.add_deploy(new_deploy(), &large_deploy_footprint),
+            Err(AddError::PayloadSize)
+        ));
+        assert!(matches!(
+            appendable_block.add_transfer(new_deploy(), &large_transfer_footprint),
+            Err(AddError::PayloadSize)
+        ));
+        appendable_block
+            .add_deploy(new_deploy(), &deploy_footprint)
+            .unwrap();
+    }
+
+    #[test]
+    fn should_limit_count_per_account() {
+        let mut rng = TestRng::new();
+        let timestamp = Timestamp::now();
+        let ttl = TimeDiff::from_seconds(60);
+        let deploy_config = DeployConfig {
+            block_max_deploys_per_account: Some(1),
+            block_max_transfers_per_account: Some(2),
+            ..Default::default()
+        };
+        let mut appendable_block = AppendableBlock::new(deploy_config, timestamp);
+
+        let deploy_footprint = Deploy::random_with_timestamp_and_ttl(&mut rng, timestamp, ttl)
+            .footprint()
+            .unwrap();
+        let transfer_footprint =
+            Deploy::random_valid_native_transfer_with_timestamp_and_ttl(&mut rng, timestamp, ttl)
+                .footprint()
+                .unwrap();
+        let other_transfer_footprint =
+            Deploy::random_valid_native_transfer_with_timestamp_and_ttl(&mut rng, timestamp, ttl)
+                .footprint()
+                .unwrap();
+        let mut new_deploy =
+            || DeployHashWithApprovals::new(DeployHash::random(&mut rng), BTreeSet::new());
+
+        appendable_block
+            .add_deploy(new_deploy(), &deploy_footprint)
+            .unwrap();
+        assert!(matches!(
+            appendable_block.add_deploy(new_deploy(), &deploy_footprint),
+            Err(AddError::AccountDeployCount)
+        ));
+
+        for _ in 0..2 {
+            appendable_block
+                .add_transfer(new_deploy(), &transfer_footprint)
+                .unwrap();
+        }
+        assert!(matches!(
+            appendable_block.add_transfer(new_deploy(), &transfer_footprint),
+            Err(AddError::AccountTransferCount)
+        ));
+        // Other accounts still have their full allowance.
+        appendable_block
+            .add_transfer(new_deploy(), &other_transfer_footprint)
+            .unwrap();
+    }
 }
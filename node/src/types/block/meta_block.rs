@@ -1,14 +1,15 @@
 mod merge_mismatch_error;
 mod state;
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use datasize::DataSize;
 use serde::Serialize;
 
+use casper_execution_engine::core::engine_state::ExecutionWarning;
 use casper_types::ExecutionResult;
 
-use crate::types::{ActivationPoint, Block, DeployHash, DeployHeader};
+use crate::types::{ActivationPoint, Block, DeployExecutionStats, DeployHash, DeployHeader};
 
 pub(crate) use merge_mismatch_error::MergeMismatchError;
 pub(crate) use state::State;
@@ -24,6 +25,12 @@ pub(crate) use state::State;
 pub(crate) struct MetaBlock {
     pub(crate) block: Arc<Block>,
     pub(crate) execution_results: Vec<(DeployHash, DeployHeader, ExecutionResult)>,
+    /// How long the deploys took to execute on this node and how much Wasm memory they used.
+    /// Empty unless this node executed the block.
+    pub(crate) execution_stats: BTreeMap<DeployHash, DeployExecutionStats>,
+    /// The warnings raised while executing the deploys which raised any.  Empty unless this node
+    /// executed the block.
+    pub(crate) execution_warnings: BTreeMap<DeployHash, Vec<ExecutionWarning>>,
     pub(crate) state: State,
 }
 
@@ -36,10 +43,30 @@ impl MetaBlock {
         MetaBlock {
             block,
             execution_results,
+            execution_stats: BTreeMap::new(),
+            execution_warnings: BTreeMap::new(),
             state,
         }
     }
 
+    /// Sets the execution stats of the deploys, as measured while executing the block.
+    pub(crate) fn with_execution_stats(
+        mut self,
+        execution_stats: BTreeMap<DeployHash, DeployExecutionStats>,
+    ) -> Self {
+        self.execution_stats = execution_stats;
+        self
+    }
+
+    /// Sets the warnings raised while executing the deploys of the block.
+    pub(crate) fn with_execution_warnings(
+        mut self,
+        execution_warnings: BTreeMap<DeployHash, Vec<ExecutionWarning>>,
+    ) -> Self {
+        self.execution_warnings = execution_warnings;
+        self
+    }
+
     pub(crate) fn merge(mut self, other: MetaBlock) -> Result<Self, MergeMismatchError> {
         if self.block != other.block {
             return Err(MergeMismatchError::Block);
@@ -55,6 +82,16 @@ impl MetaBlock {
             return Err(MergeMismatchError::ExecutionResults);
         }
 
+        // The execution stats differ between nodes, so they are taken from either side rather
+        // than compared.
+        if self.execution_stats.is_empty() {
+            self.execution_stats = other.execution_stats;
+        }
+        // Likewise, the warnings are only known to the node which executed the block.
+        if self.execution_warnings.is_empty() {
+            self.execution_warnings = other.execution_warnings;
+        }
+
         self.state = self.state.merge(other.state)?;
 
         Ok(self)
@@ -82,7 +119,7 @@ mod tests {
 
     use rand::Rng;
 
-    use casper_types::testing::TestRng;
+    use casper_types::{testing::TestRng, TimeDiff};
 
     use super::*;
     use crate::types::Deploy;
@@ -198,4 +235,33 @@ mod tests {
             Err(MergeMismatchError::ExecutionResults)
         ));
     }
+
+    #[test]
+    fn should_merge_execution_stats_from_either_side() {
+        let mut rng = TestRng::new();
+
+        let block = Arc::new(Block::random(&mut rng));
+        let deploy = Deploy::random(&mut rng);
+        let deploy_hash = *deploy.hash();
+        let execution_results = vec![(deploy_hash, deploy.take_header(), rng.gen())];
+        let execution_stats: BTreeMap<_, _> = iter::once((
+            deploy_hash,
+            DeployExecutionStats {
+                duration: TimeDiff::from_millis(rng.gen_range(0..1_000)),
+                memory_high_water_mark: rng.gen(),
+                effects_size: rng.gen(),
+            },
+        ))
+        .collect();
+        let state = State::new();
+
+        let executed = MetaBlock::new(Arc::clone(&block), execution_results.clone(), state)
+            .with_execution_stats(execution_stats.clone());
+        let accumulated = MetaBlock::new(Arc::clone(&block), execution_results, state);
+
+        let merged = executed.clone().merge(accumulated.clone()).unwrap();
+        assert_eq!(merged.execution_stats, execution_stats);
+        let merged = accumulated.merge(executed).unwrap();
+        assert_eq!(merged.execution_stats, execution_stats);
+    }
 }
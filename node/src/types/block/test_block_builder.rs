@@ -20,6 +20,7 @@ pub(crate) struct TestBlockBuilder {
     height: Option<u64>,
     protocol_version: ProtocolVersion,
     deploys: Vec<Deploy>,
+    transfers: Vec<Deploy>,
     is_switch: Option<bool>,
 }
 
@@ -32,6 +33,7 @@ impl TestBlockBuilder {
             protocol_version: ProtocolVersion::V1_0_0,
             is_switch: None,
             deploys: Vec::new(),
+            transfers: Vec::new(),
             state_root_hash: None,
             parent_hash: None,
             timestamp: None,
@@ -77,6 +79,15 @@ impl TestBlockBuilder {
         self
     }
 
+    #[allow(unused)]
+    pub(crate) fn transfers<'a, I: IntoIterator<Item = &'a Deploy>>(
+        mut self,
+        transfers_iter: I,
+    ) -> Self {
+        self.transfers = transfers_iter.into_iter().cloned().collect();
+        self
+    }
+
     #[allow(unused)]
     pub(crate) fn random_deploys(mut self, count: usize, rng: &mut TestRng) -> Self {
         self.deploys = iter::repeat(())
@@ -138,9 +149,15 @@ impl TestBlockBuilder {
                 .iter()
                 .map(DeployHashWithApprovals::from)
                 .collect::<Vec<_>>();
+            let transfer_hashes = self
+                .transfers
+                .iter()
+                .map(DeployHashWithApprovals::from)
+                .collect::<Vec<_>>();
 
             let random_bit = rng.gen();
-            let block_payload = BlockPayload::new(deploy_hashes, vec![], vec![], random_bit);
+            let block_payload =
+                BlockPayload::new(deploy_hashes, transfer_hashes, vec![], random_bit);
 
             let era_report = if is_switch {
                 let equivocators_count = rng.gen_range(0..5);
@@ -3,9 +3,12 @@
 
 mod accounts_config;
 mod activation_point;
+#[cfg(test)]
+mod chainspec_compat;
 mod chainspec_raw_bytes;
 mod core_config;
 mod deploy_config;
+mod deprecated_deploy_items;
 mod error;
 mod global_state_update;
 mod highway_config;
@@ -24,7 +27,7 @@ use tracing::{error, info, warn};
 use casper_execution_engine::{
     core::engine_state::{
         genesis::{ExecConfig, ExecConfigBuilder},
-        ChainspecRegistry, UpgradeConfig,
+        ChainspecRegistry, UpgradeConfig, UpgradeConfigBuilder,
     },
     shared::{system_config::SystemConfig, wasm_config::WasmConfig},
 };
@@ -33,6 +36,7 @@ use casper_hashing::Digest;
 use casper_types::testing::TestRng;
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
+    system::{auction, mint},
     EraId, ProtocolVersion,
 };
 
@@ -42,6 +46,7 @@ pub use self::{
     chainspec_raw_bytes::ChainspecRawBytes,
     core_config::{ConsensusProtocolName, CoreConfig, LegacyRequiredFinality},
     deploy_config::DeployConfig,
+    deprecated_deploy_items::{DeployItemKind, DeprecatedDeployItems},
     error::Error,
     global_state_update::GlobalStateUpdate,
     highway_config::{HighwayConfig, PerformanceMeterConfig},
@@ -56,6 +61,15 @@ pub const CHAINSPEC_FILENAME: &str = "chainspec.toml";
 // Additional overhead accounted for (eg. lower level networking packet encapsulation).
 const CHAINSPEC_NETWORK_MESSAGE_SAFETY_MARGIN: usize = 256;
 
+/// The first protocol version whose chainspec encoding includes the costs of the host functions,
+/// mint and auction entry points added since 1.5.x.  Older chainspecs keep the legacy layouts of
+/// the cost tables so that their hashes are unchanged.
+const EXTENDED_COSTS_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::from_parts(1, 6, 0);
+
+fn has_extended_costs(protocol_version: ProtocolVersion) -> bool {
+    protocol_version >= EXTENDED_COSTS_PROTOCOL_VERSION
+}
+
 /// A collection of configuration settings describing the state of the system at genesis and after
 /// upgrades to basic system functionality occurring after genesis.
 #[derive(DataSize, PartialEq, Eq, Serialize, Debug)]
@@ -137,7 +151,19 @@ impl Chainspec {
             }
         }
 
+        for compatibility_mode in &self.core_config.compatibility_modes {
+            if compatibility_mode.protocol_version >= self.protocol_config.version {
+                error!(
+                    mode_protocol_version = %compatibility_mode.protocol_version,
+                    protocol_version = %self.protocol_config.version,
+                    "compatibility modes may only cover earlier protocol versions",
+                );
+                return false;
+            }
+        }
+
         self.protocol_config.is_valid()
+            && self.network_config.is_valid()
             && self.core_config.is_valid()
             && self.deploy_config.is_valid()
     }
@@ -164,9 +190,38 @@ impl Chainspec {
             .then(|| self.protocol_config.activation_point.era_id())
     }
 
+    /// Returns the gas cost this chainspec fixes for the native operation `entry_point`, or `None`
+    /// if it isn't one.
+    ///
+    /// `"transfer"` is a wasmless transfer, whose cost is the whole cost of the deploy.  The others
+    /// are entry points of the auction contract, or the mint's `"update_administrators"`, whose
+    /// cost excludes that of the payment code and of any session code calling them.
+    pub fn native_cost_of(&self, entry_point: &str) -> Option<u64> {
+        let auction_costs = self.system_costs_config.auction_costs();
+        let cost = match entry_point {
+            mint::METHOD_TRANSFER => self.system_costs_config.wasmless_transfer_cost(),
+            mint::METHOD_UPDATE_ADMINISTRATORS => {
+                self.system_costs_config.mint_costs().update_administrators
+            }
+            auction::METHOD_ADD_BID => auction_costs.add_bid,
+            auction::METHOD_WITHDRAW_BID => auction_costs.withdraw_bid,
+            auction::METHOD_ACTIVATE_BID => auction_costs.activate_bid,
+            auction::METHOD_CHANGE_BID_PUBLIC_KEY => auction_costs.change_bid_public_key,
+            auction::METHOD_DELEGATE => auction_costs.delegate,
+            auction::METHOD_UNDELEGATE => auction_costs.undelegate,
+            auction::METHOD_REDELEGATE => auction_costs.redelegate,
+            auction::METHOD_WITHDRAW_DELEGATOR_REWARDS => auction_costs.withdraw_delegator_reward,
+            auction::METHOD_ADD_RESERVATIONS => auction_costs.add_reservations,
+            auction::METHOD_CANCEL_RESERVATIONS => auction_costs.cancel_reservations,
+            _ => return None,
+        };
+        Some(u64::from(cost))
+    }
+
     pub(crate) fn ee_upgrade_config(
         &self,
         pre_state_hash: Digest,
+        pre_state_era_id: EraId,
         current_protocol_version: ProtocolVersion,
         era_id: EraId,
         chainspec_raw_bytes: Arc<ChainspecRawBytes>,
@@ -182,19 +237,23 @@ impl Chainspec {
             }
         };
 
-        Ok(UpgradeConfig::new(
+        UpgradeConfigBuilder::new(
             pre_state_hash,
             current_protocol_version,
             self.protocol_config.version,
-            Some(era_id),
-            Some(self.core_config.validator_slots),
-            Some(self.core_config.auction_delay),
-            Some(self.core_config.locked_funds_period.millis()),
-            Some(self.core_config.round_seigniorage_rate),
-            Some(self.core_config.unbonding_delay),
-            global_state_update,
             chainspec_registry,
-        ))
+        )
+        .with_activation_point(era_id)
+        .with_pre_state_era_id(pre_state_era_id)
+        .with_new_validator_slots(self.core_config.validator_slots)
+        .with_new_auction_delay(self.core_config.auction_delay)
+        .with_new_locked_funds_period_millis(self.core_config.locked_funds_period.millis())
+        .with_new_round_seigniorage_rate(self.core_config.round_seigniorage_rate)
+        .with_new_unbonding_delay(self.core_config.unbonding_delay)
+        .with_global_state_update(global_state_update)
+        .with_allow_dangerous_overrides(self.protocol_config.allow_dangerous_overrides())
+        .build()
+        .map_err(|error| format!("invalid upgrade config: {}", error))
     }
 }
 
@@ -207,8 +266,19 @@ impl Chainspec {
         let core_config = CoreConfig::random(rng);
         let highway_config = HighwayConfig::random(rng);
         let deploy_config = DeployConfig::random(rng);
-        let wasm_costs_config = rng.gen();
-        let system_costs_config = rng.gen();
+        let mut wasm_costs_config: WasmConfig = rng.gen();
+        let mut system_costs_config: SystemConfig = rng.gen();
+        if !has_extended_costs(protocol_config.version) {
+            // The legacy layouts can't carry the costs added since, so reset them to the defaults.
+            wasm_costs_config =
+                WasmConfig::from_legacy_bytes(&wasm_costs_config.to_legacy_bytes().unwrap())
+                    .unwrap()
+                    .0;
+            system_costs_config =
+                SystemConfig::from_legacy_bytes(&system_costs_config.to_legacy_bytes().unwrap())
+                    .unwrap()
+                    .0;
+        }
 
         Chainspec {
             protocol_config,
@@ -230,8 +300,13 @@ impl ToBytes for Chainspec {
         buffer.extend(self.core_config.to_bytes()?);
         buffer.extend(self.highway_config.to_bytes()?);
         buffer.extend(self.deploy_config.to_bytes()?);
-        buffer.extend(self.wasm_config.to_bytes()?);
-        buffer.extend(self.system_costs_config.to_bytes()?);
+        if has_extended_costs(self.protocol_version()) {
+            buffer.extend(self.wasm_config.to_bytes()?);
+            buffer.extend(self.system_costs_config.to_bytes()?);
+        } else {
+            buffer.extend(self.wasm_config.to_legacy_bytes()?);
+            buffer.extend(self.system_costs_config.to_legacy_bytes()?);
+        }
         Ok(buffer)
     }
 
@@ -241,8 +316,12 @@ impl ToBytes for Chainspec {
             + self.core_config.serialized_length()
             + self.highway_config.serialized_length()
             + self.deploy_config.serialized_length()
-            + self.wasm_config.serialized_length()
-            + self.system_costs_config.serialized_length()
+            + if has_extended_costs(self.protocol_version()) {
+                self.wasm_config.serialized_length() + self.system_costs_config.serialized_length()
+            } else {
+                self.wasm_config.legacy_serialized_length()
+                    + self.system_costs_config.legacy_serialized_length()
+            }
     }
 }
 
@@ -253,8 +332,16 @@ impl FromBytes for Chainspec {
         let (core_config, remainder) = CoreConfig::from_bytes(remainder)?;
         let (highway_config, remainder) = HighwayConfig::from_bytes(remainder)?;
         let (deploy_config, remainder) = DeployConfig::from_bytes(remainder)?;
-        let (wasm_config, remainder) = WasmConfig::from_bytes(remainder)?;
-        let (system_costs_config, remainder) = SystemConfig::from_bytes(remainder)?;
+        let (wasm_config, system_costs_config, remainder) =
+            if has_extended_costs(protocol_config.version) {
+                let (wasm_config, remainder) = WasmConfig::from_bytes(remainder)?;
+                let (system_costs_config, remainder) = SystemConfig::from_bytes(remainder)?;
+                (wasm_config, system_costs_config, remainder)
+            } else {
+                let (wasm_config, remainder) = WasmConfig::from_legacy_bytes(remainder)?;
+                let (system_costs_config, remainder) = SystemConfig::from_legacy_bytes(remainder)?;
+                (wasm_config, system_costs_config, remainder)
+            };
         let chainspec = Chainspec {
             protocol_config,
             network_config,
@@ -363,6 +450,24 @@ mod tests {
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
             random_bytes: HostFunction::new(123, [0, 1]),
             enable_contract_version: HostFunction::new(142, [0, 1, 2, 3]),
+            create_contract_package_at_seed: HostFunction::new(143, [0, 1, 2, 3, 4, 5]),
+            emit_event: HostFunction::new(144, [0, 1, 2, 3]),
+            set_contract_package_paused: HostFunction::new(145, [0, 1, 2]),
+            get_remaining_call_depth: HostFunction::new(146, [0]),
+            sorted_index_insert: HostFunction::new(147, [0, 1, 2, 3, 4, 5]),
+            sorted_index_remove: HostFunction::new(148, [0, 1, 2, 3]),
+            sorted_index_get: HostFunction::new(149, [0, 1, 2, 3, 4]),
+            sorted_index_range: HostFunction::new(150, [0, 1, 2, 3, 4, 5, 6]),
+            namespace_put: HostFunction::new(151, [0, 1, 2, 3, 4, 5]),
+            namespace_get: HostFunction::new(152, [0, 1, 2, 3, 4]),
+            namespace_clear: HostFunction::new(153, [0, 1]),
+            new_unique_address: HostFunction::new(154, [0, 1, 2]),
+            schedule_callback: HostFunction::new(155, [0, 1, 2, 3, 4, 5, 6, 7, 8]),
+            cancel_callback: HostFunction::new(156, [0]),
+            transfer_from_purse_to_purse_with_result: HostFunction::new(
+                157,
+                [0, 1, 2, 3, 4, 5, 6, 7, 8],
+            ),
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
@@ -449,6 +554,10 @@ mod tests {
                 EraId::from(1)
             );
             assert!(spec.network_config.accounts_config.accounts().is_empty());
+            assert_eq!(
+                spec.network_config.chain_bound_approvals,
+                Some(EraId::from(2))
+            );
             assert!(spec.protocol_config.global_state_update.is_some());
             assert!(spec
                 .protocol_config
@@ -469,6 +578,7 @@ mod tests {
         }
 
         assert_eq!(spec.network_config.name, "test-chain");
+        assert_eq!(spec.network_config.chain_id, 10);
 
         assert_eq!(spec.core_config.era_duration, TimeDiff::from_seconds(180));
         assert_eq!(spec.core_config.minimum_era_height, 9);
@@ -497,6 +607,10 @@ mod tests {
         assert_eq!(spec.deploy_config.max_block_size, 12);
         assert_eq!(spec.deploy_config.block_max_deploy_count, 125);
         assert_eq!(spec.deploy_config.block_gas_limit, 13);
+        assert_eq!(spec.deploy_config.block_max_gas_per_account, 13);
+        assert_eq!(spec.deploy_config.block_max_deploys_per_account, Some(14));
+        assert_eq!(spec.deploy_config.block_max_transfers_per_account, Some(15));
+        assert_eq!(spec.deploy_config.block_max_payload_size, Some(16));
 
         assert_eq!(spec.wasm_config, *EXPECTED_GENESIS_WASM_COSTS);
     }
@@ -571,4 +685,29 @@ mod tests {
 
         assert!(chainspec.is_valid());
     }
+
+    #[test]
+    fn should_return_native_costs() {
+        let (chainspec, _raw_bytes): (Chainspec, ChainspecRawBytes) =
+            Loadable::from_resources("production");
+        let system_costs = &chainspec.system_costs_config;
+
+        assert_eq!(
+            chainspec.native_cost_of(mint::METHOD_TRANSFER),
+            Some(u64::from(system_costs.wasmless_transfer_cost()))
+        );
+        assert_eq!(
+            chainspec.native_cost_of(auction::METHOD_DELEGATE),
+            Some(u64::from(system_costs.auction_costs().delegate))
+        );
+        assert_eq!(
+            chainspec.native_cost_of(auction::METHOD_WITHDRAW_DELEGATOR_REWARDS),
+            Some(u64::from(
+                system_costs.auction_costs().withdraw_delegator_reward
+            ))
+        );
+        // System-only entry points aren't native operations.
+        assert_eq!(chainspec.native_cost_of(auction::METHOD_RUN_AUCTION), None);
+        assert_eq!(chainspec.native_cost_of("no_such_entry_point"), None);
+    }
 }
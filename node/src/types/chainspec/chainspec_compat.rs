@@ -0,0 +1,299 @@
+//! Guards against accidental changes to the binary encoding of the chainspec's vm config.
+//!
+//! The `ToBytes` output of the wasm and system cost tables feeds into the chainspec hash which
+//! every node must agree on, so the encoding may only change alongside a protocol version bump.
+//! The digests of the bundled production values are pinned per protocol version in
+//! `resources/test/chainspec_compat.json`.  When bumping the protocol version, add a new entry for
+//! it using the values printed by the failing test.
+//!
+//! Chainspecs before 1.6.0 encode the cost tables in their 1.5.x layouts, which are checked against
+//! the 1.5.6 entry.
+
+use std::{collections::BTreeMap, fs, str::FromStr};
+
+use casper_execution_engine::shared::{
+    host_function_costs::HostFunction,
+    system_config::{
+        auction_costs::{
+            AuctionCosts, DEFAULT_ADD_RESERVATIONS_COST, DEFAULT_CANCEL_RESERVATIONS_COST,
+            DEFAULT_CHANGE_BID_PUBLIC_KEY_COST,
+        },
+        mint_costs::DEFAULT_UPDATE_ADMINISTRATORS_COST,
+        SystemConfig,
+    },
+    wasm_config::WasmConfig,
+};
+use casper_hashing::Digest;
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    ProtocolVersion,
+};
+
+use super::{has_extended_costs, Chainspec, ChainspecRawBytes};
+use crate::utils::{Loadable, RESOURCES_PATH};
+
+const SNAPSHOTS_PATH: &str = "test/chainspec_compat.json";
+
+/// The production auction costs as encoded by protocol version 1.5.6.
+const AUCTION_COSTS_1_5_6: &str = concat!(
+    "102700001027000000f9029500f9029500f9029500f902951027000010270000",
+    "102700001027000010270000102700001027000000f90295",
+);
+
+/// The production system config as encoded by protocol version 1.5.6.
+const SYSTEM_CONFIG_1_5_6: &str = concat!(
+    "00e1f505102700001027000000f9029500f9029500f9029500f9029510270000",
+    "10270000102700001027000010270000102700001027000000f9029500f90295",
+    "1027000000f9029510270000102700001027000000f902951027000010270000",
+    "102700001027000010270000",
+);
+
+type Snapshot = BTreeMap<String, String>;
+
+fn digest(bytes: Vec<u8>, serialized_length: usize) -> String {
+    assert_eq!(
+        bytes.len(),
+        serialized_length,
+        "serialized_length should match the serialized bytes"
+    );
+    base16::encode_lower(&Digest::hash(bytes).value())
+}
+
+fn digest_of<T: ToBytes>(value: &T) -> String {
+    let bytes = value.to_bytes().expect("should serialize");
+    digest(bytes, value.serialized_length())
+}
+
+/// Takes the digests of the cost tables as the chainspec encodes them, i.e. in their legacy
+/// layouts before 1.6.0.
+fn snapshot_of(chainspec: &Chainspec) -> Snapshot {
+    let wasm_config = &chainspec.wasm_config;
+    let host_function_costs = wasm_config.take_host_function_costs();
+    let system_config = &chainspec.system_costs_config;
+
+    let mut snapshot = Snapshot::new();
+    let mut insert = |name: &str, digest: String| snapshot.insert(name.to_string(), digest);
+    insert("opcode_costs", digest_of(&wasm_config.opcode_costs()));
+    insert("storage_costs", digest_of(&wasm_config.storage_costs()));
+    insert(
+        "handle_payment_costs",
+        digest_of(system_config.handle_payment_costs()),
+    );
+    insert(
+        "standard_payment_costs",
+        digest_of(system_config.standard_payment_costs()),
+    );
+    if has_extended_costs(chainspec.protocol_version()) {
+        insert("wasm_config", digest_of(wasm_config));
+        insert("host_function_costs", digest_of(&host_function_costs));
+        insert("system_config", digest_of(system_config));
+        insert("auction_costs", digest_of(system_config.auction_costs()));
+        insert("mint_costs", digest_of(system_config.mint_costs()));
+    } else {
+        let auction_costs = system_config.auction_costs();
+        let mint_costs = system_config.mint_costs();
+        insert(
+            "wasm_config",
+            digest(
+                wasm_config.to_legacy_bytes().expect("should serialize"),
+                wasm_config.legacy_serialized_length(),
+            ),
+        );
+        insert(
+            "host_function_costs",
+            digest(
+                host_function_costs
+                    .to_legacy_bytes()
+                    .expect("should serialize"),
+                host_function_costs.legacy_serialized_length(),
+            ),
+        );
+        insert(
+            "system_config",
+            digest(
+                system_config.to_legacy_bytes().expect("should serialize"),
+                system_config.legacy_serialized_length(),
+            ),
+        );
+        insert(
+            "auction_costs",
+            digest(
+                auction_costs.to_legacy_bytes().expect("should serialize"),
+                auction_costs.legacy_serialized_length(),
+            ),
+        );
+        insert(
+            "mint_costs",
+            digest(
+                mint_costs.to_legacy_bytes().expect("should serialize"),
+                mint_costs.legacy_serialized_length(),
+            ),
+        );
+    }
+    snapshot
+}
+
+fn recorded_snapshots() -> BTreeMap<ProtocolVersion, Snapshot> {
+    let path = RESOURCES_PATH.join(SNAPSHOTS_PATH);
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("should read {}: {}", path.display(), error));
+    let snapshots: BTreeMap<String, Snapshot> =
+        serde_json::from_str(&contents).expect("should parse snapshots");
+    snapshots
+        .into_iter()
+        .map(|(version, snapshot)| {
+            let version = ProtocolVersion::from_str(&version)
+                .unwrap_or_else(|_| panic!("invalid protocol version {}", version));
+            (version, snapshot)
+        })
+        .collect()
+}
+
+#[test]
+fn vm_config_encoding_should_only_change_with_protocol_version() {
+    let (chainspec, _): (Chainspec, ChainspecRawBytes) = Loadable::from_resources("production");
+    let protocol_version = chainspec.protocol_version();
+    let actual = snapshot_of(&chainspec);
+    let printable = serde_json::to_string_pretty(&actual).unwrap();
+
+    let snapshots = recorded_snapshots();
+    let expected = match snapshots.get(&protocol_version) {
+        Some(expected) => expected,
+        None => {
+            let latest = snapshots.keys().next_back();
+            assert!(
+                latest.map_or(true, |latest| *latest < protocol_version),
+                "protocol version {} is older than the latest recorded snapshot {:?}",
+                protocol_version,
+                latest
+            );
+            panic!(
+                "no snapshot recorded for protocol version {}; add it to {}:\n\"{}\": {}",
+                protocol_version, SNAPSHOTS_PATH, protocol_version, printable
+            );
+        }
+    };
+
+    for (name, expected_digest) in expected {
+        let actual_digest = actual
+            .get(name)
+            .unwrap_or_else(|| panic!("snapshot {} is no longer produced", name));
+        assert_eq!(
+            actual_digest, expected_digest,
+            "encoding of {} changed without bumping the protocol version from {}",
+            name, protocol_version
+        );
+    }
+    for name in actual.keys() {
+        assert!(
+            expected.contains_key(name),
+            "{} is missing from the snapshot for {}; add it:\n{}",
+            name,
+            protocol_version,
+            printable
+        );
+    }
+}
+
+#[test]
+fn legacy_encoding_should_match_1_5_6_snapshot() {
+    // The current production chainspec keeps the 1.5.6 values of all the costs both versions
+    // define, so encoding it in the 1.5.x layouts has to reproduce the 1.5.6 digests.
+    let protocol_version = ProtocolVersion::from_parts(1, 5, 6);
+    let (mut chainspec, _): (Chainspec, ChainspecRawBytes) = Loadable::from_resources("production");
+    chainspec.protocol_config.version = protocol_version;
+    let expected = recorded_snapshots()
+        .remove(&protocol_version)
+        .expect("should have a 1.5.6 snapshot");
+    assert_eq!(snapshot_of(&chainspec), expected);
+}
+
+#[test]
+fn vm_config_should_roundtrip() {
+    let (chainspec, _): (Chainspec, ChainspecRawBytes) = Loadable::from_resources("production");
+    let wasm_config = chainspec.wasm_config;
+    let system_config = chainspec.system_costs_config;
+
+    bytesrepr::test_serialization_roundtrip(&wasm_config);
+    bytesrepr::test_serialization_roundtrip(&wasm_config.opcode_costs());
+    bytesrepr::test_serialization_roundtrip(&wasm_config.storage_costs());
+    bytesrepr::test_serialization_roundtrip(&wasm_config.take_host_function_costs());
+    bytesrepr::test_serialization_roundtrip(&system_config);
+    bytesrepr::test_serialization_roundtrip(system_config.auction_costs());
+    bytesrepr::test_serialization_roundtrip(system_config.mint_costs());
+    bytesrepr::test_serialization_roundtrip(system_config.handle_payment_costs());
+    bytesrepr::test_serialization_roundtrip(system_config.standard_payment_costs());
+}
+
+#[test]
+fn legacy_auction_costs_should_roundtrip_1_5_6_encoding() {
+    let bytes = base16::decode(AUCTION_COSTS_1_5_6).unwrap();
+    let (auction_costs, remainder) =
+        AuctionCosts::from_legacy_bytes(&bytes).expect("should decode 1.5.6 auction costs");
+    assert!(remainder.is_empty());
+    assert_eq!(
+        auction_costs.change_bid_public_key,
+        DEFAULT_CHANGE_BID_PUBLIC_KEY_COST
+    );
+    assert_eq!(
+        auction_costs.add_reservations,
+        DEFAULT_ADD_RESERVATIONS_COST
+    );
+    assert_eq!(
+        auction_costs.cancel_reservations,
+        DEFAULT_CANCEL_RESERVATIONS_COST
+    );
+    assert_eq!(auction_costs.legacy_serialized_length(), bytes.len());
+    assert_eq!(auction_costs.to_legacy_bytes().unwrap(), bytes);
+}
+
+#[test]
+fn legacy_system_config_should_roundtrip_1_5_6_encoding() {
+    let bytes = base16::decode(SYSTEM_CONFIG_1_5_6).unwrap();
+    let (system_config, remainder) =
+        SystemConfig::from_legacy_bytes(&bytes).expect("should decode 1.5.6 system config");
+    assert!(remainder.is_empty());
+    assert_eq!(
+        system_config.mint_costs().update_administrators,
+        DEFAULT_UPDATE_ADMINISTRATORS_COST
+    );
+    assert_eq!(system_config.legacy_serialized_length(), bytes.len());
+    assert_eq!(system_config.to_legacy_bytes().unwrap(), bytes);
+}
+
+#[test]
+fn chainspec_before_1_6_0_should_encode_legacy_cost_tables() {
+    let (mut chainspec, _): (Chainspec, ChainspecRawBytes) = Loadable::from_resources("production");
+    chainspec.protocol_config.version = ProtocolVersion::from_parts(1, 5, 6);
+    bytesrepr::test_serialization_roundtrip(&chainspec);
+
+    let hash = chainspec.hash();
+    let system_config = chainspec.system_costs_config;
+    let mut auction_costs = *system_config.auction_costs();
+    auction_costs.change_bid_public_key += 1;
+    auction_costs.add_reservations += 1;
+    auction_costs.cancel_reservations += 1;
+    let mut mint_costs = *system_config.mint_costs();
+    mint_costs.update_administrators += 1;
+    chainspec.system_costs_config = SystemConfig::new(
+        system_config.wasmless_transfer_cost(),
+        auction_costs,
+        mint_costs,
+        *system_config.handle_payment_costs(),
+        *system_config.standard_payment_costs(),
+    );
+    let wasm_config = chainspec.wasm_config;
+    let mut host_function_costs = wasm_config.take_host_function_costs();
+    host_function_costs.emit_event = HostFunction::fixed(host_function_costs.emit_event.cost() + 1);
+    chainspec.wasm_config = WasmConfig::new(
+        wasm_config.max_memory,
+        wasm_config.max_stack_height,
+        wasm_config.opcode_costs(),
+        wasm_config.storage_costs(),
+        host_function_costs,
+    );
+    assert_eq!(chainspec.hash(), hash);
+
+    chainspec.protocol_config.version = ProtocolVersion::from_parts(1, 6, 0);
+    bytesrepr::test_serialization_roundtrip(&chainspec);
+}
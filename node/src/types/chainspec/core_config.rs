@@ -2,7 +2,12 @@ use std::collections::BTreeSet;
 
 use tracing::{error, warn};
 
-use casper_execution_engine::core::engine_state::engine_config::{FeeHandling, RefundHandling};
+#[cfg(test)]
+use casper_execution_engine::core::engine_state::engine_config::Quirk;
+use casper_execution_engine::core::engine_state::engine_config::{
+    CompatibilityModeConfig, ExistentialDepositHandling, FeeDistribution, FeeHandling,
+    RefundHandling, StorageRefund,
+};
 #[cfg(test)]
 use casper_types::testing::TestRng;
 use casper_types::{
@@ -22,7 +27,18 @@ use serde::{
     Deserialize, Serialize, Serializer,
 };
 
-use casper_types::{system::auction::VESTING_SCHEDULE_LENGTH_MILLIS, ProtocolVersion, TimeDiff};
+use casper_types::{
+    system::auction::{self, DelegationRate, VESTING_SCHEDULE_LENGTH_MILLIS},
+    ProtocolVersion, TimeDiff,
+};
+
+/// The auction entry points called by the step, which can't be disabled.
+const STEP_AUCTION_ENTRY_POINTS: [&str; 4] = [
+    auction::METHOD_RUN_AUCTION,
+    auction::METHOD_SLASH,
+    auction::METHOD_DISTRIBUTE,
+    auction::METHOD_READ_ERA_ID,
+];
 
 /// Configuration values associated with the core protocol.
 #[derive(Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -78,6 +94,12 @@ pub struct CoreConfig {
     /// Maximum height of contract runtime call stack.
     pub max_runtime_call_stack_height: u32,
 
+    /// Maximum number of stored contract frames on the contract runtime call stack.
+    pub max_contract_call_stack_height: u32,
+
+    /// Maximum serialized size in bytes of the effects of a single deploy.
+    pub max_effects_size_bytes: u32,
+
     /// The minimum bound of motes that can be delegated to a validator.
     pub minimum_delegation_amount: u64,
 
@@ -104,14 +126,43 @@ pub struct CoreConfig {
     pub(crate) allow_unrestricted_transfers: bool,
     /// If set to false then consensus doesn't compute rewards and always uses 0.
     pub(crate) compute_rewards: bool,
+    /// Minimum balance in motes an account's main purse must retain after a transfer, unless it
+    /// is emptied entirely. Zero disables the check.
+    pub(crate) existential_deposit: u64,
     /// Administrative accounts are valid option for a private chain only.
     #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
     pub(crate) administrators: BTreeSet<PublicKey>,
+    /// Number of eras a validator has to wait after increasing its delegation rate before
+    /// increasing it again. Zero disables the check.
+    pub(crate) delegation_rate_change_cooldown: u64,
+    /// Maximum amount by which a validator can increase its delegation rate at once.
+    pub(crate) max_delegation_rate_increase: DelegationRate,
+    /// Names of the auction entry points which fail when called, allowing a faulty entry point to
+    /// be switched off at an upgrade.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub(crate) disabled_auction_entry_points: BTreeSet<String>,
+    /// Whether the mint records the account or contract creating each purse.
+    #[serde(default)]
+    pub(crate) enable_purse_ownership_index: bool,
+    /// Whether byte code is compressed when written to global state storage.
+    #[serde(default)]
+    pub(crate) compress_contract_wasm: bool,
     /// Refund handling.
     #[data_size(skip)]
     pub(crate) refund_handling: RefundHandling,
     /// Fee handling.
     pub(crate) fee_handling: FeeHandling,
+    /// Distribution of fees accumulated under `FeeHandling::Accumulate`.
+    pub(crate) fee_distribution: FeeDistribution,
+    /// Existential deposit handling.
+    pub(crate) existential_deposit_handling: ExistentialDepositHandling,
+    /// Gas refunded for storage freed by a deploy.
+    #[data_size(skip)]
+    pub(crate) storage_refund: StorageRefund,
+    /// The behavior switches applied when re-executing blocks of earlier protocol versions, each
+    /// covering the protocol versions up to its own which no mode of a lower version covers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) compatibility_modes: Vec<CompatibilityModeConfig>,
 }
 
 impl CoreConfig {
@@ -161,6 +212,53 @@ impl CoreConfig {
             return false;
         }
 
+        if !self.fee_distribution.is_valid() {
+            error!(
+                frequency = self.fee_distribution.frequency,
+                treasury_weight = self.fee_distribution.treasury_weight,
+                "fee distribution frequency must be at least 1, and a treasury account is \
+                required if the treasury weight is not 0",
+            );
+            return false;
+        }
+
+        if !self.storage_refund.is_valid() {
+            error!(
+                refund_ratio = %self.storage_refund.refund_ratio,
+                "storage refund ratio is not in the range [0, 1]",
+            );
+            return false;
+        }
+
+        let auction_entry_points = auction::auction_entry_points();
+        for entry_point_name in &self.disabled_auction_entry_points {
+            if !auction_entry_points.has_entry_point(entry_point_name) {
+                error!(
+                    %entry_point_name,
+                    "cannot disable an auction entry point which doesn't exist",
+                );
+                return false;
+            }
+            if STEP_AUCTION_ENTRY_POINTS.contains(&entry_point_name.as_str()) {
+                error!(
+                    %entry_point_name,
+                    "cannot disable an auction entry point called by the step",
+                );
+                return false;
+            }
+        }
+
+        let mut mode_protocol_versions = BTreeSet::new();
+        for compatibility_mode in &self.compatibility_modes {
+            if !mode_protocol_versions.insert(compatibility_mode.protocol_version) {
+                error!(
+                    protocol_version = %compatibility_mode.protocol_version,
+                    "more than one compatibility mode for the same protocol version",
+                );
+                return false;
+            }
+        }
+
         true
     }
 }
@@ -187,6 +285,8 @@ impl CoreConfig {
         );
         let max_associated_keys = rng.gen();
         let max_runtime_call_stack_height = rng.gen();
+        let max_contract_call_stack_height = rng.gen();
+        let max_effects_size_bytes = rng.gen();
         let minimum_delegation_amount = rng.gen::<u32>() as u64;
         let prune_batch_size = rng.gen_range(0..100);
         let strict_argument_checking = rng.gen();
@@ -209,6 +309,53 @@ impl CoreConfig {
         } else {
             FeeHandling::Accumulate
         };
+        let fee_distribution = FeeDistribution {
+            frequency: rng.gen_range(1..10),
+            administrators_weight: rng.gen_range(0..10),
+            validators_weight: rng.gen_range(0..10),
+            treasury_weight: rng.gen_range(0..10),
+            treasury: Some(PublicKey::random(rng)),
+            minimum_amount: rng.gen_range(0..1_000_000_000_000),
+        };
+
+        let existential_deposit = rng.gen_range(0..1_000_000_000);
+        let existential_deposit_handling = if rng.gen() {
+            ExistentialDepositHandling::Reject
+        } else {
+            ExistentialDepositHandling::Sweep
+        };
+        let storage_refund = StorageRefund {
+            refund_ratio: Ratio::new(rng.gen_range(0..=100), 100),
+            max_refund: rng.gen_range(0..1_000_000_000),
+        };
+        let delegation_rate_change_cooldown = rng.gen_range(0..10);
+        let max_delegation_rate_increase = rng.gen_range(0..=100);
+        let disabled_auction_entry_points = if rng.gen() {
+            BTreeSet::new()
+        } else {
+            [auction::METHOD_REDELEGATE, auction::METHOD_ADD_RESERVATIONS]
+                .iter()
+                .map(|entry_point_name| entry_point_name.to_string())
+                .collect()
+        };
+        let enable_purse_ownership_index = rng.gen();
+        let compress_contract_wasm = rng.gen();
+        let compatibility_modes = (0..rng.gen_range(0..3u32))
+            .map(|minor| CompatibilityModeConfig {
+                protocol_version: ProtocolVersion::from_parts(1, minor, rng.gen_range(0..100)),
+                unavailable_host_functions: BTreeSet::new(),
+                quirks: if rng.gen() {
+                    BTreeSet::new()
+                } else {
+                    [
+                        Quirk::ReinvestOnlyDelegatorRewards,
+                        Quirk::UnrecordedPaymentTransfers,
+                    ]
+                    .into_iter()
+                    .collect()
+                },
+            })
+            .collect();
 
         CoreConfig {
             era_duration,
@@ -225,6 +372,8 @@ impl CoreConfig {
             round_seigniorage_rate,
             max_associated_keys,
             max_runtime_call_stack_height,
+            max_contract_call_stack_height,
+            max_effects_size_bytes,
             minimum_delegation_amount,
             prune_batch_size,
             strict_argument_checking,
@@ -237,6 +386,16 @@ impl CoreConfig {
             compute_rewards,
             refund_handling,
             fee_handling,
+            fee_distribution,
+            existential_deposit,
+            existential_deposit_handling,
+            storage_refund,
+            delegation_rate_change_cooldown,
+            max_delegation_rate_increase,
+            disabled_auction_entry_points,
+            enable_purse_ownership_index,
+            compress_contract_wasm,
+            compatibility_modes,
         }
     }
 }
@@ -261,6 +420,8 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
         buffer.extend(self.max_associated_keys.to_bytes()?);
         buffer.extend(self.max_runtime_call_stack_height.to_bytes()?);
+        buffer.extend(self.max_contract_call_stack_height.to_bytes()?);
+        buffer.extend(self.max_effects_size_bytes.to_bytes()?);
         buffer.extend(self.minimum_delegation_amount.to_bytes()?);
         buffer.extend(self.prune_batch_size.to_bytes()?);
         buffer.extend(self.strict_argument_checking.to_bytes()?);
@@ -273,6 +434,16 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.administrators.to_bytes()?);
         buffer.extend(self.refund_handling.to_bytes()?);
         buffer.extend(self.fee_handling.to_bytes()?);
+        buffer.extend(self.fee_distribution.to_bytes()?);
+        buffer.extend(self.existential_deposit.to_bytes()?);
+        buffer.extend(self.existential_deposit_handling.to_bytes()?);
+        buffer.extend(self.storage_refund.to_bytes()?);
+        buffer.extend(self.delegation_rate_change_cooldown.to_bytes()?);
+        buffer.extend(self.max_delegation_rate_increase.to_bytes()?);
+        buffer.extend(self.disabled_auction_entry_points.to_bytes()?);
+        buffer.extend(self.enable_purse_ownership_index.to_bytes()?);
+        buffer.extend(self.compress_contract_wasm.to_bytes()?);
+        buffer.extend(self.compatibility_modes.to_bytes()?);
         Ok(buffer)
     }
 
@@ -293,6 +464,8 @@ impl ToBytes for CoreConfig {
             + self.round_seigniorage_rate.serialized_length()
             + self.max_associated_keys.serialized_length()
             + self.max_runtime_call_stack_height.serialized_length()
+            + self.max_contract_call_stack_height.serialized_length()
+            + self.max_effects_size_bytes.serialized_length()
             + self.minimum_delegation_amount.serialized_length()
             + self.prune_batch_size.serialized_length()
             + self.strict_argument_checking.serialized_length()
@@ -305,6 +478,16 @@ impl ToBytes for CoreConfig {
             + self.administrators.serialized_length()
             + self.refund_handling.serialized_length()
             + self.fee_handling.serialized_length()
+            + self.fee_distribution.serialized_length()
+            + self.existential_deposit.serialized_length()
+            + self.existential_deposit_handling.serialized_length()
+            + self.storage_refund.serialized_length()
+            + self.delegation_rate_change_cooldown.serialized_length()
+            + self.max_delegation_rate_increase.serialized_length()
+            + self.disabled_auction_entry_points.serialized_length()
+            + self.enable_purse_ownership_index.serialized_length()
+            + self.compress_contract_wasm.serialized_length()
+            + self.compatibility_modes.serialized_length()
     }
 }
 
@@ -325,6 +508,8 @@ impl FromBytes for CoreConfig {
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
         let (max_associated_keys, remainder) = u32::from_bytes(remainder)?;
         let (max_runtime_call_stack_height, remainder) = u32::from_bytes(remainder)?;
+        let (max_contract_call_stack_height, remainder) = u32::from_bytes(remainder)?;
+        let (max_effects_size_bytes, remainder) = u32::from_bytes(remainder)?;
         let (minimum_delegation_amount, remainder) = u64::from_bytes(remainder)?;
         let (prune_batch_size, remainder) = u64::from_bytes(remainder)?;
         let (strict_argument_checking, remainder) = bool::from_bytes(remainder)?;
@@ -337,6 +522,16 @@ impl FromBytes for CoreConfig {
         let (administrative_accounts, remainder) = FromBytes::from_bytes(remainder)?;
         let (refund_handling, remainder) = FromBytes::from_bytes(remainder)?;
         let (fee_handling, remainder) = FromBytes::from_bytes(remainder)?;
+        let (fee_distribution, remainder) = FromBytes::from_bytes(remainder)?;
+        let (existential_deposit, remainder) = u64::from_bytes(remainder)?;
+        let (existential_deposit_handling, remainder) = FromBytes::from_bytes(remainder)?;
+        let (storage_refund, remainder) = FromBytes::from_bytes(remainder)?;
+        let (delegation_rate_change_cooldown, remainder) = u64::from_bytes(remainder)?;
+        let (max_delegation_rate_increase, remainder) = DelegationRate::from_bytes(remainder)?;
+        let (disabled_auction_entry_points, remainder) = BTreeSet::from_bytes(remainder)?;
+        let (enable_purse_ownership_index, remainder) = bool::from_bytes(remainder)?;
+        let (compress_contract_wasm, remainder) = bool::from_bytes(remainder)?;
+        let (compatibility_modes, remainder) = Vec::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
@@ -352,6 +547,8 @@ impl FromBytes for CoreConfig {
             round_seigniorage_rate,
             max_associated_keys,
             max_runtime_call_stack_height,
+            max_contract_call_stack_height,
+            max_effects_size_bytes,
             minimum_delegation_amount,
             prune_batch_size,
             strict_argument_checking,
@@ -364,6 +561,16 @@ impl FromBytes for CoreConfig {
             administrators: administrative_accounts,
             refund_handling,
             fee_handling,
+            fee_distribution,
+            existential_deposit,
+            existential_deposit_handling,
+            storage_refund,
+            delegation_rate_change_cooldown,
+            max_delegation_rate_increase,
+            disabled_auction_entry_points,
+            enable_purse_ownership_index,
+            compress_contract_wasm,
+            compatibility_modes,
         };
         Ok((config, remainder))
     }
@@ -522,6 +729,8 @@ impl Distribution<LegacyRequiredFinality> for Standard {
 
 #[cfg(test)]
 mod tests {
+    use std::iter;
+
     use super::*;
 
     #[test]
@@ -559,4 +768,25 @@ mod tests {
         config.finality_threshold_fraction = Ratio::new(u64::MAX, u64::MAX - 1);
         assert!(!config.is_valid());
     }
+
+    #[test]
+    fn should_validate_disabled_auction_entry_points() {
+        let mut rng = crate::new_rng();
+        let mut config = CoreConfig::random(&mut rng);
+        config.disabled_auction_entry_points = BTreeSet::new();
+        assert!(config.is_valid());
+        config
+            .disabled_auction_entry_points
+            .insert(auction::METHOD_REDELEGATE.to_string());
+        assert!(config.is_valid());
+        // Should be invalid for unknown entry points.
+        config
+            .disabled_auction_entry_points
+            .insert("no_such_entry_point".to_string());
+        assert!(!config.is_valid());
+        // Should be invalid for entry points the step relies on.
+        config.disabled_auction_entry_points =
+            iter::once(auction::METHOD_RUN_AUCTION.to_string()).collect();
+        assert!(!config.is_valid());
+    }
 }
@@ -17,6 +17,8 @@ use casper_types::{
     Motes, TimeDiff, U512,
 };
 
+use super::DeprecatedDeployItems;
+
 /// Configuration values associated with deploys.
 #[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
@@ -31,15 +33,33 @@ pub struct DeployConfig {
     pub(crate) block_max_transfer_count: u32,
     pub(crate) block_max_approval_count: u32,
     pub(crate) block_gas_limit: u64,
+    pub(crate) block_max_gas_per_account: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) block_max_deploys_per_account: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) block_max_transfers_per_account: Option<u32>,
+    /// The maximum summed size in bytes of all the deploys and transfers in a block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) block_max_payload_size: Option<u32>,
     pub(crate) payment_args_max_length: u32,
     pub(crate) session_args_max_length: u32,
     pub(crate) native_transfer_minimum_motes: u64,
     pub(crate) max_timestamp_leeway: TimeDiff,
+    pub(crate) replay_protection_window: TimeDiff,
+    #[serde(default)]
+    pub(crate) deprecated_items: DeprecatedDeployItems,
 }
 
 impl DeployConfig {
     /// Validates `DeployConfig` parameters
     pub fn is_valid(&self) -> bool {
+        if self.block_max_gas_per_account == 0
+            || self.block_max_deploys_per_account == Some(0)
+            || self.block_max_transfers_per_account == Some(0)
+            || self.block_max_payload_size == Some(0)
+        {
+            return false;
+        }
         // the total number of deploys + transfers should not exceed the number of approvals because
         // each deploy or transfer needs at least one approval to be valid
         if let Some(total_deploy_and_transfer_slots) = self
@@ -51,6 +71,20 @@ impl DeployConfig {
             false
         }
     }
+
+    /// Returns the maximum number of deploys a single account may have in a block, defaulting to
+    /// `block_max_deploy_count` if not set.
+    pub(crate) fn max_deploys_per_account(&self) -> u32 {
+        self.block_max_deploys_per_account
+            .unwrap_or(self.block_max_deploy_count)
+    }
+
+    /// Returns the maximum number of transfers a single account may have in a block, defaulting
+    /// to `block_max_transfer_count` if not set.
+    pub(crate) fn max_transfers_per_account(&self) -> u32 {
+        self.block_max_transfers_per_account
+            .unwrap_or(self.block_max_transfer_count)
+    }
 }
 
 #[cfg(test)]
@@ -66,11 +100,18 @@ impl DeployConfig {
         let block_max_transfer_count = rng.gen();
         let block_max_approval_count = rng.gen();
         let block_gas_limit = rng.gen_range(100_000_000_000..1_000_000_000_000_000);
+        let block_max_gas_per_account = rng.gen_range(1..=block_gas_limit);
+        let block_max_deploys_per_account = rng.gen_bool(0.5).then(|| rng.gen_range(1..=u32::MAX));
+        let block_max_transfers_per_account =
+            rng.gen_bool(0.5).then(|| rng.gen_range(1..=u32::MAX));
+        let block_max_payload_size = rng.gen_bool(0.5).then(|| rng.gen_range(1..=max_block_size));
         let payment_args_max_length = rng.gen();
         let session_args_max_length = rng.gen();
         let native_transfer_minimum_motes =
             rng.gen_range(MAX_PAYMENT_AMOUNT..1_000_000_000_000_000);
         let max_timestamp_leeway = TimeDiff::from_seconds(rng.gen_range(0..6));
+        let replay_protection_window = TimeDiff::from_seconds(rng.gen_range(0..86_400));
+        let deprecated_items = DeprecatedDeployItems::random(rng);
 
         DeployConfig {
             max_payment_cost,
@@ -82,10 +123,16 @@ impl DeployConfig {
             block_max_transfer_count,
             block_max_approval_count,
             block_gas_limit,
+            block_max_gas_per_account,
+            block_max_deploys_per_account,
+            block_max_transfers_per_account,
+            block_max_payload_size,
             payment_args_max_length,
             session_args_max_length,
             native_transfer_minimum_motes,
             max_timestamp_leeway,
+            replay_protection_window,
+            deprecated_items,
         }
     }
 }
@@ -103,10 +150,16 @@ impl Default for DeployConfig {
             block_max_transfer_count: 1000,
             block_max_approval_count: 2600,
             block_gas_limit: 10_000_000_000_000,
+            block_max_gas_per_account: 10_000_000_000_000,
+            block_max_deploys_per_account: None,
+            block_max_transfers_per_account: None,
+            block_max_payload_size: None,
             payment_args_max_length: 1024,
             session_args_max_length: 1024,
             native_transfer_minimum_motes: MAX_PAYMENT_AMOUNT,
             max_timestamp_leeway: TimeDiff::from_str("5sec").unwrap(),
+            replay_protection_window: TimeDiff::from_str("1day").unwrap(),
+            deprecated_items: DeprecatedDeployItems::default(),
         }
     }
 }
@@ -123,10 +176,16 @@ impl ToBytes for DeployConfig {
         buffer.extend(self.block_max_transfer_count.to_bytes()?);
         buffer.extend(self.block_max_approval_count.to_bytes()?);
         buffer.extend(self.block_gas_limit.to_bytes()?);
+        buffer.extend(self.block_max_gas_per_account.to_bytes()?);
+        buffer.extend(self.block_max_deploys_per_account.to_bytes()?);
+        buffer.extend(self.block_max_transfers_per_account.to_bytes()?);
+        buffer.extend(self.block_max_payload_size.to_bytes()?);
         buffer.extend(self.payment_args_max_length.to_bytes()?);
         buffer.extend(self.session_args_max_length.to_bytes()?);
         buffer.extend(self.native_transfer_minimum_motes.to_bytes()?);
         buffer.extend(self.max_timestamp_leeway.to_bytes()?);
+        buffer.extend(self.replay_protection_window.to_bytes()?);
+        buffer.extend(self.deprecated_items.to_bytes()?);
         Ok(buffer)
     }
 
@@ -140,10 +199,16 @@ impl ToBytes for DeployConfig {
             + self.block_max_transfer_count.serialized_length()
             + self.block_max_approval_count.serialized_length()
             + self.block_gas_limit.serialized_length()
+            + self.block_max_gas_per_account.serialized_length()
+            + self.block_max_deploys_per_account.serialized_length()
+            + self.block_max_transfers_per_account.serialized_length()
+            + self.block_max_payload_size.serialized_length()
             + self.payment_args_max_length.serialized_length()
             + self.session_args_max_length.serialized_length()
             + self.native_transfer_minimum_motes.serialized_length()
             + self.max_timestamp_leeway.serialized_length()
+            + self.replay_protection_window.serialized_length()
+            + self.deprecated_items.serialized_length()
     }
 }
 
@@ -159,10 +224,16 @@ impl FromBytes for DeployConfig {
         let (block_max_transfer_count, remainder) = u32::from_bytes(remainder)?;
         let (block_max_approval_count, remainder) = u32::from_bytes(remainder)?;
         let (block_gas_limit, remainder) = u64::from_bytes(remainder)?;
+        let (block_max_gas_per_account, remainder) = u64::from_bytes(remainder)?;
+        let (block_max_deploys_per_account, remainder) = Option::<u32>::from_bytes(remainder)?;
+        let (block_max_transfers_per_account, remainder) = Option::<u32>::from_bytes(remainder)?;
+        let (block_max_payload_size, remainder) = Option::<u32>::from_bytes(remainder)?;
         let (payment_args_max_length, remainder) = u32::from_bytes(remainder)?;
         let (session_args_max_length, remainder) = u32::from_bytes(remainder)?;
         let (native_transfer_minimum_motes, remainder) = u64::from_bytes(remainder)?;
         let (max_timestamp_leeway, remainder) = TimeDiff::from_bytes(remainder)?;
+        let (replay_protection_window, remainder) = TimeDiff::from_bytes(remainder)?;
+        let (deprecated_items, remainder) = DeprecatedDeployItems::from_bytes(remainder)?;
         let config = DeployConfig {
             max_payment_cost,
             max_ttl,
@@ -173,10 +244,16 @@ impl FromBytes for DeployConfig {
             block_max_transfer_count,
             block_max_approval_count,
             block_gas_limit,
+            block_max_gas_per_account,
+            block_max_deploys_per_account,
+            block_max_transfers_per_account,
+            block_max_payload_size,
             payment_args_max_length,
             session_args_max_length,
             native_transfer_minimum_motes,
             max_timestamp_leeway,
+            replay_protection_window,
+            deprecated_items,
         };
         Ok((config, remainder))
     }
@@ -228,4 +305,60 @@ mod tests {
         };
         assert!(config.is_valid());
     }
+
+    #[test]
+    fn block_max_gas_per_account_valid() {
+        let config = DeployConfig {
+            block_max_gas_per_account: 0,
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+    }
+
+    #[test]
+    fn block_max_count_per_account_valid() {
+        let config = DeployConfig {
+            block_max_deploys_per_account: Some(0),
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+
+        let config = DeployConfig {
+            block_max_transfers_per_account: Some(0),
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+    }
+
+    #[test]
+    fn block_max_payload_size_valid() {
+        let config = DeployConfig {
+            block_max_payload_size: Some(0),
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+
+        let config = DeployConfig {
+            block_max_payload_size: Some(1),
+            ..Default::default()
+        };
+        assert!(config.is_valid());
+    }
+
+    #[test]
+    fn count_per_account_defaults_to_block_limits() {
+        let config = DeployConfig::default();
+        let encoded = toml::to_string_pretty(&config).unwrap();
+        assert!(!encoded.contains("max_deploys_per_account"));
+        assert!(!encoded.contains("max_transfers_per_account"));
+        let decoded: DeployConfig = toml::from_str(&encoded).unwrap();
+        assert_eq!(
+            decoded.max_deploys_per_account(),
+            decoded.block_max_deploy_count
+        );
+        assert_eq!(
+            decoded.max_transfers_per_account(),
+            decoded.block_max_transfer_count
+        );
+    }
 }
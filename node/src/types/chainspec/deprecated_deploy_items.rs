@@ -0,0 +1,230 @@
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+#[cfg(test)]
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+#[cfg(test)]
+use casper_types::testing::TestRng;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    EraId,
+};
+
+/// The forms an [`ExecutableDeployItem`] can take.
+#[derive(
+    Copy, Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Serialize, Deserialize,
+)]
+pub enum DeployItemKind {
+    /// [`ExecutableDeployItem::ModuleBytes`].
+    ModuleBytes,
+    /// [`ExecutableDeployItem::StoredContractByHash`].
+    StoredContractByHash,
+    /// [`ExecutableDeployItem::StoredContractByName`].
+    StoredContractByName,
+    /// [`ExecutableDeployItem::StoredVersionedContractByHash`].
+    StoredVersionedContractByHash,
+    /// [`ExecutableDeployItem::StoredVersionedContractByName`].
+    StoredVersionedContractByName,
+    /// [`ExecutableDeployItem::Transfer`].
+    Transfer,
+}
+
+impl DeployItemKind {
+    /// Returns the kind of the given deploy item.
+    pub(crate) fn of(item: &ExecutableDeployItem) -> Self {
+        match item {
+            ExecutableDeployItem::ModuleBytes { .. } => DeployItemKind::ModuleBytes,
+            ExecutableDeployItem::StoredContractByHash { .. } => {
+                DeployItemKind::StoredContractByHash
+            }
+            ExecutableDeployItem::StoredContractByName { .. } => {
+                DeployItemKind::StoredContractByName
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash { .. } => {
+                DeployItemKind::StoredVersionedContractByHash
+            }
+            ExecutableDeployItem::StoredVersionedContractByName { .. } => {
+                DeployItemKind::StoredVersionedContractByName
+            }
+            ExecutableDeployItem::Transfer { .. } => DeployItemKind::Transfer,
+        }
+    }
+
+    /// Returns the advice given to users of a deprecated deploy item of this kind.
+    pub(crate) fn replacement_advice(self) -> &'static str {
+        match self {
+            DeployItemKind::StoredContractByName => {
+                "call the contract by hash via StoredContractByHash instead"
+            }
+            DeployItemKind::StoredContractByHash => {
+                "call the contract via its package using StoredVersionedContractByHash instead"
+            }
+            DeployItemKind::StoredVersionedContractByName => {
+                "call the contract package by hash via StoredVersionedContractByHash instead"
+            }
+            DeployItemKind::ModuleBytes
+            | DeployItemKind::StoredVersionedContractByHash
+            | DeployItemKind::Transfer => "there is no replacement available via a deploy",
+        }
+    }
+}
+
+impl Display for DeployItemKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(
+            match self {
+                DeployItemKind::ModuleBytes => "ModuleBytes",
+                DeployItemKind::StoredContractByHash => "StoredContractByHash",
+                DeployItemKind::StoredContractByName => "StoredContractByName",
+                DeployItemKind::StoredVersionedContractByHash => "StoredVersionedContractByHash",
+                DeployItemKind::StoredVersionedContractByName => "StoredVersionedContractByName",
+                DeployItemKind::Transfer => "Transfer",
+            },
+            formatter,
+        )
+    }
+}
+
+/// The eras from which deploys using the legacy forms of deploy items are neither accepted nor
+/// executed.
+///
+/// Standard payment (empty module bytes in the payment) is never treated as deprecated.
+#[derive(Copy, Clone, Default, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct DeprecatedDeployItems {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) module_bytes: Option<EraId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) stored_contract_by_hash: Option<EraId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) stored_contract_by_name: Option<EraId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) stored_versioned_contract_by_hash: Option<EraId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) stored_versioned_contract_by_name: Option<EraId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) transfer: Option<EraId>,
+}
+
+impl DeprecatedDeployItems {
+    /// Returns the era from which deploy items of the given kind are deprecated, if any.
+    pub(crate) fn activation_era(&self, kind: DeployItemKind) -> Option<EraId> {
+        match kind {
+            DeployItemKind::ModuleBytes => self.module_bytes,
+            DeployItemKind::StoredContractByHash => self.stored_contract_by_hash,
+            DeployItemKind::StoredContractByName => self.stored_contract_by_name,
+            DeployItemKind::StoredVersionedContractByHash => self.stored_versioned_contract_by_hash,
+            DeployItemKind::StoredVersionedContractByName => self.stored_versioned_contract_by_name,
+            DeployItemKind::Transfer => self.transfer,
+        }
+    }
+
+    /// Returns the era from which deploy items of the given kind are deprecated if that is no
+    /// later than `era_id`.
+    pub(crate) fn deprecated_since(&self, kind: DeployItemKind, era_id: EraId) -> Option<EraId> {
+        self.activation_era(kind)
+            .filter(|activation_era| *activation_era <= era_id)
+    }
+}
+
+#[cfg(test)]
+impl DeprecatedDeployItems {
+    /// Generates a random instance using a `TestRng`.
+    pub fn random(rng: &mut TestRng) -> Self {
+        let mut random_era = || {
+            if rng.gen() {
+                Some(EraId::new(rng.gen_range(0..1_000)))
+            } else {
+                None
+            }
+        };
+        DeprecatedDeployItems {
+            module_bytes: random_era(),
+            stored_contract_by_hash: random_era(),
+            stored_contract_by_name: random_era(),
+            stored_versioned_contract_by_hash: random_era(),
+            stored_versioned_contract_by_name: random_era(),
+            transfer: random_era(),
+        }
+    }
+}
+
+impl ToBytes for DeprecatedDeployItems {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.module_bytes.to_bytes()?);
+        buffer.extend(self.stored_contract_by_hash.to_bytes()?);
+        buffer.extend(self.stored_contract_by_name.to_bytes()?);
+        buffer.extend(self.stored_versioned_contract_by_hash.to_bytes()?);
+        buffer.extend(self.stored_versioned_contract_by_name.to_bytes()?);
+        buffer.extend(self.transfer.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.module_bytes.serialized_length()
+            + self.stored_contract_by_hash.serialized_length()
+            + self.stored_contract_by_name.serialized_length()
+            + self.stored_versioned_contract_by_hash.serialized_length()
+            + self.stored_versioned_contract_by_name.serialized_length()
+            + self.transfer.serialized_length()
+    }
+}
+
+impl FromBytes for DeprecatedDeployItems {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (module_bytes, remainder) = Option::<EraId>::from_bytes(bytes)?;
+        let (stored_contract_by_hash, remainder) = Option::<EraId>::from_bytes(remainder)?;
+        let (stored_contract_by_name, remainder) = Option::<EraId>::from_bytes(remainder)?;
+        let (stored_versioned_contract_by_hash, remainder) =
+            Option::<EraId>::from_bytes(remainder)?;
+        let (stored_versioned_contract_by_name, remainder) =
+            Option::<EraId>::from_bytes(remainder)?;
+        let (transfer, remainder) = Option::<EraId>::from_bytes(remainder)?;
+        let deprecated_deploy_items = DeprecatedDeployItems {
+            module_bytes,
+            stored_contract_by_hash,
+            stored_contract_by_name,
+            stored_versioned_contract_by_hash,
+            stored_versioned_contract_by_name,
+            transfer,
+        };
+        Ok((deprecated_deploy_items, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        let mut rng = crate::new_rng();
+        let deprecated_deploy_items = DeprecatedDeployItems::random(&mut rng);
+        bytesrepr::test_serialization_roundtrip(&deprecated_deploy_items);
+    }
+
+    #[test]
+    fn should_be_deprecated_from_activation_era() {
+        let deprecated_deploy_items = DeprecatedDeployItems {
+            transfer: Some(EraId::new(10)),
+            ..Default::default()
+        };
+        assert_eq!(
+            deprecated_deploy_items.deprecated_since(DeployItemKind::Transfer, EraId::new(9)),
+            None
+        );
+        assert_eq!(
+            deprecated_deploy_items.deprecated_since(DeployItemKind::Transfer, EraId::new(10)),
+            Some(EraId::new(10))
+        );
+        assert_eq!(
+            deprecated_deploy_items.deprecated_since(DeployItemKind::ModuleBytes, EraId::new(10)),
+            None
+        );
+    }
+}
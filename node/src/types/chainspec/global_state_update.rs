@@ -35,6 +35,8 @@ pub struct GlobalStateUpdateValidatorInfo {
 pub struct GlobalStateUpdateConfig {
     validators: Option<Vec<GlobalStateUpdateValidatorInfo>>,
     entries: Vec<GlobalStateUpdateEntry>,
+    #[serde(default)]
+    allow_dangerous_overrides: bool,
 }
 
 impl GlobalStateUpdateConfig {
@@ -62,12 +64,35 @@ impl GlobalStateUpdateConfig {
 pub struct GlobalStateUpdate {
     pub(crate) validators: Option<BTreeMap<PublicKey, U512>>,
     pub(crate) entries: BTreeMap<Key, Bytes>,
+    /// Whether `entries` may overwrite balances, the total supply or the system contract registry.
+    pub(crate) allow_dangerous_overrides: bool,
+}
+
+/// Leading byte of the extended encoding.
+///
+/// The legacy encoding starts with the `Option` tag of `validators` (`0` or `1`), so any other
+/// value unambiguously marks an update carrying the fields added after it.
+const EXTENDED_ENCODING_TAG: u8 = 2;
+
+impl GlobalStateUpdate {
+    /// Updates which don't allow dangerous overrides keep the legacy encoding, so that the
+    /// serialized form (and hence the chainspec hash) of existing upgrades doesn't change.
+    fn uses_extended_encoding(&self) -> bool {
+        self.allow_dangerous_overrides
+    }
 }
 
 impl ToBytes for GlobalStateUpdate {
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        if self.uses_extended_encoding() {
+            writer.push(EXTENDED_ENCODING_TAG);
+        }
         self.validators.write_bytes(writer)?;
-        self.entries.write_bytes(writer)
+        self.entries.write_bytes(writer)?;
+        if self.uses_extended_encoding() {
+            self.allow_dangerous_overrides.write_bytes(writer)?;
+        }
+        Ok(())
     }
 
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
@@ -77,17 +102,34 @@ impl ToBytes for GlobalStateUpdate {
     }
 
     fn serialized_length(&self) -> usize {
-        self.validators.serialized_length() + self.entries.serialized_length()
+        let legacy_length = self.validators.serialized_length() + self.entries.serialized_length();
+        if self.uses_extended_encoding() {
+            EXTENDED_ENCODING_TAG.serialized_length()
+                + legacy_length
+                + self.allow_dangerous_overrides.serialized_length()
+        } else {
+            legacy_length
+        }
     }
 }
 
 impl FromBytes for GlobalStateUpdate {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (validators, remainder) = Option::<BTreeMap<PublicKey, U512>>::from_bytes(bytes)?;
+        let (extended, remainder) = match bytes.split_first() {
+            Some((&EXTENDED_ENCODING_TAG, remainder)) => (true, remainder),
+            _ => (false, bytes),
+        };
+        let (validators, remainder) = Option::<BTreeMap<PublicKey, U512>>::from_bytes(remainder)?;
         let (entries, remainder) = BTreeMap::<Key, Bytes>::from_bytes(remainder)?;
+        let (allow_dangerous_overrides, remainder) = if extended {
+            bool::from_bytes(remainder)?
+        } else {
+            (false, remainder)
+        };
         let global_state_update = GlobalStateUpdate {
             entries,
             validators,
+            allow_dangerous_overrides,
         };
         Ok((global_state_update, remainder))
     }
@@ -133,6 +175,7 @@ impl TryFrom<GlobalStateUpdateConfig> for GlobalStateUpdate {
         Ok(GlobalStateUpdate {
             validators,
             entries,
+            allow_dangerous_overrides: config.allow_dangerous_overrides,
         })
     }
 }
@@ -157,6 +200,7 @@ impl GlobalStateUpdate {
         Self {
             validators: Some(validators),
             entries,
+            allow_dangerous_overrides: rng.gen(),
         }
     }
 }
@@ -171,4 +215,22 @@ mod tests {
         let update = GlobalStateUpdate::random(&mut rng);
         bytesrepr::test_serialization_roundtrip(&update);
     }
+
+    #[test]
+    fn should_keep_legacy_encoding_without_dangerous_overrides() {
+        let mut rng = crate::new_rng();
+        let mut update = GlobalStateUpdate::random(&mut rng);
+        update.allow_dangerous_overrides = false;
+
+        let mut legacy_bytes = update.validators.to_bytes().unwrap();
+        legacy_bytes.extend(update.entries.to_bytes().unwrap());
+        assert_eq!(update.to_bytes().unwrap(), legacy_bytes);
+
+        let (decoded, remainder) = GlobalStateUpdate::from_bytes(&legacy_bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(decoded, update);
+
+        update.allow_dangerous_overrides = true;
+        bytesrepr::test_serialization_roundtrip(&update);
+    }
 }
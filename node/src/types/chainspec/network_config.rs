@@ -2,10 +2,14 @@ use datasize::DataSize;
 #[cfg(test)]
 use rand::Rng;
 use serde::Serialize;
+use tracing::error;
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 #[cfg(test)]
 use casper_types::testing::TestRng;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    EraId,
+};
 
 use super::AccountsConfig;
 
@@ -14,6 +18,14 @@ use super::AccountsConfig;
 pub struct NetworkConfig {
     /// The network name.
     pub name: String,
+    /// The numeric identifier of the network, or zero if none is assigned.
+    ///
+    /// Networks which share key pairs must use distinct chain ids.  The ids of the public networks
+    /// are reserved for their names, see [`REGISTERED_CHAIN_IDS`].
+    pub chain_id: u32,
+    /// The era from which deploy approvals have to sign the deploy hash bound to the chain id,
+    /// rather than the bare deploy hash.
+    pub chain_bound_approvals: Option<EraId>,
     /// The maximum size of an accepted network message, in bytes.
     pub maximum_net_message_size: u32,
     /// Validator accounts specified in the chainspec.
@@ -22,16 +34,53 @@ pub struct NetworkConfig {
     pub accounts_config: AccountsConfig,
 }
 
+/// The chain ids reserved for the public networks, along with the names of those networks.
+pub const REGISTERED_CHAIN_IDS: &[(u32, &str)] = &[(1, "casper"), (2, "casper-test")];
+
+impl NetworkConfig {
+    /// Returns `false` if approvals are to be bound to the chain without a chain id being assigned,
+    /// or if either an assigned chain id or the network name is registered but not paired with its
+    /// registered counterpart.
+    pub(crate) fn is_valid(&self) -> bool {
+        if self.chain_id == 0 {
+            if self.chain_bound_approvals.is_some() {
+                error!("approvals can't be bound to the chain without a chain id");
+                return false;
+            }
+            return true;
+        }
+        for (registered_id, registered_name) in REGISTERED_CHAIN_IDS {
+            if (self.chain_id == *registered_id) != (self.name == *registered_name) {
+                error!(
+                    chain_id = self.chain_id,
+                    name = %self.name,
+                    %registered_id,
+                    %registered_name,
+                    "chain id and network name don't match the chain id registry"
+                );
+                return false;
+            }
+        }
+        true
+    }
+}
+
 #[cfg(test)]
 impl NetworkConfig {
     /// Generates a random instance using a `TestRng`.
     pub fn random(rng: &mut TestRng) -> Self {
         let name = rng.gen::<char>().to_string();
+        let chain_id = rng.gen_range(1_000..u32::MAX);
+        let chain_bound_approvals = rng
+            .gen::<bool>()
+            .then(|| EraId::new(rng.gen_range(0..1_000)));
         let maximum_net_message_size = 4 + rng.gen_range(0..4);
         let accounts_config = AccountsConfig::random(rng);
 
         NetworkConfig {
             name,
+            chain_id,
+            chain_bound_approvals,
             maximum_net_message_size,
             accounts_config,
         }
@@ -44,6 +93,8 @@ impl ToBytes for NetworkConfig {
         buffer.extend(self.name.to_bytes()?);
         buffer.extend(self.accounts_config.to_bytes()?);
         buffer.extend(self.maximum_net_message_size.to_bytes()?);
+        buffer.extend(self.chain_id.to_bytes()?);
+        buffer.extend(self.chain_bound_approvals.to_bytes()?);
         Ok(buffer)
     }
 
@@ -51,6 +102,8 @@ impl ToBytes for NetworkConfig {
         self.name.serialized_length()
             + self.accounts_config.serialized_length()
             + self.maximum_net_message_size.serialized_length()
+            + self.chain_id.serialized_length()
+            + self.chain_bound_approvals.serialized_length()
     }
 }
 
@@ -59,8 +112,12 @@ impl FromBytes for NetworkConfig {
         let (name, remainder) = String::from_bytes(bytes)?;
         let (accounts_config, remainder) = FromBytes::from_bytes(remainder)?;
         let (maximum_net_message_size, remainder) = FromBytes::from_bytes(remainder)?;
+        let (chain_id, remainder) = FromBytes::from_bytes(remainder)?;
+        let (chain_bound_approvals, remainder) = FromBytes::from_bytes(remainder)?;
         let config = NetworkConfig {
             name,
+            chain_id,
+            chain_bound_approvals,
             maximum_net_message_size,
             accounts_config,
         };
@@ -78,4 +135,30 @@ mod tests {
         let config = NetworkConfig::random(&mut rng);
         bytesrepr::test_serialization_roundtrip(&config);
     }
+
+    #[test]
+    fn should_validate_chain_id_against_registry() {
+        let mut rng = crate::new_rng();
+        let mut config = NetworkConfig::random(&mut rng);
+        assert!(config.is_valid());
+
+        // No chain id needs to be assigned unless approvals are to be bound to the chain.
+        config.chain_id = 0;
+        config.chain_bound_approvals = None;
+        assert!(config.is_valid());
+        config.chain_bound_approvals = Some(EraId::new(1));
+        assert!(!config.is_valid());
+
+        config.name = "casper".to_string();
+        config.chain_id = 1;
+        assert!(config.is_valid());
+
+        // A registered name must use its registered chain id, and vice versa.
+        config.chain_id = 2;
+        assert!(!config.is_valid());
+        config.name = "casper-tset".to_string();
+        assert!(!config.is_valid());
+        config.chain_id = 1_000;
+        assert!(config.is_valid());
+    }
 }
@@ -11,7 +11,7 @@ use std::{convert::TryFrom, path::Path};
 use serde::{Deserialize, Serialize};
 
 use casper_execution_engine::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
-use casper_types::{bytesrepr::Bytes, file_utils, ProtocolVersion};
+use casper_types::{bytesrepr::Bytes, file_utils, EraId, ProtocolVersion};
 
 use super::{
     accounts_config::AccountsConfig, global_state_update::GlobalStateUpdateConfig, ActivationPoint,
@@ -24,6 +24,10 @@ use super::{
 #[serde(deny_unknown_fields)]
 struct TomlNetwork {
     name: String,
+    #[serde(default)]
+    chain_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    chain_bound_approvals: Option<EraId>,
     maximum_net_message_size: u32,
 }
 
@@ -59,6 +63,8 @@ impl From<&Chainspec> for TomlChainspec {
         };
         let network = TomlNetwork {
             name: chainspec.network_config.name.clone(),
+            chain_id: chainspec.network_config.chain_id,
+            chain_bound_approvals: chainspec.network_config.chain_bound_approvals,
             maximum_net_message_size: chainspec.network_config.maximum_net_message_size,
         };
 
@@ -97,6 +103,8 @@ pub(super) fn parse_toml<P: AsRef<Path>>(
 
     let network_config = NetworkConfig {
         name: toml_chainspec.network.name,
+        chain_id: toml_chainspec.network.chain_id,
+        chain_bound_approvals: toml_chainspec.network.chain_bound_approvals,
         accounts_config,
         maximum_net_message_size: toml_chainspec.network.maximum_net_message_size,
     };
@@ -52,6 +52,14 @@ impl ProtocolConfig {
         Ok(update_mapping)
     }
 
+    /// Returns `true` if the emergency update may overwrite balances, the total supply or the
+    /// system contract registry.
+    pub(crate) fn allow_dangerous_overrides(&self) -> bool {
+        self.global_state_update
+            .as_ref()
+            .map_or(false, |update| update.allow_dangerous_overrides)
+    }
+
     /// Returns whether the block header belongs to the last block before the upgrade to the
     /// current protocol version.
     pub fn is_last_block_before_activation(&self, block_header: &BlockHeader) -> bool {
@@ -7,6 +7,7 @@ mod deploy_hash;
 mod deploy_hash_with_approvals;
 mod deploy_header;
 mod deploy_or_transfer_hash;
+mod deploy_status;
 mod deploy_with_finalized_approvals;
 mod error;
 mod finalized_approvals;
@@ -19,7 +20,7 @@ use std::{
     cmp,
     collections::BTreeSet,
     fmt::{self, Debug, Display, Formatter},
-    hash,
+    hash, iter,
 };
 
 use datasize::DataSize;
@@ -43,19 +44,19 @@ use casper_types::bytesrepr::Bytes;
 use casper_types::testing::TestRng;
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
-    crypto, runtime_args,
+    runtime_args,
     system::standard_payment::ARG_AMOUNT,
-    PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, U512,
+    EraId, PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, U512,
 };
 
 use crate::{
     components::{
-        fetcher::{EmptyValidationMetadata, FetchItem, Tag},
+        fetcher::{FetchItem, Tag},
         gossiper::{GossipItem, LargeGossipItem},
     },
     effect::GossipTarget,
     rpcs::docs::DocExample,
-    types::chainspec::DeployConfig,
+    types::chainspec::{DeployConfig, DeployItemKind, DeprecatedDeployItems},
     utils::{ds, DisplayIter},
 };
 pub use approval::Approval;
@@ -64,6 +65,7 @@ pub use deploy_hash::DeployHash;
 pub(crate) use deploy_hash_with_approvals::DeployHashWithApprovals;
 pub use deploy_header::DeployHeader;
 pub use deploy_or_transfer_hash::DeployOrTransferHash;
+pub use deploy_status::DeployStatus;
 pub(crate) use deploy_with_finalized_approvals::DeployWithFinalizedApprovals;
 pub use error::{DeployConfigurationFailure, Error as DeployError, ExcessiveSizeError};
 pub(crate) use finalized_approvals::FinalizedApprovals;
@@ -181,6 +183,13 @@ impl Deploy {
         self.approvals.insert(approval);
     }
 
+    /// Adds a signature of this deploy's hash bound to the chain with the given id to its
+    /// approvals.
+    pub fn sign_for_chain(&mut self, chain_id: u32, secret_key: &SecretKey) {
+        let approval = Approval::create_for_chain(&self.hash, chain_id, secret_key);
+        self.approvals.insert(approval);
+    }
+
     /// Returns the `DeployHash` identifying this `Deploy`.
     pub fn hash(&self) -> &DeployHash {
         &self.hash
@@ -246,11 +255,13 @@ impl Deploy {
         };
         let size_estimate = self.serialized_length();
         let is_transfer = self.session.is_transfer();
+        let approvals_count = self.approvals.len();
         Ok(DeployFootprint {
             header,
             gas_estimate,
             size_estimate,
             is_transfer,
+            approvals_count,
         })
     }
 
@@ -289,9 +300,14 @@ impl Deploy {
     ///   * the deploy hash is correct (should be the hash of the header), and
     ///   * the body hash is correct (should be the hash of the body), and
     ///   * approvals are non empty, and
-    ///   * all approvals are valid signatures of the deploy hash
-    pub fn is_valid(&self) -> Result<(), DeployConfigurationFailure> {
-        self.is_valid.get_or_init(|| validate_deploy(self)).clone()
+    ///   * all approvals are valid signatures of the deploy hash, either bare or bound to the chain
+    ///     with the given id
+    ///
+    /// The result is cached, so a deploy must only ever be checked against a single chain id.
+    pub fn is_valid(&self, chain_id: u32) -> Result<(), DeployConfigurationFailure> {
+        self.is_valid
+            .get_or_init(|| validate_deploy(self, chain_id))
+            .clone()
     }
 
     /// Returns Ok if and only if:
@@ -419,6 +435,70 @@ impl Deploy {
 
         Ok(())
     }
+
+    /// Returns Ok if and only if all approvals are bound to the chain with the given id, should
+    /// approvals be required to be bound to the chain in the given era.
+    ///
+    /// The approvals are expected to have been validated already.
+    pub(crate) fn is_approval_binding_compliant(
+        &self,
+        chain_id: u32,
+        chain_bound_approvals: Option<EraId>,
+        era_id: EraId,
+    ) -> Result<(), DeployConfigurationFailure> {
+        let activation_era = match chain_bound_approvals {
+            Some(activation_era) if activation_era <= era_id => activation_era,
+            _ => return Ok(()),
+        };
+        for (index, approval) in self.approvals.iter().enumerate() {
+            if !approval.is_bound_to_chain(&self.hash, chain_id) {
+                debug!(
+                    deploy_hash = %self.hash(),
+                    index,
+                    %activation_era,
+                    "approval not bound to the chain"
+                );
+                return Err(DeployConfigurationFailure::UnboundApproval {
+                    index,
+                    activation_era,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns Ok if and only if neither the payment nor the session use a form of deploy item
+    /// which is deprecated in the given era.
+    ///
+    /// Standard payment is accepted even if module bytes are deprecated.
+    pub(crate) fn is_deprecation_compliant(
+        &self,
+        deprecated_items: &DeprecatedDeployItems,
+        era_id: EraId,
+    ) -> Result<(), DeployConfigurationFailure> {
+        let is_standard_payment = matches!(
+            self.payment(),
+            ExecutableDeployItem::ModuleBytes { module_bytes, .. } if module_bytes.is_empty()
+        );
+        let items =
+            iter::once(self.session()).chain((!is_standard_payment).then(|| self.payment()));
+        for item in items {
+            let kind = DeployItemKind::of(item);
+            if let Some(activation_era) = deprecated_items.deprecated_since(kind, era_id) {
+                debug!(
+                    deploy_hash = %self.hash(),
+                    %kind,
+                    %activation_era,
+                    "deprecated deploy item"
+                );
+                return Err(DeployConfigurationFailure::DeprecatedDeployItem {
+                    kind,
+                    activation_era,
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 impl hash::Hash for Deploy {
@@ -527,10 +607,17 @@ impl FromBytes for Deploy {
     }
 }
 
+/// The metadata required to validate a fetched deploy.
+#[derive(Clone, Copy, Eq, PartialEq, Serialize, Debug, DataSize)]
+pub(crate) struct DeployValidationMetadata {
+    /// The id of the chain the deploy's approvals may be bound to.
+    pub(crate) chain_id: u32,
+}
+
 impl FetchItem for Deploy {
     type Id = DeployId;
     type ValidationError = DeployConfigurationFailure;
-    type ValidationMetadata = EmptyValidationMetadata;
+    type ValidationMetadata = DeployValidationMetadata;
 
     const TAG: Tag = Tag::Deploy;
 
@@ -543,8 +630,8 @@ impl FetchItem for Deploy {
         DeployId::new(deploy_hash, approvals_hash)
     }
 
-    fn validate(&self, _metadata: &EmptyValidationMetadata) -> Result<(), Self::ValidationError> {
-        self.is_valid()
+    fn validate(&self, metadata: &DeployValidationMetadata) -> Result<(), Self::ValidationError> {
+        self.is_valid(metadata.chain_id)
     }
 }
 
@@ -630,7 +717,7 @@ fn serialize_body(payment: &ExecutableDeployItem, session: &ExecutableDeployItem
 
 /// Computationally expensive validity check for a given deploy instance, including
 /// asymmetric_key signing verification.
-fn validate_deploy(deploy: &Deploy) -> Result<(), DeployConfigurationFailure> {
+fn validate_deploy(deploy: &Deploy, chain_id: u32) -> Result<(), DeployConfigurationFailure> {
     if deploy.approvals.is_empty() {
         warn!(?deploy, "deploy has no approvals");
         return Err(DeployConfigurationFailure::EmptyApprovals);
@@ -639,7 +726,7 @@ fn validate_deploy(deploy: &Deploy) -> Result<(), DeployConfigurationFailure> {
     deploy.has_valid_hash()?;
 
     for (index, approval) in deploy.approvals.iter().enumerate() {
-        if let Err(error) = crypto::verify(deploy.hash, approval.signature(), approval.signer()) {
+        if let Err(error) = approval.verify(&deploy.hash, chain_id) {
             warn!(?deploy, "failed to verify approval {}: {}", index, error);
             return Err(DeployConfigurationFailure::InvalidApproval {
                 index,
@@ -1117,7 +1204,7 @@ impl Deploy {
 
 #[cfg(test)]
 mod tests {
-    use std::{iter, time::Duration};
+    use std::time::Duration;
 
     use casper_execution_engine::core::engine_state::MAX_PAYMENT_AMOUNT;
     use casper_types::{bytesrepr::Bytes, CLValue};
@@ -1125,6 +1212,7 @@ mod tests {
     use super::*;
 
     const DEFAULT_MAX_ASSOCIATED_KEYS: u32 = 100;
+    const CHAIN_ID: u32 = 10;
 
     #[test]
     fn json_roundtrip() {
@@ -1196,7 +1284,7 @@ mod tests {
             None,
             "is valid should initially be None"
         );
-        deploy.is_valid().expect("should be valid");
+        deploy.is_valid(CHAIN_ID).expect("should be valid");
         assert_eq!(
             deploy.is_valid.get(),
             Some(&Ok(())),
@@ -1209,7 +1297,7 @@ mod tests {
             invalid_deploy.is_valid.get().is_none(),
             "is valid should initially be None"
         );
-        let actual_error = invalid_deploy.is_valid().unwrap_err();
+        let actual_error = invalid_deploy.is_valid(CHAIN_ID).unwrap_err();
 
         // Ignore the `error_msg` field of `InvalidApproval` when comparing to expected error, as
         // this makes the test too fragile.  Otherwise expect the actual error should exactly match
@@ -1828,4 +1916,107 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn not_acceptable_due_to_deprecated_deploy_item() {
+        let mut rng = crate::new_rng();
+        let chain_name = "net-1";
+        let deploy_config = DeployConfig::default();
+        let deploy = create_deploy(
+            &mut rng,
+            deploy_config.max_ttl,
+            deploy_config.max_dependencies as usize,
+            chain_name,
+        );
+        let activation_era = EraId::new(5);
+
+        // Standard payment is not affected by deprecating module bytes.
+        let deprecated_items = DeprecatedDeployItems {
+            module_bytes: Some(activation_era),
+            ..Default::default()
+        };
+        assert_eq!(
+            Ok(()),
+            deploy.is_deprecation_compliant(&deprecated_items, activation_era)
+        );
+
+        let deprecated_items = DeprecatedDeployItems {
+            transfer: Some(activation_era),
+            ..Default::default()
+        };
+        assert_eq!(
+            Ok(()),
+            deploy.is_deprecation_compliant(&deprecated_items, EraId::new(4))
+        );
+        assert_eq!(
+            Err(DeployConfigurationFailure::DeprecatedDeployItem {
+                kind: DeployItemKind::Transfer,
+                activation_era,
+            }),
+            deploy.is_deprecation_compliant(&deprecated_items, activation_era)
+        );
+    }
+
+    #[test]
+    fn is_valid_with_chain_bound_approvals() {
+        let mut rng = crate::new_rng();
+        let secret_key = SecretKey::random(&mut rng);
+        let mut deploy = create_deploy(&mut rng, DeployConfig::default().max_ttl, 0, "net-1");
+        deploy.approvals.clear();
+        deploy.sign_for_chain(CHAIN_ID, &secret_key);
+
+        deploy.clone().is_valid(CHAIN_ID).expect("should be valid");
+        assert!(matches!(
+            deploy.is_valid(CHAIN_ID + 1),
+            Err(DeployConfigurationFailure::InvalidApproval { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn not_acceptable_due_to_unbound_approval() {
+        let mut rng = crate::new_rng();
+        let secret_key = SecretKey::random(&mut rng);
+        let legacy_deploy = create_deploy(&mut rng, DeployConfig::default().max_ttl, 0, "net-1");
+        let mut deploy = legacy_deploy.clone();
+        deploy.approvals.clear();
+        deploy.sign_for_chain(CHAIN_ID, &secret_key);
+        let activation_era = EraId::new(5);
+
+        for deploy in [&legacy_deploy, &deploy] {
+            assert_eq!(
+                Ok(()),
+                deploy.is_approval_binding_compliant(CHAIN_ID, None, activation_era)
+            );
+            assert_eq!(
+                Ok(()),
+                deploy.is_approval_binding_compliant(CHAIN_ID, Some(activation_era), EraId::new(4))
+            );
+        }
+
+        assert_eq!(
+            Ok(()),
+            deploy.is_approval_binding_compliant(CHAIN_ID, Some(activation_era), activation_era)
+        );
+        let expected_error = Err(DeployConfigurationFailure::UnboundApproval {
+            index: 0,
+            activation_era,
+        });
+        assert_eq!(
+            expected_error,
+            legacy_deploy.is_approval_binding_compliant(
+                CHAIN_ID,
+                Some(activation_era),
+                activation_era
+            )
+        );
+        // Approvals bound to another chain are no better.
+        assert_eq!(
+            expected_error,
+            deploy.is_approval_binding_compliant(
+                CHAIN_ID + 1,
+                Some(activation_era),
+                activation_era
+            )
+        );
+    }
 }
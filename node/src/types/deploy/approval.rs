@@ -4,6 +4,7 @@ use datasize::DataSize;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use casper_hashing::Digest;
 #[cfg(any(feature = "testing", test))]
 use casper_types::testing::TestRng;
 use casper_types::{
@@ -13,6 +14,10 @@ use casper_types::{
 
 use super::DeployHash;
 
+/// The prefix of the message signed by approvals bound to a chain, keeping it apart from the bare
+/// deploy hash signed by legacy approvals.
+const CHAIN_BOUND_APPROVAL_PREFIX: &[u8] = b"casper-deploy-approval";
+
 /// A struct containing a signature of a deploy hash and the public key of the signer.
 #[derive(
     Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug, JsonSchema,
@@ -25,12 +30,47 @@ pub struct Approval {
 
 impl Approval {
     /// Creates an approval for the given deploy hash using the given secret key.
+    ///
+    /// The approval signs the bare deploy hash, so it is only accepted until the era from which
+    /// approvals have to be bound to the chain.
     pub fn create(hash: &DeployHash, secret_key: &SecretKey) -> Self {
         let signer = PublicKey::from(secret_key);
         let signature = crypto::sign(hash, secret_key, &signer);
         Self { signer, signature }
     }
 
+    /// Creates an approval for the given deploy hash on the chain with the given id using the given
+    /// secret key.
+    pub fn create_for_chain(hash: &DeployHash, chain_id: u32, secret_key: &SecretKey) -> Self {
+        let signer = PublicKey::from(secret_key);
+        let signature = crypto::sign(chain_bound_digest(hash, chain_id), secret_key, &signer);
+        Self { signer, signature }
+    }
+
+    /// Verifies the approval as a signature of the given deploy hash, either bare or bound to the
+    /// chain with the given id.
+    pub(crate) fn verify(&self, hash: &DeployHash, chain_id: u32) -> Result<(), crypto::Error> {
+        crypto::verify(hash, &self.signature, &self.signer).or_else(|error| {
+            crypto::verify(
+                chain_bound_digest(hash, chain_id),
+                &self.signature,
+                &self.signer,
+            )
+            .map_err(|_| error)
+        })
+    }
+
+    /// Returns `true` if the approval is a valid signature of the given deploy hash bound to the
+    /// chain with the given id.
+    pub(crate) fn is_bound_to_chain(&self, hash: &DeployHash, chain_id: u32) -> bool {
+        crypto::verify(
+            chain_bound_digest(hash, chain_id),
+            &self.signature,
+            &self.signer,
+        )
+        .is_ok()
+    }
+
     /// Returns the public key of the approval's signer.
     pub fn signer(&self) -> &PublicKey {
         &self.signer
@@ -53,6 +93,15 @@ impl Approval {
     }
 }
 
+/// Returns the digest signed by approvals of the given deploy hash which are bound to the chain
+/// with the given id.
+fn chain_bound_digest(hash: &DeployHash, chain_id: u32) -> Digest {
+    let mut message = CHAIN_BOUND_APPROVAL_PREFIX.to_vec();
+    message.extend(chain_id.to_le_bytes());
+    message.extend(hash.as_ref());
+    Digest::hash(message)
+}
+
 impl Display for Approval {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         write!(formatter, "approval({})", self.signer)
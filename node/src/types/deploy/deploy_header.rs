@@ -143,6 +143,14 @@ impl DeployHeader {
     pub fn expires(&self) -> Timestamp {
         self.timestamp.saturating_add(self.ttl)
     }
+
+    /// Returns the timestamp until which the deploy's hash remains replay-protected, i.e. its
+    /// expiry plus the chainspec's `deploys.replay_protection_window`.
+    ///
+    /// Past this point the deploy's `Key::DeployInfo` record may be pruned from global state.
+    pub fn replay_protection_expiry(&self, replay_protection_window: TimeDiff) -> Timestamp {
+        self.expires().saturating_add(replay_protection_window)
+    }
 }
 
 impl ToBytes for DeployHeader {
@@ -0,0 +1,56 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{ExecutionResult, U512};
+
+use crate::types::BlockHash;
+
+/// The lifecycle status of a deploy, as known to this node.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum DeployStatus {
+    /// The deploy is not stored on this node.
+    Unknown,
+    /// The deploy is stored and has not yet been included in a block, nor has it expired.
+    Pending,
+    /// The deploy expired before being included in a block.
+    Expired,
+    /// The deploy has been included in a block, but its execution results are not yet known on
+    /// this node, or the block does not yet have sufficient finality signatures.
+    Included {
+        /// The hash of the block in which the deploy was included.
+        block_hash: BlockHash,
+        /// The height of the block in which the deploy was included.
+        block_height: u64,
+    },
+    /// The deploy has been executed in a block with sufficient finality signatures.
+    Executed {
+        /// The hash of the block in which the deploy was executed.
+        block_hash: BlockHash,
+        /// The height of the block in which the deploy was executed.
+        block_height: u64,
+        /// Whether the execution succeeded.
+        success: bool,
+        /// The cost of executing the deploy.
+        cost: U512,
+    },
+}
+
+impl DeployStatus {
+    pub(crate) fn executed(
+        block_hash: BlockHash,
+        block_height: u64,
+        execution_result: &ExecutionResult,
+    ) -> Self {
+        let (success, cost) = match execution_result {
+            ExecutionResult::Failure { cost, .. } => (false, *cost),
+            ExecutionResult::Success { cost, .. } => (true, *cost),
+        };
+        DeployStatus::Executed {
+            block_hash,
+            block_height,
+            success,
+            cost,
+        }
+    }
+}
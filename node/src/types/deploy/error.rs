@@ -4,7 +4,9 @@ use datasize::DataSize;
 use serde::Serialize;
 use thiserror::Error;
 
-use casper_types::{TimeDiff, Timestamp, U512};
+use casper_types::{EraId, TimeDiff, Timestamp, U512};
+
+use crate::types::chainspec::DeployItemKind;
 
 /// A representation of the way in which a deploy failed validation checks.
 #[derive(Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Error, Serialize)]
@@ -135,6 +137,30 @@ pub enum DeployConfigurationFailure {
         /// The chainspec limit for max_associated_keys.
         max_associated_keys: u32,
     },
+
+    /// The deploy uses a form of deploy item which is deprecated.
+    #[error(
+        "{kind} deploy items are not accepted from era {activation_era} on; {}",
+        .kind.replacement_advice()
+    )]
+    DeprecatedDeployItem {
+        /// The deprecated form of deploy item used.
+        kind: DeployItemKind,
+        /// The era from which the form of deploy item is deprecated.
+        activation_era: EraId,
+    },
+
+    /// An approval signs the bare deploy hash while approvals have to be bound to the chain.
+    #[error(
+        "the approval at index {index} is not bound to the chain, as required from era \
+        {activation_era} on"
+    )]
+    UnboundApproval {
+        /// The index of the approval at fault.
+        index: usize,
+        /// The era from which approvals have to be bound to the chain.
+        activation_era: EraId,
+    },
 }
 
 /// Error returned when a Deploy is too large.
@@ -11,5 +11,8 @@ pub(crate) struct Footprint {
     pub(crate) header: DeployHeader,
     pub(crate) gas_estimate: Gas,
     pub(crate) size_estimate: usize,
+    /// Whether the deploy goes into the transfer lane of a block rather than the deploy lane.
     pub(crate) is_transfer: bool,
+    /// The number of approvals the deploy carries, counted against the block's approval limit.
+    pub(crate) approvals_count: usize,
 }
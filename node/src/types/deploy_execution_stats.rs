@@ -0,0 +1,35 @@
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_execution_engine::core::engine_state::ExecutionStats;
+use casper_types::TimeDiff;
+
+/// How long executing a deploy took on this node, how much Wasm memory it used and how large its
+/// effects are.
+///
+/// The duration and memory usage differ between nodes, so these measurements are not part of the
+/// deploy's execution result and not covered by any checksum.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeployExecutionStats {
+    /// The wall-clock time taken to execute the deploy.
+    pub duration: TimeDiff,
+    /// The size in bytes of the largest Wasm memory used by the deploy's payment, session or any
+    /// stored contract they called.
+    pub memory_high_water_mark: u64,
+    /// The size in bytes of the deploy's effects once serialized.  The share of the session code is
+    /// limited by the chainspec's `core.max_effects_size_bytes`.
+    #[serde(default)]
+    pub effects_size: u64,
+}
+
+impl From<ExecutionStats> for DeployExecutionStats {
+    fn from(execution_stats: ExecutionStats) -> Self {
+        DeployExecutionStats {
+            duration: TimeDiff::from(execution_stats.duration()),
+            memory_high_water_mark: execution_stats.memory_high_water_mark(),
+            effects_size: execution_stats.effects_size(),
+        }
+    }
+}
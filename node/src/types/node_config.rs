@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
@@ -49,6 +51,10 @@ pub struct NodeConfig {
     /// Hash used as a trust anchor when joining, if any.
     pub trusted_hash: Option<BlockHash>,
 
+    /// Path to a state snapshot of the block of `trusted_hash`, whose global state is imported at
+    /// startup instead of being synchronized from peers.
+    pub state_snapshot_path: Option<PathBuf>,
+
     /// Which historical sync option?
     ///  Genesis: sync all the way back to genesis
     ///  Ttl: sync the necessary number of historical blocks to satisfy TTL requirement.
@@ -83,6 +89,7 @@ impl Default for NodeConfig {
     fn default() -> NodeConfig {
         NodeConfig {
             trusted_hash: None,
+            state_snapshot_path: None,
             sync_handling: SyncHandling::default(),
             idle_tolerance: DEFAULT_IDLE_TOLERANCE.parse().unwrap(),
             max_attempts: DEFAULT_MAX_ATTEMPTS,
@@ -11,6 +11,7 @@ use once_cell::sync::Lazy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use casper_execution_engine::shared::module_cache::ModuleCacheStats;
 use casper_hashing::Digest;
 use casper_types::{EraId, ProtocolVersion, PublicKey, TimeDiff, Timestamp};
 
@@ -55,6 +56,13 @@ static GET_STATUS_RESULT: Lazy<GetStatusResult> = Lazy::new(|| {
         available_block_range: AvailableBlockRange::RANGE_0_0,
         block_sync: BlockSynchronizerStatus::doc_example().clone(),
         starting_state_root_hash: Digest::default(),
+        module_cache: ModuleCacheStats {
+            hits: 120,
+            misses: 5,
+            entries: 5,
+            size: 1_187_840,
+            capacity: 33_554_432,
+        },
     };
     GetStatusResult::new(status_feed, DOCS_EXAMPLE_PROTOCOL_VERSION)
 });
@@ -109,6 +117,8 @@ pub struct StatusFeed {
     pub block_sync: BlockSynchronizerStatus,
     /// The state root hash of the lowest block in the available block range.
     pub starting_state_root_hash: Digest,
+    /// The statistics of the cache of preprocessed Wasm modules.
+    pub module_cache: ModuleCacheStats,
 }
 
 impl StatusFeed {
@@ -124,6 +134,7 @@ impl StatusFeed {
         available_block_range: AvailableBlockRange,
         block_sync: BlockSynchronizerStatus,
         starting_state_root_hash: Digest,
+        module_cache: ModuleCacheStats,
     ) -> Self {
         let (our_public_signing_key, round_length) = match consensus_status {
             Some((public_key, round_length)) => (Some(public_key), round_length),
@@ -142,6 +153,7 @@ impl StatusFeed {
             available_block_range,
             block_sync,
             starting_state_root_hash,
+            module_cache,
         }
     }
 }
@@ -204,6 +216,8 @@ pub struct GetStatusResult {
     pub available_block_range: AvailableBlockRange,
     /// The status of the block synchronizer builders.
     pub block_sync: BlockSynchronizerStatus,
+    /// The statistics of the cache of preprocessed Wasm modules.
+    pub module_cache: ModuleCacheStats,
 }
 
 impl GetStatusResult {
@@ -223,6 +237,7 @@ impl GetStatusResult {
             last_progress: status_feed.last_progress,
             available_block_range: status_feed.available_block_range,
             block_sync: status_feed.block_sync,
+            module_cache: status_feed.module_cache,
             #[cfg(not(test))]
             build_version: crate::VERSION_STRING.clone(),
 
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_execution_engine::shared::{execution_journal::ExecutionJournal, transform::Transform};
+use casper_types::{
+    account::AccountHash,
+    system::auction::{Bid, EraInfo, SeigniorageRecipientsSnapshot, UnbondingPurse},
+    CLTyped, EraId, Key, StoredValue,
+};
+
+/// A categorized effect of a step on the auction, derived from the transforms it committed.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize, JsonSchema)]
+pub enum StepEffect {
+    /// The rewards distributed by the step were recorded.
+    RewardsRecorded(EraInfo),
+    /// A validator's bid was written, e.g. as rewards were added to its stake or it was evicted.
+    BidUpdated(Box<Bid>),
+    /// The unbonding purses of an account were processed.
+    UnbondsProcessed {
+        /// The account which requested the unbonds.
+        account_hash: AccountHash,
+        /// The unbonding purses still waiting for their unbonding delay to elapse.
+        remaining: Vec<UnbondingPurse>,
+    },
+    /// The auction rotated its snapshot of seigniorage recipients.
+    SnapshotRotated {
+        /// The eras covered by the new snapshot.
+        era_ids: Vec<EraId>,
+    },
+}
+
+impl StepEffect {
+    /// Categorizes the writes recorded in the journal of a step.
+    ///
+    /// Only the last write to each key is considered, and writes which don't fall into any
+    /// category are left out.
+    pub(crate) fn categorize(execution_journal: &ExecutionJournal) -> Vec<StepEffect> {
+        let mut writes = BTreeMap::new();
+        for (key, transform) in execution_journal.iter() {
+            if let Transform::Write(stored_value) = transform {
+                writes.insert(*key, stored_value);
+            }
+        }
+
+        writes
+            .into_iter()
+            .filter_map(|(key, stored_value)| match (key, stored_value) {
+                (Key::EraSummary, StoredValue::EraInfo(era_info)) => {
+                    Some(StepEffect::RewardsRecorded(era_info.clone()))
+                }
+                (Key::Bid(_), StoredValue::Bid(bid)) => Some(StepEffect::BidUpdated(bid.clone())),
+                (Key::Unbond(account_hash), StoredValue::Unbonding(unbonding_purses)) => {
+                    Some(StepEffect::UnbondsProcessed {
+                        account_hash,
+                        remaining: unbonding_purses.clone(),
+                    })
+                }
+                (Key::URef(_), StoredValue::CLValue(cl_value))
+                    if *cl_value.cl_type() == SeigniorageRecipientsSnapshot::cl_type() =>
+                {
+                    let snapshot: SeigniorageRecipientsSnapshot = cl_value.clone().into_t().ok()?;
+                    Some(StepEffect::SnapshotRotated {
+                        era_ids: snapshot.into_keys().collect(),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use casper_types::{
+        system::auction::{SeigniorageRecipients, DELEGATION_RATE_DENOMINATOR},
+        testing::TestRng,
+        AccessRights, CLValue, PublicKey, URef, U512,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_categorize_step_writes() {
+        let mut rng = TestRng::new();
+
+        let validator = PublicKey::random(&mut rng);
+        let bid = Bid::unlocked(
+            validator.clone(),
+            URef::new(rng.gen(), AccessRights::READ_ADD_WRITE),
+            U512::from(1_000),
+            DELEGATION_RATE_DENOMINATOR,
+        );
+        let mut updated_bid = bid.clone();
+        updated_bid.increase_stake(U512::from(10)).unwrap();
+
+        let snapshot: SeigniorageRecipientsSnapshot = (2..=4)
+            .map(|era| (EraId::new(era), SeigniorageRecipients::new()))
+            .collect();
+        let snapshot_uref = URef::new(rng.gen(), AccessRights::READ_ADD_WRITE);
+        let account_hash = validator.to_account_hash();
+
+        let execution_journal = ExecutionJournal::new(vec![
+            (
+                Key::Bid(account_hash),
+                Transform::Write(StoredValue::Bid(Box::new(bid))),
+            ),
+            (Key::EraSummary, Transform::Identity),
+            (
+                Key::EraSummary,
+                Transform::Write(StoredValue::EraInfo(EraInfo::new())),
+            ),
+            (
+                Key::Bid(account_hash),
+                Transform::Write(StoredValue::Bid(Box::new(updated_bid.clone()))),
+            ),
+            (
+                Key::Unbond(account_hash),
+                Transform::Write(StoredValue::Unbonding(vec![])),
+            ),
+            (
+                Key::URef(snapshot_uref),
+                Transform::Write(StoredValue::CLValue(CLValue::from_t(snapshot).unwrap())),
+            ),
+            (
+                Key::URef(URef::new(rng.gen(), AccessRights::READ_ADD_WRITE)),
+                Transform::Write(StoredValue::CLValue(CLValue::from_t(1_u64).unwrap())),
+            ),
+        ]);
+
+        let step_effects = StepEffect::categorize(&execution_journal);
+        assert_eq!(step_effects.len(), 4);
+        assert!(step_effects.contains(&StepEffect::RewardsRecorded(EraInfo::new())));
+        assert!(step_effects.contains(&StepEffect::BidUpdated(Box::new(updated_bid))));
+        assert!(step_effects.contains(&StepEffect::UnbondsProcessed {
+            account_hash,
+            remaining: vec![],
+        }));
+        assert!(step_effects.contains(&StepEffect::SnapshotRotated {
+            era_ids: vec![EraId::new(2), EraId::new(3), EraId::new(4)],
+        }));
+    }
+}
@@ -1151,6 +1151,7 @@ mod tests {
         let global_state_update = GlobalStateUpdate {
             validators: Some(new_validators),
             entries: Default::default(),
+            allow_dangerous_overrides: false,
         };
 
         let unbonding_delay = 7;
@@ -1943,6 +1944,7 @@ mod tests {
             global_state_update: Some(GlobalStateUpdate {
                 validators: Some(BTreeMap::new()),
                 entries: BTreeMap::new(),
+                allow_dangerous_overrides: false,
             }),
             hard_reset: false,
             activation_point: ActivationPoint::EraId(rng.gen()),
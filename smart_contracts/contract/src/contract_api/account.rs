@@ -6,13 +6,17 @@ use core::convert::TryFrom;
 use casper_types::{
     account::{
         AccountHash, ActionType, AddKeyFailure, RemoveKeyFailure, SetThresholdFailure,
-        UpdateKeyFailure, Weight,
+        UpdateKeyFailure, Weight, AUTHORIZATION_CONTRACT_KEY,
     },
-    bytesrepr, URef, UREF_SERIALIZED_LENGTH,
+    bytesrepr, ContractHash, Key, URef, UREF_SERIALIZED_LENGTH,
 };
 
 use super::to_ptr;
-use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+use crate::{
+    contract_api::{self, runtime},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
 
 /// Retrieves the ID of the account's main purse.
 pub fn get_main_purse() -> URef {
@@ -93,3 +97,18 @@ pub fn update_associated_key(
         Err(UpdateKeyFailure::try_from(result).unwrap_or_revert())
     }
 }
+
+/// Registers the contract under `contract_hash` as the account's authorization contract.
+///
+/// Every later deploy of the account has to be approved by the contract's
+/// [`METHOD_AUTHORIZE`](casper_types::account::METHOD_AUTHORIZE) entry point.  Must be called
+/// from session code of the account.
+pub fn set_authorization_contract(contract_hash: ContractHash) {
+    runtime::put_key(AUTHORIZATION_CONTRACT_KEY, Key::from(contract_hash));
+}
+
+/// Unregisters the account's authorization contract.  Must be called from session code of the
+/// account.
+pub fn remove_authorization_contract() {
+    runtime::remove_key(AUTHORIZATION_CONTRACT_KEY);
+}
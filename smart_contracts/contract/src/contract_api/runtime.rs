@@ -6,11 +6,12 @@ use core::mem::MaybeUninit;
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
     contracts::{ContractVersion, NamedKeys},
     system::CallStackElement,
     ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    RuntimeArgs, ScheduledCallbackAddr, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH,
+    KEY_SCHEDULED_CALLBACK_LENGTH, PHASE_SERIALIZED_LENGTH,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -226,6 +227,25 @@ pub fn get_phase() -> Phase {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns the number of further contract calls which can be made before the runtime call stack
+/// overflows.
+///
+/// Calls to stored contracts are bound both by the maximum height of the call stack and by the
+/// maximum number of stored contract frames on it, so this returns the lower of the two remaining
+/// depths.  Code which recurses into contracts can use it to stop gracefully rather than fail.
+pub fn get_remaining_call_depth() -> u32 {
+    let dest_non_null_ptr = contract_api::alloc_bytes(U32_SERIALIZED_LENGTH);
+    unsafe { ext_ffi::casper_get_remaining_call_depth(dest_non_null_ptr.as_ptr()) };
+    let bytes = unsafe {
+        Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            U32_SERIALIZED_LENGTH,
+            U32_SERIALIZED_LENGTH,
+        )
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
 /// Returns the requested named [`Key`] from the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -359,6 +379,65 @@ pub fn random_bytes() -> [u8; RANDOM_BYTES_COUNT] {
     ret
 }
 
+/// Returns a new 32-byte address, unique within the executing deploy.
+///
+/// The address is derived from the deploy hash, `tag` and the number of unique addresses created
+/// so far in the deploy's current phase.  Unlike addresses derived from the block time or the
+/// caller, addresses of deploys executed in the same block never collide.
+pub fn new_unique_address(tag: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let mut ret = [0; BLAKE2B_DIGEST_LENGTH];
+    let result =
+        unsafe { ext_ffi::casper_new_unique_address(tag.as_ptr(), tag.len(), ret.as_mut_ptr()) };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Schedules the entry point `entry_point_name` of the calling contract to be called with
+/// `runtime_args` by the step at the start of every `era_interval`th era from now, returning the
+/// address of the scheduled callback.
+///
+/// Each call may spend up to `gas_budget`, which must be available in `purse` when the callback is
+/// due.  The gas used is taken from `purse` at a price of one mote per unit, even if the call
+/// fails.  The callback is made in the context of the account executing the current deploy, but
+/// without any of its keys.
+pub fn schedule_callback(
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+    era_interval: u64,
+    gas_budget: u64,
+    purse: URef,
+) -> ScheduledCallbackAddr {
+    let (entry_point_name_ptr, entry_point_name_size, _bytes1) =
+        contract_api::to_ptr(entry_point_name);
+    let (runtime_args_ptr, runtime_args_size, _bytes2) = contract_api::to_ptr(runtime_args);
+    let (schedule_ptr, schedule_size, _bytes3) = contract_api::to_ptr((era_interval, gas_budget));
+    let (purse_ptr, purse_size, _bytes4) = contract_api::to_ptr(purse);
+    let mut ret = [0; KEY_SCHEDULED_CALLBACK_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_schedule_callback(
+            entry_point_name_ptr,
+            entry_point_name_size,
+            runtime_args_ptr,
+            runtime_args_size,
+            schedule_ptr,
+            schedule_size,
+            purse_ptr,
+            purse_size,
+            ret.as_mut_ptr(),
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Cancels a callback scheduled by the calling contract.
+///
+/// Reverts with [`ApiError::MissingKey`] if there is no such callback.
+pub fn cancel_callback(callback_addr: ScheduledCallbackAddr) {
+    let result = unsafe { ext_ffi::casper_cancel_callback(callback_addr.as_ptr()) };
+    api_error::result_from(result).unwrap_or_revert();
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
@@ -403,6 +482,19 @@ pub fn get_call_stack() -> Vec<CallStackElement> {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Emits an event named `name` carrying `data` on behalf of the currently executing stored
+/// contract.
+///
+/// Events are only recorded if the deploy executes successfully.  Calling this from session code
+/// stops execution.
+pub fn emit_event<T: CLTyped + ToBytes>(name: &str, data: T) {
+    let data = CLValue::from_t(data).unwrap_or_revert();
+    let (name_ptr, name_size, _bytes1) = contract_api::to_ptr(name);
+    let (data_ptr, data_size, _bytes2) = contract_api::to_ptr(data);
+    let ret = unsafe { ext_ffi::casper_emit_event(name_ptr, name_size, data_ptr, data_size) };
+    api_error::result_from(ret).unwrap_or_revert()
+}
+
 #[cfg(feature = "test-support")]
 /// Prints a debug message
 pub fn print(text: &str) {
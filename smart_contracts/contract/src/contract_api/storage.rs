@@ -6,9 +6,9 @@ use core::{convert::From, mem::MaybeUninit};
 use casper_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    contracts::{ContractVersion, EntryPoints, NamedKeys},
+    contracts::{ContractVersion, EntryPoints, NamedKeys, INSTALL_SEED_LENGTH},
     AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, HashAddr, Key,
-    URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, UREF_SERIALIZED_LENGTH,
+    URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, U128, UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -145,6 +145,84 @@ fn create_contract(
     add_contract_version(contract_package_hash, entry_points, named_keys)
 }
 
+/// Create a new contract stored under a Key::Hash derived from the calling account and
+/// `install_seed`, unless it was already installed.
+///
+/// Submitting the same installer again with the same seed targets the same contract package. If
+/// that package already has an enabled version, no new version is added and the latest one is
+/// returned instead. This requires the access key of the existing package to be present in the
+/// current context, so `uref_name` should be provided for retries to succeed.
+/// if `named_keys` are provided, will apply them
+/// if `hash_name` is provided, puts contract hash in current context's named keys under `hash_name`
+/// if `uref_name` is provided, puts access_uref in current context's named keys under `uref_name`
+pub fn new_contract_with_seed(
+    install_seed: [u8; INSTALL_SEED_LENGTH],
+    entry_points: EntryPoints,
+    named_keys: Option<NamedKeys>,
+    hash_name: Option<String>,
+    uref_name: Option<String>,
+) -> (ContractHash, ContractVersion) {
+    let (contract_package_hash, access_uref, installed) =
+        create_contract_package_at_seed(install_seed, false);
+
+    if let Some(hash_name) = hash_name {
+        runtime::put_key(&hash_name, contract_package_hash.into());
+    };
+
+    if let Some(uref_name) = uref_name {
+        runtime::put_key(&uref_name, access_uref.into());
+    };
+
+    if let Some(installed) = installed {
+        return installed;
+    }
+
+    let named_keys = match named_keys {
+        Some(named_keys) => named_keys,
+        None => NamedKeys::new(),
+    };
+
+    add_contract_version(contract_package_hash, entry_points, named_keys)
+}
+
+/// Create a new (versioned) contract at the Key::Hash derived from the calling account and
+/// `install_seed`, or return the existing one. Along with the package hash and its access URef,
+/// returns the hash and version of its latest enabled contract, if any.
+pub fn create_contract_package_at_seed(
+    install_seed: [u8; INSTALL_SEED_LENGTH],
+    is_locked: bool,
+) -> (
+    ContractPackageHash,
+    URef,
+    Option<(ContractHash, ContractVersion)>,
+) {
+    let (seed_ptr, seed_size, _bytes) = contract_api::to_ptr(install_seed);
+    let mut hash_addr: HashAddr = ContractPackageHash::default().value();
+    let mut access_addr = [0u8; 32];
+    let value_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_create_contract_package_at_seed(
+                seed_ptr,
+                seed_size,
+                hash_addr.as_mut_ptr(),
+                access_addr.as_mut_ptr(),
+                is_locked,
+                output_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let contract_package_hash: ContractPackageHash = hash_addr.into();
+    let access_uref = URef::new(access_addr, AccessRights::READ_ADD_WRITE);
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    let installed = bytesrepr::deserialize(value_bytes).unwrap_or_revert();
+
+    (contract_package_hash, access_uref, installed)
+}
+
 /// Create a new (versioned) contract stored under a Key::Hash. Initially there
 /// are no versions; a version must be added via `add_contract_version` before
 /// the contract can be executed.
@@ -365,6 +443,38 @@ pub fn enable_contract_version(
     api_error::result_from(result)
 }
 
+/// Pause the contract package stored at the given hash. Until it is unpaused, calls to any of its
+/// contracts fail. Note that the access key of the package must be present in the current context.
+pub fn pause_contract_package(contract_package_hash: ContractPackageHash) -> Result<(), ApiError> {
+    set_contract_package_paused(contract_package_hash, true)
+}
+
+/// Unpause the contract package stored at the given hash, allowing its contracts to be called
+/// again.
+pub fn unpause_contract_package(
+    contract_package_hash: ContractPackageHash,
+) -> Result<(), ApiError> {
+    set_contract_package_paused(contract_package_hash, false)
+}
+
+fn set_contract_package_paused(
+    contract_package_hash: ContractPackageHash,
+    paused: bool,
+) -> Result<(), ApiError> {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes) =
+        contract_api::to_ptr(contract_package_hash);
+
+    let result = unsafe {
+        ext_ffi::casper_set_contract_package_paused(
+            contract_package_hash_ptr,
+            contract_package_hash_size,
+            paused,
+        )
+    };
+
+    api_error::result_from(result)
+}
+
 /// Creates new [`URef`] that represents a seed for a dictionary partition of the global state and
 /// puts it under named keys.
 pub fn new_dictionary(dictionary_name: &str) -> Result<URef, ApiError> {
@@ -498,3 +608,244 @@ pub fn named_dictionary_put<V: CLTyped + ToBytes>(
 ) {
     dictionary_put(get_named_uref(dictionary_name), dictionary_item_key, value)
 }
+
+/// Creates new [`URef`] that represents a seed for a sorted index partition of the global state and
+/// puts it under named keys.
+///
+/// A sorted index maps `u128` entry keys to values and can be read in the order of its entry keys,
+/// e.g. to keep the orders of an order book sorted by price.
+pub fn new_sorted_index(sorted_index_name: &str) -> Result<URef, ApiError> {
+    if sorted_index_name.is_empty() || runtime::has_key(sorted_index_name) {
+        return Err(ApiError::InvalidArgument);
+    }
+
+    // A sorted index is seeded by a fresh unit URef, just like a dictionary.
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::casper_new_dictionary(value_size.as_mut_ptr()) };
+        api_error::result_from(ret)?;
+        unsafe { value_size.assume_init() }
+    };
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    let uref: URef = bytesrepr::deserialize(value_bytes).unwrap_or_revert();
+    runtime::put_key(sorted_index_name, Key::from(uref));
+    Ok(uref)
+}
+
+/// Writes `value` under `entry_key` in the sorted index accessed by `sorted_index_seed_uref`,
+/// replacing the value of an existing entry.
+pub fn sorted_index_insert<V: CLTyped + ToBytes>(
+    sorted_index_seed_uref: URef,
+    entry_key: u128,
+    value: V,
+) {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(sorted_index_seed_uref);
+    let (entry_key_ptr, entry_key_size, _bytes2) = contract_api::to_ptr(U128::from(entry_key));
+    let cl_value = CLValue::from_t(value).unwrap_or_revert();
+    let (cl_value_ptr, cl_value_size, _bytes3) = contract_api::to_ptr(cl_value);
+
+    let result = unsafe {
+        let ret = ext_ffi::casper_sorted_index_insert(
+            uref_ptr,
+            uref_size,
+            entry_key_ptr,
+            entry_key_size,
+            cl_value_ptr,
+            cl_value_size,
+        );
+        api_error::result_from(ret)
+    };
+
+    result.unwrap_or_revert()
+}
+
+/// Removes the entry under `entry_key` from the sorted index accessed by `sorted_index_seed_uref`.
+///
+/// Returns `false` if the index has no such entry.
+pub fn sorted_index_remove(sorted_index_seed_uref: URef, entry_key: u128) -> bool {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(sorted_index_seed_uref);
+    let (entry_key_ptr, entry_key_size, _bytes2) = contract_api::to_ptr(U128::from(entry_key));
+
+    let ret = unsafe {
+        ext_ffi::casper_sorted_index_remove(uref_ptr, uref_size, entry_key_ptr, entry_key_size)
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => true,
+        Err(ApiError::ValueNotFound) => false,
+        Err(e) => runtime::revert(e),
+    }
+}
+
+/// Retrieve `value` stored under `entry_key` in the sorted index accessed by
+/// `sorted_index_seed_uref`.
+pub fn sorted_index_get<V: CLTyped + FromBytes>(
+    sorted_index_seed_uref: URef,
+    entry_key: u128,
+) -> Result<Option<V>, bytesrepr::Error> {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(sorted_index_seed_uref);
+    let (entry_key_ptr, entry_key_size, _bytes2) = contract_api::to_ptr(U128::from(entry_key));
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_sorted_index_get(
+                uref_ptr,
+                uref_size,
+                entry_key_ptr,
+                entry_key_size,
+                value_size.as_mut_ptr(),
+            )
+        };
+        match api_error::result_from(ret) {
+            Ok(_) => unsafe { value_size.assume_init() },
+            Err(ApiError::ValueNotFound) => return Ok(None),
+            Err(e) => runtime::revert(e),
+        }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    Ok(Some(bytesrepr::deserialize(value_bytes)?))
+}
+
+/// Returns at most `limit` entry keys of the sorted index accessed by `sorted_index_seed_uref`.
+///
+/// The entry keys are returned in ascending order starting with `start` if it's given, or with the
+/// lowest entry key otherwise.  If `descending` is `true`, they are returned in descending order
+/// starting with `start` or the highest entry key instead.  Each entry visited is charged as a read
+/// from global state.
+pub fn sorted_index_range(
+    sorted_index_seed_uref: URef,
+    start: Option<u128>,
+    limit: u32,
+    descending: bool,
+) -> Vec<u128> {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(sorted_index_seed_uref);
+    let (start_ptr, start_size, _bytes2) = contract_api::to_ptr(start.map(U128::from));
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_sorted_index_range(
+                uref_ptr,
+                uref_size,
+                start_ptr,
+                start_size,
+                limit,
+                descending,
+                value_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { value_size.assume_init() }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    let entry_keys: Vec<U128> = bytesrepr::deserialize(value_bytes).unwrap_or_revert();
+    entry_keys
+        .into_iter()
+        .map(|entry_key| entry_key.as_u128())
+        .collect()
+}
+
+/// Returns the lowest entry key of the sorted index accessed by `sorted_index_seed_uref`, or `None`
+/// if the index is empty.
+pub fn sorted_index_min(sorted_index_seed_uref: URef) -> Option<u128> {
+    sorted_index_range(sorted_index_seed_uref, None, 1, false)
+        .into_iter()
+        .next()
+}
+
+/// Returns the highest entry key of the sorted index accessed by `sorted_index_seed_uref`, or
+/// `None` if the index is empty.
+pub fn sorted_index_max(sorted_index_seed_uref: URef) -> Option<u128> {
+    sorted_index_range(sorted_index_seed_uref, None, 1, true)
+        .into_iter()
+        .next()
+}
+
+/// Creates a new storage namespace and puts its seed [`URef`] under named keys.
+///
+/// Items are put in and read from a namespace like dictionary items, but all items of a namespace
+/// can be removed at once by [`namespace_clear`], at a cost independent of their number.
+pub fn new_namespace(namespace_name: &str) -> Result<URef, ApiError> {
+    if namespace_name.is_empty() || runtime::has_key(namespace_name) {
+        return Err(ApiError::InvalidArgument);
+    }
+
+    // The seed holds the current generation of the namespace, which is bumped by clearing it.
+    let uref = new_uref(0u64);
+    runtime::put_key(namespace_name, Key::from(uref));
+    Ok(uref)
+}
+
+/// Writes `value` under `item_key` in the namespace accessed by `namespace_seed_uref`.
+pub fn namespace_put<V: CLTyped + ToBytes>(namespace_seed_uref: URef, item_key: &str, value: V) {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(namespace_seed_uref);
+    let (item_key_ptr, item_key_size) = contract_api::dictionary_item_key_to_ptr(item_key);
+
+    if item_key_size > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+        revert(ApiError::DictionaryItemKeyExceedsLength)
+    }
+
+    let cl_value = CLValue::from_t(value).unwrap_or_revert();
+    let (cl_value_ptr, cl_value_size, _bytes) = contract_api::to_ptr(cl_value);
+
+    let result = unsafe {
+        let ret = ext_ffi::casper_namespace_put(
+            uref_ptr,
+            uref_size,
+            item_key_ptr,
+            item_key_size,
+            cl_value_ptr,
+            cl_value_size,
+        );
+        api_error::result_from(ret)
+    };
+
+    result.unwrap_or_revert()
+}
+
+/// Retrieve `value` stored under `item_key` in the namespace accessed by `namespace_seed_uref`.
+///
+/// Returns `None` for items put before the namespace was last cleared.
+pub fn namespace_get<V: CLTyped + FromBytes>(
+    namespace_seed_uref: URef,
+    item_key: &str,
+) -> Result<Option<V>, bytesrepr::Error> {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(namespace_seed_uref);
+    let (item_key_ptr, item_key_size) = contract_api::dictionary_item_key_to_ptr(item_key);
+
+    if item_key_size > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+        revert(ApiError::DictionaryItemKeyExceedsLength)
+    }
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_namespace_get(
+                uref_ptr,
+                uref_size,
+                item_key_ptr,
+                item_key_size,
+                value_size.as_mut_ptr(),
+            )
+        };
+        match api_error::result_from(ret) {
+            Ok(_) => unsafe { value_size.assume_init() },
+            Err(ApiError::ValueNotFound) => return Ok(None),
+            Err(e) => runtime::revert(e),
+        }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    Ok(Some(bytesrepr::deserialize(value_bytes)?))
+}
+
+/// Removes all items from the namespace accessed by `namespace_seed_uref`.
+///
+/// The items become unreadable immediately, while their records are pruned from global state
+/// later on.
+pub fn namespace_clear(namespace_seed_uref: URef) {
+    let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(namespace_seed_uref);
+    unsafe { ext_ffi::casper_namespace_clear(uref_ptr, uref_size) }
+}
@@ -5,13 +5,14 @@ use core::mem::MaybeUninit;
 
 use casper_types::{
     account::AccountHash,
-    api_error, bytesrepr,
+    api_error,
+    bytesrepr::{self, FromBytes},
     system::{
         auction::{self, EraInfo},
         SystemContractType,
     },
-    ApiError, ContractHash, EraId, HashAddr, PublicKey, TransferResult, TransferredTo, URef, U512,
-    UREF_SERIALIZED_LENGTH,
+    ApiError, ContractHash, EraId, HashAddr, PublicKey, TransferAddr, TransferResult,
+    TransferredTo, URef, TRANSFER_ADDR_LENGTH, U512, UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -222,6 +223,43 @@ pub fn transfer_from_purse_to_purse(
     api_error::result_from(result)
 }
 
+/// Transfers `amount` of motes from `source` purse to `target` purse, like
+/// [`transfer_from_purse_to_purse`], and returns the address of the [`Transfer`] recording it.
+///
+/// The record holds the given `id`, so it can be used to correlate the transfer with later logic.
+/// Only transfers made during the session phase are recorded, so in other phases the transfer is
+/// made but `None` is returned.
+///
+/// [`Transfer`]: casper_types::Transfer
+pub fn transfer_from_purse_to_purse_with_result(
+    source: URef,
+    target: URef,
+    amount: U512,
+    id: Option<u64>,
+) -> Result<Option<TransferAddr>, ApiError> {
+    let (source_ptr, source_size, _bytes1) = contract_api::to_ptr(source);
+    let (target_ptr, target_size, _bytes2) = contract_api::to_ptr(target);
+    let (amount_ptr, amount_size, _bytes3) = contract_api::to_ptr(amount);
+    let (id_ptr, id_size, _bytes4) = contract_api::to_ptr(id);
+    let mut result_bytes = [0u8; TRANSFER_ADDR_LENGTH + 1];
+    let result = unsafe {
+        ext_ffi::casper_transfer_from_purse_to_purse_with_result(
+            source_ptr,
+            source_size,
+            target_ptr,
+            target_size,
+            amount_ptr,
+            amount_size,
+            id_ptr,
+            id_size,
+            result_bytes.as_mut_ptr(),
+        )
+    };
+    api_error::result_from(result)?;
+    let (maybe_transfer_addr, _remainder) = Option::<TransferAddr>::from_bytes(&result_bytes)?;
+    Ok(maybe_transfer_addr)
+}
+
 /// Records a transfer.  Can only be called from within the mint contract.
 /// Needed to support system contract-based execution.
 #[doc(hidden)]
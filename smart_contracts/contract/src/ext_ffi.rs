@@ -483,6 +483,27 @@ extern "C" {
         access_addr_ptr: *mut u8,
         is_locked: bool,
     );
+    /// Creates new contract package at the hash derived from the caller's account hash and an
+    /// install seed, unless one already exists there. Returns both the
+    /// [`casper_types::ContractPackageHash`] and a [`casper_types::URef`] for further modifying
+    /// access, and writes the latest installed contract version (if any) to the host buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed_ptr` - pointer to the serialized 32-byte install seed.
+    /// * `seed_size` - size of the serialized install seed.
+    /// * `hash_addr_ptr` - pointer where the contract package hash will be written.
+    /// * `access_addr_ptr` - pointer where the access key address will be written.
+    /// * `is_locked` - flag to determine if the contract can be versioned.
+    /// * `output_size` - pointer where the size of the host buffer contents will be written.
+    pub fn casper_create_contract_package_at_seed(
+        seed_ptr: *const u8,
+        seed_size: usize,
+        hash_addr_ptr: *mut u8,
+        access_addr_ptr: *mut u8,
+        is_locked: bool,
+        output_size: *mut usize,
+    ) -> i32;
     /// Creates new named contract user group under a contract package.
     ///
     /// # Arguments
@@ -790,6 +811,75 @@ extern "C" {
     /// * `out_ptr` - pointer to the location where argument bytes will be copied from the host side
     /// * `out_size` - size of output pointer
     pub fn casper_random_bytes(out_ptr: *mut u8, out_size: usize) -> i32;
+    /// Writes a new 32-byte address, unique within the executing deploy, to `out_ptr`.
+    ///
+    /// The address is derived from the deploy hash, the given tag and the number of unique
+    /// addresses created so far, so it is deterministic across executions of the deploy.
+    ///
+    /// # Arguments
+    /// * `tag_ptr` - pointer to the tag bytes
+    /// * `tag_size` - size of the tag (in bytes)
+    /// * `out_ptr` - pointer to the location where the 32 bytes of the address will be written
+    pub fn casper_new_unique_address(tag_ptr: *const u8, tag_size: usize, out_ptr: *mut u8) -> i32;
+    /// Schedules an entry point of the calling contract to be called back by the step, and writes
+    /// the 32-byte address of the scheduled callback to `out_ptr`.
+    ///
+    /// # Arguments
+    /// * `entry_point_name_ptr` - pointer to serialized entry point name
+    /// * `entry_point_name_size` - size of serialized entry point name
+    /// * `runtime_args_ptr` - pointer to serialized runtime arguments
+    /// * `runtime_args_size` - size of serialized runtime arguments
+    /// * `schedule_ptr` - pointer to the serialized era interval and gas budget, as a `(u64, u64)`
+    /// * `schedule_size` - size of the serialized era interval and gas budget
+    /// * `purse_ptr` - pointer to the serialized purse paying for the callback
+    /// * `purse_size` - size of the serialized purse
+    /// * `out_ptr` - pointer to the location where the 32 bytes of the address will be written
+    pub fn casper_schedule_callback(
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        runtime_args_ptr: *const u8,
+        runtime_args_size: usize,
+        schedule_ptr: *const u8,
+        schedule_size: usize,
+        purse_ptr: *const u8,
+        purse_size: usize,
+        out_ptr: *mut u8,
+    ) -> i32;
+    /// Cancels a callback scheduled by the calling contract.
+    ///
+    /// # Arguments
+    /// * `callback_addr_ptr` - pointer to the 32-byte address of the scheduled callback
+    pub fn casper_cancel_callback(callback_addr_ptr: *const u8) -> i32;
+    /// Like [`casper_transfer_from_purse_to_purse`], but also writes the serialized
+    /// `Option<TransferAddr>` of the recorded transfer to `result_ptr`.  The address is `None`
+    /// outside of the session phase, as only session code transfers are recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_ptr` - pointer in wasm memory to bytes representing the source `URef` to transfer
+    ///   from
+    /// * `source_size` - size of the source `URef` (in bytes)
+    /// * `target_ptr` - pointer in wasm memory to bytes representing the target `URef` to transfer
+    ///   to
+    /// * `target_size` - size of the target (in bytes)
+    /// * `amount_ptr` - pointer in wasm memory to bytes representing the amount to transfer to the
+    ///   target account
+    /// * `amount_size` - size of the amount (in bytes)
+    /// * `id_ptr` - pointer in wasm memory to bytes representing the user-defined transaction id
+    /// * `id_size` - size of the id (in bytes)
+    /// * `result_ptr` - pointer in wasm memory to a buffer of at least 33 bytes, receiving the
+    ///   serialized `Option<TransferAddr>`
+    pub fn casper_transfer_from_purse_to_purse_with_result(
+        source_ptr: *const u8,
+        source_size: usize,
+        target_ptr: *const u8,
+        target_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+        id_ptr: *const u8,
+        id_size: usize,
+        result_ptr: *mut u8,
+    ) -> i32;
     /// Enables contract in a contract package. Returns non-zero standard error for a failure,
     /// otherwise a zero indicates success.
     ///
@@ -805,4 +895,168 @@ extern "C" {
         contract_hash_ptr: *const u8,
         contract_hash_size: usize,
     ) -> i32;
+    /// Pauses or unpauses a contract package. While paused, calls to any of the package's
+    /// contracts are refused. Returns non-zero standard error for a failure, otherwise a zero
+    /// indicates success.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `paused` - flag to determine if the package is paused.
+    pub fn casper_set_contract_package_paused(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        paused: bool,
+    ) -> i32;
+    /// Emits a structured event on behalf of the currently executing stored contract. Returns
+    /// non-zero standard error for a failure, otherwise a zero indicates success.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_ptr` - pointer to serialized event name.
+    /// * `name_size` - size of event name in serialized form.
+    /// * `data_ptr` - pointer to serialized `CLValue` attached to the event.
+    /// * `data_size` - size of the event data in serialized form.
+    pub fn casper_emit_event(
+        name_ptr: *const u8,
+        name_size: usize,
+        data_ptr: *const u8,
+        data_size: usize,
+    ) -> i32;
+    /// This function writes the number of further contract calls which can be made from the
+    /// currently executing code before the runtime call stack overflows, as a serialized `u32`, to
+    /// the specified pointer. It is up to the caller to ensure four bytes of memory are allocated
+    /// at `dest_ptr`, otherwise data corruption in the wasm memory could occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer to position in wasm memory to write the result
+    pub fn casper_get_remaining_call_depth(dest_ptr: *mut u8);
+    /// Inserts a value into the sorted index whose seed is the passed URef, under the entry key
+    /// read by de-serializing the bytes in wasm memory from offset `entry_key_ptr` to
+    /// `entry_key_ptr + entry_key_size`.  The value of an existing entry is replaced.  This
+    /// function will cause a `Trap` if the entry key or value fail to de-serialize.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the sorted index
+    /// * `uref_size` - size of the URef (in bytes)
+    /// * `entry_key_ptr` - pointer to bytes representing the serialized `U128` entry key
+    /// * `entry_key_size` - size of the entry key (in bytes)
+    /// * `value_ptr` - pointer to bytes representing the value to insert
+    /// * `value_size` - size of the value (in bytes)
+    pub fn casper_sorted_index_insert(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        entry_key_ptr: *const u8,
+        entry_key_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    ) -> i32;
+    /// Removes the entry under the given entry key from the sorted index whose seed is the passed
+    /// URef.  Returns `ValueNotFound` if the index has no such entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the sorted index
+    /// * `uref_size` - size of the URef (in bytes)
+    /// * `entry_key_ptr` - pointer to bytes representing the serialized `U128` entry key
+    /// * `entry_key_size` - size of the entry key (in bytes)
+    pub fn casper_sorted_index_remove(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        entry_key_ptr: *const u8,
+        entry_key_size: usize,
+    ) -> i32;
+    /// The value under the given entry key in the sorted index whose seed is the passed URef is
+    /// serialized and buffered in the runtime. This result can be obtained via the
+    /// [`casper_read_host_buffer`] function.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the sorted index
+    /// * `uref_size` - size of the URef (in bytes)
+    /// * `entry_key_ptr` - pointer to bytes representing the serialized `U128` entry key
+    /// * `entry_key_size` - size of the entry key (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of bytes of the value
+    pub fn casper_sorted_index_get(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        entry_key_ptr: *const u8,
+        entry_key_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    /// At most `limit` entry keys of the sorted index whose seed is the passed URef are serialized
+    /// as a `Vec<U128>` and buffered in the runtime, in ascending order from the start key onwards,
+    /// or in descending order from it downwards. This result can be obtained via the
+    /// [`casper_read_host_buffer`] function.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the sorted index
+    /// * `uref_size` - size of the URef (in bytes)
+    /// * `start_ptr` - pointer to bytes representing the serialized `Option<U128>` start key, where
+    ///   `None` starts from the lowest or highest entry key
+    /// * `start_size` - size of the start key (in bytes)
+    /// * `limit` - maximum number of entry keys to return
+    /// * `descending` - flag to determine if the entry keys are returned in descending order
+    /// * `output_size` - pointer to a value where host will write size of bytes of the entry keys
+    pub fn casper_sorted_index_range(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        start_ptr: *const u8,
+        start_size: usize,
+        limit: u32,
+        descending: bool,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Puts a value in the current generation of the namespace whose seed is the passed URef,
+    /// under the item key read from wasm memory from offset `key_ptr` to `key_ptr + key_size`.
+    /// This function will cause a `Trap` if the value fails to de-serialize, or if the seed
+    /// doesn't hold a `u64` generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the namespace
+    /// * `uref_size` - size of the URef (in bytes)
+    /// * `key_ptr` - pointer to bytes representing the UTF-8 item key
+    /// * `key_size` - size of the item key (in bytes)
+    /// * `value_ptr` - pointer to bytes representing the value to write
+    /// * `value_size` - size of the value (in bytes)
+    pub fn casper_namespace_put(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    ) -> i32;
+    /// The value under the given item key in the current generation of the namespace whose seed
+    /// is the passed URef is serialized and buffered in the runtime. This result can be obtained
+    /// via the [`casper_read_host_buffer`] function.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the namespace
+    /// * `uref_size` - size of the URef (in bytes)
+    /// * `key_ptr` - pointer to bytes representing the UTF-8 item key
+    /// * `key_size` - size of the item key (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of bytes of the value
+    pub fn casper_namespace_get(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Starts a new generation of the namespace whose seed is the passed URef, making all items of
+    /// the current generation unreadable.  Their records are pruned from global state outside of
+    /// execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed URef of the namespace
+    /// * `uref_size` - size of the URef (in bytes)
+    pub fn casper_namespace_clear(uref_ptr: *const u8, uref_size: usize);
 }
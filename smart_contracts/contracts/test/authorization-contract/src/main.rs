@@ -0,0 +1,73 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeSet, string::ToString, vec};
+
+use casper_contract::{
+    contract_api::{account, runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    account::{AccountHash, ARG_APPROVALS, ARG_DEPLOY_HASH, METHOD_AUTHORIZE},
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    ApiError, CLType, CLTyped, CLValue, EntryPointAccess, EntryPointType, Key, Parameter,
+};
+
+const ARG_REQUIRED_APPROVAL: &str = "required_approval";
+const REQUIRED_APPROVAL_KEY: &str = "required_approval";
+const HASH_KEY_NAME: &str = "authorization_contract_hash";
+
+/// Approves deploys which carry an approval of the key the contract was installed with, e.g. the
+/// key of a second factor.
+#[no_mangle]
+pub extern "C" fn authorize() {
+    let _deploy_hash: [u8; 32] = runtime::get_named_arg(ARG_DEPLOY_HASH);
+    let approvals: BTreeSet<AccountHash> = runtime::get_named_arg(ARG_APPROVALS);
+
+    let required_approval_uref = runtime::get_key(REQUIRED_APPROVAL_KEY)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert_with(ApiError::MissingKey);
+    let required_approval: AccountHash = storage::read(required_approval_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(ApiError::ValueNotFound);
+
+    let authorized = approvals.contains(&required_approval);
+    runtime::ret(CLValue::from_t(authorized).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let required_approval: AccountHash = runtime::get_named_arg(ARG_REQUIRED_APPROVAL);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            METHOD_AUTHORIZE,
+            vec![
+                Parameter::new(ARG_DEPLOY_HASH, <[u8; 32]>::cl_type()),
+                Parameter::new(ARG_APPROVALS, BTreeSet::<AccountHash>::cl_type()),
+            ],
+            CLType::Bool,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let named_keys = {
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            REQUIRED_APPROVAL_KEY.to_string(),
+            storage::new_uref(required_approval).into(),
+        );
+        named_keys
+    };
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+
+    account::set_authorization_contract(contract_hash);
+}
@@ -0,0 +1,73 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, Parameters},
+    ApiError, CLType, EntryPointAccess, EntryPointType, Parameter,
+};
+
+const METHOD_EMIT: &str = "emit";
+const METHOD_EMIT_AND_REVERT: &str = "emit_and_revert";
+const METHOD_EMIT_FROM_SESSION: &str = "emit_from_session";
+const ARG_NAME: &str = "name";
+const ARG_VALUE: &str = "value";
+const HASH_KEY_NAME: &str = "emit_event_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "emit_event_package_hash";
+
+fn emit_from_args() {
+    let name: String = runtime::get_named_arg(ARG_NAME);
+    let value: u64 = runtime::get_named_arg(ARG_VALUE);
+    runtime::emit_event(&name, value);
+}
+
+#[no_mangle]
+pub extern "C" fn emit() {
+    emit_from_args();
+}
+
+#[no_mangle]
+pub extern "C" fn emit_and_revert() {
+    emit_from_args();
+    runtime::revert(ApiError::User(0));
+}
+
+#[no_mangle]
+pub extern "C" fn emit_from_session() {
+    emit_from_args();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let params = || {
+            vec![
+                Parameter::new(ARG_NAME, CLType::String),
+                Parameter::new(ARG_VALUE, CLType::U64),
+            ]
+        };
+        for (name, entry_point_type) in [
+            (METHOD_EMIT, EntryPointType::Contract),
+            (METHOD_EMIT_AND_REVERT, EntryPointType::Contract),
+            (METHOD_EMIT_FROM_SESSION, EntryPointType::Session),
+        ] {
+            entry_points.add_entry_point(EntryPoint::new(
+                name,
+                params(),
+                CLType::Unit,
+                EntryPointAccess::Public,
+                entry_point_type,
+            ));
+        }
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, None, Some(PACKAGE_HASH_KEY_NAME.into()), None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
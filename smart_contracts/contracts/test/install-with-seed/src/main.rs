@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, Parameters, INSTALL_SEED_LENGTH},
+    CLType, EntryPointAccess, EntryPointType,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const ARG_INSTALL_SEED: &str = "install_seed";
+const HASH_KEY_NAME: &str = "seeded_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "seeded_package_hash";
+const ACCESS_KEY_NAME: &str = "seeded_access";
+const CONTRACT_VERSION: &str = "seeded_contract_version";
+
+#[no_mangle]
+pub extern "C" fn delegate() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let install_seed: [u8; INSTALL_SEED_LENGTH] = runtime::get_named_arg(ARG_INSTALL_SEED);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_FUNCTION_NAME,
+            Parameters::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let (contract_hash, contract_version) = storage::new_contract_with_seed(
+        install_seed,
+        entry_points,
+        None,
+        Some(PACKAGE_HASH_KEY_NAME.into()),
+        Some(ACCESS_KEY_NAME.into()),
+    );
+
+    runtime::put_key(CONTRACT_VERSION, storage::new_uref(contract_version).into());
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
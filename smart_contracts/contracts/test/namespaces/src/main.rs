@@ -0,0 +1,45 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::ApiError;
+
+const NAMESPACE_NAME: &str = "namespace";
+const ARG_PUTS: &str = "puts";
+const ARG_CLEAR: &str = "clear";
+const ARG_GETS: &str = "gets";
+const VALUES_KEY_NAME: &str = "values";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let puts: Vec<(String, u64)> = runtime::get_named_arg(ARG_PUTS);
+    let clear: bool = runtime::get_named_arg(ARG_CLEAR);
+    let gets: Vec<String> = runtime::get_named_arg(ARG_GETS);
+
+    let namespace = match runtime::get_key(NAMESPACE_NAME) {
+        Some(key) => key
+            .into_uref()
+            .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant),
+        None => storage::new_namespace(NAMESPACE_NAME).unwrap_or_revert(),
+    };
+
+    for (item_key, value) in puts {
+        storage::namespace_put(namespace, &item_key, value);
+    }
+    if clear {
+        storage::namespace_clear(namespace);
+    }
+    let values: Vec<Option<u64>> = gets
+        .iter()
+        .map(|item_key| storage::namespace_get(namespace, item_key).unwrap_or_revert())
+        .collect();
+
+    runtime::put_key(VALUES_KEY_NAME, storage::new_uref(values).into());
+}
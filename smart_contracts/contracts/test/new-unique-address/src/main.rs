@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::contract_api::{runtime, storage};
+
+const ARG_TAGS: &str = "tags";
+const ADDRESSES_KEY_NAME: &str = "addresses";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let tags: Vec<String> = runtime::get_named_arg(ARG_TAGS);
+
+    let addresses: Vec<[u8; 32]> = tags
+        .iter()
+        .map(|tag| runtime::new_unique_address(tag.as_bytes()))
+        .collect();
+
+    runtime::put_key(ADDRESSES_KEY_NAME, storage::new_uref(addresses).into());
+}
@@ -0,0 +1,72 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints},
+    runtime_args, ApiError, CLType, EntryPointAccess, EntryPointType, Parameter, RuntimeArgs,
+};
+
+const METHOD_RECURSE: &str = "recurse";
+const METHOD_RECURSE_REENTRANT: &str = "recurse_reentrant";
+const ARG_DEPTH: &str = "depth";
+const HASH_KEY_NAME: &str = "non_reentrant_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "non_reentrant_package_hash";
+
+/// Calls `entry_point_name` of the currently executing contract until `depth` reaches zero.
+fn recurse_into(entry_point_name: &str) {
+    let depth: u32 = runtime::get_named_arg(ARG_DEPTH);
+    if depth == 0 {
+        return;
+    }
+    let contract_hash = runtime::get_call_stack()
+        .last()
+        .and_then(|frame| frame.contract_hash().copied())
+        .unwrap_or_revert_with(ApiError::User(0));
+    runtime::call_contract::<()>(
+        contract_hash,
+        entry_point_name,
+        runtime_args! { ARG_DEPTH => depth - 1 },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn recurse() {
+    recurse_into(METHOD_RECURSE);
+}
+
+#[no_mangle]
+pub extern "C" fn recurse_reentrant() {
+    recurse_into(METHOD_RECURSE_REENTRANT);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        for (name, entry_point_type) in [
+            (METHOD_RECURSE, EntryPointType::NonReentrantContract),
+            (METHOD_RECURSE_REENTRANT, EntryPointType::Contract),
+        ] {
+            entry_points.add_entry_point(EntryPoint::new(
+                name,
+                vec![Parameter::new(ARG_DEPTH, CLType::U32)],
+                CLType::Unit,
+                EntryPointAccess::Public,
+                entry_point_type,
+            ));
+        }
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, None, Some(PACKAGE_HASH_KEY_NAME.into()), None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,133 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use casper_contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    runtime_args, CLType, EntryPointAccess, EntryPointType, Parameter, RuntimeArgs,
+    ScheduledCallbackAddr, URef, U512,
+};
+
+const METHOD_REGISTER: &str = "register";
+const METHOD_CANCEL: &str = "cancel";
+const METHOD_TICK: &str = "tick";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ERA_INTERVAL: &str = "era_interval";
+const ARG_GAS_BUDGET: &str = "gas_budget";
+const ARG_DRAIN: &str = "drain";
+const PURSE_KEY_NAME: &str = "purse";
+const COUNTER_KEY_NAME: &str = "counter";
+const CALLBACK_KEY_NAME: &str = "callback";
+const DRAIN_KEY_NAME: &str = "drain";
+const HASH_KEY_NAME: &str = "scheduled_callback_hash";
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+#[no_mangle]
+pub extern "C" fn register() {
+    let era_interval: u64 = runtime::get_named_arg(ARG_ERA_INTERVAL);
+    let gas_budget: u64 = runtime::get_named_arg(ARG_GAS_BUDGET);
+    let callback_addr = runtime::schedule_callback(
+        METHOD_TICK,
+        RuntimeArgs::new(),
+        era_interval,
+        gas_budget,
+        get_uref(PURSE_KEY_NAME),
+    );
+    storage::write(get_uref(CALLBACK_KEY_NAME), callback_addr);
+}
+
+#[no_mangle]
+pub extern "C" fn cancel() {
+    let callback_addr: ScheduledCallbackAddr = storage::read(get_uref(CALLBACK_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    runtime::cancel_callback(callback_addr);
+}
+
+#[no_mangle]
+pub extern "C" fn tick() {
+    let counter = get_uref(COUNTER_KEY_NAME);
+    let count: u64 = storage::read(counter).unwrap_or_revert().unwrap_or_revert();
+    storage::write(counter, count + 1);
+
+    let drain: bool = storage::read(get_uref(DRAIN_KEY_NAME))
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if drain {
+        let purse = get_uref(PURSE_KEY_NAME);
+        let balance = system::get_purse_balance(purse).unwrap_or_revert();
+        system::transfer_from_purse_to_purse(purse, system::create_purse(), balance, None)
+            .unwrap_or_revert();
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let era_interval: u64 = runtime::get_named_arg(ARG_ERA_INTERVAL);
+    let gas_budget: u64 = runtime::get_named_arg(ARG_GAS_BUDGET);
+    let drain: bool = runtime::get_named_arg(ARG_DRAIN);
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount, None)
+        .unwrap_or_revert();
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(PURSE_KEY_NAME.into(), purse.into());
+    named_keys.insert(COUNTER_KEY_NAME.into(), storage::new_uref(0u64).into());
+    named_keys.insert(DRAIN_KEY_NAME.into(), storage::new_uref(drain).into());
+    named_keys.insert(
+        CALLBACK_KEY_NAME.into(),
+        storage::new_uref([0u8; 32]).into(),
+    );
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            METHOD_REGISTER,
+            vec![
+                Parameter::new(ARG_ERA_INTERVAL, CLType::U64),
+                Parameter::new(ARG_GAS_BUDGET, CLType::U64),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        for name in [METHOD_CANCEL, METHOD_TICK] {
+            entry_points.add_entry_point(EntryPoint::new(
+                name,
+                vec![],
+                CLType::Unit,
+                EntryPointAccess::Public,
+                EntryPointType::Contract,
+            ));
+        }
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+
+    runtime::call_contract::<()>(
+        contract_hash,
+        METHOD_REGISTER,
+        runtime_args! {
+            ARG_ERA_INTERVAL => era_interval,
+            ARG_GAS_BUDGET => gas_budget,
+        },
+    );
+}
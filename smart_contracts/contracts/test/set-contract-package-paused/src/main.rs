@@ -0,0 +1,24 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::ContractPackageHash;
+
+const ARG_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+const ARG_PAUSED: &str = "paused";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_package_hash: ContractPackageHash =
+        runtime::get_named_arg(ARG_CONTRACT_PACKAGE_HASH);
+    let paused: bool = runtime::get_named_arg(ARG_PAUSED);
+
+    if paused {
+        storage::pause_contract_package(contract_package_hash).unwrap_or_revert();
+    } else {
+        storage::unpause_contract_package(contract_package_hash).unwrap_or_revert();
+    }
+}
@@ -0,0 +1,81 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::ApiError;
+
+const SORTED_INDEX_NAME: &str = "sorted_index";
+const ARG_INSERTS: &str = "inserts";
+const ARG_REMOVALS: &str = "removals";
+const ARG_START: &str = "start";
+const ARG_LIMIT: &str = "limit";
+const ASCENDING_KEY_NAME: &str = "ascending";
+const DESCENDING_KEY_NAME: &str = "descending";
+const VALUES_KEY_NAME: &str = "values";
+const MIN_KEY_NAME: &str = "min";
+const MAX_KEY_NAME: &str = "max";
+const REMOVED_KEY_NAME: &str = "removed";
+
+fn to_u64s(entry_keys: Vec<u128>) -> Vec<u64> {
+    entry_keys
+        .into_iter()
+        .map(|entry_key| entry_key as u64)
+        .collect()
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let inserts: Vec<(u64, String)> = runtime::get_named_arg(ARG_INSERTS);
+    let removals: Vec<u64> = runtime::get_named_arg(ARG_REMOVALS);
+    let start: Option<u64> = runtime::get_named_arg(ARG_START);
+    let limit: u32 = runtime::get_named_arg(ARG_LIMIT);
+
+    let sorted_index = match runtime::get_key(SORTED_INDEX_NAME) {
+        Some(key) => key
+            .into_uref()
+            .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant),
+        None => storage::new_sorted_index(SORTED_INDEX_NAME).unwrap_or_revert(),
+    };
+
+    for (entry_key, value) in inserts {
+        storage::sorted_index_insert(sorted_index, entry_key.into(), value);
+    }
+    let removed: Vec<bool> = removals
+        .into_iter()
+        .map(|entry_key| storage::sorted_index_remove(sorted_index, entry_key.into()))
+        .collect();
+
+    let start = start.map(u128::from);
+    let ascending = storage::sorted_index_range(sorted_index, start, limit, false);
+    let descending = storage::sorted_index_range(sorted_index, start, limit, true);
+    let values: Vec<String> = ascending
+        .iter()
+        .map(|entry_key| {
+            storage::sorted_index_get(sorted_index, *entry_key)
+                .unwrap_or_revert()
+                .unwrap_or_revert_with(ApiError::ValueNotFound)
+        })
+        .collect();
+    let min = storage::sorted_index_min(sorted_index).map(|entry_key| entry_key as u64);
+    let max = storage::sorted_index_max(sorted_index).map(|entry_key| entry_key as u64);
+
+    runtime::put_key(
+        ASCENDING_KEY_NAME,
+        storage::new_uref(to_u64s(ascending)).into(),
+    );
+    runtime::put_key(
+        DESCENDING_KEY_NAME,
+        storage::new_uref(to_u64s(descending)).into(),
+    );
+    runtime::put_key(VALUES_KEY_NAME, storage::new_uref(values).into());
+    runtime::put_key(MIN_KEY_NAME, storage::new_uref(min).into());
+    runtime::put_key(MAX_KEY_NAME, storage::new_uref(max).into());
+    runtime::put_key(REMOVED_KEY_NAME, storage::new_uref(removed).into());
+}
@@ -0,0 +1,44 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ApiError, Key, U512};
+
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ID: &str = "id";
+
+const TRANSFER_KEY: &str = "transfer";
+
+#[repr(u16)]
+enum CustomError {
+    MissingTransferAddr = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let target_name: String = runtime::get_named_arg(ARG_TARGET);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let id: Option<u64> = runtime::get_named_arg(ARG_ID);
+
+    let target = system::create_purse();
+    runtime::put_key(&target_name, target.into());
+
+    let transfer_addr = system::transfer_from_purse_to_purse_with_result(
+        account::get_main_purse(),
+        target,
+        amount,
+        id,
+    )
+    .unwrap_or_revert()
+    .unwrap_or_revert_with(ApiError::User(CustomError::MissingTransferAddr as u16));
+
+    runtime::put_key(TRANSFER_KEY, Key::Transfer(transfer_addr));
+}
@@ -30,9 +30,25 @@ pub use self::{
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
     contracts::NamedKeys,
-    crypto, AccessRights, ContextAccessRights, Key, URef, BLAKE2B_DIGEST_LENGTH,
+    crypto, AccessRights, ContextAccessRights, ContractHash, Key, URef, BLAKE2B_DIGEST_LENGTH,
 };
 
+/// Name of the named key under which an account stores the [`ContractHash`] of its authorization
+/// contract.
+///
+/// If set, every deploy sent by the account has to be approved by the contract's
+/// [`METHOD_AUTHORIZE`] entry point in addition to meeting the account's deployment threshold.
+pub const AUTHORIZATION_CONTRACT_KEY: &str = "authorization_contract";
+/// Name of the entry point of an authorization contract which approves deploys.
+///
+/// It is called with [`ARG_DEPLOY_HASH`] and [`ARG_APPROVALS`] and has to return a `bool`.
+pub const METHOD_AUTHORIZE: &str = "authorize";
+/// Named argument of [`METHOD_AUTHORIZE`] holding the deploy hash as a `[u8; 32]`.
+pub const ARG_DEPLOY_HASH: &str = "deploy_hash";
+/// Named argument of [`METHOD_AUTHORIZE`] holding the account hashes of the deploy's approvals as
+/// a `BTreeSet<AccountHash>`.
+pub const ARG_APPROVALS: &str = "approvals";
+
 /// Represents an Account in the global state.
 #[derive(PartialEq, Eq, Clone, Debug, Serialize)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -105,6 +121,15 @@ impl Account {
         &mut self.named_keys
     }
 
+    /// Returns the hash of the account's authorization contract, if one is registered under
+    /// [`AUTHORIZATION_CONTRACT_KEY`].
+    pub fn authorization_contract(&self) -> Option<ContractHash> {
+        self.named_keys
+            .get(AUTHORIZATION_CONTRACT_KEY)
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new)
+    }
+
     /// Returns account hash.
     pub fn account_hash(&self) -> AccountHash {
         self.account_hash
@@ -273,6 +298,42 @@ impl Account {
     }
 }
 
+/// Checks whether `approvals` authorize an action of the given type on behalf of `account`.
+///
+/// This applies the same rules as execution: every approval has to come from one of the account's
+/// associated keys, and their combined weight has to meet the account's threshold for the action.
+/// Returns the combined weight of the approvals if they suffice.
+///
+/// Administrator approvals, which bypass these rules on private chains, and authorization
+/// contracts aren't taken into account.
+pub fn check_approvals(
+    account: &Account,
+    approvals: &BTreeSet<AccountHash>,
+    action_type: ActionType,
+) -> Result<Weight, ApprovalsFailure> {
+    if approvals.is_empty() {
+        return Err(ApprovalsFailure::NoApprovals);
+    }
+
+    if let Some(account_hash) = approvals
+        .iter()
+        .find(|account_hash| !account.associated_keys.contains_key(account_hash))
+    {
+        return Err(ApprovalsFailure::UnassociatedKey(*account_hash));
+    }
+
+    let weight = account.associated_keys.calculate_keys_weight(approvals);
+    let threshold = match action_type {
+        ActionType::Deployment => *account.action_thresholds.deployment(),
+        ActionType::KeyManagement => *account.action_thresholds.key_management(),
+    };
+    if weight < threshold {
+        return Err(ApprovalsFailure::InsufficientWeight { weight, threshold });
+    }
+
+    Ok(weight)
+}
+
 impl ToBytes for Account {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -474,6 +535,42 @@ impl TryFrom<i32> for UpdateKeyFailure {
     }
 }
 
+/// Errors that can occur while checking the approvals of an action against an account.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum ApprovalsFailure {
+    /// No approvals were given.
+    NoApprovals,
+    /// The given [`AccountHash`] is not associated with the account.
+    UnassociatedKey(AccountHash),
+    /// The combined [`Weight`] of the approvals is below the account's threshold for the action.
+    InsufficientWeight {
+        /// The combined weight of the approvals.
+        weight: Weight,
+        /// The account's threshold for the action.
+        threshold: Weight,
+    },
+}
+
+impl Display for ApprovalsFailure {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            ApprovalsFailure::NoApprovals => formatter.write_str("No approvals given"),
+            ApprovalsFailure::UnassociatedKey(account_hash) => write!(
+                formatter,
+                "Approval from {} which is not an associated key",
+                account_hash
+            ),
+            ApprovalsFailure::InsufficientWeight { weight, threshold } => write!(
+                formatter,
+                "Approvals weight {} is below the action threshold {}",
+                weight.value(),
+                threshold.value()
+            ),
+        }
+    }
+}
+
 #[doc(hidden)]
 #[cfg(any(feature = "testing", feature = "gens", test))]
 pub mod gens {
@@ -764,6 +861,61 @@ mod tests {
         ])));
     }
 
+    #[test]
+    fn should_check_approvals() {
+        let associated_keys = {
+            let mut res = AssociatedKeys::new(AccountHash::new([1u8; 32]), Weight::new(1));
+            res.add_key(AccountHash::new([2u8; 32]), Weight::new(11))
+                .expect("should add key 1");
+            res.add_key(AccountHash::new([3u8; 32]), Weight::new(11))
+                .expect("should add key 2");
+            res
+        };
+        let account = Account::new(
+            AccountHash::new([0u8; 32]),
+            NamedKeys::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            associated_keys,
+            ActionThresholds::new(Weight::new(11), Weight::new(22))
+                .expect("should create thresholds"),
+        );
+
+        assert_eq!(
+            check_approvals(&account, &BTreeSet::new(), ActionType::Deployment),
+            Err(ApprovalsFailure::NoApprovals)
+        );
+
+        let unassociated_key = AccountHash::new([4u8; 32]);
+        assert_eq!(
+            check_approvals(
+                &account,
+                &BTreeSet::from_iter(vec![AccountHash::new([2u8; 32]), unassociated_key]),
+                ActionType::Deployment
+            ),
+            Err(ApprovalsFailure::UnassociatedKey(unassociated_key))
+        );
+
+        let approvals = BTreeSet::from_iter(vec![
+            AccountHash::new([1u8; 32]),
+            AccountHash::new([2u8; 32]),
+        ]);
+        assert_eq!(
+            check_approvals(&account, &approvals, ActionType::Deployment),
+            Ok(Weight::new(12))
+        );
+        assert_eq!(
+            check_approvals(&account, &approvals, ActionType::KeyManagement),
+            Err(ApprovalsFailure::InsufficientWeight {
+                weight: Weight::new(12),
+                threshold: Weight::new(22),
+            })
+        );
+        assert_eq!(
+            check_approvals(&account, &approvals, ActionType::KeyManagement).is_ok(),
+            account.can_manage_keys_with(&approvals)
+        );
+    }
+
     #[test]
     fn set_action_threshold_higher_than_total_weight() {
         let identity_key = AccountHash::new([1u8; 32]);
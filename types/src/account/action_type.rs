@@ -3,6 +3,7 @@ use core::convert::TryFrom;
 use super::TryFromIntError;
 
 /// The various types of action which can be performed in the context of a given account.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum ActionType {
     /// Represents performing a deploy.
@@ -0,0 +1,146 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "datasize")]
+use datasize::DataSize;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::ContractHash,
+    crypto, CLType, CLTyped, CLValue, BLAKE2B_DIGEST_LENGTH,
+};
+
+/// The maximum length in bytes of the name of a [`ContractEvent`].
+pub const CONTRACT_EVENT_NAME_MAX_LENGTH: usize = 100;
+
+/// A structured event emitted by a stored contract during execution.
+///
+/// Events are only retained if the deploy which emitted them executed successfully.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContractEvent {
+    /// The contract which emitted the event.
+    contract_hash: ContractHash,
+    /// The name of the event.
+    name: String,
+    /// The data attached to the event.
+    data: CLValue,
+}
+
+impl ContractEvent {
+    /// Creates a [`ContractEvent`].
+    pub fn new(contract_hash: ContractHash, name: String, data: CLValue) -> Self {
+        ContractEvent {
+            contract_hash,
+            name,
+            data,
+        }
+    }
+
+    /// Returns the hash of the contract which emitted the event.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    /// Returns the name of the event.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the data attached to the event.
+    pub fn data(&self) -> &CLValue {
+        &self.data
+    }
+
+    /// Returns the Blake2b hash of the serialized event.
+    pub fn hash(&self) -> Result<[u8; BLAKE2B_DIGEST_LENGTH], bytesrepr::Error> {
+        Ok(crypto::blake2b(self.to_bytes()?))
+    }
+}
+
+impl CLTyped for ContractEvent {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for ContractEvent {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_hash, rem) = ContractHash::from_bytes(bytes)?;
+        let (name, rem) = String::from_bytes(rem)?;
+        let (data, rem) = CLValue::from_bytes(rem)?;
+        Ok((
+            ContractEvent {
+                contract_hash,
+                name,
+                data,
+            },
+            rem,
+        ))
+    }
+}
+
+impl ToBytes for ContractEvent {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut result)?;
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_hash.serialized_length()
+            + self.name.serialized_length()
+            + self.data.serialized_length()
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.contract_hash.write_bytes(writer)?;
+        self.name.write_bytes(writer)?;
+        self.data.write_bytes(writer)?;
+        Ok(())
+    }
+}
+
+/// Generators for a `ContractEvent`
+#[cfg(any(feature = "testing", feature = "gens", test))]
+pub(crate) mod gens {
+    use proptest::{
+        array,
+        prelude::{Arbitrary, Strategy},
+    };
+
+    use crate::{contracts::ContractHash, gens::cl_value_arb, ContractEvent};
+
+    /// Creates an arbitrary `ContractEvent`
+    pub fn contract_event_arb() -> impl Strategy<Value = ContractEvent> {
+        (
+            array::uniform32(<u8>::arbitrary()).prop_map(ContractHash::new),
+            "[a-z_]{1,32}",
+            cl_value_arb(),
+        )
+            .prop_map(|(contract_hash, name, data)| ContractEvent::new(contract_hash, name, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::bytesrepr;
+
+    use super::gens;
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(event in gens::contract_event_arb()) {
+            bytesrepr::test_serialization_roundtrip(&event)
+        }
+    }
+}
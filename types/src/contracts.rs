@@ -22,11 +22,11 @@ use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Seria
 
 use crate::{
     account,
-    account::TryFromSliceForAccountHashError,
+    account::{AccountHash, TryFromSliceForAccountHashError},
     bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
     checksummed_hex,
     contract_wasm::ContractWasmHash,
-    uref,
+    crypto, uref,
     uref::URef,
     CLType, CLTyped, ContextAccessRights, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
 };
@@ -40,6 +40,11 @@ const CONTRACT_STRING_PREFIX: &str = "contract-";
 const PACKAGE_STRING_PREFIX: &str = "contract-package-";
 // We need to support the legacy prefix of "contract-package-wasm".
 const PACKAGE_STRING_LEGACY_EXTRA_PREFIX: &str = "wasm";
+// Domain separator for contract package hashes derived from an install seed.
+const INSTALL_SEED_DOMAIN: &[u8] = b"contract-package-install-seed";
+
+/// Length of the seed from which a contract package hash can be derived.
+pub const INSTALL_SEED_LENGTH: usize = 32;
 
 /// Set of errors which may happen when working with contract headers.
 #[derive(Debug, PartialEq, Eq)]
@@ -507,6 +512,21 @@ impl ContractPackageHash {
         &self.0
     }
 
+    /// Derives the hash of the contract package installed by `account_hash` with `install_seed`.
+    ///
+    /// Installing with the same seed from the same account always targets this package.
+    pub fn from_install_seed(
+        account_hash: AccountHash,
+        install_seed: [u8; INSTALL_SEED_LENGTH],
+    ) -> ContractPackageHash {
+        let mut preimage =
+            Vec::with_capacity(INSTALL_SEED_DOMAIN.len() + KEY_HASH_LENGTH + INSTALL_SEED_LENGTH);
+        preimage.extend_from_slice(INSTALL_SEED_DOMAIN);
+        preimage.extend_from_slice(account_hash.as_bytes());
+        preimage.extend_from_slice(&install_seed);
+        ContractPackageHash(crypto::blake2b(preimage))
+    }
+
     /// Formats the `ContractPackageHash` for users getting and putting.
     pub fn to_formatted_string(self) -> String {
         format!("{}{}", PACKAGE_STRING_PREFIX, base16::encode_lower(&self.0),)
@@ -719,6 +739,13 @@ pub struct ContractPackage {
     groups: Groups,
     /// A flag that determines whether a contract is locked
     lock_status: ContractPackageStatus,
+    /// A flag that determines whether calls to the package's contracts are refused
+    #[serde(skip_serializing_if = "is_not_paused")]
+    paused: bool,
+}
+
+fn is_not_paused(paused: &bool) -> bool {
+    !*paused
 }
 
 impl CLTyped for ContractPackage {
@@ -742,6 +769,7 @@ impl ContractPackage {
             disabled_versions,
             groups,
             lock_status,
+            paused: false,
         }
     }
 
@@ -921,8 +949,34 @@ impl ContractPackage {
     pub fn get_lock_status(&self) -> ContractPackageStatus {
         self.lock_status.clone()
     }
+
+    /// Returns `true` if calls to the package's contracts are refused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pauses or unpauses the package.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// The lock status and pause flag share a single byte so that the encoding of unpaused
+    /// packages is unchanged.
+    fn status_byte(&self) -> u8 {
+        let mut status = 0;
+        if self.is_locked() {
+            status |= PACKAGE_LOCKED_BIT;
+        }
+        if self.paused {
+            status |= PACKAGE_PAUSED_BIT;
+        }
+        status
+    }
 }
 
+const PACKAGE_LOCKED_BIT: u8 = 0b01;
+const PACKAGE_PAUSED_BIT: u8 = 0b10;
+
 impl ToBytes for ContractPackage {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -930,7 +984,7 @@ impl ToBytes for ContractPackage {
         self.versions().write_bytes(&mut result)?;
         self.disabled_versions().write_bytes(&mut result)?;
         self.groups().write_bytes(&mut result)?;
-        self.lock_status.write_bytes(&mut result)?;
+        self.status_byte().write_bytes(&mut result)?;
         Ok(result)
     }
 
@@ -939,7 +993,7 @@ impl ToBytes for ContractPackage {
             + self.versions.serialized_length()
             + self.disabled_versions.serialized_length()
             + self.groups.serialized_length()
-            + self.lock_status.serialized_length()
+            + self.status_byte().serialized_length()
     }
 
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
@@ -947,7 +1001,7 @@ impl ToBytes for ContractPackage {
         self.versions().write_bytes(writer)?;
         self.disabled_versions().write_bytes(writer)?;
         self.groups().write_bytes(writer)?;
-        self.lock_status.write_bytes(writer)?;
+        self.status_byte().write_bytes(writer)?;
         Ok(())
     }
 }
@@ -958,13 +1012,17 @@ impl FromBytes for ContractPackage {
         let (versions, bytes) = ContractVersions::from_bytes(bytes)?;
         let (disabled_versions, bytes) = DisabledVersions::from_bytes(bytes)?;
         let (groups, bytes) = Groups::from_bytes(bytes)?;
-        let (lock_status, bytes) = ContractPackageStatus::from_bytes(bytes)?;
+        let (status, bytes) = u8::from_bytes(bytes)?;
+        if status & !(PACKAGE_LOCKED_BIT | PACKAGE_PAUSED_BIT) != 0 {
+            return Err(bytesrepr::Error::Formatting);
+        }
         let result = ContractPackage {
             access_key,
             versions,
             disabled_versions,
             groups,
-            lock_status,
+            lock_status: ContractPackageStatus::new(status & PACKAGE_LOCKED_BIT != 0),
+            paused: status & PACKAGE_PAUSED_BIT != 0,
         };
 
         Ok((result, bytes))
@@ -1264,6 +1322,9 @@ pub enum EntryPointType {
     Session = 0,
     /// Runs within contract's context
     Contract = 1,
+    /// Runs within contract's context, but can't be called while the same entry point of the same
+    /// contract is already executing further up the call stack.
+    NonReentrantContract = 2,
 }
 
 impl ToBytes for EntryPointType {
@@ -1287,6 +1348,7 @@ impl FromBytes for EntryPointType {
         match value {
             0 => Ok((EntryPointType::Session, bytes)),
             1 => Ok((EntryPointType::Contract, bytes)),
+            2 => Ok((EntryPointType::NonReentrantContract, bytes)),
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -1693,6 +1755,27 @@ mod tests {
         assert_eq!(rem.len(), 0);
     }
 
+    #[test]
+    fn should_keep_encoding_of_unpaused_packages() {
+        let mut contract_package = make_contract_package();
+        let unpaused_bytes = contract_package.to_bytes().expect("should serialize");
+        assert_eq!(
+            unpaused_bytes.last(),
+            Some(&u8::from(contract_package.is_locked()))
+        );
+
+        contract_package.set_paused(true);
+        let paused_bytes = contract_package.to_bytes().expect("should serialize");
+        assert_eq!(paused_bytes.len(), unpaused_bytes.len());
+        bytesrepr::test_serialization_roundtrip(&contract_package);
+
+        contract_package.set_paused(false);
+        assert_eq!(
+            contract_package.to_bytes().expect("should serialize"),
+            unpaused_bytes
+        );
+    }
+
     #[test]
     fn should_remove_group() {
         let mut contract_package = make_contract_package();
@@ -778,6 +778,7 @@ mod tests {
     use rand::{rngs::SmallRng, Rng, SeedableRng};
 
     use super::*;
+    use crate::{canonical_json, CanonicalJsonVersion, Key, KEY_HASH_LENGTH};
 
     fn get_rng() -> SmallRng {
         let mut seed = [0u8; 32];
@@ -798,4 +799,75 @@ mod tests {
         let execution_result: ExecutionResult = rng.gen();
         bytesrepr::test_serialization_roundtrip(&execution_result);
     }
+
+    fn golden_execution_result() -> ExecutionResult {
+        let written_key = Key::Hash([2; KEY_HASH_LENGTH]).to_formatted_string();
+        let added_key = Key::Hash([3; KEY_HASH_LENGTH]).to_formatted_string();
+        ExecutionResult::Success {
+            effect: ExecutionEffect {
+                operations: vec![Operation {
+                    key: written_key.clone(),
+                    kind: OpKind::Write,
+                }],
+                transforms: vec![
+                    TransformEntry {
+                        key: written_key,
+                        transform: Transform::WriteCLValue(CLValue::from_t(1_u64).unwrap()),
+                    },
+                    TransformEntry {
+                        key: added_key,
+                        transform: Transform::AddUInt64(7),
+                    },
+                ],
+            },
+            transfers: vec![TransferAddr::new([1; 32])],
+            cost: U512::from(123),
+        }
+    }
+
+    #[test]
+    fn canonical_json_of_execution_result_should_match_golden_value() {
+        let golden = format!(
+            concat!(
+                r#"{{"Success":{{"cost":"123","effect":{{"operations":["#,
+                r#"{{"key":"hash-{0}","kind":"Write"}}],"transforms":["#,
+                r#"{{"key":"hash-{0}","transform":{{"WriteCLValue":{{"bytes":"0100000000000000","#,
+                r#""cl_type":"U64","parsed":1}}}}}},{{"key":"hash-{1}","transform":"#,
+                r#"{{"AddUInt64":7}}}}]}},"transfers":["transfer-{2}"]}}}}"#
+            ),
+            "02".repeat(32),
+            "03".repeat(32),
+            "01".repeat(32)
+        );
+        let execution_result = golden_execution_result();
+        assert_eq!(
+            canonical_json(&execution_result, CanonicalJsonVersion::V1).unwrap(),
+            golden
+        );
+        // The canonical form is itself valid JSON for the same value.
+        let parsed: ExecutionResult = serde_json::from_str(&golden).unwrap();
+        assert_eq!(parsed, execution_result);
+    }
+
+    #[test]
+    fn canonical_json_of_execution_effect_should_match_golden_value() {
+        let effect = match golden_execution_result() {
+            ExecutionResult::Success { effect, .. } => effect,
+            ExecutionResult::Failure { .. } => unreachable!(),
+        };
+        let expected = format!(
+            concat!(
+                r#"{{"operations":[{{"key":"hash-{0}","kind":"Write"}}],"transforms":["#,
+                r#"{{"key":"hash-{0}","transform":{{"WriteCLValue":{{"bytes":"0100000000000000","#,
+                r#""cl_type":"U64","parsed":1}}}}}},{{"key":"hash-{1}","transform":"#,
+                r#"{{"AddUInt64":7}}}}]}}"#
+            ),
+            "02".repeat(32),
+            "03".repeat(32)
+        );
+        assert_eq!(
+            canonical_json(&effect, CanonicalJsonVersion::V1).unwrap(),
+            expected
+        );
+    }
 }
@@ -0,0 +1,78 @@
+//! Harnesses for fuzzing the `bytesrepr` decoders of types received from the network.
+//!
+//! Only compiled when fuzzing, i.e. with `--cfg fuzzing` as set by `cargo fuzz`.  The targets
+//! under `types/fuzz` pass their input straight to these functions.
+//!
+//! Each harness decodes arbitrary bytes and, if that succeeds, checks the decoded value survives
+//! being encoded and decoded again.  The re-encoding needn't match the input, as some encodings
+//! (e.g. a map with repeated keys) aren't canonical.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::{EntryPoint, EntryPoints, Parameter},
+    CLValue, Key, PublicKey, RuntimeArgs, Signature, StoredValue,
+};
+
+fn check_round_trip<T>(data: &[u8])
+where
+    T: FromBytes + ToBytes + PartialEq + Debug,
+{
+    let value = match T::from_bytes(data) {
+        Ok((value, _remainder)) => value,
+        Err(_) => return,
+    };
+
+    let encoded: Vec<u8> = value.to_bytes().expect("decoded value should be encodable");
+    assert_eq!(encoded.len(), value.serialized_length());
+
+    let decoded: T = bytesrepr::deserialize(encoded).expect("encoded value should be decodable");
+    assert_eq!(decoded, value);
+}
+
+/// Fuzzes the decoding of [`RuntimeArgs`], as carried by the session and payment of a deploy.
+pub fn fuzz_runtime_args(data: &[u8]) {
+    check_round_trip::<RuntimeArgs>(data);
+}
+
+/// Fuzzes the decoding of a [`CLValue`].
+pub fn fuzz_cl_value(data: &[u8]) {
+    check_round_trip::<CLValue>(data);
+}
+
+/// Fuzzes the decoding of [`EntryPoints`].
+pub fn fuzz_entry_points(data: &[u8]) {
+    check_round_trip::<EntryPoints>(data);
+}
+
+/// Fuzzes the decoding of a single [`EntryPoint`].
+pub fn fuzz_entry_point(data: &[u8]) {
+    check_round_trip::<EntryPoint>(data);
+}
+
+/// Fuzzes the decoding of an entry point [`Parameter`].
+pub fn fuzz_parameter(data: &[u8]) {
+    check_round_trip::<Parameter>(data);
+}
+
+/// Fuzzes the decoding of a [`Key`].
+pub fn fuzz_key(data: &[u8]) {
+    check_round_trip::<Key>(data);
+}
+
+/// Fuzzes the decoding of a [`PublicKey`], as carried by the header and approvals of a deploy.
+pub fn fuzz_public_key(data: &[u8]) {
+    check_round_trip::<PublicKey>(data);
+}
+
+/// Fuzzes the decoding of a [`Signature`], as carried by the approvals of a deploy.
+pub fn fuzz_signature(data: &[u8]) {
+    check_round_trip::<Signature>(data);
+}
+
+/// Fuzzes the decoding of a [`StoredValue`], as carried by synchronized global state.
+pub fn fuzz_stored_value(data: &[u8]) {
+    check_round_trip::<StoredValue>(data);
+}
@@ -29,7 +29,10 @@ use crate::{
 };
 
 use crate::deploy_info::gens::{deploy_hash_arb, transfer_addr_arb};
-pub use crate::{deploy_info::gens::deploy_info_arb, transfer::gens::transfer_arb};
+pub use crate::{
+    contract_event::gens::contract_event_arb, deploy_info::gens::deploy_info_arb,
+    transfer::gens::transfer_arb,
+};
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
     collection::vec(any::<u8>(), 32).prop_map(|b| {
@@ -284,6 +287,7 @@ pub fn entry_point_type_arb() -> impl Strategy<Value = EntryPointType> {
     prop_oneof![
         Just(EntryPointType::Session),
         Just(EntryPointType::Contract),
+        Just(EntryPointType::NonReentrantContract),
     ]
 }
 
@@ -372,16 +376,22 @@ pub fn contract_package_arb() -> impl Strategy<Value = ContractPackage> {
         contract_versions_arb(),
         disabled_versions_arb(),
         groups_arb(),
+        any::<bool>(),
+        any::<bool>(),
     )
-        .prop_map(|(access_key, versions, disabled_versions, groups)| {
-            ContractPackage::new(
-                access_key,
-                versions,
-                disabled_versions,
-                groups,
-                ContractPackageStatus::default(),
-            )
-        })
+        .prop_map(
+            |(access_key, versions, disabled_versions, groups, is_locked, paused)| {
+                let mut contract_package = ContractPackage::new(
+                    access_key,
+                    versions,
+                    disabled_versions,
+                    groups,
+                    ContractPackageStatus::new(is_locked),
+                );
+                contract_package.set_paused(paused);
+                contract_package
+            },
+        )
 }
 
 fn delegator_arb() -> impl Strategy<Value = Delegator> {
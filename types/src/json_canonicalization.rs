@@ -0,0 +1,143 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{ser::Error as _, Serialize};
+use serde_json::{Number, Value};
+
+/// The version of the canonical JSON encoding produced by [`canonical_json`].
+///
+/// Digests computed over canonical JSON should be recorded alongside the version used, as any
+/// change to the encoding rules will be introduced as a new version rather than by altering an
+/// existing one.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum CanonicalJsonVersion {
+    /// The initial encoding:
+    ///   * object members are sorted by the UTF-8 bytes of their keys
+    ///   * no insignificant whitespace is emitted
+    ///   * numbers must be integers and are written in plain decimal notation
+    ///   * strings are escaped as `serde_json` escapes them
+    V1,
+}
+
+impl CanonicalJsonVersion {
+    /// The latest version of the encoding.
+    pub const LATEST: CanonicalJsonVersion = CanonicalJsonVersion::V1;
+}
+
+/// Serializes the given data structure as a `String` of canonical JSON, as defined by the given
+/// `version` of the encoding.
+///
+/// Unlike `serde_json::to_string()`, the output doesn't depend on the order in which maps are
+/// populated, so it can be hashed and the digest reproduced by implementations in other languages.
+/// Values containing non-integer numbers are rejected.
+pub fn canonical_json<T>(value: &T, version: CanonicalJsonVersion) -> serde_json::Result<String>
+where
+    T: ?Sized + Serialize,
+{
+    let json_value = serde_json::to_value(value)?;
+    let mut output = String::new();
+    match version {
+        CanonicalJsonVersion::V1 => write_value_v1(&json_value, &mut output)?,
+    }
+    Ok(output)
+}
+
+fn write_value_v1(value: &Value, output: &mut String) -> serde_json::Result<()> {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(true) => output.push_str("true"),
+        Value::Bool(false) => output.push_str("false"),
+        Value::Number(number) => write_number_v1(number, output)?,
+        Value::String(string) => output.push_str(&serde_json::to_string(string)?),
+        Value::Array(values) => {
+            output.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_value_v1(value, output)?;
+            }
+            output.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.as_bytes().cmp(rhs.as_bytes()));
+            output.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                output.push_str(&serde_json::to_string(key)?);
+                output.push(':');
+                write_value_v1(value, output)?;
+            }
+            output.push('}');
+        }
+    }
+    Ok(())
+}
+
+fn write_number_v1(number: &Number, output: &mut String) -> serde_json::Result<()> {
+    if let Some(unsigned) = number.as_u64() {
+        output.push_str(&unsigned.to_string());
+    } else if let Some(signed) = number.as_i64() {
+        output.push_str(&signed.to_string());
+    } else {
+        return Err(serde_json::Error::custom(
+            "canonical json doesn't support non-integer numbers",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Map};
+
+    use super::*;
+
+    #[test]
+    fn should_sort_keys_regardless_of_insertion_order() {
+        let mut forwards = Map::new();
+        forwards.insert("a".to_string(), json!(1));
+        forwards.insert("b".to_string(), json!({ "y": [true, null], "x": "z" }));
+        let mut backwards = Map::new();
+        backwards.insert("b".to_string(), json!({ "x": "z", "y": [true, null] }));
+        backwards.insert("a".to_string(), json!(1));
+
+        let expected = r#"{"a":1,"b":{"x":"z","y":[true,null]}}"#;
+        assert_eq!(
+            canonical_json(&forwards, CanonicalJsonVersion::V1).unwrap(),
+            expected
+        );
+        assert_eq!(
+            canonical_json(&backwards, CanonicalJsonVersion::V1).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn should_write_integers_in_plain_decimal() {
+        let value = json!([0, -1, u64::MAX, i64::MIN]);
+        assert_eq!(
+            canonical_json(&value, CanonicalJsonVersion::V1).unwrap(),
+            "[0,-1,18446744073709551615,-9223372036854775808]"
+        );
+    }
+
+    #[test]
+    fn should_escape_strings() {
+        let value = json!({ "\u{e9}\n": "\"\\\u{1}" });
+        assert_eq!(
+            canonical_json(&value, CanonicalJsonVersion::V1).unwrap(),
+            "{\"\u{e9}\\n\":\"\\\"\\\\\\u0001\"}"
+        );
+    }
+
+    #[test]
+    fn should_reject_non_integer_numbers() {
+        assert!(canonical_json(&json!({ "a": 1.5 }), CanonicalJsonVersion::V1).is_err());
+    }
+}
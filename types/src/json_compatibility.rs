@@ -0,0 +1,25 @@
+//! Types which are serializable to JSON, which map to types defined outside this module.
+//!
+//! These types decouple the JSON representation served to downstream consumers (e.g. RPC clients
+//! and SDKs) from the internal layout of the domain types they mirror.  Changes to them are
+//! additive only.
+
+mod contracts;
+
+use alloc::vec::Vec;
+
+use crate::{contracts::NamedKeys, NamedKey};
+
+pub use contracts::{Contract, ContractPackage, ContractVersion, DisabledVersion, Groups};
+
+/// A helper function to change NamedKeys into a `Vec<NamedKey>`
+pub fn vectorize(keys: &NamedKeys) -> Vec<NamedKey> {
+    let named_keys = keys
+        .iter()
+        .map(|(name, key)| NamedKey {
+            name: name.clone(),
+            key: key.to_formatted_string(),
+        })
+        .collect();
+    named_keys
+}
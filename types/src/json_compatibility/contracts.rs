@@ -0,0 +1,229 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "datasize")]
+use datasize::DataSize;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::vectorize;
+use crate::{
+    contracts::ContractPackageStatus, Contract as DomainContract, ContractHash,
+    ContractPackage as DomainContractPackage, ContractPackageHash, ContractWasmHash, EntryPoint,
+    NamedKey, ProtocolVersion, URef,
+};
+
+/// An enabled version of a contract within a [`ContractPackage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ContractVersion {
+    protocol_version_major: u32,
+    contract_version: u32,
+    contract_hash: ContractHash,
+}
+
+impl ContractVersion {
+    /// Returns the major protocol version the contract version was added under.
+    pub fn protocol_version_major(&self) -> u32 {
+        self.protocol_version_major
+    }
+
+    /// Returns the contract version number.
+    pub fn contract_version(&self) -> u32 {
+        self.contract_version
+    }
+
+    /// Returns the hash of the contract for this version.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+}
+
+/// A disabled version of a contract within a [`ContractPackage`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DisabledVersion {
+    protocol_version_major: u32,
+    contract_version: u32,
+}
+
+impl DisabledVersion {
+    /// Returns the major protocol version the contract version was added under.
+    pub fn protocol_version_major(&self) -> u32 {
+        self.protocol_version_major
+    }
+
+    /// Returns the contract version number.
+    pub fn contract_version(&self) -> u32 {
+        self.contract_version
+    }
+}
+
+/// A user group of a [`ContractPackage`] and its URefs.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct Groups {
+    group: String,
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    keys: Vec<URef>,
+}
+
+impl Groups {
+    /// Returns the name of the group.
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Returns the URefs belonging to the group.
+    pub fn keys(&self) -> &[URef] {
+        &self.keys
+    }
+}
+
+/// A contract struct that can be serialized as  JSON object.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Contract {
+    contract_package_hash: ContractPackageHash,
+    contract_wasm_hash: ContractWasmHash,
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    named_keys: Vec<NamedKey>,
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    entry_points: Vec<EntryPoint>,
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    protocol_version: ProtocolVersion,
+}
+
+impl Contract {
+    /// Returns the hash of the package containing the contract.
+    pub fn contract_package_hash(&self) -> ContractPackageHash {
+        self.contract_package_hash
+    }
+
+    /// Returns the hash of the contract's Wasm.
+    pub fn contract_wasm_hash(&self) -> ContractWasmHash {
+        self.contract_wasm_hash
+    }
+
+    /// Returns the contract's named keys, with keys in their formatted string form.
+    pub fn named_keys(&self) -> &[NamedKey] {
+        &self.named_keys
+    }
+
+    /// Returns the contract's entry points.
+    pub fn entry_points(&self) -> &[EntryPoint] {
+        &self.entry_points
+    }
+
+    /// Returns the protocol version the contract was installed under.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+}
+
+impl From<&DomainContract> for Contract {
+    fn from(contract: &DomainContract) -> Self {
+        let entry_points = contract.entry_points().clone().take_entry_points();
+        let named_keys = vectorize(contract.named_keys());
+        Contract {
+            contract_package_hash: contract.contract_package_hash(),
+            contract_wasm_hash: contract.contract_wasm_hash(),
+            named_keys,
+            entry_points,
+            protocol_version: contract.protocol_version(),
+        }
+    }
+}
+
+/// Contract definition, metadata, and security container.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContractPackage {
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    access_key: URef,
+    versions: Vec<ContractVersion>,
+    disabled_versions: Vec<DisabledVersion>,
+    groups: Vec<Groups>,
+    lock_status: ContractPackageStatus,
+}
+
+impl ContractPackage {
+    /// Returns the access key of the package.
+    pub fn access_key(&self) -> URef {
+        self.access_key
+    }
+
+    /// Returns the enabled versions of the package.
+    pub fn versions(&self) -> &[ContractVersion] {
+        &self.versions
+    }
+
+    /// Returns the disabled versions of the package.
+    pub fn disabled_versions(&self) -> &[DisabledVersion] {
+        &self.disabled_versions
+    }
+
+    /// Returns the user groups of the package.
+    pub fn groups(&self) -> &[Groups] {
+        &self.groups
+    }
+
+    /// Returns the lock status of the package.
+    pub fn lock_status(&self) -> ContractPackageStatus {
+        self.lock_status.clone()
+    }
+}
+
+impl From<&DomainContractPackage> for ContractPackage {
+    fn from(contract_package: &DomainContractPackage) -> Self {
+        let versions = contract_package
+            .versions()
+            .iter()
+            .map(|(version_key, hash)| ContractVersion {
+                protocol_version_major: version_key.protocol_version_major(),
+                contract_version: version_key.contract_version(),
+                contract_hash: *hash,
+            })
+            .collect();
+
+        let disabled_versions = contract_package
+            .disabled_versions()
+            .iter()
+            .map(|version| DisabledVersion {
+                protocol_version_major: version.protocol_version_major(),
+                contract_version: version.contract_version(),
+            })
+            .collect();
+
+        let groups = contract_package
+            .groups()
+            .iter()
+            .map(|(group, keys)| Groups {
+                group: group.clone().value().to_string(),
+                keys: keys.iter().cloned().collect(),
+            })
+            .collect();
+
+        ContractPackage {
+            access_key: contract_package.access_key(),
+            versions,
+            disabled_versions,
+            groups,
+            lock_status: contract_package.get_lock_status(),
+        }
+    }
+}
@@ -47,6 +47,14 @@ const SYSTEM_CONTRACT_REGISTRY_PREFIX: &str = "system-contract-registry-";
 const ERA_SUMMARY_PREFIX: &str = "era-summary-";
 const CHAINSPEC_REGISTRY_PREFIX: &str = "chainspec-registry-";
 const CHECKSUM_REGISTRY_PREFIX: &str = "checksum-registry-";
+const BLOCK_EVENTS_PREFIX: &str = "block-events-";
+const SORTED_INDEX_PREFIX: &str = "sorted-index-";
+const NAMESPACE_PREFIX: &str = "namespace-";
+const SCHEDULED_CALLBACK_PREFIX: &str = "scheduled-callback-";
+const BLOCK_GAS_UTILIZATION_PREFIX: &str = "block-gas-utilization-";
+const ADMINISTRATOR_REGISTRY_PREFIX: &str = "administrator-registry-";
+const PURSE_OWNER_PREFIX: &str = "purse-owner-";
+const RESERVATIONS_PREFIX: &str = "reservations-";
 
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
@@ -60,6 +68,20 @@ pub const KEY_DEPLOY_INFO_LENGTH: usize = DEPLOY_HASH_LENGTH;
 pub const KEY_DICTIONARY_LENGTH: usize = 32;
 /// The maximum length for a `dictionary_item_key`.
 pub const DICTIONARY_ITEM_KEY_MAX_LENGTH: usize = 128;
+/// The number of bytes in a [`Key::SortedIndex`].
+pub const KEY_SORTED_INDEX_LENGTH: usize = 32;
+/// The number of leading bytes of a [`Key::SortedIndex`] identifying the index the entry belongs
+/// to.
+pub const SORTED_INDEX_ID_LENGTH: usize = 16;
+/// The number of bytes in a [`Key::Namespace`].
+pub const KEY_NAMESPACE_LENGTH: usize = 32;
+/// The number of leading bytes of a [`Key::Namespace`] identifying the generation of the namespace
+/// the item belongs to.
+pub const NAMESPACE_ID_LENGTH: usize = 16;
+/// The namespace id under which the retired generations of all namespaces are recorded.
+const RETIRED_NAMESPACES_ID: [u8; NAMESPACE_ID_LENGTH] = [0; NAMESPACE_ID_LENGTH];
+/// The number of bytes in a [`Key::ScheduledCallback`].
+pub const KEY_SCHEDULED_CALLBACK_LENGTH: usize = 32;
 const PADDING_BYTES: [u8; 32] = [0u8; 32];
 const KEY_ID_SERIALIZED_LENGTH: usize = 1;
 // u8 used to determine the ID
@@ -80,6 +102,18 @@ const KEY_CHAINSPEC_REGISTRY_SERIALIZED_LENGTH: usize =
     KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
 const KEY_CHECKSUM_REGISTRY_SERIALIZED_LENGTH: usize =
     KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
+const KEY_BLOCK_EVENTS_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
+const KEY_SORTED_INDEX_SERIALIZED_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + KEY_SORTED_INDEX_LENGTH;
+const KEY_NAMESPACE_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_NAMESPACE_LENGTH;
+const KEY_SCHEDULED_CALLBACK_SERIALIZED_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + KEY_SCHEDULED_CALLBACK_LENGTH;
+const KEY_BLOCK_GAS_UTILIZATION_SERIALIZED_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
+const KEY_ADMINISTRATOR_REGISTRY_SERIALIZED_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
+const KEY_PURSE_OWNER_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + UREF_ADDR_LENGTH;
+const KEY_RESERVATIONS_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
 
 /// An alias for [`Key`]s hash variant.
 pub type HashAddr = [u8; KEY_HASH_LENGTH];
@@ -87,6 +121,15 @@ pub type HashAddr = [u8; KEY_HASH_LENGTH];
 /// An alias for [`Key`]s dictionary variant.
 pub type DictionaryAddr = [u8; KEY_DICTIONARY_LENGTH];
 
+/// An alias for [`Key`]s sorted index variant.
+pub type SortedIndexAddr = [u8; KEY_SORTED_INDEX_LENGTH];
+
+/// An alias for [`Key`]s namespace variant.
+pub type NamespaceAddr = [u8; KEY_NAMESPACE_LENGTH];
+
+/// An alias for [`Key`]s scheduled callback variant.
+pub type ScheduledCallbackAddr = [u8; KEY_SCHEDULED_CALLBACK_LENGTH];
+
 #[allow(missing_docs)]
 #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -106,10 +149,23 @@ pub enum KeyTag {
     Unbond = 12,
     ChainspecRegistry = 13,
     ChecksumRegistry = 14,
+    BlockEvents = 15,
+    SortedIndex = 16,
+    Namespace = 17,
+    ScheduledCallback = 18,
+    BlockGasUtilization = 19,
+    AdministratorRegistry = 20,
+    PurseOwner = 21,
+    Reservations = 22,
 }
 
 /// The type under which data (e.g. [`CLValue`](crate::CLValue)s, smart contracts, user accounts)
 /// are indexed on the network.
+///
+/// The canonical order in which the keys sharing a tag are enumerated from global state is the
+/// ascending order of their serialized form, i.e. the order in which they are laid out in the trie.
+/// It may differ from the `Ord` implementation, e.g. for [`Key::EraInfo`], whose era id serializes
+/// little-endian.
 #[repr(C)]
 #[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -145,6 +201,29 @@ pub enum Key {
     ChainspecRegistry,
     /// A `Key` variant under which we store a registry of checksums.
     ChecksumRegistry,
+    /// A `Key` variant under which we store the contract events emitted in the latest block.
+    BlockEvents,
+    /// A `Key` variant under which we store an entry of a sorted index.  Its value is the
+    /// hash of the index's seed [`URef`] address followed by the big-endian entry key, so the
+    /// entries of an index are adjacent and ordered by their key in global state.
+    SortedIndex(SortedIndexAddr),
+    /// A `Key` variant under which we store an item of a storage namespace.  Its value is the hash
+    /// of the namespace's seed [`URef`] address and current generation, followed by the hash of
+    /// the item key, so the items of a namespace generation are adjacent in global state.
+    Namespace(NamespaceAddr),
+    /// A `Key` variant under which we store a callback scheduled by a contract to be called by
+    /// the step at the end of an era.
+    ScheduledCallback(ScheduledCallbackAddr),
+    /// A `Key` variant under which we store the gas consumed by the deploys of the latest block
+    /// against the block gas limit.
+    BlockGasUtilization,
+    /// A `Key` variant under which we store the administrative accounts, once they've been
+    /// updated on-chain.
+    AdministratorRegistry,
+    /// A `Key` variant under which the mint records the owner of a purse it created.
+    PurseOwner(URefAddr),
+    /// A `Key` variant under which we store the delegator slots reserved by a validator.
+    Reservations(AccountHash),
 }
 
 /// Errors produced when converting a `String` into a `Key`.
@@ -181,6 +260,22 @@ pub enum FromStrError {
     ChainspecRegistry(String),
     /// Checksum registry error.
     ChecksumRegistry(String),
+    /// Block events error.
+    BlockEvents(String),
+    /// Sorted index error.
+    SortedIndex(String),
+    /// Namespace error.
+    Namespace(String),
+    /// Scheduled callback error.
+    ScheduledCallback(String),
+    /// Block gas utilization error.
+    BlockGasUtilization(String),
+    /// Administrator registry error.
+    AdministratorRegistry(String),
+    /// Purse owner error.
+    PurseOwner(String),
+    /// Reservations error.
+    Reservations(String),
     /// Unknown prefix.
     UnknownPrefix,
 }
@@ -239,6 +334,30 @@ impl Display for FromStrError {
             FromStrError::ChecksumRegistry(error) => {
                 write!(f, "checksum-registry-key from string error: {}", error)
             }
+            FromStrError::BlockEvents(error) => {
+                write!(f, "block-events-key from string error: {}", error)
+            }
+            FromStrError::SortedIndex(error) => {
+                write!(f, "sorted-index-key from string error: {}", error)
+            }
+            FromStrError::Namespace(error) => {
+                write!(f, "namespace-key from string error: {}", error)
+            }
+            FromStrError::ScheduledCallback(error) => {
+                write!(f, "scheduled-callback-key from string error: {}", error)
+            }
+            FromStrError::BlockGasUtilization(error) => {
+                write!(f, "block-gas-utilization-key from string error: {}", error)
+            }
+            FromStrError::AdministratorRegistry(error) => {
+                write!(f, "administrator-registry-key from string error: {}", error)
+            }
+            FromStrError::PurseOwner(error) => {
+                write!(f, "purse-owner-key from string error: {}", error)
+            }
+            FromStrError::Reservations(error) => {
+                write!(f, "reservations-key from string error: {}", error)
+            }
             FromStrError::UnknownPrefix => write!(f, "unknown prefix for key"),
         }
     }
@@ -264,6 +383,14 @@ impl Key {
             Key::Unbond(_) => String::from("Key::Unbond"),
             Key::ChainspecRegistry => String::from("Key::ChainspecRegistry"),
             Key::ChecksumRegistry => String::from("Key::ChecksumRegistry"),
+            Key::BlockEvents => String::from("Key::BlockEvents"),
+            Key::SortedIndex(_) => String::from("Key::SortedIndex"),
+            Key::Namespace(_) => String::from("Key::Namespace"),
+            Key::ScheduledCallback(_) => String::from("Key::ScheduledCallback"),
+            Key::BlockGasUtilization => String::from("Key::BlockGasUtilization"),
+            Key::AdministratorRegistry => String::from("Key::AdministratorRegistry"),
+            Key::PurseOwner(_) => String::from("Key::PurseOwner"),
+            Key::Reservations(_) => String::from("Key::Reservations"),
         }
     }
 
@@ -347,6 +474,50 @@ impl Key {
                     base16::encode_lower(&PADDING_BYTES)
                 )
             }
+            Key::BlockEvents => {
+                format!(
+                    "{}{}",
+                    BLOCK_EVENTS_PREFIX,
+                    base16::encode_lower(&PADDING_BYTES)
+                )
+            }
+            Key::SortedIndex(addr) => {
+                format!("{}{}", SORTED_INDEX_PREFIX, base16::encode_lower(&addr))
+            }
+            Key::Namespace(addr) => {
+                format!("{}{}", NAMESPACE_PREFIX, base16::encode_lower(&addr))
+            }
+            Key::ScheduledCallback(addr) => {
+                format!(
+                    "{}{}",
+                    SCHEDULED_CALLBACK_PREFIX,
+                    base16::encode_lower(&addr)
+                )
+            }
+            Key::BlockGasUtilization => {
+                format!(
+                    "{}{}",
+                    BLOCK_GAS_UTILIZATION_PREFIX,
+                    base16::encode_lower(&PADDING_BYTES)
+                )
+            }
+            Key::AdministratorRegistry => {
+                format!(
+                    "{}{}",
+                    ADMINISTRATOR_REGISTRY_PREFIX,
+                    base16::encode_lower(&PADDING_BYTES)
+                )
+            }
+            Key::PurseOwner(uref_addr) => {
+                format!("{}{}", PURSE_OWNER_PREFIX, base16::encode_lower(&uref_addr))
+            }
+            Key::Reservations(account_hash) => {
+                format!(
+                    "{}{}",
+                    RESERVATIONS_PREFIX,
+                    base16::encode_lower(&account_hash)
+                )
+            }
         }
     }
 
@@ -474,6 +645,77 @@ impl Key {
             return Ok(Key::ChecksumRegistry);
         }
 
+        if let Some(gas_utilization_address) = input.strip_prefix(BLOCK_GAS_UTILIZATION_PREFIX) {
+            let padded_bytes = checksummed_hex::decode(gas_utilization_address)
+                .map_err(|error| FromStrError::BlockGasUtilization(error.to_string()))?;
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+                FromStrError::BlockGasUtilization(
+                    "Failed to deserialize block gas utilization key".to_string(),
+                )
+            })?;
+            return Ok(Key::BlockGasUtilization);
+        }
+
+        if let Some(registry_address) = input.strip_prefix(ADMINISTRATOR_REGISTRY_PREFIX) {
+            let padded_bytes = checksummed_hex::decode(registry_address)
+                .map_err(|error| FromStrError::AdministratorRegistry(error.to_string()))?;
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+                FromStrError::AdministratorRegistry(
+                    "Failed to deserialize administrator registry key".to_string(),
+                )
+            })?;
+            return Ok(Key::AdministratorRegistry);
+        }
+
+        if let Some(hex) = input.strip_prefix(PURSE_OWNER_PREFIX) {
+            let addr = checksummed_hex::decode(hex)
+                .map_err(|error| FromStrError::PurseOwner(error.to_string()))?;
+            let uref_addr = URefAddr::try_from(addr.as_ref())
+                .map_err(|error| FromStrError::PurseOwner(error.to_string()))?;
+            return Ok(Key::PurseOwner(uref_addr));
+        }
+
+        if let Some(hex) = input.strip_prefix(RESERVATIONS_PREFIX) {
+            let hash = checksummed_hex::decode(hex)
+                .map_err(|error| FromStrError::Reservations(error.to_string()))?;
+            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_ref())
+                .map_err(|error| FromStrError::Reservations(error.to_string()))?;
+            return Ok(Key::Reservations(AccountHash::new(account_hash)));
+        }
+
+        if let Some(events_address) = input.strip_prefix(BLOCK_EVENTS_PREFIX) {
+            let padded_bytes = checksummed_hex::decode(events_address)
+                .map_err(|error| FromStrError::BlockEvents(error.to_string()))?;
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+                FromStrError::BlockEvents("Failed to deserialize block events key".to_string())
+            })?;
+            return Ok(Key::BlockEvents);
+        }
+
+        if let Some(sorted_index_addr) = input.strip_prefix(SORTED_INDEX_PREFIX) {
+            let sorted_index_addr_bytes = checksummed_hex::decode(sorted_index_addr)
+                .map_err(|error| FromStrError::SortedIndex(error.to_string()))?;
+            let addr = SortedIndexAddr::try_from(sorted_index_addr_bytes.as_ref())
+                .map_err(|error| FromStrError::SortedIndex(error.to_string()))?;
+            return Ok(Key::SortedIndex(addr));
+        }
+
+        if let Some(namespace_addr) = input.strip_prefix(NAMESPACE_PREFIX) {
+            let namespace_addr_bytes = checksummed_hex::decode(namespace_addr)
+                .map_err(|error| FromStrError::Namespace(error.to_string()))?;
+            let addr = NamespaceAddr::try_from(namespace_addr_bytes.as_ref())
+                .map_err(|error| FromStrError::Namespace(error.to_string()))?;
+            return Ok(Key::Namespace(addr));
+        }
+
+        if let Some(scheduled_callback_addr) = input.strip_prefix(SCHEDULED_CALLBACK_PREFIX) {
+            let scheduled_callback_addr_bytes = checksummed_hex::decode(scheduled_callback_addr)
+                .map_err(|error| FromStrError::ScheduledCallback(error.to_string()))?;
+            let addr = ScheduledCallbackAddr::try_from(scheduled_callback_addr_bytes.as_ref())
+                .map_err(|error| FromStrError::ScheduledCallback(error.to_string()))?;
+            return Ok(Key::ScheduledCallback(addr));
+        }
+
         Err(FromStrError::UnknownPrefix)
     }
 
@@ -575,6 +817,114 @@ impl Key {
         }
         false
     }
+
+    /// Creates a new [`Key::SortedIndex`] variant for the entry under `entry_key` in the sorted
+    /// index identified by `seed_uref`.
+    pub fn sorted_index(seed_uref: URef, entry_key: u128) -> Key {
+        let mut addr = SortedIndexAddr::default();
+        addr[..SORTED_INDEX_ID_LENGTH].copy_from_slice(&sorted_index_id(seed_uref));
+        addr[SORTED_INDEX_ID_LENGTH..].copy_from_slice(&entry_key.to_be_bytes());
+        Key::SortedIndex(addr)
+    }
+
+    /// Returns the prefix shared by the serialized [`Key::SortedIndex`] keys of all entries in the
+    /// sorted index identified by `seed_uref`.
+    pub fn sorted_index_prefix(seed_uref: URef) -> Vec<u8> {
+        let mut prefix = Vec::with_capacity(KEY_ID_SERIALIZED_LENGTH + SORTED_INDEX_ID_LENGTH);
+        prefix.push(KeyTag::SortedIndex as u8);
+        prefix.extend_from_slice(&sorted_index_id(seed_uref));
+        prefix
+    }
+
+    /// Returns the entry key if `self` is of type [`Key::SortedIndex`], otherwise returns `None`.
+    pub fn as_sorted_index_entry_key(&self) -> Option<u128> {
+        match self {
+            Key::SortedIndex(addr) => {
+                let mut entry_key_bytes = [0u8; KEY_SORTED_INDEX_LENGTH - SORTED_INDEX_ID_LENGTH];
+                entry_key_bytes.copy_from_slice(&addr[SORTED_INDEX_ID_LENGTH..]);
+                Some(u128::from_be_bytes(entry_key_bytes))
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates a new [`Key::Namespace`] variant for the item under `item_key` in the given
+    /// generation of the namespace identified by `seed_uref`.
+    pub fn namespace_item(seed_uref: URef, generation: u64, item_key: &[u8]) -> Key {
+        let mut addr = NamespaceAddr::default();
+        addr[..NAMESPACE_ID_LENGTH].copy_from_slice(&namespace_id(seed_uref, generation));
+        addr[NAMESPACE_ID_LENGTH..].copy_from_slice(&namespace_item_id(item_key));
+        Key::Namespace(addr)
+    }
+
+    /// Returns the prefix shared by the serialized [`Key::Namespace`] keys of all items in the
+    /// given generation of the namespace identified by `seed_uref`.
+    pub fn namespace_prefix(seed_uref: URef, generation: u64) -> Vec<u8> {
+        let mut prefix = Vec::with_capacity(KEY_ID_SERIALIZED_LENGTH + NAMESPACE_ID_LENGTH);
+        prefix.push(KeyTag::Namespace as u8);
+        prefix.extend_from_slice(&namespace_id(seed_uref, generation));
+        prefix
+    }
+
+    /// Creates the [`Key::Namespace`] variant recording that the given generation of the namespace
+    /// identified by `seed_uref` has been retired, and its items are due to be pruned.
+    pub fn retired_namespace(seed_uref: URef, generation: u64) -> Key {
+        let mut addr = NamespaceAddr::default();
+        addr[..NAMESPACE_ID_LENGTH].copy_from_slice(&RETIRED_NAMESPACES_ID);
+        addr[NAMESPACE_ID_LENGTH..].copy_from_slice(&namespace_id(seed_uref, generation));
+        Key::Namespace(addr)
+    }
+
+    /// Returns the prefix shared by the serialized keys created by [`Key::retired_namespace`].
+    pub fn retired_namespaces_prefix() -> Vec<u8> {
+        let mut prefix = Vec::with_capacity(KEY_ID_SERIALIZED_LENGTH + NAMESPACE_ID_LENGTH);
+        prefix.push(KeyTag::Namespace as u8);
+        prefix.extend_from_slice(&RETIRED_NAMESPACES_ID);
+        prefix
+    }
+
+    /// Returns the prefix shared by the serialized keys of the items of the retired namespace
+    /// generation if `self` was created by [`Key::retired_namespace`], otherwise returns `None`.
+    pub fn as_retired_namespace_prefix(&self) -> Option<Vec<u8>> {
+        match self {
+            Key::Namespace(addr) if addr[..NAMESPACE_ID_LENGTH] == RETIRED_NAMESPACES_ID => {
+                let mut prefix = Vec::with_capacity(KEY_ID_SERIALIZED_LENGTH + NAMESPACE_ID_LENGTH);
+                prefix.push(KeyTag::Namespace as u8);
+                prefix.extend_from_slice(&addr[NAMESPACE_ID_LENGTH..]);
+                Some(prefix)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn namespace_id(seed_uref: URef, generation: u64) -> [u8; NAMESPACE_ID_LENGTH] {
+    // NOTE: Expect below is safe because the length passed is supported.
+    let mut hasher = VarBlake2b::new(NAMESPACE_ID_LENGTH).expect("should create hasher");
+    hasher.update(seed_uref.addr().as_ref());
+    hasher.update(generation.to_le_bytes());
+    let mut id = [0u8; NAMESPACE_ID_LENGTH];
+    hasher.finalize_variable(|hash| id.clone_from_slice(hash));
+    id
+}
+
+fn namespace_item_id(item_key: &[u8]) -> [u8; KEY_NAMESPACE_LENGTH - NAMESPACE_ID_LENGTH] {
+    // NOTE: Expect below is safe because the length passed is supported.
+    let mut hasher =
+        VarBlake2b::new(KEY_NAMESPACE_LENGTH - NAMESPACE_ID_LENGTH).expect("should create hasher");
+    hasher.update(item_key);
+    let mut id = [0u8; KEY_NAMESPACE_LENGTH - NAMESPACE_ID_LENGTH];
+    hasher.finalize_variable(|hash| id.clone_from_slice(hash));
+    id
+}
+
+fn sorted_index_id(seed_uref: URef) -> [u8; SORTED_INDEX_ID_LENGTH] {
+    // NOTE: Expect below is safe because the length passed is supported.
+    let mut hasher = VarBlake2b::new(SORTED_INDEX_ID_LENGTH).expect("should create hasher");
+    hasher.update(seed_uref.addr().as_ref());
+    let mut id = [0u8; SORTED_INDEX_ID_LENGTH];
+    hasher.finalize_variable(|hash| id.clone_from_slice(hash));
+    id
 }
 
 impl Display for Key {
@@ -621,6 +971,32 @@ impl Display for Key {
                     base16::encode_lower(&PADDING_BYTES)
                 )
             }
+            Key::BlockEvents => write!(
+                f,
+                "Key::BlockEvents({})",
+                base16::encode_lower(&PADDING_BYTES)
+            ),
+            Key::SortedIndex(addr) => {
+                write!(f, "Key::SortedIndex({})", base16::encode_lower(addr))
+            }
+            Key::Namespace(addr) => write!(f, "Key::Namespace({})", base16::encode_lower(addr)),
+            Key::ScheduledCallback(addr) => {
+                write!(f, "Key::ScheduledCallback({})", base16::encode_lower(addr))
+            }
+            Key::BlockGasUtilization => write!(
+                f,
+                "Key::BlockGasUtilization({})",
+                base16::encode_lower(&PADDING_BYTES)
+            ),
+            Key::AdministratorRegistry => write!(
+                f,
+                "Key::AdministratorRegistry({})",
+                base16::encode_lower(&PADDING_BYTES)
+            ),
+            Key::PurseOwner(uref_addr) => {
+                write!(f, "Key::PurseOwner({})", base16::encode_lower(uref_addr))
+            }
+            Key::Reservations(account_hash) => write!(f, "Key::Reservations({})", account_hash),
         }
     }
 }
@@ -649,6 +1025,14 @@ impl Tagged<KeyTag> for Key {
             Key::Unbond(_) => KeyTag::Unbond,
             Key::ChainspecRegistry => KeyTag::ChainspecRegistry,
             Key::ChecksumRegistry => KeyTag::ChecksumRegistry,
+            Key::BlockEvents => KeyTag::BlockEvents,
+            Key::SortedIndex(_) => KeyTag::SortedIndex,
+            Key::Namespace(_) => KeyTag::Namespace,
+            Key::ScheduledCallback(_) => KeyTag::ScheduledCallback,
+            Key::BlockGasUtilization => KeyTag::BlockGasUtilization,
+            Key::AdministratorRegistry => KeyTag::AdministratorRegistry,
+            Key::PurseOwner(_) => KeyTag::PurseOwner,
+            Key::Reservations(_) => KeyTag::Reservations,
         }
     }
 }
@@ -722,6 +1106,14 @@ impl ToBytes for Key {
             Key::Unbond(_) => KEY_UNBOND_SERIALIZED_LENGTH,
             Key::ChainspecRegistry => KEY_CHAINSPEC_REGISTRY_SERIALIZED_LENGTH,
             Key::ChecksumRegistry => KEY_CHECKSUM_REGISTRY_SERIALIZED_LENGTH,
+            Key::BlockEvents => KEY_BLOCK_EVENTS_SERIALIZED_LENGTH,
+            Key::SortedIndex(_) => KEY_SORTED_INDEX_SERIALIZED_LENGTH,
+            Key::Namespace(_) => KEY_NAMESPACE_SERIALIZED_LENGTH,
+            Key::ScheduledCallback(_) => KEY_SCHEDULED_CALLBACK_SERIALIZED_LENGTH,
+            Key::BlockGasUtilization => KEY_BLOCK_GAS_UTILIZATION_SERIALIZED_LENGTH,
+            Key::AdministratorRegistry => KEY_ADMINISTRATOR_REGISTRY_SERIALIZED_LENGTH,
+            Key::PurseOwner(_) => KEY_PURSE_OWNER_SERIALIZED_LENGTH,
+            Key::Reservations(_) => KEY_RESERVATIONS_SERIALIZED_LENGTH,
         }
     }
 
@@ -739,10 +1131,18 @@ impl ToBytes for Key {
             Key::Withdraw(account_hash) => account_hash.write_bytes(writer),
             Key::Dictionary(addr) => addr.write_bytes(writer),
             Key::Unbond(account_hash) => account_hash.write_bytes(writer),
+            Key::SortedIndex(addr) => addr.write_bytes(writer),
+            Key::Namespace(addr) => addr.write_bytes(writer),
+            Key::ScheduledCallback(addr) => addr.write_bytes(writer),
+            Key::PurseOwner(uref_addr) => uref_addr.write_bytes(writer),
+            Key::Reservations(account_hash) => account_hash.write_bytes(writer),
             Key::SystemContractRegistry
             | Key::EraSummary
             | Key::ChainspecRegistry
-            | Key::ChecksumRegistry => PADDING_BYTES.write_bytes(writer),
+            | Key::ChecksumRegistry
+            | Key::BlockEvents
+            | Key::BlockGasUtilization
+            | Key::AdministratorRegistry => PADDING_BYTES.write_bytes(writer),
         }
     }
 }
@@ -811,6 +1211,38 @@ impl FromBytes for Key {
                 let (_, rem) = <[u8; 32]>::from_bytes(remainder)?;
                 Ok((Key::ChecksumRegistry, rem))
             }
+            tag if tag == KeyTag::BlockEvents as u8 => {
+                let (_, rem) = <[u8; 32]>::from_bytes(remainder)?;
+                Ok((Key::BlockEvents, rem))
+            }
+            tag if tag == KeyTag::SortedIndex as u8 => {
+                let (addr, rem) = SortedIndexAddr::from_bytes(remainder)?;
+                Ok((Key::SortedIndex(addr), rem))
+            }
+            tag if tag == KeyTag::Namespace as u8 => {
+                let (addr, rem) = NamespaceAddr::from_bytes(remainder)?;
+                Ok((Key::Namespace(addr), rem))
+            }
+            tag if tag == KeyTag::ScheduledCallback as u8 => {
+                let (addr, rem) = ScheduledCallbackAddr::from_bytes(remainder)?;
+                Ok((Key::ScheduledCallback(addr), rem))
+            }
+            tag if tag == KeyTag::BlockGasUtilization as u8 => {
+                let (_, rem) = <[u8; 32]>::from_bytes(remainder)?;
+                Ok((Key::BlockGasUtilization, rem))
+            }
+            tag if tag == KeyTag::AdministratorRegistry as u8 => {
+                let (_, rem) = <[u8; 32]>::from_bytes(remainder)?;
+                Ok((Key::AdministratorRegistry, rem))
+            }
+            tag if tag == KeyTag::PurseOwner as u8 => {
+                let (uref_addr, rem) = URefAddr::from_bytes(remainder)?;
+                Ok((Key::PurseOwner(uref_addr), rem))
+            }
+            tag if tag == KeyTag::Reservations as u8 => {
+                let (account_hash, rem) = AccountHash::from_bytes(remainder)?;
+                Ok((Key::Reservations(account_hash), rem))
+            }
             _ => Err(Error::Formatting),
         }
     }
@@ -836,12 +1268,20 @@ fn please_add_to_distribution_impl(key: Key) {
         Key::Unbond(_) => unimplemented!(),
         Key::ChainspecRegistry => unimplemented!(),
         Key::ChecksumRegistry => unimplemented!(),
+        Key::BlockEvents => unimplemented!(),
+        Key::SortedIndex(_) => unimplemented!(),
+        Key::Namespace(_) => unimplemented!(),
+        Key::ScheduledCallback(_) => unimplemented!(),
+        Key::BlockGasUtilization => unimplemented!(),
+        Key::AdministratorRegistry => unimplemented!(),
+        Key::PurseOwner(_) => unimplemented!(),
+        Key::Reservations(_) => unimplemented!(),
     }
 }
 
 impl Distribution<Key> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Key {
-        match rng.gen_range(0..=14) {
+        match rng.gen_range(0..=22) {
             0 => Key::Account(rng.gen()),
             1 => Key::Hash(rng.gen()),
             2 => Key::URef(rng.gen()),
@@ -857,6 +1297,14 @@ impl Distribution<Key> for Standard {
             12 => Key::Unbond(rng.gen()),
             13 => Key::ChainspecRegistry,
             14 => Key::ChecksumRegistry,
+            15 => Key::BlockEvents,
+            16 => Key::SortedIndex(rng.gen()),
+            17 => Key::Namespace(rng.gen()),
+            18 => Key::ScheduledCallback(rng.gen()),
+            19 => Key::BlockGasUtilization,
+            20 => Key::AdministratorRegistry,
+            21 => Key::PurseOwner(rng.gen()),
+            22 => Key::Reservations(rng.gen()),
             _ => unreachable!(),
         }
     }
@@ -882,6 +1330,14 @@ mod serde_helpers {
         Unbond(String),
         ChainspecRegistry(String),
         ChecksumRegistry(String),
+        BlockEvents(String),
+        SortedIndex(String),
+        Namespace(String),
+        ScheduledCallback(String),
+        BlockGasUtilization(String),
+        AdministratorRegistry(String),
+        PurseOwner(String),
+        Reservations(String),
     }
 
     impl From<&Key> for HumanReadable {
@@ -905,6 +1361,16 @@ mod serde_helpers {
                 Key::Unbond(_) => HumanReadable::Unbond(formatted_string),
                 Key::ChainspecRegistry => HumanReadable::ChainspecRegistry(formatted_string),
                 Key::ChecksumRegistry => HumanReadable::ChecksumRegistry(formatted_string),
+                Key::BlockEvents => HumanReadable::BlockEvents(formatted_string),
+                Key::SortedIndex(_) => HumanReadable::SortedIndex(formatted_string),
+                Key::Namespace(_) => HumanReadable::Namespace(formatted_string),
+                Key::ScheduledCallback(_) => HumanReadable::ScheduledCallback(formatted_string),
+                Key::BlockGasUtilization => HumanReadable::BlockGasUtilization(formatted_string),
+                Key::AdministratorRegistry => {
+                    HumanReadable::AdministratorRegistry(formatted_string)
+                }
+                Key::PurseOwner(_) => HumanReadable::PurseOwner(formatted_string),
+                Key::Reservations(_) => HumanReadable::Reservations(formatted_string),
             }
         }
     }
@@ -928,7 +1394,15 @@ mod serde_helpers {
                 | HumanReadable::EraSummary(formatted_string)
                 | HumanReadable::Unbond(formatted_string)
                 | HumanReadable::ChainspecRegistry(formatted_string)
-                | HumanReadable::ChecksumRegistry(formatted_string) => {
+                | HumanReadable::ChecksumRegistry(formatted_string)
+                | HumanReadable::BlockEvents(formatted_string)
+                | HumanReadable::SortedIndex(formatted_string)
+                | HumanReadable::Namespace(formatted_string)
+                | HumanReadable::ScheduledCallback(formatted_string)
+                | HumanReadable::BlockGasUtilization(formatted_string)
+                | HumanReadable::AdministratorRegistry(formatted_string)
+                | HumanReadable::PurseOwner(formatted_string)
+                | HumanReadable::Reservations(formatted_string) => {
                     Key::from_formatted_str(&formatted_string)
                 }
             }
@@ -952,6 +1426,14 @@ mod serde_helpers {
         Unbond(&'a AccountHash),
         ChainspecRegistry,
         ChecksumRegistry,
+        BlockEvents,
+        SortedIndex(&'a SortedIndexAddr),
+        Namespace(&'a NamespaceAddr),
+        ScheduledCallback(&'a ScheduledCallbackAddr),
+        BlockGasUtilization,
+        AdministratorRegistry,
+        PurseOwner(&'a URefAddr),
+        Reservations(&'a AccountHash),
     }
 
     impl<'a> From<&'a Key> for BinarySerHelper<'a> {
@@ -972,6 +1454,14 @@ mod serde_helpers {
                 Key::Unbond(account_hash) => BinarySerHelper::Unbond(account_hash),
                 Key::ChainspecRegistry => BinarySerHelper::ChainspecRegistry,
                 Key::ChecksumRegistry => BinarySerHelper::ChecksumRegistry,
+                Key::BlockEvents => BinarySerHelper::BlockEvents,
+                Key::SortedIndex(addr) => BinarySerHelper::SortedIndex(addr),
+                Key::Namespace(addr) => BinarySerHelper::Namespace(addr),
+                Key::ScheduledCallback(addr) => BinarySerHelper::ScheduledCallback(addr),
+                Key::BlockGasUtilization => BinarySerHelper::BlockGasUtilization,
+                Key::AdministratorRegistry => BinarySerHelper::AdministratorRegistry,
+                Key::PurseOwner(uref_addr) => BinarySerHelper::PurseOwner(uref_addr),
+                Key::Reservations(account_hash) => BinarySerHelper::Reservations(account_hash),
             }
         }
     }
@@ -993,6 +1483,14 @@ mod serde_helpers {
         Unbond(AccountHash),
         ChainspecRegistry,
         ChecksumRegistry,
+        BlockEvents,
+        SortedIndex(SortedIndexAddr),
+        Namespace(NamespaceAddr),
+        ScheduledCallback(ScheduledCallbackAddr),
+        BlockGasUtilization,
+        AdministratorRegistry,
+        PurseOwner(URefAddr),
+        Reservations(AccountHash),
     }
 
     impl From<BinaryDeserHelper> for Key {
@@ -1013,6 +1511,14 @@ mod serde_helpers {
                 BinaryDeserHelper::Unbond(account_hash) => Key::Unbond(account_hash),
                 BinaryDeserHelper::ChainspecRegistry => Key::ChainspecRegistry,
                 BinaryDeserHelper::ChecksumRegistry => Key::ChecksumRegistry,
+                BinaryDeserHelper::BlockEvents => Key::BlockEvents,
+                BinaryDeserHelper::SortedIndex(addr) => Key::SortedIndex(addr),
+                BinaryDeserHelper::Namespace(addr) => Key::Namespace(addr),
+                BinaryDeserHelper::ScheduledCallback(addr) => Key::ScheduledCallback(addr),
+                BinaryDeserHelper::BlockGasUtilization => Key::BlockGasUtilization,
+                BinaryDeserHelper::AdministratorRegistry => Key::AdministratorRegistry,
+                BinaryDeserHelper::PurseOwner(uref_addr) => Key::PurseOwner(uref_addr),
+                BinaryDeserHelper::Reservations(account_hash) => Key::Reservations(account_hash),
             }
         }
     }
@@ -1070,6 +1576,14 @@ mod tests {
     const UNBOND_KEY: Key = Key::Unbond(AccountHash::new([42; 32]));
     const CHAINSPEC_REGISTRY_KEY: Key = Key::ChainspecRegistry;
     const CHECKSUM_REGISTRY_KEY: Key = Key::ChecksumRegistry;
+    const BLOCK_EVENTS_KEY: Key = Key::BlockEvents;
+    const SORTED_INDEX_KEY: Key = Key::SortedIndex([42; 32]);
+    const NAMESPACE_KEY: Key = Key::Namespace([42; 32]);
+    const SCHEDULED_CALLBACK_KEY: Key = Key::ScheduledCallback([42; 32]);
+    const BLOCK_GAS_UTILIZATION_KEY: Key = Key::BlockGasUtilization;
+    const ADMINISTRATOR_REGISTRY_KEY: Key = Key::AdministratorRegistry;
+    const PURSE_OWNER_KEY: Key = Key::PurseOwner([42; 32]);
+    const RESERVATIONS_KEY: Key = Key::Reservations(AccountHash::new([42; 32]));
     const KEYS: &[Key] = &[
         ACCOUNT_KEY,
         HASH_KEY,
@@ -1086,6 +1600,14 @@ mod tests {
         UNBOND_KEY,
         CHAINSPEC_REGISTRY_KEY,
         CHECKSUM_REGISTRY_KEY,
+        BLOCK_EVENTS_KEY,
+        SORTED_INDEX_KEY,
+        NAMESPACE_KEY,
+        SCHEDULED_CALLBACK_KEY,
+        BLOCK_GAS_UTILIZATION_KEY,
+        ADMINISTRATOR_REGISTRY_KEY,
+        PURSE_OWNER_KEY,
+        RESERVATIONS_KEY,
     ];
     const HEX_STRING: &str = "2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a";
 
@@ -1202,6 +1724,44 @@ mod tests {
                 base16::encode_lower(&PADDING_BYTES),
             )
         );
+        assert_eq!(
+            format!("{}", BLOCK_EVENTS_KEY),
+            format!("Key::BlockEvents({})", base16::encode_lower(&PADDING_BYTES))
+        );
+        assert_eq!(
+            format!("{}", SORTED_INDEX_KEY),
+            format!("Key::SortedIndex({})", HEX_STRING)
+        );
+        assert_eq!(
+            format!("{}", NAMESPACE_KEY),
+            format!("Key::Namespace({})", HEX_STRING)
+        );
+        assert_eq!(
+            format!("{}", SCHEDULED_CALLBACK_KEY),
+            format!("Key::ScheduledCallback({})", HEX_STRING)
+        );
+        assert_eq!(
+            format!("{}", BLOCK_GAS_UTILIZATION_KEY),
+            format!(
+                "Key::BlockGasUtilization({})",
+                base16::encode_lower(&PADDING_BYTES)
+            )
+        );
+        assert_eq!(
+            format!("{}", ADMINISTRATOR_REGISTRY_KEY),
+            format!(
+                "Key::AdministratorRegistry({})",
+                base16::encode_lower(&PADDING_BYTES)
+            )
+        );
+        assert_eq!(
+            format!("{}", PURSE_OWNER_KEY),
+            format!("Key::PurseOwner({})", HEX_STRING)
+        );
+        assert_eq!(
+            format!("{}", RESERVATIONS_KEY),
+            format!("Key::Reservations({})", HEX_STRING)
+        );
     }
 
     #[test]
@@ -1353,6 +1913,34 @@ mod tests {
             .unwrap_err()
             .to_string()
             .starts_with("checksum-registry-key from string error: "));
+        assert!(Key::from_formatted_str(BLOCK_EVENTS_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("block-events-key from string error: "));
+        assert!(Key::from_formatted_str(SCHEDULED_CALLBACK_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("scheduled-callback-key from string error: "));
+        assert!(Key::from_formatted_str(BLOCK_GAS_UTILIZATION_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("block-gas-utilization-key from string error: "));
+        assert!(Key::from_formatted_str(ADMINISTRATOR_REGISTRY_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("administrator-registry-key from string error: "));
+        assert!(Key::from_formatted_str(PURSE_OWNER_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("purse-owner-key from string error: "));
+        assert!(Key::from_formatted_str(RESERVATIONS_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("reservations-key from string error: "));
+        assert!(Key::from_formatted_str(SORTED_INDEX_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("sorted-index-key from string error: "));
         let invalid_prefix = "a-0000000000000000000000000000000000000000000000000000000000000000";
         assert_eq!(
             Key::from_formatted_str(invalid_prefix)
@@ -1412,6 +2000,28 @@ mod tests {
                 "ChecksumRegistry":
                     format!("checksum-registry-{}", base16::encode_lower(&PADDING_BYTES))
             }),
+            json!({
+                "BlockEvents": format!("block-events-{}", base16::encode_lower(&PADDING_BYTES))
+            }),
+            json!({ "SortedIndex": format!("sorted-index-{}", HEX_STRING) }),
+            json!({ "Namespace": format!("namespace-{}", HEX_STRING) }),
+            json!({ "ScheduledCallback": format!("scheduled-callback-{}", HEX_STRING) }),
+            json!({
+                "BlockGasUtilization":
+                    format!(
+                        "block-gas-utilization-{}",
+                        base16::encode_lower(&PADDING_BYTES)
+                    )
+            }),
+            json!({
+                "AdministratorRegistry":
+                    format!(
+                        "administrator-registry-{}",
+                        base16::encode_lower(&PADDING_BYTES)
+                    )
+            }),
+            json!({ "PurseOwner": format!("purse-owner-{}", HEX_STRING) }),
+            json!({ "Reservations": format!("reservations-{}", HEX_STRING) }),
         ];
 
         assert_eq!(
@@ -1463,5 +2073,62 @@ mod tests {
         round_trip(&Key::Unbond(AccountHash::new(zeros)));
         round_trip(&Key::ChainspecRegistry);
         round_trip(&Key::ChecksumRegistry);
+        round_trip(&Key::BlockEvents);
+        round_trip(&Key::SortedIndex(zeros));
+        round_trip(&Key::Namespace(zeros));
+        round_trip(&Key::ScheduledCallback(zeros));
+        round_trip(&Key::BlockGasUtilization);
+        round_trip(&Key::AdministratorRegistry);
+        round_trip(&Key::PurseOwner(zeros));
+        round_trip(&Key::Reservations(AccountHash::new(zeros)));
+    }
+
+    #[test]
+    fn sorted_index_keys_should_be_ordered_by_entry_key() {
+        let seed_uref = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        let other_seed_uref = URef::new([43; 32], AccessRights::READ_ADD_WRITE);
+        let prefix = Key::sorted_index_prefix(seed_uref);
+
+        let entry_keys = [0, 1, 255, 256, u64::MAX as u128, u128::MAX];
+        let keys: Vec<Key> = entry_keys
+            .iter()
+            .map(|entry_key| Key::sorted_index(seed_uref, *entry_key))
+            .collect();
+        let mut sorted_keys = keys.clone();
+        sorted_keys.sort();
+        assert_eq!(keys, sorted_keys);
+
+        for (key, entry_key) in keys.iter().zip(entry_keys) {
+            assert!(key.to_bytes().unwrap().starts_with(&prefix));
+            assert_eq!(key.as_sorted_index_entry_key(), Some(entry_key));
+        }
+        assert!(!Key::sorted_index(other_seed_uref, 0)
+            .to_bytes()
+            .unwrap()
+            .starts_with(&prefix));
+        assert_eq!(HASH_KEY.as_sorted_index_entry_key(), None);
+    }
+
+    #[test]
+    fn namespace_items_should_share_prefix_of_their_generation() {
+        let seed_uref = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        let prefix = Key::namespace_prefix(seed_uref, 0);
+
+        let item = Key::namespace_item(seed_uref, 0, b"item");
+        assert!(item.to_bytes().unwrap().starts_with(&prefix));
+        assert_ne!(item, Key::namespace_item(seed_uref, 0, b"other item"));
+        assert!(!Key::namespace_item(seed_uref, 1, b"item")
+            .to_bytes()
+            .unwrap()
+            .starts_with(&prefix));
+
+        let retired = Key::retired_namespace(seed_uref, 0);
+        assert!(retired
+            .to_bytes()
+            .unwrap()
+            .starts_with(&Key::retired_namespaces_prefix()));
+        assert_eq!(retired.as_retired_namespace_prefix(), Some(prefix));
+        assert_eq!(item.as_retired_namespace_prefix(), None);
+        assert_eq!(HASH_KEY.as_retired_namespace_prefix(), None);
     }
 }
@@ -29,6 +29,7 @@ pub mod bytesrepr;
 pub mod checksummed_hex;
 mod cl_type;
 mod cl_value;
+mod contract_event;
 mod contract_wasm;
 pub mod contracts;
 pub mod crypto;
@@ -37,11 +38,16 @@ mod era_id;
 mod execution_result;
 #[cfg(any(feature = "std-fs-io", test))]
 pub mod file_utils;
+#[cfg(fuzzing)]
+pub mod fuzzing;
 mod gas;
 #[cfg(any(feature = "testing", feature = "gens", test))]
 pub mod gens;
+mod json_canonicalization;
+pub mod json_compatibility;
 mod json_pretty_printer;
 mod key;
+mod merkle_proof;
 mod motes;
 mod named_key;
 mod phase;
@@ -67,6 +73,7 @@ pub use api_error::ApiError;
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
 pub use cl_type::{named_key_type, CLType, CLTyped};
 pub use cl_value::{cl_value_to_json, CLTypeMismatch, CLValue, CLValueError};
+pub use contract_event::{ContractEvent, CONTRACT_EVENT_NAME_MAX_LENGTH};
 pub use contract_wasm::{ContractWasm, ContractWasmHash};
 #[doc(inline)]
 pub use contracts::{
@@ -80,12 +87,15 @@ pub use execution_result::{
     ExecutionEffect, ExecutionResult, OpKind, Operation, Transform, TransformEntry,
 };
 pub use gas::Gas;
+pub use json_canonicalization::{canonical_json, CanonicalJsonVersion};
 pub use json_pretty_printer::json_pretty_print;
 #[doc(inline)]
 pub use key::{
-    DictionaryAddr, FromStrError as KeyFromStrError, HashAddr, Key, KeyTag, BLAKE2B_DIGEST_LENGTH,
-    DICTIONARY_ITEM_KEY_MAX_LENGTH, KEY_DICTIONARY_LENGTH, KEY_HASH_LENGTH,
+    DictionaryAddr, FromStrError as KeyFromStrError, HashAddr, Key, KeyTag, NamespaceAddr,
+    ScheduledCallbackAddr, SortedIndexAddr, BLAKE2B_DIGEST_LENGTH, DICTIONARY_ITEM_KEY_MAX_LENGTH,
+    KEY_DICTIONARY_LENGTH, KEY_HASH_LENGTH, KEY_SCHEDULED_CALLBACK_LENGTH,
 };
+pub use merkle_proof::{verify_merkle_proof, MerkleProofError};
 pub use motes::Motes;
 pub use named_key::NamedKey;
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
@@ -99,8 +109,8 @@ pub use tagged::Tagged;
 pub use timestamp::serde_option_time_diff;
 pub use timestamp::{TimeDiff, Timestamp};
 pub use transfer::{
-    DeployHash, FromStrError as TransferFromStrError, Transfer, TransferAddr, DEPLOY_HASH_LENGTH,
-    TRANSFER_ADDR_LENGTH,
+    DeployHash, FromStrError as TransferFromStrError, SystemTransferKind, Transfer, TransferAddr,
+    DEPLOY_HASH_LENGTH, TRANSFER_ADDR_LENGTH,
 };
 pub use transfer_result::{TransferResult, TransferredTo};
 pub use uref::{
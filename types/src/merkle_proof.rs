@@ -0,0 +1,312 @@
+//! Verification of Merkle proofs of the entries of global state.
+//!
+//! A node answers a query of global state with proofs in the form of serialized
+//! `TrieMerkleProof<Key, StoredValue>`s of the execution engine.  [`verify_merkle_proof`] checks
+//! such a proof against a trusted state root hash without depending on the engine.
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+
+use crate::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG},
+    crypto, Key, StoredValue, BLAKE2B_DIGEST_LENGTH,
+};
+
+type Hash = [u8; BLAKE2B_DIGEST_LENGTH];
+
+const PROOF_STEP_NODE_TAG: u8 = 0;
+const PROOF_STEP_EXTENSION_TAG: u8 = 1;
+const TRIE_LEAF_TAG: u8 = 0;
+const TRIE_NODE_TAG: u8 = 1;
+const TRIE_EXTENSION_TAG: u8 = 2;
+const LEAF_POINTER_TAG: u8 = 0;
+const NODE_POINTER_TAG: u8 = 1;
+const RADIX: usize = 256;
+
+/// Leaves longer than this are hashed as a Merkle tree of chunks of this size.
+const CHUNK_SIZE_BYTES: usize = 8 * 1024 * 1024;
+
+/// Error verifying a Merkle proof of an entry of global state.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum MerkleProofError {
+    /// The proof could not be deserialized.
+    Bytesrepr(bytesrepr::Error),
+    /// The trie nodes of the proof are not on the path of the proven key.
+    PathMismatch,
+    /// The root hash computed from the proof doesn't match the trusted state root hash.
+    UnexpectedRootHash,
+}
+
+impl Display for MerkleProofError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            MerkleProofError::Bytesrepr(error) => {
+                write!(formatter, "failed to deserialize Merkle proof: {}", error)
+            }
+            MerkleProofError::PathMismatch => {
+                write!(formatter, "Merkle proof is not on the path of its key")
+            }
+            MerkleProofError::UnexpectedRootHash => {
+                write!(formatter, "Merkle proof has an unexpected root hash")
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl std::error::Error for MerkleProofError {}
+
+impl From<bytesrepr::Error> for MerkleProofError {
+    fn from(error: bytesrepr::Error) -> Self {
+        MerkleProofError::Bytesrepr(error)
+    }
+}
+
+/// A step of a proof, from the leaf up to the root.
+enum ProofStep {
+    Node {
+        hole_index: u8,
+        indexed_pointers_with_hole: Vec<(u8, (u8, Hash))>,
+    },
+    Extension {
+        affix: Bytes,
+    },
+}
+
+impl FromBytes for ProofStep {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            PROOF_STEP_NODE_TAG => {
+                let (hole_index, remainder) = u8::from_bytes(remainder)?;
+                let (indexed_pointers_with_hole, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ProofStep::Node {
+                        hole_index,
+                        indexed_pointers_with_hole,
+                    },
+                    remainder,
+                ))
+            }
+            PROOF_STEP_EXTENSION_TAG => {
+                let (affix, remainder) = Bytes::from_bytes(remainder)?;
+                Ok((ProofStep::Extension { affix }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// Verifies a Merkle proof of an entry of global state against a trusted state root hash,
+/// returning the proven key and value.
+///
+/// `proof` is the serialized `TrieMerkleProof<Key, StoredValue>` of the execution engine, as
+/// returned (hex-encoded) by the node's query RPCs.
+pub fn verify_merkle_proof(
+    state_root_hash: &[u8; BLAKE2B_DIGEST_LENGTH],
+    proof: &[u8],
+) -> Result<(Key, StoredValue), MerkleProofError> {
+    let (key, remainder) = Key::from_bytes(proof)?;
+    let (value, remainder) = StoredValue::from_bytes(remainder)?;
+    let (proof_steps, remainder) = Vec::<ProofStep>::from_bytes(remainder)?;
+    if !remainder.is_empty() {
+        return Err(bytesrepr::Error::LeftOverBytes.into());
+    }
+
+    // Walking down from the root, every step has to follow the key's path.
+    let path = key.to_bytes()?;
+    let mut depth = 0;
+    for proof_step in proof_steps.iter().rev() {
+        let on_path = match proof_step {
+            ProofStep::Node { hole_index, .. } => path.get(depth) == Some(hole_index),
+            ProofStep::Extension { affix } => path
+                .get(depth..)
+                .map_or(false, |remaining_path| remaining_path.starts_with(affix)),
+        };
+        if !on_path {
+            return Err(MerkleProofError::PathMismatch);
+        }
+        depth += match proof_step {
+            ProofStep::Node { .. } => 1,
+            ProofStep::Extension { affix } => affix.len(),
+        };
+    }
+
+    // Walking up from the leaf, every step is hashed with the pointer to the one before it.
+    let mut leaf_bytes =
+        Vec::with_capacity(1 + key.serialized_length() + value.serialized_length());
+    leaf_bytes.push(TRIE_LEAF_TAG);
+    key.write_bytes(&mut leaf_bytes)?;
+    value.write_bytes(&mut leaf_bytes)?;
+    let mut hash = hash_trie_leaf(&leaf_bytes);
+    let mut pointer_tag = LEAF_POINTER_TAG;
+    for proof_step in &proof_steps {
+        let mut bytes = Vec::new();
+        match proof_step {
+            ProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                let mut pointers = [None; RADIX];
+                for (index, pointer) in indexed_pointers_with_hole {
+                    pointers[usize::from(*index)] = Some(*pointer);
+                }
+                pointers[usize::from(*hole_index)] = Some((pointer_tag, hash));
+                bytes.push(TRIE_NODE_TAG);
+                for pointer in pointers.iter() {
+                    match pointer {
+                        Some((tag, pointer_hash)) => {
+                            bytes.push(OPTION_SOME_TAG);
+                            bytes.push(*tag);
+                            bytes.extend_from_slice(pointer_hash);
+                        }
+                        None => bytes.push(OPTION_NONE_TAG),
+                    }
+                }
+            }
+            ProofStep::Extension { affix } => {
+                bytes.push(TRIE_EXTENSION_TAG);
+                affix.write_bytes(&mut bytes)?;
+                bytes.push(pointer_tag);
+                bytes.extend_from_slice(&hash);
+            }
+        }
+        hash = crypto::blake2b(&bytes);
+        pointer_tag = NODE_POINTER_TAG;
+    }
+
+    if hash != *state_root_hash {
+        return Err(MerkleProofError::UnexpectedRootHash);
+    }
+    Ok((key, value))
+}
+
+/// Hashes a serialized trie leaf the way the execution engine does, splitting leaves larger than
+/// [`CHUNK_SIZE_BYTES`] into chunks whose hashes form a Merkle tree.
+fn hash_trie_leaf(bytes: &[u8]) -> Hash {
+    if bytes.len() <= CHUNK_SIZE_BYTES {
+        return crypto::blake2b(bytes);
+    }
+
+    let mut hashes: Vec<Hash> = bytes
+        .chunks(CHUNK_SIZE_BYTES)
+        .map(crypto::blake2b)
+        .collect();
+    let leaf_count = hashes.len() as u64;
+    while hashes.len() > 1 {
+        hashes = hashes
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                _ => pair[0],
+            })
+            .collect();
+    }
+
+    // The root is hashed with the leaf count, prefixed with a chunk of zeros.
+    let zeros = [0u8; 1024];
+    let mut hasher = new_hasher();
+    for _ in 0..CHUNK_SIZE_BYTES / zeros.len() {
+        hasher.update(zeros);
+    }
+    hasher.update(leaf_count.to_le_bytes());
+    hasher.update(hashes[0]);
+    finalize(hasher)
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = new_hasher();
+    hasher.update(left);
+    hasher.update(right);
+    finalize(hasher)
+}
+
+fn new_hasher() -> VarBlake2b {
+    // NOTE: Assumed safe as `BLAKE2B_DIGEST_LENGTH` is a valid value for a hasher
+    VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).expect("should create hasher")
+}
+
+fn finalize(hasher: VarBlake2b) -> Hash {
+    let mut result = [0; BLAKE2B_DIGEST_LENGTH];
+    hasher.finalize_variable(|slice| {
+        result.copy_from_slice(slice);
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CLValue;
+
+    /// A proof of `Key::Hash([1; 32])` holding `CLValue::from_t(42u64)`, under a root node with a
+    /// sibling node at index 0, an extension of `[1, 1]`, and a node with a sibling leaf at index 2.
+    const PROOF: &str = "01010101010101010101010101010101010101010101010101010101010101010100080000\
+        002a00000000000000050300000000010100000002008928aae63c84d87ea098564d1e03ad813f107add474e56aed\
+        d286349c0c03ea40102000000010100010100000000016e5c1f45cbaf19f94230ba3501c378a5335af71a331b5b5a\
+        ed62792332288dc3";
+    const STATE_ROOT_HASH: &str =
+        "bfae38c85ca402d42e7c083ee9b3399455a461f0544a7b414a24f2176de57977";
+
+    fn proof() -> Vec<u8> {
+        base16::decode(PROOF).unwrap()
+    }
+
+    fn state_root_hash() -> Hash {
+        let mut state_root_hash = [0; BLAKE2B_DIGEST_LENGTH];
+        base16::decode_slice(STATE_ROOT_HASH, &mut state_root_hash).unwrap();
+        state_root_hash
+    }
+
+    #[test]
+    fn should_verify_proof() {
+        let (key, value) = verify_merkle_proof(&state_root_hash(), &proof()).unwrap();
+        assert_eq!(key, Key::Hash([1; 32]));
+        assert_eq!(value, StoredValue::CLValue(CLValue::from_t(42u64).unwrap()));
+    }
+
+    #[test]
+    fn should_not_verify_against_another_root() {
+        assert_eq!(
+            verify_merkle_proof(&[0; BLAKE2B_DIGEST_LENGTH], &proof()),
+            Err(MerkleProofError::UnexpectedRootHash)
+        );
+    }
+
+    #[test]
+    fn should_not_verify_tampered_value() {
+        let mut proof = proof();
+        // The least significant byte of the `u64` value.
+        proof[38] = 43;
+        assert_eq!(
+            verify_merkle_proof(&state_root_hash(), &proof),
+            Err(MerkleProofError::UnexpectedRootHash)
+        );
+    }
+
+    #[test]
+    fn should_not_verify_proof_off_the_key_path() {
+        let mut proof = proof();
+        // A byte of the key's path covered by the extension.
+        proof[2] = 2;
+        assert_eq!(
+            verify_merkle_proof(&state_root_hash(), &proof),
+            Err(MerkleProofError::PathMismatch)
+        );
+    }
+
+    #[test]
+    fn should_not_verify_proof_with_trailing_bytes() {
+        let mut proof = proof();
+        proof.push(0);
+        assert_eq!(
+            verify_merkle_proof(&state_root_hash(), &proof),
+            Err(MerkleProofError::Bytesrepr(bytesrepr::Error::LeftOverBytes))
+        );
+    }
+}
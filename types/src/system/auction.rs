@@ -5,6 +5,7 @@ mod delegator;
 mod entry_points;
 mod era_info;
 mod error;
+mod reservation;
 mod seigniorage_recipient;
 mod unbonding_purse;
 mod withdraw_purse;
@@ -17,6 +18,7 @@ pub use delegator::Delegator;
 pub use entry_points::auction_entry_points;
 pub use era_info::{EraInfo, SeigniorageAllocation};
 pub use error::Error;
+pub use reservation::Reservation;
 pub use seigniorage_recipient::SeigniorageRecipient;
 pub use unbonding_purse::UnbondingPurse;
 pub use withdraw_purse::WithdrawPurse;
@@ -49,5 +51,14 @@ pub type SeigniorageRecipientsSnapshot = BTreeMap<EraId, SeigniorageRecipients>;
 /// Validators and delegators mapped to their unbonding purses.
 pub type UnbondingPurses = BTreeMap<AccountHash, Vec<UnbondingPurse>>;
 
+/// Validators mapped to the delegator slots they reserved.
+pub type Reservations = BTreeMap<PublicKey, Vec<Reservation>>;
+
+/// Delegators mapped to the rewards they accrued with each validator and haven't withdrawn yet.
+pub type DelegatorRewards = BTreeMap<PublicKey, BTreeMap<PublicKey, U512>>;
+
+/// Validators mapped to the era in which they last increased their delegation rate.
+pub type DelegationRateChanges = BTreeMap<PublicKey, EraId>;
+
 /// Validators and delegators mapped to their withdraw purses.
 pub type WithdrawPurses = BTreeMap<AccountHash, Vec<WithdrawPurse>>;
@@ -227,6 +227,15 @@ impl Bid {
         Ok(updated_staked_amount)
     }
 
+    /// Moves the provided bid, along with its delegators, to a new validator public key
+    pub fn with_validator_public_key(&mut self, validator_public_key: PublicKey) -> &mut Self {
+        for delegator in self.delegators.values_mut() {
+            delegator.with_validator_public_key(validator_public_key.clone());
+        }
+        self.validator_public_key = validator_public_key;
+        self
+    }
+
     /// Updates the delegation rate of the provided bid
     pub fn with_delegation_rate(&mut self, delegation_rate: DelegationRate) -> &mut Self {
         self.delegation_rate = delegation_rate;
@@ -400,6 +409,37 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&founding_validator);
     }
 
+    #[test]
+    fn should_move_bid_and_delegators_to_new_validator_public_key() {
+        let validator_pk: PublicKey = (&SecretKey::ed25519_from_bytes([42; 32]).unwrap()).into();
+        let new_validator_pk: PublicKey =
+            (&SecretKey::ed25519_from_bytes([43; 32]).unwrap()).into();
+        let delegator_pk: PublicKey = (&SecretKey::ed25519_from_bytes([44; 32]).unwrap()).into();
+
+        let mut bid = Bid::unlocked(
+            validator_pk.clone(),
+            URef::new([42; 32], AccessRights::ADD),
+            U512::from(1000),
+            0,
+        );
+        bid.delegators_mut().insert(
+            delegator_pk.clone(),
+            Delegator::unlocked(
+                delegator_pk.clone(),
+                U512::from(10),
+                URef::new([44; 32], AccessRights::ADD),
+                validator_pk,
+            ),
+        );
+
+        bid.with_validator_public_key(new_validator_pk.clone());
+
+        assert_eq!(bid.validator_public_key(), &new_validator_pk);
+        let delegator = bid.delegators().get(&delegator_pk).unwrap();
+        assert_eq!(delegator.validator_public_key(), &new_validator_pk);
+        assert_eq!(delegator.staked_amount(), &U512::from(10));
+    }
+
     #[test]
     fn should_immediately_initialize_unlock_amounts() {
         const TIMESTAMP_MILLIS: u64 = 0;
@@ -58,6 +58,12 @@ pub const ARG_UNBONDING_DELAY: &str = "unbonding_delay";
 pub const ARG_ERA_END_TIMESTAMP_MILLIS: &str = "era_end_timestamp_millis";
 /// Named constant for `evicted_validators`;
 pub const ARG_EVICTED_VALIDATORS: &str = "evicted_validators";
+/// Named constant for `new_public_key`.
+pub const ARG_NEW_PUBLIC_KEY: &str = "new_public_key";
+/// Named constant for `reservations`.
+pub const ARG_RESERVATIONS: &str = "reservations";
+/// Named constant for `delegators`.
+pub const ARG_DELEGATORS: &str = "delegators";
 
 /// Named constant for method `get_era_validators`.
 pub const METHOD_GET_ERA_VALIDATORS: &str = "get_era_validators";
@@ -81,6 +87,18 @@ pub const METHOD_DISTRIBUTE: &str = "distribute";
 pub const METHOD_READ_ERA_ID: &str = "read_era_id";
 /// Named constant for method `activate_bid`.
 pub const METHOD_ACTIVATE_BID: &str = "activate_bid";
+/// Named constant for method `change_bid_public_key`.
+pub const METHOD_CHANGE_BID_PUBLIC_KEY: &str = "change_bid_public_key";
+/// Named constant for method `add_reservations`.
+pub const METHOD_ADD_RESERVATIONS: &str = "add_reservations";
+/// Named constant for method `cancel_reservations`.
+pub const METHOD_CANCEL_RESERVATIONS: &str = "cancel_reservations";
+/// Named constant for method `withdraw_delegator_rewards`.
+pub const METHOD_WITHDRAW_DELEGATOR_REWARDS: &str = "withdraw_delegator_rewards";
+
+/// Name of the event recorded for each validator a delegator withdraws rewards from, holding the
+/// delegator's key, the validator's key and the withdrawn amount.
+pub const EVENT_DELEGATOR_REWARD_WITHDRAWN: &str = "delegator_reward_withdrawn";
 
 /// Storage for `EraId`.
 pub const ERA_ID_KEY: &str = "era_id";
@@ -88,6 +106,13 @@ pub const ERA_ID_KEY: &str = "era_id";
 pub const ERA_END_TIMESTAMP_MILLIS_KEY: &str = "era_end_timestamp_millis";
 /// Storage for `SeigniorageRecipientsSnapshot`.
 pub const SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY: &str = "seigniorage_recipients_snapshot";
+/// Storage for the public keys of validators which changed their bid public key, mapped to the
+/// new public keys.
+pub const VALIDATOR_PUBLIC_KEY_BRIDGES_KEY: &str = "validator_public_key_bridges";
+/// Storage for the rewards delegators accrued since they last withdrew them.
+pub const DELEGATOR_REWARDS_KEY: &str = "delegator_rewards";
+/// Storage for the era in which each validator last increased its delegation rate.
+pub const DELEGATION_RATE_CHANGES_KEY: &str = "delegation_rate_changes";
 /// Total validator slots allowed.
 pub const VALIDATOR_SLOTS_KEY: &str = "validator_slots";
 /// Amount of auction delay.
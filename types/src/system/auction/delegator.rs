@@ -89,6 +89,12 @@ impl Delegator {
         &self.validator_public_key
     }
 
+    /// Updates the delegatee of the provided delegator
+    pub fn with_validator_public_key(&mut self, validator_public_key: PublicKey) -> &mut Self {
+        self.validator_public_key = validator_public_key;
+        self
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -327,6 +327,44 @@ pub enum Error {
     /// assert_eq!(49, Error::TransferToAdministrator as u8);
     /// ```
     TransferToAdministrator = 49,
+    /// A bid already exists for the public key a validator attempted to move its bid to.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(50, Error::ValidatorBidExistsAlready as u8);
+    /// ```
+    ValidatorBidExistsAlready = 50,
+    /// The reserved delegator slots of a validator, along with its delegators occupying
+    /// unreserved slots, would exceed the maximum number of delegators per validator.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(51, Error::ExceededReservationsLimit as u8);
+    /// ```
+    ExceededReservationsLimit = 51,
+    /// Reservation was not found.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(52, Error::ReservationNotFound as u8);
+    /// ```
+    ReservationNotFound = 52,
+    /// A validator attempted to increase its delegation rate before the cooldown since its last
+    /// increase elapsed.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(53, Error::DelegationRateChangeCooldown as u8);
+    /// ```
+    DelegationRateChangeCooldown = 53,
+    /// A validator attempted to increase its delegation rate by more than allowed at once.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(54, Error::DelegationRateIncreaseTooLarge as u8);
+    /// ```
+    DelegationRateIncreaseTooLarge = 54,
+    /// The called auction entry point is disabled by the chainspec.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(55, Error::EntryPointDisabled as u8);
+    /// ```
+    EntryPointDisabled = 55,
 }
 
 impl Display for Error {
@@ -382,6 +420,12 @@ impl Display for Error {
             Error::AuctionBidsDisabled => formatter.write_str("Auction bids are disabled"),
             Error::GetAccumulationPurse => formatter.write_str("Get accumulation purse error"),
             Error::TransferToAdministrator => formatter.write_str("Transfer to administrator error"),
+            Error::ValidatorBidExistsAlready => formatter.write_str("Validator bid with given public key already exists"),
+            Error::ExceededReservationsLimit => formatter.write_str("The amount of reserved delegator slots per validator has been exceeded"),
+            Error::ReservationNotFound => formatter.write_str("Reservation not found"),
+            Error::DelegationRateChangeCooldown => formatter.write_str("Delegation rate was increased too recently"),
+            Error::DelegationRateIncreaseTooLarge => formatter.write_str("Delegation rate increase too large"),
+            Error::EntryPointDisabled => formatter.write_str("Auction entry point is disabled"),
         }
     }
 }
@@ -463,6 +507,20 @@ impl TryFrom<u8> for Error {
             d if d == Error::AuctionBidsDisabled as u8 => Ok(Error::AuctionBidsDisabled),
             d if d == Error::GetAccumulationPurse as u8 => Ok(Error::GetAccumulationPurse),
             d if d == Error::TransferToAdministrator as u8 => Ok(Error::TransferToAdministrator),
+            d if d == Error::ValidatorBidExistsAlready as u8 => {
+                Ok(Error::ValidatorBidExistsAlready)
+            }
+            d if d == Error::ExceededReservationsLimit as u8 => {
+                Ok(Error::ExceededReservationsLimit)
+            }
+            d if d == Error::ReservationNotFound as u8 => Ok(Error::ReservationNotFound),
+            d if d == Error::DelegationRateChangeCooldown as u8 => {
+                Ok(Error::DelegationRateChangeCooldown)
+            }
+            d if d == Error::DelegationRateIncreaseTooLarge as u8 => {
+                Ok(Error::DelegationRateIncreaseTooLarge)
+            }
+            d if d == Error::EntryPointDisabled as u8 => Ok(Error::EntryPointDisabled),
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -0,0 +1,111 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "datasize")]
+use datasize::DataSize;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, PublicKey,
+};
+
+/// Represents a delegator slot a validator holds for a given delegator.
+///
+/// A reserved slot counts against the validator's delegator limit whether or not the delegator has
+/// delegated yet, and allows the delegator to delegate even once all other slots are taken.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Reservation {
+    delegator_public_key: PublicKey,
+    validator_public_key: PublicKey,
+}
+
+impl Reservation {
+    /// Creates a new [`Reservation`]
+    pub fn new(delegator_public_key: PublicKey, validator_public_key: PublicKey) -> Self {
+        Reservation {
+            delegator_public_key,
+            validator_public_key,
+        }
+    }
+
+    /// Returns public key of the delegator the slot is reserved for.
+    pub fn delegator_public_key(&self) -> &PublicKey {
+        &self.delegator_public_key
+    }
+
+    /// Returns public key of the validator holding the slot.
+    pub fn validator_public_key(&self) -> &PublicKey {
+        &self.validator_public_key
+    }
+
+    /// Updates the validator holding the slot
+    pub fn with_validator_public_key(&mut self, validator_public_key: PublicKey) -> &mut Self {
+        self.validator_public_key = validator_public_key;
+        self
+    }
+}
+
+impl CLTyped for Reservation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Reservation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.delegator_public_key.to_bytes()?);
+        buffer.extend(self.validator_public_key.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.delegator_public_key.serialized_length()
+            + self.validator_public_key.serialized_length()
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.delegator_public_key.write_bytes(writer)?;
+        self.validator_public_key.write_bytes(writer)?;
+        Ok(())
+    }
+}
+
+impl FromBytes for Reservation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (delegator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
+        let (validator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
+        Ok((
+            Reservation {
+                delegator_public_key,
+                validator_public_key,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bytesrepr, system::auction::Reservation, PublicKey, SecretKey};
+
+    #[test]
+    fn serialization_roundtrip() {
+        let delegator_public_key: PublicKey = PublicKey::from(
+            &SecretKey::ed25519_from_bytes([42; SecretKey::ED25519_LENGTH]).unwrap(),
+        );
+        let validator_public_key: PublicKey = PublicKey::from(
+            &SecretKey::ed25519_from_bytes([43; SecretKey::ED25519_LENGTH]).unwrap(),
+        );
+        let reservation = Reservation::new(delegator_public_key, validator_public_key);
+        bytesrepr::test_serialization_roundtrip(&reservation);
+    }
+}
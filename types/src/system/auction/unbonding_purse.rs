@@ -95,6 +95,16 @@ impl UnbondingPurse {
     pub fn new_validator(&self) -> &Option<PublicKey> {
         &self.new_validator
     }
+
+    /// Moves the unbonding request to a new validator public key.  A request made by the validator
+    /// itself moves its unbonder public key as well.
+    pub fn with_validator_public_key(&mut self, validator_public_key: PublicKey) -> &mut Self {
+        if self.is_validator() {
+            self.unbonder_public_key = validator_public_key.clone();
+        }
+        self.validator_public_key = validator_public_key;
+        self
+    }
 }
 
 impl ToBytes for UnbondingPurse {
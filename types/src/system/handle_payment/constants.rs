@@ -6,6 +6,10 @@ pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_ACCOUNT: &str = "account";
 /// Named constant for `target`.
 pub const ARG_TARGET: &str = "target";
+/// Named constant for `next_era_id`.
+pub const ARG_NEXT_ERA_ID: &str = "next_era_id";
+/// Named constant for `validators`.
+pub const ARG_VALIDATORS: &str = "validators";
 
 /// Named constant for method `get_payment_purse`.
 pub const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
@@ -35,3 +39,7 @@ pub const REFUND_PURSE_KEY: &str = "refund_purse";
 /// This purse is used when `fee_elimination` config is set to `Accumulate` which makes sense for
 /// some private chains.
 pub const ACCUMULATION_PURSE_KEY: &str = "accumulation_purse";
+/// The uref name under which the refund handling the network is running with is recorded.
+pub const REFUND_HANDLING_KEY: &str = "refund_handling";
+/// The uref name under which the fee handling the network is running with is recorded.
+pub const FEE_HANDLING_KEY: &str = "fee_handling";
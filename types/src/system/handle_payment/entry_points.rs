@@ -2,8 +2,9 @@ use alloc::boxed::Box;
 
 use crate::{
     system::handle_payment::{
-        ARG_ACCOUNT, ARG_AMOUNT, ARG_PURSE, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
-        METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE,
+        ARG_ACCOUNT, ARG_AMOUNT, ARG_NEXT_ERA_ID, ARG_PURSE, ARG_VALIDATORS,
+        METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE, METHOD_GET_REFUND_PURSE,
+        METHOD_SET_REFUND_PURSE,
     },
     CLType, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter,
 };
@@ -55,7 +56,10 @@ pub fn handle_payment_entry_points() -> EntryPoints {
 
     let distribute_accumulated_fees = EntryPoint::new(
         METHOD_DISTRIBUTE_ACCUMULATED_FEES,
-        vec![],
+        vec![
+            Parameter::new(ARG_NEXT_ERA_ID, CLType::U64),
+            Parameter::new(ARG_VALIDATORS, CLType::List(Box::new(CLType::PublicKey))),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -10,6 +10,8 @@ pub const ARG_TO: &str = "to";
 pub const ARG_SOURCE: &str = "source";
 /// Named constant for `target`.
 pub const ARG_TARGET: &str = "target";
+/// Named constant for `administrators`.
+pub const ARG_ADMINISTRATORS: &str = "administrators";
 /// Named constant for `round_seigniorage_rate` used in installer.
 pub const ARG_ROUND_SEIGNIORAGE_RATE: &str = "round_seigniorage_rate";
 
@@ -27,6 +29,8 @@ pub const METHOD_TRANSFER: &str = "transfer";
 pub const METHOD_READ_BASE_ROUND_REWARD: &str = "read_base_round_reward";
 /// Named constant for method `mint_into_existing_purse`.
 pub const METHOD_MINT_INTO_EXISTING_PURSE: &str = "mint_into_existing_purse";
+/// Named constant for method `update_administrators`.
+pub const METHOD_UPDATE_ADMINISTRATORS: &str = "update_administrators";
 
 /// Storage for mint contract hash.
 pub const HASH_KEY: &str = "mint_hash";
@@ -36,5 +40,7 @@ pub const ACCESS_KEY: &str = "mint_access";
 pub const BASE_ROUND_REWARD_KEY: &str = "mint_base_round_reward";
 /// Storage for mint total supply key.
 pub const TOTAL_SUPPLY_KEY: &str = "total_supply";
+/// Storage for the cumulative amount of token burned by the mint.
+pub const TOTAL_BURNED_KEY: &str = "total_burned";
 /// Storage for mint round seigniorage rate.
 pub const ROUND_SEIGNIORAGE_RATE_KEY: &str = "round_seigniorage_rate";
@@ -3,9 +3,10 @@ use alloc::boxed::Box;
 use crate::{
     contracts::Parameters,
     system::mint::{
-        ARG_AMOUNT, ARG_ID, ARG_PURSE, ARG_SOURCE, ARG_TARGET, ARG_TO, METHOD_BALANCE,
-        METHOD_CREATE, METHOD_MINT, METHOD_MINT_INTO_EXISTING_PURSE, METHOD_READ_BASE_ROUND_REWARD,
-        METHOD_REDUCE_TOTAL_SUPPLY, METHOD_TRANSFER,
+        ARG_ADMINISTRATORS, ARG_AMOUNT, ARG_ID, ARG_PURSE, ARG_SOURCE, ARG_TARGET, ARG_TO,
+        METHOD_BALANCE, METHOD_CREATE, METHOD_MINT, METHOD_MINT_INTO_EXISTING_PURSE,
+        METHOD_READ_BASE_ROUND_REWARD, METHOD_REDUCE_TOTAL_SUPPLY, METHOD_TRANSFER,
+        METHOD_UPDATE_ADMINISTRATORS,
     },
     CLType, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter,
 };
@@ -98,5 +99,17 @@ pub fn mint_entry_points() -> EntryPoints {
     );
     entry_points.add_entry_point(entry_point);
 
+    let entry_point = EntryPoint::new(
+        METHOD_UPDATE_ADMINISTRATORS,
+        vec![Parameter::new(
+            ARG_ADMINISTRATORS,
+            CLType::List(Box::new(CLType::ByteArray(32))),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
     entry_points
 }
@@ -153,6 +153,32 @@ pub enum Error {
     /// # use casper_types::system::mint::Error;
     /// assert_eq!(22, Error::DisabledUnrestrictedTransfers as u8);
     DisabledUnrestrictedTransfers = 22,
+    /// Transfer would leave the source purse with a non-zero balance below the existential
+    /// deposit.
+    /// ```
+    /// # use casper_types::system::mint::Error;
+    /// assert_eq!(23, Error::BelowExistentialDeposit as u8);
+    /// ```
+    BelowExistentialDeposit = 23,
+    /// The administrative accounts weren't updated by a deploy approved by more than half of the
+    /// current administrators.
+    /// ```
+    /// # use casper_types::system::mint::Error;
+    /// assert_eq!(24, Error::InsufficientAdministratorApprovals as u8);
+    /// ```
+    InsufficientAdministratorApprovals = 24,
+    /// An attempt was made to replace the administrative accounts with an empty set.
+    /// ```
+    /// # use casper_types::system::mint::Error;
+    /// assert_eq!(25, Error::EmptyAdministrators as u8);
+    /// ```
+    EmptyAdministrators = 25,
+    /// A named key of the mint holds a key of an unexpected variant.
+    /// ```
+    /// # use casper_types::system::mint::Error;
+    /// assert_eq!(26, Error::UnexpectedKeyVariant as u8);
+    /// ```
+    UnexpectedKeyVariant = 26,
 
     #[cfg(test)]
     #[doc(hidden)]
@@ -209,6 +235,12 @@ impl TryFrom<u8> for Error {
             d if d == Error::DisabledUnrestrictedTransfers as u8 => {
                 Ok(Error::DisabledUnrestrictedTransfers)
             }
+            d if d == Error::BelowExistentialDeposit as u8 => Ok(Error::BelowExistentialDeposit),
+            d if d == Error::InsufficientAdministratorApprovals as u8 => {
+                Ok(Error::InsufficientAdministratorApprovals)
+            }
+            d if d == Error::EmptyAdministrators as u8 => Ok(Error::EmptyAdministrators),
+            d if d == Error::UnexpectedKeyVariant as u8 => Ok(Error::UnexpectedKeyVariant),
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -269,6 +301,14 @@ impl Display for Error {
             Error::DisabledUnrestrictedTransfers => {
                 formatter.write_str("Disabled unrestricted transfers")
             }
+            Error::BelowExistentialDeposit => {
+                formatter.write_str("Balance would fall below the existential deposit")
+            }
+            Error::InsufficientAdministratorApprovals => {
+                formatter.write_str("Insufficient administrator approvals")
+            }
+            Error::EmptyAdministrators => formatter.write_str("Empty administrators"),
+            Error::UnexpectedKeyVariant => formatter.write_str("Unexpected key variant"),
             #[cfg(test)]
             Error::Sentinel => formatter.write_str("Sentinel error"),
         }
@@ -21,7 +21,7 @@ use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Seria
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes},
-    checksummed_hex, CLType, CLTyped, URef, U512,
+    checksummed_hex, CLType, CLTyped, PublicKey, URef, U512,
 };
 
 /// The length of a deploy hash.
@@ -131,6 +131,29 @@ impl Distribution<DeployHash> for Standard {
     }
 }
 
+/// The kind of a transfer made by the system rather than by a deploy's own code.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[repr(u64)]
+pub enum SystemTransferKind {
+    /// Unspent payment returned from the payment purse to the refund purse or to the deploy's
+    /// account.
+    PaymentRefund = 0,
+    /// The fee for a deploy moved from the payment purse to the proposer or to the accumulation
+    /// purse.
+    PaymentFee = 1,
+}
+
+impl SystemTransferKind {
+    fn from_id(id: u64) -> Option<Self> {
+        match id {
+            0 => Some(SystemTransferKind::PaymentRefund),
+            1 => Some(SystemTransferKind::PaymentFee),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a transfer from one purse to another
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize, Default)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -179,6 +202,44 @@ impl Transfer {
             id,
         }
     }
+
+    /// Creates a [`Transfer`] made by the system on behalf of the deploy `deploy_hash`.
+    ///
+    /// A system transfer is recorded as made from the system account, with the kind in place of
+    /// the user-defined id, which keeps its encoding that of any other transfer.
+    pub fn new_system(
+        deploy_hash: DeployHash,
+        kind: SystemTransferKind,
+        to: Option<AccountHash>,
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Self {
+        Transfer {
+            deploy_hash,
+            from: PublicKey::System.to_account_hash(),
+            to,
+            source,
+            target,
+            amount,
+            gas: U512::zero(),
+            id: Some(kind as u64),
+        }
+    }
+
+    /// Returns the kind of the transfer if it was made by the system, or `None` if it was made by
+    /// a deploy's own code.
+    pub fn system_kind(&self) -> Option<SystemTransferKind> {
+        if self.from != PublicKey::System.to_account_hash() {
+            return None;
+        }
+        self.id.and_then(SystemTransferKind::from_id)
+    }
+
+    /// Returns `true` if the transfer was made by the system rather than by a deploy's own code.
+    pub fn is_system(&self) -> bool {
+        self.system_kind().is_some()
+    }
 }
 
 impl FromBytes for Transfer {
@@ -467,6 +528,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_distinguish_system_transfers() {
+        let mut rng = crate::testing::TestRng::new();
+        let deploy_hash: DeployHash = rng.gen();
+        let account_hash = AccountHash::new(rng.gen());
+
+        let user_transfer = Transfer::new(
+            deploy_hash,
+            account_hash,
+            None,
+            rng.gen(),
+            rng.gen(),
+            U512::one(),
+            U512::zero(),
+            Some(SystemTransferKind::PaymentFee as u64),
+        );
+        assert_eq!(user_transfer.system_kind(), None);
+        assert!(!user_transfer.is_system());
+
+        for kind in [
+            SystemTransferKind::PaymentRefund,
+            SystemTransferKind::PaymentFee,
+        ] {
+            let system_transfer = Transfer::new_system(
+                deploy_hash,
+                kind,
+                Some(account_hash),
+                rng.gen(),
+                rng.gen(),
+                U512::one(),
+            );
+            assert_eq!(system_transfer.system_kind(), Some(kind));
+            bytesrepr::test_serialization_roundtrip(&system_transfer);
+        }
+    }
+
     #[test]
     fn transfer_addr_from_str() {
         let transfer_address = TransferAddr([4; 32]);
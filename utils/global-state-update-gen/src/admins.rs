@@ -6,7 +6,9 @@ use casper_types::{
 use clap::ArgMatches;
 use rand::Rng;
 
-use crate::utils::{hash_from_str, print_entry};
+use crate::utils::{
+    hash_from_str, is_dangerous_override, print_allow_dangerous_overrides, print_entry,
+};
 
 const DEFAULT_MAIN_PURSE_ACCESS_RIGHTS: AccessRights = AccessRights::READ_ADD_WRITE;
 
@@ -29,9 +31,23 @@ pub(crate) fn generate_admins(matches: &ArgMatches<'_>) {
 
     let admin_values = matches.values_of("admin").expect("at least one argument");
 
+    let total_supply_key = {
+        let mint_contract_hash = test_builder.get_mint_contract_hash();
+        let mint_contract = test_builder
+            .get_contract(mint_contract_hash)
+            .expect("mint system contract");
+        mint_contract
+            .named_keys()
+            .get(mint::TOTAL_SUPPLY_KEY)
+            .cloned()
+            .expect("valid key in mint named keys")
+    };
+
     let mut total_supply = test_builder.total_supply(Some(post_state_hash));
     let total_supply_before = total_supply;
 
+    let mut entries = vec![];
+
     for value in admin_values {
         let mut fields = value.split(',').peekable();
         let field1 = fields.next().unwrap();
@@ -46,11 +62,11 @@ pub(crate) fn generate_admins(matches: &ArgMatches<'_>) {
 
         let purse_balance_key = Key::Balance(main_purse.addr());
         let purse_balance_value = make_stored_clvalue(balance);
-        print_entry(&purse_balance_key, &purse_balance_value);
+        entries.push((purse_balance_key, purse_balance_value));
 
         let purse_uref_key = Key::URef(main_purse);
         let purse_uref_value = make_stored_clvalue(());
-        print_entry(&purse_uref_key, &purse_uref_value);
+        entries.push((purse_uref_key, purse_uref_value));
 
         let account_key = Key::Account(pub_key.to_account_hash());
         let account_value = {
@@ -61,13 +77,27 @@ pub(crate) fn generate_admins(matches: &ArgMatches<'_>) {
             };
             StoredValue::Account(account)
         };
-        print_entry(&account_key, &account_value);
+        entries.push((account_key, account_value));
 
         total_supply = total_supply.checked_add(balance).expect("no overflow");
     }
 
-    if total_supply == total_supply_before {
-        // Don't update total supply if it did not change
+    // Don't update total supply if it did not change
+    let total_supply_changed = total_supply != total_supply_before;
+
+    if total_supply_changed
+        || entries
+            .iter()
+            .any(|(key, _)| is_dangerous_override(key, &total_supply_key))
+    {
+        print_allow_dangerous_overrides();
+    }
+
+    for (key, value) in &entries {
+        print_entry(key, value);
+    }
+
+    if !total_supply_changed {
         return;
     }
 
@@ -76,17 +106,6 @@ pub(crate) fn generate_admins(matches: &ArgMatches<'_>) {
         total_supply_before, total_supply
     );
 
-    let total_supply_key = {
-        let mint_contract_hash = test_builder.get_mint_contract_hash();
-        let mint_contract = test_builder
-            .get_contract(mint_contract_hash)
-            .expect("mint system contract");
-        mint_contract
-            .named_keys()
-            .get(mint::TOTAL_SUPPLY_KEY)
-            .cloned()
-            .expect("valid key in mint named keys")
-    };
     let total_supply_value = make_stored_clvalue(total_supply);
     print_entry(&total_supply_key, &total_supply_value);
 }
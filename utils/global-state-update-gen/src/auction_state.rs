@@ -0,0 +1,418 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+use clap::ArgMatches;
+use serde::{Deserialize, Serialize};
+
+use casper_engine_test_support::LmdbWasmTestBuilder;
+use casper_types::{
+    system::auction::{Bids, DelegationRate, SeigniorageRecipientsSnapshot, UnbondingPurses},
+    AsymmetricType, PublicKey, StoredValue, U512,
+};
+
+use crate::{generic::state_reader::StateReader, utils::hash_from_str};
+
+/// The auction state at some global state root, in a format independent of the purses holding
+/// the staked amounts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct AuctionState {
+    /// The root hash of the global state the auction state was exported from.
+    pub state_hash: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validators: Vec<ValidatorState>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snapshot: Vec<SnapshotEra>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unbonds: Vec<UnbondState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ValidatorState {
+    pub public_key: PublicKey,
+    pub staked_amount: U512,
+    pub delegation_rate: DelegationRate,
+    pub inactive: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delegators: Vec<DelegatorState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DelegatorState {
+    pub public_key: PublicKey,
+    pub staked_amount: U512,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SnapshotEra {
+    pub era_id: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<SnapshotRecipient>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SnapshotRecipient {
+    pub public_key: PublicKey,
+    pub stake: U512,
+    pub delegation_rate: DelegationRate,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delegators: Vec<DelegatorState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UnbondState {
+    pub validator_public_key: PublicKey,
+    pub unbonder_public_key: PublicKey,
+    pub amount: U512,
+    pub era_of_creation: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_validator: Option<PublicKey>,
+}
+
+/// The accounts of a genesis, in the format of the chainspec's `accounts.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct GenesisAccounts {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<GenesisAccount>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub delegators: Vec<GenesisDelegator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct GenesisAccount {
+    pub public_key: PublicKey,
+    pub balance: U512,
+    pub validator: GenesisValidator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct GenesisValidator {
+    pub bonded_amount: U512,
+    pub delegation_rate: DelegationRate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct GenesisDelegator {
+    pub validator_public_key: PublicKey,
+    pub delegator_public_key: PublicKey,
+    pub balance: U512,
+    pub delegated_amount: U512,
+}
+
+fn delegator_states<'a>(
+    delegators: impl Iterator<Item = (&'a PublicKey, &'a U512)>,
+) -> Vec<DelegatorState> {
+    delegators
+        .map(|(public_key, staked_amount)| DelegatorState {
+            public_key: public_key.clone(),
+            staked_amount: *staked_amount,
+        })
+        .collect()
+}
+
+impl AuctionState {
+    /// Collects the auction state from the given bids, snapshot and unbonds.
+    pub fn new(
+        state_hash: String,
+        bids: &Bids,
+        snapshot: &SeigniorageRecipientsSnapshot,
+        unbonds: &UnbondingPurses,
+    ) -> Self {
+        let validators = bids
+            .iter()
+            .map(|(public_key, bid)| ValidatorState {
+                public_key: public_key.clone(),
+                staked_amount: *bid.staked_amount(),
+                delegation_rate: *bid.delegation_rate(),
+                inactive: bid.inactive(),
+                delegators: delegator_states(
+                    bid.delegators()
+                        .iter()
+                        .map(|(public_key, delegator)| (public_key, delegator.staked_amount())),
+                ),
+            })
+            .collect();
+
+        let snapshot = snapshot
+            .iter()
+            .map(|(era_id, recipients)| SnapshotEra {
+                era_id: era_id.value(),
+                recipients: recipients
+                    .iter()
+                    .map(|(public_key, recipient)| SnapshotRecipient {
+                        public_key: public_key.clone(),
+                        stake: *recipient.stake(),
+                        delegation_rate: *recipient.delegation_rate(),
+                        delegators: delegator_states(recipient.delegator_stake().iter()),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let unbonds = unbonds
+            .values()
+            .flatten()
+            .map(|unbonding_purse| UnbondState {
+                validator_public_key: unbonding_purse.validator_public_key().clone(),
+                unbonder_public_key: unbonding_purse.unbonder_public_key().clone(),
+                amount: *unbonding_purse.amount(),
+                era_of_creation: unbonding_purse.era_of_creation().value(),
+                new_validator: unbonding_purse.new_validator().clone(),
+            })
+            .collect();
+
+        AuctionState {
+            state_hash,
+            validators,
+            snapshot,
+            unbonds,
+        }
+    }
+
+    /// Reads the auction state at the state root of `reader`.
+    pub fn read<T: StateReader>(mut reader: T, state_hash: String) -> Self {
+        let bids = reader.get_bids();
+        let unbonds = reader.get_unbonds();
+        let snapshot_key = reader.get_seigniorage_recipients_key();
+        let snapshot: SeigniorageRecipientsSnapshot = match reader.query(snapshot_key) {
+            Some(StoredValue::CLValue(cl_value)) => cl_value
+                .into_t()
+                .expect("should be a seigniorage recipients snapshot"),
+            _ => panic!("seigniorage recipients snapshot should exist"),
+        };
+        AuctionState::new(state_hash, &bids, &snapshot, &unbonds)
+    }
+
+    /// Turns the stakes of the active validators and their delegators into genesis accounts,
+    /// replacing public keys according to `rekey`.
+    ///
+    /// Public keys missing from `rekey` are kept.  Every validator and delegator entry is given
+    /// `balance`, the actual balances aren't part of the auction state.  The snapshot is derived
+    /// from the bids by the genesis, and pending unbonds have no equivalent in a genesis, so both
+    /// are ignored.
+    pub fn to_genesis_accounts(
+        &self,
+        rekey: &BTreeMap<PublicKey, PublicKey>,
+        balance: U512,
+    ) -> GenesisAccounts {
+        let rekeyed = |public_key: &PublicKey| {
+            rekey
+                .get(public_key)
+                .cloned()
+                .unwrap_or_else(|| public_key.clone())
+        };
+
+        let mut accounts = vec![];
+        let mut delegators = vec![];
+        for validator in &self.validators {
+            if validator.inactive || validator.staked_amount.is_zero() {
+                continue;
+            }
+            let validator_public_key = rekeyed(&validator.public_key);
+            accounts.push(GenesisAccount {
+                public_key: validator_public_key.clone(),
+                balance,
+                validator: GenesisValidator {
+                    bonded_amount: validator.staked_amount,
+                    delegation_rate: validator.delegation_rate,
+                },
+            });
+            for delegator in &validator.delegators {
+                if delegator.staked_amount.is_zero() {
+                    continue;
+                }
+                delegators.push(GenesisDelegator {
+                    validator_public_key: validator_public_key.clone(),
+                    delegator_public_key: rekeyed(&delegator.public_key),
+                    balance,
+                    delegated_amount: delegator.staked_amount,
+                });
+            }
+        }
+
+        GenesisAccounts {
+            accounts,
+            delegators,
+        }
+    }
+}
+
+/// Parses a rekeying file mapping hex-encoded public keys to the hex-encoded public keys which
+/// should replace them.
+fn parse_rekey_map(bytes: &[u8]) -> BTreeMap<PublicKey, PublicKey> {
+    let hex_map: BTreeMap<String, String> =
+        toml::from_slice(bytes).expect("couldn't parse the rekey file");
+    let parse = |hex: &str| {
+        PublicKey::from_hex(hex.as_bytes())
+            .unwrap_or_else(|error| panic!("invalid public key {}: {}", hex, error))
+    };
+    let rekey: BTreeMap<PublicKey, PublicKey> = hex_map
+        .iter()
+        .map(|(old, new)| (parse(old), parse(new)))
+        .collect();
+
+    let new_keys: BTreeSet<&PublicKey> = rekey.values().collect();
+    if new_keys.len() != rekey.len() {
+        panic!("the rekey file maps multiple public keys to the same one");
+    }
+    rekey
+}
+
+pub(crate) fn export_auction_state(matches: &ArgMatches<'_>) {
+    let data_dir = matches.value_of("data_dir").unwrap_or(".");
+    let state_hash_str = matches.value_of("hash").unwrap();
+    let state_hash = hash_from_str(state_hash_str);
+
+    let builder = LmdbWasmTestBuilder::open_raw(data_dir, Default::default(), state_hash);
+    let auction_state = AuctionState::read(builder, state_hash_str.to_string());
+
+    print!(
+        "{}",
+        toml::to_string(&auction_state).expect("couldn't serialize the auction state")
+    );
+}
+
+pub(crate) fn import_auction_state(matches: &ArgMatches<'_>) {
+    let auction_state_path = matches.value_of("auction_state_file").unwrap();
+    let auction_state_bytes =
+        fs::read(auction_state_path).expect("couldn't read the auction state file");
+    let auction_state: AuctionState =
+        toml::from_slice(&auction_state_bytes).expect("couldn't parse the auction state file");
+
+    let rekey = match matches.value_of("rekey_file") {
+        Some(rekey_path) => {
+            parse_rekey_map(&fs::read(rekey_path).expect("couldn't read the rekey file"))
+        }
+        None => BTreeMap::new(),
+    };
+    let balance = U512::from_dec_str(matches.value_of("balance").unwrap())
+        .expect("balance should be a valid decimal number");
+
+    let genesis_accounts = auction_state.to_genesis_accounts(&rekey, balance);
+
+    print!(
+        "{}",
+        toml::to_string(&genesis_accounts).expect("couldn't serialize the genesis accounts")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        system::auction::{Bid, Delegator, SeigniorageRecipient, UnbondingPurse},
+        AccessRights, EraId, SecretKey, URef,
+    };
+
+    use super::*;
+
+    fn public_key(seed: u8) -> PublicKey {
+        PublicKey::from(&SecretKey::ed25519_from_bytes([seed; SecretKey::ED25519_LENGTH]).unwrap())
+    }
+
+    fn purse(seed: u8) -> URef {
+        URef::new([seed; 32], AccessRights::READ_ADD_WRITE)
+    }
+
+    fn auction_state() -> AuctionState {
+        let validator = public_key(1);
+        let inactive_validator = public_key(2);
+        let delegator = public_key(3);
+
+        let mut bids = Bids::new();
+        let mut bid = Bid::unlocked(validator.clone(), purse(1), U512::from(100), 10);
+        bid.delegators_mut().insert(
+            delegator.clone(),
+            Delegator::unlocked(
+                delegator.clone(),
+                U512::from(50),
+                purse(3),
+                validator.clone(),
+            ),
+        );
+        bids.insert(validator.clone(), bid);
+        let mut inactive_bid =
+            Bid::unlocked(inactive_validator.clone(), purse(2), U512::from(70), 5);
+        inactive_bid.deactivate();
+        bids.insert(inactive_validator.clone(), inactive_bid);
+
+        let mut snapshot = SeigniorageRecipientsSnapshot::new();
+        let recipients = bids
+            .iter()
+            .map(|(public_key, bid)| (public_key.clone(), SeigniorageRecipient::from(bid)))
+            .collect();
+        snapshot.insert(EraId::new(3), recipients);
+
+        let mut unbonds = UnbondingPurses::new();
+        unbonds.insert(
+            delegator.to_account_hash(),
+            vec![UnbondingPurse::new(
+                purse(4),
+                validator,
+                delegator,
+                EraId::new(2),
+                U512::from(20),
+                None,
+            )],
+        );
+
+        AuctionState::new("00".to_string(), &bids, &snapshot, &unbonds)
+    }
+
+    #[test]
+    fn should_roundtrip_auction_state() {
+        let auction_state = auction_state();
+        assert_eq!(auction_state.validators.len(), 2);
+        assert_eq!(auction_state.snapshot.len(), 1);
+        assert_eq!(auction_state.unbonds.len(), 1);
+
+        let serialized = toml::to_string(&auction_state).expect("should serialize");
+        let deserialized: AuctionState = toml::from_str(&serialized).expect("should deserialize");
+        assert_eq!(deserialized, auction_state);
+    }
+
+    #[test]
+    fn should_rekey_active_stakes_into_genesis_accounts() {
+        let auction_state = auction_state();
+        let mut rekey = BTreeMap::new();
+        rekey.insert(public_key(1), public_key(11));
+        let balance = U512::from(1_000);
+
+        let genesis_accounts = auction_state.to_genesis_accounts(&rekey, balance);
+
+        assert_eq!(
+            genesis_accounts.accounts,
+            vec![GenesisAccount {
+                public_key: public_key(11),
+                balance,
+                validator: GenesisValidator {
+                    bonded_amount: U512::from(100),
+                    delegation_rate: 10,
+                },
+            }]
+        );
+        assert_eq!(
+            genesis_accounts.delegators,
+            vec![GenesisDelegator {
+                validator_public_key: public_key(11),
+                delegator_public_key: public_key(3),
+                balance,
+                delegated_amount: U512::from(50),
+            }]
+        );
+        assert!(toml::to_string(&genesis_accounts).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple public keys to the same one")]
+    fn should_reject_ambiguous_rekey_map() {
+        let rekey_file = format!(
+            "{} = \"{}\"\n{} = \"{}\"\n",
+            public_key(1).to_hex(),
+            public_key(3).to_hex(),
+            public_key(2).to_hex(),
+            public_key(3).to_hex()
+        );
+        parse_rekey_map(rekey_file.as_bytes());
+    }
+}
@@ -1,5 +1,5 @@
 pub(crate) mod config;
-mod state_reader;
+pub(crate) mod state_reader;
 mod state_tracker;
 #[cfg(test)]
 mod testing;
@@ -56,7 +56,7 @@ fn get_update<T: StateReader>(reader: T, config: Config) -> Update {
 
     let entries = state_tracker.get_entries();
 
-    Update::new(entries, validators)
+    Update::new(entries, validators, state_tracker.total_supply_key())
 }
 
 pub(crate) fn update_from_config<T: StateReader>(reader: T, config: Config) {
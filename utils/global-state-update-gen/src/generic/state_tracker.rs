@@ -53,6 +53,11 @@ impl<T: StateReader> StateTracker<T> {
         }
     }
 
+    /// Returns the key under which the total supply is stored.
+    pub fn total_supply_key(&self) -> Key {
+        self.total_supply_key
+    }
+
     /// Returns all the entries to be written to the global state
     pub fn get_entries(&self) -> BTreeMap<Key, StoredValue> {
         self.entries_to_write.clone()
@@ -343,6 +343,9 @@ fn should_transfer_funds() {
     // - balance of account 2
     // - total supply
     assert_eq!(update.len(), 3);
+
+    // writing balances and the total supply requires opting in to dangerous overrides
+    assert!(update.needs_dangerous_overrides());
 }
 
 #[test]
@@ -583,6 +586,75 @@ fn should_change_only_stake_of_one_validator() {
     assert_eq!(update.len(), 4);
 }
 
+#[test]
+fn should_not_need_dangerous_overrides_for_validator_change() {
+    let mut rng = TestRng::new();
+
+    let validator1 = PublicKey::random(&mut rng);
+    let validator2 = PublicKey::random(&mut rng);
+
+    let mut reader = MockStateReader::new().with_validators(
+        vec![
+            (
+                validator1.clone(),
+                U512::from(101),
+                ValidatorConfig {
+                    bonded_amount: U512::from(101),
+                    ..Default::default()
+                },
+            ),
+            (
+                validator2.clone(),
+                U512::from(102),
+                ValidatorConfig {
+                    bonded_amount: U512::from(102),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &mut rng,
+    );
+
+    // we'll be updating only the delegation rate of validator 2, leaving the stake as it is
+    let config = Config {
+        accounts: vec![AccountConfig {
+            public_key: validator2.clone(),
+            balance: None,
+            validator: Some(ValidatorConfig {
+                bonded_amount: U512::from(102),
+                delegation_rate: Some(5),
+                delegators: None,
+            }),
+        }],
+        ..Default::default()
+    };
+
+    let update = get_update(&mut reader, config);
+
+    // check that the update contains the correct list of validators
+    update.assert_validators(&[
+        ValidatorInfo::new(&validator1, U512::from(101)),
+        ValidatorInfo::new(&validator2, U512::from(102)),
+    ]);
+
+    update.assert_seigniorage_recipients_written(&mut reader);
+
+    // neither purse balances nor the total supply change
+    let bid_purse = *reader
+        .get_bids()
+        .get(&validator2)
+        .expect("should have bid")
+        .bonding_purse();
+    update.assert_key_absent(&Key::Balance(bid_purse.addr()));
+    update.assert_key_absent(&TOTAL_SUPPLY_KEY.into());
+
+    // only the seigniorage recipients should be written
+    assert_eq!(update.len(), 1);
+
+    // so the update shouldn't opt in to dangerous overrides
+    assert!(!update.needs_dangerous_overrides());
+}
+
 #[test]
 fn should_change_only_balance_of_one_validator() {
     let mut rng = TestRng::new();
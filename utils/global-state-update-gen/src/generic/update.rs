@@ -13,26 +13,43 @@ use casper_types::{Key, StoredValue};
 #[cfg(test)]
 use super::state_reader::StateReader;
 
-use crate::utils::{print_entry, print_validators, ValidatorInfo};
+use crate::utils::{
+    is_dangerous_override, print_allow_dangerous_overrides, print_entry, print_validators,
+    ValidatorInfo,
+};
 
 pub(crate) struct Update {
     entries: BTreeMap<Key, StoredValue>,
     // Holds the complete set of validators, only if the validator set changed
     validators: Option<Vec<ValidatorInfo>>,
+    total_supply_key: Key,
 }
 
 impl Update {
     pub(crate) fn new(
         entries: BTreeMap<Key, StoredValue>,
         validators: Option<Vec<ValidatorInfo>>,
+        total_supply_key: Key,
     ) -> Self {
         Self {
             entries,
             validators,
+            total_supply_key,
         }
     }
 
+    /// Returns whether the update overwrites balances, the total supply or the system contract
+    /// registry, and so has to opt in to `allow_dangerous_overrides`.
+    pub(crate) fn needs_dangerous_overrides(&self) -> bool {
+        self.entries
+            .keys()
+            .any(|key| is_dangerous_override(key, &self.total_supply_key))
+    }
+
     pub(crate) fn print(&self) {
+        if self.needs_dangerous_overrides() {
+            print_allow_dangerous_overrides();
+        }
         if let Some(validators) = &self.validators {
             print_validators(validators);
         }
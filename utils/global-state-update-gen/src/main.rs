@@ -1,4 +1,5 @@
 mod admins;
+mod auction_state;
 mod balances;
 mod generic;
 mod system_contract_registry;
@@ -9,7 +10,9 @@ use admins::generate_admins;
 use clap::{crate_version, App, Arg, SubCommand};
 
 use crate::{
-    balances::generate_balances_update, generic::generate_generic_update,
+    auction_state::{export_auction_state, import_auction_state},
+    balances::generate_balances_update,
+    generic::generate_generic_update,
     system_contract_registry::generate_system_contract_registry,
     validators::generate_validators_update,
 };
@@ -184,6 +187,57 @@ fn main() {
                         .number_of_values(1),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("export-auction-state")
+                .about("Exports the bids, seigniorage recipients snapshot and unbonds")
+                .arg(
+                    Arg::with_name("data_dir")
+                        .short("d")
+                        .long("data-dir")
+                        .value_name("PATH")
+                        .help("Data storage directory containing the global state database file")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("hash")
+                        .short("s")
+                        .long("state-hash")
+                        .value_name("HEX_STRING")
+                        .help("The global state hash to export the auction state at")
+                        .takes_value(true)
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("import-auction-state")
+                .about("Generates genesis accounts staking as in an exported auction state")
+                .arg(
+                    Arg::with_name("rekey_file")
+                        .short("r")
+                        .long("rekey")
+                        .value_name("FILE")
+                        .help("A TOML file mapping exported public keys to their replacements")
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("balance")
+                        .short("b")
+                        .long("balance")
+                        .value_name("MOTES")
+                        .help("The balance of every validator and delegator account")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("auction_state_file")
+                        .value_name("FILE")
+                        .index(1)
+                        .required(true)
+                        .help("The auction state exported by export-auction-state"),
+                ),
+        )
         .get_matches();
 
     match matches.subcommand() {
@@ -194,6 +248,8 @@ fn main() {
         }
         ("generic", Some(sub_matches)) => generate_generic_update(sub_matches),
         ("generate-admins", Some(sub_matches)) => generate_admins(sub_matches),
+        ("export-auction-state", Some(sub_matches)) => export_auction_state(sub_matches),
+        ("import-auction-state", Some(sub_matches)) => import_auction_state(sub_matches),
         (subcommand, _) => {
             println!("Unknown subcommand: \"{}\"", subcommand);
         }
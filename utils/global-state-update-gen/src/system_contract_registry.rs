@@ -4,14 +4,14 @@ use clap::ArgMatches;
 use lmdb::{self, Cursor, Environment, EnvironmentFlags, Transaction};
 
 use casper_engine_test_support::LmdbWasmTestBuilder;
-use casper_execution_engine::core::engine_state::SystemContractRegistry;
+use casper_execution_engine::core::engine_state::SystemEntityRegistry;
 use casper_types::{
     bytesrepr::FromBytes,
     system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
     CLValue, ContractHash, Key, StoredValue, KEY_HASH_LENGTH,
 };
 
-use crate::utils::{hash_from_str, print_entry};
+use crate::utils::{hash_from_str, print_allow_dangerous_overrides, print_entry};
 
 const DATABASE_NAME: &str = "PROTOCOL_DATA_STORE";
 
@@ -90,12 +90,13 @@ fn generate_system_contract_registry_using_protocol_data(data_dir: &Path) {
     });
     assert!(remainder.is_empty());
 
-    let mut registry = SystemContractRegistry::new();
-    registry.insert(MINT.to_string(), mint_hash);
-    registry.insert(HANDLE_PAYMENT.to_string(), handle_payment_hash);
-    registry.insert(STANDARD_PAYMENT.to_string(), standard_payment_hash);
-    registry.insert(AUCTION.to_string(), auction_hash);
+    let mut registry = SystemEntityRegistry::new();
+    registry.insert(MINT.to_string(), mint_hash.value());
+    registry.insert(HANDLE_PAYMENT.to_string(), handle_payment_hash.value());
+    registry.insert(STANDARD_PAYMENT.to_string(), standard_payment_hash.value());
+    registry.insert(AUCTION.to_string(), auction_hash.value());
 
+    print_allow_dangerous_overrides();
     print_entry(
         &Key::SystemContractRegistry,
         &StoredValue::from(CLValue::from_t(registry).unwrap()),
@@ -111,12 +112,13 @@ fn generate_system_contract_registry_using_global_state(data_dir: &Path, state_h
     let standard_payment_hash = builder.get_system_standard_payment_hash();
     let auction_hash = builder.get_system_auction_hash();
 
-    let mut registry = SystemContractRegistry::new();
-    registry.insert(MINT.to_string(), mint_hash);
-    registry.insert(HANDLE_PAYMENT.to_string(), handle_payment_hash);
-    registry.insert(STANDARD_PAYMENT.to_string(), standard_payment_hash);
-    registry.insert(AUCTION.to_string(), auction_hash);
+    let mut registry = SystemEntityRegistry::new();
+    registry.insert(MINT.to_string(), mint_hash.value());
+    registry.insert(HANDLE_PAYMENT.to_string(), handle_payment_hash.value());
+    registry.insert(STANDARD_PAYMENT.to_string(), standard_payment_hash.value());
+    registry.insert(AUCTION.to_string(), auction_hash.value());
 
+    print_allow_dangerous_overrides();
     print_entry(
         &Key::SystemContractRegistry,
         &StoredValue::from(CLValue::from_t(registry).unwrap()),
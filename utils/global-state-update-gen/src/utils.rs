@@ -16,6 +16,23 @@ pub fn hash_from_str(hex_str: &str) -> Digest {
         .unwrap()
 }
 
+/// Prints the opt-in required for an update overwriting balances, the total supply or the system
+/// contract registry. Must be printed before any `[[validators]]` or `[[entries]]` table.
+pub(crate) fn print_allow_dangerous_overrides() {
+    println!("allow_dangerous_overrides = true");
+    println!();
+}
+
+/// Returns whether an update writing `key` needs the `allow_dangerous_overrides` opt-in, i.e.
+/// whether `key` is a purse balance, the total supply under `total_supply_key` or the system
+/// contract registry.
+pub(crate) fn is_dangerous_override(key: &Key, total_supply_key: &Key) -> bool {
+    match key {
+        Key::Balance(_) | Key::SystemContractRegistry => true,
+        _ => key.normalize() == total_supply_key.normalize(),
+    }
+}
+
 pub(crate) fn print_validators(validators: &[ValidatorInfo]) {
     for validator in validators {
         println!("[[validators]]");